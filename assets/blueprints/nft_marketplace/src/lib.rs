@@ -0,0 +1,91 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor)]
+pub struct Listing {
+    pub price: Decimal,
+    pub seller: Global<Account>,
+}
+
+#[blueprint]
+mod nft_marketplace {
+    enable_package_royalties! {
+        instantiate => Free;
+        list => Free;
+        buy => Xrd(1.into());
+    }
+
+    struct NftMarketplace {
+        collection: ResourceAddress,
+        listings: KeyValueStore<NonFungibleLocalId, Listing>,
+        nfts: Vault,
+    }
+
+    impl NftMarketplace {
+        /// Instantiates a marketplace for a single non-fungible collection. The creator royalty
+        /// is charged on every sale through the component royalty of `buy`, and can be updated
+        /// and claimed by whoever satisfies the `royalty_admin` rule.
+        pub fn instantiate(
+            owner_role: OwnerRole,
+            collection: ResourceAddress,
+            royalty_admin: AccessRule,
+            creator_royalty: Decimal,
+        ) -> Global<NftMarketplace> {
+            Self {
+                collection,
+                listings: KeyValueStore::new(),
+                nfts: Vault::new(collection),
+            }
+            .instantiate()
+            .prepare_to_globalize(owner_role)
+            .enable_component_royalties(component_royalties! {
+                roles {
+                    royalty_setter => royalty_admin.clone();
+                    royalty_setter_updater => rule!(deny_all);
+                    royalty_locker => royalty_admin.clone();
+                    royalty_locker_updater => rule!(deny_all);
+                    royalty_claimer => royalty_admin;
+                    royalty_claimer_updater => rule!(deny_all);
+                },
+                init {
+                    list => Free, locked;
+                    buy => Xrd(creator_royalty), updatable;
+                }
+            })
+            .globalize()
+        }
+
+        /// Lists every non-fungible of the given bucket at the same price, paid in XRD straight
+        /// into the seller account when bought.
+        pub fn list(&mut self, nfts: Bucket, price: Decimal, seller: Global<Account>) {
+            assert_eq!(
+                nfts.resource_address(),
+                self.collection,
+                "The marketplace does not trade this resource"
+            );
+            assert!(!price.is_negative(), "The price can't be negative");
+
+            for id in nfts.as_non_fungible().non_fungible_local_ids() {
+                self.listings.insert(id, Listing { price, seller });
+            }
+            self.nfts.put(nfts);
+        }
+
+        /// Buys a listed non-fungible, returning it together with the change of the payment.
+        pub fn buy(&mut self, id: NonFungibleLocalId, mut payment: Bucket) -> (Bucket, Bucket) {
+            assert_eq!(
+                payment.resource_address(),
+                XRD,
+                "Payments must be made in XRD"
+            );
+            let Listing { price, mut seller } = self
+                .listings
+                .remove(&id)
+                .expect("The non-fungible is not listed");
+
+            seller.try_deposit_or_abort(payment.take(price));
+            let nft = self.nfts.as_non_fungible().take_non_fungible(&id);
+
+            (nft.0, payment)
+        }
+    }
+}
@@ -27,7 +27,8 @@ scrypto="scrypto"
 
 echo "Building scrypto packages used in tests..."
 (
-    find "radix-engine-tests/tests/blueprints" -mindepth 2 -maxdepth 2 -type f \( -name Cargo.toml \) -print \
+    # The workspace packages are compiled by the tests, with the addresses of their dependencies
+    find "radix-engine-tests/tests/blueprints" -mindepth 2 -maxdepth 2 -type f \( -name Cargo.toml \) -not -path "*/blueprints/workspace/*" -print \
     | awk '{print substr($1, 1, length($1)-length("Cargo.toml"))}' \
     | xargs -I '{}' bash -c "set -x; $scrypto build --path {}"
 )
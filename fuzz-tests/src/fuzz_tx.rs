@@ -18,9 +18,9 @@ use scrypto_unit::{TestRunner, TestRunnerSnapshot};
 #[cfg(test)]
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use strum::EnumCount;
-use transaction::prelude::*;
 use transaction::manifest::ast;
 use transaction::model::InstructionV1;
+use transaction::prelude::*;
 use transaction::signing::secp256k1::Secp256k1PrivateKey;
 
 use crate::common::*;
@@ -221,8 +221,42 @@ impl TxFuzzer {
                     // TODO
                     None
                 }
-                // AssertWorktopContains
+                // AssertMetadata
                 1 => {
+                    let key = String::arbitrary(&mut unstructured).unwrap();
+                    let value = MetadataValue::arbitrary(&mut unstructured).unwrap();
+
+                    Some(InstructionV1::AssertMetadata {
+                        address: component_address.into(),
+                        key,
+                        value,
+                    })
+                }
+                // AssertNextCallReturnsExactly
+                2 => Some(InstructionV1::AssertNextCallReturnsExactly {
+                    resources: vec![ResourceAssertion::Amount(
+                        resource_address,
+                        Decimal::arbitrary(&mut unstructured).unwrap(),
+                    )],
+                }),
+                // AssertNextCallReturnsInclude
+                3 => Some(InstructionV1::AssertNextCallReturnsInclude {
+                    resources: vec![ResourceAssertion::NonFungibles(
+                        resource_address,
+                        non_fungible_ids.clone(),
+                    )],
+                }),
+                // AssertNextCallReturnsValue
+                4 => {
+                    let value = Decimal::arbitrary(&mut unstructured).unwrap();
+
+                    match to_manifest_value(&value) {
+                        Ok(value) => Some(InstructionV1::AssertNextCallReturnsValue { value }),
+                        Err(_) => None,
+                    }
+                }
+                // AssertWorktopContains
+                5 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::AssertWorktopContains {
@@ -231,20 +265,20 @@ impl TxFuzzer {
                     })
                 }
                 // AssertWorktopContainsAny
-                2 => Some(InstructionV1::AssertWorktopContainsAny { resource_address }),
+                6 => Some(InstructionV1::AssertWorktopContainsAny { resource_address }),
                 // AssertWorktopContainsNonFungibles
-                3 => Some(InstructionV1::AssertWorktopContainsNonFungibles {
+                7 => Some(InstructionV1::AssertWorktopContainsNonFungibles {
                     resource_address,
                     ids: non_fungible_ids.clone(),
                 }),
                 // BurnResource
-                4 => {
+                8 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::BurnResource { bucket_id })
                 }
                 // CallAccessRulesMethod
-                5 => {
+                9 => {
                     // TODO - fuzz more methods
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
@@ -260,22 +294,22 @@ impl TxFuzzer {
                     }
                 }
                 // CallFunction
-                6 => {
+                10 => {
                     // TODO
                     None
                 }
                 // CallMetadataMethod
-                7 => {
+                11 => {
                     // TODO
                     None
                 }
                 // CallMethod
-                8 => {
+                12 => {
                     // TODO
                     None
                 }
                 // CallRoyaltyMethod
-                9 =>
+                13 =>
                 // TODO - fuzz more methods
                 {
                     Some(InstructionV1::CallRoyaltyMethod {
@@ -285,13 +319,13 @@ impl TxFuzzer {
                     })
                 }
                 // ClaimComponentRoyalty
-                10 => Some(InstructionV1::CallRoyaltyMethod {
+                14 => Some(InstructionV1::CallRoyaltyMethod {
                     address: component_address.into(),
                     method_name: COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT.to_string(),
                     args: manifest_args!().into(),
                 }),
                 // ClaimPackageRoyalty
-                11 => {
+                15 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     Some(InstructionV1::CallMethod {
@@ -301,17 +335,17 @@ impl TxFuzzer {
                     })
                 }
                 // ClearAuthZone
-                12 => Some(InstructionV1::ClearAuthZone),
+                16 => Some(InstructionV1::ClearAuthZone),
                 // ClearSignatureProofs
-                13 => Some(InstructionV1::ClearSignatureProofs),
+                17 => Some(InstructionV1::ClearSignatureProofs),
                 // CloneProof
-                14 => {
+                18 => {
                     let proof_id = *unstructured.choose(&proof_ids[..]).unwrap();
 
                     Some(InstructionV1::CloneProof { proof_id })
                 }
                 // CreateAccessController
-                15 => {
+                19 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
@@ -323,11 +357,12 @@ impl TxFuzzer {
                         package_address: package_address.into(),
                         blueprint_name: ACCESS_CONTROLLER_BLUEPRINT.to_string(),
                         function_name: ACCESS_CONTROLLER_CREATE_GLOBAL_IDENT.to_string(),
-                        args: manifest_args!(bucket_id, rule_set, timed_recovery_delay_in_minutes).into(),
+                        args: manifest_args!(bucket_id, rule_set, timed_recovery_delay_in_minutes)
+                            .into(),
                     })
                 }
                 // CreateAccount
-                16 => {
+                20 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = AccountCreateInput::arbitrary(&mut unstructured).unwrap();
@@ -343,7 +378,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateAccountAdvanced
-                17 => {
+                21 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = AccountCreateAdvancedInput::arbitrary(&mut unstructured).unwrap();
@@ -359,7 +394,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateFungibleResource
-                18 => {
+                22 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -377,7 +412,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateFungibleResourceWithInitialSupply
-                19 => {
+                23 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -399,7 +434,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateIdentity
-                20 => {
+                24 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = IdentityCreateInput::arbitrary(&mut unstructured).unwrap();
@@ -415,7 +450,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateIdentityAdvanced
-                21 => {
+                25 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = IdentityCreateAdvancedInput::arbitrary(&mut unstructured).unwrap();
@@ -431,7 +466,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateNonFungibleResource
-                22 => {
+                26 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -450,7 +485,7 @@ impl TxFuzzer {
                 }
 
                 // CreateNonFungibleResourceWithInitialSupply
-                23 => {
+                27 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -472,9 +507,9 @@ impl TxFuzzer {
                     }
                 }
                 // CreateProofFromAuthZoneofAll
-                24 => Some(InstructionV1::CreateProofFromAuthZoneOfAll { resource_address }),
+                28 => Some(InstructionV1::CreateProofFromAuthZoneOfAll { resource_address }),
                 // CreateProofFromAuthZoneOfAmount
-                25 => {
+                29 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CreateProofFromAuthZoneOfAmount {
@@ -483,32 +518,32 @@ impl TxFuzzer {
                     })
                 }
                 // CreateProofFromAuthZoneOfNonFungibles
-                26 => Some(InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                30 => Some(InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
                     ids: non_fungible_ids.clone(),
                     resource_address,
                 }),
                 // CreateProofFromBucketOfAll
-                27 => {
+                31 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::CreateProofFromBucketOfAll { bucket_id })
                 }
                 // CreateProofFromBucketOfAmount
-                28 => {
+                32 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount })
                 }
                 // CreateProofFromBucketOfNonFungibles
-                29 => {
+                33 => {
                     let ids = non_fungible_ids.clone();
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids })
                 }
                 // CreateValidator
-                30 => {
+                34 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     let input = ConsensusManagerCreateValidatorManifestInput {
@@ -527,15 +562,15 @@ impl TxFuzzer {
                     }
                 }
                 // DropAllProofs
-                31 => Some(InstructionV1::DropAllProofs),
+                35 => Some(InstructionV1::DropAllProofs),
                 // DropProof
-                32 => {
+                36 => {
                     let proof_id = *unstructured.choose(&proof_ids[..]).unwrap();
 
                     Some(InstructionV1::DropProof { proof_id })
                 }
                 // FreezeVault
-                33 => {
+                37 => {
                     let vault_id = {
                         let vaults = self
                             .runner
@@ -560,7 +595,7 @@ impl TxFuzzer {
                     }
                 }
                 // LockComponentRoyalty
-                34 => {
+                38 => {
                     let method = String::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CallRoyaltyMethod {
@@ -570,7 +605,7 @@ impl TxFuzzer {
                     })
                 }
                 // LockMetadata
-                35 => {
+                39 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let key = String::arbitrary(&mut unstructured).unwrap();
@@ -582,7 +617,7 @@ impl TxFuzzer {
                     })
                 }
                 // LockOwnerRole
-                36 => {
+                40 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let input =
@@ -598,7 +633,7 @@ impl TxFuzzer {
                     }
                 }
                 // MintFungible
-                37 => {
+                41 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CallMethod {
@@ -608,7 +643,7 @@ impl TxFuzzer {
                     })
                 }
                 // MintNonFungible
-                38 => {
+                42 => {
                     let input =
                         NonFungibleResourceManagerMintManifestInput::arbitrary(&mut unstructured)
                             .unwrap();
@@ -623,7 +658,7 @@ impl TxFuzzer {
                     }
                 }
                 // MintRuidNonFungible
-                39 => {
+                43 => {
                     let input = NonFungibleResourceManagerMintRuidManifestInput::arbitrary(
                         &mut unstructured,
                     )
@@ -639,9 +674,9 @@ impl TxFuzzer {
                     }
                 }
                 // PopFromAuthZone
-                40 => Some(InstructionV1::PopFromAuthZone {}),
+                44 => Some(InstructionV1::PopFromAuthZone {}),
                 // PublishPackage | PublishPackageAdvanced
-                41 | 42 => {
+                44 | 45 => {
                     // Publishing package involves a compilation by scrypto compiler.
                     // In case of AFL invoking external tool breaks fuzzing.
                     // For now we skip this step
@@ -650,13 +685,13 @@ impl TxFuzzer {
                     None
                 }
                 // PushToAuthZone
-                43 => {
+                47 => {
                     let proof_id = *unstructured.choose(&proof_ids[..]).unwrap();
 
                     Some(InstructionV1::PushToAuthZone { proof_id })
                 }
                 // RecallFromVault
-                44 => {
+                48 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
                     let vault_id = {
                         let vaults = self
@@ -678,7 +713,7 @@ impl TxFuzzer {
                     })
                 }
                 // RecallNonFungiblesFromVault
-                45 => {
+                49 => {
                     let input = NonFungibleVaultRecallNonFungiblesInput {
                         non_fungible_local_ids: BTreeSet::from_iter(
                             non_fungible_ids.clone().into_iter(),
@@ -695,7 +730,7 @@ impl TxFuzzer {
                     }
                 }
                 // RemoveMetadata
-                46 => {
+                50 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let key = String::arbitrary(&mut unstructured).unwrap();
@@ -707,13 +742,17 @@ impl TxFuzzer {
                     })
                 }
                 // ReturnToWorktop
-                47 => {
+                51 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::ReturnToWorktop { bucket_id })
                 }
+                // RollbackToSavepoint
+                52 => Some(InstructionV1::RollbackToSavepoint),
+                // Savepoint
+                53 => Some(InstructionV1::Savepoint),
                 // SetComponentRoyalty
-                48 => {
+                54 => {
                     let method = String::arbitrary(&mut unstructured).unwrap();
                     let amount = RoyaltyAmount::arbitrary(&mut unstructured).unwrap();
 
@@ -724,7 +763,7 @@ impl TxFuzzer {
                     })
                 }
                 // SetMetadata
-                49 => {
+                55 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let key = String::arbitrary(&mut unstructured).unwrap();
@@ -737,7 +776,7 @@ impl TxFuzzer {
                     })
                 }
                 // SetOwnerRole
-                50 => {
+                56 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let input = AccessRulesSetOwnerRoleInput::arbitrary(&mut unstructured).unwrap();
@@ -752,7 +791,7 @@ impl TxFuzzer {
                     }
                 }
                 // SetRole
-                51 => {
+                57 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let input = AccessRulesSetRoleInput::arbitrary(&mut unstructured).unwrap();
@@ -767,9 +806,9 @@ impl TxFuzzer {
                     }
                 }
                 // TakeAllFromWorktop
-                52 => Some(InstructionV1::TakeAllFromWorktop { resource_address }),
+                58 => Some(InstructionV1::TakeAllFromWorktop { resource_address }),
                 // TakeFromWorktop
-                53 => {
+                59 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::TakeFromWorktop {
@@ -778,12 +817,12 @@ impl TxFuzzer {
                     })
                 }
                 // TakeNonFungiblesFromWorktop
-                54 => Some(InstructionV1::TakeNonFungiblesFromWorktop {
+                60 => Some(InstructionV1::TakeNonFungiblesFromWorktop {
                     ids: non_fungible_ids.clone(),
                     resource_address,
                 }),
                 // UnfreezeVault
-                55 => {
+                61 => {
                     let vault_id = {
                         let vaults = self
                             .runner
@@ -822,7 +861,14 @@ impl TxFuzzer {
                 ),
             };
             if let Some(instruction) = instruction {
-                let (updated_builder, NewSymbols { new_bucket, new_proof, .. }) = builder.add_instruction_advanced(instruction);
+                let (
+                    updated_builder,
+                    NewSymbols {
+                        new_bucket,
+                        new_proof,
+                        ..
+                    },
+                ) = builder.add_instruction_advanced(instruction);
                 builder = updated_builder;
                 if let Some(bucket_id) = new_bucket {
                     buckets.push(bucket_id)
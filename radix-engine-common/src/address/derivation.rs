@@ -0,0 +1,59 @@
+use crate::crypto::{hash, Hash, PublicKey};
+use crate::types::{ComponentAddress, EntityType, NodeId};
+
+/// Pre-computes the address of the virtual account controlled by the given public key.
+pub fn derive_virtual_account_address<P: Into<PublicKey> + Clone>(
+    public_key: &P,
+) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(public_key)
+}
+
+/// Pre-computes the address of the virtual identity controlled by the given public key.
+pub fn derive_virtual_identity_address<P: Into<PublicKey> + Clone>(
+    public_key: &P,
+) -> ComponentAddress {
+    ComponentAddress::virtual_identity_from_public_key(public_key)
+}
+
+/// Predicts the id of the node allocated by a transaction at the given (0-based) index, given
+/// the intent hash of the transaction and the entity type of the node.
+///
+/// Every node created by the transaction is counted, in creation order, including the internal
+/// and transient ones such as vaults and buckets, so the index of a given entity depends on the
+/// exact sequence of invocations made by the transaction.
+pub fn derive_transaction_node_id(
+    intent_hash: &Hash,
+    index: u32,
+    entity_type: EntityType,
+) -> NodeId {
+    // Compute `hash(intent_hash, index)`
+    let mut buf = [0u8; Hash::LENGTH + 4];
+    buf[..Hash::LENGTH].copy_from_slice(intent_hash.as_ref());
+    buf[Hash::LENGTH..].copy_from_slice(&index.to_le_bytes());
+    let hash = hash(buf);
+
+    // Install the entity type
+    let mut node_id: [u8; NodeId::LENGTH] = hash.lower_bytes();
+    node_id[0] = entity_type as u8;
+
+    NodeId(node_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_transaction_node_id() {
+        let node_id = derive_transaction_node_id(
+            &Hash([0u8; Hash::LENGTH]),
+            1,
+            EntityType::GlobalFungibleResourceManager,
+        );
+
+        assert_eq!(
+            node_id.to_hex(),
+            "5de641a22a8f5e0a42652fe82dc231fd27f8c4ffb9bc927265c92ecadc67"
+        );
+    }
+}
@@ -1,10 +1,12 @@
 mod decoder;
+mod derivation;
 mod display;
 mod encoder;
 mod errors;
 mod hrpset;
 
 pub use decoder::*;
+pub use derivation::*;
 pub use display::*;
 pub use encoder::*;
 pub use errors::*;
@@ -493,6 +493,80 @@ well_known_scrypto_custom_type!(
 
 manifest_type!(Decimal, ManifestCustomValueKind::Decimal, Decimal::BITS / 8);
 
+//=============
+// fixed point
+//=============
+
+impl Decimal {
+    /// Creates a `Decimal` from its underlying integer, which counts units of `10^-18`, or attos.
+    pub fn from_attos(attos: BnumI256) -> Self {
+        Self(attos)
+    }
+
+    /// Returns the underlying integer of this number, which counts units of `10^-18`, or attos.
+    pub fn to_attos(&self) -> BnumI256 {
+        self.0
+    }
+
+    /// Parses a number in scientific notation, such as `1.5e12` or `-25E-3`. Plain decimal
+    /// numbers are accepted too.
+    ///
+    /// Fails with `UnsupportedDecimalPlace` if the number has non-zero digits beyond the
+    /// `Decimal::SCALE` decimal places, rather than silently rounding it.
+    pub fn from_scientific(s: &str) -> Result<Self, ParseDecimalError> {
+        let (mantissa, exponent) = match s.find(|c: char| c == 'e' || c == 'E') {
+            Some(index) => (&s[..index], &s[index + 1..]),
+            None => (s, "0"),
+        };
+        let exponent = i64::from_str(exponent).map_err(|_| ParseDecimalError::InvalidDigit)?;
+        let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits = BnumI256::from_str(&format!("{}{}", int_part, frac_part))
+            .map_err(|_| ParseDecimalError::InvalidDigit)?;
+        if digits.is_zero() {
+            return Ok(Self::ZERO);
+        }
+
+        let scale = exponent + Self::SCALE as i64 - frac_part.len() as i64;
+        let power_of_ten = |n: i64| {
+            u32::try_from(n)
+                .ok()
+                .and_then(|n| BnumI256::TEN.0.checked_pow(n))
+                .map(BnumI256)
+        };
+        if scale >= 0 {
+            power_of_ten(scale)
+                .and_then(|multiplier| digits.checked_mul(multiplier))
+                .map(Self)
+                .ok_or(ParseDecimalError::Overflow)
+        } else {
+            match power_of_ten(-scale) {
+                Some(divisor) if (digits % divisor).is_zero() => Ok(Self(digits / divisor)),
+                _ => Err(ParseDecimalError::UnsupportedDecimalPlace),
+            }
+        }
+    }
+
+    /// Returns the largest integer that is equal to or less than this number, or `None` if it
+    /// does not fit in a `u64`.
+    pub fn to_u64_floor(&self) -> Option<u64> {
+        if self.is_negative() {
+            return None;
+        }
+        u64::try_from(self.0 / Self::ONE.0).ok()
+    }
+
+    /// Returns the smallest integer that is equal to or greater than this number, or `None` if it
+    /// does not fit in a `u64`.
+    pub fn to_u64_ceil(&self) -> Option<u64> {
+        let quotient = u64::try_from(self.0 / Self::ONE.0).ok()?;
+        if (self.0 % Self::ONE.0).is_positive() {
+            quotient.checked_add(1)
+        } else {
+            Some(quotient)
+        }
+    }
+}
+
 //======
 // text
 //======
@@ -690,6 +764,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_attos_decimal() {
+        assert_eq!(
+            Decimal::from_attos(BnumI256::ONE),
+            dec!("0.000000000000000001")
+        );
+        assert_eq!(
+            Decimal::from_attos(BnumI256::from(-15) * BnumI256::TEN.pow(17)),
+            dec!("-1.5")
+        );
+        assert_eq!(
+            dec!("1.5").to_attos(),
+            BnumI256::from(15) * BnumI256::TEN.pow(17)
+        );
+        assert_eq!(Decimal::from_attos(Decimal::MAX.to_attos()), Decimal::MAX);
+    }
+
+    #[test]
+    fn test_from_scientific_decimal() {
+        assert_eq!(
+            Decimal::from_scientific("1.5e12").unwrap(),
+            dec!("1500000000000")
+        );
+        assert_eq!(Decimal::from_scientific("-25E-3").unwrap(), dec!("-0.025"));
+        assert_eq!(
+            Decimal::from_scientific("1e-18").unwrap(),
+            dec!("0.000000000000000001")
+        );
+        assert_eq!(Decimal::from_scientific("123.45").unwrap(), dec!("123.45"));
+        assert_eq!(Decimal::from_scientific("0e1000").unwrap(), Decimal::ZERO);
+        assert_eq!(
+            Decimal::from_scientific("1.50e-18"),
+            Err(ParseDecimalError::UnsupportedDecimalPlace)
+        );
+        assert_eq!(
+            Decimal::from_scientific("1e-19"),
+            Err(ParseDecimalError::UnsupportedDecimalPlace)
+        );
+        assert_eq!(
+            Decimal::from_scientific("1e60"),
+            Err(ParseDecimalError::Overflow)
+        );
+        assert_eq!(
+            Decimal::from_scientific("1.5e"),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            Decimal::from_scientific("1.5x3"),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+    }
+
+    #[test]
+    fn test_to_u64_decimal() {
+        assert_eq!(dec!("1.5").to_u64_floor(), Some(1));
+        assert_eq!(dec!("1.5").to_u64_ceil(), Some(2));
+        assert_eq!(dec!("2").to_u64_floor(), Some(2));
+        assert_eq!(dec!("2").to_u64_ceil(), Some(2));
+        assert_eq!(dec!("-0.5").to_u64_floor(), None);
+        assert_eq!(dec!("-0.5").to_u64_ceil(), Some(0));
+        assert_eq!(dec!("-1").to_u64_ceil(), None);
+        assert_eq!(Decimal::from(u64::MAX).to_u64_floor(), Some(u64::MAX));
+        assert_eq!((Decimal::from(u64::MAX) + dec!("0.1")).to_u64_ceil(), None);
+        assert_eq!(Decimal::MAX.to_u64_floor(), None);
+        assert_eq!(Decimal::MIN.to_u64_ceil(), None);
+    }
+
     #[test]
     fn test_add_decimal() {
         let a = Decimal::from(5u32);
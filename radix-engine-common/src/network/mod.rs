@@ -1,5 +1,5 @@
+use sbor::rust::prelude::*;
 use sbor::rust::str::FromStr;
-use sbor::rust::string::String;
 use sbor::*;
 
 /// Network Definition is intended to be the actual definition of a network
@@ -52,8 +52,41 @@ impl NetworkDefinition {
             hrp_suffix: String::from("rdx"),
         }
     }
+
+    /// Defines a private network, e.g. for a consortium deployment, whose addresses are branded
+    /// with the given HRP suffix.
+    ///
+    /// The suffix must consist of lowercase ASCII letters, digits and underscores only, as bech32
+    /// decoding always yields a lowercase HRP.
+    pub fn custom(
+        id: u8,
+        logical_name: &str,
+        hrp_suffix: &str,
+    ) -> Result<NetworkDefinition, ParseNetworkError> {
+        if logical_name.is_empty() {
+            return Err(ParseNetworkError::EmptyLogicalName);
+        }
+        if hrp_suffix.is_empty()
+            || hrp_suffix.len() > MAX_HRP_SUFFIX_LENGTH
+            || !hrp_suffix
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+        {
+            return Err(ParseNetworkError::InvalidHrpSuffix(hrp_suffix.to_string()));
+        }
+
+        Ok(NetworkDefinition {
+            id,
+            logical_name: logical_name.to_string(),
+            hrp_suffix: hrp_suffix.to_string(),
+        })
+    }
 }
 
+/// The longest HRP suffix allowed for custom networks, which keeps the longest HRP (e.g.
+/// `internal_keyvaluestore_<suffix>`) within the bech32 limit of 83 characters.
+pub const MAX_HRP_SUFFIX_LENGTH: usize = 60;
+
 impl FromStr for NetworkDefinition {
     type Err = ParseNetworkError;
 
@@ -63,12 +96,77 @@ impl FromStr for NetworkDefinition {
             "adapanet" => Ok(NetworkDefinition::adapanet()),
             "nebunet" => Ok(NetworkDefinition::nebunet()),
             "mainnet" => Ok(NetworkDefinition::mainnet()),
-            _ => Err(ParseNetworkError::InvalidNetworkString),
+            // A custom network, in form of "<logical_name>:<id>:<hrp_suffix>"
+            custom => match custom.split(':').collect::<Vec<_>>().as_slice() {
+                [logical_name, id, hrp_suffix] => {
+                    let id = id
+                        .parse::<u8>()
+                        .map_err(|_| ParseNetworkError::InvalidNetworkId(id.to_string()))?;
+                    NetworkDefinition::custom(id, logical_name, hrp_suffix)
+                }
+                _ => Err(ParseNetworkError::InvalidNetworkString),
+            },
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParseNetworkError {
     InvalidNetworkString,
+    InvalidNetworkId(String),
+    EmptyLogicalName,
+    InvalidHrpSuffix(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
+    use crate::types::*;
+
+    #[test]
+    fn custom_network_can_be_parsed() {
+        assert_eq!(
+            NetworkDefinition::from_str("consortium:240:cons"),
+            Ok(NetworkDefinition {
+                id: 240,
+                logical_name: "consortium".to_string(),
+                hrp_suffix: "cons".to_string(),
+            })
+        );
+        assert_eq!(
+            NetworkDefinition::from_str("consortium:256:cons"),
+            Err(ParseNetworkError::InvalidNetworkId("256".to_string()))
+        );
+        assert_eq!(
+            NetworkDefinition::from_str("consortium:240:co-ns"),
+            Err(ParseNetworkError::InvalidHrpSuffix("co-ns".to_string()))
+        );
+        assert_eq!(
+            NetworkDefinition::from_str("consortium:240"),
+            Err(ParseNetworkError::InvalidNetworkString)
+        );
+    }
+
+    #[test]
+    fn addresses_of_custom_network_are_branded_with_its_hrp_suffix() {
+        let network = NetworkDefinition::custom(240, "consortium", "cons").unwrap();
+        let node_id = [EntityType::GlobalFungibleResourceManager as u8; NodeId::LENGTH];
+
+        let address = AddressBech32Encoder::new(&network)
+            .encode(&node_id)
+            .unwrap();
+
+        assert!(address.starts_with("resource_cons1"));
+        assert_eq!(
+            AddressBech32Decoder::new(&network)
+                .validate_and_decode(&address)
+                .unwrap()
+                .1,
+            node_id.to_vec()
+        );
+        assert!(AddressBech32Decoder::for_simulator()
+            .validate_and_decode(&address)
+            .is_err());
+    }
 }
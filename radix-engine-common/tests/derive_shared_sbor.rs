@@ -0,0 +1,104 @@
+use radix_engine_common::prelude::*;
+use sbor::Describe;
+
+// Types shared between the Scrypto and Manifest extensions, with bounds over both custom value kinds
+
+#[derive(ScryptoSbor, ManifestSbor, PartialEq, Eq, Debug, Clone)]
+pub struct SharedStruct<T: ScryptoEncode + ManifestEncode>
+where
+    T: ScryptoDecode + ManifestDecode,
+{
+    pub a: T,
+    pub b: Vec<T>,
+    #[sbor(skip)]
+    pub c: u32,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, PartialEq, Eq, Debug, Clone)]
+#[sbor(transparent)]
+pub struct SharedTransparent<T: ScryptoEncode + ManifestEncode>(pub T);
+
+#[derive(ScryptoSbor, ManifestSbor, PartialEq, Eq, Debug, Clone)]
+pub enum SharedEnum<T: ScryptoEncode + ManifestEncode, U> {
+    A { named: T },
+    B(U, Vec<T>),
+    C,
+}
+
+#[test]
+fn shared_struct_can_be_encoded_with_both_extensions() {
+    let value = SharedStruct {
+        a: 1u32,
+        b: vec![2u32, 3u32],
+        c: 0,
+    };
+
+    let scrypto_payload = scrypto_encode(&value).unwrap();
+    let manifest_payload = manifest_encode(&value).unwrap();
+
+    assert_eq!(
+        scrypto_decode::<SharedStruct<u32>>(&scrypto_payload).unwrap(),
+        value
+    );
+    assert_eq!(
+        manifest_decode::<SharedStruct<u32>>(&manifest_payload).unwrap(),
+        value
+    );
+    assert_eq!(scrypto_payload[1..], manifest_payload[1..]);
+}
+
+#[test]
+fn shared_transparent_struct_can_be_encoded_with_both_extensions() {
+    let value = SharedTransparent(SharedTransparent(5u8));
+
+    let scrypto_payload = scrypto_encode(&value).unwrap();
+    let manifest_payload = manifest_encode(&value).unwrap();
+
+    assert_eq!(scrypto_payload, scrypto_encode(&5u8).unwrap());
+    assert_eq!(manifest_payload, manifest_encode(&5u8).unwrap());
+    assert_eq!(
+        scrypto_decode::<SharedTransparent<SharedTransparent<u8>>>(&scrypto_payload).unwrap(),
+        value
+    );
+    assert_eq!(
+        manifest_decode::<SharedTransparent<SharedTransparent<u8>>>(&manifest_payload).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn shared_enum_can_be_encoded_with_both_extensions() {
+    let values: Vec<SharedEnum<String, Decimal>> = vec![
+        SharedEnum::A {
+            named: "a".to_owned(),
+        },
+        SharedEnum::B(Decimal::ONE, vec!["b".to_owned()]),
+        SharedEnum::C,
+    ];
+
+    for value in values {
+        let scrypto_payload = scrypto_encode(&value).unwrap();
+        let manifest_payload = manifest_encode(&value).unwrap();
+
+        assert_eq!(
+            scrypto_decode::<SharedEnum<String, Decimal>>(&scrypto_payload).unwrap(),
+            value
+        );
+        assert_eq!(
+            manifest_decode::<SharedEnum<String, Decimal>>(&manifest_payload).unwrap(),
+            value
+        );
+    }
+}
+
+#[test]
+fn shared_generic_types_are_described_per_type_parameter() {
+    assert_ne!(
+        <SharedStruct<u32> as Describe<ScryptoCustomTypeKind>>::TYPE_ID,
+        <SharedStruct<String> as Describe<ScryptoCustomTypeKind>>::TYPE_ID
+    );
+    assert_ne!(
+        <SharedEnum<u32, u8> as Describe<ScryptoCustomTypeKind>>::TYPE_ID,
+        <SharedEnum<u32, u16> as Describe<ScryptoCustomTypeKind>>::TYPE_ID
+    );
+}
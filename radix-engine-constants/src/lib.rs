@@ -113,9 +113,39 @@ pub const DEFAULT_MAX_PANIC_MESSAGE_SIZE: usize = 64 * 1024;
 /// The max number of events
 pub const DEFAULT_MAX_NUMBER_OF_EVENTS: usize = 256;
 
+/// The max total size of all events emitted by a transaction
+pub const DEFAULT_MAX_TOTAL_EVENT_SIZE: usize = 1024 * 1024;
+
 /// The max number of logs
 pub const DEFAULT_MAX_NUMBER_OF_LOGS: usize = 256;
 
+/// The max metric name size
+pub const DEFAULT_MAX_METRIC_NAME_SIZE: usize = 256;
+
+/// The max number of metrics
+pub const DEFAULT_MAX_NUMBER_OF_METRICS: usize = 256;
+
+/// The default max number of substates read from the database, unlimited.
+pub const DEFAULT_MAX_NUMBER_OF_SUBSTATE_READS: usize = usize::MAX;
+
+/// The default max number of substates written, unlimited.
+pub const DEFAULT_MAX_NUMBER_OF_SUBSTATE_WRITES: usize = usize::MAX;
+
+/// The default max total size of the substates read from the database, unlimited.
+pub const DEFAULT_MAX_TOTAL_SUBSTATE_READ_SIZE: usize = usize::MAX;
+
+/// The default max total size of the substates written, unlimited.
+pub const DEFAULT_MAX_TOTAL_SUBSTATE_WRITE_SIZE: usize = usize::MAX;
+
+/// The default max number of heap nodes a call frame, including its callees, may add.
+pub const DEFAULT_MAX_HEAP_NODES_PER_CALL_FRAME: usize = 16 * 1024;
+
+/// The default max total size of the heap substates a call frame, including its callees, may add.
+pub const DEFAULT_MAX_HEAP_SUBSTATE_SIZE_PER_CALL_FRAME: usize = 64 * 1024 * 1024;
+
+/// The default max number of WASM invocations per transaction, including nested ones.
+pub const DEFAULT_MAX_WASM_INVOCATIONS: usize = 1024;
+
 /// The max SBOR size of metadata key
 pub const DEFAULT_MAX_METADATA_KEY_STRING_LEN: usize = 100;
 
@@ -135,5 +165,8 @@ pub const DEFAULT_STATE_EXPANSION_PRICE_IN_XRD: &str = "0.00001";
 /// The default USD price, in XRD
 pub const DEFAULT_USD_PRICE_IN_XRD: &str = "10";
 
+/// The default multiplier applied to the costs of cryptographic operations, in percentage.
+pub const DEFAULT_CRYPTO_COST_MULTIPLIER_PERCENTAGE: u32 = 100;
+
 /// The default maximum that a package or component owner is allowed to set their method royalty to
 pub const DEFAULT_MAX_PER_FUNCTION_ROYALTY_IN_XRD: &str = "150.0";
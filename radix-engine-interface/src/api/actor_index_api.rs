@@ -81,6 +81,37 @@ pub trait ClientActorIndexApi<E> {
         Ok(entries)
     }
 
+    /// Scans elements of count from an index, starting after the element with the given key.
+    /// The elements are returned in a stable order, so that a large index can be read page by
+    /// page by passing the key of the last element of a page as the cursor for the next one.
+    fn actor_index_scan_after(
+        &mut self,
+        object_handle: ObjectHandle,
+        collection_index: CollectionIndex,
+        cursor: Option<Vec<u8>>,
+        count: u32,
+    ) -> Result<Vec<Vec<u8>>, E>;
+
+    /// Scans elements of count from an index, starting after the element with the given key
+    fn actor_index_scan_after_typed<S: ScryptoDecode>(
+        &mut self,
+        object_handle: ObjectHandle,
+        collection_index: CollectionIndex,
+        cursor: Option<Vec<u8>>,
+        count: u32,
+    ) -> Result<Vec<S>, E> {
+        let entries = self
+            .actor_index_scan_after(object_handle, collection_index, cursor, count)?
+            .into_iter()
+            .map(|buf| {
+                let typed: S = scrypto_decode(&buf).unwrap();
+                typed
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
     /// Removes and returns arbitrary elements of count from an index
     fn actor_index_take(
         &mut self,
@@ -0,0 +1,17 @@
+use crate::api::{FieldIndex, ObjectHandle};
+use radix_engine_common::types::GlobalAddress;
+use radix_engine_derive::ScryptoSbor;
+use sbor::rust::prelude::*;
+
+/// A read-only call which can be submitted as part of a batch, so that a blueprint
+/// can perform several reads with a single WASM/host boundary crossing.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum BatchCall {
+    /// Reads a field of an actor object; returns the encoded field value.
+    ActorFieldRead {
+        object_handle: ObjectHandle,
+        field: FieldIndex,
+    },
+    /// Reads a metadata entry of a global entity; returns the encoded `Option<MetadataValue>`.
+    GetMetadata { address: GlobalAddress, key: String },
+}
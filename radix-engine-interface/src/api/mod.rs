@@ -2,6 +2,7 @@ pub mod actor_api;
 pub mod actor_index_api;
 pub mod actor_key_value_entry_api;
 pub mod actor_sorted_index_api;
+pub mod batch_api;
 pub mod blueprint_api;
 pub mod field_lock_api;
 pub mod key_value_entry_api;
@@ -15,6 +16,7 @@ pub use actor_api::ClientActorApi;
 use actor_index_api::ClientActorIndexApi;
 pub use actor_key_value_entry_api::ClientActorKeyValueEntryApi;
 pub use actor_sorted_index_api::ClientActorSortedIndexApi;
+pub use batch_api::BatchCall;
 pub use blueprint_api::ClientBlueprintApi;
 pub use field_lock_api::ClientFieldLockApi;
 pub use field_lock_api::LockFlags;
@@ -109,6 +109,20 @@ pub trait ClientObjectApi<E> {
 
     fn get_reservation_address(&mut self, node_id: &NodeId) -> Result<GlobalAddress, E>;
 
+    /// Reads the metadata entry of a visible global object, without calling the object.
+    /// Returns the encoded `Option<MetadataValue>`.
+    fn get_metadata(&mut self, address: GlobalAddress, key: &str) -> Result<Vec<u8>, E>;
+
+    /// Lists the nodes directly owned by a visible object or key value store, across all of its
+    /// partitions, such as its vaults and key value stores. The nodes are listed in a stable
+    /// order, from which `count` nodes are returned, starting at `offset`.
+    fn list_owned_nodes(
+        &mut self,
+        node_id: &NodeId,
+        offset: u32,
+        count: u32,
+    ) -> Result<Vec<NodeId>, E>;
+
     /// Pre-allocates a global address, for a future globalization.
     fn allocate_global_address(
         &mut self,
@@ -1,3 +1,7 @@
 pub trait ClientExecutionTraceApi<E> {
     fn update_instruction_index(&mut self, new_index: usize) -> Result<(), E>;
+
+    /// Reports the memory consumed by the WASM instance of the current invocation, once it
+    /// has returned.
+    fn update_wasm_memory_usage(&mut self, consumed_memory: usize) -> Result<(), E>;
 }
@@ -1,6 +1,7 @@
 use crate::sbor::rust::prelude::*;
 use crate::types::Level;
 use radix_engine_common::crypto::Hash;
+use radix_engine_common::math::Decimal;
 
 pub trait ClientTransactionRuntimeApi<E> {
     fn get_transaction_hash(&mut self) -> Result<Hash, E>;
@@ -11,5 +12,7 @@ pub trait ClientTransactionRuntimeApi<E> {
 
     fn emit_event(&mut self, event_name: String, event_data: Vec<u8>) -> Result<(), E>;
 
+    fn emit_metric(&mut self, name: String, value: Decimal) -> Result<(), E>;
+
     fn panic(&mut self, message: String) -> Result<(), E>;
 }
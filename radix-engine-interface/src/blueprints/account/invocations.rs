@@ -156,6 +156,26 @@ pub struct AccountWithdrawNonFungiblesInput {
 
 pub type AccountWithdrawNonFungiblesOutput = Bucket;
 
+//========================
+// Account Withdraw Batch
+//========================
+
+pub const ACCOUNT_WITHDRAW_BATCH_IDENT: &str = "withdraw_batch";
+
+/// The resources to withdraw from a single vault of an account.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub enum ResourceSpecifier {
+    Amount(ResourceAddress, Decimal),
+    Ids(ResourceAddress, BTreeSet<NonFungibleLocalId>),
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountWithdrawBatchInput {
+    pub resources: Vec<ResourceSpecifier>,
+}
+
+pub type AccountWithdrawBatchOutput = Vec<Bucket>;
+
 //=====================================
 // Account Withdraw
 //=====================================
@@ -242,6 +262,20 @@ pub struct AccountConfigureResourceDepositRuleInput {
 
 pub type AccountConfigureResourceDepositRuleOutput = ();
 
+//============================
+// Get Resource Preference
+//============================
+
+pub const ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT: &str = "get_resource_preference";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountGetResourcePreferenceInput {
+    pub resource_address: ResourceAddress,
+}
+
+/// The deposit rule configured for the resource, or `Neither` if it is on neither list.
+pub type AccountGetResourcePreferenceOutput = ResourceDepositRule;
+
 //===============================
 // Account Try Deposit Or Refund
 //===============================
@@ -321,3 +355,31 @@ pub struct AccountBurnNonFungiblesInput {
 }
 
 pub type AccountBurnNonFungiblesOutput = ();
+
+//=========================
+// Account Balances
+//=========================
+
+pub const ACCOUNT_BALANCES_IDENT: &str = "balances";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountBalancesInput {
+    pub resources: Vec<ResourceAddress>,
+}
+
+/// The balance of each of the requested resources, in the requested order. Resources which the
+/// account holds no vault of have a zero balance.
+pub type AccountBalancesOutput = Vec<(ResourceAddress, Decimal)>;
+
+//=========================
+// Account Non Fungible Ids Of
+//=========================
+
+pub const ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT: &str = "non_fungible_ids_of";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountNonFungibleIdsOfInput {
+    pub resource_address: ResourceAddress,
+}
+
+pub type AccountNonFungibleIdsOfOutput = BTreeSet<NonFungibleLocalId>;
@@ -0,0 +1,86 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+pub const ACCOUNT_LOCKER_BLUEPRINT: &str = "AccountLocker";
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        storer_rule: AccessRule,
+        recoverer_rule: AccessRule
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        storer_rule: AccessRule,
+        recoverer_rule: AccessRule
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: store,
+    input: struct {
+        claimant: ComponentAddress,
+        bucket: Bucket,
+        try_direct_send: bool
+    },
+    output: type (),
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        bucket: ManifestBucket,
+        try_direct_send: bool
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: claim,
+    input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    },
+    output: type (),
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: recover,
+    input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: get_amount,
+    input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress
+    },
+    output: type Decimal,
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress
+    }
+}
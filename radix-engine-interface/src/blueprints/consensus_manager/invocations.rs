@@ -49,6 +49,9 @@ pub struct ConsensusManagerConfig {
     pub num_fee_increase_delay_epochs: u64,
 
     pub validator_creation_usd_cost: Decimal,
+
+    /// The price of one USD in XRD, used to charge costs and royalties denominated in USD.
+    pub usd_price_in_xrd: Decimal,
 }
 
 impl ConsensusManagerConfig {
@@ -86,6 +89,11 @@ impl ConsensusManagerConfig {
         self.num_fee_increase_delay_epochs = new_value;
         self
     }
+
+    pub fn with_usd_price_in_xrd(mut self, new_value: Decimal) -> Self {
+        self.usd_price_in_xrd = new_value;
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor, ManifestSbor)]
@@ -287,6 +295,41 @@ pub struct LeaderProposalHistory {
 
 pub type ConsensusManagerNextRoundOutput = ();
 
+pub const CONSENSUS_MANAGER_UPDATE_USD_PRICE_IDENT: &str = "update_usd_price";
+
+#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+pub struct ConsensusManagerUpdateUsdPriceInput {
+    /// The new price of one USD, in XRD.
+    pub usd_price_in_xrd: Decimal,
+}
+
+pub type ConsensusManagerUpdateUsdPriceOutput = ();
+
+pub const CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT: &str = "update_config";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ConsensusManagerUpdateConfigInput {
+    /// The new condition for changing epochs, effective from the next round.
+    pub epoch_change_condition: EpochChangeCondition,
+    /// The new maximum number of validators in the active set, effective from the next epoch.
+    pub max_validators: u32,
+}
+
+pub type ConsensusManagerUpdateConfigOutput = ();
+
+pub const CONSENSUS_MANAGER_UPDATE_EMISSION_CONFIG_IDENT: &str = "update_emission_config";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ConsensusManagerUpdateEmissionConfigInput {
+    /// The new amount of XRD emitted per epoch, effective from the current epoch's emissions.
+    pub total_emission_xrd_per_epoch: Decimal,
+    /// The new proportion of proposals a validator needs to complete in an epoch to get
+    /// emissions, between 0 and 1.
+    pub min_validator_reliability: Decimal,
+}
+
+pub type ConsensusManagerUpdateEmissionConfigOutput = ();
+
 pub const CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT: &str = "create_validator";
 
 #[derive(Debug, Eq, PartialEq, ScryptoSbor)]
@@ -399,6 +442,43 @@ pub struct ValidatorUpdateFeeInput {
 
 pub type ValidatorUpdateFeeOutput = ();
 
+pub const VALIDATOR_SCHEDULE_FEE_CHANGE_IDENT: &str = "schedule_fee_change";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ValidatorScheduleFeeChangeInput {
+    /// A fraction of the effective emission amount which gets transferred to the validator's owner.
+    /// Must be within `[0.0, 1.0]`.
+    pub new_fee_factor: Decimal,
+    /// The number of epochs after which the change becomes effective. It is raised to the
+    /// configured fee increase delay for an increase, and to 1 for a decrease.
+    pub delay_epochs: u64,
+}
+
+pub type ValidatorScheduleFeeChangeOutput = ();
+
+pub const VALIDATOR_GET_PENDING_FEE_CHANGE_IDENT: &str = "get_pending_fee_change";
+
+#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+pub struct ValidatorGetPendingFeeChangeInput {}
+
+/// The requested fee change which is not yet effective, if any.
+pub type ValidatorGetPendingFeeChangeOutput = Option<ValidatorFeeChangeRequest>;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ValidatorFeeChangeRequest {
+    /// An epoch number at (or after) which the fee change is effective.
+    /// To be specific: when a next epoch `N` begins, we perform accounting of emissions due for
+    /// previous epoch `N-1` - this means that we will use this [`new_validator_fee_factor`] only if
+    /// `epoch_effective <= N-1`, and [`ValidatorSubstate.validator_fee_factor`] otherwise.
+    /// Note: when requesting a fee decrease, this will be "next epoch"; and when requesting an
+    /// increase, this will be set to [`ConsensusManagerConfigSubstate.num_fee_increase_delay_epochs`]
+    /// epochs away (unless a longer delay is scheduled).
+    pub epoch_effective: Epoch,
+
+    /// A requested new value of [`ConsensusManagerSubstate.validator_fee_factor`].
+    pub new_fee_factor: Decimal,
+}
+
 pub const VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT: &str = "update_accept_delegated_stake";
 
 #[derive(Debug, Clone, Eq, PartialEq, Sbor)]
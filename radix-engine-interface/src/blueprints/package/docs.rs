@@ -0,0 +1,600 @@
+use super::{
+    AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
+    PackageDefinition, RoleSpecification,
+};
+use crate::blueprints::resource::{MethodAccessibility, MethodKey};
+use radix_engine_common::data::scrypto::{ScryptoCustomTypeKind, ScryptoSchema};
+use sbor::rust::prelude::*;
+use sbor::*;
+use scrypto_schema::{BlueprintCollectionSchema, Condition, Receiver, TypeRef};
+
+/// Generates Markdown documentation of each blueprint of a package, describing its state,
+/// functions and methods, roles and events as recorded in the package definition.
+pub fn generate_package_markdown_docs(definition: &PackageDefinition) -> String {
+    let mut output = String::new();
+
+    for (blueprint_name, blueprint) in &definition.blueprints {
+        let docs = BlueprintDocs::new(blueprint);
+
+        output.push_str(&format!("# {}\n\n", blueprint_name));
+        if let Some(outer_blueprint) = &docs.outer_blueprint {
+            output.push_str(&format!("Inner blueprint of `{}`.\n\n", outer_blueprint));
+        }
+
+        output.push_str("## State\n\n");
+        if docs.fields.is_empty() && docs.collections.is_empty() {
+            output.push_str("This blueprint has no state.\n\n");
+        }
+        for field in &docs.fields {
+            output.push_str(&format!("### Field {}: `{}`", field.index, field.type_name));
+            if let Some(condition) = &field.condition {
+                output.push_str(&format!(" ({})", condition));
+            }
+            output.push_str("\n\n");
+            push_markdown_members(&mut output, &field.members);
+        }
+        for (index, collection) in docs.collections.iter().enumerate() {
+            output.push_str(&format!("### Collection {}: {}\n\n", index, collection));
+        }
+
+        output.push_str("## Functions and Methods\n\n");
+        if docs.functions.is_empty() {
+            output.push_str("This blueprint has no functions or methods.\n\n");
+        }
+        for function in &docs.functions {
+            output.push_str(&format!("### `{}`\n\n", function.signature()));
+            output.push_str(&format!("- Kind: {}\n", function.kind));
+            output.push_str(&format!("- Access: {}\n\n", function.access));
+        }
+
+        output.push_str("## Roles\n\n");
+        if docs.roles.is_empty() {
+            output.push_str("This blueprint defines no roles.\n\n");
+        } else {
+            output.push_str("| Role | Updaters |\n| --- | --- |\n");
+            for (role, updaters) in &docs.roles {
+                output.push_str(&format!("| `{}` | {} |\n", role, updaters));
+            }
+            output.push_str("\n");
+        }
+
+        output.push_str("## Events\n\n");
+        if docs.events.is_empty() {
+            output.push_str("This blueprint emits no events.\n\n");
+        }
+        for event in &docs.events {
+            output.push_str(&format!("### `{}`\n\n", event.name));
+            push_markdown_members(&mut output, &event.members);
+        }
+    }
+
+    output
+}
+
+/// Generates a standalone HTML page with the same content as [`generate_package_markdown_docs`].
+pub fn generate_package_html_docs(definition: &PackageDefinition) -> String {
+    let mut output = String::new();
+    output.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    output.push_str("<title>Package Documentation</title>\n</head>\n<body>\n");
+
+    for (blueprint_name, blueprint) in &definition.blueprints {
+        let docs = BlueprintDocs::new(blueprint);
+
+        output.push_str(&format!(
+            "<section id=\"{0}\">\n<h1>{0}</h1>\n",
+            escape_html(blueprint_name)
+        ));
+        if let Some(outer_blueprint) = &docs.outer_blueprint {
+            output.push_str(&format!(
+                "<p>Inner blueprint of <code>{}</code>.</p>\n",
+                escape_html(outer_blueprint)
+            ));
+        }
+
+        output.push_str("<h2>State</h2>\n");
+        if docs.fields.is_empty() && docs.collections.is_empty() {
+            output.push_str("<p>This blueprint has no state.</p>\n");
+        }
+        for field in &docs.fields {
+            output.push_str(&format!(
+                "<h3>Field {}: <code>{}</code>",
+                field.index,
+                escape_html(&field.type_name)
+            ));
+            if let Some(condition) = &field.condition {
+                output.push_str(&format!(" ({})", escape_html(condition)));
+            }
+            output.push_str("</h3>\n");
+            push_html_members(&mut output, &field.members);
+        }
+        for (index, collection) in docs.collections.iter().enumerate() {
+            output.push_str(&format!(
+                "<h3>Collection {}: {}</h3>\n",
+                index,
+                escape_html(collection)
+            ));
+        }
+
+        output.push_str("<h2>Functions and Methods</h2>\n");
+        if docs.functions.is_empty() {
+            output.push_str("<p>This blueprint has no functions or methods.</p>\n");
+        }
+        for function in &docs.functions {
+            output.push_str(&format!(
+                "<h3><code>{}</code></h3>\n<ul>\n<li>Kind: {}</li>\n<li>Access: {}</li>\n</ul>\n",
+                escape_html(&function.signature()),
+                escape_html(function.kind),
+                escape_html(&function.access)
+            ));
+        }
+
+        output.push_str("<h2>Roles</h2>\n");
+        if docs.roles.is_empty() {
+            output.push_str("<p>This blueprint defines no roles.</p>\n");
+        } else {
+            output.push_str("<table>\n<tr><th>Role</th><th>Updaters</th></tr>\n");
+            for (role, updaters) in &docs.roles {
+                output.push_str(&format!(
+                    "<tr><td><code>{}</code></td><td>{}</td></tr>\n",
+                    escape_html(role),
+                    escape_html(updaters)
+                ));
+            }
+            output.push_str("</table>\n");
+        }
+
+        output.push_str("<h2>Events</h2>\n");
+        if docs.events.is_empty() {
+            output.push_str("<p>This blueprint emits no events.</p>\n");
+        }
+        for event in &docs.events {
+            output.push_str(&format!(
+                "<h3><code>{}</code></h3>\n",
+                escape_html(&event.name)
+            ));
+            push_html_members(&mut output, &event.members);
+        }
+
+        output.push_str("</section>\n");
+    }
+
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+/// The documented parts of a blueprint, with all types already rendered.
+struct BlueprintDocs {
+    outer_blueprint: Option<String>,
+    fields: Vec<FieldDocs>,
+    collections: Vec<String>,
+    functions: Vec<FunctionDocs>,
+    roles: Vec<(String, String)>,
+    events: Vec<EventDocs>,
+}
+
+struct FieldDocs {
+    index: usize,
+    type_name: String,
+    condition: Option<String>,
+    members: Vec<(String, String)>,
+}
+
+struct FunctionDocs {
+    name: String,
+    kind: &'static str,
+    arguments: Vec<(String, String)>,
+    output: String,
+    access: String,
+}
+
+struct EventDocs {
+    name: String,
+    members: Vec<(String, String)>,
+}
+
+impl FunctionDocs {
+    fn signature(&self) -> String {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|(name, type_name)| format!("{}: {}", name, type_name))
+            .collect::<Vec<_>>();
+        format!("{}({}) -> {}", self.name, arguments.join(", "), self.output)
+    }
+}
+
+impl BlueprintDocs {
+    fn new(blueprint: &BlueprintDefinitionInit) -> Self {
+        let schema = &blueprint.schema.schema;
+
+        let outer_blueprint = match &blueprint.blueprint_type {
+            BlueprintType::Outer => None,
+            BlueprintType::Inner { outer_blueprint } => Some(outer_blueprint.clone()),
+        };
+
+        let fields = blueprint
+            .schema
+            .state
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| FieldDocs {
+                index,
+                type_name: type_ref_name(schema, &field.field),
+                condition: match &field.condition {
+                    Condition::Always => None,
+                    Condition::IfFeature(feature) => {
+                        Some(format!("if feature {} is enabled", feature))
+                    }
+                    Condition::IfOuterFeature(feature) => {
+                        Some(format!("if outer feature {} is enabled", feature))
+                    }
+                },
+                members: type_ref_members(schema, &field.field),
+            })
+            .collect();
+
+        let collections = blueprint
+            .schema
+            .state
+            .collections
+            .iter()
+            .map(|collection| match collection {
+                BlueprintCollectionSchema::KeyValueStore(kv_store) => format!(
+                    "key value store of `{}` to `{}`",
+                    type_ref_name(schema, &kv_store.key),
+                    type_ref_name(schema, &kv_store.value)
+                ),
+                BlueprintCollectionSchema::Index(..) => "index".to_string(),
+                BlueprintCollectionSchema::SortedIndex(..) => "sorted index".to_string(),
+            })
+            .collect();
+
+        let functions = blueprint
+            .schema
+            .functions
+            .functions
+            .iter()
+            .map(|(function_name, function)| FunctionDocs {
+                name: function_name.clone(),
+                kind: match &function.receiver {
+                    None => "function",
+                    Some(receiver) => match receiver.receiver {
+                        Receiver::SelfRef => "method (&self)",
+                        Receiver::SelfRefMut => "method (&mut self)",
+                    },
+                },
+                arguments: type_ref_members(schema, &function.input),
+                output: type_ref_name(schema, &function.output),
+                access: match &function.receiver {
+                    None => function_access(&blueprint.auth_config, function_name),
+                    Some(..) => method_access(&blueprint.auth_config, function_name),
+                },
+            })
+            .collect();
+
+        let roles = match &blueprint.auth_config.method_auth {
+            MethodAuthTemplate::StaticRoles(static_roles) => match &static_roles.roles {
+                RoleSpecification::Normal(roles) => roles
+                    .iter()
+                    .map(|(role, updaters)| {
+                        let updaters = if updaters.list.is_empty() {
+                            "nobody".to_string()
+                        } else {
+                            role_list(updaters.list.iter().map(|role| role.key.as_str()))
+                        };
+                        (role.key.clone(), updaters)
+                    })
+                    .collect(),
+                RoleSpecification::UseOuter => Vec::new(),
+            },
+            MethodAuthTemplate::AllowAll => Vec::new(),
+        };
+
+        let events = blueprint
+            .schema
+            .events
+            .event_schema
+            .iter()
+            .map(|(event_name, type_ref)| EventDocs {
+                name: event_name.clone(),
+                members: type_ref_members(schema, type_ref),
+            })
+            .collect();
+
+        Self {
+            outer_blueprint,
+            fields,
+            collections,
+            functions,
+            roles,
+            events,
+        }
+    }
+}
+
+fn function_access(auth_config: &AuthConfig, function_name: &str) -> String {
+    match &auth_config.function_auth {
+        FunctionAuth::AllowAll => "public".to_string(),
+        FunctionAuth::AccessRules(rules) => {
+            if rules.contains_key(function_name) {
+                "protected by an access rule".to_string()
+            } else {
+                "public".to_string()
+            }
+        }
+        FunctionAuth::RootOnly => "root transaction only".to_string(),
+    }
+}
+
+fn method_access(auth_config: &AuthConfig, method_name: &str) -> String {
+    match &auth_config.method_auth {
+        MethodAuthTemplate::AllowAll => "public".to_string(),
+        MethodAuthTemplate::StaticRoles(static_roles) => {
+            match static_roles.methods.get(&MethodKey::new(method_name)) {
+                Some(MethodAccessibility::Public) => "public".to_string(),
+                Some(MethodAccessibility::OuterObjectOnly) => "outer object only".to_string(),
+                Some(MethodAccessibility::OwnPackageOnly) => "own package only".to_string(),
+                Some(MethodAccessibility::RoleProtected(roles)) if roles.list.is_empty() => {
+                    "nobody".to_string()
+                }
+                Some(MethodAccessibility::RoleProtected(roles)) => {
+                    format!(
+                        "roles {}",
+                        role_list(roles.list.iter().map(|role| role.key.as_str()))
+                    )
+                }
+                None => "public".to_string(),
+            }
+        }
+    }
+}
+
+fn role_list<'a>(roles: impl Iterator<Item = &'a str>) -> String {
+    roles.collect::<Vec<_>>().join(", ")
+}
+
+fn type_ref_name(schema: &ScryptoSchema, type_ref: &TypeRef<LocalTypeIndex>) -> String {
+    match type_ref {
+        TypeRef::Static(index) => type_name(schema, *index),
+        TypeRef::Generic(index) => format!("Generic{}", index),
+    }
+}
+
+/// The named (or positional) fields of a struct type, used to list the members of state fields
+/// and events and the arguments of functions.
+fn type_ref_members(
+    schema: &ScryptoSchema,
+    type_ref: &TypeRef<LocalTypeIndex>,
+) -> Vec<(String, String)> {
+    let TypeRef::Static(index) = type_ref else {
+        return Vec::new();
+    };
+    let Some(TypeKind::Tuple { field_types }) = schema.resolve_type_kind(*index) else {
+        return Vec::new();
+    };
+
+    let field_names = schema
+        .resolve_matching_tuple_metadata(*index, field_types.len())
+        .field_names;
+    field_types
+        .iter()
+        .enumerate()
+        .map(|(i, field_type)| {
+            let field_name = field_names
+                .map(|field_names| field_names[i].to_string())
+                .unwrap_or_else(|| i.to_string());
+            (field_name, type_name(schema, *field_type))
+        })
+        .collect()
+}
+
+/// Renders a type in a Rust-like notation, using the names recorded in the schema.
+fn type_name(schema: &ScryptoSchema, index: LocalTypeIndex) -> String {
+    let Some(type_kind) = schema.resolve_type_kind(index) else {
+        return "Unknown".to_string();
+    };
+    let name = schema
+        .resolve_type_metadata(index)
+        .and_then(|metadata| metadata.get_name())
+        .filter(|name| !name.is_empty());
+
+    match type_kind {
+        TypeKind::Any => "Any".to_string(),
+        TypeKind::Bool => "bool".to_string(),
+        TypeKind::I8 => "i8".to_string(),
+        TypeKind::I16 => "i16".to_string(),
+        TypeKind::I32 => "i32".to_string(),
+        TypeKind::I64 => "i64".to_string(),
+        TypeKind::I128 => "i128".to_string(),
+        TypeKind::U8 => "u8".to_string(),
+        TypeKind::U16 => "u16".to_string(),
+        TypeKind::U32 => "u32".to_string(),
+        TypeKind::U64 => "u64".to_string(),
+        TypeKind::U128 => "u128".to_string(),
+        TypeKind::String => "String".to_string(),
+        TypeKind::Array { element_type } => format!("Vec<{}>", type_name(schema, *element_type)),
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "Map<{}, {}>",
+            type_name(schema, *key_type),
+            type_name(schema, *value_type)
+        ),
+        TypeKind::Enum { variants } if name == Some("Option") => match variants.get(&1) {
+            Some(field_types) if field_types.len() == 1 => {
+                format!("Option<{}>", type_name(schema, field_types[0]))
+            }
+            _ => "Option".to_string(),
+        },
+        TypeKind::Tuple { field_types } => match name {
+            Some(name) => name.to_string(),
+            None => {
+                let field_types = field_types
+                    .iter()
+                    .map(|field_type| type_name(schema, *field_type))
+                    .collect::<Vec<_>>();
+                format!("({})", field_types.join(", "))
+            }
+        },
+        TypeKind::Enum { .. } => name.unwrap_or("Enum").to_string(),
+        TypeKind::Custom(custom_type_kind) => match name {
+            Some(name) => name.to_string(),
+            None => match custom_type_kind {
+                ScryptoCustomTypeKind::Reference => "Reference",
+                ScryptoCustomTypeKind::Own => "Own",
+                ScryptoCustomTypeKind::Decimal => "Decimal",
+                ScryptoCustomTypeKind::PreciseDecimal => "PreciseDecimal",
+                ScryptoCustomTypeKind::NonFungibleLocalId => "NonFungibleLocalId",
+            }
+            .to_string(),
+        },
+    }
+}
+
+fn push_markdown_members(output: &mut String, members: &[(String, String)]) {
+    if members.is_empty() {
+        return;
+    }
+    output.push_str("| Name | Type |\n| --- | --- |\n");
+    for (name, type_name) in members {
+        output.push_str(&format!("| `{}` | `{}` |\n", name, type_name));
+    }
+    output.push_str("\n");
+}
+
+fn push_html_members(output: &mut String, members: &[(String, String)]) {
+    if members.is_empty() {
+        return;
+    }
+    output.push_str("<table>\n<tr><th>Name</th><th>Type</th></tr>\n");
+    for (name, type_name) in members {
+        output.push_str(&format!(
+            "<tr><td><code>{}</code></td><td><code>{}</code></td></tr>\n",
+            escape_html(name),
+            escape_html(type_name)
+        ));
+    }
+    output.push_str("</table>\n");
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blueprints::package::{BlueprintDefinitionInit, StaticRoles};
+    use crate::blueprints::resource::{RoleKey, RoleList};
+    use radix_engine_common::math::Decimal;
+    use radix_engine_common::types::ResourceAddress;
+    use radix_engine_common::ScryptoSbor;
+    use scrypto_schema::{
+        BlueprintEventSchemaInit, BlueprintFunctionsSchemaInit, BlueprintSchemaInit,
+        BlueprintStateSchemaInit, FieldSchema, FunctionSchemaInit, ReceiverInfo,
+    };
+    use utils::btreemap;
+
+    #[derive(ScryptoSbor)]
+    struct Faucet {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct FaucetFreeInput {
+        amount: Option<Decimal>,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct FreeEvent {
+        amount: Decimal,
+    }
+
+    fn definition() -> PackageDefinition {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let state_type = aggregator.add_child_type_and_descendents::<Faucet>();
+        let input_type = aggregator.add_child_type_and_descendents::<FaucetFreeInput>();
+        let output_type = aggregator.add_child_type_and_descendents::<Decimal>();
+        let event_type = aggregator.add_child_type_and_descendents::<FreeEvent>();
+
+        PackageDefinition {
+            blueprints: btreemap!(
+                "Faucet".to_string() => BlueprintDefinitionInit {
+                    schema: BlueprintSchemaInit {
+                        schema: generate_full_schema(aggregator),
+                        state: BlueprintStateSchemaInit {
+                            fields: vec![FieldSchema::static_field(state_type)],
+                            collections: vec![],
+                        },
+                        events: BlueprintEventSchemaInit {
+                            event_schema: btreemap!(
+                                "FreeEvent".to_string() => TypeRef::Static(event_type)
+                            ),
+                        },
+                        functions: BlueprintFunctionsSchemaInit {
+                            functions: btreemap!(
+                                "free".to_string() => FunctionSchemaInit {
+                                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                                    input: TypeRef::Static(input_type),
+                                    output: TypeRef::Static(output_type),
+                                    export: "Faucet_free".to_string(),
+                                }
+                            ),
+                            virtual_lazy_load_functions: btreemap!(),
+                        },
+                        ..Default::default()
+                    },
+                    auth_config: AuthConfig {
+                        function_auth: FunctionAuth::AllowAll,
+                        method_auth: MethodAuthTemplate::StaticRoles(StaticRoles {
+                            roles: RoleSpecification::Normal(btreemap!(
+                                RoleKey::new("admin") => RoleList::none()
+                            )),
+                            methods: btreemap!(
+                                MethodKey::new("free") => MethodAccessibility::from(["admin"])
+                            ),
+                        }),
+                    },
+                    ..Default::default()
+                }
+            ),
+        }
+    }
+
+    #[test]
+    fn test_markdown_docs_describe_blueprint() {
+        let docs = generate_package_markdown_docs(&definition());
+
+        assert!(docs.contains("# Faucet\n"));
+        assert!(docs.contains("### Field 0: `Faucet`\n"));
+        assert!(docs.contains("| `resource_address` | `ResourceAddress` |\n"));
+        assert!(docs.contains("### `free(amount: Option<Decimal>) -> Decimal`\n"));
+        assert!(docs.contains("- Kind: method (&mut self)\n"));
+        assert!(docs.contains("- Access: roles admin\n"));
+        assert!(docs.contains("| `admin` | nobody |\n"));
+        assert!(docs.contains("### `FreeEvent`\n"));
+    }
+
+    #[test]
+    fn test_html_docs_escape_types() {
+        let docs = generate_package_html_docs(&definition());
+
+        assert!(docs.contains("<h1>Faucet</h1>"));
+        assert!(docs.contains("<code>free(amount: Option&lt;Decimal&gt;) -&gt; Decimal</code>"));
+        assert!(docs.contains("<li>Kind: method (&amp;mut self)</li>"));
+        assert!(docs.contains("<li>Access: roles admin</li>"));
+    }
+}
@@ -0,0 +1,521 @@
+use super::PackageDefinition;
+use radix_engine_common::data::scrypto::{ScryptoCustomTypeKind, ScryptoSchema};
+use sbor::rust::prelude::*;
+use sbor::*;
+use scrypto_schema::TypeRef;
+use serde_json::{json, Map, Value};
+
+pub const EVENT_JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates a JSON Schema describing the events of each blueprint of a package, as they are
+/// rendered by the natural JSON serialization of SBOR.
+///
+/// The types of the events are placed under `$defs`, and the `events` keyword maps each
+/// blueprint and event name to the schema of the event. Generic events can only be described
+/// once instantiated, so they are left unconstrained.
+pub fn generate_event_json_schema(definition: &PackageDefinition) -> Value {
+    let mut defs = Map::new();
+    let mut events = Map::new();
+
+    for (blueprint_name, blueprint) in &definition.blueprints {
+        let mut generator = EventTypeGenerator::new(blueprint_name, &blueprint.schema.schema);
+
+        let mut blueprint_events = Map::new();
+        for (event_name, type_ref) in &blueprint.schema.events.event_schema {
+            let event_schema = match type_ref {
+                TypeRef::Static(index) => generator.json_schema(*index),
+                TypeRef::Generic(_) => json!({}),
+            };
+            blueprint_events.insert(event_name.clone(), event_schema);
+        }
+        if blueprint_events.is_empty() {
+            continue;
+        }
+
+        defs.extend(generator.drain_pending(|generator, index| generator.json_schema_def(index)));
+        events.insert(blueprint_name.clone(), Value::Object(blueprint_events));
+    }
+
+    json!({
+        "$schema": EVENT_JSON_SCHEMA_DIALECT,
+        "$defs": defs,
+        "events": events,
+    })
+}
+
+/// Generates TypeScript types of the events of each blueprint of a package, matching the natural
+/// JSON serialization of SBOR.
+///
+/// Each blueprint with events gets an `<Blueprint>Events` interface, mapping each event name to
+/// the type of the event.
+pub fn generate_event_typescript_types(definition: &PackageDefinition) -> String {
+    let mut output = String::new();
+
+    for (blueprint_name, blueprint) in &definition.blueprints {
+        let mut generator = EventTypeGenerator::new(blueprint_name, &blueprint.schema.schema);
+
+        let mut blueprint_events = Vec::new();
+        for (event_name, type_ref) in &blueprint.schema.events.event_schema {
+            let event_type = match type_ref {
+                TypeRef::Static(index) => generator.typescript_type(*index),
+                TypeRef::Generic(_) => "unknown".to_string(),
+            };
+            blueprint_events.push((event_name, event_type));
+        }
+        if blueprint_events.is_empty() {
+            continue;
+        }
+
+        let mut defs =
+            generator.drain_pending(|generator, index| generator.typescript_type_def(index));
+        defs.sort();
+        for (name, def) in defs {
+            output.push_str(&format!("export type {} = {};\n\n", name, def));
+        }
+
+        output.push_str(&format!(
+            "export interface {}Events {{\n",
+            sanitize_identifier(blueprint_name)
+        ));
+        for (event_name, event_type) in blueprint_events {
+            output.push_str(&format!("  {}: {};\n", quote(event_name), event_type));
+        }
+        output.push_str("}\n\n");
+    }
+
+    output
+}
+
+/// Walks the types reachable from the events of a blueprint. Well-known types are inlined, while
+/// the types local to the schema are named, so that recursive types can be described.
+struct EventTypeGenerator<'s> {
+    blueprint_name: &'s str,
+    schema: &'s ScryptoSchema,
+    names: BTreeMap<usize, String>,
+    used_names: BTreeSet<String>,
+    pending: Vec<usize>,
+}
+
+impl<'s> EventTypeGenerator<'s> {
+    fn new(blueprint_name: &'s str, schema: &'s ScryptoSchema) -> Self {
+        Self {
+            blueprint_name,
+            schema,
+            names: BTreeMap::new(),
+            used_names: BTreeSet::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    fn name_of(&mut self, index: usize) -> String {
+        if let Some(name) = self.names.get(&index) {
+            return name.clone();
+        }
+
+        let type_name = self
+            .schema
+            .resolve_type_metadata(LocalTypeIndex::SchemaLocalIndex(index))
+            .and_then(|metadata| metadata.get_name())
+            .map(sanitize_identifier)
+            .filter(|type_name| !type_name.is_empty())
+            .unwrap_or_else(|| format!("Type{}", index));
+        let mut name = format!("{}_{}", sanitize_identifier(self.blueprint_name), type_name);
+        if self.used_names.contains(&name) {
+            name = format!("{}_{}", name, index);
+        }
+
+        self.used_names.insert(name.clone());
+        self.names.insert(index, name.clone());
+        self.pending.push(index);
+        name
+    }
+
+    /// Generates the definitions of all named types which have been referenced so far, including
+    /// the ones referenced while generating them.
+    fn drain_pending<T>(
+        &mut self,
+        mut generate: impl FnMut(&mut Self, LocalTypeIndex) -> T,
+    ) -> Vec<(String, T)> {
+        let mut defs = Vec::new();
+        while let Some(index) = self.pending.pop() {
+            let def = generate(self, LocalTypeIndex::SchemaLocalIndex(index));
+            defs.push((self.names[&index].clone(), def));
+        }
+        defs
+    }
+
+    fn is_u8(&self, index: LocalTypeIndex) -> bool {
+        matches!(self.schema.resolve_type_kind(index), Some(TypeKind::U8))
+    }
+
+    fn is_string(&self, index: LocalTypeIndex) -> bool {
+        matches!(self.schema.resolve_type_kind(index), Some(TypeKind::String))
+    }
+
+    //==================
+    // JSON Schema
+    //==================
+
+    fn json_schema(&mut self, index: LocalTypeIndex) -> Value {
+        match index {
+            LocalTypeIndex::SchemaLocalIndex(local_index) => {
+                json!({ "$ref": format!("#/$defs/{}", self.name_of(local_index)) })
+            }
+            LocalTypeIndex::WellKnown(_) => self.json_schema_def(index),
+        }
+    }
+
+    fn json_schema_def(&mut self, index: LocalTypeIndex) -> Value {
+        let schema = self.schema;
+        let Some(type_kind) = schema.resolve_type_kind(index) else {
+            return json!({});
+        };
+
+        match type_kind {
+            TypeKind::Any => json!({}),
+            TypeKind::Bool => json!({ "type": "boolean" }),
+            TypeKind::I8
+            | TypeKind::I16
+            | TypeKind::I32
+            | TypeKind::U8
+            | TypeKind::U16
+            | TypeKind::U32 => json!({ "type": "integer" }),
+            // These may exceed the safe integer range of JavaScript, so are rendered as strings
+            TypeKind::I64 | TypeKind::I128 | TypeKind::U64 | TypeKind::U128 => {
+                json!({ "type": "string", "pattern": "^-?[0-9]+$" })
+            }
+            TypeKind::String => json!({ "type": "string" }),
+            TypeKind::Array { element_type } if self.is_u8(*element_type) => json!({
+                "type": "object",
+                "properties": { "hex": { "type": "string" } },
+                "required": ["hex"],
+                "additionalProperties": false,
+            }),
+            TypeKind::Array { element_type } => json!({
+                "type": "array",
+                "items": self.json_schema(*element_type),
+            }),
+            TypeKind::Tuple { field_types } => {
+                let field_names = schema
+                    .resolve_matching_tuple_metadata(index, field_types.len())
+                    .field_names;
+                self.json_schema_of_fields(field_types, field_names)
+            }
+            TypeKind::Enum { variants } => {
+                let mut variant_schemas = Vec::new();
+                for (variant_id, field_types) in variants {
+                    let variant_data = schema.resolve_matching_enum_metadata(
+                        index,
+                        *variant_id,
+                        field_types.len(),
+                    );
+                    let mut properties = Map::new();
+                    properties.insert("variant_id".to_string(), json!({ "const": variant_id }));
+                    if let Some(variant_name) = variant_data.variant_name {
+                        properties
+                            .insert("variant_name".to_string(), json!({ "const": variant_name }));
+                    }
+                    properties.insert(
+                        "fields".to_string(),
+                        self.json_schema_of_fields(field_types, variant_data.field_names),
+                    );
+                    variant_schemas.push(json!({
+                        "type": "object",
+                        "properties": properties,
+                        "required": ["variant_id", "fields"],
+                    }));
+                }
+                json!({ "oneOf": variant_schemas })
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } if self.is_string(*key_type) => json!({
+                "type": "object",
+                "additionalProperties": self.json_schema(*value_type),
+            }),
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "key": self.json_schema(*key_type),
+                        "value": self.json_schema(*value_type),
+                    },
+                    "required": ["key", "value"],
+                },
+            }),
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal)
+            | TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+                json!({ "type": "string" })
+            }
+            TypeKind::Custom(custom_type_kind) => json!({
+                "type": "object",
+                "properties": {
+                    "kind": { "const": tagged_custom_kind_name(custom_type_kind) },
+                    "value": { "type": "string" },
+                },
+                "required": ["kind", "value"],
+            }),
+        }
+    }
+
+    fn json_schema_of_fields(
+        &mut self,
+        field_types: &[LocalTypeIndex],
+        field_names: Option<&[Cow<'static, str>]>,
+    ) -> Value {
+        match field_names {
+            Some(field_names) => {
+                let mut properties = Map::new();
+                for (field_name, field_type) in field_names.iter().zip(field_types) {
+                    properties.insert(field_name.to_string(), self.json_schema(*field_type));
+                }
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": field_names,
+                    "additionalProperties": false,
+                })
+            }
+            None => {
+                let items = field_types
+                    .iter()
+                    .map(|field_type| self.json_schema(*field_type))
+                    .collect::<Vec<_>>();
+                json!({
+                    "type": "array",
+                    "prefixItems": items,
+                    "minItems": field_types.len(),
+                    "maxItems": field_types.len(),
+                })
+            }
+        }
+    }
+
+    //==================
+    // TypeScript
+    //==================
+
+    fn typescript_type(&mut self, index: LocalTypeIndex) -> String {
+        match index {
+            LocalTypeIndex::SchemaLocalIndex(local_index) => self.name_of(local_index),
+            LocalTypeIndex::WellKnown(_) => self.typescript_type_def(index),
+        }
+    }
+
+    fn typescript_type_def(&mut self, index: LocalTypeIndex) -> String {
+        let schema = self.schema;
+        let Some(type_kind) = schema.resolve_type_kind(index) else {
+            return "unknown".to_string();
+        };
+
+        match type_kind {
+            TypeKind::Any => "unknown".to_string(),
+            TypeKind::Bool => "boolean".to_string(),
+            TypeKind::I8
+            | TypeKind::I16
+            | TypeKind::I32
+            | TypeKind::U8
+            | TypeKind::U16
+            | TypeKind::U32 => "number".to_string(),
+            TypeKind::I64 | TypeKind::I128 | TypeKind::U64 | TypeKind::U128 | TypeKind::String => {
+                "string".to_string()
+            }
+            TypeKind::Array { element_type } if self.is_u8(*element_type) => {
+                "{ hex: string }".to_string()
+            }
+            TypeKind::Array { element_type } => {
+                format!("Array<{}>", self.typescript_type(*element_type))
+            }
+            TypeKind::Tuple { field_types } => {
+                let field_names = schema
+                    .resolve_matching_tuple_metadata(index, field_types.len())
+                    .field_names;
+                self.typescript_type_of_fields(field_types, field_names)
+            }
+            TypeKind::Enum { variants } => {
+                let mut variant_types = Vec::new();
+                for (variant_id, field_types) in variants {
+                    let variant_data = schema.resolve_matching_enum_metadata(
+                        index,
+                        *variant_id,
+                        field_types.len(),
+                    );
+                    let variant_name = variant_data
+                        .variant_name
+                        .map(|variant_name| format!(" variant_name: {};", quote(variant_name)))
+                        .unwrap_or_default();
+                    variant_types.push(format!(
+                        "{{ variant_id: {};{} fields: {} }}",
+                        variant_id,
+                        variant_name,
+                        self.typescript_type_of_fields(field_types, variant_data.field_names)
+                    ));
+                }
+                if variant_types.is_empty() {
+                    "never".to_string()
+                } else {
+                    variant_types.join(" | ")
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } if self.is_string(*key_type) => {
+                format!("Record<string, {}>", self.typescript_type(*value_type))
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "Array<{{ key: {}; value: {} }}>",
+                self.typescript_type(*key_type),
+                self.typescript_type(*value_type)
+            ),
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal)
+            | TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => "string".to_string(),
+            TypeKind::Custom(custom_type_kind) => format!(
+                "{{ kind: {}; value: string }}",
+                quote(tagged_custom_kind_name(custom_type_kind))
+            ),
+        }
+    }
+
+    fn typescript_type_of_fields(
+        &mut self,
+        field_types: &[LocalTypeIndex],
+        field_names: Option<&[Cow<'static, str>]>,
+    ) -> String {
+        match field_names {
+            Some(field_names) => {
+                let fields = field_names
+                    .iter()
+                    .zip(field_types)
+                    .map(|(field_name, field_type)| {
+                        format!(
+                            "{}: {}",
+                            quote(field_name),
+                            self.typescript_type(*field_type)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!("{{ {} }}", fields.join("; "))
+            }
+            None => {
+                let fields = field_types
+                    .iter()
+                    .map(|field_type| self.typescript_type(*field_type))
+                    .collect::<Vec<_>>();
+                format!("[{}]", fields.join(", "))
+            }
+        }
+    }
+}
+
+/// The `kind` tag which the natural JSON serialization keeps on custom values whose meaning isn't
+/// obvious from their string representation.
+fn tagged_custom_kind_name(custom_type_kind: &ScryptoCustomTypeKind) -> &'static str {
+    match custom_type_kind {
+        ScryptoCustomTypeKind::Reference => "Reference",
+        ScryptoCustomTypeKind::Own => "Own",
+        ScryptoCustomTypeKind::Decimal => "Decimal",
+        ScryptoCustomTypeKind::PreciseDecimal => "PreciseDecimal",
+        ScryptoCustomTypeKind::NonFungibleLocalId => "NonFungibleLocalId",
+    }
+}
+
+fn sanitize_identifier(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect()
+}
+
+fn quote(value: &str) -> String {
+    Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blueprints::package::BlueprintDefinitionInit;
+    use radix_engine_common::math::Decimal;
+    use radix_engine_common::types::ResourceAddress;
+    use radix_engine_common::ScryptoSbor;
+    use scrypto_schema::{BlueprintEventSchemaInit, BlueprintSchemaInit};
+    use utils::btreemap;
+
+    #[derive(ScryptoSbor)]
+    struct DepositEvent {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        memo: Option<String>,
+    }
+
+    fn definition() -> PackageDefinition {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let event_type = aggregator.add_child_type_and_descendents::<DepositEvent>();
+
+        PackageDefinition {
+            blueprints: btreemap!(
+                "Vault".to_string() => BlueprintDefinitionInit {
+                    schema: BlueprintSchemaInit {
+                        schema: generate_full_schema(aggregator),
+                        events: BlueprintEventSchemaInit {
+                            event_schema: btreemap!(
+                                "DepositEvent".to_string() => TypeRef::Static(event_type)
+                            ),
+                        },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+            ),
+        }
+    }
+
+    #[test]
+    fn test_json_schema_describes_event_fields() {
+        let json_schema = generate_event_json_schema(&definition());
+
+        assert_eq!(
+            json_schema["events"]["Vault"]["DepositEvent"],
+            json!({ "$ref": "#/$defs/Vault_DepositEvent" })
+        );
+        let event = &json_schema["$defs"]["Vault_DepositEvent"];
+        assert_eq!(
+            event["required"],
+            json!(["resource_address", "amount", "memo"])
+        );
+        assert_eq!(
+            event["properties"]["resource_address"],
+            json!({
+                "type": "object",
+                "properties": {
+                    "kind": { "const": "Reference" },
+                    "value": { "type": "string" },
+                },
+                "required": ["kind", "value"],
+            })
+        );
+        assert_eq!(event["properties"]["amount"], json!({ "type": "string" }));
+    }
+
+    #[test]
+    fn test_typescript_types_describe_event_fields() {
+        let typescript_types = generate_event_typescript_types(&definition());
+
+        assert!(typescript_types.contains(
+            "export type Vault_DepositEvent = { \"resource_address\": { kind: \"Reference\"; value: string }; \"amount\": string; \"memo\": Vault_Option };"
+        ));
+        assert!(typescript_types.contains(
+            "export type Vault_Option = { variant_id: 0; variant_name: \"None\"; fields: [] } | { variant_id: 1; variant_name: \"Some\"; fields: [string] };"
+        ));
+        assert!(typescript_types.contains(
+            "export interface VaultEvents {\n  \"DepositEvent\": Vault_DepositEvent;\n}"
+        ));
+    }
+}
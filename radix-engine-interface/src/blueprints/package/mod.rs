@@ -1,5 +1,11 @@
+mod docs;
+mod event_schemas;
 mod invocations;
+mod schema_comparison;
 mod substates;
 
+pub use docs::*;
+pub use event_schemas::*;
 pub use invocations::*;
+pub use schema_comparison::*;
 pub use substates::*;
@@ -0,0 +1,500 @@
+use super::{BlueprintDefinitionInit, PackageDefinition};
+use radix_engine_common::data::scrypto::ScryptoSchema;
+use sbor::rust::fmt;
+use sbor::rust::prelude::*;
+use sbor::*;
+use scrypto_schema::{BlueprintCollectionSchema, TypeRef};
+
+/// A difference between two versions of a package definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSchemaChange {
+    BlueprintAdded {
+        blueprint: String,
+    },
+    BlueprintRemoved {
+        blueprint: String,
+    },
+    FunctionAdded {
+        blueprint: String,
+        function: String,
+    },
+    FunctionRemoved {
+        blueprint: String,
+        function: String,
+    },
+    /// The receiver, input or output type of a function has changed.
+    FunctionSignatureChanged {
+        blueprint: String,
+        function: String,
+    },
+    FieldAdded {
+        blueprint: String,
+        index: usize,
+    },
+    FieldRemoved {
+        blueprint: String,
+        index: usize,
+    },
+    /// The type or condition of a state field has changed.
+    FieldChanged {
+        blueprint: String,
+        index: usize,
+    },
+    CollectionAdded {
+        blueprint: String,
+        index: usize,
+    },
+    CollectionRemoved {
+        blueprint: String,
+        index: usize,
+    },
+    /// The kind, key or value type of a state collection has changed.
+    CollectionChanged {
+        blueprint: String,
+        index: usize,
+    },
+    EventAdded {
+        blueprint: String,
+        event: String,
+    },
+    EventRemoved {
+        blueprint: String,
+        event: String,
+    },
+    /// The type of an event has changed.
+    EventChanged {
+        blueprint: String,
+        event: String,
+    },
+}
+
+impl PackageSchemaChange {
+    /// Whether the change may break existing callers, state or event consumers.
+    ///
+    /// Only additions of blueprints, functions and events are backwards compatible, as any change
+    /// to the state layout invalidates existing substates.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(
+            self,
+            PackageSchemaChange::BlueprintAdded { .. }
+                | PackageSchemaChange::FunctionAdded { .. }
+                | PackageSchemaChange::EventAdded { .. }
+        )
+    }
+}
+
+impl fmt::Display for PackageSchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackageSchemaChange::BlueprintAdded { blueprint } => {
+                write!(f, "blueprint `{}` added", blueprint)
+            }
+            PackageSchemaChange::BlueprintRemoved { blueprint } => {
+                write!(f, "blueprint `{}` removed", blueprint)
+            }
+            PackageSchemaChange::FunctionAdded {
+                blueprint,
+                function,
+            } => write!(f, "function `{}::{}` added", blueprint, function),
+            PackageSchemaChange::FunctionRemoved {
+                blueprint,
+                function,
+            } => write!(f, "function `{}::{}` removed", blueprint, function),
+            PackageSchemaChange::FunctionSignatureChanged {
+                blueprint,
+                function,
+            } => write!(
+                f,
+                "function `{}::{}` signature changed",
+                blueprint, function
+            ),
+            PackageSchemaChange::FieldAdded { blueprint, index } => {
+                write!(f, "field {} of `{}` added", index, blueprint)
+            }
+            PackageSchemaChange::FieldRemoved { blueprint, index } => {
+                write!(f, "field {} of `{}` removed", index, blueprint)
+            }
+            PackageSchemaChange::FieldChanged { blueprint, index } => {
+                write!(f, "field {} of `{}` changed", index, blueprint)
+            }
+            PackageSchemaChange::CollectionAdded { blueprint, index } => {
+                write!(f, "collection {} of `{}` added", index, blueprint)
+            }
+            PackageSchemaChange::CollectionRemoved { blueprint, index } => {
+                write!(f, "collection {} of `{}` removed", index, blueprint)
+            }
+            PackageSchemaChange::CollectionChanged { blueprint, index } => {
+                write!(f, "collection {} of `{}` changed", index, blueprint)
+            }
+            PackageSchemaChange::EventAdded { blueprint, event } => {
+                write!(f, "event `{}::{}` added", blueprint, event)
+            }
+            PackageSchemaChange::EventRemoved { blueprint, event } => {
+                write!(f, "event `{}::{}` removed", blueprint, event)
+            }
+            PackageSchemaChange::EventChanged { blueprint, event } => {
+                write!(f, "event `{}::{}` changed", blueprint, event)
+            }
+        }
+    }
+}
+
+/// Compares two versions of a package definition, returning the changes from `old` to `new`.
+///
+/// Types are compared structurally, by their SBOR encoding and validation; renaming a type, a
+/// field or an enum variant is not reported.
+pub fn compare_package_definitions(
+    old: &PackageDefinition,
+    new: &PackageDefinition,
+) -> Vec<PackageSchemaChange> {
+    let mut changes = Vec::new();
+
+    for (blueprint, old_blueprint) in &old.blueprints {
+        match new.blueprints.get(blueprint) {
+            Some(new_blueprint) => {
+                compare_blueprints(blueprint, old_blueprint, new_blueprint, &mut changes)
+            }
+            None => changes.push(PackageSchemaChange::BlueprintRemoved {
+                blueprint: blueprint.clone(),
+            }),
+        }
+    }
+    for blueprint in new.blueprints.keys() {
+        if !old.blueprints.contains_key(blueprint) {
+            changes.push(PackageSchemaChange::BlueprintAdded {
+                blueprint: blueprint.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+fn compare_blueprints(
+    blueprint: &str,
+    old: &BlueprintDefinitionInit,
+    new: &BlueprintDefinitionInit,
+    changes: &mut Vec<PackageSchemaChange>,
+) {
+    let old_schema = &old.schema.schema;
+    let new_schema = &new.schema.schema;
+    let type_refs_equivalent =
+        |old: &TypeRef<LocalTypeIndex>, new: &TypeRef<LocalTypeIndex>| match (old, new) {
+            (TypeRef::Static(old), TypeRef::Static(new)) => {
+                types_equivalent(old_schema, *old, new_schema, *new)
+            }
+            (TypeRef::Generic(old), TypeRef::Generic(new)) => old == new,
+            _ => false,
+        };
+
+    // Functions
+    let old_functions = &old.schema.functions.functions;
+    let new_functions = &new.schema.functions.functions;
+    for (function, old_function) in old_functions {
+        match new_functions.get(function) {
+            Some(new_function) => {
+                if old_function.receiver != new_function.receiver
+                    || !type_refs_equivalent(&old_function.input, &new_function.input)
+                    || !type_refs_equivalent(&old_function.output, &new_function.output)
+                {
+                    changes.push(PackageSchemaChange::FunctionSignatureChanged {
+                        blueprint: blueprint.to_string(),
+                        function: function.clone(),
+                    });
+                }
+            }
+            None => changes.push(PackageSchemaChange::FunctionRemoved {
+                blueprint: blueprint.to_string(),
+                function: function.clone(),
+            }),
+        }
+    }
+    for function in new_functions.keys() {
+        if !old_functions.contains_key(function) {
+            changes.push(PackageSchemaChange::FunctionAdded {
+                blueprint: blueprint.to_string(),
+                function: function.clone(),
+            });
+        }
+    }
+
+    // State fields
+    let old_fields = &old.schema.state.fields;
+    let new_fields = &new.schema.state.fields;
+    for (index, old_field) in old_fields.iter().enumerate() {
+        match new_fields.get(index) {
+            Some(new_field) => {
+                if old_field.condition != new_field.condition
+                    || !type_refs_equivalent(&old_field.field, &new_field.field)
+                {
+                    changes.push(PackageSchemaChange::FieldChanged {
+                        blueprint: blueprint.to_string(),
+                        index,
+                    });
+                }
+            }
+            None => changes.push(PackageSchemaChange::FieldRemoved {
+                blueprint: blueprint.to_string(),
+                index,
+            }),
+        }
+    }
+    for index in old_fields.len()..new_fields.len() {
+        changes.push(PackageSchemaChange::FieldAdded {
+            blueprint: blueprint.to_string(),
+            index,
+        });
+    }
+
+    // State collections
+    let old_collections = &old.schema.state.collections;
+    let new_collections = &new.schema.state.collections;
+    for (index, old_collection) in old_collections.iter().enumerate() {
+        match new_collections.get(index) {
+            Some(new_collection) => {
+                let equivalent = match (old_collection, new_collection) {
+                    (
+                        BlueprintCollectionSchema::KeyValueStore(old),
+                        BlueprintCollectionSchema::KeyValueStore(new),
+                    ) => {
+                        old.can_own == new.can_own
+                            && type_refs_equivalent(&old.key, &new.key)
+                            && type_refs_equivalent(&old.value, &new.value)
+                    }
+                    (
+                        BlueprintCollectionSchema::Index(..),
+                        BlueprintCollectionSchema::Index(..),
+                    )
+                    | (
+                        BlueprintCollectionSchema::SortedIndex(..),
+                        BlueprintCollectionSchema::SortedIndex(..),
+                    ) => true,
+                    _ => false,
+                };
+                if !equivalent {
+                    changes.push(PackageSchemaChange::CollectionChanged {
+                        blueprint: blueprint.to_string(),
+                        index,
+                    });
+                }
+            }
+            None => changes.push(PackageSchemaChange::CollectionRemoved {
+                blueprint: blueprint.to_string(),
+                index,
+            }),
+        }
+    }
+    for index in old_collections.len()..new_collections.len() {
+        changes.push(PackageSchemaChange::CollectionAdded {
+            blueprint: blueprint.to_string(),
+            index,
+        });
+    }
+
+    // Events
+    let old_events = &old.schema.events.event_schema;
+    let new_events = &new.schema.events.event_schema;
+    for (event, old_event) in old_events {
+        match new_events.get(event) {
+            Some(new_event) => {
+                if !type_refs_equivalent(old_event, new_event) {
+                    changes.push(PackageSchemaChange::EventChanged {
+                        blueprint: blueprint.to_string(),
+                        event: event.clone(),
+                    });
+                }
+            }
+            None => changes.push(PackageSchemaChange::EventRemoved {
+                blueprint: blueprint.to_string(),
+                event: event.clone(),
+            }),
+        }
+    }
+    for event in new_events.keys() {
+        if !old_events.contains_key(event) {
+            changes.push(PackageSchemaChange::EventAdded {
+                blueprint: blueprint.to_string(),
+                event: event.clone(),
+            });
+        }
+    }
+}
+
+/// Whether a type of the old schema has the same encoding and validation as a type of the new
+/// schema.
+pub fn types_equivalent(
+    old_schema: &ScryptoSchema,
+    old: LocalTypeIndex,
+    new_schema: &ScryptoSchema,
+    new: LocalTypeIndex,
+) -> bool {
+    TypeComparator {
+        old_schema,
+        new_schema,
+        assumed: BTreeSet::new(),
+    }
+    .equivalent(old, new)
+}
+
+struct TypeComparator<'s> {
+    old_schema: &'s ScryptoSchema,
+    new_schema: &'s ScryptoSchema,
+    /// The pairs of schema-local types being compared, which are assumed to be equivalent when
+    /// reached again through a recursive type.
+    assumed: BTreeSet<(usize, usize)>,
+}
+
+impl<'s> TypeComparator<'s> {
+    fn equivalent(&mut self, old: LocalTypeIndex, new: LocalTypeIndex) -> bool {
+        match (old, new) {
+            (LocalTypeIndex::WellKnown(old), LocalTypeIndex::WellKnown(new)) => old == new,
+            (
+                LocalTypeIndex::SchemaLocalIndex(old_index),
+                LocalTypeIndex::SchemaLocalIndex(new_index),
+            ) => {
+                if !self.assumed.insert((old_index, new_index)) {
+                    return true;
+                }
+                if self.old_schema.resolve_type_validation(old)
+                    != self.new_schema.resolve_type_validation(new)
+                {
+                    return false;
+                }
+                let (Some(old_kind), Some(new_kind)) = (
+                    self.old_schema.resolve_type_kind(old),
+                    self.new_schema.resolve_type_kind(new),
+                ) else {
+                    return false;
+                };
+                match (old_kind, new_kind) {
+                    (
+                        TypeKind::Array { element_type: old },
+                        TypeKind::Array { element_type: new },
+                    ) => self.equivalent(*old, *new),
+                    (
+                        TypeKind::Tuple { field_types: old },
+                        TypeKind::Tuple { field_types: new },
+                    ) => self.all_equivalent(old, new),
+                    (TypeKind::Enum { variants: old }, TypeKind::Enum { variants: new }) => {
+                        old.len() == new.len()
+                            && old.iter().all(|(discriminator, old_fields)| {
+                                new.get(discriminator).map_or(false, |new_fields| {
+                                    self.all_equivalent(old_fields, new_fields)
+                                })
+                            })
+                    }
+                    (
+                        TypeKind::Map {
+                            key_type: old_key,
+                            value_type: old_value,
+                        },
+                        TypeKind::Map {
+                            key_type: new_key,
+                            value_type: new_value,
+                        },
+                    ) => {
+                        self.equivalent(*old_key, *new_key)
+                            && self.equivalent(*old_value, *new_value)
+                    }
+                    (old_kind, new_kind) => old_kind == new_kind,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn all_equivalent(&mut self, old: &[LocalTypeIndex], new: &[LocalTypeIndex]) -> bool {
+        old.len() == new.len()
+            && old
+                .iter()
+                .zip(new.iter())
+                .all(|(old, new)| self.equivalent(*old, *new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_common::data::scrypto::{ScryptoCustomSchema, ScryptoDescribe};
+    use radix_engine_common::ScryptoSbor;
+    use sbor::basic_well_known_types::{ANY_ID, UNIT_ID};
+    use scrypto_schema::{FieldSchema, FunctionSchemaInit};
+    use utils::btreemap;
+
+    #[derive(ScryptoSbor)]
+    struct StateV1 {
+        count: u32,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct StateV2 {
+        counter: u32,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct StateV3 {
+        count: u64,
+    }
+
+    fn definition<T: ScryptoDescribe>(functions: &[&str]) -> PackageDefinition {
+        let (state_index, schema) =
+            generate_full_schema_from_single_type::<T, ScryptoCustomSchema>();
+        let mut blueprint = BlueprintDefinitionInit::default();
+        blueprint.schema.schema = schema;
+        blueprint.schema.state.fields = vec![FieldSchema::static_field(state_index)];
+        for function in functions {
+            blueprint.schema.functions.functions.insert(
+                function.to_string(),
+                FunctionSchemaInit {
+                    receiver: None,
+                    input: TypeRef::Static(LocalTypeIndex::WellKnown(ANY_ID)),
+                    output: TypeRef::Static(LocalTypeIndex::WellKnown(UNIT_ID)),
+                    export: format!("Counter_{}", function),
+                },
+            );
+        }
+        PackageDefinition {
+            blueprints: btreemap!("Counter".to_string() => blueprint),
+        }
+    }
+
+    #[test]
+    fn renaming_a_field_is_not_a_change() {
+        let changes = compare_package_definitions(
+            &definition::<StateV1>(&["increment"]),
+            &definition::<StateV2>(&["increment"]),
+        );
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn changing_a_field_type_is_breaking() {
+        let changes = compare_package_definitions(
+            &definition::<StateV1>(&["increment", "reset"]),
+            &definition::<StateV3>(&["increment", "decrement"]),
+        );
+
+        assert_eq!(
+            changes,
+            vec![
+                PackageSchemaChange::FunctionRemoved {
+                    blueprint: "Counter".to_string(),
+                    function: "reset".to_string(),
+                },
+                PackageSchemaChange::FunctionAdded {
+                    blueprint: "Counter".to_string(),
+                    function: "decrement".to_string(),
+                },
+                PackageSchemaChange::FieldChanged {
+                    blueprint: "Counter".to_string(),
+                    index: 0,
+                },
+            ]
+        );
+        assert_eq!(
+            changes.iter().filter(|change| change.is_breaking()).count(),
+            2
+        );
+    }
+}
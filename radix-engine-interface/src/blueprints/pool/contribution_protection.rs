@@ -0,0 +1,65 @@
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+/// The protections of a pool against the inflation of the value of its pool units, configured
+/// when the pool is instantiated.
+///
+/// Without any protection, the first contributor to a pool can mint a tiny amount of pool units
+/// and then deposit resources into the pool directly, inflating the value of each pool unit so
+/// much that later contributions mint a rounded down amount of pool units (or none at all), and
+/// the difference is captured by the first contributor.
+#[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor, ManifestSbor)]
+pub struct PoolContributionProtection {
+    /// The amount of pool units locked away from the first contribution to the pool, so that the
+    /// supply of pool units can never go back to (nearly) zero. No liquidity is locked if `None`.
+    pub minimum_liquidity: Option<MinimumLiquidity>,
+
+    /// The minimum amount of pool units that a contribution must mint for the contributor, any
+    /// contribution minting less is rejected. Any amount is accepted if `None`.
+    pub dust_threshold: Option<Decimal>,
+}
+
+/// The minimum liquidity locked away from the first contribution to a pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct MinimumLiquidity {
+    /// The amount of pool units to lock, which must be positive.
+    pub amount: Decimal,
+    pub lock: MinimumLiquidityLock,
+}
+
+/// How the minimum liquidity of a pool is locked away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub enum MinimumLiquidityLock {
+    /// The locked pool units are never minted, but still count towards the supply of pool units
+    /// that the reserves of the pool are shared between.
+    Burn,
+    /// The locked pool units are minted into a vault of the pool which can't be withdrawn from.
+    Escrow,
+}
+
+impl PoolContributionProtection {
+    /// Locks the given amount of pool units away from the first contribution, by burning them.
+    pub fn burn_minimum_liquidity(mut self, amount: Decimal) -> Self {
+        self.minimum_liquidity = Some(MinimumLiquidity {
+            amount,
+            lock: MinimumLiquidityLock::Burn,
+        });
+        self
+    }
+
+    /// Locks the given amount of pool units away from the first contribution, by escrowing them.
+    pub fn escrow_minimum_liquidity(mut self, amount: Decimal) -> Self {
+        self.minimum_liquidity = Some(MinimumLiquidity {
+            amount,
+            lock: MinimumLiquidityLock::Escrow,
+        });
+        self
+    }
+
+    /// Rejects the contributions which would mint less than the given amount of pool units.
+    pub fn dust_threshold(mut self, dust_threshold: Decimal) -> Self {
+        self.dust_threshold = Some(dust_threshold);
+        self
+    }
+}
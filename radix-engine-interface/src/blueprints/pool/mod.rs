@@ -1,7 +1,9 @@
+mod contribution_protection;
 mod multi_resource_pool;
 mod one_resource_pool;
 mod two_resource_pool;
 
+pub use contribution_protection::*;
 pub use multi_resource_pool::*;
 pub use one_resource_pool::*;
 pub use two_resource_pool::*;
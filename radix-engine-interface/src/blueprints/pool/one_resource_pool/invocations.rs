@@ -1,10 +1,13 @@
 use crate::blueprints::macros::*;
+use crate::blueprints::pool::PoolContributionProtection;
 use crate::blueprints::resource::*;
 use radix_engine_common::data::manifest::model::*;
 use radix_engine_common::math::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
 
+pub const ONE_RESOURCE_POOL_BLUEPRINT: &str = "OneResourcePool";
+
 define_invocation! {
     blueprint_name: OneResourcePool,
     function_name: instantiate,
@@ -21,6 +24,24 @@ define_invocation! {
     }
 }
 
+define_invocation! {
+    blueprint_name: OneResourcePool,
+    function_name: instantiate_with_contribution_protection,
+    input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_address: ResourceAddress,
+        contribution_protection: PoolContributionProtection
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_address: ResourceAddress,
+        contribution_protection: PoolContributionProtection
+    }
+}
+
 define_invocation! {
     blueprint_name: OneResourcePool,
     function_name: contribute,
@@ -1,10 +1,13 @@
 use crate::blueprints::macros::*;
+use crate::blueprints::pool::PoolContributionProtection;
 use crate::blueprints::resource::*;
 use radix_engine_common::data::manifest::model::*;
 use radix_engine_common::math::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
 
+pub const TWO_RESOURCE_POOL_BLUEPRINT: &str = "TwoResourcePool";
+
 define_invocation! {
     blueprint_name: TwoResourcePool,
     function_name: instantiate,
@@ -21,6 +24,24 @@ define_invocation! {
     }
 }
 
+define_invocation! {
+    blueprint_name: TwoResourcePool,
+    function_name: instantiate_with_contribution_protection,
+    input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: (ResourceAddress, ResourceAddress),
+        contribution_protection: PoolContributionProtection
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: (ResourceAddress, ResourceAddress),
+        contribution_protection: PoolContributionProtection
+    }
+}
+
 define_invocation! {
     blueprint_name: TwoResourcePool,
     function_name: contribute,
@@ -92,3 +113,27 @@ define_invocation! {
     output: type BTreeMap<ResourceAddress, Decimal>,
     manifest_input: struct {}
 }
+
+define_invocation! {
+    blueprint_name: TwoResourcePool,
+    function_name: contribute_single_sided,
+    input: struct {
+        bucket: Bucket
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        bucket: ManifestBucket
+    }
+}
+
+define_invocation! {
+    blueprint_name: TwoResourcePool,
+    function_name: set_single_sided_contribution_fee,
+    input: struct {
+        fee: Option<Decimal>
+    },
+    output: type (),
+    manifest_input: struct {
+        fee: Option<Decimal>
+    }
+}
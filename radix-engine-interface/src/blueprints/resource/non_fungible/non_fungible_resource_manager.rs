@@ -65,10 +65,55 @@ impl NonFungibleResourceRoles {
         }
     }
 
+    /// Roles of a soulbound resource, whose non-fungibles can never be withdrawn from the vault
+    /// they are first deposited into, and which can only be minted, updated and burned by the owner.
+    ///
+    /// Deposits stay allowed so that newly minted non-fungibles can reach their holder. None of
+    /// the roles can be updated later.
+    pub fn soulbound(owner_rule: AccessRule) -> Self {
+        Self {
+            mint_roles: mint_roles! {
+                minter => owner_rule.clone();
+                minter_updater => rule!(deny_all);
+            },
+            burn_roles: burn_roles! {
+                burner => owner_rule.clone();
+                burner_updater => rule!(deny_all);
+            },
+            freeze_roles: None,
+            recall_roles: None,
+            non_fungible_data_update_roles: non_fungible_data_update_roles! {
+                non_fungible_data_updater => owner_rule;
+                non_fungible_data_updater_updater => rule!(deny_all);
+            },
+            withdraw_roles: withdraw_roles! {
+                withdrawer => rule!(deny_all);
+                withdrawer_updater => rule!(deny_all);
+            },
+            deposit_roles: deposit_roles! {
+                depositor => rule!(allow_all);
+                depositor_updater => rule!(deny_all);
+            },
+        }
+    }
+
+    /// Whether withdrawals are permanently denied, which makes the resource soulbound.
+    pub fn is_soulbound(&self) -> bool {
+        self.withdraw_roles
+            == withdraw_roles! {
+                withdrawer => rule!(deny_all);
+                withdrawer_updater => rule!(deny_all);
+            }
+    }
+
     pub fn to_features_and_roles(self) -> (Vec<&'static str>, RolesInit) {
         let mut features = Vec::new();
         let mut roles = RolesInit::new();
 
+        if self.is_soulbound() {
+            features.push(SOULBOUND_FEATURE);
+        }
+
         if self.mint_roles.is_some() {
             features.push(MINT_FEATURE);
         }
@@ -23,6 +23,18 @@ pub struct NonFungibleVaultGetNonFungibleLocalIdsInput {}
 
 pub type NonFungibleVaultGetNonFungibleLocalIdsOutput = BTreeSet<NonFungibleLocalId>;
 
+pub const NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT: &str =
+    "get_non_fungible_local_ids_page";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleVaultGetNonFungibleLocalIdsPageInput {
+    pub limit: u32,
+    /// The last id of the previous page, or [`None`] for the first page.
+    pub cursor: Option<NonFungibleLocalId>,
+}
+
+pub type NonFungibleVaultGetNonFungibleLocalIdsPageOutput = Vec<NonFungibleLocalId>;
+
 pub const NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT: &str = "recall_non_fungibles";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
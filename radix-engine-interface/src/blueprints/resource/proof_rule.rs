@@ -1,10 +1,13 @@
 use crate::blueprints::resource::AccessRuleNode::{AllOf, AnyOf};
 use crate::blueprints::resource::*;
+use crate::crypto::IsPublicKeyHash;
 use crate::math::Decimal;
 use crate::*;
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::Arbitrary;
 use radix_engine_common::types::*;
+use sbor::rust::string::String;
+use sbor::rust::string::ToString;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
 
@@ -113,6 +116,13 @@ pub fn global_caller(global_caller: impl Into<GlobalCaller>) -> ResourceOrNonFun
     ResourceOrNonFungible::NonFungible(NonFungibleGlobalId::global_caller_badge(global_caller))
 }
 
+/// A requirement for the transaction to be signed by the key with the given hash.
+///
+/// This is checked against the signature proofs of the transaction, so no badge needs to be issued.
+pub fn signature(public_key_hash: impl IsPublicKeyHash) -> ResourceOrNonFungible {
+    ResourceOrNonFungible::NonFungible(NonFungibleGlobalId::from_public_key_hash(public_key_hash))
+}
+
 pub fn require<T>(required: T) -> AccessRuleNode
 where
     T: Into<AccessRuleNode>,
@@ -159,6 +169,49 @@ pub enum AccessRule {
     AllowAll,
     DenyAll,
     Protected(AccessRuleNode),
+    /// Grants access when the given function, called with the address of the protected object,
+    /// returns `true`.
+    StateGated(StateGate),
+}
+
+/// A read-only blueprint function deciding on access from the state of the protected object.
+///
+/// The function takes the `GlobalAddress` of the object and returns a `bool`. It's called without
+/// any of the caller's proofs, may not write any substate, and may not itself be guarded by
+/// another state-gated rule.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, ScryptoSbor, ManifestSbor)]
+pub struct StateGate {
+    pub package_address: PackageAddress,
+    pub blueprint_name: String,
+    pub function_name: String,
+}
+
+impl StateGate {
+    pub fn new<S: ToString, T: ToString>(
+        package_address: PackageAddress,
+        blueprint_name: S,
+        function_name: T,
+    ) -> Self {
+        Self {
+            package_address,
+            blueprint_name: blueprint_name.to_string(),
+            function_name: function_name.to_string(),
+        }
+    }
+}
+
+impl AccessRule {
+    /// A rule which is only met by transactions signed by the key with the given hash.
+    pub fn require_signature(public_key_hash: impl IsPublicKeyHash) -> Self {
+        AccessRule::Protected(require(signature(public_key_hash)))
+    }
+}
+
+impl From<StateGate> for AccessRule {
+    fn from(value: StateGate) -> Self {
+        AccessRule::StateGated(value)
+    }
 }
 
 impl From<AccessRuleNode> for AccessRule {
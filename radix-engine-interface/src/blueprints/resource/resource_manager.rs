@@ -8,6 +8,7 @@ pub const VAULT_FREEZE_FEATURE: &str = "vault_freeze";
 pub const VAULT_RECALL_FEATURE: &str = "vault_recall";
 pub const MINT_FEATURE: &str = "mint";
 pub const BURN_FEATURE: &str = "burn";
+pub const SOULBOUND_FEATURE: &str = "soulbound";
 
 // Main roles
 pub const MINTER_ROLE: &str = "minter";
@@ -101,6 +101,15 @@ pub struct VaultBurnInput {
 
 pub type VaultBurnOutput = ();
 
+pub const VAULT_BURN_FROM_VAULT_IDENT: &str = "burn_from_vault";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct VaultBurnFromVaultInput {
+    pub amount: Decimal,
+}
+
+pub type VaultBurnFromVaultOutput = ();
+
 //========
 // Stub
 //========
@@ -14,6 +14,14 @@ pub type TransactionProcessorRunOutput = Vec<InstructionOutput>;
 pub enum InstructionOutput {
     CallReturn(Vec<u8>),
     None,
+    /// The output of a `ROLLBACK_TO_SAVEPOINT` instruction whose section failed, and whose
+    /// changes have been reverted.
+    RolledBack {
+        /// The index of the failed instruction
+        failed_instruction_index: u32,
+        /// The error the instruction failed with
+        error: String,
+    },
 }
 
 impl InstructionOutput {
@@ -28,6 +36,9 @@ impl InstructionOutput {
             Self::None => {
                 panic!("Expected: {:?} but was None", expected);
             }
+            Self::RolledBack { error, .. } => {
+                panic!("Expected: {:?} but was rolled back: {}", expected, error);
+            }
         }
     }
 }
@@ -9,6 +9,72 @@ use sbor::traversal::*;
 use sbor::*;
 use utils::ContextualDisplay;
 
+/// A node referenced by a value, classified by its entity type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypedReference {
+    Package(PackageAddress),
+    Component(ComponentAddress),
+    ResourceManager(ResourceAddress),
+    InternalVault(InternalAddress),
+    /// Any other node, such as an internal key value store or component.
+    Other(NodeId),
+}
+
+impl TypedReference {
+    pub fn from_node_id(node_id: &NodeId) -> Self {
+        if node_id.is_global_package() {
+            Self::Package(PackageAddress::new_or_panic(node_id.0))
+        } else if node_id.is_global_component() {
+            Self::Component(ComponentAddress::new_or_panic(node_id.0))
+        } else if node_id.is_global_resource_manager() {
+            Self::ResourceManager(ResourceAddress::new_or_panic(node_id.0))
+        } else if node_id.is_internal_vault() {
+            Self::InternalVault(InternalAddress::new_or_panic(node_id.0))
+        } else {
+            Self::Other(*node_id)
+        }
+    }
+
+    pub fn as_node_id(&self) -> &NodeId {
+        match self {
+            Self::Package(address) => address.as_node_id(),
+            Self::Component(address) => address.as_node_id(),
+            Self::ResourceManager(address) => address.as_node_id(),
+            Self::InternalVault(address) => address.as_node_id(),
+            Self::Other(node_id) => node_id,
+        }
+    }
+}
+
+/// The kind of a node owned by a value, as determined by its entity type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnedNodeKind {
+    FungibleVault,
+    NonFungibleVault,
+    KeyValueStore,
+    /// An internal object, such as a bucket, a proof or an internal component.
+    Object,
+    Other,
+}
+
+impl OwnedNodeKind {
+    pub fn from_node_id(node_id: &NodeId) -> Self {
+        match node_id.entity_type() {
+            Some(EntityType::InternalFungibleVault) => Self::FungibleVault,
+            Some(EntityType::InternalNonFungibleVault) => Self::NonFungibleVault,
+            Some(EntityType::InternalKeyValueStore) => Self::KeyValueStore,
+            Some(EntityType::InternalGenericComponent) | Some(EntityType::InternalAccount) => {
+                Self::Object
+            }
+            _ => Self::Other,
+        }
+    }
+
+    pub fn is_vault(&self) -> bool {
+        matches!(self, Self::FungibleVault | Self::NonFungibleVault)
+    }
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct IndexedScryptoValue {
     bytes: Vec<u8>,
@@ -132,6 +198,34 @@ impl IndexedScryptoValue {
         &self.owned_nodes
     }
 
+    pub fn typed_references(&self) -> impl Iterator<Item = TypedReference> + '_ {
+        self.references.iter().map(TypedReference::from_node_id)
+    }
+
+    pub fn owned_nodes_with_kind(&self) -> impl Iterator<Item = (&NodeId, OwnedNodeKind)> + '_ {
+        self.owned_nodes
+            .iter()
+            .map(|node_id| (node_id, OwnedNodeKind::from_node_id(node_id)))
+    }
+
+    /// Validates the value against the type at the given index of the schema.
+    pub fn validate_against_schema<'s, T>(
+        &self,
+        schema: &'s ScryptoSchema,
+        type_index: LocalTypeIndex,
+        context: &T,
+    ) -> Result<(), LocatedValidationError<'s, ScryptoCustomExtension>>
+    where
+        ScryptoCustomExtension: ValidatableCustomExtension<T>,
+    {
+        validate_payload_against_schema::<ScryptoCustomExtension, T>(
+            &self.bytes,
+            schema,
+            type_index,
+            context,
+        )
+    }
+
     pub fn unpack(self) -> (Vec<u8>, Vec<NodeId>, Vec<NodeId>) {
         (self.bytes, self.owned_nodes, self.references)
     }
@@ -170,3 +264,52 @@ impl<'s, 'a> ContextualDisplay<ValueDisplayParameters<'s, 'a, ScryptoCustomExten
         ScryptoRawPayload::new_from_valid_slice(self.as_slice()).format(f, *context)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_references_and_owned_nodes_are_classified() {
+        let package = NodeId([EntityType::GlobalPackage as u8; NodeId::LENGTH]);
+        let resource = NodeId([EntityType::GlobalFungibleResourceManager as u8; NodeId::LENGTH]);
+        let vault = NodeId([EntityType::InternalFungibleVault as u8; NodeId::LENGTH]);
+        let kv_store = NodeId([EntityType::InternalKeyValueStore as u8; NodeId::LENGTH]);
+        let value = IndexedScryptoValue::from_typed(&(
+            Reference(package),
+            Reference(resource),
+            Reference(vault),
+            Own(vault),
+            Own(kv_store),
+        ));
+
+        assert_eq!(
+            value.typed_references().collect::<Vec<_>>(),
+            vec![
+                TypedReference::Package(PackageAddress::new_or_panic(package.0)),
+                TypedReference::ResourceManager(ResourceAddress::new_or_panic(resource.0)),
+                TypedReference::InternalVault(InternalAddress::new_or_panic(vault.0)),
+            ]
+        );
+        assert_eq!(
+            value.owned_nodes_with_kind().collect::<Vec<_>>(),
+            vec![
+                (&vault, OwnedNodeKind::FungibleVault),
+                (&kv_store, OwnedNodeKind::KeyValueStore),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_against_schema() {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<(u32, String), ScryptoCustomSchema>();
+
+        assert!(IndexedScryptoValue::from_typed(&(1u32, "a".to_string()))
+            .validate_against_schema(&schema, type_index, &())
+            .is_ok());
+        assert!(IndexedScryptoValue::from_typed(&(1u8, "a".to_string()))
+            .validate_against_schema(&schema, type_index, &())
+            .is_err());
+    }
+}
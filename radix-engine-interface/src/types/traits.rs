@@ -1,6 +1,32 @@
 use radix_engine_common::data::scrypto::ScryptoSbor;
+use sbor::rust::marker::PhantomData;
 
 /// Represents the data structure of a non-fungible.
 pub trait NonFungibleData: ScryptoSbor {
     const MUTABLE_FIELDS: &'static [&'static str];
 }
+
+/// A field of a non-fungible data structure which is declared `#[mutable]`, as generated by
+/// `#[derive(NonFungibleData)]`.
+///
+/// Updating a non-fungible through it rather than through the field name checks at compile time
+/// that the field exists, is mutable and is given data of the right type.
+pub struct MutableNonFungibleDataField<D, V> {
+    name: &'static str,
+    data_type: PhantomData<fn() -> (D, V)>,
+}
+
+impl<D, V> MutableNonFungibleDataField<D, V> {
+    /// Meant to be called by the derived code only, which knows that the field is mutable.
+    #[doc(hidden)]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            data_type: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
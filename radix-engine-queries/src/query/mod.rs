@@ -1,7 +1,9 @@
 mod accounter;
+mod owned_node_finder;
 mod traverse;
 mod vault_finder;
 
 pub use accounter::*;
+pub use owned_node_finder::*;
 pub use traverse::*;
 pub use vault_finder::*;
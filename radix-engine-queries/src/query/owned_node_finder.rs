@@ -0,0 +1,35 @@
+use super::StateTreeVisitor;
+use radix_engine::types::SubstateKey;
+use radix_engine_interface::types::{NodeId, PartitionNumber};
+use sbor::rust::vec::Vec;
+
+/// Collects the nodes directly owned by the root of a traversal, in traversal order.
+#[derive(Default)]
+pub struct OwnedNodeFinder {
+    owned_nodes: Vec<NodeId>,
+}
+
+impl OwnedNodeFinder {
+    pub fn new() -> Self {
+        OwnedNodeFinder {
+            owned_nodes: Vec::new(),
+        }
+    }
+
+    pub fn to_owned_nodes(self) -> Vec<NodeId> {
+        self.owned_nodes
+    }
+}
+
+impl StateTreeVisitor for OwnedNodeFinder {
+    fn visit_node_id(
+        &mut self,
+        _parent_id: Option<&(NodeId, PartitionNumber, SubstateKey)>,
+        node_id: &NodeId,
+        depth: u32,
+    ) {
+        if depth == 1 {
+            self.owned_nodes.push(*node_id);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use radix_engine_derive::ScryptoSbor;
 use utils::rust::boxed::Box;
 use utils::rust::collections::IndexMap;
+use utils::rust::ops::RangeInclusive;
 use utils::rust::vec::Vec;
 
 /// A database-level key of an entire partition.
@@ -54,6 +55,23 @@ pub trait SubstateDatabase {
         &self,
         partition_key: &DbPartitionKey,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_>;
+
+    /// Iterates over the entries of the given partition whose [`DbSortKey`]s are greater than or
+    /// equal to the given one, in a lexicographical order (ascending) of the [`DbSortKey`]s.
+    ///
+    /// The default implementation skips over the preceding entries; vendors able to seek should
+    /// override it.
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: &DbSortKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let from_sort_key = from_sort_key.clone();
+        Box::new(
+            self.list_entries(partition_key)
+                .skip_while(move |(sort_key, _)| *sort_key < from_sort_key),
+        )
+    }
 }
 
 /// A write interface between Track and a database vendor.
@@ -67,3 +85,101 @@ pub trait ListableSubstateDatabase {
     /// Iterates over all partition keys, in an arbitrary order.
     fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_>;
 }
+
+/// A historical read interface of a database vendor which keeps previous substate values, so that
+/// the state can be read as it was at a past state version.
+pub trait VersionedSubstateDatabase {
+    /// The state versions which can be read, from the earliest one whose history is not yet pruned
+    /// to the current one.
+    fn readable_state_versions(&self) -> RangeInclusive<u64>;
+
+    /// Reads a substate value as it was at the given state version, or [`Option::None`] if it was
+    /// missing at that version.
+    fn get_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Result<Option<DbSubstateValue>, StateVersionError>;
+
+    /// Iterates over all entries of the given partition as they were at the given state version,
+    /// in a lexicographical order (ascending) of the [`DbSortKey`]s.
+    fn list_entries_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+    ) -> Result<Box<dyn Iterator<Item = PartitionEntry> + '_>, StateVersionError>;
+}
+
+/// The reason a state version cannot be read from a [`VersionedSubstateDatabase`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateVersionError {
+    /// The history needed to read the state version has been pruned.
+    Pruned {
+        earliest_readable_state_version: u64,
+    },
+    /// The state version has not been committed yet.
+    NotCommitted { current_state_version: u64 },
+}
+
+/// Checks that the state version is within the range readable from the database.
+pub fn check_state_version_readable<S: VersionedSubstateDatabase + ?Sized>(
+    substate_db: &S,
+    state_version: u64,
+) -> Result<(), StateVersionError> {
+    let readable = substate_db.readable_state_versions();
+    if state_version < *readable.start() {
+        Err(StateVersionError::Pruned {
+            earliest_readable_state_version: *readable.start(),
+        })
+    } else if state_version > *readable.end() {
+        Err(StateVersionError::NotCommitted {
+            current_state_version: *readable.end(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Adapts a [`VersionedSubstateDatabase`] into a [`SubstateDatabase`] which reads the state as it
+/// was at a fixed state version, e.g. to preview a transaction "as of" that version.
+pub struct SubstateDatabaseAtVersion<'s, S: VersionedSubstateDatabase> {
+    substate_db: &'s S,
+    state_version: u64,
+}
+
+impl<'s, S: VersionedSubstateDatabase> SubstateDatabaseAtVersion<'s, S> {
+    pub fn new(substate_db: &'s S, state_version: u64) -> Result<Self, StateVersionError> {
+        check_state_version_readable(substate_db, state_version)?;
+        Ok(Self {
+            substate_db,
+            state_version,
+        })
+    }
+
+    pub fn state_version(&self) -> u64 {
+        self.state_version
+    }
+}
+
+impl<'s, S: VersionedSubstateDatabase> SubstateDatabase for SubstateDatabaseAtVersion<'s, S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        // The version was checked on creation, and history is only pruned offline
+        self.substate_db
+            .get_substate_at_version(self.state_version, partition_key, sort_key)
+            .expect("State version should be readable")
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.substate_db
+            .list_entries_at_version(self.state_version, partition_key)
+            .expect("State version should be readable")
+    }
+}
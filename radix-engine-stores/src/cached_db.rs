@@ -0,0 +1,200 @@
+use radix_engine_store_interface::interface::*;
+use sbor::rust::num::NonZeroUsize;
+use sbor::rust::prelude::*;
+
+/// The hit and miss counters of a [`CachedSubstateDatabase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// The ratio of reads served from the cache, or zero if there was no read.
+    pub fn hit_ratio(&self) -> f64 {
+        let reads = self.hits + self.misses;
+        if reads == 0 {
+            0.0
+        } else {
+            self.hits as f64 / reads as f64
+        }
+    }
+}
+
+/// A read-through cache in front of another substate database, keeping the most recently read
+/// substates (including absent ones) in memory.
+///
+/// Committed updates are written through to the underlying database and evict the updated
+/// substates from the cache. Partition listings are not cached.
+pub struct CachedSubstateDatabase<D> {
+    database: D,
+    cache: RefCell<lru::LruCache<DbSubstateKey, Option<DbSubstateValue>>>,
+    stats: Cell<CacheStats>,
+}
+
+impl<D> CachedSubstateDatabase<D> {
+    pub fn new(database: D, capacity: NonZeroUsize) -> Self {
+        Self {
+            database,
+            cache: RefCell::new(lru::LruCache::new(capacity)),
+            stats: Cell::new(CacheStats::default()),
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats.get()
+    }
+
+    pub fn reset_stats(&self) {
+        self.stats.set(CacheStats::default());
+    }
+
+    /// Empties the cache, e.g. after the underlying database has been modified directly.
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+    }
+
+    pub fn database(&self) -> &D {
+        &self.database
+    }
+
+    pub fn into_database(self) -> D {
+        self.database
+    }
+}
+
+impl<D: SubstateDatabase> SubstateDatabase for CachedSubstateDatabase<D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        let key = (partition_key.clone(), sort_key.clone());
+        let mut stats = self.stats.get();
+        let mut cache = self.cache.borrow_mut();
+        let value = match cache.get(&key) {
+            Some(value) => {
+                stats.hits += 1;
+                value.clone()
+            }
+            None => {
+                stats.misses += 1;
+                let value = self.database.get_substate(partition_key, sort_key);
+                cache.put(key, value.clone());
+                value
+            }
+        };
+        self.stats.set(stats);
+        value
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.database.list_entries(partition_key)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: &DbSortKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.database
+            .list_entries_from(partition_key, from_sort_key)
+    }
+}
+
+impl<D: CommittableSubstateDatabase> CommittableSubstateDatabase for CachedSubstateDatabase<D> {
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        let cache = self.cache.get_mut();
+        for (partition_key, partition_updates) in database_updates {
+            for sort_key in partition_updates.keys() {
+                cache.pop(&(partition_key.clone(), sort_key.clone()));
+            }
+        }
+        self.database.commit(database_updates);
+    }
+}
+
+impl<D: ListableSubstateDatabase> ListableSubstateDatabase for CachedSubstateDatabase<D> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        self.database.list_partition_keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    fn set(partition: u8, sort: u8, value: u8) -> DatabaseUpdates {
+        indexmap!(
+            DbPartitionKey(vec![partition]) => indexmap!(
+                DbSortKey(vec![sort]) => DatabaseUpdate::Set(vec![value])
+            )
+        )
+    }
+
+    #[test]
+    fn test_repeated_reads_are_served_from_cache() {
+        let mut db = CachedSubstateDatabase::new(
+            InMemorySubstateDatabase::standard(),
+            NonZeroUsize::new(2).unwrap(),
+        );
+        db.commit(&set(1, 1, 10));
+
+        for _ in 0..3 {
+            assert_eq!(
+                db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![1])),
+                Some(vec![10])
+            );
+        }
+        // Absent substates are cached too
+        for _ in 0..2 {
+            assert_eq!(
+                db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![2])),
+                None
+            );
+        }
+
+        assert_eq!(db.stats(), CacheStats { hits: 3, misses: 2 });
+        assert_eq!(db.stats().hit_ratio(), 0.6);
+    }
+
+    #[test]
+    fn test_commit_evicts_updated_substates() {
+        let mut db = CachedSubstateDatabase::new(
+            InMemorySubstateDatabase::standard(),
+            NonZeroUsize::new(2).unwrap(),
+        );
+        db.commit(&set(1, 1, 10));
+        db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![1]));
+
+        db.commit(&set(1, 1, 20));
+
+        assert_eq!(
+            db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![1])),
+            Some(vec![20])
+        );
+        assert_eq!(db.stats(), CacheStats { hits: 0, misses: 2 });
+    }
+
+    #[test]
+    fn test_least_recently_used_substates_are_evicted_beyond_capacity() {
+        let mut db = CachedSubstateDatabase::new(
+            InMemorySubstateDatabase::standard(),
+            NonZeroUsize::new(2).unwrap(),
+        );
+        for sort in 1..=3 {
+            db.commit(&set(1, sort, sort));
+            db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![sort]));
+        }
+        db.reset_stats();
+
+        db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![3]));
+        db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![1]));
+
+        assert_eq!(db.stats(), CacheStats { hits: 1, misses: 1 });
+    }
+}
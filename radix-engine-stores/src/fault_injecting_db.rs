@@ -0,0 +1,249 @@
+use radix_engine_store_interface::interface::*;
+use sbor::rust::iter;
+use sbor::rust::prelude::*;
+
+/// A fault to inject into a read of a [`FaultInjectingSubstateDatabase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadFault {
+    /// The read panics, as database vendors do on an IO error.
+    IoError,
+    /// The read finds nothing, as if the substate (or the whole partition) was missing.
+    Missing,
+}
+
+/// A fault to inject into a commit of a [`FaultInjectingSubstateDatabase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitFault {
+    /// The commit panics before writing anything, as database vendors do on an IO error.
+    IoError,
+    /// Only the updates of the first `applied_partitions` partitions are written, as if the
+    /// commit was interrupted.
+    Partial { applied_partitions: usize },
+}
+
+/// The faults to inject into a [`FaultInjectingSubstateDatabase`], each at the given (zero-based)
+/// index of the accesses of its kind.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubstateDatabaseFaults {
+    pub reads: IndexMap<u64, ReadFault>,
+    pub commits: IndexMap<u64, CommitFault>,
+}
+
+impl SubstateDatabaseFaults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fail_read_at(mut self, read_index: u64, fault: ReadFault) -> Self {
+        self.reads.insert(read_index, fault);
+        self
+    }
+
+    pub fn fail_commit_at(mut self, commit_index: u64, fault: CommitFault) -> Self {
+        self.commits.insert(commit_index, fault);
+        self
+    }
+}
+
+/// A wrapper of another substate database which deterministically fails chosen accesses, so that
+/// the handling of database faults can be tested.
+///
+/// Every call to a [`SubstateDatabase`] method counts as one read, and every call to
+/// [`CommittableSubstateDatabase::commit`] as one commit. Partition listings are passed through.
+pub struct FaultInjectingSubstateDatabase<D> {
+    database: D,
+    faults: SubstateDatabaseFaults,
+    reads: Cell<u64>,
+    commits: u64,
+    injected_faults: Cell<u64>,
+}
+
+impl<D> FaultInjectingSubstateDatabase<D> {
+    pub fn new(database: D, faults: SubstateDatabaseFaults) -> Self {
+        Self {
+            database,
+            faults,
+            reads: Cell::new(0),
+            commits: 0,
+            injected_faults: Cell::new(0),
+        }
+    }
+
+    /// The number of reads so far.
+    pub fn reads(&self) -> u64 {
+        self.reads.get()
+    }
+
+    /// The number of commits so far.
+    pub fn commits(&self) -> u64 {
+        self.commits
+    }
+
+    /// The number of faults injected so far (excluding the panicking ones).
+    pub fn injected_faults(&self) -> u64 {
+        self.injected_faults.get()
+    }
+
+    pub fn database(&self) -> &D {
+        &self.database
+    }
+
+    pub fn into_database(self) -> D {
+        self.database
+    }
+
+    /// Counts a read and returns the fault to inject into it, if any.
+    fn next_read_fault(&self) -> Option<ReadFault> {
+        let read_index = self.reads.get();
+        self.reads.set(read_index + 1);
+        let fault = self.faults.reads.get(&read_index).cloned();
+        match fault {
+            Some(ReadFault::IoError) => {
+                panic!("Injected IO error on substate database read {}", read_index)
+            }
+            Some(ReadFault::Missing) => {
+                self.injected_faults.set(self.injected_faults.get() + 1);
+            }
+            None => {}
+        }
+        fault
+    }
+}
+
+impl<D: SubstateDatabase> SubstateDatabase for FaultInjectingSubstateDatabase<D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match self.next_read_fault() {
+            Some(_) => None,
+            None => self.database.get_substate(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        match self.next_read_fault() {
+            Some(_) => Box::new(iter::empty()),
+            None => self.database.list_entries(partition_key),
+        }
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: &DbSortKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        match self.next_read_fault() {
+            Some(_) => Box::new(iter::empty()),
+            None => self
+                .database
+                .list_entries_from(partition_key, from_sort_key),
+        }
+    }
+}
+
+impl<D: CommittableSubstateDatabase> CommittableSubstateDatabase
+    for FaultInjectingSubstateDatabase<D>
+{
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        let commit_index = self.commits;
+        self.commits += 1;
+        match self.faults.commits.get(&commit_index).cloned() {
+            Some(CommitFault::IoError) => {
+                panic!(
+                    "Injected IO error on substate database commit {}",
+                    commit_index
+                )
+            }
+            Some(CommitFault::Partial { applied_partitions }) => {
+                self.injected_faults.set(self.injected_faults.get() + 1);
+                let applied_updates: DatabaseUpdates = database_updates
+                    .iter()
+                    .take(applied_partitions)
+                    .map(|(partition_key, updates)| (partition_key.clone(), updates.clone()))
+                    .collect();
+                self.database.commit(&applied_updates);
+            }
+            None => self.database.commit(database_updates),
+        }
+    }
+}
+
+impl<D: ListableSubstateDatabase> ListableSubstateDatabase for FaultInjectingSubstateDatabase<D> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        self.database.list_partition_keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    fn set(partition: u8, sort: u8, value: u8) -> DatabaseUpdates {
+        indexmap!(
+            DbPartitionKey(vec![partition]) => indexmap!(
+                DbSortKey(vec![sort]) => DatabaseUpdate::Set(vec![value])
+            )
+        )
+    }
+
+    #[test]
+    fn test_missing_read_fault_is_injected_at_the_given_read() {
+        let mut db = FaultInjectingSubstateDatabase::new(
+            InMemorySubstateDatabase::standard(),
+            SubstateDatabaseFaults::new().fail_read_at(1, ReadFault::Missing),
+        );
+        db.commit(&set(1, 1, 10));
+
+        let read = || db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![1]));
+        assert_eq!(read(), Some(vec![10]));
+        assert_eq!(read(), None);
+        assert_eq!(read(), Some(vec![10]));
+        assert_eq!(db.reads(), 3);
+        assert_eq!(db.injected_faults(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Injected IO error on substate database read 0")]
+    fn test_io_error_read_fault_panics() {
+        let db = FaultInjectingSubstateDatabase::new(
+            InMemorySubstateDatabase::standard(),
+            SubstateDatabaseFaults::new().fail_read_at(0, ReadFault::IoError),
+        );
+
+        db.list_entries(&DbPartitionKey(vec![1]));
+    }
+
+    #[test]
+    fn test_partial_commit_fault_applies_leading_partitions_only() {
+        let mut db = FaultInjectingSubstateDatabase::new(
+            InMemorySubstateDatabase::standard(),
+            SubstateDatabaseFaults::new().fail_commit_at(
+                0,
+                CommitFault::Partial {
+                    applied_partitions: 1,
+                },
+            ),
+        );
+        let mut updates = set(1, 1, 10);
+        updates.extend(set(2, 1, 20));
+
+        db.commit(&updates);
+
+        assert_eq!(
+            db.get_substate(&DbPartitionKey(vec![1]), &DbSortKey(vec![1])),
+            Some(vec![10])
+        );
+        assert_eq!(
+            db.get_substate(&DbPartitionKey(vec![2]), &DbSortKey(vec![1])),
+            None
+        );
+        assert_eq!(db.commits(), 1);
+        assert_eq!(db.injected_faults(), 1);
+    }
+}
@@ -0,0 +1,340 @@
+use radix_engine_common::types::*;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::*;
+use sbor::rust::prelude::*;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// The file formats a ledger can be exported to with [`export_ledger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerExportFormat {
+    Csv,
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+/// The blueprint an entity is an instance of, as resolved by the caller of [`export_ledger`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeBlueprint {
+    pub blueprint: String,
+    pub version: String,
+}
+
+/// A single substate of the ledger, with the columns commonly needed for analytics decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstateRow {
+    pub node_id: NodeId,
+    pub entity_type: Option<EntityType>,
+    pub partition_number: PartitionNumber,
+    pub sort_key: DbSortKey,
+    /// The blueprint of the entity, if it's an object.
+    pub blueprint: Option<NodeBlueprint>,
+    /// The size of the encoded substate value, in bytes.
+    pub size: usize,
+}
+
+impl SubstateRow {
+    pub const COLUMNS: [&'static str; 8] = [
+        "node_id",
+        "entity_type",
+        "partition_number",
+        "sort_key",
+        "blueprint",
+        "blueprint_version",
+        "size",
+        "value",
+    ];
+}
+
+/// Reads every substate of the database into rows, calling `resolve_blueprint` once per node.
+///
+/// The substate values themselves are not kept, only their size: see [`export_ledger`] for
+/// writing values out.
+pub fn collect_substate_rows<S, F>(substate_db: &S, mut resolve_blueprint: F) -> Vec<SubstateRow>
+where
+    S: SubstateDatabase + ListableSubstateDatabase,
+    F: FnMut(&S, &NodeId) -> Option<NodeBlueprint>,
+{
+    let mut blueprints = index_map_new::<NodeId, Option<NodeBlueprint>>();
+    let mut rows = Vec::new();
+    for partition_key in substate_db.list_partition_keys() {
+        let (node_id, partition_number) =
+            SpreadPrefixKeyMapper::from_db_partition_key(&partition_key);
+        let blueprint = blueprints
+            .entry(node_id)
+            .or_insert_with(|| resolve_blueprint(substate_db, &node_id))
+            .clone();
+        for (sort_key, value) in substate_db.list_entries(&partition_key) {
+            rows.push(SubstateRow {
+                node_id,
+                entity_type: node_id.entity_type(),
+                partition_number,
+                sort_key,
+                blueprint: blueprint.clone(),
+                size: value.len(),
+            });
+        }
+    }
+    rows.sort_by(|a, b| {
+        (a.node_id, a.partition_number, &a.sort_key).cmp(&(
+            b.node_id,
+            b.partition_number,
+            &b.sort_key,
+        ))
+    });
+    rows
+}
+
+/// Exports every substate of the database into a file at `path`, one row per substate, and
+/// returns the number of rows written.
+///
+/// Besides the raw keys and value (hex encoded), each row has the entity type, the blueprint and
+/// blueprint version of the entity (as resolved by `resolve_blueprint`) and the value size, so
+/// the export can be analysed without decoding SBOR.
+pub fn export_ledger<S, F>(
+    substate_db: &S,
+    resolve_blueprint: F,
+    format: LedgerExportFormat,
+    path: &Path,
+) -> Result<usize, io::Error>
+where
+    S: SubstateDatabase + ListableSubstateDatabase,
+    F: FnMut(&S, &NodeId) -> Option<NodeBlueprint>,
+{
+    let rows = collect_substate_rows(substate_db, resolve_blueprint);
+    let values = rows
+        .iter()
+        .map(|row| {
+            let partition_key =
+                SpreadPrefixKeyMapper::to_db_partition_key(&row.node_id, row.partition_number);
+            substate_db
+                .get_substate(&partition_key, &row.sort_key)
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let file = File::create(path)?;
+    match format {
+        LedgerExportFormat::Csv => write_csv(&rows, &values, BufWriter::new(file))?,
+        #[cfg(feature = "parquet")]
+        LedgerExportFormat::Parquet => write_parquet(&rows, &values, file)?,
+    }
+    Ok(rows.len())
+}
+
+/// Writes the rows as CSV, with a header line of [`SubstateRow::COLUMNS`].
+pub fn write_csv<W: Write>(
+    rows: &[SubstateRow],
+    values: &[DbSubstateValue],
+    mut writer: W,
+) -> Result<(), io::Error> {
+    writeln!(writer, "{}", SubstateRow::COLUMNS.join(","))?;
+    for (row, value) in rows.iter().zip(values) {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{}",
+            hex::encode(row.node_id.as_bytes()),
+            entity_type_name(row),
+            row.partition_number.0,
+            hex::encode(&row.sort_key.0),
+            row.blueprint
+                .as_ref()
+                .map(|b| csv_escape(&b.blueprint))
+                .unwrap_or_default(),
+            row.blueprint
+                .as_ref()
+                .map(|b| csv_escape(&b.version))
+                .unwrap_or_default(),
+            row.size,
+            hex::encode(value),
+        )?;
+    }
+    writer.flush()
+}
+
+/// Writes the rows as a Parquet file with a single row group; the blueprint columns are optional.
+#[cfg(feature = "parquet")]
+pub fn write_parquet<W: Write + Send>(
+    rows: &[SubstateRow],
+    values: &[DbSubstateValue],
+    writer: W,
+) -> Result<(), io::Error> {
+    use parquet::data_type::{ByteArray, ByteArrayType, Int32Type, Int64Type};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+    use std::sync::Arc;
+
+    const SCHEMA: &str = "
+        message substate {
+            REQUIRED BYTE_ARRAY node_id;
+            REQUIRED BYTE_ARRAY entity_type (UTF8);
+            REQUIRED INT32 partition_number;
+            REQUIRED BYTE_ARRAY sort_key;
+            OPTIONAL BYTE_ARRAY blueprint (UTF8);
+            OPTIONAL BYTE_ARRAY blueprint_version (UTF8);
+            REQUIRED INT64 size;
+            REQUIRED BYTE_ARRAY value;
+        }
+    ";
+
+    fn bytes<T: AsRef<[u8]>>(values: impl Iterator<Item = T>) -> Vec<ByteArray> {
+        values
+            .map(|v| ByteArray::from(v.as_ref().to_vec()))
+            .collect()
+    }
+
+    fn optional_bytes<'a>(
+        values: impl Iterator<Item = Option<&'a String>>,
+    ) -> (Vec<ByteArray>, Vec<i16>) {
+        let mut present = Vec::new();
+        let mut definition_levels = Vec::new();
+        for value in values {
+            if let Some(value) = value {
+                present.push(ByteArray::from(value.as_str()));
+                definition_levels.push(1);
+            } else {
+                definition_levels.push(0);
+            }
+        }
+        (present, definition_levels)
+    }
+
+    let to_io_error = |e: parquet::errors::ParquetError| io::Error::new(io::ErrorKind::Other, e);
+
+    let schema = Arc::new(parse_message_type(SCHEMA).map_err(to_io_error)?);
+    let properties = Arc::new(WriterProperties::builder().build());
+    let mut file_writer =
+        SerializedFileWriter::new(writer, schema, properties).map_err(to_io_error)?;
+    let mut row_group_writer = file_writer.next_row_group().map_err(to_io_error)?;
+
+    let mut column_index = 0;
+    while let Some(mut column_writer) = row_group_writer.next_column().map_err(to_io_error)? {
+        match column_index {
+            0 => column_writer.typed::<ByteArrayType>().write_batch(
+                &bytes(rows.iter().map(|row| row.node_id.as_bytes())),
+                None,
+                None,
+            ),
+            1 => column_writer.typed::<ByteArrayType>().write_batch(
+                &bytes(rows.iter().map(entity_type_name)),
+                None,
+                None,
+            ),
+            2 => column_writer.typed::<Int32Type>().write_batch(
+                &rows
+                    .iter()
+                    .map(|row| row.partition_number.0 as i32)
+                    .collect::<Vec<_>>(),
+                None,
+                None,
+            ),
+            3 => column_writer.typed::<ByteArrayType>().write_batch(
+                &bytes(rows.iter().map(|row| &row.sort_key.0)),
+                None,
+                None,
+            ),
+            4 => {
+                let (values, definition_levels) = optional_bytes(
+                    rows.iter()
+                        .map(|row| row.blueprint.as_ref().map(|b| &b.blueprint)),
+                );
+                column_writer.typed::<ByteArrayType>().write_batch(
+                    &values,
+                    Some(&definition_levels),
+                    None,
+                )
+            }
+            5 => {
+                let (values, definition_levels) = optional_bytes(
+                    rows.iter()
+                        .map(|row| row.blueprint.as_ref().map(|b| &b.version)),
+                );
+                column_writer.typed::<ByteArrayType>().write_batch(
+                    &values,
+                    Some(&definition_levels),
+                    None,
+                )
+            }
+            6 => column_writer.typed::<Int64Type>().write_batch(
+                &rows.iter().map(|row| row.size as i64).collect::<Vec<_>>(),
+                None,
+                None,
+            ),
+            _ => column_writer.typed::<ByteArrayType>().write_batch(
+                &bytes(values.iter()),
+                None,
+                None,
+            ),
+        }
+        .map_err(to_io_error)?;
+        column_writer.close().map_err(to_io_error)?;
+        column_index += 1;
+    }
+
+    row_group_writer.close().map_err(to_io_error)?;
+    file_writer.close().map_err(to_io_error)?;
+    Ok(())
+}
+
+fn entity_type_name(row: &SubstateRow) -> String {
+    row.entity_type
+        .map(|entity_type| format!("{:?}", entity_type))
+        .unwrap_or_default()
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    #[test]
+    fn test_substates_are_exported_as_csv_rows() {
+        let node_id = NodeId([EntityType::GlobalGenericComponent as u8; NodeId::LENGTH]);
+        let partition_key =
+            SpreadPrefixKeyMapper::to_db_partition_key(&node_id, PartitionNumber(64));
+        let mut db = InMemorySubstateDatabase::standard();
+        db.commit(&indexmap!(
+            partition_key => indexmap!(
+                DbSortKey(vec![0]) => DatabaseUpdate::Set(vec![1, 2, 3]),
+                DbSortKey(vec![1]) => DatabaseUpdate::Set(vec![4]),
+            )
+        ));
+
+        let rows = collect_substate_rows(&db, |_, _| {
+            Some(NodeBlueprint {
+                blueprint: "Hello".to_string(),
+                version: "1.0.0".to_string(),
+            })
+        });
+        let values = vec![vec![1, 2, 3], vec![4]];
+        let mut csv = Vec::new();
+        write_csv(&rows, &values, &mut csv).unwrap();
+
+        let node_id_hex = hex::encode(node_id.as_bytes());
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            format!(
+                "node_id,entity_type,partition_number,sort_key,blueprint,blueprint_version,size,value\n\
+                 {node_id_hex},GlobalGenericComponent,64,00,Hello,1.0.0,3,010203\n\
+                 {node_id_hex},GlobalGenericComponent,64,01,Hello,1.0.0,1,04\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,90 @@
+use crate::rocks_db::RocksdbSubstateStore;
+use crate::rocks_db_with_merkle_tree::{
+    RocksDBWithMerkleTreeSubstateStore, STALE_MERKLE_NODE_KEYS_CF,
+};
+use rocksdb::{Options, DB};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The outcome of pruning and compacting a ledger with [`prune_ledger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LedgerPruningReport {
+    /// The number of state versions whose history was pruned.
+    pub pruned_state_versions: u64,
+    /// The number of stale merkle nodes deleted.
+    pub pruned_merkle_nodes: u64,
+    /// The number of previous substate values deleted.
+    pub pruned_substate_history_entries: u64,
+    /// The size of the ledger directory before pruning, in bytes.
+    pub size_before: u64,
+    /// The size of the ledger directory after pruning and compaction, in bytes.
+    pub size_after: u64,
+}
+
+impl LedgerPruningReport {
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.size_before.saturating_sub(self.size_after)
+    }
+}
+
+/// Prunes the history of the ledger at the given directory, keeping the last
+/// `retained_state_versions` state versions, and compacts it.
+///
+/// Ledgers with a merkle tree keep the tree nodes made stale and the substate values replaced by
+/// each state version, which are what gets pruned. Ledgers without one only hold the latest version
+/// of each substate, so they are compacted only.
+///
+/// This is an offline operation: the ledger must not be opened by anyone else.
+pub fn prune_ledger(
+    root: PathBuf,
+    retained_state_versions: u64,
+) -> Result<LedgerPruningReport, io::Error> {
+    let size_before = directory_size(&root)?;
+
+    let column_families = DB::list_cf(&Options::default(), &root)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let (pruned_state_versions, pruned_merkle_nodes, pruned_substate_history_entries) =
+        if column_families
+            .iter()
+            .any(|cf| cf == STALE_MERKLE_NODE_KEYS_CF)
+        {
+            let mut substate_db = RocksDBWithMerkleTreeSubstateStore::standard(root.clone());
+            let (pruned_state_versions, pruned_merkle_nodes) =
+                substate_db.prune_stale_merkle_nodes(retained_state_versions);
+            let pruned_substate_history_entries =
+                substate_db.prune_substate_history(retained_state_versions);
+            substate_db.compact();
+            (
+                pruned_state_versions,
+                pruned_merkle_nodes,
+                pruned_substate_history_entries,
+            )
+        } else {
+            let mut substate_db = RocksdbSubstateStore::standard(root.clone());
+            substate_db.compact();
+            (0, 0, 0)
+        };
+
+    Ok(LedgerPruningReport {
+        pruned_state_versions,
+        pruned_merkle_nodes,
+        pruned_substate_history_entries,
+        size_before,
+        size_after: directory_size(&root)?,
+    })
+}
+
+fn directory_size(path: &Path) -> Result<u64, io::Error> {
+    let mut size = 0;
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        size += if metadata.is_dir() {
+            directory_size(&entry.path())?
+        } else {
+            metadata.len()
+        };
+    }
+    Ok(size)
+}
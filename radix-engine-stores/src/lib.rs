@@ -5,7 +5,13 @@ compile_error!("Either feature `std` or `alloc` must be enabled for this crate."
 #[cfg(all(feature = "std", feature = "alloc"))]
 compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 
+pub mod cached_db;
+pub mod fault_injecting_db;
 pub mod hash_tree;
+#[cfg(feature = "std")]
+pub mod ledger_export;
+#[cfg(feature = "rocksdb")]
+pub mod ledger_pruning;
 pub mod memory_db;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
@@ -39,6 +39,21 @@ impl SubstateDatabase for InMemorySubstateDatabase {
 
         Box::new(iter)
     }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: &DbSortKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let iter = self
+            .partitions
+            .get(partition_key)
+            .into_iter()
+            .flat_map(|partition| partition.range(from_sort_key.clone()..))
+            .map(|(key, substate)| (key.clone(), substate.clone()));
+
+        Box::new(iter)
+    }
 }
 
 impl CommittableSubstateDatabase for InMemorySubstateDatabase {
@@ -21,6 +21,11 @@ impl RocksdbSubstateStore {
 
         Self { db }
     }
+
+    /// Compacts the database, reclaiming the space held by deleted and overwritten entries.
+    pub fn compact(&mut self) {
+        self.db.compact_range::<&[u8], &[u8]>(None, None);
+    }
 }
 
 impl SubstateDatabase for RocksdbSubstateStore {
@@ -36,9 +41,17 @@ impl SubstateDatabase for RocksdbSubstateStore {
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.list_entries_from(partition_key, &DbSortKey(vec![]))
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: &DbSortKey,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         let partition_key = partition_key.clone();
-        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![]));
+        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, from_sort_key);
         let iter = self
             .db
             .iterator(IteratorMode::From(&start_key_bytes, Direction::Forward))
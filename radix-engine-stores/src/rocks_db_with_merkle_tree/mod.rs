@@ -9,6 +9,7 @@ use rocksdb::{
     ColumnFamily, ColumnFamilyDescriptor, DBWithThreadMode, Direction, IteratorMode,
     SingleThreaded, WriteBatch, DB,
 };
+use sbor::rust::ops::RangeInclusive;
 use sbor::rust::prelude::*;
 use std::path::PathBuf;
 use utils::copy_u8_array;
@@ -18,7 +19,10 @@ use state_tree::*;
 const META_CF: &str = "meta";
 const SUBSTATES_CF: &str = "substates";
 const MERKLE_NODES_CF: &str = "merkle_nodes";
-const STALE_MERKLE_NODE_KEYS_CF: &str = "stale_merkle_node_keys";
+pub(crate) const STALE_MERKLE_NODE_KEYS_CF: &str = "stale_merkle_node_keys";
+const SUBSTATE_HISTORY_CF: &str = "substate_history";
+
+const EARLIEST_READABLE_STATE_VERSION_KEY: &[u8] = b"earliest_readable_state_version";
 
 pub struct RocksDBWithMerkleTreeSubstateStore {
     db: DBWithThreadMode<SingleThreaded>,
@@ -41,18 +45,142 @@ impl RocksDBWithMerkleTreeSubstateStore {
                 SUBSTATES_CF,
                 MERKLE_NODES_CF,
                 STALE_MERKLE_NODE_KEYS_CF,
+                SUBSTATE_HISTORY_CF,
             ]
             .into_iter()
             .map(|name| ColumnFamilyDescriptor::new(name, Options::default()))
             .collect::<Vec<_>>(),
         )
         .unwrap();
-        Self { db }
+        let store = Self { db };
+
+        // Ledgers created before substate history was kept can only be read at their current version
+        if store
+            .db
+            .get_cf(store.cf(META_CF), EARLIEST_READABLE_STATE_VERSION_KEY)
+            .unwrap()
+            .is_none()
+        {
+            let current_state_version = store.read_metadata().current_state_version;
+            store.write_earliest_readable_state_version(current_state_version);
+        }
+
+        store
     }
 
     fn cf(&self, cf: &str) -> &ColumnFamily {
         self.db.cf_handle(cf).unwrap()
     }
+
+    fn read_metadata(&self) -> Metadata {
+        self.db
+            .get_cf(self.cf(META_CF), [])
+            .unwrap()
+            .map(|bytes| scrypto_decode::<Metadata>(&bytes).unwrap())
+            .unwrap_or_else(|| Metadata {
+                current_state_version: 0,
+            })
+    }
+
+    fn read_earliest_readable_state_version(&self) -> u64 {
+        self.db
+            .get_cf(self.cf(META_CF), EARLIEST_READABLE_STATE_VERSION_KEY)
+            .unwrap()
+            .map(|bytes| u64::from_be_bytes(copy_u8_array(&bytes)))
+            .unwrap_or(0)
+    }
+
+    fn write_earliest_readable_state_version(&self, state_version: u64) {
+        self.db
+            .put_cf(
+                self.cf(META_CF),
+                EARLIEST_READABLE_STATE_VERSION_KEY,
+                state_version.to_be_bytes(),
+            )
+            .unwrap();
+    }
+
+    /// Deletes the merkle nodes which became stale more than `retained_state_versions` state
+    /// versions ago, after which the tree can no longer be read at those versions.
+    ///
+    /// Returns the number of state versions and the number of merkle nodes pruned.
+    pub fn prune_stale_merkle_nodes(&mut self, retained_state_versions: u64) -> (u64, u64) {
+        let last_prunable_state_version = self
+            .read_metadata()
+            .current_state_version
+            .saturating_sub(retained_state_versions);
+
+        let mut batch = WriteBatch::default();
+        let mut pruned_state_versions = 0;
+        let mut pruned_merkle_nodes = 0;
+        for kv in self
+            .db
+            .iterator_cf(self.cf(STALE_MERKLE_NODE_KEYS_CF), IteratorMode::Start)
+        {
+            let (state_version_bytes, encoded_node_keys) = kv.unwrap();
+            // State versions are encoded in big endian, so the iteration is in version order
+            let state_version = u64::from_be_bytes(copy_u8_array(&state_version_bytes));
+            if state_version > last_prunable_state_version {
+                break;
+            }
+            for encoded_node_key in scrypto_decode::<Vec<Vec<u8>>>(&encoded_node_keys).unwrap() {
+                batch.delete_cf(self.cf(MERKLE_NODES_CF), encoded_node_key);
+                pruned_merkle_nodes += 1;
+            }
+            batch.delete_cf(self.cf(STALE_MERKLE_NODE_KEYS_CF), state_version_bytes);
+            pruned_state_versions += 1;
+        }
+        self.db.write(batch).unwrap();
+
+        (pruned_state_versions, pruned_merkle_nodes)
+    }
+
+    /// Deletes the previous substate values replaced more than `retained_state_versions` state
+    /// versions ago, after which the substates can no longer be read at those versions.
+    ///
+    /// Returns the number of substate history entries pruned.
+    pub fn prune_substate_history(&mut self, retained_state_versions: u64) -> u64 {
+        let earliest_readable_state_version = self
+            .read_metadata()
+            .current_state_version
+            .saturating_sub(retained_state_versions);
+        if earliest_readable_state_version <= self.read_earliest_readable_state_version() {
+            return 0;
+        }
+
+        // Reading at a version needs the previous values replaced by all the later versions
+        let mut batch = WriteBatch::default();
+        let mut pruned_entries = 0;
+        for kv in self
+            .db
+            .iterator_cf(self.cf(SUBSTATE_HISTORY_CF), IteratorMode::Start)
+        {
+            let (history_key, _) = kv.unwrap();
+            let (_, state_version) = decode_history_key(&history_key);
+            if state_version <= earliest_readable_state_version {
+                batch.delete_cf(self.cf(SUBSTATE_HISTORY_CF), history_key);
+                pruned_entries += 1;
+            }
+        }
+        self.db.write(batch).unwrap();
+        self.write_earliest_readable_state_version(earliest_readable_state_version);
+
+        pruned_entries
+    }
+
+    /// Compacts all column families, reclaiming the space held by deleted and overwritten entries.
+    pub fn compact(&mut self) {
+        for cf in [
+            META_CF,
+            SUBSTATES_CF,
+            MERKLE_NODES_CF,
+            STALE_MERKLE_NODE_KEYS_CF,
+            SUBSTATE_HISTORY_CF,
+        ] {
+            self.db
+                .compact_range_cf::<&[u8], &[u8]>(self.cf(cf), None, None);
+        }
+    }
 }
 
 impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
@@ -70,9 +198,17 @@ impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.list_entries_from(partition_key, &DbSortKey(vec![]))
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: &DbSortKey,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         let partition_key = partition_key.clone();
-        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![]));
+        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, from_sort_key);
         let iter = self
             .db
             .iterator_cf(
@@ -94,23 +230,23 @@ impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
 impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     fn commit(&mut self, database_updates: &DatabaseUpdates) {
         // read required info about current database state (here I fake it a bit)
-        let metadata = self
-            .db
-            .get_cf(self.cf(META_CF), [])
-            .unwrap()
-            .map(|bytes| scrypto_decode::<Metadata>(&bytes).unwrap())
-            .unwrap_or_else(|| Metadata {
-                current_state_version: 0,
-            });
+        let metadata = self.read_metadata();
         let parent_state_version = metadata.current_state_version;
         let next_state_version = parent_state_version + 1;
 
         // prepare a batch write (we use the same approach in the actual Node)
         let mut batch = WriteBatch::default();
 
-        // put regular substate changes
+        // put regular substate changes (and keep the values they replace, for historical reads)
         for (patrition_key, partition_updates) in database_updates {
             for (sort_key, database_update) in partition_updates {
+                let previous_value = self.get_substate(patrition_key, sort_key);
+                batch.put_cf(
+                    self.cf(SUBSTATE_HISTORY_CF),
+                    encode_history_key(patrition_key, sort_key, next_state_version),
+                    scrypto_encode(&previous_value).unwrap(),
+                );
+
                 let key_bytes = encode_to_rocksdb_bytes(patrition_key, sort_key);
                 match database_update {
                     DatabaseUpdate::Set(value_bytes) => {
@@ -121,7 +257,7 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
             }
         }
 
-        // derive and put new JMT nodes (also record keys of stale nodes, for later amortized background GC [not implemented here!])
+        // derive and put new JMT nodes (also record keys of stale nodes, for later pruning)
         let state_hash_tree_update =
             compute_state_tree_update(self, parent_state_version, database_updates);
         for (key, node) in state_hash_tree_update.new_re_node_layer_nodes {
@@ -181,6 +317,78 @@ impl ListableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     }
 }
 
+impl VersionedSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
+    fn readable_state_versions(&self) -> RangeInclusive<u64> {
+        self.read_earliest_readable_state_version()..=self.read_metadata().current_state_version
+    }
+
+    fn get_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Result<Option<DbSubstateValue>, StateVersionError> {
+        check_state_version_readable(self, state_version)?;
+
+        // The value at a version is the one replaced by the first later version which changed it
+        let substate_prefix = encode_history_prefix(partition_key, Some(sort_key));
+        let start_key = encode_history_key(partition_key, sort_key, state_version + 1);
+        let replaced = self
+            .db
+            .iterator_cf(
+                self.cf(SUBSTATE_HISTORY_CF),
+                IteratorMode::From(&start_key, Direction::Forward),
+            )
+            .next()
+            .map(|kv| kv.unwrap())
+            .filter(|(history_key, _)| {
+                history_key.len() == substate_prefix.len() + 8
+                    && history_key.starts_with(&substate_prefix)
+            });
+
+        Ok(match replaced {
+            Some((_, previous_value)) => scrypto_decode(&previous_value).unwrap(),
+            None => self.get_substate(partition_key, sort_key),
+        })
+    }
+
+    fn list_entries_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+    ) -> Result<Box<dyn Iterator<Item = PartitionEntry> + '_>, StateVersionError> {
+        check_state_version_readable(self, state_version)?;
+
+        let mut entries: BTreeMap<DbSortKey, DbSubstateValue> =
+            self.list_entries(partition_key).collect();
+
+        // History entries of a substate are in version order, so the first one after the requested
+        // version holds the value at that version
+        let partition_prefix = encode_history_prefix(partition_key, None);
+        let mut restored = BTreeSet::new();
+        for kv in self.db.iterator_cf(
+            self.cf(SUBSTATE_HISTORY_CF),
+            IteratorMode::From(&partition_prefix, Direction::Forward),
+        ) {
+            let (history_key, previous_value) = kv.unwrap();
+            if !history_key.starts_with(&partition_prefix) {
+                break;
+            }
+            let ((_, sort_key), version) = decode_history_key(&history_key);
+            if version <= state_version || restored.contains(&sort_key) {
+                continue;
+            }
+            match scrypto_decode::<Option<DbSubstateValue>>(&previous_value).unwrap() {
+                Some(value) => entries.insert(sort_key.clone(), value),
+                None => entries.remove(&sort_key),
+            };
+            restored.insert(sort_key);
+        }
+
+        Ok(Box::new(entries.into_iter()))
+    }
+}
+
 impl<P: Payload> ReadableTreeStore<P> for RocksDBWithMerkleTreeSubstateStore {
     fn get_node(&self, key: &NodeKey) -> Option<TreeNode<P>> {
         self.db
@@ -207,7 +415,125 @@ fn decode_from_rocksdb_bytes(buffer: &[u8]) -> DbSubstateKey {
     (partition_key, sort_key)
 }
 
+/// Encodes the key prefix of the history entries of a partition, or of a single substate if a sort
+/// key is given. Both keys are length-prefixed, so that a sort key can be followed by a version.
+fn encode_history_prefix(partition_key: &DbPartitionKey, sort_key: Option<&DbSortKey>) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    buffer.extend(u32::try_from(partition_key.0.len()).unwrap().to_be_bytes());
+    buffer.extend(partition_key.0.clone());
+    if let Some(sort_key) = sort_key {
+        buffer.extend(u32::try_from(sort_key.0.len()).unwrap().to_be_bytes());
+        buffer.extend(sort_key.0.clone());
+    }
+    buffer
+}
+
+fn encode_history_key(
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+    state_version: u64,
+) -> Vec<u8> {
+    let mut buffer = encode_history_prefix(partition_key, Some(sort_key));
+    buffer.extend(state_version.to_be_bytes());
+    buffer
+}
+
+fn decode_history_key(buffer: &[u8]) -> (DbSubstateKey, u64) {
+    let partition_key_len =
+        usize::try_from(u32::from_be_bytes(copy_u8_array(&buffer[..4]))).unwrap();
+    let sort_key_len_offset = 4 + partition_key_len;
+    let sort_key_offset = sort_key_len_offset + 4;
+    let partition_key = DbPartitionKey(buffer[4..sort_key_len_offset].to_vec());
+    let sort_key = DbSortKey(buffer[sort_key_offset..buffer.len() - 8].to_vec());
+    let state_version = u64::from_be_bytes(copy_u8_array(&buffer[buffer.len() - 8..]));
+    ((partition_key, sort_key), state_version)
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, ScryptoSbor)]
 struct Metadata {
     current_state_version: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::indexmap;
+
+    fn update(
+        partition_key: &DbPartitionKey,
+        updates: Vec<(DbSortKey, DatabaseUpdate)>,
+    ) -> DatabaseUpdates {
+        indexmap!(partition_key.clone() => updates.into_iter().collect())
+    }
+
+    #[test]
+    fn test_substates_are_readable_at_past_versions_until_pruned() {
+        let root = std::env::temp_dir().join(format!("substate_history_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let mut substate_db = RocksDBWithMerkleTreeSubstateStore::standard(root.clone());
+        let partition_key = DbPartitionKey(vec![1, 3, 3, 7]);
+        let (a, b) = (DbSortKey(vec![1]), DbSortKey(vec![1, 0]));
+
+        // Version 1: a = 1, version 2: a = 2 and b = 2, version 3: a deleted
+        substate_db.commit(&update(
+            &partition_key,
+            vec![(a.clone(), DatabaseUpdate::Set(vec![1]))],
+        ));
+        substate_db.commit(&update(
+            &partition_key,
+            vec![
+                (a.clone(), DatabaseUpdate::Set(vec![2])),
+                (b.clone(), DatabaseUpdate::Set(vec![2])),
+            ],
+        ));
+        substate_db.commit(&update(
+            &partition_key,
+            vec![(a.clone(), DatabaseUpdate::Delete)],
+        ));
+
+        assert_eq!(substate_db.readable_state_versions(), 0..=3);
+        let read = |version| {
+            (
+                substate_db
+                    .get_substate_at_version(version, &partition_key, &a)
+                    .unwrap(),
+                substate_db
+                    .list_entries_at_version(version, &partition_key)
+                    .unwrap()
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(read(0), (None, vec![]));
+        assert_eq!(read(1), (Some(vec![1]), vec![(a.clone(), vec![1])]));
+        assert_eq!(
+            read(2),
+            (
+                Some(vec![2]),
+                vec![(a.clone(), vec![2]), (b.clone(), vec![2])]
+            )
+        );
+        assert_eq!(read(3), (None, vec![(b.clone(), vec![2])]));
+        assert_eq!(
+            substate_db.get_substate_at_version(4, &partition_key, &a),
+            Err(StateVersionError::NotCommitted {
+                current_state_version: 3
+            })
+        );
+
+        assert_eq!(substate_db.prune_substate_history(1), 3);
+        assert_eq!(substate_db.readable_state_versions(), 2..=3);
+        assert_eq!(
+            substate_db.get_substate_at_version(2, &partition_key, &a),
+            Ok(Some(vec![2]))
+        );
+        assert_eq!(
+            substate_db.get_substate_at_version(1, &partition_key, &a),
+            Err(StateVersionError::Pruned {
+                earliest_readable_state_version: 2
+            })
+        );
+
+        drop(substate_db);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -11,8 +11,8 @@ use radix_engine::{
 use radix_engine_queries::typed_substate_layout::PackageDefinition;
 use sbor::rust::iter;
 use transaction::{
-    prelude::Secp256k1PrivateKey,
-    validation::{recover_secp256k1, verify_secp256k1},
+    prelude::{Ed25519PrivateKey, Secp256k1PrivateKey},
+    validation::{recover_secp256k1, verify_ed25519, verify_secp256k1},
 };
 use wabt::wat2wasm;
 
@@ -58,6 +58,28 @@ fn bench_validate_secp256k1(c: &mut Criterion) {
     });
 }
 
+fn bench_validate_ed25519(c: &mut Criterion) {
+    let message = "m".repeat(1_000_000);
+    let message_hash = hash(message.as_bytes());
+    let signer = Ed25519PrivateKey::from_u64(123123123123).unwrap();
+    let public_key = signer.public_key();
+    let signature = signer.sign(&message_hash);
+
+    c.bench_function("costing::validate_ed25519", |b| {
+        b.iter(|| {
+            verify_ed25519(&message_hash, &public_key, &signature);
+        })
+    });
+}
+
+fn bench_hash_blake2b(c: &mut Criterion) {
+    let data = "m".repeat(1_000_000);
+
+    c.bench_function("costing::hash_blake2b", |b| {
+        b.iter(|| blake2b_256_hash(data.as_bytes()))
+    });
+}
+
 fn bench_spin_loop(c: &mut Criterion) {
     // Prepare code
     let code = wat2wasm(&include_str!("../tests/wasm/loop.wat").replace("${n}", "100000")).unwrap();
@@ -139,6 +161,8 @@ criterion_group!(
     bench_decode_sbor,
     bench_validate_sbor_payload,
     bench_validate_secp256k1,
+    bench_validate_ed25519,
+    bench_hash_blake2b,
     bench_spin_loop,
     bench_instantiate_radiswap,
     bench_validate_wasm,
@@ -1723,27 +1723,27 @@ impl AccessControllerTestRunner {
         proposed_confirmation_role: AccessRule,
         timed_recovery_delay_in_minutes: Option<u32>,
     ) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
-            Role::Recovery => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
-            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
+        let rule_set = RuleSet {
+            primary_role: proposed_primary_role,
+            recovery_role: proposed_recovery_role,
+            confirmation_role: proposed_confirmation_role,
         };
 
-        let manifest = self
-            .manifest_builder(as_role)
-            .call_method(
+        let manifest_builder = self.manifest_builder(as_role);
+        let manifest = match as_role {
+            Role::Primary => manifest_builder.initiate_recovery_as_primary(
                 self.access_controller_address,
-                method_name,
-                AccessControllerInitiateRecoveryAsPrimaryInput {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
-                },
-            )
-            .build();
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            ),
+            Role::Recovery => manifest_builder.initiate_recovery_as_recovery(
+                self.access_controller_address,
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            ),
+            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
+        }
+        .build();
         self.execute_manifest(manifest)
     }
 
@@ -1789,28 +1789,17 @@ impl AccessControllerTestRunner {
             Role::Confirmation => panic!("Confirmation is not a valid proposer"),
         };
 
-        let method_name = match proposer {
-            Proposer::Primary => {
-                ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT
-            }
-            Proposer::Recovery => {
-                ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT
-            }
-        };
-
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
+            .quick_confirm_recovery(
                 self.access_controller_address,
-                method_name,
-                AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInput {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
+                proposer,
+                RuleSet {
+                    primary_role: proposed_primary_role,
+                    recovery_role: proposed_recovery_role,
+                    confirmation_role: proposed_confirmation_role,
                 },
+                timed_recovery_delay_in_minutes,
             )
             .build();
         self.execute_manifest(manifest)
@@ -1857,36 +1846,29 @@ impl AccessControllerTestRunner {
     ) -> TransactionReceipt {
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
+            .timed_confirm_recovery(
                 self.access_controller_address,
-                ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
-                AccessControllerTimedConfirmRecoveryInput {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
+                RuleSet {
+                    primary_role: proposed_primary_role,
+                    recovery_role: proposed_recovery_role,
+                    confirmation_role: proposed_confirmation_role,
                 },
+                timed_recovery_delay_in_minutes,
             )
             .build();
         self.execute_manifest(manifest)
     }
 
     pub fn cancel_recovery_attempt(&mut self, as_role: Role) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
-            Role::Recovery => ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+        let proposer = match as_role {
+            Role::Primary => Proposer::Primary,
+            Role::Recovery => Proposer::Recovery,
             Role::Confirmation => panic!("No method for the given role"),
         };
 
         let manifest = self
             .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_address,
-                method_name,
-                AccessControllerCancelPrimaryRoleRecoveryProposalInput,
-            )
+            .cancel_recovery(self.access_controller_address, proposer)
             .build();
         self.execute_manifest(manifest)
     }
@@ -1,4 +1,4 @@
-use radix_engine::errors::{RuntimeError, SystemError, SystemModuleError};
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
@@ -7,6 +7,7 @@ use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use radix_engine_interface::rule;
+use scrypto::component::{PausedEvent, UnpausedEvent};
 use scrypto_unit::*;
 use transaction::prelude::*;
 
@@ -327,6 +328,217 @@ fn change_lock_owner_role_rules() {
     })
 }
 
+fn create_state_gated_component(
+    test_runner: &mut TestRunner,
+    gate_function: &str,
+) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/access_rules");
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "StateGatedAccessRules",
+            "new",
+            manifest_args!(gate_function),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, []);
+    receipt.expect_commit(true).new_component_addresses()[0]
+}
+
+fn call_state_gated_method(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+    args: ManifestArgs,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .call_method(component_address, method_name, args)
+        .build();
+    test_runner.execute_manifest_ignoring_fee(manifest, [])
+}
+
+#[test]
+fn state_gated_rule_follows_component_state() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = create_state_gated_component(&mut test_runner, "not_paused");
+
+    // Act
+    let unpaused_receipt = call_state_gated_method(
+        &mut test_runner,
+        component_address,
+        "operate",
+        manifest_args!(),
+    );
+    call_state_gated_method(
+        &mut test_runner,
+        component_address,
+        "set_paused",
+        manifest_args!(true),
+    )
+    .expect_commit_success();
+    let paused_receipt = call_state_gated_method(
+        &mut test_runner,
+        component_address,
+        "operate",
+        manifest_args!(),
+    );
+
+    // Assert
+    unpaused_receipt.expect_commit_success();
+    paused_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn state_gate_cannot_write_substates() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = create_state_gated_component(&mut test_runner, "unpause_and_allow");
+
+    // Act
+    let receipt = call_state_gated_method(
+        &mut test_runner,
+        component_address,
+        "operate",
+        manifest_args!(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::StateGateWriteNotAllowed(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn state_gate_cannot_depend_on_another_state_gate() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = create_state_gated_component(&mut test_runner, "operate_and_allow");
+
+    // Act
+    let receipt = call_state_gated_method(
+        &mut test_runner,
+        component_address,
+        "operate",
+        manifest_args!(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::StateGateDepthExceeded(..)
+            ))
+        )
+    });
+}
+
+fn create_pausable_counter(
+    test_runner: &mut TestRunner,
+    pauser_public_key: &Secp256k1PublicKey,
+) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/access_rules");
+    let pauser = rule!(require(NonFungibleGlobalId::from_public_key(
+        pauser_public_key
+    )));
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .call_function(
+                    package_address,
+                    "PausableCounter",
+                    "new",
+                    manifest_args!(pauser),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0]
+}
+
+#[test]
+fn pausable_component_cannot_be_paused_without_pauser_role() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let component_address = create_pausable_counter(&mut test_runner, &public_key);
+
+    // Act
+    let receipt = test_runner.call_method(component_address, "pause", manifest_args!());
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn pausing_component_blocks_guarded_methods_until_unpaused() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let component_address = create_pausable_counter(&mut test_runner, &public_key);
+    let pauser_proofs = vec![NonFungibleGlobalId::from_public_key(&public_key)];
+
+    // Act
+    let pause_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(component_address, "pause", manifest_args!())
+            .build(),
+        pauser_proofs.clone(),
+    );
+    let paused_increment_receipt =
+        test_runner.call_method(component_address, "increment", manifest_args!());
+    let unpause_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(component_address, "unpause", manifest_args!())
+            .build(),
+        pauser_proofs,
+    );
+    let unpaused_increment_receipt =
+        test_runner.call_method(component_address, "increment", manifest_args!());
+
+    // Assert
+    let pause_result = pause_receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .extract_events_of_type::<PausedEvent>(pause_result)
+            .len(),
+        1
+    );
+    paused_increment_receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::Panic(message)) => {
+            message.starts_with("Component is paused")
+        }
+        _ => false,
+    });
+    let unpause_result = unpause_receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .extract_events_of_type::<UnpausedEvent>(unpause_result)
+            .len(),
+        1
+    );
+    unpaused_increment_receipt.expect_commit_success();
+    let count: u32 = test_runner
+        .call_method(component_address, "count", manifest_args!())
+        .expect_commit_success()
+        .output(0);
+    assert_eq!(count, 1);
+}
+
 struct MutableAccessRulesTestRunner {
     test_runner: TestRunner,
     component_address: ComponentAddress,
@@ -4,7 +4,9 @@ use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::transaction::BalanceChange;
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::metadata::MetadataValue;
-use radix_engine_interface::blueprints::account::{AccountSecurifyInput, ACCOUNT_SECURIFY_IDENT};
+use radix_engine_interface::blueprints::account::{
+    AccountSecurifyInput, ResourceSpecifier, ACCOUNT_SECURIFY_IDENT,
+};
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -142,6 +144,72 @@ fn can_withdraw_non_fungible_from_my_virtual_account() {
     can_withdraw_non_fungible_from_my_account_internal(true)
 }
 
+#[test]
+fn can_withdraw_batch_from_my_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let fungible_resource = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_batch_from_account(
+            account,
+            vec![
+                ResourceSpecifier::Amount(fungible_resource, dec!(40)),
+                ResourceSpecifier::Ids(
+                    non_fungible_resource,
+                    btreeset!(
+                        NonFungibleLocalId::integer(1),
+                        NonFungibleLocalId::integer(3)
+                    ),
+                ),
+            ],
+        )
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(other_account, fungible_resource),
+        Some(dec!(40))
+    );
+    assert_eq!(
+        test_runner.account_balance(other_account, non_fungible_resource),
+        Some(dec!(2))
+    );
+}
+
+#[test]
+fn cannot_withdraw_batch_from_other_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500u32)
+        .withdraw_batch_from_account(other_account, vec![ResourceSpecifier::Amount(XRD, dec!(1))])
+        .try_deposit_batch_or_refund(account)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
 fn cannot_withdraw_from_other_account_internal(is_virtual: bool) {
     // Arrange
     let mut test_runner = TestRunner::builder().build();
@@ -313,6 +381,56 @@ fn account_created_with_create_advanced_has_an_empty_owner_badge() {
     assert!(is_metadata_empty(&metadata))
 }
 
+#[test]
+fn account_balances_are_returned_in_requested_order() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_account(true);
+    let fungible = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let non_fungible = test_runner.create_non_fungible_resource(account);
+    let (_, _, other_account) = test_runner.new_account(true);
+    let not_held = test_runner.create_fungible_resource(dec!("100"), 18, other_account);
+
+    // Act
+    let balances =
+        test_runner.account_balances(account, vec![non_fungible, XRD, not_held, fungible]);
+
+    // Assert
+    assert_eq!(
+        balances,
+        vec![
+            (non_fungible, dec!("3")),
+            (XRD, test_runner.account_balance(account, XRD).unwrap()),
+            (not_held, Decimal::ZERO),
+            (fungible, dec!("100")),
+        ]
+    );
+}
+
+#[test]
+fn account_non_fungible_ids_can_be_queried() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_account(true);
+    let non_fungible = test_runner.create_non_fungible_resource(account);
+    let (_, _, other_account) = test_runner.new_account(true);
+
+    // Act
+    let ids = test_runner.account_non_fungible_ids(account, non_fungible);
+    let other_ids = test_runner.account_non_fungible_ids(other_account, non_fungible);
+
+    // Assert
+    assert_eq!(
+        ids,
+        btreeset![
+            NonFungibleLocalId::integer(1),
+            NonFungibleLocalId::integer(2),
+            NonFungibleLocalId::integer(3)
+        ]
+    );
+    assert!(other_ids.is_empty());
+}
+
 fn is_metadata_empty(metadata_value: &Option<MetadataValue>) -> bool {
     if let None = metadata_value {
         true
@@ -320,3 +438,22 @@ fn is_metadata_empty(metadata_value: &Option<MetadataValue>) -> bool {
         false
     }
 }
+
+#[test]
+fn can_list_the_vaults_owned_by_an_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let fungible_resource = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let owned_nodes = test_runner.list_owned_nodes(account.into_node_id());
+
+    // Assert
+    for resource_address in [fungible_resource, non_fungible_resource] {
+        let vaults = test_runner.get_component_vaults(account, resource_address);
+        assert_eq!(vaults.len(), 1);
+        assert!(owned_nodes.contains(&vaults[0]));
+    }
+}
@@ -1,3 +1,6 @@
+use radix_engine::blueprints::account::{
+    RemoveResourcePreferenceEvent, SetDefaultDepositRuleEvent, SetResourcePreferenceEvent,
+};
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::transaction::TransactionReceipt;
@@ -439,6 +442,99 @@ fn disallow_all_permits_deposit_of_resource_in_allow_list() {
     }
 }
 
+#[test]
+fn changing_account_default_deposit_rule_emits_an_event() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+
+        // Act
+        let receipt = test_runner
+            .transition_account_default_deposit_rule(AccountDefaultDepositRule::Reject, true);
+
+        // Assert
+        let result = receipt.expect_commit_success();
+        let events = test_runner
+            .test_runner
+            .extract_events_of_type::<SetDefaultDepositRuleEvent>(result);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].default_deposit_rule,
+            AccountDefaultDepositRule::Reject
+        );
+    }
+}
+
+#[test]
+fn adding_a_resource_to_the_allow_list_emits_an_event_and_sets_its_preference() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+        let resource_address = test_runner.freely_mintable_resource();
+
+        // Act
+        let receipt = test_runner.add_to_allow_list(resource_address, true);
+
+        // Assert
+        let result = receipt.expect_commit_success();
+        let events = test_runner
+            .test_runner
+            .extract_events_of_type::<SetResourcePreferenceEvent>(result);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].resource_address, resource_address);
+        assert_eq!(events[0].preference, ResourceDepositRule::Allowed);
+        assert_eq!(
+            test_runner.resource_preference(resource_address),
+            ResourceDepositRule::Allowed
+        );
+    }
+}
+
+#[test]
+fn removing_a_resource_from_the_deny_list_emits_an_event_and_clears_its_preference() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+        let resource_address = test_runner.freely_mintable_resource();
+        test_runner
+            .add_to_deny_list(resource_address, true)
+            .expect_commit_success();
+        assert_eq!(
+            test_runner.resource_preference(resource_address),
+            ResourceDepositRule::Disallowed
+        );
+
+        // Act
+        let receipt = test_runner.remove_from_deny_list(resource_address, true);
+
+        // Assert
+        let result = receipt.expect_commit_success();
+        let events = test_runner
+            .test_runner
+            .extract_events_of_type::<RemoveResourcePreferenceEvent>(result);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].resource_address, resource_address);
+        assert_eq!(
+            test_runner.resource_preference(resource_address),
+            ResourceDepositRule::Neither
+        );
+    }
+}
+
+#[test]
+fn resource_preference_of_an_unconfigured_resource_is_neither() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+
+        // Act
+        let preference = test_runner.resource_preference(XRD);
+
+        // Assert
+        assert_eq!(preference, ResourceDepositRule::Neither);
+    }
+}
+
 struct AccountDepositModesTestRunner {
     test_runner: TestRunner,
     public_key: PublicKey,
@@ -601,6 +697,14 @@ impl AccountDepositModesTestRunner {
         self.configure_resource_deposit_rule(resource_address, ResourceDepositRule::Neither, sign)
     }
 
+    pub fn resource_preference(
+        &mut self,
+        resource_address: ResourceAddress,
+    ) -> ResourceDepositRule {
+        self.test_runner
+            .account_resource_preference(self.component_address, resource_address)
+    }
+
     pub fn virtual_signature_badge(&self) -> NonFungibleGlobalId {
         NonFungibleGlobalId::from_public_key(&self.public_key)
     }
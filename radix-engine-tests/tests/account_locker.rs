@@ -0,0 +1,252 @@
+use radix_engine::blueprints::account::{AccountLockerError, ClaimEvent, StoreEvent};
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+struct AccountLockerTestRunner {
+    test_runner: TestRunner,
+    sender_badge: NonFungibleGlobalId,
+    sender_account: ComponentAddress,
+    claimant_badge: NonFungibleGlobalId,
+    claimant_account: ComponentAddress,
+    resource_address: ResourceAddress,
+    locker: ComponentAddress,
+}
+
+impl AccountLockerTestRunner {
+    fn new() -> Self {
+        let mut test_runner = TestRunner::builder().build();
+        let (sender_key, _, sender_account) = test_runner.new_allocated_account();
+        let (claimant_key, _, claimant_account) = test_runner.new_allocated_account();
+        let sender_badge = NonFungibleGlobalId::from_public_key(&sender_key);
+        let claimant_badge = NonFungibleGlobalId::from_public_key(&claimant_key);
+        let resource_address =
+            test_runner.create_fungible_resource(dec!("100"), 18, sender_account);
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_account_locker(
+                OwnerRole::None,
+                rule!(require(sender_badge.clone())),
+                rule!(require(sender_badge.clone())),
+            )
+            .build();
+        let locker = test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            sender_badge,
+            sender_account,
+            claimant_badge,
+            claimant_account,
+            resource_address,
+            locker,
+        }
+    }
+
+    fn reject_deposits(&mut self) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.claimant_account,
+                ACCOUNT_CHANGE_DEFAULT_DEPOSIT_RULE_IDENT,
+                AccountChangeDefaultDepositRuleInput {
+                    default_deposit_rule: AccountDefaultDepositRule::Reject,
+                },
+            )
+            .build();
+        self.test_runner
+            .execute_manifest(manifest, vec![self.claimant_badge.clone()])
+            .expect_commit_success();
+    }
+
+    fn send_or_store(&mut self, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.sender_account, self.resource_address, amount)
+            .take_all_from_worktop(self.resource_address, "bucket")
+            .send_or_store(self.locker, self.claimant_account, "bucket")
+            .build();
+        self.test_runner
+            .execute_manifest(manifest, vec![self.sender_badge.clone()])
+    }
+
+    fn claim(&mut self, amount: Decimal, sign: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .claim_from_account_locker(
+                self.locker,
+                self.claimant_account,
+                self.resource_address,
+                amount,
+            )
+            .build();
+        let initial_proofs = if sign {
+            vec![self.claimant_badge.clone()]
+        } else {
+            vec![]
+        };
+        self.test_runner.execute_manifest(manifest, initial_proofs)
+    }
+
+    fn stored_amount(&mut self) -> Decimal {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.locker,
+                ACCOUNT_LOCKER_GET_AMOUNT_IDENT,
+                AccountLockerGetAmountManifestInput {
+                    claimant: self.claimant_account,
+                    resource_address: self.resource_address,
+                },
+            )
+            .build();
+        self.test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn claimant_balance(&mut self) -> Option<Decimal> {
+        self.test_runner
+            .account_balance(self.claimant_account, self.resource_address)
+    }
+}
+
+#[test]
+fn send_or_store_deposits_directly_into_accepting_account() {
+    // Arrange
+    let mut test_runner = AccountLockerTestRunner::new();
+
+    // Act
+    let receipt = test_runner.send_or_store(dec!("10"));
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert!(test_runner
+        .test_runner
+        .extract_events_of_type::<StoreEvent>(result)
+        .is_empty());
+    assert_eq!(test_runner.claimant_balance(), Some(dec!("10")));
+    assert_eq!(test_runner.stored_amount(), Decimal::ZERO);
+}
+
+#[test]
+fn send_or_store_stores_resources_rejected_by_account() {
+    // Arrange
+    let mut test_runner = AccountLockerTestRunner::new();
+    test_runner.reject_deposits();
+
+    // Act
+    let receipt = test_runner.send_or_store(dec!("10"));
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let events = test_runner
+        .test_runner
+        .extract_events_of_type::<StoreEvent>(result);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].claimant, test_runner.claimant_account);
+    assert_eq!(events[0].amount, dec!("10"));
+    assert_eq!(test_runner.stored_amount(), dec!("10"));
+}
+
+#[test]
+fn claimant_can_claim_stored_resources() {
+    // Arrange
+    let mut test_runner = AccountLockerTestRunner::new();
+    test_runner.reject_deposits();
+    test_runner
+        .send_or_store(dec!("10"))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.claim(dec!("4"), true);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .test_runner
+            .extract_events_of_type::<ClaimEvent>(result)
+            .len(),
+        1
+    );
+    assert_eq!(test_runner.claimant_balance(), Some(dec!("4")));
+    assert_eq!(test_runner.stored_amount(), dec!("6"));
+}
+
+#[test]
+fn claim_without_claimant_signature_fails() {
+    // Arrange
+    let mut test_runner = AccountLockerTestRunner::new();
+    test_runner.reject_deposits();
+    test_runner
+        .send_or_store(dec!("10"))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.claim(dec!("4"), false);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn claim_with_nothing_stored_fails() {
+    // Arrange
+    let mut test_runner = AccountLockerTestRunner::new();
+
+    // Act
+    let receipt = test_runner.claim(dec!("4"), true);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountLockerError(
+                AccountLockerError::NothingStoredForClaimant { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn sender_can_recover_unclaimed_resources() {
+    // Arrange
+    let mut test_runner = AccountLockerTestRunner::new();
+    test_runner.reject_deposits();
+    test_runner
+        .send_or_store(dec!("10"))
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recover_from_account_locker(
+            test_runner.locker,
+            test_runner.claimant_account,
+            test_runner.resource_address,
+            dec!("10"),
+        )
+        .try_deposit_batch_or_abort(test_runner.sender_account)
+        .build();
+    let receipt = test_runner
+        .test_runner
+        .execute_manifest(manifest, vec![test_runner.sender_badge.clone()]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.stored_amount(), Decimal::ZERO);
+    assert_eq!(
+        test_runner
+            .test_runner
+            .account_balance(test_runner.sender_account, test_runner.resource_address),
+        Some(dec!("100"))
+    );
+}
@@ -215,3 +215,33 @@ fn test_create_and_store_in_metadata() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn test_address_of_manifest_reservation_can_be_previewed_before_globalization() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package = test_runner.compile_and_publish("./tests/blueprints/allocated_address");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .allocate_global_address(package, "AllocatedAddressTest", "reservation", "address")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_function(
+                package,
+                "AllocatedAddressTest",
+                "globalize_with_address_preview",
+                manifest_args!(lookup.address_reservation("reservation")),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let previewed_address: ComponentAddress = commit_result.output(2);
+    assert_eq!(
+        commit_result.new_component_addresses(),
+        &vec![previewed_address]
+    );
+}
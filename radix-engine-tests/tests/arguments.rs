@@ -35,18 +35,17 @@ fn tuple_of_buckets_argument_should_succeed() {
     let package_address = test_runner.compile_and_publish("./tests/blueprints/arguments");
 
     // Act
-    let manifest = ManifestBuilder::new()
+    let (builder, lookup) = ManifestBuilder::new_with_name_lookup();
+    let manifest = builder
         .lock_fee_from_faucet()
         .take_all_from_worktop(XRD, "bucket1")
         .take_all_from_worktop(XRD, "bucket2")
-        .with_name_lookup(|builder, lookup| {
-            builder.call_function(
-                package_address,
-                "Arguments",
-                "tuple_argument",
-                manifest_args!((lookup.bucket("bucket1"), lookup.bucket("bucket2"))),
-            )
-        })
+        .call_function(
+            package_address,
+            "Arguments",
+            "tuple_argument",
+            manifest_args!((lookup.bucket("bucket1"), lookup.bucket("bucket2"))),
+        )
         .build();
     let receipt = test_runner.execute_manifest(manifest, vec![]);
 
@@ -119,17 +118,16 @@ fn some_optional_bucket_argument_should_succeed() {
     let package_address = test_runner.compile_and_publish("./tests/blueprints/arguments");
 
     // Act
-    let manifest = ManifestBuilder::new()
+    let (builder, lookup) = ManifestBuilder::new_with_name_lookup();
+    let manifest = builder
         .lock_fee_from_faucet()
         .take_all_from_worktop(XRD, "bucket1")
-        .with_name_lookup(|builder, lookup| {
-            builder.call_function(
-                package_address,
-                "Arguments",
-                "option_argument",
-                manifest_args!(Some(lookup.bucket("bucket1"))),
-            )
-        })
+        .call_function(
+            package_address,
+            "Arguments",
+            "option_argument",
+            manifest_args!(Some(lookup.bucket("bucket1"))),
+        )
         .build();
     let receipt = test_runner.execute_manifest(manifest, vec![]);
 
@@ -319,6 +319,62 @@ fn can_update_updatable_owner_role_account() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn can_update_signature_gated_owner_role_account_without_badge() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _) = test_runner.new_key_pair();
+    let (other_public_key, _) = test_runner.new_key_pair();
+    let account = test_runner.new_account_advanced(OwnerRole::Updatable(
+        AccessRule::require_signature(public_key.get_hash()),
+    ));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_owner_role(
+            account,
+            rule!(require(signature(other_public_key.get_hash()))),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest.clone(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn cannot_update_signature_gated_owner_role_account_without_signature() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _) = test_runner.new_key_pair();
+    let (other_public_key, _) = test_runner.new_key_pair();
+    let account = test_runner.new_account_advanced(OwnerRole::Updatable(
+        AccessRule::require_signature(public_key.get_hash()),
+    ));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_owner_role(account, AccessRule::AllowAll)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&other_public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
 #[test]
 fn cannot_set_royalty_on_accounts() {
     // Arrange
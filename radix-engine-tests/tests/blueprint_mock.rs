@@ -0,0 +1,83 @@
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn call_get_value_via_package_call(
+    test_runner: &mut TestRunner,
+    package_address: PackageAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ExternalBlueprintTarget",
+            "get_value_via_package_call",
+            manifest_args!(),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn mocked_blueprint_returns_canned_output() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    test_runner.mock_blueprint(
+        package_address,
+        "ExternalBlueprintTarget",
+        |ident, _input| match ident {
+            "get_value_via_package_call" => Ok(IndexedScryptoValue::from_typed("MOCKED")),
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(ident.to_string()),
+            )),
+        },
+    );
+
+    // Act
+    let receipt = call_get_value_via_package_call(&mut test_runner, package_address);
+
+    // Assert
+    let output: String = receipt.expect_commit_success().output(1);
+    assert_eq!(output, "MOCKED");
+}
+
+#[test]
+fn unmocked_blueprint_runs_its_code() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    test_runner.mock_blueprint(package_address, "ExternalBlueprintTarget", |_, _| {
+        Ok(IndexedScryptoValue::from_typed("MOCKED"))
+    });
+    test_runner.unmock_blueprint(package_address, "ExternalBlueprintTarget");
+
+    // Act
+    let receipt = call_get_value_via_package_call(&mut test_runner, package_address);
+
+    // Assert
+    let output: String = receipt.expect_commit_success().output(1);
+    assert_eq!(output, "SUCCESS");
+}
+
+#[test]
+fn mocked_blueprint_output_is_validated_against_schema() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    test_runner.mock_blueprint(package_address, "ExternalBlueprintTarget", |_, _| {
+        Ok(IndexedScryptoValue::from_typed(&1u32))
+    });
+
+    // Act
+    let receipt = call_get_value_via_package_call(&mut test_runner, package_address);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::PayloadValidationAgainstSchemaError(..))
+        )
+    });
+}
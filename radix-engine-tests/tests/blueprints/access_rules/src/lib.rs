@@ -1,3 +1,5 @@
 mod assert_access_rule;
 mod function_access_rules;
 mod mutable_access_rules;
+mod pausable;
+mod state_gated_access_rules;
@@ -0,0 +1,41 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+#[pausable]
+mod pausable_counter {
+    enable_method_auth! {
+        methods {
+            increment => PUBLIC;
+            count => PUBLIC;
+        }
+    }
+
+    struct PausableCounter {
+        pausable: Pausable,
+        count: u32,
+    }
+
+    impl PausableCounter {
+        pub fn new(pauser: AccessRule) -> Global<PausableCounter> {
+            Self {
+                pausable: Pausable::new(),
+                count: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .roles(roles! {
+                pauser => pauser;
+            })
+            .globalize()
+        }
+
+        pub fn increment(&mut self) {
+            when_not_paused!(self);
+            self.count += 1;
+        }
+
+        pub fn count(&self) -> u32 {
+            self.count
+        }
+    }
+}
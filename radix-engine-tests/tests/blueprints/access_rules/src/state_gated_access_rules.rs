@@ -0,0 +1,59 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod state_gated_access_rules {
+    enable_method_auth! {
+        roles {
+            operator => updatable_by: [];
+        },
+        methods {
+            operate => restrict_to: [operator];
+            is_paused => PUBLIC;
+            set_paused => PUBLIC;
+        }
+    }
+
+    struct StateGatedAccessRules {
+        paused: bool,
+    }
+
+    impl StateGatedAccessRules {
+        pub fn new(gate_function: String) -> Global<StateGatedAccessRules> {
+            Self { paused: false }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .roles(roles! {
+                    operator => AccessRule::StateGated(StateGate::new(
+                        Runtime::package_address(),
+                        "StateGatedAccessRules",
+                        gate_function,
+                    ));
+                })
+                .globalize()
+        }
+
+        pub fn not_paused(component: Global<StateGatedAccessRules>) -> bool {
+            !component.is_paused()
+        }
+
+        pub fn unpause_and_allow(component: Global<StateGatedAccessRules>) -> bool {
+            component.set_paused(false);
+            true
+        }
+
+        pub fn operate_and_allow(component: Global<StateGatedAccessRules>) -> bool {
+            component.operate();
+            true
+        }
+
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        pub fn set_paused(&mut self, paused: bool) {
+            self.paused = paused;
+        }
+
+        pub fn operate(&self) {}
+    }
+}
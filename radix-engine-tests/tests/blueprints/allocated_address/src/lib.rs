@@ -83,6 +83,13 @@ mod apa {
                 .globalize();
         }
 
+        pub fn globalize_with_address_preview(own: GlobalAddressReservation) -> ComponentAddress {
+            let address = own.component_address();
+            info!("Globalizing at: {:?}", address);
+            Self::globalize_with_preallocated_address(own);
+            address
+        }
+
         pub fn globalize_with_preallocated_address(own: GlobalAddressReservation) {
             Self { store: None }
                 .instantiate()
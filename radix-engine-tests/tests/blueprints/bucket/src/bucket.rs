@@ -150,6 +150,17 @@ mod bucket_test {
             (bucket, x)
         }
 
+        pub fn take_from_bucket_advanced(
+            mut bucket: Bucket,
+            amount: Decimal,
+            withdraw_strategy: WithdrawStrategy,
+        ) -> (Bucket, Decimal) {
+            let x = bucket.take_advanced(amount, withdraw_strategy);
+            let taken = x.amount();
+            bucket.put(x);
+            (bucket, taken)
+        }
+
         pub fn create_empty_bucket_fungible() -> Bucket {
             Bucket::new(XRD)
         }
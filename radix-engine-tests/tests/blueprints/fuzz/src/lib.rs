@@ -0,0 +1,36 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod fuzz_target {
+    struct FuzzTarget {
+        vaults: Vec<Vault>,
+        total: u64,
+    }
+
+    impl FuzzTarget {
+        pub fn new() -> Global<FuzzTarget> {
+            Self {
+                vaults: Vec::new(),
+                total: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn deposit(&mut self, bucket: Bucket) {
+            self.vaults.push(Vault::with_bucket(bucket));
+        }
+
+        pub fn record(&mut self, amounts: Vec<u32>, label: String) {
+            for amount in amounts {
+                self.total += amount as u64;
+            }
+            info!("Recorded {}: {}", label, self.total);
+        }
+
+        pub fn divide(&self, dividend: u32, divisor: u32) -> u32 {
+            dividend / divisor
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod queue_test {
+    struct QueueTest {
+        queue: Queue<u32>,
+    }
+
+    impl QueueTest {
+        pub fn new() -> Global<QueueTest> {
+            Self {
+                queue: Queue::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn push_back(&mut self, item: u32) {
+            self.queue.push_back(item);
+        }
+
+        pub fn pop_front(&mut self) -> Option<u32> {
+            self.queue.pop_front()
+        }
+
+        pub fn front(&self) -> Option<u32> {
+            self.queue.front().map(|item| *item)
+        }
+
+        pub fn len(&self) -> u64 {
+            self.queue.len()
+        }
+    }
+}
+
+#[blueprint]
+mod ring_buffer_test {
+    struct RingBufferTest {
+        buffer: RingBuffer<u32>,
+    }
+
+    impl RingBufferTest {
+        pub fn new(capacity: u64) -> Global<RingBufferTest> {
+            Self {
+                buffer: RingBuffer::with_capacity(capacity),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn push(&mut self, item: u32) -> Option<u32> {
+            self.buffer.push(item)
+        }
+
+        pub fn pop(&mut self) -> Option<u32> {
+            self.buffer.pop()
+        }
+
+        pub fn get(&self, index: u64) -> Option<u32> {
+            self.buffer.get(index).map(|item| *item)
+        }
+
+        pub fn len(&self) -> u64 {
+            self.buffer.len()
+        }
+    }
+}
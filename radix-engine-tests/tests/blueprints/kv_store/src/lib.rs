@@ -1,4 +1,5 @@
 pub mod basic;
+pub mod collections;
 pub mod cyclic_map;
 pub mod kv_store;
 pub mod nested_kv_stores;
@@ -11,6 +11,10 @@ mod logger {
             info!("{}", message)
         }
 
+        pub fn emit_metric(name: String) {
+            Runtime::emit_metric(name, dec!("1.5"))
+        }
+
         pub fn rust_panic(message: String) {
             panic!("{}", message)
         }
@@ -1,3 +1,5 @@
+use scrypto::api::{BatchCall, OBJECT_HANDLE_SELF};
+use scrypto::engine::scrypto_env::ScryptoEnv;
 use scrypto::prelude::*;
 
 #[blueprint]
@@ -41,5 +43,36 @@ mod metadata_component {
         pub fn remove_metadata(global: Global<MetadataComponent>, key: String) {
             global.remove_metadata(key);
         }
+
+        pub fn read_metadata(address: ComponentAddress, key: String) -> Option<String> {
+            Runtime::get_metadata(address, key).ok()
+        }
+
+        pub fn batch_read(&self, keys: Vec<String>) -> Vec<Option<String>> {
+            let address = Runtime::global_address();
+            let mut calls = vec![BatchCall::ActorFieldRead {
+                object_handle: OBJECT_HANDLE_SELF,
+                field: 0u8,
+            }];
+            for key in keys {
+                calls.push(BatchCall::GetMetadata {
+                    address: address.into(),
+                    key,
+                });
+            }
+
+            let results = ScryptoEnv.batch_invoke(&calls).unwrap();
+            let _state: MetadataComponent = scrypto_decode(&results[0]).unwrap();
+            results[1..]
+                .iter()
+                .map(|result| {
+                    let value: Option<MetadataValue> = scrypto_decode(result).unwrap();
+                    match value {
+                        Some(MetadataValue::String(value)) => Some(value),
+                        _ => None,
+                    }
+                })
+                .collect()
+        }
     }
 }
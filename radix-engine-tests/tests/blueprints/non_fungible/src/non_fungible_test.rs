@@ -392,6 +392,50 @@ mod non_fungible_test {
             non_fungible_bucket
         }
 
+        pub fn create_vault_with_non_fungibles(count: u64) -> Global<NonFungibleTest> {
+            let bucket = ResourceBuilder::new_integer_non_fungible(OwnerRole::None)
+                .mint_initial_supply((1..=count).map(|i| {
+                    (
+                        i.into(),
+                        Sandwich {
+                            name: format!("Sandwich {}", i),
+                            available: true,
+                            tastes_great: true,
+                            reference: None,
+                            own: None,
+                        },
+                    )
+                }));
+
+            NonFungibleTest {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn take_and_list_non_fungible_local_ids_in_pages(
+            &mut self,
+            take: u32,
+            limit: u32,
+        ) -> (Bucket, Vec<Vec<NonFungibleLocalId>>) {
+            let bucket = self.vault.take(take);
+            let vault = self.vault.as_non_fungible();
+
+            let mut pages: Vec<Vec<NonFungibleLocalId>> = Vec::new();
+            loop {
+                let cursor = pages.last().and_then(|page| page.last());
+                let page = vault.non_fungible_local_ids_page(limit, cursor);
+                if page.is_empty() {
+                    break;
+                }
+                pages.push(page);
+            }
+
+            (bucket, pages)
+        }
+
         pub fn get_non_fungible_local_id_vault() -> Bucket {
             let mut vault = Vault::with_bucket(Self::create_non_fungible_fixed());
             let non_fungible_bucket = vault.take(1);
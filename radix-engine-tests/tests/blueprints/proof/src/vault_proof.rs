@@ -135,5 +135,55 @@ mod vault_proof {
                 });
             self.vault.put(bucket);
         }
+
+        pub fn compose_vault_and_buckets_proof_by_amount(
+            &mut self,
+            buckets: Vec<Bucket>,
+            amount: Decimal,
+        ) {
+            let resource_address = self.vault.resource_address();
+            for bucket in &buckets {
+                LocalAuthZone::push(bucket.create_proof_of_all());
+            }
+            self.vault.as_fungible().authorize_with_amount(dec!(1), || {
+                let proof = LocalAuthZone::compose_proof_of_amount(resource_address, amount)
+                    .skip_checking();
+                assert_eq!(proof.resource_address(), resource_address);
+                assert_eq!(proof.amount(), amount);
+                proof.drop();
+            });
+            LocalAuthZone::clear();
+            for bucket in buckets {
+                self.vault.put(bucket);
+            }
+        }
+
+        pub fn compose_vault_and_buckets_proof_by_ids(
+            &mut self,
+            buckets: Vec<Bucket>,
+            ids: BTreeSet<NonFungibleLocalId>,
+        ) {
+            let resource_address = self.vault.resource_address();
+            for bucket in &buckets {
+                LocalAuthZone::push(bucket.create_proof_of_all());
+            }
+            let vault_ids = self.vault.as_non_fungible().non_fungible_local_ids();
+            self.vault
+                .as_non_fungible()
+                .authorize_with_non_fungibles(&vault_ids, || {
+                    let proof = LocalAuthZone::compose_proof_of_non_fungibles(
+                        resource_address,
+                        ids.clone(),
+                    )
+                    .skip_checking();
+                    assert_eq!(proof.resource_address(), resource_address);
+                    assert_eq!(proof.as_non_fungible().non_fungible_local_ids(), ids);
+                    proof.drop();
+                });
+            LocalAuthZone::clear();
+            for bucket in buckets {
+                self.vault.put(bucket);
+            }
+        }
     }
 }
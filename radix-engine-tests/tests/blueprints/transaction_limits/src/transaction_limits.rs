@@ -40,6 +40,13 @@ mod transaction_limits {
             }
         }
 
+        pub fn create_empty_buckets(n: u32) {
+            let buckets: Vec<Bucket> = (0..n).map(|_| Bucket::new(XRD)).collect();
+            for bucket in buckets {
+                bucket.drop_empty();
+            }
+        }
+
         pub fn recursive_with_memory(n: u32, m: usize) {
             if n > 1 {
                 let _v: Vec<u8> = Vec::with_capacity(m);
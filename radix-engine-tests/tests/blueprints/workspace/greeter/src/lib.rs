@@ -0,0 +1,12 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod greeter {
+    struct Greeter {}
+
+    impl Greeter {
+        pub fn greet() -> String {
+            "Hello".to_owned()
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod greeter_caller {
+    const GREETER_PACKAGE: PackageAddress = address!(env!("SCRYPTO_PACKAGE_ADDRESS_GREETER"));
+
+    extern_blueprint!(
+        GREETER_PACKAGE,
+        Greeter {
+            fn greet() -> String;
+        }
+    );
+
+    struct GreeterCaller {}
+
+    impl GreeterCaller {
+        pub fn call_greet() -> String {
+            Blueprint::<Greeter>::greet()
+        }
+    }
+}
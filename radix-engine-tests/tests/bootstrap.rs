@@ -378,6 +378,123 @@ fn test_genesis_time() {
     assert_eq!(proposer_minute_timestamp.epoch_minute, 123);
 }
 
+#[test]
+fn test_genesis_xrd_balances_can_be_streamed_in_chunks() {
+    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    let balance = dec!("10");
+    let accounts: Vec<ComponentAddress> = (1..=5u64)
+        .map(|i| {
+            ComponentAddress::virtual_account_from_public_key(
+                &Secp256k1PrivateKey::from_u64(i).unwrap().public_key(),
+            )
+        })
+        .collect();
+    let genesis_data_chunks = GenesisDataChunk::xrd_balances_in_chunks(
+        accounts
+            .clone()
+            .into_iter()
+            .map(|account| (account, balance)),
+        2,
+    );
+
+    let mut bootstrapper = Bootstrapper::new(&mut substate_db, &scrypto_vm, false);
+
+    let mut balance_changes = index_map_new();
+    let mut ingested_chunk_indices = vec![];
+    let GenesisReceipts {
+        data_ingestion_receipts,
+        ..
+    } = bootstrapper
+        .bootstrap_with_genesis_data_stream(
+            genesis_data_chunks,
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+            1,
+            Some(0),
+            Decimal::zero(),
+            |chunk_index, receipt| {
+                ingested_chunk_indices.push(chunk_index);
+                balance_changes.extend(
+                    receipt
+                        .expect_commit_success()
+                        .state_update_summary
+                        .balance_changes
+                        .clone(),
+                );
+            },
+        )
+        .unwrap();
+
+    assert!(data_ingestion_receipts.is_empty());
+    assert_eq!(ingested_chunk_indices, vec![0, 1, 2]);
+    for account in accounts {
+        assert_eq!(
+            balance_changes
+                .get(&GlobalAddress::from(account))
+                .unwrap()
+                .get(&XRD)
+                .unwrap(),
+            &BalanceChange::Fungible(balance)
+        );
+    }
+}
+
+#[test]
+fn test_runner_can_be_bootstrapped_from_encoded_genesis_data_chunks() {
+    // Arrange
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let resource_address = ResourceAddress::new_or_panic(
+        NodeId::new(
+            EntityType::GlobalFungibleResourceManager as u8,
+            &hash(vec![4, 5, 6]).lower_bytes(),
+        )
+        .0,
+    );
+    let genesis_data_chunks = vec![
+        GenesisDataChunk::Resources(vec![GenesisResource {
+            reserved_resource_address: resource_address,
+            metadata: vec![(
+                "symbol".to_string(),
+                MetadataValue::String("TST".to_string()),
+            )],
+            owner: None,
+        }]),
+        GenesisDataChunk::ResourceBalances {
+            accounts: vec![account],
+            allocations: vec![(
+                resource_address,
+                vec![GenesisResourceAllocation {
+                    account_index: 0,
+                    amount: dec!("42"),
+                }],
+            )],
+        },
+    ];
+    let encoded = scrypto_encode(&genesis_data_chunks).unwrap();
+
+    // Act
+    let mut test_runner = TestRunner::builder()
+        .with_custom_genesis(CustomGenesis::from_data_chunks(
+            scrypto_decode(&encoded).unwrap(),
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .build();
+
+    // Assert
+    assert_eq!(
+        test_runner.account_balance(account, resource_address),
+        Some(dec!("42"))
+    );
+    assert_eq!(
+        test_runner.get_metadata(resource_address.into(), "symbol"),
+        Some(MetadataValue::String("TST".to_string()))
+    );
+}
+
 #[test]
 fn should_not_be_able_to_create_genesis_helper() {
     // Arrange
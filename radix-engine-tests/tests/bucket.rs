@@ -2,6 +2,7 @@ use radix_engine::{
     blueprints::resource::BucketError,
     errors::{ApplicationError, CallFrameError, KernelError, RuntimeError},
     kernel::call_frame::DropNodeError,
+    transaction::TransactionReceipt,
     types::*,
 };
 use scrypto_unit::*;
@@ -160,6 +161,70 @@ fn test_take_with_invalid_granularity() {
     });
 }
 
+fn take_from_bucket_advanced(
+    amount: Decimal,
+    withdraw_strategy: WithdrawStrategy,
+) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 2, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/bucket");
+
+    let manifest = ManifestBuilder::new()
+        .lock_standard_test_fee(account)
+        .withdraw_from_account(account, resource_address, 100)
+        .take_all_from_worktop(resource_address, "bucket")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_function(
+                package_address,
+                "BucketTest",
+                "take_from_bucket_advanced",
+                manifest_args!(lookup.bucket("bucket"), amount, withdraw_strategy),
+            )
+        })
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn test_take_advanced_rounds_amount_to_divisibility() {
+    // Act
+    let floor_receipt = take_from_bucket_advanced(
+        dec!("1.123"),
+        WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+    );
+    let ceil_receipt = take_from_bucket_advanced(
+        dec!("1.123"),
+        WithdrawStrategy::Rounded(RoundingMode::ToPositiveInfinity),
+    );
+
+    // Assert
+    let (_, floor_amount): (Bucket, Decimal) = floor_receipt.expect_commit_success().output(3);
+    let (_, ceil_amount): (Bucket, Decimal) = ceil_receipt.expect_commit_success().output(3);
+    assert_eq!(floor_amount, dec!("1.12"));
+    assert_eq!(ceil_amount, dec!("1.13"));
+}
+
+#[test]
+fn test_take_advanced_with_exact_strategy_rejects_invalid_granularity() {
+    // Act
+    let receipt = take_from_bucket_advanced(dec!("1.123"), WithdrawStrategy::Exact);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::BucketError(
+                BucketError::InvalidAmount,
+            ))
+        )
+    });
+}
+
 #[test]
 fn test_take_with_negative_amount() {
     // Arrange
@@ -1,9 +1,12 @@
 use radix_engine::blueprints::consensus_manager::{
-    Validator, ValidatorEmissionAppliedEvent, ValidatorError,
+    ConfigUpdateEvent, EmissionConfigUpdateEvent, EpochEmissionSummary, Validator,
+    ValidatorEmissionAppliedEvent, ValidatorError, ValidatorFeeChangeRequestEvent,
+    ValidatorSetChangeEvent,
 };
 use radix_engine::blueprints::resource::BucketError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
 use radix_engine::system::bootstrap::*;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::blueprints::consensus_manager::*;
@@ -233,6 +236,179 @@ fn next_round_causes_epoch_change_on_reaching_max_rounds() {
     assert_eq!(next_epoch, initial_epoch.next());
 }
 
+#[test]
+fn updated_epoch_change_condition_is_effective_from_next_round() {
+    // Arrange
+    let genesis_epoch = Epoch::of(5);
+    let initial_epoch = genesis_epoch.next();
+    let genesis = CustomGenesis::default(
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+            EpochChangeCondition {
+                min_round_count: 0,
+                max_round_count: 100,
+                target_duration_millis: 1000,
+            },
+        ),
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+
+    // Act
+    let epoch_change_condition = EpochChangeCondition {
+        min_round_count: 0,
+        max_round_count: 1,
+        target_duration_millis: 1000,
+    };
+    let transaction = SystemTransactionBuilder::new()
+        .update_consensus_config(epoch_change_condition.clone(), 10)
+        .build(hash("update consensus config"));
+    let receipt = test_runner.execute_transaction(
+        transaction
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!(AuthAddresses::validator_role())),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_system_transaction(),
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.extract_events_of_type::<ConfigUpdateEvent>(result),
+        vec![ConfigUpdateEvent {
+            epoch_change_condition,
+            max_validators: 10,
+        }]
+    );
+    let receipt = test_runner.advance_to_round_at_timestamp(Round::of(1), 1);
+    let result = receipt.expect_commit_success();
+    let next_epoch = result.next_epoch().expect("Should have next epoch").epoch;
+    assert_eq!(next_epoch, initial_epoch.next());
+}
+
+#[test]
+fn update_consensus_config_fails_if_min_round_count_exceeds_max_round_count() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let transaction = SystemTransactionBuilder::new()
+        .update_consensus_config(
+            EpochChangeCondition {
+                min_round_count: 10,
+                max_round_count: 5,
+                target_duration_millis: 1000,
+            },
+            10,
+        )
+        .build(hash("update consensus config"));
+    let receipt = test_runner.execute_transaction(
+        transaction
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!(AuthAddresses::validator_role())),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_system_transaction(),
+    );
+
+    // Assert
+    let error = receipt.expect_failure();
+    assert_eq!(
+        error,
+        &RuntimeError::ApplicationError(ApplicationError::ConsensusManagerError(
+            ConsensusManagerError::InvalidEpochChangeCondition {
+                min_round_count: 10,
+                max_round_count: 5,
+            }
+        ))
+    );
+}
+
+#[test]
+fn updated_emission_config_is_applied_on_next_epoch_change() {
+    // Arrange
+    let genesis_epoch = Epoch::of(5);
+    let rounds_per_epoch = 1;
+    let validator_pub_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let validator_stake = dec!("500.0");
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_pub_key,
+        validator_stake,
+        ComponentAddress::virtual_account_from_public_key(&validator_pub_key),
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config()
+            .with_epoch_change_condition(EpochChangeCondition {
+                min_round_count: rounds_per_epoch,
+                max_round_count: rounds_per_epoch,
+                target_duration_millis: 1000,
+            })
+            .with_total_emission_xrd_per_epoch(dec!("10")),
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+
+    // Act
+    let transaction = SystemTransactionBuilder::new()
+        .update_emission_config(dec!("20"), dec!("0.5"))
+        .build(hash("update emission config"));
+    let receipt = test_runner.execute_transaction(
+        transaction
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!(AuthAddresses::validator_role())),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_system_transaction(),
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.extract_events_of_type::<EmissionConfigUpdateEvent>(result),
+        vec![EmissionConfigUpdateEvent {
+            total_emission_xrd_per_epoch: dec!("20"),
+            min_validator_reliability: dec!("0.5"),
+        }]
+    );
+    let receipt = test_runner.advance_to_round(Round::of(rounds_per_epoch));
+    let result = receipt.expect_commit_success();
+    let emission = result
+        .next_epoch()
+        .expect("Should have next epoch")
+        .emission;
+    assert_eq!(emission.total_emission_xrd_per_epoch, dec!("20"));
+    assert_eq!(emission.min_validator_reliability, dec!("0.5"));
+    assert_eq!(emission.effective_total_emission_xrd, dec!("20"));
+}
+
+#[test]
+fn update_emission_config_fails_if_min_validator_reliability_exceeds_one() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let transaction = SystemTransactionBuilder::new()
+        .update_emission_config(dec!("10"), dec!("1.5"))
+        .build(hash("update emission config"));
+    let receipt = test_runner.execute_transaction(
+        transaction
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!(AuthAddresses::validator_role())),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_system_transaction(),
+    );
+
+    // Assert
+    let error = receipt.expect_failure();
+    assert_eq!(
+        error,
+        &RuntimeError::ApplicationError(ApplicationError::ConsensusManagerError(
+            ConsensusManagerError::InvalidMinValidatorReliability {
+                min_validator_reliability: dec!("1.5"),
+            }
+        ))
+    );
+}
+
 #[test]
 fn next_round_fails_if_time_moves_backward() {
     // Arrange
@@ -972,6 +1148,23 @@ fn validator_receives_emission_penalty_when_some_proposals_missed() {
         },]
     );
 
+    // Assert: the next epoch event also summarizes the applied emissions
+    let validator_address = test_runner.get_active_validator_with_key(&validator_pub_key);
+    let mut validator_emissions_xrd = index_map_new();
+    validator_emissions_xrd.insert(validator_address, validator_stake_added);
+    assert_eq!(
+        result
+            .next_epoch()
+            .expect("Should have next epoch")
+            .emission,
+        EpochEmissionSummary {
+            total_emission_xrd_per_epoch: epoch_emissions_xrd,
+            min_validator_reliability: min_required_reliability,
+            effective_total_emission_xrd: validator_stake_added,
+            validator_emissions_xrd,
+        }
+    );
+
     // Assert: emitted event gives the details/breakdown
     assert_eq!(
         test_runner.extract_events_of_type::<ValidatorEmissionAppliedEvent>(result),
@@ -1326,6 +1519,87 @@ fn increasing_validator_fee_takes_effect_after_configured_epochs_delay() {
     assert_eq!(event.proposals_missed, 0);
 }
 
+#[test]
+fn scheduled_validator_fee_change_is_pending_until_requested_epoch() {
+    // Arrange
+    let genesis_epoch = Epoch::of(7);
+    let initial_epoch = genesis_epoch.next();
+    let delay_epochs = 3;
+    let new_fee_factor = dec!("0.5");
+    let validator_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let validator_account = ComponentAddress::virtual_account_from_public_key(&validator_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_key,
+        dec!("10.0"),
+        validator_account,
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+            EpochChangeCondition {
+                min_round_count: 1,
+                max_round_count: 1, // deliberate, to go through rounds/epoch without gaps
+                target_duration_millis: 0,
+            },
+        ),
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator_address = test_runner.get_active_validator_with_key(&validator_key);
+
+    // Act: schedule a fee decrease later than the minimum delay of 1 epoch
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            validator_account,
+            VALIDATOR_OWNER_BADGE,
+            &btreeset!(NonFungibleLocalId::bytes(validator_address.as_node_id().0).unwrap()),
+        )
+        .schedule_validator_fee_change(validator_address, new_fee_factor, delay_epochs)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_key)],
+    );
+
+    // Assert
+    let epoch_effective = initial_epoch.after(delay_epochs);
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.extract_events_of_type::<ValidatorFeeChangeRequestEvent>(result),
+        vec![ValidatorFeeChangeRequestEvent {
+            new_fee_factor,
+            epoch_effective,
+        }]
+    );
+    let expected_request = Some(ValidatorFeeChangeRequest {
+        epoch_effective,
+        new_fee_factor,
+    });
+    assert_eq!(
+        test_runner.get_validator_pending_fee_change(validator_address),
+        expected_request
+    );
+
+    // Act: advance to the epoch at which the change becomes effective
+    for _ in 1..delay_epochs {
+        test_runner
+            .advance_to_round(Round::of(1))
+            .expect_commit_success();
+        assert_eq!(
+            test_runner.get_validator_pending_fee_change(validator_address),
+            expected_request
+        );
+    }
+    test_runner
+        .advance_to_round(Round::of(1))
+        .expect_commit_success();
+
+    // Assert
+    assert_eq!(test_runner.get_current_epoch(), epoch_effective);
+    assert_eq!(
+        test_runner.get_validator_pending_fee_change(validator_address),
+        None
+    );
+}
+
 fn create_custom_genesis(
     initial_epoch: Epoch,
     rounds_per_epoch: u64,
@@ -1768,6 +2042,14 @@ fn unregistered_validator_gets_removed_on_epoch_change() {
         .validator_set
         .validators_by_stake_desc
         .contains_key(&validator_address));
+    assert_eq!(
+        test_runner.extract_events_of_type::<ValidatorSetChangeEvent>(result),
+        vec![ValidatorSetChangeEvent {
+            epoch: initial_epoch.next(),
+            added_validators: index_map_new(),
+            removed_validators: indexset!(validator_address),
+        }]
+    );
 }
 
 #[test]
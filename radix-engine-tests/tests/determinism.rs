@@ -74,3 +74,42 @@ fn create_and_pass_multiple_proofs() -> Hash {
 
     test_runner.get_state_hash()
 }
+
+#[test]
+fn test_runner_cluster_executes_consistently() {
+    // Arrange
+    let mut cluster = TestRunnerCluster::new(3);
+    let (public_key, _, account) = cluster.new_allocated_account();
+    let resource_address = cluster.run(|test_runner| {
+        test_runner.create_fungible_resource(100.into(), DIVISIBILITY_MAXIMUM, account)
+    });
+    let package_address = cluster.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+    let mut proof_ids: Vec<_> = vec![];
+    for _ in 0..5 {
+        let proof_name = builder.generate_proof_name("proof");
+        builder = builder
+            .create_proof_from_account_of_amount(account, resource_address, 1)
+            .pop_from_auth_zone(&proof_name);
+
+        proof_ids.push(builder.proof(proof_name));
+    }
+    let manifest = builder
+        .call_function(
+            package_address,
+            "VaultProof",
+            "receive_proofs",
+            manifest_args!(proof_ids),
+        )
+        .build();
+    let receipt = cluster.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(cluster.size(), 3);
+}
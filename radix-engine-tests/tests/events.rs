@@ -7,7 +7,10 @@ use radix_engine::blueprints::resource::*;
 use radix_engine::errors::{
     ApplicationError, PayloadValidationAgainstSchemaError, RuntimeError, SystemError,
 };
-use radix_engine::system::node_modules::metadata::SetMetadataEvent;
+use radix_engine::system::event_schema_resolver::{
+    DecodedEvent, EventDecodeError, EventSchemaResolver,
+};
+use radix_engine::system::node_modules::metadata::{LockMetadataEvent, SetMetadataEvent};
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::auth::{RoleDefinition, ToRoleEntry};
 use radix_engine_interface::api::node_modules::metadata::MetadataValue;
@@ -222,52 +225,17 @@ fn vault_fungible_recall_emits_correct_events() {
     let receipt = test_runner.execute_manifest(manifest, vec![]);
 
     // Assert
-    {
-        let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault fungible withdraw, vault fungible recall, vault fungible deposit
-        assert!(match events.get(0) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ref event_data,
-            )) if test_runner.is_event_name_equal::<LockFeeEvent>(event_identifier)
-                && is_decoded_equal(&LockFeeEvent { amount: 500.into() }, event_data) =>
-                true,
-            _ => false,
-        });
-        // FIXME: Currently recall first emits a withdraw event and then a recall event. Should the
-        // redundant withdraw event go away or does it make sense from a user perspective?
-        assert!(match events.get(1) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ref event_data,
-            )) if test_runner.is_event_name_equal::<WithdrawResourceEvent>(event_identifier)
-                && is_decoded_equal(&WithdrawResourceEvent::Amount(1.into()), event_data) =>
-                true,
-            _ => false,
-        });
-        assert!(match events.get(2) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ref event_data,
-            )) if test_runner.is_event_name_equal::<RecallResourceEvent>(event_identifier)
-                && is_decoded_equal(&RecallResourceEvent::Amount(1.into()), event_data) =>
-                true,
-            _ => false,
-        });
-        assert!(match events.get(3) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ref event_data,
-            )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier)
-                && is_decoded_equal(&DepositResourceEvent::Amount(1.into()), event_data) =>
-                true,
-            _ => false,
-        });
-    }
+    receipt.expect_commit_success();
+    // FIXME: Currently recall first emits a withdraw event and then a recall event. Should the
+    // redundant withdraw event go away or does it make sense from a user perspective?
+    test_runner
+        .event_stream(&receipt)
+        .assert_sequence(expect_sequence![
+            LockFeeEvent { amount } if *amount == dec!("500"),
+            WithdrawResourceEvent::Amount(amount) if *amount == dec!("1"),
+            RecallResourceEvent::Amount(amount) if *amount == dec!("1"),
+            DepositResourceEvent::Amount(amount) if *amount == dec!("1"),
+        ]);
 }
 
 #[test]
@@ -311,54 +279,17 @@ fn vault_non_fungible_recall_emits_correct_events() {
     let receipt = test_runner.execute_manifest(manifest, vec![]);
 
     // Assert
-    {
-        let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault non-fungible withdraw, vault non-fungible recall, vault non-fungible deposit
-        assert!(match events.get(0) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ref event_data,
-            )) if test_runner.is_event_name_equal::<LockFeeEvent>(event_identifier)
-                && is_decoded_equal(&LockFeeEvent { amount: 500.into() }, event_data) =>
-                true,
-            _ => false,
-        });
-        // FIXME: Currently recall first emits a withdraw event and then a recall event. Should the
-        // redundant withdraw event go away or does it make sense from a user perspective?
-        assert!(match events.get(1) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ..,
-            )) if test_runner.is_event_name_equal::<WithdrawResourceEvent>(event_identifier) =>
-                true,
-            _ => false,
-        });
-        assert!(match events.get(2) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ref event_data,
-            )) if test_runner.is_event_name_equal::<RecallResourceEvent>(event_identifier)
-                && is_decoded_equal(&RecallResourceEvent::Amount(1.into()), event_data) =>
-                true,
-            _ => false,
-        });
-        assert!(match events.get(3) {
-            Some((
-                event_identifier
-                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
-                ref event_data,
-            )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier)
-                && is_decoded_equal(
-                    &DepositResourceEvent::Ids([non_fungible_local_id.clone()].into()),
-                    event_data
-                ) =>
-                true,
-            _ => false,
-        });
-    }
+    receipt.expect_commit_success();
+    // FIXME: Currently recall first emits a withdraw event and then a recall event. Should the
+    // redundant withdraw event go away or does it make sense from a user perspective?
+    test_runner
+        .event_stream(&receipt)
+        .assert_sequence(expect_sequence![
+            LockFeeEvent { amount } if *amount == dec!("500"),
+            WithdrawResourceEvent::Ids(..),
+            RecallResourceEvent::Amount(amount) if *amount == dec!("1"),
+            DepositResourceEvent::Ids(ids) if ids.len() == 1 && ids.contains(&non_fungible_local_id),
+        ]);
 }
 
 //==================
@@ -789,6 +720,58 @@ fn consensus_manager_round_update_emits_correct_event() {
     }
 }
 
+#[test]
+fn event_schema_resolver_decodes_events_without_their_types() {
+    let genesis = CustomGenesis::default(
+        Epoch::of(1),
+        CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+            EpochChangeCondition {
+                min_round_count: 100,
+                max_round_count: 100,
+                target_duration_millis: 1000,
+            },
+        ),
+    );
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+
+    // Act
+    let receipt = test_runner.execute_validator_transaction(vec![InstructionV1::CallMethod {
+        address: CONSENSUS_MANAGER.into(),
+        method_name: CONSENSUS_MANAGER_NEXT_ROUND_IDENT.to_string(),
+        args: to_manifest_value_and_unwrap!(&ConsensusManagerNextRoundInput::successful(
+            Round::of(1),
+            0,
+            180000i64,
+        )),
+    }]);
+
+    // Assert
+    let events = receipt.expect_commit(true).clone().application_events;
+    let (event_type_identifier, event_data) = events.first().unwrap();
+    let resolver = EventSchemaResolver::new(test_runner.substate_db());
+    assert_eq!(
+        resolver.event_name(event_type_identifier),
+        Ok(Some("RoundChangeEvent".to_string()))
+    );
+    assert_eq!(
+        resolver.decode_event_to_value(event_type_identifier, event_data),
+        Ok(DecodedEvent {
+            name: Some("RoundChangeEvent".to_string()),
+            value: scrypto_decode(
+                &scrypto_encode(&RoundChangeEvent {
+                    round: Round::of(1)
+                })
+                .unwrap()
+            )
+            .unwrap(),
+        })
+    );
+    assert!(matches!(
+        resolver.decode_event_to_value(event_type_identifier, &scrypto_encode("1").unwrap()),
+        Err(EventDecodeError::PayloadValidationError(..))
+    ));
+}
+
 #[test]
 fn consensus_manager_epoch_update_emits_epoch_change_event() {
     let genesis_epoch = Epoch::of(3);
@@ -1542,6 +1525,37 @@ fn setting_metadata_emits_correct_events() {
     }
 }
 
+#[test]
+fn locking_metadata_emits_correct_events() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let resource_address = create_all_allowed_resource(&mut test_runner);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET, 500)
+        .set_metadata(resource_address, "key", MetadataValue::I32(1))
+        .lock_metadata(resource_address, "key")
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    {
+        let result = receipt.expect_commit(true);
+        /*
+        Three events:
+        1. Vault lock fee
+        2. Metadata set entry
+        3. Metadata lock entry
+         */
+        assert_eq!(result.application_events.len(), 3);
+        let events = test_runner.extract_events_of_type::<LockMetadataEvent>(result);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].key, "key");
+    }
+}
+
 //=========
 // Account
 //=========
@@ -593,6 +593,127 @@ fn test_worktop_changes() {
     }
 }
 
+#[test]
+fn test_trace_wasm_memory_peaks() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/execution_trace");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .call_function(
+            package_address,
+            "ExecutionTraceTest",
+            "transfer_resource_between_two_components",
+            manifest_args!(10u8),
+        )
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest,
+        vec![public_key.clone().into()],
+        0,
+        PreviewFlags::default(),
+    );
+
+    // Assert
+    let wasm_memory_peaks = &receipt
+        .expect_commit_success()
+        .execution_trace
+        .wasm_memory_peaks;
+    let blueprint_id = BlueprintId::new(&package_address, "ExecutionTraceTest");
+    assert!(wasm_memory_peaks
+        .iter()
+        .any(|(id, bytes)| id.eq(&blueprint_id) && *bytes > 0));
+    // Native blueprints, such as the account, don't run in WASM
+    assert!(wasm_memory_peaks
+        .iter()
+        .all(|(id, _)| id.package_address.eq(&package_address)));
+}
+
+#[test]
+fn test_instruction_traces_record_resource_movements() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_all_from_worktop(XRD, "bucket")
+        .create_proof_from_bucket_of_all("bucket", "proof")
+        .drop_proof("proof")
+        .return_to_worktop("bucket")
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.preview_manifest(manifest, vec![], 0, PreviewFlags::default());
+
+    // Assert
+    let instruction_traces = &receipt
+        .expect_commit_success()
+        .execution_trace
+        .instruction_traces;
+    let instruction_trace = |instruction_index: usize| {
+        instruction_traces
+            .iter()
+            .find(|trace| trace.instruction_index == instruction_index)
+            .unwrap()
+    };
+
+    // CALL_METHOD: free
+    let free = instruction_trace(1);
+    assert_eq!(
+        free.worktop_changes,
+        vec![WorktopChange::Put(ResourceSpecifier::Amount(
+            XRD,
+            dec!("10000")
+        ))]
+    );
+    assert!(free
+        .vault_changes
+        .iter()
+        .any(|change| change.node_id == FAUCET.into() && change.amount == dec!("-10000")));
+
+    // TAKE_ALL_FROM_WORKTOP
+    let take = instruction_trace(2);
+    assert_eq!(
+        take.worktop_changes,
+        vec![WorktopChange::Take(ResourceSpecifier::Amount(
+            XRD,
+            dec!("10000")
+        ))]
+    );
+    assert!(take.proofs_created.is_empty());
+
+    // CREATE_PROOF_FROM_BUCKET_OF_ALL
+    let create_proof = instruction_trace(3);
+    assert!(create_proof.worktop_changes.is_empty());
+    assert_eq!(create_proof.proofs_created.len(), 1);
+    assert_eq!(create_proof.proofs_created[0].resource_address(), XRD);
+    assert_eq!(create_proof.proofs_created[0].amount(), dec!("10000"));
+
+    // DROP_PROOF moves no resources
+    assert!(instruction_traces
+        .iter()
+        .all(|trace| trace.instruction_index != 4));
+
+    // CALL_METHOD: try_deposit_batch_or_abort
+    let deposit = instruction_trace(6);
+    assert_eq!(
+        deposit.worktop_changes,
+        vec![WorktopChange::Take(ResourceSpecifier::Amount(
+            XRD,
+            dec!("10000")
+        ))]
+    );
+    assert!(deposit
+        .vault_changes
+        .iter()
+        .any(|change| change.node_id == account.into() && change.amount == dec!("10000")));
+}
+
 fn traces_for_instruction(
     traces: &Vec<ExecutionTrace>,
     instruction_index: usize,
@@ -1,12 +1,16 @@
+use radix_engine::blueprints::resource::MintNonFungibleResourceEvent;
 use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::node_modules::access_rules::{SetOwnerRoleEvent, SetRoleEvent};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::transaction::BalanceChange;
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::metadata::MetadataValue;
+use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::identity::{
     IdentityCreateAdvancedInput, IdentitySecurifyToSingleBadgeInput, IDENTITY_BLUEPRINT,
     IDENTITY_CREATE_ADVANCED_IDENT, IDENTITY_SECURIFY_IDENT,
 };
+use radix_engine_interface::rule;
 use scrypto_unit::*;
 use transaction::prelude::*;
 
@@ -228,6 +232,91 @@ fn securified_identity_is_owned_by_correct_owner_badge() {
     )
 }
 
+#[test]
+fn securify_identity_emits_owner_badge_mint_and_owner_role_events() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (pk, _, account) = test_runner.new_account(false);
+    let identity = test_runner.new_identity(pk.clone(), true);
+    let owner_badge = TestRunner::identity_owner_badge(identity);
+
+    // Act
+    let receipt = test_runner.securify_identity(pk, account);
+
+    // Assert
+    let events = &receipt.expect_commit_success().application_events;
+    assert!(events.iter().any(|(event_identifier, event_data)| {
+        test_runner.is_event_name_equal::<MintNonFungibleResourceEvent>(event_identifier)
+            && scrypto_decode::<MintNonFungibleResourceEvent>(event_data).unwrap()
+                == MintNonFungibleResourceEvent {
+                    ids: btreeset!(owner_badge.local_id().clone()),
+                }
+    }));
+    assert!(events.iter().any(|(event_identifier, event_data)| {
+        event_identifier.0 == Emitter::Method(*identity.as_node_id(), ObjectModuleId::AccessRules)
+            && test_runner.is_event_name_equal::<SetRoleEvent>(event_identifier)
+            && scrypto_decode::<SetRoleEvent>(event_data).unwrap().rule == AccessRule::DenyAll
+    }));
+    assert!(events.iter().any(|(event_identifier, event_data)| {
+        event_identifier.0 == Emitter::Method(*identity.as_node_id(), ObjectModuleId::AccessRules)
+            && test_runner.is_event_name_equal::<SetOwnerRoleEvent>(event_identifier)
+            && scrypto_decode::<SetOwnerRoleEvent>(event_data)
+                .unwrap()
+                .rule
+                == rule!(require(owner_badge.clone()))
+    }));
+}
+
+#[test]
+fn securified_identity_requires_owner_badge_instead_of_signature() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (pk, _, account) = test_runner.new_account(false);
+    let (identity, owner_badge) = test_runner.new_virtual_then_securified_identity(pk, account);
+    let set_metadata = |builder: ManifestBuilder| {
+        builder
+            .set_metadata(
+                identity,
+                "name".to_string(),
+                MetadataValue::String("securified".to_string()),
+            )
+            .build()
+    };
+
+    // Act
+    let signature_only_receipt = test_runner.execute_manifest(
+        set_metadata(ManifestBuilder::new().lock_fee_from_faucet()),
+        vec![NonFungibleGlobalId::from_public_key(&pk)],
+    );
+    let owner_badge_receipt = test_runner.execute_manifest(
+        set_metadata(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .create_proof_from_account_of_non_fungibles(
+                    account,
+                    owner_badge.resource_address(),
+                    &btreeset!(owner_badge.local_id().clone()),
+                ),
+        ),
+        vec![NonFungibleGlobalId::from_public_key(&pk)],
+    );
+
+    // Assert
+    signature_only_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+    owner_badge_receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_metadata(identity.into(), "name"),
+        Some(MetadataValue::String("securified".to_string()))
+    );
+}
+
 #[test]
 fn identity_created_with_create_advanced_has_an_empty_owner_badge() {
     // Arrange
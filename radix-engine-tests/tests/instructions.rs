@@ -6,6 +6,7 @@ use radix_engine::{
     types::*,
 };
 use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use scrypto::prelude::{require, require_amount};
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -125,3 +126,186 @@ fn clear_signature_proofs_should_not_invalid_physical_proof() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn assert_next_call_returns_include_should_pass_for_returned_resources() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_next_call_returns_include(vec![ResourceAssertion::Amount(XRD, dec!("100"))])
+        .get_free_xrd_from_faucet()
+        .assert_next_call_returns_value(&())
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn assert_next_call_returns_exactly_should_fail_for_different_amount() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_next_call_returns_exactly(vec![ResourceAssertion::Amount(XRD, dec!("100"))])
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::NextCallReturnsResourceAssertionFailed { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn assert_next_call_returns_should_fail_if_not_followed_by_call() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_next_call_returns_value(&())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::UnconsumedNextCallAssertion
+            ))
+        )
+    });
+}
+
+#[test]
+fn rollback_to_savepoint_should_revert_failed_section_only() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let balance_before = test_runner.account_balance(account, XRD).unwrap();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .savepoint()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .assert_worktop_contains(XRD, dec!("1")) // Fails, as the worktop is empty
+        .rollback_to_savepoint()
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success().outcome.expect_success();
+    assert!(matches!(
+        outputs[5],
+        InstructionOutput::RolledBack {
+            failed_instruction_index: 4,
+            ..
+        }
+    ));
+    assert_eq!(
+        test_runner.account_balance(account, XRD).unwrap(),
+        balance_before
+    );
+}
+
+#[test]
+fn rollback_to_savepoint_should_keep_successful_section() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let balance_before = test_runner.account_balance(account, XRD).unwrap();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .savepoint()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .rollback_to_savepoint()
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(account, XRD).unwrap(),
+        balance_before + dec!("10000")
+    );
+}
+
+#[test]
+fn assert_metadata_should_pass_for_matching_value() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_metadata(XRD, "symbol", MetadataValue::String("XRD".to_owned()))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn assert_metadata_should_fail_for_different_or_missing_value() {
+    for (key, value, expected_actual) in [
+        (
+            "symbol",
+            MetadataValue::String("FAKE".to_owned()),
+            Some(MetadataValue::String("XRD".to_owned())),
+        ),
+        (
+            "dapp_definition",
+            MetadataValue::GlobalAddress(FAUCET.into()),
+            None,
+        ),
+    ] {
+        // Arrange
+        let mut test_runner = TestRunner::builder().build();
+
+        // Act
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .assert_metadata(XRD, key, value.clone())
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            e.eq(&RuntimeError::ApplicationError(
+                ApplicationError::TransactionProcessorError(
+                    TransactionProcessorError::MetadataAssertionFailed {
+                        address: XRD.into(),
+                        key: key.to_owned(),
+                        expected: value.clone(),
+                        actual: expected_actual.clone(),
+                    },
+                ),
+            ))
+        });
+    }
+}
@@ -0,0 +1,43 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn test_resource_invariants_hold_when_minting_transferring_and_burning() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_invariant_checks().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+        OwnerRole::None,
+        Some(dec!(100)),
+        18,
+        account,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account, resource_address, 10)
+        .mint_fungible(resource_address, 50)
+        .withdraw_from_account(account, resource_address, 30)
+        .take_from_worktop(resource_address, 20, "bucket")
+        .create_proof_from_bucket_of_amount("bucket", 5, "proof")
+        .drop_proof("proof")
+        .burn_resource("bucket")
+        .burn_from_worktop(10, resource_address)
+        .drop_all_proofs()
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(other_account, resource_address),
+        Some(dec!(50))
+    );
+}
@@ -3,6 +3,7 @@ use radix_engine::kernel::call_frame::OpenSubstateError;
 use radix_engine::kernel::id_allocator::IdAllocator;
 use radix_engine::kernel::kernel::KernelBoot;
 use radix_engine::kernel::kernel_api::KernelSubstateApi;
+use radix_engine::system::blueprint_mocks::BlueprintMocks;
 use radix_engine::system::bootstrap::Bootstrapper;
 use radix_engine::system::system_callback::{SystemConfig, SystemLockData};
 use radix_engine::system::system_modules::costing::{FeeTable, SystemLoanFeeReserve};
@@ -41,6 +42,7 @@ pub fn test_open_substate_of_invisible_package_address() {
         blueprint_cache: NonIterMap::new(),
         auth_cache: NonIterMap::new(),
         schema_cache: NonIterMap::new(),
+        blueprint_mocks: BlueprintMocks::default(),
         callback_obj: Vm {
             scrypto_vm: &scrypto_vm,
         },
@@ -523,3 +523,91 @@ fn nested_kv_stores_works() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn queue_pops_items_in_push_order() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "QueueTest", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(component, "push_back", manifest_args!(1u32))
+                .call_method(component, "push_back", manifest_args!(2u32))
+                .call_method(component, "push_back", manifest_args!(3u32))
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component, "pop_front", manifest_args!())
+            .call_method(component, "front", manifest_args!())
+            .call_method(component, "len", manifest_args!())
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.output::<Option<u32>>(1), Some(1));
+    assert_eq!(result.output::<Option<u32>>(2), Some(2));
+    assert_eq!(result.output::<u64>(3), 2);
+}
+
+#[test]
+fn ring_buffer_evicts_oldest_item_once_full() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "RingBufferTest",
+                "new",
+                manifest_args!(2u64),
+            )
+            .build(),
+        vec![],
+    );
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component, "push", manifest_args!(1u32))
+            .call_method(component, "push", manifest_args!(2u32))
+            .call_method(component, "push", manifest_args!(3u32))
+            .call_method(component, "get", manifest_args!(0u64))
+            .call_method(component, "get", manifest_args!(1u64))
+            .call_method(component, "pop", manifest_args!())
+            .call_method(component, "len", manifest_args!())
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.output::<Option<u32>>(1), None);
+    assert_eq!(result.output::<Option<u32>>(2), None);
+    assert_eq!(result.output::<Option<u32>>(3), Some(1));
+    assert_eq!(result.output::<Option<u32>>(4), Some(2));
+    assert_eq!(result.output::<Option<u32>>(5), Some(3));
+    assert_eq!(result.output::<Option<u32>>(6), Some(2));
+    assert_eq!(result.output::<u64>(7), 1);
+}
@@ -1,5 +1,6 @@
 use radix_engine::blueprints::resource::FungibleResourceManagerError;
 use radix_engine::errors::{ApplicationError, KernelError, RuntimeError};
+use radix_engine::system::system_modules::node_audit::AuditedNodeKind;
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -50,6 +51,13 @@ fn dangling_bucket_should_fail() {
     receipt.expect_specific_failure(|e| {
         matches!(e, RuntimeError::KernelError(KernelError::NodeOrphaned(..)))
     });
+    let leaked_bucket = receipt
+        .expect_commit_failure()
+        .leaked_nodes
+        .iter()
+        .find(|allocation| allocation.kind == AuditedNodeKind::Bucket)
+        .expect("Leaked bucket should be reported");
+    assert_eq!(leaked_bucket.instruction_index, 1);
 }
 
 #[test]
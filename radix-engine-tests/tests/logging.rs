@@ -44,6 +44,28 @@ fn test_emit_log() {
     }
 }
 
+#[test]
+fn test_emit_metric() {
+    // Arrange
+    let function_name = "emit_metric";
+    let name = "latency";
+
+    // Act
+    let receipt = call(function_name, name);
+
+    // Assert
+    {
+        let commit = receipt.expect_commit_success();
+
+        assert_eq!(
+            commit.application_metrics,
+            vec![(name.to_owned(), dec!("1.5"))]
+        );
+        assert!(commit.application_events.is_empty());
+        assert!(commit.application_logs.is_empty());
+    }
+}
+
 #[test]
 fn test_rust_panic() {
     // Arrange
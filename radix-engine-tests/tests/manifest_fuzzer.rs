@@ -0,0 +1,54 @@
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use scrypto_unit::fuzz::*;
+use scrypto_unit::*;
+
+fn expect_commit_success(_: &mut TestRunner, receipt: &TransactionReceipt) -> Result<(), String> {
+    if receipt.is_commit_success() {
+        Ok(())
+    } else {
+        Err(format!("{:?}", receipt))
+    }
+}
+
+#[test]
+fn fuzzer_finds_and_shrinks_failing_manifest() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/fuzz");
+    let mut fuzzer = ManifestFuzzer::new(&test_runner, package_address, 1234);
+
+    // Act
+    let failure = fuzzer
+        .run(&mut test_runner, 100, expect_commit_success)
+        .expect_err("Division by zero should be found");
+
+    // Assert
+    assert_eq!(failure.calls.len(), 1);
+    assert_eq!(failure.calls[0].ident, "divide");
+    assert_eq!(
+        failure.calls[0].args,
+        to_manifest_value(&(0u32, 0u32)).unwrap()
+    );
+}
+
+#[test]
+fn fuzzer_can_pass_buckets_withdrawn_from_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/fuzz");
+    let initial_balance = test_runner.account_balance(account, XRD).unwrap();
+    let mut fuzzer = ManifestFuzzer::new(&test_runner, package_address, 1234)
+        .with_account(account, NonFungibleGlobalId::from_public_key(&public_key))
+        .with_bucket_resource(XRD, dec!(100))
+        .without_call("divide");
+
+    // Act
+    let result = fuzzer.run(&mut test_runner, 50, expect_commit_success);
+
+    // Assert
+    assert!(result.is_ok(), "{:?}", result);
+    assert!(test_runner.account_balance(account, XRD).unwrap() < initial_balance);
+}
@@ -56,6 +56,82 @@ fn can_set_metadata_after_globalized() {
     assert_eq!(value, MetadataValue::String("value".to_string()));
 }
 
+#[test]
+fn can_read_metadata_of_another_component_without_calling_it() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/metadata_component");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            manifest_args!("key".to_string(), "value".to_string()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "read_metadata",
+            manifest_args!(component_address, "key".to_string()),
+        )
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "read_metadata",
+            manifest_args!(component_address, "missing".to_string()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let value: Option<String> = commit.output(1);
+    assert_eq!(value, Some("value".to_string()));
+    let value: Option<String> = commit.output(2);
+    assert_eq!(value, None);
+}
+
+#[test]
+fn can_batch_read_state_and_metadata() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/metadata_component");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            manifest_args!("key".to_string(), "value".to_string()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "batch_read",
+            manifest_args!(vec!["key".to_string(), "missing".to_string()]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let values: Vec<Option<String>> = receipt.expect_commit_success().output(1);
+    assert_eq!(values, vec![Some("value".to_string()), None]);
+}
+
 #[test]
 fn can_remove_metadata() {
     // Arrange
@@ -0,0 +1,78 @@
+use radix_engine::system::node_modules::access_rules::{
+    AccessRulesNativePackage, LockOwnerRoleEvent, LockRoleEvent, SetAndLockOwnerRoleEvent,
+    SetAndLockRoleEvent, SetOwnerRoleEvent, SetRoleEvent,
+};
+use radix_engine::system::node_modules::metadata::{
+    LockMetadataEvent, MetadataNativePackage, RemoveMetadataEvent, SetMetadataEvent,
+};
+use radix_engine::system::node_modules::royalty::{
+    ClaimComponentRoyaltyEvent, LockComponentRoyaltyEvent, RoyaltyNativePackage,
+    SetComponentRoyaltyEvent,
+};
+use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::auth::ACCESS_RULES_BLUEPRINT;
+use radix_engine_interface::api::node_modules::metadata::METADATA_BLUEPRINT;
+use radix_engine_interface::api::node_modules::royalty::COMPONENT_ROYALTY_BLUEPRINT;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+use radix_engine_interface::traits::ScryptoEvent;
+
+macro_rules! event_names {
+    ($($event_type: ty),* $(,)?) => {
+        btreeset!($(<$event_type as ScryptoEvent>::event_name().to_string()),*)
+    };
+}
+
+/// The events each node module is expected to declare, one for every kind of mutation it allows.
+fn expected_module_events() -> Vec<(&'static str, PackageDefinition, BTreeSet<String>)> {
+    vec![
+        (
+            METADATA_BLUEPRINT,
+            MetadataNativePackage::definition(),
+            event_names![SetMetadataEvent, LockMetadataEvent, RemoveMetadataEvent],
+        ),
+        (
+            COMPONENT_ROYALTY_BLUEPRINT,
+            RoyaltyNativePackage::definition(),
+            event_names![
+                SetComponentRoyaltyEvent,
+                LockComponentRoyaltyEvent,
+                ClaimComponentRoyaltyEvent,
+            ],
+        ),
+        (
+            ACCESS_RULES_BLUEPRINT,
+            AccessRulesNativePackage::definition(),
+            event_names![
+                SetOwnerRoleEvent,
+                LockOwnerRoleEvent,
+                SetAndLockOwnerRoleEvent,
+                SetRoleEvent,
+                LockRoleEvent,
+                SetAndLockRoleEvent,
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn node_modules_declare_exactly_the_expected_events() {
+    for (blueprint_name, definition, expected_events) in expected_module_events() {
+        let blueprint = definition
+            .blueprints
+            .get(blueprint_name)
+            .unwrap_or_else(|| panic!("Missing blueprint {}", blueprint_name));
+        let declared_events: BTreeSet<String> = blueprint
+            .schema
+            .events
+            .event_schema
+            .keys()
+            .cloned()
+            .collect();
+
+        assert_eq!(
+            declared_events, expected_events,
+            "Unexpected events declared by {}",
+            blueprint_name
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use radix_engine::blueprints::resource::NonFungibleResourceManagerError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
@@ -428,6 +429,54 @@ fn test_non_fungible_part_2() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_non_fungible_vault_ids_can_be_listed_in_pages() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "NonFungibleTest",
+                "create_vault_with_non_fungibles",
+                manifest_args!(10u64),
+            )
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act: take some ids first, so that the pages overlay the changes on the stored ids
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                component_address,
+                "take_and_list_non_fungible_local_ids_in_pages",
+                manifest_args!(2u32, 3u32),
+            )
+            .try_deposit_batch_or_abort(account)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let (_, pages): (Bucket, Vec<Vec<NonFungibleLocalId>>) =
+        receipt.expect_commit_success().output(1);
+    assert_eq!(
+        pages.iter().map(|page| page.len()).collect::<Vec<_>>(),
+        vec![3, 3, 2]
+    );
+    let listed: BTreeSet<NonFungibleLocalId> = pages.into_iter().flatten().collect();
+    assert_eq!(listed.len(), 8);
+    assert!(listed
+        .iter()
+        .all(|id| (1..=10).any(|i| *id == NonFungibleLocalId::integer(i))));
+}
+
 #[test]
 fn test_singleton_non_fungible() {
     let mut test_runner = TestRunner::builder().build();
@@ -673,6 +722,51 @@ fn can_mint_ruid_non_fungible_in_scrypto() {
     receipt.expect_commit_success();
 }
 
+fn mint_ruid_non_fungible_with_deterministic_ruids(seed: u64) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().with_deterministic_ruids(seed).build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_ruid_non_fungible_and_mint",
+            manifest_args!(),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn deterministic_ruids_are_stable_for_the_same_seed() {
+    // Act
+    let receipt1 = mint_ruid_non_fungible_with_deterministic_ruids(1);
+    let receipt2 = mint_ruid_non_fungible_with_deterministic_ruids(1);
+
+    // Assert
+    receipt1.expect_commit_success();
+    let diff = receipt1.diff(&receipt2);
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[test]
+fn deterministic_ruids_differ_between_seeds() {
+    // Act
+    let receipt1 = mint_ruid_non_fungible_with_deterministic_ruids(1);
+    let receipt2 = mint_ruid_non_fungible_with_deterministic_ruids(2);
+
+    // Assert
+    receipt1.expect_commit_success();
+    receipt2.expect_commit_success();
+    assert!(!receipt1.diff(&receipt2).is_empty());
+}
+
 #[derive(ManifestSbor, ScryptoSbor, NonFungibleData)]
 pub struct Sandwich {
     pub name: String,
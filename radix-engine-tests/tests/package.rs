@@ -360,3 +360,29 @@ fn should_not_be_able_to_publish_native_packages_in_scrypto() {
         )
     });
 }
+
+#[test]
+fn prebuilt_package_can_be_published_and_called() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (code, definition) = include_package!("../../assets", "radiswap");
+
+    // Act
+    let package_address = test_runner.publish_prebuilt_package(code, definition);
+
+    // Assert
+    let (_, _, account) = test_runner.new_allocated_account();
+    let btc = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let eth = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Radiswap",
+            "new",
+            manifest_args!(OwnerRole::None, btc, eth),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+}
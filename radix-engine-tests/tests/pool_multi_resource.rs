@@ -1,6 +1,6 @@
 use radix_engine::errors::{SystemError, SystemModuleError};
 use radix_engine::{
-    blueprints::pool::multi_resource_pool::*,
+    blueprints::pool::{multi_resource_pool::*, ContributionProtectionError},
     errors::{ApplicationError, RuntimeError},
     transaction::{BalanceChange, TransactionReceipt},
     types::*,
@@ -743,6 +743,168 @@ fn cant_withdraw_without_proper_signature() {
     receipt.expect_specific_failure(is_auth_error)
 }
 
+#[test]
+fn single_sided_contribution_without_fee_mints_expected_pool_units() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<3>::new([18, 18, 18]);
+    test_runner
+        .contribute(
+            btreemap!(
+                test_runner.pool_resources[0] => dec!("100"),
+                test_runner.pool_resources[1] => dec!("100"),
+                test_runner.pool_resources[2] => dec!("100")
+            ),
+            true,
+        )
+        .expect_commit_success();
+    test_runner
+        .set_single_sided_contribution_fee(Some(Decimal::ZERO), true)
+        .expect_commit_success();
+
+    // Act
+    let receipt =
+        test_runner.contribute_single_sided(test_runner.pool_resources[0], dec!("33.1"), true);
+
+    // Assert
+    let SingleSidedContributionEvent {
+        pool_units_minted,
+        fee,
+        implied_swap_output,
+        ..
+    } = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if test_runner.test_runner.event_name(event_type_identifier)
+                == "SingleSidedContributionEvent"
+            {
+                Some(scrypto_decode(event_data).unwrap())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    assert_eq!(pool_units_minted, dec!("100"));
+    assert_eq!(fee, Decimal::ZERO);
+    assert_eq!(
+        implied_swap_output.keys().collect::<BTreeSet<_>>(),
+        btreeset!(
+            &test_runner.pool_resources[1],
+            &test_runner.pool_resources[2]
+        )
+    );
+}
+
+#[test]
+fn single_sided_contribution_fails_when_disabled() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<3>::new([18, 18, 18]);
+    test_runner
+        .contribute(
+            btreemap!(
+                test_runner.pool_resources[0] => dec!("100"),
+                test_runner.pool_resources[1] => dec!("100"),
+                test_runner.pool_resources[2] => dec!("100")
+            ),
+            true,
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt =
+        test_runner.contribute_single_sided(test_runner.pool_resources[0], dec!("33.1"), true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::MultiResourcePoolError(
+                MultiResourcePoolError::SingleSidedContributionsAreDisabled
+            ))
+        )
+    })
+}
+
+#[test]
+fn cant_set_single_sided_contribution_fee_without_proper_signature() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<3>::new([18, 18, 18]);
+
+    // Act
+    let receipt = test_runner.set_single_sided_contribution_fee(Some(Decimal::ZERO), false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error)
+}
+
+#[test]
+fn first_contribution_to_pool_burns_minimum_liquidity() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_contribution_protection(
+        [18, 18],
+        PoolContributionProtection::default().burn_minimum_liquidity(1.into()),
+    );
+
+    // Act
+    let receipt = test_runner.contribute(
+        btreemap!(
+            test_runner.pool_resources[0] => dec!("100"),
+            test_runner.pool_resources[1] => dec!("100"),
+        ),
+        true,
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_eq!(
+        commit_result
+            .balance_changes()
+            .get(&GlobalAddress::from(test_runner.account_component_address))
+            .unwrap()
+            .get(&test_runner.pool_unit_resource_address)
+            .unwrap()
+            .clone(),
+        BalanceChange::Fungible(99.into())
+    );
+
+    test_runner.redeem(99, true).expect_commit_success();
+    let amounts = test_runner.get_vault_amounts(true);
+    for item in amounts.iter() {
+        assert_eq!(*item.1, 1.into());
+    }
+}
+
+#[test]
+fn first_contribution_not_exceeding_minimum_liquidity_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<2>::new_with_contribution_protection(
+        [18, 18],
+        PoolContributionProtection::default().burn_minimum_liquidity(100.into()),
+    );
+
+    // Act
+    let receipt = test_runner.contribute(
+        btreemap!(
+            test_runner.pool_resources[0] => dec!("100"),
+            test_runner.pool_resources[1] => dec!("100"),
+        ),
+        true,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::MultiResourcePoolError(
+                MultiResourcePoolError::ContributionProtectionError(
+                    ContributionProtectionError::ContributionDoesNotExceedMinimumLiquidity { .. }
+                )
+            ))
+        )
+    });
+}
+
 struct TestEnvironment<const N: usize> {
     test_runner: TestRunner,
 
@@ -761,6 +923,25 @@ impl<const N: usize> TestEnvironment<N> {
     }
 
     pub fn new_with_owner(divisibility: [u8; N], owner_role: OwnerRole) -> Self {
+        Self::new_with_owner_and_contribution_protection(divisibility, owner_role, None)
+    }
+
+    pub fn new_with_contribution_protection(
+        divisibility: [u8; N],
+        contribution_protection: PoolContributionProtection,
+    ) -> Self {
+        Self::new_with_owner_and_contribution_protection(
+            divisibility,
+            OwnerRole::None,
+            Some(contribution_protection),
+        )
+    }
+
+    pub fn new_with_owner_and_contribution_protection(
+        divisibility: [u8; N],
+        owner_role: OwnerRole,
+        contribution_protection: Option<PoolContributionProtection>,
+    ) -> Self {
         let mut test_runner = TestRunner::builder().without_trace().build();
         let (public_key, _, account) = test_runner.new_account(false);
         let virtual_signature_badge = NonFungibleGlobalId::from_public_key(&public_key);
@@ -775,8 +956,8 @@ impl<const N: usize> TestEnvironment<N> {
         });
 
         let (pool_component, pool_unit_resource) = {
-            let manifest = ManifestBuilder::new()
-                .call_function(
+            let manifest = match contribution_protection {
+                None => ManifestBuilder::new().call_function(
                     POOL_PACKAGE,
                     MULTI_RESOURCE_POOL_BLUEPRINT_IDENT,
                     MULTI_RESOURCE_POOL_INSTANTIATE_IDENT,
@@ -785,8 +966,15 @@ impl<const N: usize> TestEnvironment<N> {
                         pool_manager_rule: rule!(require(virtual_signature_badge)),
                         owner_role,
                     },
-                )
-                .build();
+                ),
+                Some(contribution_protection) => ManifestBuilder::new().create_multi_resource_pool(
+                    owner_role,
+                    rule!(require(virtual_signature_badge)),
+                    resource_addresses.clone().into(),
+                    contribution_protection,
+                ),
+            }
+            .build();
             let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
             let commit_result = receipt.expect_commit_success();
 
@@ -899,6 +1087,44 @@ impl<const N: usize> TestEnvironment<N> {
         self.execute_manifest(manifest, sign)
     }
 
+    fn contribute_single_sided<D: Into<Decimal>>(
+        &mut self,
+        resource_address: ResourceAddress,
+        amount: D,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .mint_fungible(resource_address, amount.into())
+            .take_all_from_worktop(resource_address, "contribution")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_method(
+                    self.pool_component_address,
+                    MULTI_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_IDENT,
+                    MultiResourcePoolContributeSingleSidedManifestInput {
+                        bucket: lookup.bucket("contribution"),
+                    },
+                )
+            })
+            .try_deposit_batch_or_abort(self.account_component_address)
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn set_single_sided_contribution_fee(
+        &mut self,
+        fee: Option<Decimal>,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                MULTI_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_IDENT,
+                MultiResourcePoolSetSingleSidedContributionFeeManifestInput { fee },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
     fn execute_manifest(
         &mut self,
         manifest: TransactionManifestV1,
@@ -1,4 +1,5 @@
 use radix_engine::blueprints::pool::one_resource_pool::*;
+use radix_engine::blueprints::pool::ContributionProtectionError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
 use radix_engine::transaction::{BalanceChange, TransactionReceipt};
 use radix_engine::types::*;
@@ -607,6 +608,198 @@ pub fn owner_can_update_pool_metadata() {
     // Arrange
 }
 
+#[test]
+fn first_contribution_to_pool_burns_minimum_liquidity() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        18,
+        PoolContributionProtection::default().burn_minimum_liquidity(1.into()),
+    );
+
+    // Act
+    let receipt = test_runner.contribute(100, true);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let balance_change = commit_result
+        .balance_changes()
+        .get(&GlobalAddress::from(test_runner.account_component_address))
+        .unwrap()
+        .get(&test_runner.pool_unit_resource_address)
+        .unwrap();
+    assert_eq!(balance_change.clone(), BalanceChange::Fungible(99.into()));
+
+    // The burned pool units keep their share of the pool once all others are redeemed
+    test_runner.redeem(99, true).expect_commit_success();
+    assert_eq!(test_runner.get_vault_amount(true), 1.into());
+}
+
+#[test]
+fn first_contribution_to_pool_escrows_minimum_liquidity() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        18,
+        PoolContributionProtection::default().escrow_minimum_liquidity(1.into()),
+    );
+
+    // Act
+    let receipt = test_runner.contribute(100, true);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let balance_changes = commit_result.balance_changes();
+    assert_eq!(
+        balance_changes
+            .get(&GlobalAddress::from(test_runner.account_component_address))
+            .unwrap()
+            .get(&test_runner.pool_unit_resource_address)
+            .unwrap()
+            .clone(),
+        BalanceChange::Fungible(99.into())
+    );
+    assert_eq!(
+        balance_changes
+            .get(&GlobalAddress::from(test_runner.pool_component_address))
+            .unwrap()
+            .get(&test_runner.pool_unit_resource_address)
+            .unwrap()
+            .clone(),
+        BalanceChange::Fungible(1.into())
+    );
+
+    test_runner.redeem(99, true).expect_commit_success();
+    assert_eq!(test_runner.get_vault_amount(true), 1.into());
+}
+
+#[test]
+fn minimum_liquidity_is_only_locked_by_first_contribution() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        18,
+        PoolContributionProtection::default().burn_minimum_liquidity(1.into()),
+    );
+    test_runner.contribute(100, true).expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute(50, true);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let balance_change = commit_result
+        .balance_changes()
+        .get(&GlobalAddress::from(test_runner.account_component_address))
+        .unwrap()
+        .get(&test_runner.pool_unit_resource_address)
+        .unwrap();
+    assert_eq!(balance_change.clone(), BalanceChange::Fungible(50.into()));
+}
+
+#[test]
+fn first_contribution_not_exceeding_minimum_liquidity_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        18,
+        PoolContributionProtection::default().burn_minimum_liquidity(10.into()),
+    );
+
+    // Act
+    let receipt = test_runner.contribute(10, true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::OneResourcePoolError(
+                OneResourcePoolError::ContributionProtectionError(
+                    ContributionProtectionError::ContributionDoesNotExceedMinimumLiquidity { .. }
+                )
+            ))
+        )
+    });
+}
+
+#[test]
+fn contribution_below_dust_threshold_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        18,
+        PoolContributionProtection::default().dust_threshold(1.into()),
+    );
+    test_runner.contribute(100, true).expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute(dec!("0.5"), true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::OneResourcePoolError(
+                OneResourcePoolError::ContributionProtectionError(
+                    ContributionProtectionError::ContributionBelowDustThreshold { .. }
+                )
+            ))
+        )
+    });
+}
+
+#[test]
+fn minimum_liquidity_limits_inflation_of_pool_unit_value_by_first_contributor() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        18,
+        PoolContributionProtection::default().burn_minimum_liquidity(dec!("0.000001")),
+    );
+
+    // The first contributor mints a tiny amount of pool units and then inflates their value
+    test_runner
+        .contribute(dec!("0.000002"), true)
+        .expect_commit_success();
+    test_runner
+        .protected_deposit(1000, true)
+        .expect_commit_success();
+
+    // Act
+    test_runner.contribute(1000, true).expect_commit_success();
+
+    // Assert
+    // The first contributor only holds a fraction of the pool units, so the later contribution
+    // is still redeemable for (nearly) all that was contributed.
+    let redemption_value = test_runner.get_redemption_value(dec!("0.000001999999996"), true);
+    assert!(redemption_value > 999.into());
+}
+
+#[test]
+fn creating_a_pool_with_invalid_contribution_protection_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (_, _, account) = test_runner.new_account(false);
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .create_one_resource_pool(
+            OwnerRole::None,
+            rule!(allow_all),
+            resource_address,
+            PoolContributionProtection::default().burn_minimum_liquidity(Decimal::ZERO),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::OneResourcePoolError(
+                OneResourcePoolError::ContributionProtectionError(
+                    ContributionProtectionError::InvalidMinimumLiquidity { .. }
+                )
+            ))
+        )
+    });
+}
+
 //===================================
 // Test Runner and Utility Functions
 //===================================
@@ -626,6 +819,25 @@ impl TestEnvironment {
     }
 
     fn new_with_owner(divisibility: u8, owner_role: OwnerRole) -> Self {
+        Self::new_with_owner_and_contribution_protection(divisibility, owner_role, None)
+    }
+
+    fn new_with_contribution_protection(
+        divisibility: u8,
+        contribution_protection: PoolContributionProtection,
+    ) -> Self {
+        Self::new_with_owner_and_contribution_protection(
+            divisibility,
+            OwnerRole::None,
+            Some(contribution_protection),
+        )
+    }
+
+    fn new_with_owner_and_contribution_protection(
+        divisibility: u8,
+        owner_role: OwnerRole,
+        contribution_protection: Option<PoolContributionProtection>,
+    ) -> Self {
         let mut test_runner = TestRunner::builder().without_trace().build();
         let (public_key, _, account) = test_runner.new_account(false);
         let virtual_signature_badge = NonFungibleGlobalId::from_public_key(&public_key);
@@ -638,8 +850,8 @@ impl TestEnvironment {
         );
 
         let (pool_component, pool_unit_resource) = {
-            let manifest = ManifestBuilder::new()
-                .call_function(
+            let manifest = match contribution_protection {
+                None => ManifestBuilder::new().call_function(
                     POOL_PACKAGE,
                     ONE_RESOURCE_POOL_BLUEPRINT_IDENT,
                     ONE_RESOURCE_POOL_INSTANTIATE_IDENT,
@@ -648,8 +860,15 @@ impl TestEnvironment {
                         pool_manager_rule: rule!(require(virtual_signature_badge)),
                         owner_role,
                     },
-                )
-                .build();
+                ),
+                Some(contribution_protection) => ManifestBuilder::new().create_one_resource_pool(
+                    owner_role,
+                    rule!(require(virtual_signature_badge)),
+                    resource_address,
+                    contribution_protection,
+                ),
+            }
+            .build();
             let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
             let commit_result = receipt.expect_commit_success();
 
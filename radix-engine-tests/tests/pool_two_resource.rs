@@ -1,4 +1,5 @@
 use radix_engine::blueprints::pool::two_resource_pool::*;
+use radix_engine::blueprints::pool::ContributionProtectionError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
 use radix_engine::transaction::{BalanceChange, TransactionReceipt};
 use radix_engine::types::*;
@@ -897,6 +898,278 @@ pub fn contribute_fails_without_proper_authority_present() {
     receipt.expect_specific_failure(is_auth_error)
 }
 
+#[test]
+pub fn single_sided_contribution_fails_when_disabled() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18));
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 100),
+            true,
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute_single_sided(test_runner.pool_resource1, 21, true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::TwoResourcePoolError(
+                TwoResourcePoolError::SingleSidedContributionsAreDisabled
+            ))
+        )
+    })
+}
+
+#[test]
+pub fn single_sided_contribution_without_fee_mints_expected_pool_units() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18));
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 100),
+            true,
+        )
+        .expect_commit_success();
+    test_runner
+        .set_single_sided_contribution_fee(Some(Decimal::ZERO), true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute_single_sided(test_runner.pool_resource1, 21, true);
+
+    // Assert
+    let SingleSidedContributionEvent {
+        resource_address,
+        amount,
+        pool_units_minted,
+        fee,
+        implied_swap_output,
+        ..
+    } = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if test_runner.test_runner.event_name(event_type_identifier)
+                == "SingleSidedContributionEvent"
+            {
+                Some(scrypto_decode(event_data).unwrap())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    assert_eq!(resource_address, test_runner.pool_resource1);
+    assert_eq!(amount, dec!("21"));
+    assert_eq!(pool_units_minted, dec!("10"));
+    assert_eq!(fee, Decimal::ZERO);
+    assert_eq!(
+        implied_swap_output.keys().collect::<Vec<_>>(),
+        vec![&test_runner.pool_resource2]
+    );
+    assert_eq!(
+        test_runner.get_vault_amounts(true),
+        btreemap!(
+            test_runner.pool_resource1 => dec!("121"),
+            test_runner.pool_resource2 => dec!("100"),
+        )
+    );
+}
+
+#[test]
+pub fn single_sided_contribution_with_fee_mints_fewer_pool_units() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18));
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 100),
+            true,
+        )
+        .expect_commit_success();
+    test_runner
+        .set_single_sided_contribution_fee(Some(dec!("0.1")), true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute_single_sided(test_runner.pool_resource1, 21, true);
+
+    // Assert
+    let SingleSidedContributionEvent {
+        pool_units_minted,
+        fee,
+        ..
+    } = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if test_runner.test_runner.event_name(event_type_identifier)
+                == "SingleSidedContributionEvent"
+            {
+                Some(scrypto_decode(event_data).unwrap())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    assert!(pool_units_minted < dec!("10"));
+    assert!(fee > Decimal::ZERO);
+}
+
+#[test]
+pub fn single_sided_contribution_to_empty_pool_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18));
+    test_runner
+        .set_single_sided_contribution_fee(Some(Decimal::ZERO), true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute_single_sided(test_runner.pool_resource1, 21, true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::TwoResourcePoolError(
+                TwoResourcePoolError::SingleSidedContributionToEmptyPool
+            ))
+        )
+    })
+}
+
+#[test]
+pub fn setting_single_sided_contribution_fee_above_one_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18));
+
+    // Act
+    let receipt = test_runner.set_single_sided_contribution_fee(Some(dec!("1.1")), true);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::TwoResourcePoolError(
+                TwoResourcePoolError::InvalidSingleSidedContributionFee { .. }
+            ))
+        )
+    })
+}
+
+#[test]
+pub fn set_single_sided_contribution_fee_fails_without_proper_authority_present() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18));
+
+    // Act
+    let receipt = test_runner.set_single_sided_contribution_fee(Some(Decimal::ZERO), false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error)
+}
+
+#[test]
+pub fn contribute_single_sided_fails_without_proper_authority_present() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18));
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 100),
+            true,
+        )
+        .expect_commit_success();
+    test_runner
+        .set_single_sided_contribution_fee(Some(Decimal::ZERO), true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute_single_sided(test_runner.pool_resource1, 21, false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error)
+}
+
+#[test]
+fn first_contribution_to_pool_escrows_minimum_liquidity() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        (18, 18),
+        PoolContributionProtection::default().escrow_minimum_liquidity(1.into()),
+    );
+
+    // Act
+    let receipt = test_runner.contribute(
+        (test_runner.pool_resource1, 100),
+        (test_runner.pool_resource2, 100),
+        true,
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_eq!(
+        commit_result
+            .balance_changes()
+            .get(&GlobalAddress::from(test_runner.account_component_address))
+            .unwrap()
+            .get(&test_runner.pool_unit_resource_address)
+            .unwrap()
+            .clone(),
+        BalanceChange::Fungible(99.into())
+    );
+
+    test_runner.redeem(99, true).expect_commit_success();
+    assert_eq!(
+        test_runner.get_vault_amounts(true),
+        btreemap!(
+            test_runner.pool_resource1 => dec!("1"),
+            test_runner.pool_resource2 => dec!("1"),
+        )
+    );
+}
+
+#[test]
+fn contribution_below_dust_threshold_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new_with_contribution_protection(
+        (18, 18),
+        PoolContributionProtection::default().dust_threshold(1.into()),
+    );
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 100),
+            true,
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute(
+        (test_runner.pool_resource1, dec!("0.5")),
+        (test_runner.pool_resource2, dec!("0.5")),
+        true,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::TwoResourcePoolError(
+                TwoResourcePoolError::ContributionProtectionError(
+                    ContributionProtectionError::ContributionBelowDustThreshold { .. }
+                )
+            ))
+        )
+    });
+}
+
 struct TestEnvironment {
     test_runner: TestRunner,
 
@@ -915,7 +1188,26 @@ impl TestEnvironment {
         Self::new_with_owner((divisibility1, divisibility2), OwnerRole::None)
     }
 
-    pub fn new_with_owner((divisibility1, divisibility2): (u8, u8), owner_role: OwnerRole) -> Self {
+    pub fn new_with_owner(divisibilities: (u8, u8), owner_role: OwnerRole) -> Self {
+        Self::new_with_owner_and_contribution_protection(divisibilities, owner_role, None)
+    }
+
+    pub fn new_with_contribution_protection(
+        divisibilities: (u8, u8),
+        contribution_protection: PoolContributionProtection,
+    ) -> Self {
+        Self::new_with_owner_and_contribution_protection(
+            divisibilities,
+            OwnerRole::None,
+            Some(contribution_protection),
+        )
+    }
+
+    pub fn new_with_owner_and_contribution_protection(
+        (divisibility1, divisibility2): (u8, u8),
+        owner_role: OwnerRole,
+        contribution_protection: Option<PoolContributionProtection>,
+    ) -> Self {
         let mut test_runner = TestRunner::builder().without_trace().build();
         let (public_key, _, account) = test_runner.new_account(false);
         let virtual_signature_badge = NonFungibleGlobalId::from_public_key(&public_key);
@@ -934,8 +1226,8 @@ impl TestEnvironment {
         );
 
         let (pool_component, pool_unit_resource) = {
-            let manifest = ManifestBuilder::new()
-                .call_function(
+            let manifest = match contribution_protection {
+                None => ManifestBuilder::new().call_function(
                     POOL_PACKAGE,
                     TWO_RESOURCE_POOL_BLUEPRINT_IDENT,
                     TWO_RESOURCE_POOL_INSTANTIATE_IDENT,
@@ -944,8 +1236,15 @@ impl TestEnvironment {
                         pool_manager_rule: rule!(require(virtual_signature_badge)),
                         owner_role,
                     },
-                )
-                .build();
+                ),
+                Some(contribution_protection) => ManifestBuilder::new().create_two_resource_pool(
+                    owner_role,
+                    rule!(require(virtual_signature_badge)),
+                    (pool_resource1, pool_resource2),
+                    contribution_protection,
+                ),
+            }
+            .build();
             let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
             let commit_result = receipt.expect_commit_success();
 
@@ -1070,6 +1369,56 @@ impl TestEnvironment {
         self.execute_manifest(manifest, sign)
     }
 
+    fn contribute_single_sided<D: Into<Decimal>>(
+        &mut self,
+        resource_address: ResourceAddress,
+        amount: D,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .mint_fungible(resource_address, amount.into())
+            .take_all_from_worktop(resource_address, "contribution")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_method(
+                    self.pool_component_address,
+                    TWO_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_IDENT,
+                    TwoResourcePoolContributeSingleSidedManifestInput {
+                        bucket: lookup.bucket("contribution"),
+                    },
+                )
+            })
+            .try_deposit_batch_or_abort(self.account_component_address)
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn set_single_sided_contribution_fee(
+        &mut self,
+        fee: Option<Decimal>,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                TWO_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_IDENT,
+                TwoResourcePoolSetSingleSidedContributionFeeManifestInput { fee },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn get_vault_amounts(&mut self, sign: bool) -> TwoResourcePoolGetVaultAmountsOutput {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                TWO_RESOURCE_POOL_GET_VAULT_AMOUNTS_IDENT,
+                TwoResourcePoolGetVaultAmountsManifestInput {},
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest, sign);
+        receipt.expect_commit_success().output(1)
+    }
+
     fn execute_manifest(
         &mut self,
         manifest: TransactionManifestV1,
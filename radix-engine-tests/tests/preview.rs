@@ -1,3 +1,4 @@
+use radix_engine::system::system_modules::execution_trace::{ResourceSpecifier, WorktopChange};
 use radix_engine::transaction::ExecutionConfig;
 use radix_engine::transaction::FeeReserveConfig;
 use radix_engine::types::*;
@@ -20,6 +21,7 @@ fn test_transaction_preview_cost_estimate() {
         use_free_credit: true,
         assume_all_signature_proofs: false,
         skip_epoch_check: false,
+        ruid_seed: None,
     };
     let (notarized_transaction, preview_intent) = prepare_matching_test_tx_and_preview_intent(
         &mut test_runner,
@@ -62,6 +64,7 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
         use_free_credit: true,
         assume_all_signature_proofs: true,
         skip_epoch_check: false,
+        ruid_seed: None,
     };
 
     // Check method authorization (withdrawal) without a proof in the auth zone
@@ -85,6 +88,62 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().expect_commit_success();
 }
 
+#[test]
+fn test_ruid_seed_flag_makes_previewed_ruids_stable() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+    let seed = Some(hash("preview"));
+
+    // Act: every preview uses a new nonce, so the transaction hashes differ
+    let ids1 = preview_ruid_mint(&mut test_runner, &network, package_address, account, seed);
+    let ids2 = preview_ruid_mint(&mut test_runner, &network, package_address, account, seed);
+    let ids3 = preview_ruid_mint(&mut test_runner, &network, package_address, account, None);
+
+    // Assert
+    assert_eq!(ids1, ids2);
+    assert_ne!(ids1, ids3);
+}
+
+fn preview_ruid_mint(
+    test_runner: &mut TestRunner,
+    network: &NetworkDefinition,
+    package_address: PackageAddress,
+    account: ComponentAddress,
+    ruid_seed: Option<Hash>,
+) -> BTreeSet<NonFungibleLocalId> {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "create_ruid_non_fungible_and_mint",
+            manifest_args!(),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: false,
+        skip_epoch_check: false,
+        ruid_seed,
+    };
+    let (_, preview_intent) =
+        prepare_matching_test_tx_and_preview_intent(test_runner, network, manifest, &preview_flags);
+
+    let receipt = test_runner.preview(preview_intent, network).unwrap();
+    let worktop_changes = receipt
+        .expect_commit_success()
+        .execution_trace
+        .worktop_changes();
+    match worktop_changes.get(&1).unwrap().as_slice() {
+        [WorktopChange::Put(ResourceSpecifier::Ids(_, ids))] => ids.clone(),
+        changes => panic!("Unexpected worktop changes {:?}", changes),
+    }
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &mut TestRunner,
     network: &NetworkDefinition,
@@ -476,6 +476,130 @@ fn can_compose_bucket_and_vault_proof_by_ids() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn can_compose_proof_by_amount_across_vault_and_multiple_buckets() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.new_component(
+        btreeset![NonFungibleGlobalId::from_public_key(&public_key)],
+        |builder| {
+            builder
+                .withdraw_from_account(account, resource_address, 1)
+                .take_all_from_worktop(resource_address, "bucket")
+                .with_name_lookup(|builder, lookup| {
+                    builder.call_function(
+                        package_address,
+                        "VaultProof",
+                        "new",
+                        manifest_args!(lookup.bucket("bucket")),
+                    )
+                })
+        },
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, 5)
+        .take_from_worktop(resource_address, 2, "bucket1")
+        .take_from_worktop(resource_address, 3, "bucket2")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                component_address,
+                "compose_vault_and_buckets_proof_by_amount",
+                manifest_args!(
+                    vec![lookup.bucket("bucket1"), lookup.bucket("bucket2")],
+                    Decimal::from(6u32)
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_compose_proof_by_ids_across_vault_and_multiple_buckets() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.new_component(
+        btreeset![NonFungibleGlobalId::from_public_key(&public_key)],
+        |builder| {
+            builder
+                .withdraw_non_fungibles_from_account(
+                    account,
+                    resource_address,
+                    &btreeset!(NonFungibleLocalId::integer(1)),
+                )
+                .take_all_from_worktop(resource_address, "bucket")
+                .with_name_lookup(|builder, lookup| {
+                    builder.call_function(
+                        package_address,
+                        "VaultProof",
+                        "new",
+                        manifest_args!(lookup.bucket("bucket")),
+                    )
+                })
+        },
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            account,
+            resource_address,
+            &btreeset!(
+                NonFungibleLocalId::integer(2),
+                NonFungibleLocalId::integer(3)
+            ),
+        )
+        .take_non_fungibles_from_worktop(
+            resource_address,
+            &btreeset!(NonFungibleLocalId::integer(2)),
+            "bucket1",
+        )
+        .take_non_fungibles_from_worktop(
+            resource_address,
+            &btreeset!(NonFungibleLocalId::integer(3)),
+            "bucket2",
+        )
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                component_address,
+                "compose_vault_and_buckets_proof_by_ids",
+                manifest_args!(
+                    vec![lookup.bucket("bucket1"), lookup.bucket("bucket2")],
+                    btreeset!(
+                        NonFungibleLocalId::integer(1),
+                        NonFungibleLocalId::integer(2),
+                        NonFungibleLocalId::integer(3)
+                    )
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
 #[test]
 fn can_create_auth_zone_proof_by_amount_from_non_fungibles() {
     // Arrange
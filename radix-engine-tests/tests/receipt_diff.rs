@@ -0,0 +1,51 @@
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn transfer_from_faucet(amount: Decimal) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, amount, "xrd")
+        .try_deposit_or_abort(account, "xrd")
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn identical_executions_have_no_receipt_diff() {
+    // Arrange
+    let receipt1 = transfer_from_faucet(dec!("10"));
+    let receipt2 = transfer_from_faucet(dec!("10"));
+
+    // Act
+    let diff = receipt1.diff(&receipt2);
+
+    // Assert
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[test]
+fn receipt_diff_reports_changed_outcome() {
+    // Arrange
+    let receipt1 = transfer_from_faucet(dec!("10"));
+    let receipt2 = transfer_from_faucet(dec!("1000000"));
+
+    // Act
+    let diff = receipt1.diff(&receipt2);
+
+    // Assert
+    receipt2.expect_commit_failure();
+    let status = diff
+        .differences
+        .iter()
+        .find(|difference| difference.field == "status")
+        .unwrap();
+    assert_eq!(status.expected.as_deref(), Some("COMMITTED SUCCESS"));
+    assert_eq!(status.actual.as_deref(), Some("COMMITTED FAILURE"));
+}
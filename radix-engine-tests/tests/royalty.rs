@@ -1,6 +1,9 @@
 use radix_engine::blueprints::package::PackageError;
-use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
-use radix_engine::system::node_modules::royalty::ComponentRoyaltyError;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
+use radix_engine::system::node_modules::royalty::{
+    ClaimComponentRoyaltyEvent, ComponentRoyaltyError, LockComponentRoyaltyEvent,
+    SetComponentRoyaltyEvent,
+};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -108,6 +111,85 @@ fn test_component_royalty_in_usd() {
     );
 }
 
+#[test]
+fn test_component_royalty_in_usd_uses_updated_usd_price() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/royalty");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_function(
+                package_address,
+                "RoyaltyTest",
+                "create_component_with_royalty_enabled",
+                manifest_args!(),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let component_address: ComponentAddress = receipt.expect_commit(true).output(1);
+    let usd_price_in_xrd = dec!("42");
+    test_runner
+        .execute_validator_transaction(vec![InstructionV1::CallMethod {
+            address: CONSENSUS_MANAGER.into(),
+            method_name: CONSENSUS_MANAGER_UPDATE_USD_PRICE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&ConsensusManagerUpdateUsdPriceInput {
+                usd_price_in_xrd
+            }),
+        }])
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method_usd", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit(true);
+    assert_eq!(
+        commit_result.fee_summary.total_royalty_cost_xrd,
+        dec!(1) * usd_price_in_xrd
+    );
+    assert_eq!(
+        commit_result.fee_summary.usd_price_applied,
+        Some(usd_price_in_xrd)
+    );
+}
+
+#[test]
+fn test_update_usd_price_requires_validator_role() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(
+                CONSENSUS_MANAGER,
+                CONSENSUS_MANAGER_UPDATE_USD_PRICE_IDENT,
+                manifest_args!(dec!("42")),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(..))
+        )
+    });
+}
+
 #[test]
 fn test_package_royalty() {
     let (
@@ -144,6 +226,13 @@ fn test_package_royalty() {
     );
     assert_eq!(package_royalty, dec!("2"));
     assert_eq!(component_royalty, dec!(1));
+    assert_eq!(
+        commit_result.royalty_distributions,
+        vec![
+            (package_address.into(), XRD, dec!("2")),
+            (component_address.into(), XRD, dec!(1)),
+        ]
+    );
 }
 
 #[test]
@@ -458,6 +547,58 @@ fn cannot_set_royalty_after_locking() {
     });
 }
 
+#[test]
+fn component_royalty_mutations_emit_events() {
+    // Arrange
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        _package_address,
+        component_address,
+        owner_badge_resource,
+    ) = set_up_package_and_component();
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_standard_test_fee(account)
+                .call_method(component_address, "paid_method", manifest_args!())
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                owner_badge_resource,
+                &btreeset!(NonFungibleLocalId::integer(1)),
+            )
+            .claim_component_royalties(component_address)
+            .set_component_royalty(component_address, "paid_method", RoyaltyAmount::Free)
+            .lock_component_royalty(component_address, "paid_method")
+            .try_deposit_batch_or_abort(account)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let claim_events = test_runner.extract_events_of_type::<ClaimComponentRoyaltyEvent>(result);
+    assert_eq!(claim_events.len(), 1);
+    assert_eq!(claim_events[0].amount, dec!(1));
+    let set_events = test_runner.extract_events_of_type::<SetComponentRoyaltyEvent>(result);
+    assert_eq!(set_events.len(), 1);
+    assert_eq!(set_events[0].method, "paid_method");
+    assert_eq!(set_events[0].royalty_amount, RoyaltyAmount::Free);
+    let lock_events = test_runner.extract_events_of_type::<LockComponentRoyaltyEvent>(result);
+    assert_eq!(lock_events.len(), 1);
+    assert_eq!(lock_events[0].method, "paid_method");
+}
+
 fn set_up_package_and_component() -> (
     TestRunner,
     ComponentAddress,
@@ -0,0 +1,107 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn create_soulbound_resource(
+    test_runner: &mut TestRunner,
+    owner_key: &Secp256k1PublicKey,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let owner_badge = NonFungibleGlobalId::from_public_key(owner_key);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            true,
+            NonFungibleResourceRoles::soulbound(rule!(require(owner_badge))),
+            metadata!(),
+            Some([
+                (NonFungibleLocalId::integer(1), ()),
+                (NonFungibleLocalId::integer(2), ()),
+            ]),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_resource_addresses()[0]
+}
+
+#[test]
+fn soulbound_non_fungibles_cannot_be_withdrawn_from_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_soulbound_resource(&mut test_runner, &public_key, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            account,
+            resource_address,
+            &btreeset!(NonFungibleLocalId::integer(1)),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+    assert_eq!(
+        test_runner.account_balance(account, resource_address),
+        Some(dec!("2"))
+    );
+}
+
+#[test]
+fn soulbound_non_fungibles_can_be_burned_by_owner() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_soulbound_resource(&mut test_runner, &public_key, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .burn_in_account(account, resource_address, 1)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(account, resource_address),
+        Some(dec!("1"))
+    );
+}
+
+#[test]
+fn soulbound_non_fungibles_cannot_be_burned_without_owner_signature() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (other_public_key, _, _) = test_runner.new_allocated_account();
+    let resource_address = create_soulbound_resource(&mut test_runner, &other_public_key, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .burn_in_account(account, resource_address, 1)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
@@ -17,6 +17,34 @@ const PACKAGE_ADDRESS_PLACE_HOLDER: [u8; NodeId::LENGTH] = [
     172, 105, 67, 234, 38, 49, 140, 99, 24, 198,
 ];
 
+#[test]
+fn test_workspace_packages_are_published_with_dependency_addresses() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_addresses =
+        test_runner.compile_and_publish_workspace("./tests/blueprints/workspace");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_addresses["greeter_caller"],
+            "GreeterCaller",
+            "call_greet",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    assert_eq!(
+        package_addresses.keys().collect::<Vec<_>>(),
+        vec!["greeter", "greeter_caller"]
+    );
+    let greeting: String = receipt.expect_commit_success().output(1);
+    assert_eq!(greeting, "Hello");
+}
+
 #[test]
 fn test_static_package_address() {
     // Arrange
@@ -0,0 +1,72 @@
+use radix_engine::types::*;
+use radix_engine_stores::fault_injecting_db::{CommitFault, ReadFault, SubstateDatabaseFaults};
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn free_xrd_manifest(account: ComponentAddress) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .build()
+}
+
+#[test]
+fn partially_applied_commit_is_not_visible_to_later_transactions() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let balance_before = test_runner.account_balance(account, XRD);
+
+    // Act
+    let receipt = test_runner.execute_manifest_with_substate_db_faults(
+        free_xrd_manifest(account),
+        vec![],
+        SubstateDatabaseFaults::new().fail_commit_at(
+            0,
+            CommitFault::Partial {
+                applied_partitions: 0,
+            },
+        ),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.account_balance(account, XRD), balance_before);
+
+    // The database is still usable afterwards
+    test_runner
+        .execute_manifest(free_xrd_manifest(account), vec![])
+        .expect_commit_success();
+    assert_ne!(test_runner.account_balance(account, XRD), balance_before);
+}
+
+#[test]
+#[should_panic(expected = "Injected IO error on substate database commit 0")]
+fn io_error_on_commit_panics() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    test_runner.execute_manifest_with_substate_db_faults(
+        free_xrd_manifest(account),
+        vec![],
+        SubstateDatabaseFaults::new().fail_commit_at(0, CommitFault::IoError),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Injected IO error on substate database read 0")]
+fn io_error_on_read_panics() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    test_runner.execute_manifest_with_substate_db_faults(
+        free_xrd_manifest(account),
+        vec![],
+        SubstateDatabaseFaults::new().fail_read_at(0, ReadFault::IoError),
+    );
+}
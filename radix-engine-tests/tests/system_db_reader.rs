@@ -0,0 +1,48 @@
+use radix_engine::blueprints::account::ACCOUNT_VAULT_INDEX;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::types::*;
+use radix_engine_interface::api::ObjectModuleId;
+use scrypto_unit::*;
+
+#[test]
+fn can_read_the_fields_of_a_resource_manager() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 12, account);
+
+    // Act
+    let reader = SystemDatabaseReader::new(test_runner.substate_db());
+    let fields = reader
+        .read_object_fields(resource_address.as_node_id(), ObjectModuleId::Main)
+        .unwrap();
+
+    // Assert
+    let divisibility = fields.iter().find(|field| field.index == 0).unwrap();
+    assert_eq!(
+        divisibility.value,
+        scrypto_decode::<ScryptoValue>(&scrypto_encode(&12u8).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn can_read_the_collections_of_an_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let reader = SystemDatabaseReader::new(test_runner.substate_db());
+    let vaults = reader
+        .read_object_collection(
+            account.as_node_id(),
+            ObjectModuleId::Main,
+            ACCOUNT_VAULT_INDEX,
+        )
+        .unwrap();
+
+    // Assert
+    let key = SubstateKey::Map(scrypto_encode(&resource_address).unwrap());
+    assert!(vaults.iter().any(|entry| entry.key == key));
+}
@@ -52,3 +52,26 @@ fn advancing_round_changes_internal_milli_timestamp() {
     // Assert
     assert_eq!(test_runner.get_current_proposer_timestamp_ms(), epoch_milli);
 }
+
+#[test]
+fn setting_proposer_timestamp_changes_app_facing_clocks() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let epoch_seconds_rounded_to_minutes = UtcDateTime::new(2023, 6, 1, 12, 30, 0)
+        .unwrap()
+        .to_instant()
+        .seconds_since_unix_epoch;
+    let epoch_milli = (epoch_seconds_rounded_to_minutes + 42) * 1000 + 5;
+
+    // Act
+    test_runner.set_proposer_timestamp(epoch_milli);
+
+    // Assert
+    assert_eq!(test_runner.get_current_proposer_timestamp_ms(), epoch_milli);
+    assert_eq!(
+        test_runner
+            .get_current_time(TimePrecision::Minute)
+            .seconds_since_unix_epoch,
+        epoch_seconds_rounded_to_minutes
+    );
+}
@@ -146,3 +146,81 @@ fn get_validated(
 
     validator.validate(transaction.prepare().unwrap())
 }
+
+#[test]
+fn transaction_epoch_window_rejections_match_standalone_validation() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let transaction = create_notarized_transaction(
+        TransactionParams {
+            start_epoch_inclusive: Epoch::of(151),
+            end_epoch_exclusive: Epoch::of(155),
+        },
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .clear_auth_zone()
+            .build(),
+    );
+
+    // Act & Assert
+    test_runner
+        .execute_notarized_transaction_at_epoch(&transaction, Epoch::of(150))
+        .expect_rejection();
+    test_runner
+        .execute_notarized_transaction_at_epoch(&transaction, Epoch::of(155))
+        .expect_rejection();
+    test_runner
+        .execute_notarized_transaction_at_epoch(&transaction, Epoch::of(154))
+        .expect_commit_success();
+}
+
+#[test]
+fn intent_message_is_carried_into_commit_result() {
+    // Arrange
+    let mut scrypto_interpreter = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+    };
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    Bootstrapper::new(&mut substate_db, &scrypto_interpreter, true)
+        .bootstrap_test_default()
+        .unwrap();
+
+    let message = MessageV1::Plaintext(PlaintextMessageV1 {
+        mime_type: "text/plain".to_string(),
+        message: MessageContentsV1::String("Invoice #42".to_string()),
+    });
+    let sk_notary = Secp256k1PrivateKey::from_u64(3).unwrap();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: Epoch::zero(),
+            end_epoch_exclusive: Epoch::of(100),
+            nonce: 5,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+        })
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .clear_auth_zone()
+                .build(),
+        )
+        .message(message.clone())
+        .notarize(&sk_notary)
+        .build();
+    let validated = get_validated(&transaction).expect("Invalid transaction");
+
+    // Act
+    let receipt = execute_and_commit_transaction(
+        &mut substate_db,
+        &mut scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::for_test_transaction(),
+        &validated.get_executable(),
+    );
+
+    // Assert
+    assert_eq!(receipt.expect_commit_success().message, message);
+}
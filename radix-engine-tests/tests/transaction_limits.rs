@@ -1,5 +1,5 @@
 use radix_engine::{
-    errors::{RuntimeError, SystemModuleError},
+    errors::{EventError, EventLimit, RuntimeError, SystemModuleError},
     system::system_modules::limits::TransactionLimitsError,
     transaction::{ExecutionConfig, FeeReserveConfig},
     types::*,
@@ -150,12 +150,40 @@ fn test_default_substate_size_limit() {
     // Assert #2
     receipt.expect_specific_failure(|e| match e {
         RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
-            TransactionLimitsError::MaxSubstateSizeExceeded(_),
+            TransactionLimitsError::SubstateSizeExceeded { .. },
         )) => true,
         _ => false,
     })
 }
 
+#[test]
+fn test_lowered_substate_size_limit() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("tests/blueprints/transaction_limits");
+    test_runner.set_max_substate_size(Some(1_000));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitSubstateTest",
+            "write_large_value",
+            manifest_args!(2_000usize),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+            TransactionLimitsError::SubstateSizeExceeded { actual, limit, .. },
+        )) => *actual > 2_000 && *limit == 1_000,
+        _ => false,
+    })
+}
+
 #[test]
 fn test_default_invoke_payload_size_limit() {
     let mut overhead = Vec::new();
@@ -276,9 +304,55 @@ fn verify_event_size_limit() {
     receipt.expect_specific_failure(|e| {
         matches!(
             e,
-            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
-                TransactionLimitsError::EventSizeTooLarge { .. }
-            ),)
+            RuntimeError::SystemModuleError(SystemModuleError::EventError(e))
+                if matches!(**e, EventError::LimitExceeded {
+                    limit: EventLimit::EventPayloadSize,
+                    ..
+                })
+        )
+    })
+}
+
+#[test]
+fn verify_total_event_size_limit() {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transaction_limits");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "emit_event_of_size",
+            manifest_args!(1024usize),
+        )
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "emit_event_of_size",
+            manifest_args!(1024usize),
+        )
+        .build();
+    let transactions = TestTransaction::new_from_nonce(manifest, 10);
+    let prepared = transactions.prepare().unwrap();
+    let receipt = test_runner.execute_transaction(
+        prepared.get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_test_transaction().with_event_limits(
+            DEFAULT_MAX_NUMBER_OF_EVENTS,
+            DEFAULT_MAX_EVENT_SIZE,
+            1500,
+        ),
+    );
+
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::EventError(e))
+                if matches!(**e, EventError::LimitExceeded {
+                    limit: EventLimit::TotalEventSize,
+                    ..
+                })
         )
     })
 }
@@ -307,3 +381,281 @@ fn verify_panic_size_limit() {
         )
     })
 }
+
+fn execute_with_substate_limits(
+    test_runner: &mut TestRunner,
+    method_name: &str,
+    count: u32,
+    execution_config: ExecutionConfig,
+) -> TransactionReceipt {
+    let package_address = test_runner.compile_and_publish("tests/blueprints/transaction_limits");
+    let component_address = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    package_address,
+                    "TransactionLimitTest",
+                    "new",
+                    manifest_args!(),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, method_name, manifest_args!(count))
+        .build();
+    let transactions = TestTransaction::new_from_nonce(manifest, 10);
+    let prepared = transactions.prepare().unwrap();
+    test_runner.execute_transaction(
+        prepared.get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn substate_usage_is_reported_in_fee_summary() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_with_substate_limits(
+        &mut test_runner,
+        "write_kv_stores",
+        10,
+        ExecutionConfig::for_test_transaction(),
+    );
+
+    // Assert
+    let substate_usage = receipt.expect_commit_success().fee_summary.substate_usage;
+    assert!(substate_usage.read_count > 0);
+    assert!(substate_usage.read_size > 0);
+    assert!(substate_usage.write_count >= 10);
+    assert!(substate_usage.write_size > 0);
+}
+
+#[test]
+fn transaction_limit_exceeded_substate_reads_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_with_substate_limits(
+        &mut test_runner,
+        "read_kv_stores",
+        50,
+        ExecutionConfig::for_test_transaction().with_substate_limits(
+            20,
+            DEFAULT_MAX_NUMBER_OF_SUBSTATE_WRITES,
+            DEFAULT_MAX_TOTAL_SUBSTATE_READ_SIZE,
+            DEFAULT_MAX_TOTAL_SUBSTATE_WRITE_SIZE,
+        ),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TooManySubstateReads { max: 20 }
+            ))
+        )
+    });
+}
+
+#[test]
+fn transaction_limit_exceeded_substate_write_size_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_with_substate_limits(
+        &mut test_runner,
+        "write_kv_stores",
+        50,
+        ExecutionConfig::for_test_transaction().with_substate_limits(
+            DEFAULT_MAX_NUMBER_OF_SUBSTATE_READS,
+            DEFAULT_MAX_NUMBER_OF_SUBSTATE_WRITES,
+            DEFAULT_MAX_TOTAL_SUBSTATE_READ_SIZE,
+            1024,
+        ),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::SubstateWriteSizeTooLarge { max: 1024, .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn transaction_limit_exceeded_heap_nodes_per_call_frame_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("tests/blueprints/transaction_limits");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "create_empty_buckets",
+            manifest_args!(50u32),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, 10)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_test_transaction()
+            .with_heap_limits(20, DEFAULT_MAX_HEAP_SUBSTATE_SIZE_PER_CALL_FRAME),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TooManyHeapNodes { max: 20, .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn transaction_limit_exceeded_heap_substate_size_per_call_frame_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("tests/blueprints/transaction_limits");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "create_empty_buckets",
+            manifest_args!(50u32),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, 10)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_test_transaction()
+            .with_heap_limits(DEFAULT_MAX_HEAP_NODES_PER_CALL_FRAME, 1024),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::HeapSubstateSizeTooLarge { max: 1024, .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn heap_peak_usage_is_reported_when_tracing() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("tests/blueprints/transaction_limits");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "create_empty_buckets",
+            manifest_args!(10u32),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.preview_manifest(manifest, vec![], 0, PreviewFlags::default());
+
+    // Assert
+    let heap_peak_usage = receipt
+        .expect_commit_success()
+        .execution_trace
+        .heap_peak_usage;
+    assert!(heap_peak_usage.node_count >= 10);
+    assert!(heap_peak_usage.substate_size > 0);
+}
+
+#[test]
+fn transaction_limit_exceeded_wasm_invocations_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("tests/blueprints/transaction_limits");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "recursive_with_memory",
+            manifest_args!(5u32, 0usize),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, 11)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_test_transaction().with_max_wasm_invocations(3),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TooManyWasmInvocations { max: 3 }
+            ))
+        )
+    });
+}
+
+#[test]
+fn wasm_invocation_count_is_reported() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("tests/blueprints/transaction_limits");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "recursive_with_memory",
+            manifest_args!(5u32, 0usize),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let wasm_invocation_count = receipt
+        .expect_commit_success()
+        .fee_summary
+        .wasm_invocation_count;
+    assert!(wasm_invocation_count >= 5);
+}
@@ -1,7 +1,9 @@
 #[cfg(not(feature = "alloc"))]
 mod multi_threaded_test {
     use radix_engine::system::bootstrap::Bootstrapper;
-    use radix_engine::transaction::{execute_and_commit_transaction, execute_transaction};
+    use radix_engine::transaction::{
+        execute_and_commit_transaction, execute_transaction, ConcurrentPreviewExecutor,
+    };
     use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
     use radix_engine::types::*;
     use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
@@ -16,6 +18,7 @@ mod multi_threaded_test {
     extern crate crossbeam;
     use crossbeam::thread;
     use radix_engine::vm::ScryptoVm;
+    use sbor::rust::sync::Arc;
 
     // this test was inspired by radix_engine "Transfer" benchmark
     #[test]
@@ -116,4 +119,86 @@ mod multi_threaded_test {
         })
         .unwrap();
     }
+
+    fn preview_intent(manifest: TransactionManifestV1, nonce: u32) -> PreviewIntentV1 {
+        PreviewIntentV1 {
+            intent: IntentV1 {
+                header: TransactionHeaderV1 {
+                    network_id: NetworkDefinition::simulator().id,
+                    start_epoch_inclusive: Epoch::zero(),
+                    end_epoch_exclusive: Epoch::of(99),
+                    nonce,
+                    notary_public_key: PublicKey::Secp256k1(Secp256k1PublicKey([0u8; 33])),
+                    notary_is_signatory: false,
+                    tip_percentage: 0,
+                },
+                instructions: InstructionsV1(manifest.instructions),
+                blobs: BlobsV1 { blobs: vec![] },
+                message: MessageV1::default(),
+            },
+            signer_public_keys: vec![],
+            flags: PreviewFlags {
+                use_free_credit: true,
+                assume_all_signature_proofs: true,
+                skip_epoch_check: true,
+                ruid_seed: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_concurrent_previews_share_state_without_observing_each_other() {
+        // Set up environment.
+        let scrypto_interpreter = ScryptoVm {
+            wasm_engine: DefaultWasmEngine::default(),
+            wasm_validator_config: WasmValidatorConfigV1::new(),
+        };
+        let mut substate_db = InMemorySubstateDatabase::standard();
+        Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false)
+            .bootstrap_test_default()
+            .unwrap();
+        let initial_state = substate_db.clone();
+        let executor = ConcurrentPreviewExecutor::new(
+            Arc::new(substate_db),
+            Arc::new(scrypto_interpreter),
+            NetworkDefinition::simulator(),
+        );
+
+        let create_account = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .new_account_advanced(OwnerRole::None)
+            .build();
+
+        // Every thread previews the account creation, followed by a deposit into the new account,
+        // which only succeeds if the account creation was staged in the thread's session
+        thread::scope(|s| {
+            for i in 0..20 {
+                let executor = executor.clone();
+                let create_account = create_account.clone();
+                s.spawn(move |_| {
+                    let mut session = executor.session();
+                    let receipt = session
+                        .preview(preview_intent(create_account, i), false)
+                        .unwrap();
+                    let account = receipt.expect_commit_success().new_component_addresses()[0];
+
+                    let deposit = ManifestBuilder::new()
+                        .lock_fee_from_faucet()
+                        .get_free_xrd_from_faucet()
+                        .try_deposit_batch_or_abort(account)
+                        .build();
+                    session
+                        .preview(preview_intent(deposit.clone(), i), false)
+                        .unwrap()
+                        .expect_commit_success();
+                    let receipt = executor.preview(preview_intent(deposit, i), false).unwrap();
+                    assert!(!receipt.is_commit_success());
+                });
+            }
+        })
+        .unwrap();
+
+        // The shared state is never written to
+        assert!(executor.substate_db() == &initial_state);
+    }
 }
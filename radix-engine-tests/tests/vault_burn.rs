@@ -1027,6 +1027,25 @@ fn can_burn_by_ids_from_non_fungible_account_vault() {
     )
 }
 
+#[test]
+fn cant_burn_from_vault_without_the_burner_role() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_account(false);
+    let resource_address = test_runner.create_recallable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, resource_address)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .burn_from_vault(InternalAddress::new_or_panic(vault_id.into()), 1)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_unauthorized_error);
+}
+
 fn get_vault_id(test_runner: &mut TestRunner, component_address: ComponentAddress) -> NodeId {
     let manifest = ManifestBuilder::new()
         .call_method(component_address, "vault_id", manifest_args!())
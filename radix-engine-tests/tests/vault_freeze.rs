@@ -1,4 +1,6 @@
-use radix_engine::blueprints::resource::{NonFungibleResourceManagerError, VaultError};
+use radix_engine::blueprints::resource::{
+    BurnFromVaultEvent, NonFungibleResourceManagerError, VaultError,
+};
 use radix_engine::errors::{ApplicationError, RuntimeError};
 use radix_engine::types::*;
 use scrypto::prelude::FromPublicKey;
@@ -132,6 +134,40 @@ fn can_recall_from_frozen_vault() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn can_burn_from_frozen_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_account(true);
+    let token_address = test_runner.create_freezeable_token(account);
+    let vaults = test_runner.get_component_vaults(account, token_address);
+    let vault_id = vaults[0];
+    let vault_address = InternalAddress::new_or_panic(vault_id.into());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .freeze_withdraw(vault_address)
+        .freeze_burn(vault_address)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .burn_from_vault(vault_address, 1)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert!(commit.application_events.iter().any(|(identifier, data)| {
+        test_runner.is_event_name_equal::<BurnFromVaultEvent>(identifier)
+            && scrypto_decode::<BurnFromVaultEvent>(data).unwrap()
+                == BurnFromVaultEvent::Amount(1.into())
+    }));
+    assert_eq!(test_runner.inspect_vault_balance(vault_id), Some(4.into()));
+}
+
 #[test]
 fn can_withdraw_from_unfrozen_vault() {
     // Arrange
@@ -1,7 +1,7 @@
 use radix_engine::{
     errors::{RuntimeError, VmError},
     types::*,
-    vm::wasm::{WasmRuntimeError, DEFAULT_MAX_MEMORY_SIZE_IN_PAGES},
+    vm::wasm::{WasmRuntimeError, WasmValidatorConfigV1, DEFAULT_MAX_MEMORY_SIZE_IN_PAGES},
 };
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -122,6 +122,31 @@ fn test_recursion_stack_overflow() {
     receipt.expect_specific_failure(is_wasm_error)
 }
 
+#[test]
+fn test_recursion_stack_overflow_with_lowered_max_stack_size() {
+    // Arrange
+    let mut test_runner = TestRunner::builder()
+        .with_wasm_validator_config(WasmValidatorConfigV1::new().with_max_stack_size(512))
+        .build();
+
+    // Act
+    let code = wat2wasm(&include_str!("wasm/recursion.wat").replace("${n}", "256"));
+    let package_address = test_runner.publish_package(
+        code,
+        single_function_package_definition("Test", "f"),
+        BTreeMap::new(),
+        OwnerRole::None,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Test", "f", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(is_wasm_error)
+}
+
 #[test]
 fn test_grow_memory_within_limit() {
     // Arrange
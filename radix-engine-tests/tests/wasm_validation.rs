@@ -1,5 +1,12 @@
-use radix_engine::vm::wasm::{InvalidMemory, PrepareError, WasmValidator};
+use radix_engine::blueprints::package::PackageError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::{
+    InvalidMemory, InvalidTable, PrepareError, WasmValidator, WasmValidatorConfigV1,
+};
 use scrypto_unit::*;
+use transaction::prelude::*;
 
 #[test]
 fn test_large_data() {
@@ -26,3 +33,136 @@ fn test_large_memory() {
         result
     );
 }
+
+#[test]
+fn publishing_a_package_with_too_many_globals_fails() {
+    // Arrange
+    let config = WasmValidatorConfigV1::new().with_max_number_of_globals(1);
+
+    // Act
+    let receipt = publish_with_config(
+        config,
+        r#"
+            (global $g1 i32 (i32.const 0))
+            (global $g2 i32 (i32.const 1))
+        "#,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| is_invalid_wasm_error(e, PrepareError::TooManyGlobals));
+}
+
+#[test]
+fn publishing_a_package_with_too_many_functions_fails() {
+    // Arrange
+    let config = WasmValidatorConfigV1::new().with_max_number_of_functions(1);
+
+    // Act
+    let receipt = publish_with_config(config, r#"(func $unused)"#);
+
+    // Assert
+    receipt.expect_specific_failure(|e| is_invalid_wasm_error(e, PrepareError::TooManyFunctions));
+}
+
+#[test]
+fn publishing_a_package_with_too_large_initial_memory_fails() {
+    // Arrange
+    let config = WasmValidatorConfigV1::new().with_max_initial_memory_size_in_pages(0);
+
+    // Act
+    let receipt = publish_with_config(config, "");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_invalid_wasm_error(
+            e,
+            PrepareError::InvalidMemory(InvalidMemory::InitialMemorySizeLimitExceeded),
+        )
+    });
+}
+
+#[test]
+fn publishing_a_package_with_too_large_table_fails() {
+    // Arrange
+    let config = WasmValidatorConfigV1::new().with_max_initial_table_size(1);
+
+    // Act
+    let receipt = publish_with_config(config, r#"(table 2 funcref)"#);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_invalid_wasm_error(
+            e,
+            PrepareError::InvalidTable(InvalidTable::InitialTableSizeLimitExceeded),
+        )
+    });
+}
+
+#[test]
+fn publishing_a_package_within_the_default_limits_succeeds() {
+    // Act
+    let receipt = publish_with_config(
+        WasmValidatorConfigV1::new(),
+        r#"
+            (global $g1 i32 (i32.const 0))
+            (global $g2 i32 (i32.const 1))
+            (func $unused)
+            (table 2 funcref)
+        "#,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+fn publish_with_config(config: WasmValidatorConfigV1, extra_sections: &str) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder()
+        .with_wasm_validator_config(config)
+        .build();
+    let code = wat2wasm(&format!(
+        r#"
+            (module
+                {}
+                (func $Test_f (param $0 i64) (result i64)
+                    ;; Encode () in SBOR at address 0x0
+                    (i32.const 0)
+                    (i32.const 92)  ;; prefix
+                    (i32.store8)
+                    (i32.const 1)
+                    (i32.const 33)  ;; tuple value kind
+                    (i32.store8)
+                    (i32.const 2)
+                    (i32.const 0)  ;; tuple length
+                    (i32.store8)
+
+                    ;; Return slice (ptr = 0, len = 3)
+                    (i64.const 3)
+                )
+                (memory $0 1)
+                (export "memory" (memory $0))
+                (export "Test_f" (func $Test_f))
+            )
+        "#,
+        extra_sections
+    ));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_advanced(
+            None,
+            code,
+            single_function_package_definition("Test", "f"),
+            BTreeMap::new(),
+            OwnerRole::None,
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn is_invalid_wasm_error(e: &RuntimeError, expected: PrepareError) -> bool {
+    matches!(
+        e,
+        RuntimeError::ApplicationError(ApplicationError::PackageError(PackageError::InvalidWasm(
+            error
+        ))) if *error == expected
+    )
+}
@@ -1,3 +1,6 @@
+use super::{
+    RemoveResourcePreferenceEvent, SetDefaultDepositRuleEvent, SetResourcePreferenceEvent,
+};
 use crate::blueprints::util::{PresecurifiedAccessRules, SecurifiedAccessRules};
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
@@ -9,6 +12,7 @@ use native_sdk::resource::NativeBucket;
 use native_sdk::resource::NativeFungibleVault;
 use native_sdk::resource::NativeNonFungibleVault;
 use native_sdk::resource::NativeVault;
+use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::field_lock_api::LockFlags;
 use radix_engine_interface::api::node_modules::metadata::*;
 use radix_engine_interface::api::object_api::ObjectModuleId;
@@ -428,6 +432,26 @@ impl AccountBlueprint {
         Ok(bucket)
     }
 
+    pub fn withdraw_batch<Y>(
+        resources: Vec<ResourceSpecifier>,
+        api: &mut Y,
+    ) -> Result<Vec<Bucket>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        resources
+            .into_iter()
+            .map(|resource| match resource {
+                ResourceSpecifier::Amount(resource_address, amount) => {
+                    Self::withdraw(resource_address, amount, api)
+                }
+                ResourceSpecifier::Ids(resource_address, ids) => {
+                    Self::withdraw_non_fungibles(resource_address, ids, api)
+                }
+            })
+            .collect()
+    }
+
     pub fn burn<Y>(
         resource_address: ResourceAddress,
         amount: Decimal,
@@ -538,6 +562,41 @@ impl AccountBlueprint {
         Ok(proof)
     }
 
+    pub fn balances<Y>(
+        resources: Vec<ResourceAddress>,
+        api: &mut Y,
+    ) -> Result<Vec<(ResourceAddress, Decimal)>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let mut balances = Vec::new();
+        for resource_address in resources {
+            let amount =
+                Self::get_vault_if_exists(resource_address, |vault, api| vault.amount(api), api)?
+                    .unwrap_or_default();
+            balances.push((resource_address, amount));
+        }
+
+        Ok(balances)
+    }
+
+    pub fn non_fungible_ids_of<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<BTreeSet<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let ids = Self::get_vault_if_exists(
+            resource_address,
+            |vault, api| vault.non_fungible_local_ids(api),
+            api,
+        )?
+        .unwrap_or_default();
+
+        Ok(ids)
+    }
+
     pub fn change_account_default_deposit_rule<Y>(
         default_deposit_rule: AccountDefaultDepositRule,
         api: &mut Y,
@@ -549,11 +608,18 @@ impl AccountBlueprint {
         let handle = api.actor_open_field(OBJECT_HANDLE_SELF, substate_key, LockFlags::MUTABLE)?;
         let mut account = api.field_lock_read_typed::<AccountSubstate>(handle)?;
 
-        account.default_deposit_rule = default_deposit_rule;
+        account.default_deposit_rule = default_deposit_rule.clone();
 
         api.field_lock_write_typed(handle, account)?;
         api.field_lock_release(handle)?;
 
+        Runtime::emit_event(
+            api,
+            SetDefaultDepositRuleEvent {
+                default_deposit_rule,
+            },
+        )?;
+
         Ok(())
     }
 
@@ -582,6 +648,14 @@ impl AccountBlueprint {
                 )?;
 
                 api.key_value_entry_release(kv_store_entry_lock_handle)?;
+
+                Runtime::emit_event(
+                    api,
+                    SetResourcePreferenceEvent {
+                        resource_address,
+                        preference: resource_deposit_configuration,
+                    },
+                )?;
             }
             ResourceDepositRule::Neither => {
                 api.actor_remove_key_value_entry(
@@ -589,11 +663,23 @@ impl AccountBlueprint {
                     ACCOUNT_RESOURCE_DEPOSIT_CONFIGURATION_INDEX,
                     &encoded_key,
                 )?;
+
+                Runtime::emit_event(api, RemoveResourcePreferenceEvent { resource_address })?;
             }
         };
         Ok(())
     }
 
+    pub fn get_resource_preference<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<ResourceDepositRule, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::get_resource_deposit_configuration(&resource_address, api)
+    }
+
     fn get_account_default_deposit_rule<Y>(
         api: &mut Y,
     ) -> Result<AccountDefaultDepositRule, RuntimeError>
@@ -668,6 +754,38 @@ impl AccountBlueprint {
         }
     }
 
+    /// Applies the given function to the vault of the given resource, or returns `None` if the
+    /// account has no such vault. Unlike `get_vault`, the vault is never created.
+    fn get_vault_if_exists<F, Y, R>(
+        resource_address: ResourceAddress,
+        vault_fn: F,
+        api: &mut Y,
+    ) -> Result<Option<R>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&Vault, &mut Y) -> Result<R, RuntimeError>,
+    {
+        let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
+
+        let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            OBJECT_HANDLE_SELF,
+            ACCOUNT_VAULT_INDEX,
+            &encoded_key,
+            LockFlags::read_only(),
+        )?;
+
+        let entry: AccountVaultIndexEntry =
+            api.key_value_entry_get_typed(kv_store_entry_lock_handle)?;
+        let rtn = match entry {
+            Option::Some(own) => Some(vault_fn(&Vault(own), api)?),
+            Option::None => None,
+        };
+
+        api.key_value_entry_release(kv_store_entry_lock_handle)?;
+
+        Ok(rtn)
+    }
+
     fn is_deposit_allowed<Y>(
         resource_address: &ResourceAddress,
         api: &mut Y,
@@ -0,0 +1,19 @@
+use crate::types::*;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+use radix_engine_interface::blueprints::account::*;
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct SetDefaultDepositRuleEvent {
+    pub default_deposit_rule: AccountDefaultDepositRule,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct SetResourcePreferenceEvent {
+    pub resource_address: ResourceAddress,
+    pub preference: ResourceDepositRule,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RemoveResourcePreferenceEvent {
+    pub resource_address: ResourceAddress,
+}
@@ -0,0 +1,258 @@
+use super::*;
+use crate::errors::*;
+use native_sdk::account::Account;
+use native_sdk::modules::access_rules::*;
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_interface::api::node_modules::auth::RoleDefinition;
+use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::types::*;
+use radix_engine_interface::*;
+
+pub const ACCOUNT_LOCKER_STORER_ROLE: &'static str = "storer";
+pub const ACCOUNT_LOCKER_RECOVERER_ROLE: &'static str = "recoverer";
+
+pub const ACCOUNT_LOCKER_VAULT_INDEX: CollectionIndex = 0u8;
+
+/// The vaults of an account locker, keyed by the claimant account and the resource.
+pub type AccountLockerVaultIndexEntry = Option<Own>;
+
+/// Holds resources on behalf of claimant accounts which could not (or were not intended to)
+/// receive them directly, e.g. airdrops to accounts which reject unknown resources. Claimants
+/// can claim the resources into their account at any time, and the recoverer can take back
+/// anything which hasn't been claimed.
+pub struct AccountLockerBlueprint;
+
+impl AccountLockerBlueprint {
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        storer_rule: AccessRule,
+        recoverer_rule: AccessRule,
+        api: &mut Y,
+    ) -> Result<AccountLockerInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let access_rules = AccessRules::create(
+            owner_role,
+            btreemap! {
+                ObjectModuleId::Main => roles_init! {
+                    RoleKey { key: ACCOUNT_LOCKER_STORER_ROLE.to_owned() } => storer_rule;
+                    RoleKey { key: ACCOUNT_LOCKER_RECOVERER_ROLE.to_owned() } => recoverer_rule;
+                }
+            },
+            api,
+        )?
+        .0;
+        let metadata = Metadata::create(api)?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = api.new_simple_object(ACCOUNT_LOCKER_BLUEPRINT, vec![])?;
+
+        let address = api.globalize(
+            btreemap!(
+                ObjectModuleId::Main => object_id,
+                ObjectModuleId::AccessRules => access_rules.0,
+                ObjectModuleId::Metadata => metadata.0,
+                ObjectModuleId::Royalty => royalty.0,
+            ),
+            None,
+        )?;
+
+        Ok(ComponentAddress::new_or_panic(address.as_node_id().0))
+    }
+
+    /// Stores the bucket for the claimant. If `try_direct_send` is set, the resources are first
+    /// offered to the claimant account and only stored if the account refuses the deposit.
+    pub fn store<Y>(
+        claimant: ComponentAddress,
+        bucket: Bucket,
+        try_direct_send: bool,
+        api: &mut Y,
+    ) -> Result<AccountLockerStoreOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let bucket = if try_direct_send {
+            let rtn = api.call_method(
+                claimant.as_node_id(),
+                ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT,
+                scrypto_encode(&AccountTryDepositOrRefundInput { bucket }).unwrap(),
+            )?;
+            let refund: AccountTryDepositOrRefundOutput = scrypto_decode(&rtn).unwrap();
+            match refund {
+                Some(bucket) => bucket,
+                None => return Ok(()),
+            }
+        } else {
+            bucket
+        };
+
+        let resource_address = bucket.resource_address(api)?;
+        let amount = bucket.amount(api)?;
+        Self::with_vault(
+            claimant,
+            resource_address,
+            |vault, api| vault.put(bucket, api),
+            true,
+            api,
+        )?;
+
+        Runtime::emit_event(
+            api,
+            StoreEvent {
+                claimant,
+                resource_address,
+                amount,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Moves stored resources into the claimant account. The deposit is made through the
+    /// account's owner-protected `deposit` method, so the claimant account's owner must have
+    /// authorized the transaction.
+    pub fn claim<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<AccountLockerClaimOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let bucket = Self::with_vault(
+            claimant,
+            resource_address,
+            |vault, api| vault.take(amount, api),
+            false,
+            api,
+        )?;
+        Account(claimant).deposit(bucket, api)?;
+
+        Runtime::emit_event(
+            api,
+            ClaimEvent {
+                claimant,
+                resource_address,
+                amount,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn recover<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<AccountLockerRecoverOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let bucket = Self::with_vault(
+            claimant,
+            resource_address,
+            |vault, api| vault.take(amount, api),
+            false,
+            api,
+        )?;
+
+        Runtime::emit_event(
+            api,
+            RecoverEvent {
+                claimant,
+                resource_address,
+                amount,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn get_amount<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<AccountLockerGetAmountOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let amount = Self::with_vault(
+            claimant,
+            resource_address,
+            |vault, api| vault.amount(api),
+            false,
+            api,
+        );
+
+        match amount {
+            Ok(amount) => Ok(amount),
+            Err(RuntimeError::ApplicationError(ApplicationError::AccountLockerError(
+                AccountLockerError::NothingStoredForClaimant { .. },
+            ))) => Ok(Decimal::ZERO),
+            Err(error) => Err(error),
+        }
+    }
+
+    //===================
+    // Utility Functions
+    //===================
+
+    fn with_vault<F, Y, R>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        vault_fn: F,
+        create: bool,
+        api: &mut Y,
+    ) -> Result<R, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&mut Vault, &mut Y) -> Result<R, RuntimeError>,
+    {
+        let encoded_key = scrypto_encode(&(claimant, resource_address)).expect("Impossible Case!");
+
+        let handle = api.actor_open_key_value_entry(
+            OBJECT_HANDLE_SELF,
+            ACCOUNT_LOCKER_VAULT_INDEX,
+            &encoded_key,
+            if create {
+                LockFlags::MUTABLE
+            } else {
+                LockFlags::read_only()
+            },
+        )?;
+
+        let entry: AccountLockerVaultIndexEntry = api.key_value_entry_get_typed(handle)?;
+        let mut vault = match entry {
+            Some(own) => Vault(own),
+            None => {
+                if create {
+                    let vault = Vault::create(resource_address, api)?;
+                    api.key_value_entry_set_typed(handle, &vault.0)?;
+                    vault
+                } else {
+                    api.key_value_entry_release(handle)?;
+                    return Err(AccountLockerError::NothingStoredForClaimant {
+                        claimant,
+                        resource_address,
+                    }
+                    .into());
+                }
+            }
+        };
+
+        let rtn = vault_fn(&mut vault, api)?;
+        api.key_value_entry_release(handle)?;
+
+        Ok(rtn)
+    }
+}
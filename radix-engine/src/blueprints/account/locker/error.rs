@@ -0,0 +1,18 @@
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use radix_engine_common::types::*;
+use radix_engine_common::ScryptoSbor;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum AccountLockerError {
+    NothingStoredForClaimant {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+    },
+}
+
+impl From<AccountLockerError> for RuntimeError {
+    fn from(error: AccountLockerError) -> Self {
+        Self::ApplicationError(ApplicationError::AccountLockerError(error))
+    }
+}
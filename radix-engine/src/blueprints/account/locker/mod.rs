@@ -0,0 +1,7 @@
+mod blueprint;
+mod error;
+mod events;
+
+pub use blueprint::*;
+pub use error::*;
+pub use events::*;
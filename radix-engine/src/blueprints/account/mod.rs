@@ -1,5 +1,9 @@
 mod blueprint;
+mod events;
+mod locker;
 mod package;
 
 pub use blueprint::*;
+pub use events::*;
+pub use locker::*;
 pub use package::*;
@@ -1,7 +1,11 @@
 use super::AccountSubstate;
-use crate::blueprints::account::{AccountBlueprint, SECURIFY_ROLE};
+use crate::blueprints::account::{
+    AccountBlueprint, AccountLockerBlueprint, ACCOUNT_LOCKER_RECOVERER_ROLE,
+    ACCOUNT_LOCKER_STORER_ROLE, SECURIFY_ROLE,
+};
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use crate::event_schema;
 use crate::roles_template;
 use crate::types::*;
 use native_sdk::runtime::Runtime;
@@ -13,9 +17,9 @@ use radix_engine_interface::blueprints::package::{
     PackageDefinition,
 };
 use radix_engine_interface::schema::{
-    BlueprintCollectionSchema, BlueprintEventSchemaInit, BlueprintFunctionsSchemaInit,
-    BlueprintKeyValueStoreSchema, BlueprintSchemaInit, BlueprintStateSchemaInit, FieldSchema,
-    FunctionSchemaInit, ReceiverInfo, TypeRef,
+    BlueprintCollectionSchema, BlueprintFunctionsSchemaInit, BlueprintKeyValueStoreSchema,
+    BlueprintSchemaInit, BlueprintStateSchemaInit, FieldSchema, FunctionSchemaInit, ReceiverInfo,
+    TypeRef,
 };
 
 const ACCOUNT_CREATE_VIRTUAL_SECP256K1_EXPORT_NAME: &str = "create_virtual_secp256k1";
@@ -183,6 +187,20 @@ impl AccountNativePackage {
             },
         );
 
+        functions.insert(
+            ACCOUNT_WITHDRAW_BATCH_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountWithdrawBatchInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountWithdrawBatchOutput>(),
+                ),
+                export: ACCOUNT_WITHDRAW_BATCH_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_BURN_IDENT.to_string(),
             FunctionSchemaInit {
@@ -211,6 +229,34 @@ impl AccountNativePackage {
             },
         );
 
+        functions.insert(
+            ACCOUNT_BALANCES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountBalancesInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountBalancesOutput>(),
+                ),
+                export: ACCOUNT_BALANCES_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountNonFungibleIdsOfInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountNonFungibleIdsOfOutput>(),
+                ),
+                export: ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT.to_string(),
             FunctionSchemaInit {
@@ -296,6 +342,22 @@ impl AccountNativePackage {
             },
         );
 
+        functions.insert(
+            ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountGetResourcePreferenceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountGetResourcePreferenceOutput>(),
+                ),
+                export: ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT.to_string(),
             FunctionSchemaInit {
@@ -361,7 +423,161 @@ impl AccountNativePackage {
             ACCOUNT_CREATE_VIRTUAL_ED25519_ID => ACCOUNT_CREATE_VIRTUAL_ED25519_EXPORT_NAME.to_string(),
         );
 
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                super::SetDefaultDepositRuleEvent,
+                super::SetResourcePreferenceEvent,
+                super::RemoveResourcePreferenceEvent
+            ]
+        };
+
         let schema = generate_full_schema(aggregator);
+
+        // Account Locker
+        let account_locker_blueprint = {
+            let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+
+            let fields = Vec::new();
+
+            let mut collections = Vec::new();
+            collections.push(BlueprintCollectionSchema::KeyValueStore(
+                BlueprintKeyValueStoreSchema {
+                    key: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<(ComponentAddress, ResourceAddress)>(
+                            ),
+                    ),
+                    value: TypeRef::Static(aggregator.add_child_type_and_descendents::<Own>()),
+                    can_own: true,
+                },
+            ));
+
+            let mut functions = BTreeMap::new();
+
+            functions.insert(
+                ACCOUNT_LOCKER_INSTANTIATE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: None,
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountLockerInstantiateInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountLockerInstantiateOutput>(),
+                    ),
+                    export: ACCOUNT_LOCKER_INSTANTIATE_EXPORT_NAME.to_string(),
+                },
+            );
+
+            functions.insert(
+                ACCOUNT_LOCKER_STORE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerStoreInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerStoreOutput>(),
+                    ),
+                    export: ACCOUNT_LOCKER_STORE_EXPORT_NAME.to_string(),
+                },
+            );
+
+            functions.insert(
+                ACCOUNT_LOCKER_CLAIM_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerClaimInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerClaimOutput>(),
+                    ),
+                    export: ACCOUNT_LOCKER_CLAIM_EXPORT_NAME.to_string(),
+                },
+            );
+
+            functions.insert(
+                ACCOUNT_LOCKER_RECOVER_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerRecoverInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerRecoverOutput>(),
+                    ),
+                    export: ACCOUNT_LOCKER_RECOVER_EXPORT_NAME.to_string(),
+                },
+            );
+
+            functions.insert(
+                ACCOUNT_LOCKER_GET_AMOUNT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerGetAmountInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountLockerGetAmountOutput>(),
+                    ),
+                    export: ACCOUNT_LOCKER_GET_AMOUNT_EXPORT_NAME.to_string(),
+                },
+            );
+
+            let event_schema = event_schema! {
+                aggregator,
+                [
+                    super::StoreEvent,
+                    super::ClaimEvent,
+                    super::RecoverEvent
+                ]
+            };
+
+            let schema = generate_full_schema(aggregator);
+
+            BlueprintDefinitionInit {
+                blueprint_type: BlueprintType::default(),
+                feature_set: btreeset!(),
+                dependencies: btreeset!(),
+
+                schema: BlueprintSchemaInit {
+                    generics: vec![],
+                    schema,
+                    state: BlueprintStateSchemaInit {
+                        fields,
+                        collections,
+                    },
+                    events: event_schema,
+                    functions: BlueprintFunctionsSchemaInit {
+                        virtual_lazy_load_functions: btreemap!(),
+                        functions,
+                    },
+                },
+
+                royalty_config: PackageRoyaltyConfig::default(),
+                auth_config: AuthConfig {
+                    function_auth: FunctionAuth::AllowAll,
+                    method_auth: MethodAuthTemplate::StaticRoles(roles_template!(
+                        roles {
+                            ACCOUNT_LOCKER_STORER_ROLE;
+                            ACCOUNT_LOCKER_RECOVERER_ROLE;
+                        },
+                        methods {
+                            ACCOUNT_LOCKER_STORE_IDENT => [ACCOUNT_LOCKER_STORER_ROLE];
+                            ACCOUNT_LOCKER_RECOVER_IDENT => [ACCOUNT_LOCKER_RECOVERER_ROLE];
+
+                            // Claims are authorized by the claimant account on deposit.
+                            ACCOUNT_LOCKER_CLAIM_IDENT => MethodAccessibility::Public;
+                            ACCOUNT_LOCKER_GET_AMOUNT_IDENT => MethodAccessibility::Public;
+                        }
+                    )),
+                },
+            }
+        };
+
         let blueprints = btreemap!(
             ACCOUNT_BLUEPRINT.to_string() => BlueprintDefinitionInit {
                 blueprint_type: BlueprintType::default(),
@@ -380,7 +596,7 @@ impl AccountNativePackage {
                         fields,
                         collections,
                     },
-                    events: BlueprintEventSchemaInit::default(),
+                    events: event_schema,
                     functions: BlueprintFunctionsSchemaInit {
                         virtual_lazy_load_functions,
                         functions,
@@ -401,6 +617,7 @@ impl AccountNativePackage {
                             ACCOUNT_CONFIGURE_RESOURCE_DEPOSIT_RULE_IDENT => [OWNER_ROLE];
                             ACCOUNT_WITHDRAW_IDENT => [OWNER_ROLE];
                             ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
+                            ACCOUNT_WITHDRAW_BATCH_IDENT => [OWNER_ROLE];
                             ACCOUNT_LOCK_FEE_IDENT => [OWNER_ROLE];
                             ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => [OWNER_ROLE];
                             ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => [OWNER_ROLE];
@@ -416,10 +633,14 @@ impl AccountNativePackage {
                             ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => MethodAccessibility::Public;
                             ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => MethodAccessibility::Public;
                             ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => MethodAccessibility::Public;
+                            ACCOUNT_BALANCES_IDENT => MethodAccessibility::Public;
+                            ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT => MethodAccessibility::Public;
+                            ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT => MethodAccessibility::Public;
                         }
                     )),
                 },
-            }
+            },
+            ACCOUNT_LOCKER_BLUEPRINT.to_string() => account_locker_blueprint
         );
 
         PackageDefinition { blueprints }
@@ -560,6 +781,14 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_WITHDRAW_BATCH_IDENT => {
+                let input: AccountWithdrawBatchInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountBlueprint::withdraw_batch(input.resources, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ACCOUNT_BURN_IDENT => {
                 let input: AccountBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -576,6 +805,20 @@ impl AccountNativePackage {
                     AccountBlueprint::burn_non_fungibles(input.resource_address, input.ids, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_BALANCES_IDENT => {
+                let input: AccountBalancesInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::balances(input.resources, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT => {
+                let input: AccountNonFungibleIdsOfInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::non_fungible_ids_of(input.resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
                 let input: AccountLockFeeAndWithdrawInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -650,6 +893,73 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT => {
+                let AccountGetResourcePreferenceInput { resource_address } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = AccountBlueprint::get_resource_preference(resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_INSTANTIATE_EXPORT_NAME => {
+                let AccountLockerInstantiateInput {
+                    owner_role,
+                    storer_rule,
+                    recoverer_rule,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountLockerBlueprint::instantiate(
+                    owner_role,
+                    storer_rule,
+                    recoverer_rule,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_STORE_EXPORT_NAME => {
+                let AccountLockerStoreInput {
+                    claimant,
+                    bucket,
+                    try_direct_send,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountLockerBlueprint::store(claimant, bucket, try_direct_send, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_CLAIM_EXPORT_NAME => {
+                let AccountLockerClaimInput {
+                    claimant,
+                    resource_address,
+                    amount,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountLockerBlueprint::claim(claimant, resource_address, amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_RECOVER_EXPORT_NAME => {
+                let AccountLockerRecoverInput {
+                    claimant,
+                    resource_address,
+                    amount,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountLockerBlueprint::recover(claimant, resource_address, amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_GET_AMOUNT_EXPORT_NAME => {
+                let AccountLockerGetAmountInput {
+                    claimant,
+                    resource_address,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountLockerBlueprint::get_amount(claimant, resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
@@ -1,4 +1,8 @@
-use super::{EpochChangeEvent, RoundChangeEvent, ValidatorCreator, ValidatorOwnerBadgeData};
+use super::{
+    ConfigUpdateEvent, EmissionConfigUpdateEvent, EpochChangeEvent, EpochEmissionSummary,
+    RoundChangeEvent, UsdPriceChangeEvent, ValidatorCreator, ValidatorOwnerBadgeData,
+    ValidatorSetChangeEvent,
+};
 use crate::blueprints::consensus_manager::VALIDATOR_ROLE;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
@@ -203,6 +207,22 @@ pub enum ConsensusManagerError {
     },
     AlreadyStarted,
     NotXrd,
+    InvalidUsdPrice {
+        usd_price_in_xrd: Decimal,
+    },
+    InvalidEpochChangeCondition {
+        min_round_count: u64,
+        max_round_count: u64,
+    },
+    InvalidMaxValidators {
+        max_validators: u32,
+    },
+    InvalidTotalEmission {
+        total_emission_xrd_per_epoch: Decimal,
+    },
+    InvalidMinValidatorReliability {
+        min_validator_reliability: Decimal,
+    },
 }
 
 pub const CONSENSUS_MANAGER_REGISTERED_VALIDATORS_BY_STAKE_INDEX: CollectionIndex = 0u8;
@@ -213,6 +233,64 @@ pub struct EpochRegisteredValidatorByStakeEntry {
     pub validator: Validator,
 }
 
+/// Selects the next active validator set out of the given registered validators: the top
+/// `max_validators` by stake, descending.
+///
+/// Validators with an equal stake are ordered by their address, ascending, so that the selection
+/// only depends on the candidates and not on the order in which they are given.
+pub fn select_active_validator_set(
+    mut candidates: Vec<EpochRegisteredValidatorByStakeEntry>,
+    max_validators: u32,
+) -> ActiveValidatorSet {
+    candidates.sort_by(|candidate_1, candidate_2| {
+        candidate_1
+            .validator
+            .stake
+            .cmp(&candidate_2.validator.stake)
+            .reverse()
+            .then_with(|| {
+                candidate_1
+                    .component_address
+                    .cmp(&candidate_2.component_address)
+            })
+    });
+
+    ActiveValidatorSet {
+        validators_by_stake_desc: candidates
+            .into_iter()
+            .take(max_validators as usize)
+            .map(|entry| (entry.component_address, entry.validator))
+            .collect(),
+    }
+}
+
+/// Returns the validators which joined the next set (in its order) and the addresses of the
+/// validators which left it (in the order of the previous set).
+pub fn diff_validator_sets(
+    previous_validator_addresses: &IndexSet<ComponentAddress>,
+    next_validator_set: &ActiveValidatorSet,
+) -> (
+    IndexMap<ComponentAddress, Validator>,
+    IndexSet<ComponentAddress>,
+) {
+    let added_validators = next_validator_set
+        .validators_by_stake_desc
+        .iter()
+        .filter(|(address, _)| !previous_validator_addresses.contains(*address))
+        .map(|(address, validator)| (*address, validator.clone()))
+        .collect();
+    let removed_validators = previous_validator_addresses
+        .iter()
+        .filter(|address| {
+            !next_validator_set
+                .validators_by_stake_desc
+                .contains_key(*address)
+        })
+        .cloned()
+        .collect();
+    (added_validators, removed_validators)
+}
+
 pub struct ConsensusManagerBlueprint;
 
 impl ConsensusManagerBlueprint {
@@ -559,6 +637,136 @@ impl ConsensusManagerBlueprint {
         Ok(validator_creation_xrd_cost)
     }
 
+    pub(crate) fn update_usd_price<Y>(
+        usd_price_in_xrd: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !usd_price_in_xrd.is_positive() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(ConsensusManagerError::InvalidUsdPrice {
+                    usd_price_in_xrd,
+                }),
+            ));
+        }
+
+        let config_handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            ConsensusManagerField::Config.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut config_substate: ConsensusManagerConfigSubstate =
+            api.field_lock_read_typed(config_handle)?;
+        config_substate.config.usd_price_in_xrd = usd_price_in_xrd;
+        api.field_lock_write_typed(config_handle, &config_substate)?;
+        api.field_lock_release(config_handle)?;
+
+        Runtime::emit_event(api, UsdPriceChangeEvent { usd_price_in_xrd })?;
+
+        Ok(())
+    }
+
+    pub(crate) fn update_config<Y>(
+        epoch_change_condition: EpochChangeCondition,
+        max_validators: u32,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if epoch_change_condition.min_round_count > epoch_change_condition.max_round_count {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InvalidEpochChangeCondition {
+                        min_round_count: epoch_change_condition.min_round_count,
+                        max_round_count: epoch_change_condition.max_round_count,
+                    },
+                ),
+            ));
+        }
+        if max_validators == 0 {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InvalidMaxValidators { max_validators },
+                ),
+            ));
+        }
+
+        let config_handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            ConsensusManagerField::Config.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut config_substate: ConsensusManagerConfigSubstate =
+            api.field_lock_read_typed(config_handle)?;
+        config_substate.config.epoch_change_condition = epoch_change_condition.clone();
+        config_substate.config.max_validators = max_validators;
+        api.field_lock_write_typed(config_handle, &config_substate)?;
+        api.field_lock_release(config_handle)?;
+
+        Runtime::emit_event(
+            api,
+            ConfigUpdateEvent {
+                epoch_change_condition,
+                max_validators,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn update_emission_config<Y>(
+        total_emission_xrd_per_epoch: Decimal,
+        min_validator_reliability: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if total_emission_xrd_per_epoch.is_negative() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InvalidTotalEmission {
+                        total_emission_xrd_per_epoch,
+                    },
+                ),
+            ));
+        }
+        if min_validator_reliability.is_negative() || min_validator_reliability > Decimal::one() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InvalidMinValidatorReliability {
+                        min_validator_reliability,
+                    },
+                ),
+            ));
+        }
+
+        let config_handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            ConsensusManagerField::Config.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut config_substate: ConsensusManagerConfigSubstate =
+            api.field_lock_read_typed(config_handle)?;
+        config_substate.config.total_emission_xrd_per_epoch = total_emission_xrd_per_epoch;
+        config_substate.config.min_validator_reliability = min_validator_reliability;
+        api.field_lock_write_typed(config_handle, &config_substate)?;
+        api.field_lock_release(config_handle)?;
+
+        Runtime::emit_event(
+            api,
+            EmissionConfigUpdateEvent {
+                total_emission_xrd_per_epoch,
+                min_validator_reliability,
+            },
+        )?;
+
+        Ok(())
+    }
+
     pub(crate) fn create_validator<Y>(
         key: Secp256k1PublicKey,
         fee_factor: Decimal,
@@ -694,6 +902,11 @@ impl ConsensusManagerBlueprint {
         let mut validator_set_substate: CurrentValidatorSetSubstate =
             api.field_lock_read_typed(validator_set_handle)?;
         let previous_validator_set = validator_set_substate.validator_set;
+        let previous_validator_addresses: IndexSet<ComponentAddress> = previous_validator_set
+            .validators_by_stake_desc
+            .keys()
+            .cloned()
+            .collect();
 
         // Read previous validator statistics
         let statistic_handle = api.actor_open_field(
@@ -715,7 +928,7 @@ impl ConsensusManagerBlueprint {
             api.field_lock_read_typed(rewards_handle)?;
 
         // Apply emissions
-        Self::apply_validator_emissions_and_rewards(
+        let emission = Self::apply_validator_emissions_and_rewards(
             previous_validator_set,
             previous_statistics,
             config,
@@ -735,31 +948,15 @@ impl ConsensusManagerBlueprint {
         let num_validators_to_read_from_store =
             config.max_validators + (config.max_validators / 10) + 10;
 
-        let mut top_registered_validators: Vec<EpochRegisteredValidatorByStakeEntry> = api
+        let top_registered_validators: Vec<EpochRegisteredValidatorByStakeEntry> = api
             .actor_sorted_index_scan_typed(
                 OBJECT_HANDLE_SELF,
                 CONSENSUS_MANAGER_REGISTERED_VALIDATORS_BY_STAKE_INDEX,
                 num_validators_to_read_from_store,
             )?;
 
-        // The index scan should already pull the validators out in stake DESC, but if multiple validators are on the same u16 stake,
-        // then let's be even more accurate here. This sort is stable, so if two validators tie, then the resultant order will be
-        // decided on sort key DESC.
-        top_registered_validators.sort_by(|validator_1, validator_2| {
-            validator_1
-                .validator
-                .stake
-                .cmp(&validator_2.validator.stake)
-                .reverse()
-        });
-
-        let next_active_validator_set = ActiveValidatorSet {
-            validators_by_stake_desc: top_registered_validators
-                .into_iter()
-                .take(config.max_validators as usize)
-                .map(|entry| (entry.component_address, entry.validator))
-                .collect(),
-        };
+        let next_active_validator_set =
+            select_active_validator_set(top_registered_validators, config.max_validators);
 
         // Emit epoch change event
         Runtime::emit_event(
@@ -767,9 +964,24 @@ impl ConsensusManagerBlueprint {
             EpochChangeEvent {
                 epoch: next_epoch,
                 validator_set: next_active_validator_set.clone(),
+                emission,
             },
         )?;
 
+        // Emit validator set change event
+        let (added_validators, removed_validators) =
+            diff_validator_sets(&previous_validator_addresses, &next_active_validator_set);
+        if !added_validators.is_empty() || !removed_validators.is_empty() {
+            Runtime::emit_event(
+                api,
+                ValidatorSetChangeEvent {
+                    epoch: next_epoch,
+                    added_validators,
+                    removed_validators,
+                },
+            )?;
+        }
+
         // Write updated validator rewards
         api.field_lock_write_typed(rewards_handle, rewards_substate)?;
         api.field_lock_release(rewards_handle)?;
@@ -791,6 +1003,7 @@ impl ConsensusManagerBlueprint {
 
     /// Emits a configured XRD amount ([`ConsensusManagerConfigSubstate.total_emission_xrd_per_epoch`])
     /// and distributes it across the given validator set, according to their stake.
+    /// Returns a summary of the applied emissions.
     fn apply_validator_emissions_and_rewards<Y>(
         validator_set: ActiveValidatorSet,
         validator_statistics: Vec<ProposalStatistic>,
@@ -798,7 +1011,7 @@ impl ConsensusManagerBlueprint {
         validator_rewards: &mut ValidatorRewardsSubstate,
         epoch: Epoch, // the concluded epoch, for event creation
         api: &mut Y,
-    ) -> Result<(), RuntimeError>
+    ) -> Result<EpochEmissionSummary, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -823,8 +1036,13 @@ impl ConsensusManagerBlueprint {
                 // Excluded due to slashing ?
             }
         }
+        let mut emission = EpochEmissionSummary {
+            total_emission_xrd_per_epoch: config.total_emission_xrd_per_epoch,
+            min_validator_reliability: config.min_validator_reliability,
+            ..Default::default()
+        };
         if validator_infos.is_empty() {
-            return Ok(());
+            return Ok(emission);
         }
 
         let stake_sum_xrd = validator_infos
@@ -848,10 +1066,11 @@ impl ConsensusManagerBlueprint {
             ResourceManager(XRD).mint_fungible(effective_total_emission_xrd, api)?;
 
         for validator_info in validator_infos.values() {
-            let emission_xrd_bucket = total_emission_xrd_bucket.take(
-                validator_info.effective_stake_xrd * emission_per_staked_xrd,
-                api,
-            )?;
+            let emission_xrd = validator_info.effective_stake_xrd * emission_per_staked_xrd;
+            emission
+                .validator_emissions_xrd
+                .insert(validator_info.address, emission_xrd);
+            let emission_xrd_bucket = total_emission_xrd_bucket.take(emission_xrd, api)?;
             api.call_method(
                 validator_info.address.as_node_id(),
                 VALIDATOR_APPLY_EMISSION_IDENT,
@@ -865,6 +1084,7 @@ impl ConsensusManagerBlueprint {
             )?;
         }
         total_emission_xrd_bucket.drop_empty(api)?;
+        emission.effective_total_emission_xrd = effective_total_emission_xrd;
 
         //===========================
         // Distribute rewards (fees)
@@ -896,7 +1116,7 @@ impl ConsensusManagerBlueprint {
             )?;
         }
 
-        Ok(())
+        Ok(emission)
     }
 }
 
@@ -952,3 +1172,82 @@ impl ValidatorInfo {
         reliability_reserve / max_allowed_unreliability
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(seed: u8, stake: Decimal) -> EpochRegisteredValidatorByStakeEntry {
+        let mut address = [0u8; NodeId::LENGTH];
+        address[0] = EntityType::GlobalValidator as u8;
+        address[1] = seed;
+        EpochRegisteredValidatorByStakeEntry {
+            component_address: ComponentAddress::new_or_panic(address),
+            validator: Validator {
+                key: Secp256k1PublicKey([seed; Secp256k1PublicKey::LENGTH]),
+                stake,
+            },
+        }
+    }
+
+    #[test]
+    fn validator_set_selection_breaks_stake_ties_by_address() {
+        let candidates = vec![
+            candidate(3, dec!("10")),
+            candidate(2, dec!("20")),
+            candidate(4, dec!("10")),
+            candidate(1, dec!("10")),
+        ];
+        let mut reversed = candidates.clone();
+        reversed.reverse();
+
+        let validator_set = select_active_validator_set(candidates, 3);
+
+        assert_eq!(
+            validator_set
+                .validators_by_stake_desc
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![
+                candidate(2, dec!("20")).component_address,
+                candidate(1, dec!("10")).component_address,
+                candidate(3, dec!("10")).component_address,
+            ]
+        );
+        assert_eq!(select_active_validator_set(reversed, 3), validator_set);
+    }
+
+    #[test]
+    fn validator_set_diff_reports_added_and_removed_validators() {
+        let previous = select_active_validator_set(
+            vec![candidate(1, dec!("30")), candidate(2, dec!("20"))],
+            2,
+        );
+        let next = select_active_validator_set(
+            vec![
+                candidate(1, dec!("30")),
+                candidate(2, dec!("20")),
+                candidate(3, dec!("25")),
+            ],
+            2,
+        );
+
+        let (added, removed) = diff_validator_sets(
+            &previous.validators_by_stake_desc.keys().cloned().collect(),
+            &next,
+        );
+
+        assert_eq!(
+            added.into_iter().collect::<Vec<_>>(),
+            vec![(
+                candidate(3, dec!("25")).component_address,
+                candidate(3, dec!("25")).validator
+            )]
+        );
+        assert_eq!(
+            removed.into_iter().collect::<Vec<_>>(),
+            vec![candidate(2, dec!("20")).component_address]
+        );
+    }
+}
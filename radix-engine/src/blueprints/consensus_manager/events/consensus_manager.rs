@@ -1,5 +1,6 @@
-use crate::blueprints::consensus_manager::ActiveValidatorSet;
+use crate::blueprints::consensus_manager::{ActiveValidatorSet, Validator};
 use crate::types::*;
+use radix_engine_interface::blueprints::consensus_manager::EpochChangeCondition;
 
 #[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
 pub struct RoundChangeEvent {
@@ -12,4 +13,52 @@ pub struct EpochChangeEvent {
     pub epoch: Epoch,
     /// The *new* epoch's validator set.
     pub validator_set: ActiveValidatorSet,
+    /// The emissions applied to the *concluded* epoch's validator set.
+    pub emission: EpochEmissionSummary,
+}
+
+/// Emitted on an epoch change which changes the members of the active validator set.
+#[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct ValidatorSetChangeEvent {
+    /// The *new* epoch's number.
+    pub epoch: Epoch,
+    /// The validators which joined the active set, in its order.
+    pub added_validators: IndexMap<ComponentAddress, Validator>,
+    /// The validators which were ejected from the active set.
+    pub removed_validators: IndexSet<ComponentAddress>,
+}
+
+/// The emissions applied at the end of an epoch.
+#[derive(Debug, Clone, Default, ScryptoSbor, PartialEq, Eq)]
+pub struct EpochEmissionSummary {
+    /// The configured amount of XRD to emit per epoch.
+    pub total_emission_xrd_per_epoch: Decimal,
+    /// The configured proportion of proposals a validator needed to complete to get emissions.
+    pub min_validator_reliability: Decimal,
+    /// The XRD actually emitted, i.e. the configured emission less the reliability penalties.
+    pub effective_total_emission_xrd: Decimal,
+    /// The XRD emitted to each validator which received emissions.
+    pub validator_emissions_xrd: IndexMap<ComponentAddress, Decimal>,
+}
+
+#[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct UsdPriceChangeEvent {
+    /// The *new* price of one USD, in XRD.
+    pub usd_price_in_xrd: Decimal,
+}
+
+#[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct ConfigUpdateEvent {
+    /// The *new* condition for changing epochs.
+    pub epoch_change_condition: EpochChangeCondition,
+    /// The *new* maximum number of validators in the active set.
+    pub max_validators: u32,
+}
+
+#[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct EmissionConfigUpdateEvent {
+    /// The *new* amount of XRD emitted per epoch.
+    pub total_emission_xrd_per_epoch: Decimal,
+    /// The *new* proportion of proposals a validator needs to complete to get emissions.
+    pub min_validator_reliability: Decimal,
 }
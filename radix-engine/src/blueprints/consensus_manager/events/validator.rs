@@ -27,6 +27,14 @@ pub struct UpdateAcceptingStakeDelegationStateEvent {
     pub accepts_delegation: bool,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct ValidatorFeeChangeRequestEvent {
+    /// The requested fee factor.
+    pub new_fee_factor: Decimal,
+    /// An epoch number at (or after) which the requested fee factor is effective.
+    pub epoch_effective: Epoch,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
 pub struct ProtocolUpdateReadinessSignalEvent {
     pub protocol_version_name: String,
@@ -139,6 +139,51 @@ impl ConsensusManagerNativePackage {
                     export: CONSENSUS_MANAGER_NEXT_ROUND_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                CONSENSUS_MANAGER_UPDATE_USD_PRICE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ConsensusManagerUpdateUsdPriceInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ConsensusManagerUpdateUsdPriceOutput>(),
+                    ),
+                    export: CONSENSUS_MANAGER_UPDATE_USD_PRICE_IDENT.to_string(),
+                },
+            );
+            functions.insert(
+                CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ConsensusManagerUpdateConfigInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ConsensusManagerUpdateConfigOutput>(),
+                    ),
+                    export: CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+                },
+            );
+            functions.insert(
+                CONSENSUS_MANAGER_UPDATE_EMISSION_CONFIG_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ConsensusManagerUpdateEmissionConfigInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ConsensusManagerUpdateEmissionConfigOutput>(),
+                    ),
+                    export: CONSENSUS_MANAGER_UPDATE_EMISSION_CONFIG_IDENT.to_string(),
+                },
+            );
             functions.insert(
                 CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -155,7 +200,11 @@ impl ConsensusManagerNativePackage {
                 aggregator,
                 [
                     RoundChangeEvent,
-                    EpochChangeEvent
+                    EpochChangeEvent,
+                    UsdPriceChangeEvent,
+                    ConfigUpdateEvent,
+                    EmissionConfigUpdateEvent,
+                    ValidatorSetChangeEvent
                 ]
             };
 
@@ -196,6 +245,9 @@ impl ConsensusManagerNativePackage {
                         methods {
                             CONSENSUS_MANAGER_START_IDENT => []; // Genesis is able to call this by skipping auth
                             CONSENSUS_MANAGER_NEXT_ROUND_IDENT => [VALIDATOR_ROLE];
+                            CONSENSUS_MANAGER_UPDATE_USD_PRICE_IDENT => [VALIDATOR_ROLE];
+                            CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT => [VALIDATOR_ROLE];
+                            CONSENSUS_MANAGER_UPDATE_EMISSION_CONFIG_IDENT => [VALIDATOR_ROLE];
 
                             CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT => MethodAccessibility::Public;
                             CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT => MethodAccessibility::Public;
@@ -323,6 +375,36 @@ impl ConsensusManagerNativePackage {
                     export: VALIDATOR_UPDATE_FEE_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                VALIDATOR_SCHEDULE_FEE_CHANGE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ValidatorScheduleFeeChangeInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ValidatorScheduleFeeChangeOutput>(),
+                    ),
+                    export: VALIDATOR_SCHEDULE_FEE_CHANGE_IDENT.to_string(),
+                },
+            );
+            functions.insert(
+                VALIDATOR_GET_PENDING_FEE_CHANGE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ValidatorGetPendingFeeChangeInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<ValidatorGetPendingFeeChangeOutput>(),
+                    ),
+                    export: VALIDATOR_GET_PENDING_FEE_CHANGE_IDENT.to_string(),
+                },
+            );
             functions.insert(
                 VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -437,7 +519,8 @@ impl ConsensusManagerNativePackage {
                     ProtocolUpdateReadinessSignalEvent,
                     UpdateAcceptingStakeDelegationStateEvent,
                     ValidatorEmissionAppliedEvent,
-                    ValidatorRewardAppliedEvent
+                    ValidatorRewardAppliedEvent,
+                    ValidatorFeeChangeRequestEvent
                 ]
             };
 
@@ -471,11 +554,13 @@ impl ConsensusManagerNativePackage {
                             VALIDATOR_CLAIM_XRD_IDENT => MethodAccessibility::Public;
                             VALIDATOR_STAKE_IDENT => MethodAccessibility::Public;
                             VALIDATOR_ACCEPTS_DELEGATED_STAKE_IDENT => MethodAccessibility::Public;
+                            VALIDATOR_GET_PENDING_FEE_CHANGE_IDENT => MethodAccessibility::Public;
                             VALIDATOR_STAKE_AS_OWNER_IDENT => [OWNER_ROLE];
                             VALIDATOR_REGISTER_IDENT => [OWNER_ROLE];
                             VALIDATOR_UNREGISTER_IDENT => [OWNER_ROLE];
                             VALIDATOR_UPDATE_KEY_IDENT => [OWNER_ROLE];
                             VALIDATOR_UPDATE_FEE_IDENT => [OWNER_ROLE];
+                            VALIDATOR_SCHEDULE_FEE_CHANGE_IDENT => [OWNER_ROLE];
                             VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
                             VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
                             VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
@@ -573,6 +658,40 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            CONSENSUS_MANAGER_UPDATE_USD_PRICE_IDENT => {
+                let input: ConsensusManagerUpdateUsdPriceInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::update_usd_price(input.usd_price_in_xrd, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT => {
+                let input: ConsensusManagerUpdateConfigInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConsensusManagerBlueprint::update_config(
+                    input.epoch_change_condition,
+                    input.max_validators,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            CONSENSUS_MANAGER_UPDATE_EMISSION_CONFIG_IDENT => {
+                let input: ConsensusManagerUpdateEmissionConfigInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::update_emission_config(
+                    input.total_emission_xrd_per_epoch,
+                    input.min_validator_reliability,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT => {
                 let input: ConsensusManagerCreateValidatorInput =
                     input.as_typed().map_err(|e| {
@@ -643,6 +762,24 @@ impl ConsensusManagerNativePackage {
                 let rtn = ValidatorBlueprint::update_fee(input.new_fee_factor, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            VALIDATOR_SCHEDULE_FEE_CHANGE_IDENT => {
+                let input: ValidatorScheduleFeeChangeInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::schedule_fee_change(
+                    input.new_fee_factor,
+                    input.delay_epochs,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            VALIDATOR_GET_PENDING_FEE_CHANGE_IDENT => {
+                let _: ValidatorGetPendingFeeChangeInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::get_pending_fee_change(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT => {
                 let input: ValidatorUpdateAcceptDelegatedStakeInput =
                     input.as_typed().map_err(|e| {
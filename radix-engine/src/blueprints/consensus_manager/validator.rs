@@ -23,7 +23,7 @@ use sbor::rust::mem;
 
 use super::{
     ClaimXrdEvent, RegisterValidatorEvent, StakeEvent, UnregisterValidatorEvent, UnstakeEvent,
-    UpdateAcceptingStakeDelegationStateEvent,
+    UpdateAcceptingStakeDelegationStateEvent, ValidatorFeeChangeRequestEvent,
 };
 
 pub const VALIDATOR_PROTOCOL_VERSION_NAME_LEN: usize = 32;
@@ -129,21 +129,6 @@ pub struct UnstakeData {
     pub claim_amount: Decimal,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
-pub struct ValidatorFeeChangeRequest {
-    /// An epoch number at (or after) which the fee change is effective.
-    /// To be specific: when a next epoch `N` begins, we perform accounting of emissions due for
-    /// previous epoch `N-1` - this means that we will use this [`new_validator_fee_factor`] only if
-    /// `epoch_effective <= N-1`, and [`ValidatorSubstate.validator_fee_factor`] otherwise.
-    /// Note: when requesting a fee decrease, this will be "next epoch"; and when requesting an
-    /// increase, this will be set to [`ConsensusManagerConfigSubstate.num_fee_increase_delay_epochs`]
-    /// epochs away.
-    pub epoch_effective: Epoch,
-
-    /// A requested new value of [`ConsensusManagerSubstate.validator_fee_factor`].
-    pub new_fee_factor: Decimal,
-}
-
 impl NonFungibleData for UnstakeData {
     const MUTABLE_FIELDS: &'static [&'static str] = &[];
 }
@@ -544,6 +529,51 @@ impl ValidatorBlueprint {
     }
 
     pub fn update_fee<Y>(new_fee_factor: Decimal, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::request_fee_change(new_fee_factor, 0, api)
+    }
+
+    pub fn schedule_fee_change<Y>(
+        new_fee_factor: Decimal,
+        delay_epochs: u64,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::request_fee_change(new_fee_factor, delay_epochs, api)
+    }
+
+    pub fn get_pending_fee_change<Y>(
+        api: &mut Y,
+    ) -> Result<Option<ValidatorFeeChangeRequest>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let current_epoch = Self::current_epoch(api)?;
+
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            ValidatorField::Validator.into(),
+            LockFlags::read_only(),
+        )?;
+        let substate: ValidatorSubstate = api.field_lock_read_typed(handle)?;
+        api.field_lock_release(handle)?;
+
+        Ok(substate
+            .validator_fee_change_request
+            .filter(|request| request.epoch_effective > current_epoch))
+    }
+
+    /// Requests a fee change which becomes effective after the given number of epochs, or later
+    /// if that is sooner than the minimum delay for the change.
+    fn request_fee_change<Y>(
+        new_fee_factor: Decimal,
+        delay_epochs: u64,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -551,15 +581,7 @@ impl ValidatorBlueprint {
         check_validator_fee_factor(new_fee_factor)?;
 
         // read the current epoch
-        let consensus_manager_handle = api.actor_open_field(
-            OBJECT_HANDLE_OUTER_OBJECT,
-            ConsensusManagerField::ConsensusManager.into(),
-            LockFlags::read_only(),
-        )?;
-        let consensus_manager: ConsensusManagerSubstate =
-            api.field_lock_read_typed(consensus_manager_handle)?;
-        let current_epoch = consensus_manager.epoch;
-        api.field_lock_release(consensus_manager_handle)?;
+        let current_epoch = Self::current_epoch(api)?;
 
         // read the configured fee increase epochs delay
         let config_handle = api.actor_open_field(
@@ -587,11 +609,12 @@ impl ValidatorBlueprint {
         }
 
         // - calculate the effective epoch of the requested change
-        let epoch_effective = if new_fee_factor > substate.validator_fee_factor {
-            current_epoch.after(config_substate.config.num_fee_increase_delay_epochs)
+        let min_delay_epochs = if new_fee_factor > substate.validator_fee_factor {
+            config_substate.config.num_fee_increase_delay_epochs
         } else {
-            current_epoch.next() // make it effective on the *beginning* of next epoch
+            1 // make it effective on the *beginning* of next epoch
         };
+        let epoch_effective = current_epoch.after(delay_epochs.max(min_delay_epochs));
 
         // ...end the read+modify+write of the validator substate
         substate.validator_fee_change_request = Some(ValidatorFeeChangeRequest {
@@ -601,9 +624,33 @@ impl ValidatorBlueprint {
         api.field_lock_write_typed(handle, &substate)?;
         api.field_lock_release(handle)?;
 
+        Runtime::emit_event(
+            api,
+            ValidatorFeeChangeRequestEvent {
+                new_fee_factor,
+                epoch_effective,
+            },
+        )?;
+
         Ok(())
     }
 
+    fn current_epoch<Y>(api: &mut Y) -> Result<Epoch, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let consensus_manager_handle = api.actor_open_field(
+            OBJECT_HANDLE_OUTER_OBJECT,
+            ConsensusManagerField::ConsensusManager.into(),
+            LockFlags::read_only(),
+        )?;
+        let consensus_manager: ConsensusManagerSubstate =
+            api.field_lock_read_typed(consensus_manager_handle)?;
+        api.field_lock_release(consensus_manager_handle)?;
+
+        Ok(consensus_manager.epoch)
+    }
+
     pub fn accepts_delegated_stake<Y>(api: &mut Y) -> Result<bool, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -1,3 +1,5 @@
 mod package;
+mod schema_interning;
 
 pub use package::*;
+pub use schema_interning::*;
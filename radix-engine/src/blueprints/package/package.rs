@@ -1,3 +1,4 @@
+use crate::blueprints::package::intern_schemas;
 use crate::blueprints::util::SecurifiedAccessRules;
 use crate::errors::*;
 use crate::kernel::kernel_api::{KernelApi, KernelSubstateApi};
@@ -34,6 +35,7 @@ use crate::system::system::{KeyValueEntrySubstate, SubstateMutability, SystemSer
 use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_modules::auth::{AuthError, ResolvedPermission};
+use crate::vm::wasm::WasmValidatorConfigV1;
 use crate::vm::VmPackageValidation;
 pub use radix_engine_interface::blueprints::package::{
     PackageInstrumentedCodeSubstate, PackageOriginalCodeSubstate, PackageRoyaltyAccumulatorSubstate,
@@ -976,6 +978,7 @@ impl PackageNativePackage {
     pub fn invoke_export<Y>(
         export_name: &str,
         input: &IndexedScryptoValue,
+        wasm_validator_config: &WasmValidatorConfigV1,
         api: &mut Y,
     ) -> Result<IndexedScryptoValue, RuntimeError>
     where
@@ -992,6 +995,7 @@ impl PackageNativePackage {
                     input.native_package_code_id,
                     input.definition,
                     input.metadata,
+                    wasm_validator_config,
                     api,
                 )?;
 
@@ -1002,7 +1006,13 @@ impl PackageNativePackage {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
 
-                let rtn = Self::publish_wasm(input.code, input.definition, input.metadata, api)?;
+                let rtn = Self::publish_wasm(
+                    input.code,
+                    input.definition,
+                    input.metadata,
+                    wasm_validator_config,
+                    api,
+                )?;
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
@@ -1017,6 +1027,7 @@ impl PackageNativePackage {
                     input.definition,
                     input.metadata,
                     input.owner_role,
+                    wasm_validator_config,
                     api,
                 )?;
 
@@ -1039,6 +1050,7 @@ impl PackageNativePackage {
         definition: PackageDefinition,
         vm_type: VmType,
         original_code: Vec<u8>,
+        wasm_validator_config: &WasmValidatorConfigV1,
     ) -> Result<PackageStructure, RuntimeError> {
         // Validate schema
         validate_package_schema(definition.blueprints.values().map(|s| &s.schema))
@@ -1049,8 +1061,12 @@ impl PackageNativePackage {
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
 
         // Validate VM specific properties
-        let instrumented_code =
-            VmPackageValidation::validate(&definition, vm_type, &original_code)?;
+        let instrumented_code = VmPackageValidation::validate(
+            &definition,
+            vm_type,
+            &original_code,
+            wasm_validator_config,
+        )?;
 
         // Build Package structure
         let mut definitions = BTreeMap::new();
@@ -1076,12 +1092,20 @@ impl PackageNativePackage {
         };
 
         {
-            for (blueprint, definition_init) in definition.blueprints {
-                auth_configs.insert(blueprint.clone(), definition_init.auth_config);
+            // Intern the blueprint schemas into a single package schema, to store shared types once
+            let (package_schema, schema_mappings) = intern_schemas(
+                definition
+                    .blueprints
+                    .values()
+                    .map(|definition_init| &definition_init.schema.schema),
+            );
+            let schema_hash = hash(scrypto_encode(&package_schema).unwrap());
+            schemas.insert(schema_hash, package_schema);
 
-                let blueprint_schema = definition_init.schema.schema.clone();
-                let schema_hash = hash(scrypto_encode(&blueprint_schema).unwrap());
-                schemas.insert(schema_hash, blueprint_schema);
+            for ((blueprint, definition_init), schema_mapping) in
+                definition.blueprints.into_iter().zip(schema_mappings)
+            {
+                auth_configs.insert(blueprint.clone(), definition_init.auth_config);
 
                 let mut functions = BTreeMap::new();
                 let mut function_exports = BTreeMap::new();
@@ -1110,8 +1134,8 @@ impl PackageNativePackage {
                         function.clone(),
                         FunctionSchema {
                             receiver: function_schema_init.receiver,
-                            input: TypePointer::Package(schema_hash, input),
-                            output: TypePointer::Package(schema_hash, output),
+                            input: TypePointer::Package(schema_hash, schema_mapping.map(input)),
+                            output: TypePointer::Package(schema_hash, schema_mapping.map(output)),
                         },
                     );
                     let export = PackageExport {
@@ -1124,7 +1148,9 @@ impl PackageNativePackage {
                 let mut events = BTreeMap::new();
                 for (key, type_ref) in definition_init.schema.events.event_schema {
                     let index = match type_ref {
-                        TypeRef::Static(index) => TypePointer::Package(schema_hash, index),
+                        TypeRef::Static(index) => {
+                            TypePointer::Package(schema_hash, schema_mapping.map(index))
+                        }
                         TypeRef::Generic(index) => TypePointer::Instance(index),
                     };
                     events.insert(key, index);
@@ -1139,7 +1165,28 @@ impl PackageNativePackage {
                         events,
                         state: IndexedStateSchema::from_schema(
                             schema_hash,
-                            definition_init.schema.state,
+                            BlueprintStateSchemaInit {
+                                fields: definition_init
+                                    .schema
+                                    .state
+                                    .fields
+                                    .into_iter()
+                                    .map(|field| FieldSchema {
+                                        field: schema_mapping.map_type_ref(field.field),
+                                        condition: field.condition,
+                                    })
+                                    .collect(),
+                                collections: definition_init
+                                    .schema
+                                    .state
+                                    .collections
+                                    .into_iter()
+                                    .map(|collection| {
+                                        collection
+                                            .map(|type_ref| schema_mapping.map_type_ref(type_ref))
+                                    })
+                                    .collect(),
+                            },
                         ),
                     },
                     function_exports,
@@ -1189,6 +1236,7 @@ impl PackageNativePackage {
         native_package_code_id: u64,
         definition: PackageDefinition,
         metadata_init: MetadataInit,
+        wasm_validator_config: &WasmValidatorConfigV1,
         api: &mut Y,
     ) -> Result<PackageAddress, RuntimeError>
     where
@@ -1199,6 +1247,7 @@ impl PackageNativePackage {
             definition,
             VmType::Native,
             native_package_code_id.to_be_bytes().to_vec(),
+            wasm_validator_config,
         )?;
         let access_rules = AccessRules::create(OwnerRole::None, btreemap!(), api)?;
         let metadata = Metadata::create_with_data(metadata_init, api)?;
@@ -1216,14 +1265,19 @@ impl PackageNativePackage {
         code: Vec<u8>,
         definition: PackageDefinition,
         metadata_init: MetadataInit,
+        wasm_validator_config: &WasmValidatorConfigV1,
         api: &mut Y,
     ) -> Result<(PackageAddress, Bucket), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
         validate_royalties(&definition, api)?;
-        let package_structure =
-            Self::validate_and_build_package_structure(definition, VmType::ScryptoV1, code)?;
+        let package_structure = Self::validate_and_build_package_structure(
+            definition,
+            VmType::ScryptoV1,
+            code,
+            wasm_validator_config,
+        )?;
 
         let (address_reservation, address) = api.allocate_global_address(BlueprintId {
             package_address: PACKAGE_PACKAGE,
@@ -1257,14 +1311,19 @@ impl PackageNativePackage {
         definition: PackageDefinition,
         metadata_init: MetadataInit,
         owner_role: OwnerRole,
+        wasm_validator_config: &WasmValidatorConfigV1,
         api: &mut Y,
     ) -> Result<PackageAddress, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
         validate_royalties(&definition, api)?;
-        let package_structure =
-            Self::validate_and_build_package_structure(definition, VmType::ScryptoV1, code)?;
+        let package_structure = Self::validate_and_build_package_structure(
+            definition,
+            VmType::ScryptoV1,
+            code,
+            wasm_validator_config,
+        )?;
         let metadata = Metadata::create_with_data(metadata_init, api)?;
         let access_rules = SecurifiedPackage::create_advanced(owner_role, api)?;
 
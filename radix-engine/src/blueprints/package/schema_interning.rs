@@ -0,0 +1,219 @@
+use crate::types::*;
+
+/// Maps the local type indices of a blueprint schema to the interned package schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaIndexMapping {
+    indices: Vec<usize>,
+}
+
+impl SchemaIndexMapping {
+    pub fn map(&self, index: LocalTypeIndex) -> LocalTypeIndex {
+        match index {
+            LocalTypeIndex::WellKnown(..) => index,
+            LocalTypeIndex::SchemaLocalIndex(i) => match self.indices.get(i) {
+                Some(mapped) => LocalTypeIndex::SchemaLocalIndex(*mapped),
+                // Out-of-range indices are rejected by schema validation; keep them as they are
+                None => index,
+            },
+        }
+    }
+
+    pub fn map_type_ref(&self, type_ref: TypeRef<LocalTypeIndex>) -> TypeRef<LocalTypeIndex> {
+        match type_ref {
+            TypeRef::Static(index) => TypeRef::Static(self.map(index)),
+            TypeRef::Generic(index) => TypeRef::Generic(index),
+        }
+    }
+}
+
+/// Merges the schemas of a package's blueprints into a single package schema, in which types that
+/// are structurally identical (including metadata and validation) are stored only once.
+///
+/// Returns the package schema, along with the index mapping of each of the given schemas.
+pub fn intern_schemas<'a, I: IntoIterator<Item = &'a ScryptoSchema>>(
+    schemas: I,
+) -> (ScryptoSchema, Vec<SchemaIndexMapping>) {
+    // Concatenate all types, rebasing their references onto the concatenated schema
+    let mut kinds = Vec::new();
+    let mut metadata = Vec::new();
+    let mut validations = Vec::new();
+    let mut offsets = Vec::new();
+    for schema in schemas {
+        let offset = kinds.len();
+        offsets.push((offset, schema.type_kinds.len()));
+        for type_kind in &schema.type_kinds {
+            kinds.push(map_children(type_kind, |index| match index {
+                LocalTypeIndex::SchemaLocalIndex(i) => LocalTypeIndex::SchemaLocalIndex(offset + i),
+                well_known => well_known,
+            }));
+        }
+        metadata.extend(schema.type_metadata.iter().cloned());
+        validations.extend(schema.type_validations.iter().cloned());
+    }
+
+    // Partition the types by everything but their schema-local children, then refine the
+    // partition by the classes of their children until it's stable. This merges recursive
+    // types correctly, as two types end up in the same class iff they can't be told apart.
+    let mut classes = assign_classes(kinds.iter().enumerate().map(|(i, kind)| {
+        let shape = map_children(kind, |index| match index {
+            LocalTypeIndex::SchemaLocalIndex(..) => LocalTypeIndex::SchemaLocalIndex(0),
+            well_known => well_known,
+        });
+        scrypto_encode(&(shape, &metadata[i], &validations[i])).unwrap()
+    }));
+    loop {
+        let refined = assign_classes(kinds.iter().enumerate().map(|(i, kind)| {
+            let child_classes: Vec<usize> = children(kind)
+                .into_iter()
+                .filter_map(|index| match index {
+                    LocalTypeIndex::SchemaLocalIndex(child) => Some(classes[child]),
+                    LocalTypeIndex::WellKnown(..) => None,
+                })
+                .collect();
+            scrypto_encode(&(classes[i], child_classes)).unwrap()
+        }));
+        let class_count = |classes: &Vec<usize>| classes.iter().max().map_or(0, |max| max + 1);
+        if class_count(&refined) == class_count(&classes) {
+            break;
+        }
+        classes = refined;
+    }
+
+    // Classes are numbered by first occurrence, so the first type of each class represents it
+    let mut interned = ScryptoSchema {
+        type_kinds: Vec::new(),
+        type_metadata: Vec::new(),
+        type_validations: Vec::new(),
+    };
+    for (i, kind) in kinds.iter().enumerate() {
+        if classes[i] == interned.type_kinds.len() {
+            interned
+                .type_kinds
+                .push(map_children(kind, |index| match index {
+                    LocalTypeIndex::SchemaLocalIndex(child) => {
+                        LocalTypeIndex::SchemaLocalIndex(classes[child])
+                    }
+                    well_known => well_known,
+                }));
+            interned.type_metadata.push(metadata[i].clone());
+            interned.type_validations.push(validations[i].clone());
+        }
+    }
+
+    let mappings = offsets
+        .into_iter()
+        .map(|(offset, len)| SchemaIndexMapping {
+            indices: classes[offset..offset + len].to_vec(),
+        })
+        .collect();
+
+    (interned, mappings)
+}
+
+/// Numbers the given keys by order of first occurrence.
+fn assign_classes<I: Iterator<Item = Vec<u8>>>(keys: I) -> Vec<usize> {
+    let mut class_by_key = index_map_new();
+    keys.map(|key| {
+        let next_class = class_by_key.len();
+        *class_by_key.entry(key).or_insert(next_class)
+    })
+    .collect()
+}
+
+fn children(kind: &SchemaTypeKind<ScryptoCustomSchema>) -> Vec<LocalTypeIndex> {
+    match kind {
+        TypeKind::Array { element_type } => vec![*element_type],
+        TypeKind::Tuple { field_types } => field_types.clone(),
+        TypeKind::Enum { variants } => variants.values().flatten().cloned().collect(),
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => vec![*key_type, *value_type],
+        _ => vec![],
+    }
+}
+
+fn map_children<F: Fn(LocalTypeIndex) -> LocalTypeIndex>(
+    kind: &SchemaTypeKind<ScryptoCustomSchema>,
+    f: F,
+) -> SchemaTypeKind<ScryptoCustomSchema> {
+    match kind {
+        TypeKind::Array { element_type } => TypeKind::Array {
+            element_type: f(*element_type),
+        },
+        TypeKind::Tuple { field_types } => TypeKind::Tuple {
+            field_types: field_types.iter().map(|index| f(*index)).collect(),
+        },
+        TypeKind::Enum { variants } => TypeKind::Enum {
+            variants: variants
+                .iter()
+                .map(|(discriminator, fields)| {
+                    (
+                        *discriminator,
+                        fields.iter().map(|index| f(*index)).collect(),
+                    )
+                })
+                .collect(),
+        },
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => TypeKind::Map {
+            key_type: f(*key_type),
+            value_type: f(*value_type),
+        },
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(ScryptoSbor)]
+    struct Shared {
+        amount: Decimal,
+        children: Vec<Shared>,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct OnlyInA {
+        shared: Shared,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct OnlyInB {
+        shared: Shared,
+        name: String,
+    }
+
+    #[test]
+    fn shared_types_are_stored_once() {
+        let (a_index, a) = generate_full_schema_from_single_type::<OnlyInA, ScryptoCustomSchema>();
+        let (b_index, b) = generate_full_schema_from_single_type::<OnlyInB, ScryptoCustomSchema>();
+
+        let (interned, mappings) = intern_schemas([&a, &b]);
+
+        // `OnlyInA`, `Shared`, `Vec<Shared>` and `OnlyInB`
+        assert_eq!(a.type_kinds.len() + b.type_kinds.len(), 6);
+        assert_eq!(interned.type_kinds.len(), 4);
+        assert_eq!(
+            mappings[0].map(a_index),
+            LocalTypeIndex::SchemaLocalIndex(0)
+        );
+        assert_eq!(
+            mappings[1].map(b_index),
+            LocalTypeIndex::SchemaLocalIndex(3)
+        );
+        assert!(validate_schema(&interned).is_ok());
+    }
+
+    #[test]
+    fn single_schema_is_unchanged() {
+        let (_, schema) = generate_full_schema_from_single_type::<OnlyInB, ScryptoCustomSchema>();
+
+        let (interned, _) = intern_schemas([&schema]);
+
+        assert_eq!(interned, schema);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::errors::RuntimeError;
+use native_sdk::resource::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::pool::*;
+use radix_engine_interface::blueprints::resource::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum ContributionProtectionError {
+    InvalidMinimumLiquidity {
+        amount: Decimal,
+    },
+    InvalidDustThreshold {
+        dust_threshold: Decimal,
+    },
+    ContributionDoesNotExceedMinimumLiquidity {
+        pool_units: Decimal,
+        minimum_liquidity: Decimal,
+    },
+    ContributionBelowDustThreshold {
+        pool_units: Decimal,
+        dust_threshold: Decimal,
+    },
+}
+
+/// The minimum liquidity locked away by the first contribution to a pool.
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub enum LockedLiquidity {
+    /// The amount of pool units which have been burned.
+    Burned(Decimal),
+    /// The vault of the pool units which have been escrowed.
+    Escrowed(Vault),
+}
+
+impl Clone for LockedLiquidity {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Burned(amount) => Self::Burned(*amount),
+            Self::Escrowed(vault) => Self::Escrowed(Vault(vault.0.clone())),
+        }
+    }
+}
+
+/// The pool units to mint for a contribution, once the contribution protection of the pool has
+/// been applied.
+pub(crate) struct ProtectedContribution {
+    /// The amount of pool units to mint for the contributor.
+    pub pool_units_to_mint: Decimal,
+    /// The minimum liquidity to lock away, if this is the first contribution to the pool.
+    pub liquidity_to_lock: Option<MinimumLiquidity>,
+}
+
+pub(crate) fn validate_contribution_protection(
+    protection: &PoolContributionProtection,
+) -> Result<(), ContributionProtectionError> {
+    if let Some(MinimumLiquidity { amount, .. }) = protection.minimum_liquidity {
+        if amount <= Decimal::ZERO {
+            return Err(ContributionProtectionError::InvalidMinimumLiquidity { amount });
+        }
+    }
+    if let Some(dust_threshold) = protection.dust_threshold {
+        if dust_threshold < Decimal::ZERO {
+            return Err(ContributionProtectionError::InvalidDustThreshold { dust_threshold });
+        }
+    }
+    Ok(())
+}
+
+/// Splits the pool units minted by a contribution between the contributor and the minimum
+/// liquidity, which is only locked away by the first contribution to the pool.
+pub(crate) fn protect_contribution(
+    protection: &PoolContributionProtection,
+    pool_units: Decimal,
+    is_first_contribution: bool,
+) -> Result<ProtectedContribution, ContributionProtectionError> {
+    let liquidity_to_lock = protection
+        .minimum_liquidity
+        .filter(|_| is_first_contribution);
+
+    let pool_units_to_mint = match liquidity_to_lock {
+        Some(MinimumLiquidity { amount, .. }) if pool_units <= amount => {
+            return Err(
+                ContributionProtectionError::ContributionDoesNotExceedMinimumLiquidity {
+                    pool_units,
+                    minimum_liquidity: amount,
+                },
+            );
+        }
+        Some(MinimumLiquidity { amount, .. }) => pool_units - amount,
+        None => pool_units,
+    };
+
+    if let Some(dust_threshold) = protection.dust_threshold {
+        if pool_units_to_mint < dust_threshold {
+            return Err(
+                ContributionProtectionError::ContributionBelowDustThreshold {
+                    pool_units: pool_units_to_mint,
+                    dust_threshold,
+                },
+            );
+        }
+    }
+
+    Ok(ProtectedContribution {
+        pool_units_to_mint,
+        liquidity_to_lock,
+    })
+}
+
+/// Locks the minimum liquidity of the pool away.
+pub(crate) fn lock_liquidity<Y>(
+    MinimumLiquidity { amount, lock }: MinimumLiquidity,
+    pool_unit_resource_manager: &mut ResourceManager,
+    api: &mut Y,
+) -> Result<LockedLiquidity, RuntimeError>
+where
+    Y: ClientApi<RuntimeError>,
+{
+    match lock {
+        MinimumLiquidityLock::Burn => Ok(LockedLiquidity::Burned(amount)),
+        MinimumLiquidityLock::Escrow => {
+            let pool_units = pool_unit_resource_manager.mint_fungible(amount, api)?;
+            let mut vault = Vault::create(pool_unit_resource_manager.0, api)?;
+            vault.put(pool_units, api)?;
+            Ok(LockedLiquidity::Escrowed(vault))
+        }
+    }
+}
+
+/// The supply of pool units that the reserves of a pool are shared between. This is the total
+/// supply of the pool unit resource, and the pool units burned as the minimum liquidity if any.
+pub(crate) fn pool_unit_supply<Y>(
+    pool_unit_resource_manager: &ResourceManager,
+    locked_liquidity: &Option<LockedLiquidity>,
+    api: &mut Y,
+) -> Result<Decimal, RuntimeError>
+where
+    Y: ClientApi<RuntimeError>,
+{
+    let total_supply = pool_unit_resource_manager
+        .total_supply(api)?
+        .expect("Total supply is always enabled for pool unit resource.");
+    match locked_liquidity {
+        Some(LockedLiquidity::Burned(amount)) => Ok(total_supply + *amount),
+        Some(LockedLiquidity::Escrowed(..)) | None => Ok(total_supply),
+    }
+}
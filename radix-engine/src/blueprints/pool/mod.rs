@@ -2,5 +2,9 @@ pub mod multi_resource_pool;
 pub mod one_resource_pool;
 pub mod two_resource_pool;
 
+mod contribution_protection;
 mod package;
+mod single_sided_contribution;
+pub use contribution_protection::*;
 pub use package::*;
+pub(crate) use single_sided_contribution::*;
@@ -1,5 +1,9 @@
 use crate::blueprints::pool::multi_resource_pool::*;
 use crate::blueprints::pool::POOL_MANAGER_ROLE;
+use crate::blueprints::pool::{
+    calculate_single_sided_contribution, lock_liquidity, pool_unit_supply, protect_contribution,
+    validate_contribution_protection, ProtectedContribution, SingleSidedContribution,
+};
 use crate::errors::*;
 use crate::kernel::kernel_api::*;
 use native_sdk::modules::access_rules::*;
@@ -30,6 +34,28 @@ impl MultiResourcePoolBlueprint {
     where
         Y: ClientApi<RuntimeError> + KernelNodeApi,
     {
+        Self::instantiate_with_contribution_protection(
+            resource_addresses,
+            owner_role,
+            pool_manager_rule,
+            PoolContributionProtection::default(),
+            api,
+        )
+    }
+
+    pub fn instantiate_with_contribution_protection<Y>(
+        resource_addresses: BTreeSet<ResourceAddress>,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        contribution_protection: PoolContributionProtection,
+        api: &mut Y,
+    ) -> Result<MultiResourcePoolInstantiateWithContributionProtectionOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        validate_contribution_protection(&contribution_protection)
+            .map_err(MultiResourcePoolError::from)?;
+
         // A pool can't be created where one of the resources is non-fungible - error out if any of
         // them are
         for resource_address in resource_addresses.iter() {
@@ -111,6 +137,9 @@ impl MultiResourcePoolBlueprint {
                     })
                     .collect::<Result<_, _>>()?,
                 pool_unit_resource_manager,
+                single_sided_contribution_fee: None,
+                contribution_protection,
+                locked_liquidity: None,
             };
             api.new_simple_object(
                 MULTI_RESOURCE_POOL_BLUEPRINT_IDENT,
@@ -199,7 +228,7 @@ impl MultiResourcePoolBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        let (mut substate, lock_handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+        let (mut substate, lock_handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
 
         // Checks
         let amounts_of_resources_provided = {
@@ -247,10 +276,11 @@ impl MultiResourcePoolBlueprint {
             resource_bucket_amount_mapping
         };
 
-        let pool_unit_total_supply = substate
-            .pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_unit_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
         // Case: New Pool
         let (pool_units, change) = if pool_unit_total_supply.is_zero() {
             // Regarding the unwrap here, there are two cases here where this unwrap could panic:
@@ -264,6 +294,11 @@ impl MultiResourcePoolBlueprint {
                 .reduce(|acc, item| acc * item)
                 .and_then(|value| value.sqrt())
                 .unwrap();
+            let ProtectedContribution {
+                pool_units_to_mint,
+                liquidity_to_lock,
+            } = protect_contribution(&substate.contribution_protection, pool_units_to_mint, true)
+                .map_err(MultiResourcePoolError::from)?;
 
             // The following unwrap is safe to do. We've already checked that all of the buckets
             // provided belong to the pool and have a corresponding vault.
@@ -284,12 +319,21 @@ impl MultiResourcePoolBlueprint {
                 },
             )?;
 
-            (
-                substate
-                    .pool_unit_resource_manager
-                    .mint_fungible(pool_units_to_mint, api)?,
-                vec![],
-            )
+            let pool_units = substate
+                .pool_unit_resource_manager
+                .mint_fungible(pool_units_to_mint, api)?;
+
+            // The first contribution locks away the minimum liquidity of the pool, if it has one.
+            if let Some(minimum_liquidity) = liquidity_to_lock {
+                substate.locked_liquidity = Some(lock_liquidity(
+                    minimum_liquidity,
+                    &mut substate.pool_unit_resource_manager,
+                    api,
+                )?);
+                api.field_lock_write_typed(lock_handle, &substate)?;
+            }
+
+            (pool_units, vec![])
         } else {
             // Check if any of the vaults are empty. If any of them are, then the pool is in an
             // illegal state and it can not be contributed to.
@@ -364,6 +408,8 @@ impl MultiResourcePoolBlueprint {
             }
 
             let pool_units_to_mint = pool_unit_total_supply * minimum_ratio;
+            protect_contribution(&substate.contribution_protection, pool_units_to_mint, false)
+                .map_err(MultiResourcePoolError::from)?;
 
             Runtime::emit_event(
                 api,
@@ -405,10 +451,11 @@ impl MultiResourcePoolBlueprint {
         }
 
         let pool_units_to_redeem = bucket.amount(api)?;
-        let pool_units_total_supply = substate
-            .pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_units_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
         let mut reserves = BTreeMap::new();
         for (resource_address, vault) in substate.vaults.iter() {
             let amount = vault.amount(api)?;
@@ -523,10 +570,11 @@ impl MultiResourcePoolBlueprint {
         let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
 
         let pool_units_to_redeem = amount_of_pool_units;
-        let pool_units_total_supply = substate
-            .pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_units_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
         let mut reserves = BTreeMap::new();
         for (resource_address, vault) in substate.vaults.into_iter() {
             let amount = vault.amount(api)?;
@@ -576,6 +624,118 @@ impl MultiResourcePoolBlueprint {
         Ok(amounts)
     }
 
+    pub fn contribute_single_sided<Y>(
+        bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<MultiResourcePoolContributeSingleSidedOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+
+        let fee_rate = substate
+            .single_sided_contribution_fee
+            .ok_or(MultiResourcePoolError::SingleSidedContributionsAreDisabled)?;
+
+        let resource_address = bucket.resource_address(api)?;
+        if !substate.vaults.contains_key(&resource_address) {
+            return Err(
+                MultiResourcePoolError::ResourceDoesNotBelongToPool { resource_address }.into(),
+            );
+        }
+
+        let amount = bucket.amount(api)?;
+        if amount == Decimal::ZERO {
+            return Err(MultiResourcePoolError::ContributionOfEmptyBucketError.into());
+        }
+
+        // A single-sided contribution is priced against the current reserves of the pool, so it
+        // can't be made to a pool that has not yet had any contributions.
+        let pool_unit_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
+        let mut reserves = BTreeMap::new();
+        for (address, vault) in substate.vaults.iter() {
+            reserves.insert(*address, vault.amount(api)?);
+        }
+        if pool_unit_total_supply == Decimal::ZERO
+            || reserves.values().any(|amount| *amount == Decimal::ZERO)
+        {
+            return Err(MultiResourcePoolError::SingleSidedContributionToEmptyPool.into());
+        }
+
+        let reserves_of_contributed = reserves.remove(&resource_address).unwrap();
+        let SingleSidedContribution {
+            pool_units_to_mint,
+            fee,
+            pool_share,
+        } = calculate_single_sided_contribution(
+            amount,
+            reserves_of_contributed,
+            pool_unit_total_supply,
+            substate.vaults.len() as u32,
+            fee_rate,
+        );
+
+        // A single-sided contribution is never the first one to the pool, so no minimum liquidity
+        // is locked away by it.
+        protect_contribution(&substate.contribution_protection, pool_units_to_mint, false)
+            .map_err(MultiResourcePoolError::from)?;
+
+        let event = SingleSidedContributionEvent {
+            resource_address,
+            amount,
+            pool_units_minted: pool_units_to_mint,
+            fee,
+            implied_swap_input: amount - pool_share * (reserves_of_contributed + amount),
+            implied_swap_output: reserves
+                .into_iter()
+                .map(|(address, reserves)| (address, pool_share * reserves))
+                .collect(),
+        };
+
+        // The entire contribution is deposited into the pool, including the imbalance fee.
+        substate
+            .vaults
+            .get_mut(&resource_address)
+            .unwrap()
+            .put(bucket, api)?;
+        let pool_units = substate
+            .pool_unit_resource_manager
+            .mint_fungible(pool_units_to_mint, api)?;
+
+        api.field_lock_release(handle)?;
+
+        Runtime::emit_event(api, event)?;
+
+        Ok(pool_units)
+    }
+
+    pub fn set_single_sided_contribution_fee<Y>(
+        fee: Option<Decimal>,
+        api: &mut Y,
+    ) -> Result<MultiResourcePoolSetSingleSidedContributionFeeOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if let Some(fee) = fee {
+            if fee < Decimal::ZERO || fee > Decimal::ONE {
+                return Err(
+                    MultiResourcePoolError::InvalidSingleSidedContributionFee { fee }.into(),
+                );
+            }
+        }
+
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+        substate.single_sided_contribution_fee = fee;
+        api.field_lock_write_typed(handle, &substate)?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
     //===================
     // Utility Functions
     //===================
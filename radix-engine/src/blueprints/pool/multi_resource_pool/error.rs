@@ -1,5 +1,7 @@
+use crate::blueprints::pool::ContributionProtectionError;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use radix_engine_common::math::Decimal;
 use radix_engine_common::types::*;
 use radix_engine_common::ScryptoSbor;
 use sbor::prelude::*;
@@ -22,7 +24,13 @@ pub enum MultiResourcePoolError {
     },
     PoolCreationWithSameResource,
     ContributionOfEmptyBucketError,
+    SingleSidedContributionsAreDisabled,
+    SingleSidedContributionToEmptyPool,
+    InvalidSingleSidedContributionFee {
+        fee: Decimal,
+    },
     CantCreatePoolWithLessThanOneResource,
+    ContributionProtectionError(ContributionProtectionError),
 }
 
 impl From<MultiResourcePoolError> for RuntimeError {
@@ -30,3 +38,9 @@ impl From<MultiResourcePoolError> for RuntimeError {
         Self::ApplicationError(ApplicationError::MultiResourcePoolError(error))
     }
 }
+
+impl From<ContributionProtectionError> for MultiResourcePoolError {
+    fn from(error: ContributionProtectionError) -> Self {
+        Self::ContributionProtectionError(error)
+    }
+}
@@ -1,5 +1,9 @@
 use crate::blueprints::pool::one_resource_pool::*;
 use crate::blueprints::pool::POOL_MANAGER_ROLE;
+use crate::blueprints::pool::{
+    lock_liquidity, pool_unit_supply, protect_contribution, validate_contribution_protection,
+    ProtectedContribution,
+};
 use crate::errors::*;
 use crate::kernel::kernel_api::*;
 use native_sdk::modules::access_rules::*;
@@ -30,6 +34,28 @@ impl OneResourcePoolBlueprint {
     where
         Y: ClientApi<RuntimeError> + KernelNodeApi,
     {
+        Self::instantiate_with_contribution_protection(
+            resource_address,
+            owner_role,
+            pool_manager_rule,
+            PoolContributionProtection::default(),
+            api,
+        )
+    }
+
+    pub fn instantiate_with_contribution_protection<Y>(
+        resource_address: ResourceAddress,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        contribution_protection: PoolContributionProtection,
+        api: &mut Y,
+    ) -> Result<OneResourcePoolInstantiateWithContributionProtectionOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        validate_contribution_protection(&contribution_protection)
+            .map_err(OneResourcePoolError::from)?;
+
         // Validate that the resource is a fungible resource - a pool can't be created with non
         // fungible resources.
         let resource_manager = ResourceManager(resource_address);
@@ -94,6 +120,8 @@ impl OneResourcePoolBlueprint {
             let substate = OneResourcePoolSubstate {
                 vault,
                 pool_unit_resource_manager,
+                contribution_protection,
+                locked_liquidity: None,
             };
             api.new_simple_object(
                 ONE_RESOURCE_POOL_BLUEPRINT_IDENT,
@@ -124,9 +152,8 @@ impl OneResourcePoolBlueprint {
         // No check that the bucket is of the same resource as the vault. This check will be handled
         // by the vault itself on deposit.
 
-        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
-        let mut pool_unit_resource_manager = substate.pool_unit_resource_manager;
-        let mut vault = substate.vault;
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+        let mut vault = Vault(substate.vault.0.clone());
 
         if bucket.is_empty(api)? {
             return Err(OneResourcePoolError::ContributionOfEmptyBucketError.into());
@@ -152,9 +179,11 @@ impl OneResourcePoolBlueprint {
          */
 
         let reserves = vault.amount(api)?;
-        let pool_unit_total_supply = pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_unit_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
         let amount_of_contributed_resources = bucket.amount(api)?;
 
         let pool_units_to_mint = match (
@@ -167,8 +196,30 @@ impl OneResourcePoolBlueprint {
             (true, true) => Ok(amount_of_contributed_resources * pool_unit_total_supply / reserves),
         }?;
 
+        let ProtectedContribution {
+            pool_units_to_mint,
+            liquidity_to_lock,
+        } = protect_contribution(
+            &substate.contribution_protection,
+            pool_units_to_mint,
+            pool_unit_total_supply.is_zero(),
+        )
+        .map_err(OneResourcePoolError::from)?;
+
         vault.put(bucket, api)?;
-        let pool_units = pool_unit_resource_manager.mint_fungible(pool_units_to_mint, api)?;
+        let pool_units = substate
+            .pool_unit_resource_manager
+            .mint_fungible(pool_units_to_mint, api)?;
+
+        // The first contribution locks away the minimum liquidity of the pool, if it has one.
+        if let Some(minimum_liquidity) = liquidity_to_lock {
+            substate.locked_liquidity = Some(lock_liquidity(
+                minimum_liquidity,
+                &mut substate.pool_unit_resource_manager,
+                api,
+            )?);
+            api.field_lock_write_typed(handle, &substate)?;
+        }
 
         api.field_lock_release(handle)?;
 
@@ -190,12 +241,13 @@ impl OneResourcePoolBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        let (pool_unit_resource_manager, mut vault, handle) = {
+        let (pool_unit_resource_manager, mut vault, locked_liquidity, handle) = {
             let (substate, lock_handle) = Self::lock_and_read(api, LockFlags::read_only())?;
 
             (
                 substate.pool_unit_resource_manager,
                 substate.vault,
+                substate.locked_liquidity,
                 lock_handle,
             )
         };
@@ -212,9 +264,8 @@ impl OneResourcePoolBlueprint {
 
         // Calculating the amount owed based on the passed pool units.
         let pool_units_to_redeem = bucket.amount(api)?;
-        let pool_units_total_supply = pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_units_total_supply =
+            pool_unit_supply(&pool_unit_resource_manager, &locked_liquidity, api)?;
         let pool_resource_reserves = vault.amount(api)?;
         let pool_resource_divisibility = vault
             .resource_address(api)
@@ -305,20 +356,20 @@ impl OneResourcePoolBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        let (pool_unit_resource_manager, vault, handle) = {
+        let (pool_unit_resource_manager, vault, locked_liquidity, handle) = {
             let (substate, lock_handle) = Self::lock_and_read(api, LockFlags::read_only())?;
 
             (
                 substate.pool_unit_resource_manager,
                 substate.vault,
+                substate.locked_liquidity,
                 lock_handle,
             )
         };
 
         let pool_units_to_redeem = amount_of_pool_units;
-        let pool_units_total_supply = pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_units_total_supply =
+            pool_unit_supply(&pool_unit_resource_manager, &locked_liquidity, api)?;
         let pool_resource_reserves = vault.amount(api)?;
         let pool_resource_divisibility = vault
             .resource_address(api)
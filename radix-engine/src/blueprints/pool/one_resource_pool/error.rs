@@ -1,3 +1,4 @@
+use crate::blueprints::pool::ContributionProtectionError;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
 use radix_engine_common::types::*;
@@ -14,6 +15,7 @@ pub enum OneResourcePoolError {
         actual: ResourceAddress,
     },
     ContributionOfEmptyBucketError,
+    ContributionProtectionError(ContributionProtectionError),
 }
 
 impl From<OneResourcePoolError> for RuntimeError {
@@ -21,3 +23,9 @@ impl From<OneResourcePoolError> for RuntimeError {
         Self::ApplicationError(ApplicationError::OneResourcePoolError(error))
     }
 }
+
+impl From<ContributionProtectionError> for OneResourcePoolError {
+    fn from(error: ContributionProtectionError) -> Self {
+        Self::ContributionProtectionError(error)
+    }
+}
@@ -1,6 +1,8 @@
+use crate::blueprints::pool::LockedLiquidity;
 use native_sdk::resource::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
+use radix_engine_interface::blueprints::pool::PoolContributionProtection;
 use radix_engine_interface::blueprints::resource::*;
 
 #[derive(Debug, PartialEq, Eq, ScryptoSbor)]
@@ -10,6 +12,12 @@ pub struct OneResourcePoolSubstate {
 
     /// The resource manager of the pool unit resource that the pool works with.
     pub pool_unit_resource_manager: ResourceManager,
+
+    /// The protection of the pool against the inflation of the value of its pool units.
+    pub contribution_protection: PoolContributionProtection,
+
+    /// The minimum liquidity locked away by the first contribution to the pool, if any.
+    pub locked_liquidity: Option<LockedLiquidity>,
 }
 
 impl Clone for OneResourcePoolSubstate {
@@ -17,6 +25,8 @@ impl Clone for OneResourcePoolSubstate {
         Self {
             vault: Vault(self.vault.0.clone()),
             pool_unit_resource_manager: self.pool_unit_resource_manager.clone(),
+            contribution_protection: self.contribution_protection.clone(),
+            locked_liquidity: self.locked_liquidity.clone(),
         }
     }
 }
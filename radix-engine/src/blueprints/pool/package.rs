@@ -53,6 +53,21 @@ impl PoolNativePackage {
                 },
             );
 
+            functions.insert(
+                ONE_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: None,
+                    input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                        OneResourcePoolInstantiateWithContributionProtectionInput,
+                    >()),
+                    output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                        OneResourcePoolInstantiateWithContributionProtectionOutput,
+                    >()),
+                    export: ONE_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_EXPORT_NAME
+                        .to_string(),
+                },
+            );
+
             functions.insert(
                 ONE_RESOURCE_POOL_CONTRIBUTE_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -218,6 +233,21 @@ impl PoolNativePackage {
                 },
             );
 
+            functions.insert(
+                TWO_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: None,
+                    input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                        TwoResourcePoolInstantiateWithContributionProtectionInput,
+                    >()),
+                    output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                        TwoResourcePoolInstantiateWithContributionProtectionOutput,
+                    >()),
+                    export: TWO_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_EXPORT_NAME
+                        .to_string(),
+                },
+            );
+
             functions.insert(
                 TWO_RESOURCE_POOL_CONTRIBUTE_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -303,13 +333,38 @@ impl PoolNativePackage {
                 },
             );
 
+            functions.insert(
+                TWO_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<TwoResourcePoolContributeSingleSidedInput>()),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<TwoResourcePoolContributeSingleSidedOutput>()),
+                    export: TWO_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_EXPORT_NAME.to_string(),
+                },
+            );
+
+            functions.insert(
+                TWO_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<TwoResourcePoolSetSingleSidedContributionFeeInput>()),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<TwoResourcePoolSetSingleSidedContributionFeeOutput>()),
+                    export: TWO_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_EXPORT_NAME.to_string(),
+                },
+            );
+
             let event_schema = event_schema! {
                 aggregator,
                 [
                     super::two_resource_pool::ContributionEvent,
                     super::two_resource_pool::RedemptionEvent,
                     super::two_resource_pool::WithdrawEvent,
-                    super::two_resource_pool::DepositEvent
+                    super::two_resource_pool::DepositEvent,
+                    super::two_resource_pool::SingleSidedContributionEvent
                 ]
             };
 
@@ -349,6 +404,8 @@ impl PoolNativePackage {
                             TWO_RESOURCE_POOL_CONTRIBUTE_IDENT => [POOL_MANAGER_ROLE];
                             TWO_RESOURCE_POOL_PROTECTED_DEPOSIT_IDENT => [POOL_MANAGER_ROLE];
                             TWO_RESOURCE_POOL_PROTECTED_WITHDRAW_IDENT => [POOL_MANAGER_ROLE];
+                            TWO_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_IDENT => [POOL_MANAGER_ROLE];
+                            TWO_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_IDENT => [POOL_MANAGER_ROLE];
                         }
                     }),
                 },
@@ -384,6 +441,21 @@ impl PoolNativePackage {
                 },
             );
 
+            functions.insert(
+                MULTI_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: None,
+                    input: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                        MultiResourcePoolInstantiateWithContributionProtectionInput,
+                    >()),
+                    output: TypeRef::Static(aggregator.add_child_type_and_descendents::<
+                        MultiResourcePoolInstantiateWithContributionProtectionOutput,
+                    >()),
+                    export: MULTI_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_EXPORT_NAME
+                        .to_string(),
+                },
+            );
+
             functions.insert(
                 MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -468,13 +540,38 @@ impl PoolNativePackage {
                 },
             );
 
+            functions.insert(
+                MULTI_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolContributeSingleSidedInput>()),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolContributeSingleSidedOutput>()),
+                    export: MULTI_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_EXPORT_NAME.to_string(),
+                },
+            );
+
+            functions.insert(
+                MULTI_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolSetSingleSidedContributionFeeInput>()),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<MultiResourcePoolSetSingleSidedContributionFeeOutput>()),
+                    export: MULTI_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_EXPORT_NAME.to_string(),
+                },
+            );
+
             let event_schema = event_schema! {
                 aggregator,
                 [
                     super::multi_resource_pool::ContributionEvent,
                     super::multi_resource_pool::RedemptionEvent,
                     super::multi_resource_pool::WithdrawEvent,
-                    super::multi_resource_pool::DepositEvent
+                    super::multi_resource_pool::DepositEvent,
+                    super::multi_resource_pool::SingleSidedContributionEvent
                 ]
             };
 
@@ -512,6 +609,8 @@ impl PoolNativePackage {
                             MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT => [POOL_MANAGER_ROLE];
                             MULTI_RESOURCE_POOL_PROTECTED_DEPOSIT_IDENT => [POOL_MANAGER_ROLE];
                             MULTI_RESOURCE_POOL_PROTECTED_WITHDRAW_IDENT => [POOL_MANAGER_ROLE];
+                            MULTI_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_IDENT => [POOL_MANAGER_ROLE];
+                            MULTI_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_IDENT => [POOL_MANAGER_ROLE];
                         }
                     }),
                 },
@@ -554,6 +653,26 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
+            ONE_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_EXPORT_NAME => {
+                let OneResourcePoolInstantiateWithContributionProtectionInput {
+                    resource_address,
+                    pool_manager_rule,
+                    owner_role,
+                    contribution_protection,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = OneResourcePoolBlueprint::instantiate_with_contribution_protection(
+                    resource_address,
+                    owner_role,
+                    pool_manager_rule,
+                    contribution_protection,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
             ONE_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME => {
                 let OneResourcePoolContributeInput { bucket } = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -628,6 +747,26 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
+            TWO_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_EXPORT_NAME => {
+                let TwoResourcePoolInstantiateWithContributionProtectionInput {
+                    resource_addresses,
+                    pool_manager_rule,
+                    owner_role,
+                    contribution_protection,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = TwoResourcePoolBlueprint::instantiate_with_contribution_protection(
+                    resource_addresses,
+                    owner_role,
+                    pool_manager_rule,
+                    contribution_protection,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
             TWO_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME => {
                 let TwoResourcePoolContributeInput { buckets } = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -689,6 +828,24 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
+            TWO_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_EXPORT_NAME => {
+                let TwoResourcePoolContributeSingleSidedInput { bucket } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = TwoResourcePoolBlueprint::contribute_single_sided(bucket, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            TWO_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_EXPORT_NAME => {
+                let TwoResourcePoolSetSingleSidedContributionFeeInput { fee } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = TwoResourcePoolBlueprint::set_single_sided_contribution_fee(fee, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
             MULTI_RESOURCE_POOL_INSTANTIATE_EXPORT_NAME => {
                 let MultiResourcePoolInstantiateInput {
                     resource_addresses,
@@ -707,6 +864,26 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
+            MULTI_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_EXPORT_NAME => {
+                let MultiResourcePoolInstantiateWithContributionProtectionInput {
+                    resource_addresses,
+                    pool_manager_rule,
+                    owner_role,
+                    contribution_protection,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = MultiResourcePoolBlueprint::instantiate_with_contribution_protection(
+                    resource_addresses,
+                    owner_role,
+                    pool_manager_rule,
+                    contribution_protection,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
             MULTI_RESOURCE_POOL_CONTRIBUTE_EXPORT_NAME => {
                 let MultiResourcePoolContributeInput { buckets } =
                     input.as_typed().map_err(|e| {
@@ -769,6 +946,24 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
+            MULTI_RESOURCE_POOL_CONTRIBUTE_SINGLE_SIDED_EXPORT_NAME => {
+                let MultiResourcePoolContributeSingleSidedInput { bucket } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = MultiResourcePoolBlueprint::contribute_single_sided(bucket, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            MULTI_RESOURCE_POOL_SET_SINGLE_SIDED_CONTRIBUTION_FEE_EXPORT_NAME => {
+                let MultiResourcePoolSetSingleSidedContributionFeeInput { fee } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = MultiResourcePoolBlueprint::set_single_sided_contribution_fee(fee, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
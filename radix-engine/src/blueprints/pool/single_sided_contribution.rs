@@ -0,0 +1,56 @@
+use radix_engine_common::math::*;
+
+/// The result of a single-sided contribution of some resource to a pool.
+pub(crate) struct SingleSidedContribution {
+    /// The amount of pool units to mint in exchange for the contribution.
+    pub pool_units_to_mint: Decimal,
+    /// The share of the contribution which is kept by the pool as an imbalance fee.
+    pub fee: Decimal,
+    /// The share of the pool that the minted pool units represent after the contribution.
+    pub pool_share: Decimal,
+}
+
+/**
+Calculates the pool units to mint for a single-sided contribution to a pool of `n` resources with
+equally weighted reserves.
+
+A single-sided contribution is treated as if the contributor swapped some of the contributed
+resource for each of the other resources in the pool, and then contributed all of the resources in
+the ratio of the reserves. The product of the reserves is the invariant of the implied swap, so the
+pool units minted for a contribution of c to reserves r are:
+
+s × (((r + c) / r)<sup>1/n</sup> − 1)
+
+where s is the pool units total supply. The imbalance fee is charged on the amount of the resource
+which is implicitly swapped; it is deposited into the pool along with the rest of the contribution,
+but no pool units are minted for it.
+*/
+pub(crate) fn calculate_single_sided_contribution(
+    contribution: Decimal,
+    reserves: Decimal,
+    pool_unit_total_supply: Decimal,
+    number_of_resources: u32,
+    fee_rate: Decimal,
+) -> SingleSidedContribution {
+    let pool_units_for = |amount: Decimal| {
+        let growth = ((reserves + amount) / reserves)
+            .nth_root(number_of_resources)
+            .expect("Reserves and contributions are always positive");
+        pool_unit_total_supply * (growth - Decimal::ONE)
+    };
+    let pool_share_of = |pool_units: Decimal| pool_units / (pool_unit_total_supply + pool_units);
+
+    // The amount of the contributed resource which is implicitly swapped, were no fee charged.
+    let implied_swap_input = {
+        let pool_share = pool_share_of(pool_units_for(contribution));
+        contribution - pool_share * (reserves + contribution)
+    };
+    let fee = implied_swap_input * fee_rate;
+
+    let pool_units_to_mint = pool_units_for(contribution - fee);
+    SingleSidedContribution {
+        pool_units_to_mint,
+        fee,
+        pool_share: pool_share_of(pool_units_to_mint),
+    }
+}
@@ -1,5 +1,9 @@
 use crate::blueprints::pool::two_resource_pool::*;
 use crate::blueprints::pool::POOL_MANAGER_ROLE;
+use crate::blueprints::pool::{
+    calculate_single_sided_contribution, lock_liquidity, pool_unit_supply, protect_contribution,
+    validate_contribution_protection, ProtectedContribution, SingleSidedContribution,
+};
 use crate::errors::*;
 use crate::kernel::kernel_api::*;
 use native_sdk::modules::access_rules::*;
@@ -22,7 +26,7 @@ pub const TWO_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = "TwoResourcePool";
 pub struct TwoResourcePoolBlueprint;
 impl TwoResourcePoolBlueprint {
     pub fn instantiate<Y>(
-        (resource_address1, resource_address2): (ResourceAddress, ResourceAddress),
+        resource_addresses: (ResourceAddress, ResourceAddress),
         owner_role: OwnerRole,
         pool_manager_rule: AccessRule,
         api: &mut Y,
@@ -30,6 +34,28 @@ impl TwoResourcePoolBlueprint {
     where
         Y: ClientApi<RuntimeError> + KernelNodeApi,
     {
+        Self::instantiate_with_contribution_protection(
+            resource_addresses,
+            owner_role,
+            pool_manager_rule,
+            PoolContributionProtection::default(),
+            api,
+        )
+    }
+
+    pub fn instantiate_with_contribution_protection<Y>(
+        (resource_address1, resource_address2): (ResourceAddress, ResourceAddress),
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        contribution_protection: PoolContributionProtection,
+        api: &mut Y,
+    ) -> Result<TwoResourcePoolInstantiateWithContributionProtectionOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        validate_contribution_protection(&contribution_protection)
+            .map_err(TwoResourcePoolError::from)?;
+
         // A pool can't be created between the same resources - error out if it's
         if resource_address1 == resource_address2 {
             return Err(TwoResourcePoolError::ContributionOfEmptyBucketError.into());
@@ -111,6 +137,9 @@ impl TwoResourcePoolBlueprint {
                     (resource_address2, Vault::create(resource_address2, api)?),
                 ],
                 pool_unit_resource_manager,
+                single_sided_contribution_fee: None,
+                contribution_protection,
+                locked_liquidity: None,
             };
             api.new_simple_object(
                 TWO_RESOURCE_POOL_BLUEPRINT_IDENT,
@@ -197,11 +226,12 @@ impl TwoResourcePoolBlueprint {
         };
 
         // Determine the amount of pool units to mint based on the the current state of the pool.
+        let pool_unit_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
         let (pool_units_to_mint, amount1, amount2) = {
-            let pool_unit_total_supply = substate
-                .pool_unit_resource_manager
-                .total_supply(api)?
-                .expect("Total supply is always enabled for pool unit resource.");
             let reserves1 = vault1.amount(api)?;
             let reserves2 = vault2.amount(api)?;
             let contribution1 = bucket1.amount(api)?;
@@ -278,6 +308,16 @@ impl TwoResourcePoolBlueprint {
             }
         }?;
 
+        let ProtectedContribution {
+            pool_units_to_mint,
+            liquidity_to_lock,
+        } = protect_contribution(
+            &substate.contribution_protection,
+            pool_units_to_mint,
+            pool_unit_total_supply.is_zero(),
+        )
+        .map_err(TwoResourcePoolError::from)?;
+
         // Construct the event - this will be emitted once the resources are contributed to the pool
         let event = ContributionEvent {
             contributed_resources: btreemap! {
@@ -292,6 +332,16 @@ impl TwoResourcePoolBlueprint {
             .pool_unit_resource_manager
             .mint_fungible(pool_units_to_mint, api)?;
 
+        // The first contribution locks away the minimum liquidity of the pool, if it has one.
+        if let Some(minimum_liquidity) = liquidity_to_lock {
+            substate.locked_liquidity = Some(lock_liquidity(
+                minimum_liquidity,
+                &mut substate.pool_unit_resource_manager,
+                api,
+            )?);
+            api.field_lock_write_typed(handle, &substate)?;
+        }
+
         // Deposit the calculated amount of each of the buckets into appropriate vault.
         bucket1
             .take(amount1, api)
@@ -341,10 +391,11 @@ impl TwoResourcePoolBlueprint {
         }
 
         let pool_units_to_redeem = bucket.amount(api)?;
-        let pool_units_total_supply = substate
-            .pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_units_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
         let mut reserves = BTreeMap::new();
         for (resource_address, vault) in substate.vaults.iter() {
             let amount = vault.amount(api)?;
@@ -458,10 +509,11 @@ impl TwoResourcePoolBlueprint {
         let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
 
         let pool_units_to_redeem = amount_of_pool_units;
-        let pool_units_total_supply = substate
-            .pool_unit_resource_manager
-            .total_supply(api)?
-            .expect("Total supply is always enabled for pool unit resource.");
+        let pool_units_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
         let mut reserves = BTreeMap::new();
         for (resource_address, vault) in substate.vaults.into_iter() {
             let amount = vault.amount(api)?;
@@ -511,6 +563,110 @@ impl TwoResourcePoolBlueprint {
         Ok(amounts)
     }
 
+    pub fn contribute_single_sided<Y>(
+        bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<TwoResourcePoolContributeSingleSidedOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+
+        let fee_rate = substate
+            .single_sided_contribution_fee
+            .ok_or(TwoResourcePoolError::SingleSidedContributionsAreDisabled)?;
+
+        let resource_address = bucket.resource_address(api)?;
+        let mut vault = substate
+            .vault(resource_address)
+            .ok_or(TwoResourcePoolError::ResourceDoesNotBelongToPool { resource_address })?;
+
+        let amount = bucket.amount(api)?;
+        if amount == Decimal::ZERO {
+            return Err(TwoResourcePoolError::ContributionOfEmptyBucketError.into());
+        }
+
+        // A single-sided contribution is priced against the current reserves of the pool, so it
+        // can't be made to a pool that has not yet had any contributions.
+        let pool_unit_total_supply = pool_unit_supply(
+            &substate.pool_unit_resource_manager,
+            &substate.locked_liquidity,
+            api,
+        )?;
+        let mut reserves = BTreeMap::new();
+        for (address, vault) in substate.vaults.iter() {
+            reserves.insert(*address, vault.amount(api)?);
+        }
+        if pool_unit_total_supply == Decimal::ZERO
+            || reserves.values().any(|amount| *amount == Decimal::ZERO)
+        {
+            return Err(TwoResourcePoolError::SingleSidedContributionToEmptyPool.into());
+        }
+
+        let reserves_of_contributed = reserves.remove(&resource_address).unwrap();
+        let SingleSidedContribution {
+            pool_units_to_mint,
+            fee,
+            pool_share,
+        } = calculate_single_sided_contribution(
+            amount,
+            reserves_of_contributed,
+            pool_unit_total_supply,
+            2,
+            fee_rate,
+        );
+
+        // A single-sided contribution is never the first one to the pool, so no minimum liquidity
+        // is locked away by it.
+        protect_contribution(&substate.contribution_protection, pool_units_to_mint, false)
+            .map_err(TwoResourcePoolError::from)?;
+
+        let event = SingleSidedContributionEvent {
+            resource_address,
+            amount,
+            pool_units_minted: pool_units_to_mint,
+            fee,
+            implied_swap_input: amount - pool_share * (reserves_of_contributed + amount),
+            implied_swap_output: reserves
+                .into_iter()
+                .map(|(address, reserves)| (address, pool_share * reserves))
+                .collect(),
+        };
+
+        // The entire contribution is deposited into the pool, including the imbalance fee.
+        vault.put(bucket, api)?;
+        let pool_units = substate
+            .pool_unit_resource_manager
+            .mint_fungible(pool_units_to_mint, api)?;
+
+        api.field_lock_release(handle)?;
+
+        Runtime::emit_event(api, event)?;
+
+        Ok(pool_units)
+    }
+
+    pub fn set_single_sided_contribution_fee<Y>(
+        fee: Option<Decimal>,
+        api: &mut Y,
+    ) -> Result<TwoResourcePoolSetSingleSidedContributionFeeOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if let Some(fee) = fee {
+            if fee < Decimal::ZERO || fee > Decimal::ONE {
+                return Err(TwoResourcePoolError::InvalidSingleSidedContributionFee { fee }.into());
+            }
+        }
+
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+        substate.single_sided_contribution_fee = fee;
+        api.field_lock_write_typed(handle, &substate)?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
     //===================
     // Utility Functions
     //===================
@@ -1,5 +1,7 @@
+use crate::blueprints::pool::ContributionProtectionError;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use radix_engine_common::math::Decimal;
 use radix_engine_common::types::*;
 use radix_engine_common::ScryptoSbor;
 
@@ -18,6 +20,12 @@ pub enum TwoResourcePoolError {
     },
     PoolCreationWithSameResource,
     ContributionOfEmptyBucketError,
+    SingleSidedContributionsAreDisabled,
+    SingleSidedContributionToEmptyPool,
+    InvalidSingleSidedContributionFee {
+        fee: Decimal,
+    },
+    ContributionProtectionError(ContributionProtectionError),
 }
 
 impl From<TwoResourcePoolError> for RuntimeError {
@@ -25,3 +33,9 @@ impl From<TwoResourcePoolError> for RuntimeError {
         Self::ApplicationError(ApplicationError::TwoResourcePoolError(error))
     }
 }
+
+impl From<ContributionProtectionError> for TwoResourcePoolError {
+    fn from(error: ContributionProtectionError) -> Self {
+        Self::ContributionProtectionError(error)
+    }
+}
@@ -26,3 +26,16 @@ pub struct DepositEvent {
     pub resource_address: ResourceAddress,
     pub amount: Decimal,
 }
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct SingleSidedContributionEvent {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    pub pool_units_minted: Decimal,
+    /// The imbalance fee kept by the pool, in terms of the contributed resource.
+    pub fee: Decimal,
+    /// The amount of the contributed resource implicitly swapped for the other resources.
+    pub implied_swap_input: Decimal,
+    /// The amounts of the other resources implicitly received in exchange.
+    pub implied_swap_output: BTreeMap<ResourceAddress, Decimal>,
+}
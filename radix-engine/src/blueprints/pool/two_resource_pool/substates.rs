@@ -1,6 +1,8 @@
+use crate::blueprints::pool::LockedLiquidity;
 use native_sdk::resource::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
+use radix_engine_interface::blueprints::pool::PoolContributionProtection;
 use radix_engine_interface::blueprints::resource::*;
 
 #[derive(Debug, PartialEq, Eq, ScryptoSbor)]
@@ -12,6 +14,16 @@ pub struct TwoResourcePoolSubstate {
 
     /// The resource manager of the pool unit resource that the pool works with.
     pub pool_unit_resource_manager: ResourceManager,
+
+    /// The imbalance fee charged on single-sided contributions, as a fraction of the implicitly
+    /// swapped amount. Single-sided contributions are disabled when this is [`None`].
+    pub single_sided_contribution_fee: Option<Decimal>,
+
+    /// The protection of the pool against the inflation of the value of its pool units.
+    pub contribution_protection: PoolContributionProtection,
+
+    /// The minimum liquidity locked away by the first contribution to the pool, if any.
+    pub locked_liquidity: Option<LockedLiquidity>,
 }
 
 impl TwoResourcePoolSubstate {
@@ -34,6 +46,9 @@ impl Clone for TwoResourcePoolSubstate {
                 (*resource_address2, Vault(vault2.0.clone())),
             ],
             pool_unit_resource_manager: self.pool_unit_resource_manager.clone(),
+            single_sided_contribution_fee: self.single_sided_contribution_fee,
+            contribution_protection: self.contribution_protection.clone(),
+            locked_liquidity: self.locked_liquidity.clone(),
         }
     }
 }
@@ -22,3 +22,9 @@ pub enum RecallResourceEvent {
     Amount(Decimal),
     Ids(BTreeSet<NonFungibleLocalId>),
 }
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub enum BurnFromVaultEvent {
+    Amount(Decimal),
+    Ids(BTreeSet<NonFungibleLocalId>),
+}
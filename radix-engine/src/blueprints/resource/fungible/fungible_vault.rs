@@ -256,6 +256,29 @@ impl FungibleVaultBlueprint {
         Ok(())
     }
 
+    pub fn burn_from_vault<Y>(amount: Decimal, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::assert_recallable(api)?;
+
+        let divisibility = Self::get_divisibility(api)?;
+        if !check_fungible_amount(&amount, divisibility) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::InvalidAmount),
+            ));
+        }
+
+        let taken = Self::internal_take(amount, api)?;
+
+        let bucket = FungibleResourceManagerBlueprint::create_bucket(taken.amount(), api)?;
+        bucket.package_burn(api)?;
+
+        Runtime::emit_event(api, BurnFromVaultEvent::Amount(amount))?;
+
+        Ok(())
+    }
+
     //===================
     // Protected methods
     //===================
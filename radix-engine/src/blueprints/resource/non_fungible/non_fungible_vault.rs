@@ -38,6 +38,32 @@ impl NonFungibleVaultBlueprint {
         withdraw_strategy: WithdrawStrategy,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::assert_transferable(api)?;
+
+        Self::withdraw_advanced(amount, withdraw_strategy, api)
+    }
+
+    pub fn take_non_fungibles<Y>(
+        non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_transferable(api)?;
+
+        Self::withdraw_non_fungibles(non_fungible_local_ids, api)
+    }
+
+    /// Takes from the vault into a new bucket, regardless of whether the resource is soulbound.
+    fn withdraw_advanced<Y>(
+        amount: &Decimal,
+        withdraw_strategy: WithdrawStrategy,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
     {
@@ -57,7 +83,9 @@ impl NonFungibleVaultBlueprint {
         NonFungibleResourceManagerBlueprint::create_bucket(taken.into_ids(), api)
     }
 
-    pub fn take_non_fungibles<Y>(
+    /// Takes the given non-fungibles from the vault into a new bucket, regardless of whether the
+    /// resource is soulbound.
+    fn withdraw_non_fungibles<Y>(
         non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
@@ -108,6 +136,26 @@ impl NonFungibleVaultBlueprint {
         Ok(ids)
     }
 
+    /// Returns up to `limit` ids of the vault, starting after the `cursor` id, in an order which
+    /// is stable between pages. Only the page is loaded, so vaults of any size can be listed.
+    ///
+    /// Ids locked by proofs are not included.
+    pub fn get_non_fungible_local_ids_page<Y>(
+        limit: u32,
+        cursor: Option<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<Vec<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_index_scan_after_typed(
+            OBJECT_HANDLE_SELF,
+            NON_FUNGIBLE_VAULT_CONTENTS_INDEX,
+            cursor.map(|id| scrypto_encode(&id).unwrap()),
+            limit,
+        )
+    }
+
     pub fn recall<Y>(amount: Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
@@ -218,7 +266,7 @@ impl NonFungibleVaultBlueprint {
     {
         Self::assert_not_frozen(VaultFreezeFlags::BURN, api)?;
 
-        Self::take(&amount, api)?.package_burn(api)?;
+        Self::withdraw_advanced(&amount, WithdrawStrategy::Exact, api)?.package_burn(api)?;
         Ok(())
     }
 
@@ -231,7 +279,27 @@ impl NonFungibleVaultBlueprint {
     {
         Self::assert_not_frozen(VaultFreezeFlags::BURN, api)?;
 
-        Self::take_non_fungibles(non_fungible_local_ids, api)?.package_burn(api)?;
+        Self::withdraw_non_fungibles(non_fungible_local_ids, api)?.package_burn(api)?;
+        Ok(())
+    }
+
+    pub fn burn_from_vault<Y>(amount: Decimal, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::assert_recallable(api)?;
+
+        check_non_fungible_amount(&amount).map_err(|_| {
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::InvalidAmount))
+        })?;
+
+        let ids = Self::internal_take(amount, api)?.into_ids();
+
+        let bucket = NonFungibleResourceManagerBlueprint::create_bucket(ids.clone(), api)?;
+        bucket.package_burn(api)?;
+
+        Runtime::emit_event(api, BurnFromVaultEvent::Ids(ids))?;
+
         Ok(())
     }
 
@@ -333,6 +401,21 @@ impl NonFungibleVaultBlueprint {
         Ok(())
     }
 
+    /// Rejects withdrawals of soulbound resources. Their withdrawer role is already locked to
+    /// deny all, but checking the feature here keeps the guarantee independent of the auth module.
+    fn assert_transferable<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if api.actor_is_feature_enabled(OBJECT_HANDLE_OUTER_OBJECT, SOULBOUND_FEATURE)? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::NotTransferable),
+            ));
+        }
+
+        Ok(())
+    }
+
     fn assert_freezable<Y>(api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
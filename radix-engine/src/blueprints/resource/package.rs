@@ -79,6 +79,7 @@ const FUNGIBLE_VAULT_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME: &str =
 const FUNGIBLE_VAULT_LOCK_AMOUNT_EXPORT_NAME: &str = "lock_amount_FungibleVault";
 const FUNGIBLE_VAULT_UNLOCK_AMOUNT_EXPORT_NAME: &str = "unlock_amount_FungibleVault";
 const FUNGIBLE_VAULT_BURN_EXPORT_NAME: &str = "burn_FungibleVault";
+const FUNGIBLE_VAULT_BURN_FROM_VAULT_EXPORT_NAME: &str = "burn_from_vault_FungibleVault";
 
 const NON_FUNGIBLE_VAULT_TAKE_EXPORT_NAME: &str = "take_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_NonFungibleVault";
@@ -91,6 +92,7 @@ const NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_EXPORT_NAME: &str = "unlock_fungible
 const NON_FUNGIBLE_VAULT_UNLOCK_NON_FUNGIBLES_EXPORT_NAME: &str =
     "unlock_non_fungibles_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_BURN_EXPORT_NAME: &str = "burn_NonFungibleVault";
+const NON_FUNGIBLE_VAULT_BURN_FROM_VAULT_EXPORT_NAME: &str = "burn_from_vault_NonFungibleVault";
 
 const FUNGIBLE_BUCKET_TAKE_EXPORT_NAME: &str = "take_FungibleBucket";
 const FUNGIBLE_BUCKET_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_FungibleBucket";
@@ -671,6 +673,7 @@ impl ResourceNativePackage {
                     VAULT_RECALL_FEATURE.to_string(),
                     MINT_FEATURE.to_string(),
                     BURN_FEATURE.to_string(),
+                    SOULBOUND_FEATURE.to_string(),
                 ),
                 dependencies: btreeset!(),
                 schema: BlueprintSchemaInit {
@@ -909,6 +912,22 @@ impl ResourceNativePackage {
                     export: FUNGIBLE_VAULT_BURN_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                VAULT_BURN_FROM_VAULT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo {
+                        receiver: Receiver::SelfRefMut,
+                        ref_types: RefTypes::DIRECT_ACCESS,
+                    }),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultBurnFromVaultInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultBurnFromVaultOutput>(),
+                    ),
+                    export: FUNGIBLE_VAULT_BURN_FROM_VAULT_EXPORT_NAME.to_string(),
+                },
+            );
 
             let event_schema = event_schema! {
                 aggregator,
@@ -916,7 +935,8 @@ impl ResourceNativePackage {
                     LockFeeEvent,
                     WithdrawResourceEvent,
                     DepositResourceEvent,
-                    RecallResourceEvent
+                    RecallResourceEvent,
+                    BurnFromVaultEvent
                 ]
             };
 
@@ -959,6 +979,7 @@ impl ResourceNativePackage {
                             VAULT_RECALL_IDENT => [RECALLER_ROLE];
                             VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                             VAULT_BURN_IDENT => [BURNER_ROLE];
+                            VAULT_BURN_FROM_VAULT_IDENT => [BURNER_ROLE];
                             FUNGIBLE_VAULT_LOCK_FUNGIBLE_AMOUNT_IDENT => MethodAccessibility::OwnPackageOnly;
                             FUNGIBLE_VAULT_UNLOCK_FUNGIBLE_AMOUNT_IDENT => MethodAccessibility::OwnPackageOnly;
                         },
@@ -1125,6 +1146,17 @@ impl ResourceNativePackage {
                     export: NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleVaultGetNonFungibleLocalIdsPageInput>()),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleVaultGetNonFungibleLocalIdsPageOutput>()),
+                    export: NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT.to_string(),
+                },
+            );
             functions.insert(
                 NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -1173,6 +1205,22 @@ impl ResourceNativePackage {
                     export: NON_FUNGIBLE_VAULT_BURN_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                VAULT_BURN_FROM_VAULT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo {
+                        receiver: Receiver::SelfRefMut,
+                        ref_types: RefTypes::DIRECT_ACCESS,
+                    }),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultBurnFromVaultInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultBurnFromVaultOutput>(),
+                    ),
+                    export: NON_FUNGIBLE_VAULT_BURN_FROM_VAULT_EXPORT_NAME.to_string(),
+                },
+            );
             functions.insert(
                 NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -1191,7 +1239,8 @@ impl ResourceNativePackage {
                     LockFeeEvent,
                     WithdrawResourceEvent,
                     DepositResourceEvent,
-                    RecallResourceEvent
+                    RecallResourceEvent,
+                    BurnFromVaultEvent
                 ]
             };
 
@@ -1226,6 +1275,7 @@ impl ResourceNativePackage {
                         methods: method_auth_template! {
                             VAULT_GET_AMOUNT_IDENT => MethodAccessibility::Public;
                             NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT => MethodAccessibility::Public;
+                            NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT => MethodAccessibility::Public;
                             NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => MethodAccessibility::Public;
 
                             VAULT_TAKE_IDENT => [WITHDRAWER_ROLE];
@@ -1237,6 +1287,7 @@ impl ResourceNativePackage {
                             NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT => [RECALLER_ROLE];
                             VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                             VAULT_BURN_IDENT => [BURNER_ROLE];
+                            VAULT_BURN_FROM_VAULT_IDENT => [BURNER_ROLE];
                             NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT => [BURNER_ROLE];
 
                             NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_IDENT => MethodAccessibility::OwnPackageOnly;
@@ -2553,6 +2604,13 @@ impl ResourceNativePackage {
                 let rtn = FungibleVaultBlueprint::burn(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_VAULT_BURN_FROM_VAULT_EXPORT_NAME => {
+                let input: VaultBurnFromVaultInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::burn_from_vault(input.amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
 
             NON_FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME => {
                 let input: VaultTakeAdvancedInput = input.as_typed().map_err(|e| {
@@ -2637,6 +2695,18 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::get_non_fungible_local_ids(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT => {
+                let input: NonFungibleVaultGetNonFungibleLocalIdsPageInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleVaultBlueprint::get_non_fungible_local_ids_page(
+                    input.limit,
+                    input.cursor,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
                 let receiver = Runtime::get_node_id(api)?;
                 let input: NonFungibleVaultCreateProofOfNonFungiblesInput =
@@ -2671,6 +2741,13 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::burn(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_VAULT_BURN_FROM_VAULT_EXPORT_NAME => {
+                let input: VaultBurnFromVaultInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleVaultBlueprint::burn_from_vault(input.amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT => {
                 let input: NonFungibleVaultBurnNonFungiblesInput =
                     input.as_typed().map_err(|e| {
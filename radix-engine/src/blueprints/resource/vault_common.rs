@@ -11,6 +11,7 @@ pub enum VaultError {
     InvalidAmount,
     NotFreezable,
     NotRecallable,
+    NotTransferable,
     VaultIsFrozen,
 
     LockFeeNotRadixToken,
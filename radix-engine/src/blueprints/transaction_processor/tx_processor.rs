@@ -1,6 +1,8 @@
 use crate::blueprints::resource::WorktopSubstate;
 use crate::errors::ApplicationError;
+use crate::errors::CanBeAbortion;
 use crate::errors::RuntimeError;
+use crate::errors::SystemModuleError;
 use crate::kernel::kernel_api::KernelNodeApi;
 use crate::kernel::kernel_api::KernelSubstateApi;
 use crate::system::node_init::type_info_partition;
@@ -58,6 +60,22 @@ pub enum TransactionProcessorError {
     InvalidPackageSchema(DecodeError),
     NotPackageAddress(NodeId),
     NotGlobalAddress(NodeId),
+    NextCallReturnsResourceAssertionFailed {
+        expected: ResourceAssertion,
+        actual: ResourceAssertion,
+    },
+    NextCallReturnsUnexpectedResource(ResourceAddress),
+    NextCallReturnsValueAssertionFailed,
+    InvalidNextCallReturnsValue,
+    UnconsumedNextCallAssertion,
+    NoSavepointToRollBackTo,
+    SavepointNotRolledBack,
+    MetadataAssertionFailed {
+        address: GlobalAddress,
+        key: String,
+        expected: MetadataValue,
+        actual: Option<MetadataValue>,
+    },
 }
 
 pub struct TransactionProcessorBlueprint;
@@ -70,7 +88,6 @@ macro_rules! handle_call_method {
             api: $api,
         };
         let scrypto_value = transform($args, &mut processor_with_api)?;
-        $processor = processor_with_api.processor;
 
         let rtn = $api.call_method_advanced(
             $node_id,
@@ -127,289 +144,476 @@ impl TransactionProcessorBlueprint {
                 RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
             })?;
         let mut processor = TransactionProcessor::new(blobs, global_address_reservations);
+        let mut savepoints: Vec<TransactionProcessorSavepoint> = Vec::new();
+        let mut rolled_back_section: Option<RolledBackSection> = None;
         let mut outputs = Vec::new();
         for (index, inst) in instructions.into_iter().enumerate() {
             api.update_instruction_index(index)?;
 
-            let result = match inst {
-                InstructionV1::TakeAllFromWorktop { resource_address } => {
-                    let bucket = worktop.take_all(resource_address, api)?;
-                    processor.create_manifest_bucket(bucket)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::TakeFromWorktop {
-                    amount,
-                    resource_address,
-                } => {
-                    let bucket = worktop.take(resource_address, amount, api)?;
-                    processor.create_manifest_bucket(bucket)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::TakeNonFungiblesFromWorktop {
-                    ids,
-                    resource_address,
-                } => {
-                    let bucket = worktop.take_non_fungibles(
-                        resource_address,
-                        ids.into_iter().collect(),
-                        api,
-                    )?;
-                    processor.create_manifest_bucket(bucket)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::ReturnToWorktop { bucket_id } => {
-                    let bucket = processor.take_bucket(&bucket_id)?;
-                    worktop.put(bucket, api)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::AssertWorktopContainsAny { resource_address } => {
-                    worktop.assert_contains(resource_address, api)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::AssertWorktopContains {
-                    amount,
-                    resource_address,
-                } => {
-                    worktop.assert_contains_amount(resource_address, amount, api)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::AssertWorktopContainsNonFungibles {
-                    ids,
-                    resource_address,
-                } => {
-                    worktop.assert_contains_non_fungibles(
-                        resource_address,
-                        ids.into_iter().collect(),
-                        api,
-                    )?;
-                    InstructionOutput::None
-                }
-                InstructionV1::PopFromAuthZone {} => {
-                    let proof = LocalAuthZone::pop(api)?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::ClearAuthZone => {
-                    LocalAuthZone::clear(api)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::ClearSignatureProofs => {
-                    LocalAuthZone::clear_signature_proofs(api)?;
+            // Skip the rest of a rolled back section, up to its `ROLLBACK_TO_SAVEPOINT`
+            if let Some(mut section) = rolled_back_section.take() {
+                let output = match inst {
+                    InstructionV1::Savepoint => {
+                        section.nested_savepoints += 1;
+                        rolled_back_section = Some(section);
+                        InstructionOutput::None
+                    }
+                    InstructionV1::RollbackToSavepoint if section.nested_savepoints > 0 => {
+                        section.nested_savepoints -= 1;
+                        rolled_back_section = Some(section);
+                        InstructionOutput::None
+                    }
+                    InstructionV1::RollbackToSavepoint => InstructionOutput::RolledBack {
+                        failed_instruction_index: section.failed_instruction_index,
+                        error: format!("{:?}", section.error),
+                    },
+                    _ => {
+                        rolled_back_section = Some(section);
+                        InstructionOutput::None
+                    }
+                };
+                outputs.push(output);
+                continue;
+            }
+
+            let output = match inst {
+                InstructionV1::Savepoint => {
+                    api.kernel_create_checkpoint()?;
+                    savepoints.push(TransactionProcessorSavepoint {
+                        processor: processor.savepoint(),
+                        instruction_index: index,
+                    });
                     InstructionOutput::None
                 }
-                InstructionV1::PushToAuthZone { proof_id } => {
-                    let proof = processor.take_proof(&proof_id)?;
-                    LocalAuthZone::push(proof, api)?;
+                InstructionV1::RollbackToSavepoint => {
+                    // The section succeeded, so all its changes are kept
+                    savepoints.pop().ok_or(RuntimeError::ApplicationError(
+                        ApplicationError::TransactionProcessorError(
+                            TransactionProcessorError::NoSavepointToRollBackTo,
+                        ),
+                    ))?;
+                    api.kernel_release_checkpoint()?;
                     InstructionOutput::None
                 }
-                InstructionV1::CreateProofFromAuthZoneOfAmount {
-                    amount,
+                inst => match Self::execute_instruction(inst, worktop, &mut processor, api) {
+                    Ok(output) => output,
+                    Err(error) if !savepoints.is_empty() && can_be_rolled_back(&error) => {
+                        let savepoint = savepoints.pop().unwrap();
+                        api.kernel_revert_to_checkpoint()?;
+                        processor.revert_to_savepoint(savepoint.processor);
+                        for output in &mut outputs[savepoint.instruction_index..] {
+                            *output = InstructionOutput::None;
+                        }
+                        rolled_back_section = Some(RolledBackSection {
+                            failed_instruction_index: index as u32,
+                            error,
+                            nested_savepoints: 0,
+                        });
+                        InstructionOutput::None
+                    }
+                    Err(error) => return Err(error),
+                },
+            };
+            outputs.push(output);
+        }
+
+        if !savepoints.is_empty() || rolled_back_section.is_some() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::TransactionProcessorError(
+                    TransactionProcessorError::SavepointNotRolledBack,
+                ),
+            ));
+        }
+
+        if !processor.next_call_assertions.is_empty() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::TransactionProcessorError(
+                    TransactionProcessorError::UnconsumedNextCallAssertion,
+                ),
+            ));
+        }
+
+        worktop.drop(api)?;
+
+        Ok(outputs)
+    }
+
+    fn execute_instruction<Y, L: Default>(
+        inst: InstructionV1,
+        worktop: Worktop,
+        processor: &mut TransactionProcessor,
+        api: &mut Y,
+    ) -> Result<InstructionOutput, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let output = match inst {
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                let bucket = worktop.take_all(resource_address, api)?;
+                processor.create_manifest_bucket(bucket)?;
+                InstructionOutput::None
+            }
+            InstructionV1::TakeFromWorktop {
+                amount,
+                resource_address,
+            } => {
+                let bucket = worktop.take(resource_address, amount, api)?;
+                processor.create_manifest_bucket(bucket)?;
+                InstructionOutput::None
+            }
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                ids,
+                resource_address,
+            } => {
+                let bucket =
+                    worktop.take_non_fungibles(resource_address, ids.into_iter().collect(), api)?;
+                processor.create_manifest_bucket(bucket)?;
+                InstructionOutput::None
+            }
+            InstructionV1::ReturnToWorktop { bucket_id } => {
+                let bucket = processor.take_bucket(&bucket_id)?;
+                worktop.put(bucket, api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::AssertWorktopContainsAny { resource_address } => {
+                worktop.assert_contains(resource_address, api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::AssertWorktopContains {
+                amount,
+                resource_address,
+            } => {
+                worktop.assert_contains_amount(resource_address, amount, api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::AssertWorktopContainsNonFungibles {
+                ids,
+                resource_address,
+            } => {
+                worktop.assert_contains_non_fungibles(
                     resource_address,
-                } => {
-                    let proof =
-                        LocalAuthZone::create_proof_of_amount(amount, resource_address, api)?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
+                    ids.into_iter().collect(),
+                    api,
+                )?;
+                InstructionOutput::None
+            }
+            InstructionV1::AssertNextCallReturnsInclude { resources } => {
+                processor
+                    .next_call_assertions
+                    .push(NextCallAssertion::Include(resources));
+                InstructionOutput::None
+            }
+            InstructionV1::AssertNextCallReturnsExactly { resources } => {
+                processor
+                    .next_call_assertions
+                    .push(NextCallAssertion::Exactly(resources));
+                InstructionOutput::None
+            }
+            InstructionV1::AssertNextCallReturnsValue { value } => {
+                let mut handler = NextCallReturnsValueTransformHandler { processor };
+                let scrypto_value = transform(value, &mut handler)?;
+                processor
+                    .next_call_assertions
+                    .push(NextCallAssertion::Value(
+                        scrypto_encode(&scrypto_value).unwrap(),
+                    ));
+                InstructionOutput::None
+            }
+            InstructionV1::AssertMetadata {
+                address,
+                key,
+                value,
+            } => {
+                let address = processor.resolve_global_address(address)?;
+                let rtn = api.call_method_advanced(
+                    address.as_node_id(),
+                    false,
+                    ObjectModuleId::Metadata,
+                    METADATA_GET_IDENT,
+                    scrypto_encode(&MetadataGetInput { key: key.clone() }).unwrap(),
+                )?;
+                let actual: MetadataGetOutput = scrypto_decode(&rtn).unwrap();
+                if actual.as_ref() != Some(&value) {
+                    return Err(RuntimeError::ApplicationError(
+                        ApplicationError::TransactionProcessorError(
+                            TransactionProcessorError::MetadataAssertionFailed {
+                                address,
+                                key,
+                                expected: value,
+                                actual,
+                            },
+                        ),
+                    ));
                 }
-                InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
-                    ids,
+                InstructionOutput::None
+            }
+            InstructionV1::PopFromAuthZone {} => {
+                let proof = LocalAuthZone::pop(api)?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::ClearAuthZone => {
+                LocalAuthZone::clear(api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::ClearSignatureProofs => {
+                LocalAuthZone::clear_signature_proofs(api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::PushToAuthZone { proof_id } => {
+                let proof = processor.take_proof(&proof_id)?;
+                LocalAuthZone::push(proof, api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                amount,
+                resource_address,
+            } => {
+                let proof = LocalAuthZone::create_proof_of_amount(amount, resource_address, api)?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                ids,
+                resource_address,
+            } => {
+                let proof = LocalAuthZone::create_proof_of_non_fungibles(
+                    &ids.into_iter().collect(),
                     resource_address,
-                } => {
-                    let proof = LocalAuthZone::create_proof_of_non_fungibles(
-                        &ids.into_iter().collect(),
-                        resource_address,
-                        api,
-                    )?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => {
-                    let proof = LocalAuthZone::create_proof_of_all(resource_address, api)?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount } => {
-                    let bucket = processor.get_bucket(&bucket_id)?;
-                    let proof = bucket.create_proof_of_amount(amount, api)?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids } => {
-                    let bucket = processor.get_bucket(&bucket_id)?;
-                    let proof =
-                        bucket.create_proof_of_non_fungibles(ids.into_iter().collect(), api)?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
-                    let bucket = processor.get_bucket(&bucket_id)?;
-                    let proof = bucket.create_proof_of_all(api)?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::BurnResource { bucket_id } => {
-                    let bucket = processor.take_bucket(&bucket_id)?;
-                    let rtn = bucket.burn(api)?;
+                    api,
+                )?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => {
+                let proof = LocalAuthZone::create_proof_of_all(resource_address, api)?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount } => {
+                let bucket = processor.get_bucket(&bucket_id)?;
+                let proof = bucket.create_proof_of_amount(amount, api)?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids } => {
+                let bucket = processor.get_bucket(&bucket_id)?;
+                let proof = bucket.create_proof_of_non_fungibles(ids.into_iter().collect(), api)?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                let bucket = processor.get_bucket(&bucket_id)?;
+                let proof = bucket.create_proof_of_all(api)?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::BurnResource { bucket_id } => {
+                let bucket = processor.take_bucket(&bucket_id)?;
+                let rtn = bucket.burn(api)?;
 
-                    let result = IndexedScryptoValue::from_typed(&rtn);
-                    processor.handle_call_return_data(&result, &worktop, api)?;
-                    InstructionOutput::CallReturn(result.into())
-                }
-                InstructionV1::CloneProof { proof_id } => {
-                    let proof = processor.get_proof(&proof_id)?;
-                    let proof = proof.clone(api)?;
-                    processor.create_manifest_proof(proof)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::DropProof { proof_id } => {
-                    let proof = processor.take_proof(&proof_id)?;
-                    proof.drop(api)?;
-                    InstructionOutput::None
-                }
-                InstructionV1::CallFunction {
+                let result = IndexedScryptoValue::from_typed(&rtn);
+                processor.handle_call_return_data(&result, &worktop, api)?;
+                InstructionOutput::CallReturn(result.into())
+            }
+            InstructionV1::CloneProof { proof_id } => {
+                let proof = processor.get_proof(&proof_id)?;
+                let proof = proof.clone(api)?;
+                processor.create_manifest_proof(proof)?;
+                InstructionOutput::None
+            }
+            InstructionV1::DropProof { proof_id } => {
+                let proof = processor.take_proof(&proof_id)?;
+                proof.drop(api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                args,
+            } => {
+                let mut processor_with_api = TransactionProcessorWithApi {
+                    worktop,
+                    processor,
+                    api,
+                };
+                let scrypto_value = transform(args, &mut processor_with_api)?;
+
+                let package_address = processor.resolve_package_address(package_address)?;
+                let rtn = api.call_function(
                     package_address,
-                    blueprint_name,
-                    function_name,
-                    args,
-                } => {
-                    let mut processor_with_api = TransactionProcessorWithApi {
-                        worktop,
-                        processor,
-                        api,
-                    };
-                    let scrypto_value = transform(args, &mut processor_with_api)?;
-                    processor = processor_with_api.processor;
-
-                    let package_address = processor.resolve_package_address(package_address)?;
-                    let rtn = api.call_function(
-                        package_address,
-                        &blueprint_name,
-                        &function_name,
-                        scrypto_encode(&scrypto_value).unwrap(),
-                    )?;
+                    &blueprint_name,
+                    &function_name,
+                    scrypto_encode(&scrypto_value).unwrap(),
+                )?;
 
-                    let result = IndexedScryptoValue::from_vec(rtn).unwrap();
-                    processor.handle_call_return_data(&result, &worktop, api)?;
-                    InstructionOutput::CallReturn(result.into())
-                }
-                InstructionV1::CallMethod {
-                    address,
+                let result = IndexedScryptoValue::from_vec(rtn).unwrap();
+                processor.handle_call_return_data(&result, &worktop, api)?;
+                InstructionOutput::CallReturn(result.into())
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let address = processor.resolve_global_address(address)?;
+                handle_call_method!(
+                    ObjectModuleId::Main,
+                    address.as_node_id(),
+                    false,
                     method_name,
                     args,
-                } => {
-                    let address = processor.resolve_global_address(address)?;
-                    handle_call_method!(
-                        ObjectModuleId::Main,
-                        address.as_node_id(),
-                        false,
-                        method_name,
-                        args,
-                        worktop,
-                        processor,
-                        api
-                    )
-                }
-                InstructionV1::CallRoyaltyMethod {
-                    address,
+                    worktop,
+                    processor,
+                    api
+                )
+            }
+            InstructionV1::CallRoyaltyMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let address = processor.resolve_global_address(address)?;
+                handle_call_method!(
+                    ObjectModuleId::Royalty,
+                    address.as_node_id(),
+                    false,
                     method_name,
                     args,
-                } => {
-                    let address = processor.resolve_global_address(address)?;
-                    handle_call_method!(
-                        ObjectModuleId::Royalty,
-                        address.as_node_id(),
-                        false,
-                        method_name,
-                        args,
-                        worktop,
-                        processor,
-                        api
-                    )
-                }
-                InstructionV1::CallMetadataMethod {
-                    address,
+                    worktop,
+                    processor,
+                    api
+                )
+            }
+            InstructionV1::CallMetadataMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let address = processor.resolve_global_address(address)?;
+                handle_call_method!(
+                    ObjectModuleId::Metadata,
+                    address.as_node_id(),
+                    false,
                     method_name,
                     args,
-                } => {
-                    let address = processor.resolve_global_address(address)?;
-                    handle_call_method!(
-                        ObjectModuleId::Metadata,
-                        address.as_node_id(),
-                        false,
-                        method_name,
-                        args,
-                        worktop,
-                        processor,
-                        api
-                    )
-                }
-                InstructionV1::CallAccessRulesMethod {
-                    address,
+                    worktop,
+                    processor,
+                    api
+                )
+            }
+            InstructionV1::CallAccessRulesMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let address = processor.resolve_global_address(address)?;
+                handle_call_method!(
+                    ObjectModuleId::AccessRules,
+                    address.as_node_id(),
+                    false,
                     method_name,
                     args,
-                } => {
-                    let address = processor.resolve_global_address(address)?;
-                    handle_call_method!(
-                        ObjectModuleId::AccessRules,
-                        address.as_node_id(),
-                        false,
-                        method_name,
-                        args,
-                        worktop,
-                        processor,
-                        api
-                    )
-                }
-                InstructionV1::CallDirectVaultMethod {
-                    address,
+                    worktop,
+                    processor,
+                    api
+                )
+            }
+            InstructionV1::CallDirectVaultMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                handle_call_method!(
+                    ObjectModuleId::Main,
+                    address.as_node_id(),
+                    true,
                     method_name,
                     args,
-                } => {
-                    handle_call_method!(
-                        ObjectModuleId::Main,
-                        address.as_node_id(),
-                        true,
-                        method_name,
-                        args,
-                        worktop,
-                        processor,
-                        api
-                    )
-                }
-                InstructionV1::DropAllProofs => {
-                    // NB: the difference between DROP_ALL_PROOFS and CLEAR_AUTH_ZONE is that
-                    // the former will drop all named proofs before clearing the auth zone.
+                    worktop,
+                    processor,
+                    api
+                )
+            }
+            InstructionV1::DropAllProofs => {
+                // NB: the difference between DROP_ALL_PROOFS and CLEAR_AUTH_ZONE is that
+                // the former will drop all named proofs before clearing the auth zone.
 
-                    for (_, real_id) in processor.proof_mapping.drain(..) {
-                        let proof = Proof(Own(real_id));
-                        proof.drop(api).map(|_| IndexedScryptoValue::unit())?;
-                    }
-                    LocalAuthZone::clear(api)?;
-                    InstructionOutput::None
+                for (_, real_id) in processor.proof_mapping.drain(..) {
+                    let proof = Proof(Own(real_id));
+                    proof.drop(api).map(|_| IndexedScryptoValue::unit())?;
                 }
-                InstructionV1::AllocateGlobalAddress {
-                    package_address,
-                    blueprint_name,
-                } => {
-                    let (address_reservation, address) = api.allocate_global_address(
-                        BlueprintId::new(&package_address, blueprint_name),
-                    )?;
-                    processor.create_manifest_address_reservation(address_reservation)?;
-                    processor.create_manifest_address(address)?;
+                LocalAuthZone::clear(api)?;
+                InstructionOutput::None
+            }
+            InstructionV1::Savepoint | InstructionV1::RollbackToSavepoint => {
+                unreachable!("Savepoint instructions are handled by the run loop")
+            }
+            InstructionV1::AllocateGlobalAddress {
+                package_address,
+                blueprint_name,
+            } => {
+                let (address_reservation, address) = api
+                    .allocate_global_address(BlueprintId::new(&package_address, blueprint_name))?;
+                processor.create_manifest_address_reservation(address_reservation)?;
+                processor.create_manifest_address(address)?;
 
-                    InstructionOutput::None
-                }
-            };
-            outputs.push(result);
-        }
+                InstructionOutput::None
+            }
+        };
+        Ok(output)
+    }
+}
 
-        worktop.drop(api)?;
+#[derive(Clone)]
+enum NextCallAssertion {
+    Include(Vec<ResourceAssertion>),
+    Exactly(Vec<ResourceAssertion>),
+    /// The scrypto encoded value the next call is expected to return
+    Value(Vec<u8>),
+}
 
-        Ok(outputs)
-    }
+/// A `SAVEPOINT` which hasn't been rolled back to yet.
+struct TransactionProcessorSavepoint {
+    processor: ProcessorSavepoint,
+    /// The index of the `SAVEPOINT` instruction
+    instruction_index: usize,
+}
+
+/// A section which failed and was rolled back, whose remaining instructions are skipped.
+struct RolledBackSection {
+    failed_instruction_index: u32,
+    error: RuntimeError,
+    /// The number of sections opened, and not yet closed, since the failure
+    nested_savepoints: usize,
+}
+
+/// Whether the section in which the given error occurred can be rolled back.
+///
+/// Aborting the transaction or running out of fees isn't recoverable.
+fn can_be_rolled_back(error: &RuntimeError) -> bool {
+    error.abortion().is_none()
+        && !matches!(
+            error,
+            RuntimeError::SystemModuleError(SystemModuleError::CostingError(_))
+        )
+}
+
+/// The named ids of the processor at a `SAVEPOINT`.
+///
+/// The id allocator isn't included, so that the ids allocated after a rollback keep matching the
+/// ones allocated by the manifest compiler.
+struct ProcessorSavepoint {
+    next_call_assertions: Vec<NextCallAssertion>,
+    bucket_mapping: NonIterMap<ManifestBucket, NodeId>,
+    proof_mapping: IndexMap<ManifestProof, NodeId>,
+    address_reservation_mapping: NonIterMap<ManifestAddressReservation, NodeId>,
+    address_mapping: NonIterMap<u32, NodeId>,
 }
 
 struct TransactionProcessor {
+    next_call_assertions: Vec<NextCallAssertion>,
     bucket_mapping: NonIterMap<ManifestBucket, NodeId>,
     proof_mapping: IndexMap<ManifestProof, NodeId>,
     address_reservation_mapping: NonIterMap<ManifestAddressReservation, NodeId>,
@@ -424,6 +628,7 @@ impl TransactionProcessor {
         global_address_reservations: Vec<GlobalAddressReservation>,
     ) -> Self {
         let mut processor = Self {
+            next_call_assertions: Vec::new(),
             blobs_by_hash,
             proof_mapping: index_map_new(),
             bucket_mapping: NonIterMap::new(),
@@ -440,6 +645,24 @@ impl TransactionProcessor {
         processor
     }
 
+    fn savepoint(&self) -> ProcessorSavepoint {
+        ProcessorSavepoint {
+            next_call_assertions: self.next_call_assertions.clone(),
+            bucket_mapping: self.bucket_mapping.clone(),
+            proof_mapping: self.proof_mapping.clone(),
+            address_reservation_mapping: self.address_reservation_mapping.clone(),
+            address_mapping: self.address_mapping.clone(),
+        }
+    }
+
+    fn revert_to_savepoint(&mut self, savepoint: ProcessorSavepoint) {
+        self.next_call_assertions = savepoint.next_call_assertions;
+        self.bucket_mapping = savepoint.bucket_mapping;
+        self.proof_mapping = savepoint.proof_mapping;
+        self.address_reservation_mapping = savepoint.address_reservation_mapping;
+        self.address_mapping = savepoint.address_mapping;
+    }
+
     fn get_bucket(&mut self, bucket_id: &ManifestBucket) -> Result<Bucket, RuntimeError> {
         let real_id =
             self.bucket_mapping
@@ -601,6 +824,8 @@ impl TransactionProcessor {
     where
         Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
+        self.check_next_call_assertions(value, api)?;
+
         // Auto move into worktop & auth_zone
         for node_id in value.owned_nodes() {
             let info = TypeInfoBlueprint::get_type(node_id, api)?;
@@ -633,11 +858,188 @@ impl TransactionProcessor {
 
         Ok(())
     }
+
+    fn check_next_call_assertions<Y, L: Default>(
+        &mut self,
+        value: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        if self.next_call_assertions.is_empty() {
+            return Ok(());
+        }
+
+        // Sum up the returned resources before they are moved into the worktop
+        let mut returned: IndexMap<ResourceAddress, (Decimal, BTreeSet<NonFungibleLocalId>)> =
+            index_map_new();
+        for node_id in value.owned_nodes() {
+            if let TypeInfoSubstate::Object(info) = TypeInfoBlueprint::get_type(node_id, api)? {
+                if info.blueprint_id.package_address != RESOURCE_PACKAGE {
+                    continue;
+                }
+                let bucket = Bucket(Own(node_id.clone()));
+                match info.blueprint_id.blueprint_name.as_str() {
+                    FUNGIBLE_BUCKET_BLUEPRINT => {
+                        let resource_address = bucket.resource_address(api)?;
+                        let amount = bucket.amount(api)?;
+                        let entry = returned
+                            .entry(resource_address)
+                            .or_insert((Decimal::ZERO, btreeset!()));
+                        entry.0 += amount;
+                    }
+                    NON_FUNGIBLE_BUCKET_BLUEPRINT => {
+                        let resource_address = bucket.resource_address(api)?;
+                        let ids = bucket.non_fungible_local_ids(api)?;
+                        let entry = returned
+                            .entry(resource_address)
+                            .or_insert((Decimal::ZERO, btreeset!()));
+                        entry.1.extend(ids);
+                        entry.0 = Decimal::from(entry.1.len());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for assertion in self.next_call_assertions.drain(..) {
+            match assertion {
+                NextCallAssertion::Include(resources) => {
+                    for expected in resources {
+                        check_returned_resource(expected, &returned, false)?;
+                    }
+                }
+                NextCallAssertion::Exactly(resources) => {
+                    for (resource_address, (amount, _)) in &returned {
+                        if amount.is_positive()
+                            && !resources
+                                .iter()
+                                .any(|r| r.resource_address().eq(resource_address))
+                        {
+                            return Err(RuntimeError::ApplicationError(
+                                ApplicationError::TransactionProcessorError(
+                                    TransactionProcessorError::NextCallReturnsUnexpectedResource(
+                                        *resource_address,
+                                    ),
+                                ),
+                            ));
+                        }
+                    }
+                    for expected in resources {
+                        check_returned_resource(expected, &returned, true)?;
+                    }
+                }
+                NextCallAssertion::Value(expected) => {
+                    if value.as_slice() != expected.as_slice() {
+                        return Err(RuntimeError::ApplicationError(
+                            ApplicationError::TransactionProcessorError(
+                                TransactionProcessorError::NextCallReturnsValueAssertionFailed,
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn check_returned_resource(
+    expected: ResourceAssertion,
+    returned: &IndexMap<ResourceAddress, (Decimal, BTreeSet<NonFungibleLocalId>)>,
+    exact: bool,
+) -> Result<(), RuntimeError> {
+    let (amount, ids) = returned
+        .get(&expected.resource_address())
+        .cloned()
+        .unwrap_or_default();
+
+    let (satisfied, actual) = match &expected {
+        ResourceAssertion::Amount(resource_address, expected_amount) => {
+            let satisfied = if exact {
+                amount.eq(expected_amount)
+            } else {
+                amount.ge(expected_amount)
+            };
+            (
+                satisfied,
+                ResourceAssertion::Amount(*resource_address, amount),
+            )
+        }
+        ResourceAssertion::NonFungibles(resource_address, expected_ids) => {
+            let satisfied = expected_ids.iter().all(|id| ids.contains(id))
+                && (!exact || expected_ids.len() == ids.len());
+            (
+                satisfied,
+                ResourceAssertion::NonFungibles(*resource_address, ids.into_iter().collect()),
+            )
+        }
+    };
+
+    if satisfied {
+        Ok(())
+    } else {
+        Err(RuntimeError::ApplicationError(
+            ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::NextCallReturnsResourceAssertionFailed {
+                    expected,
+                    actual,
+                },
+            ),
+        ))
+    }
+}
+
+/// Converts the expected value of an `ASSERT_NEXT_CALL_RETURNS_VALUE` instruction, which can
+/// only be plain data, so that it can be compared with the value returned by the next call.
+struct NextCallReturnsValueTransformHandler<'a> {
+    processor: &'a mut TransactionProcessor,
+}
+
+impl<'a> NextCallReturnsValueTransformHandler<'a> {
+    fn invalid_value<T>() -> Result<T, RuntimeError> {
+        Err(RuntimeError::ApplicationError(
+            ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::InvalidNextCallReturnsValue,
+            ),
+        ))
+    }
+}
+
+impl<'a> TransformHandler<RuntimeError> for NextCallReturnsValueTransformHandler<'a> {
+    fn replace_bucket(&mut self, _b: ManifestBucket) -> Result<Own, RuntimeError> {
+        Self::invalid_value()
+    }
+
+    fn replace_proof(&mut self, _p: ManifestProof) -> Result<Own, RuntimeError> {
+        Self::invalid_value()
+    }
+
+    fn replace_address_reservation(
+        &mut self,
+        _r: ManifestAddressReservation,
+    ) -> Result<Own, RuntimeError> {
+        Self::invalid_value()
+    }
+
+    fn replace_named_address(&mut self, a: u32) -> Result<Reference, RuntimeError> {
+        self.processor.get_address(&a).map(|x| Reference(x))
+    }
+
+    fn replace_expression(&mut self, _e: ManifestExpression) -> Result<Vec<Own>, RuntimeError> {
+        Self::invalid_value()
+    }
+
+    fn replace_blob(&mut self, b: ManifestBlobRef) -> Result<Vec<u8>, RuntimeError> {
+        Ok(self.processor.get_blob(&b)?.to_vec())
+    }
 }
 
 struct TransactionProcessorWithApi<'a, Y: ClientApi<RuntimeError>> {
     worktop: Worktop,
-    processor: TransactionProcessor,
+    processor: &'a mut TransactionProcessor,
     api: &'a mut Y,
 }
 
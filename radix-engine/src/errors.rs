@@ -1,5 +1,5 @@
 use crate::blueprints::access_controller::AccessControllerError;
-use crate::blueprints::account::AccountError;
+use crate::blueprints::account::{AccountError, AccountLockerError};
 use crate::blueprints::consensus_manager::{ConsensusManagerError, ValidatorError};
 use crate::blueprints::package::PackageError;
 use crate::blueprints::pool::multi_resource_pool::MultiResourcePoolError;
@@ -149,6 +149,9 @@ pub enum KernelError {
 
     // Invoke
     InvalidInvokeAccess,
+
+    // Checkpoint
+    CheckpointNotFound,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -251,6 +254,18 @@ pub enum EventError {
     EventSchemaNotMatch(String),
     NoAssociatedPackage,
     InvalidActor,
+    LimitExceeded {
+        limit: EventLimit,
+        actual: usize,
+        max: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub enum EventLimit {
+    EventCount,
+    EventPayloadSize,
+    TotalEventSize,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -456,6 +471,8 @@ pub enum ApplicationError {
 
     AccountError(AccountError),
 
+    AccountLockerError(AccountLockerError),
+
     AccessControllerError(AccessControllerError),
 
     OneResourcePoolError(OneResourcePoolError),
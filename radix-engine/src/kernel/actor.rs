@@ -31,7 +31,7 @@ impl MethodActor {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum Actor {
     Root,
     Method(MethodActor),
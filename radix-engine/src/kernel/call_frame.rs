@@ -115,6 +115,7 @@ impl NodeVisibility {
 
 /// A call frame is the basic unit that forms a transaction call stack, which keeps track of the
 /// owned objects and references by this function.
+#[derive(Clone)]
 pub struct CallFrame<L> {
     /// The frame id
     depth: usize,
@@ -996,6 +997,41 @@ impl<L: Clone> CallFrame<L> {
         Ok((substates, store_access))
     }
 
+    pub fn scan_substates_after<'f, S: SubstateStore>(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        cursor: Option<&SubstateKey>,
+        count: u32,
+        heap: &'f mut Heap,
+        store: &'f mut S,
+    ) -> Result<(Vec<IndexedScryptoValue>, StoreAccessInfo), CallFrameScanSubstateError> {
+        // Check node visibility
+        if !self.get_node_visibility(node_id).can_be_read_or_write() {
+            return Err(CallFrameScanSubstateError::NodeNotVisible(node_id.clone()));
+        }
+
+        let (substates, store_access) = if heap.contains_node(node_id) {
+            (
+                heap.scan_substates_after(node_id, partition_num, cursor, count),
+                StoreAccessInfo::new(),
+            )
+        } else {
+            store.scan_substates_after(node_id, partition_num, cursor, count)
+        };
+
+        for substate in &substates {
+            for reference in substate.references() {
+                if reference.is_global() {
+                    self.stable_references
+                        .insert(reference.clone(), StableReferenceType::Global);
+                }
+            }
+        }
+
+        Ok((substates, store_access))
+    }
+
     pub fn take_substates<'f, S: SubstateStore>(
         &mut self,
         node_id: &NodeId,
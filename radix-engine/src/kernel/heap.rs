@@ -6,15 +6,54 @@ use radix_engine_interface::blueprints::resource::{
     LockedNonFungibleResource,
 };
 use sbor::rust::collections::btree_map::Entry;
+use sbor::rust::ops::Bound;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct HeapNode {
     substates: NodeSubstates,
     borrow_count: usize,
 }
 
+impl HeapNode {
+    fn substate_size(&self) -> usize {
+        self.substates
+            .values()
+            .flat_map(|module| module.values())
+            .map(|value| value.len())
+            .sum()
+    }
+}
+
+/// The number of nodes on the heap, and the total size of their substates.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct HeapUsage {
+    pub node_count: usize,
+    /// The total size of the substates of the nodes, in bytes.
+    pub substate_size: usize,
+}
+
+impl HeapUsage {
+    /// The usage components which exceed those of `other`, and zero for the others.
+    pub fn saturating_sub(&self, other: &HeapUsage) -> HeapUsage {
+        HeapUsage {
+            node_count: self.node_count.saturating_sub(other.node_count),
+            substate_size: self.substate_size.saturating_sub(other.substate_size),
+        }
+    }
+
+    /// The largest of each usage component.
+    pub fn max(&self, other: &HeapUsage) -> HeapUsage {
+        HeapUsage {
+            node_count: self.node_count.max(other.node_count),
+            substate_size: self.substate_size.max(other.substate_size),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Heap {
     nodes: NonIterMap<NodeId, HeapNode>,
+    usage: HeapUsage,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -39,9 +78,14 @@ impl Heap {
     pub fn new() -> Self {
         Self {
             nodes: NonIterMap::new(),
+            usage: HeapUsage::default(),
         }
     }
 
+    pub fn usage(&self) -> HeapUsage {
+        self.usage
+    }
+
     /// Checks if the given node is in this heap.
     pub fn contains_node(&self, node_id: &NodeId) -> bool {
         self.nodes.contains_key(node_id)
@@ -62,6 +106,7 @@ impl Heap {
             let module = modules
                 .remove(&partition_number)
                 .ok_or(HeapRemoveModuleError::ModuleNotFound(partition_number))?;
+            self.usage.substate_size -= module.values().map(|value| value.len()).sum::<usize>();
             Ok(module)
         } else {
             Err(HeapRemoveModuleError::NodeNotFound(node_id.clone()))
@@ -85,6 +130,7 @@ impl Heap {
             .entry(substate_key.clone());
         if let Entry::Vacant(e) = entry {
             let value = virtualize();
+            self.usage.substate_size += value.len();
             e.insert(value);
         }
 
@@ -116,13 +162,18 @@ impl Heap {
         substate_key: SubstateKey,
         substate_value: IndexedScryptoValue,
     ) {
-        self.nodes
+        self.usage.substate_size += substate_value.len();
+        let previous = self
+            .nodes
             .entry(node_id)
             .or_insert_with(|| HeapNode::default())
             .substates
             .entry(partition_num)
             .or_default()
             .insert(substate_key, substate_value);
+        if let Some(previous) = previous {
+            self.usage.substate_size -= previous.len();
+        }
     }
 
     pub fn delete_substate(
@@ -131,10 +182,15 @@ impl Heap {
         partition_num: PartitionNumber,
         substate_key: &SubstateKey,
     ) -> Option<IndexedScryptoValue> {
-        self.nodes
+        let removed = self
+            .nodes
             .get_mut(node_id)
             .and_then(|n| n.substates.get_mut(&partition_num))
-            .and_then(|s| s.remove(substate_key))
+            .and_then(|s| s.remove(substate_key));
+        if let Some(removed) = &removed {
+            self.usage.substate_size -= removed.len();
+        }
+        removed
     }
 
     pub fn scan_substates(
@@ -160,6 +216,36 @@ impl Heap {
         }
     }
 
+    /// Scans substates in the order of their keys, starting after the given key.
+    ///
+    /// Unlike the store, which orders substates by their database sort keys, the heap has no
+    /// knowledge of the key mapping, so the order is only consistent while the node is on heap.
+    pub fn scan_substates_after(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        cursor: Option<&SubstateKey>,
+        count: u32,
+    ) -> Vec<IndexedScryptoValue> {
+        let node_substates = self
+            .nodes
+            .get(node_id)
+            .and_then(|n| n.substates.get(&partition_num));
+        if let Some(substates) = node_substates {
+            let lower_bound = match cursor {
+                Some(cursor) => Bound::Excluded(cursor),
+                None => Bound::Unbounded,
+            };
+            substates
+                .range::<SubstateKey, _>((lower_bound, Bound::Unbounded))
+                .map(|(_key, v)| v.clone())
+                .take(count.try_into().unwrap())
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     pub fn take_substates(
         &mut self,
         node_id: &NodeId,
@@ -181,6 +267,7 @@ impl Heap {
 
             for key in keys {
                 let value = substates.remove(&key).unwrap();
+                self.usage.substate_size -= value.len();
                 items.push(value);
             }
 
@@ -192,13 +279,16 @@ impl Heap {
 
     /// Inserts a new node to heap.
     pub fn create_node(&mut self, node_id: NodeId, substates: NodeSubstates) {
-        self.nodes.insert(
-            node_id,
-            HeapNode {
-                substates,
-                borrow_count: 0,
-            },
-        );
+        let node = HeapNode {
+            substates,
+            borrow_count: 0,
+        };
+        self.usage.node_count += 1;
+        self.usage.substate_size += node.substate_size();
+        if let Some(previous) = self.nodes.insert(node_id, node) {
+            self.usage.node_count -= 1;
+            self.usage.substate_size -= previous.substate_size();
+        }
     }
 
     /// Removes node.
@@ -217,7 +307,10 @@ impl Heap {
             None => return Err(HeapRemoveNodeError::NodeNotFound(node_id.clone())),
         }
 
-        Ok(self.nodes.remove(node_id).unwrap().substates)
+        let node = self.nodes.remove(node_id).unwrap();
+        self.usage.node_count -= 1;
+        self.usage.substate_size -= node.substate_size();
+        Ok(node.substates)
     }
 
     pub fn increase_borrow_count(&mut self, node_id: &NodeId) {
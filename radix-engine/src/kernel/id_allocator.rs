@@ -35,16 +35,10 @@ impl IdAllocator {
     }
 
     fn next_node_id(&mut self, entity_type: EntityType) -> Result<NodeId, IdAllocationError> {
-        // Compute `hash(transaction_hash, index)`
-        let mut buf = [0u8; Hash::LENGTH + 4];
-        buf[..Hash::LENGTH].copy_from_slice(self.transaction_hash.as_ref());
-        buf[Hash::LENGTH..].copy_from_slice(&self.next()?.to_le_bytes());
-        let hash = hash(buf);
-
-        // Install the entity type
-        let mut node_id: [u8; NodeId::LENGTH] = hash.lower_bytes();
-        node_id[0] = entity_type as u8;
-
-        Ok(NodeId(node_id))
+        Ok(derive_transaction_node_id(
+            &self.transaction_hash,
+            self.next()?,
+            entity_type,
+        ))
     }
 }
@@ -1,6 +1,6 @@
 use super::actor::{Actor, MethodActor};
 use super::call_frame::{CallFrame, NodeVisibility, OpenSubstateError};
-use super::heap::Heap;
+use super::heap::{Heap, HeapUsage};
 use super::id_allocator::IdAllocator;
 use super::kernel_api::{
     KernelApi, KernelInternalApi, KernelInvokeApi, KernelNodeApi, KernelSubstateApi, LockInfo,
@@ -45,6 +45,7 @@ impl<'g, 'h, V: SystemCallbackObject, S: SubstateStore> KernelBoot<'g, V, S> {
             current_frame: CallFrame::new_root(Actor::Root),
             prev_frame_stack: vec![],
             callback: self.callback,
+            checkpoints: vec![],
         }
     }
 
@@ -68,6 +69,7 @@ impl<'g, 'h, V: SystemCallbackObject, S: SubstateStore> KernelBoot<'g, V, S> {
             current_frame: CallFrame::new_root(Actor::Root),
             prev_frame_stack: vec![],
             callback: self.callback,
+            checkpoints: vec![],
         };
 
         SystemConfig::on_init(&mut kernel)?;
@@ -197,6 +199,16 @@ pub struct Kernel<
 
     /// Upstream system layer
     callback: &'g mut M,
+
+    /// Checkpoints, see [`KernelNodeApi::kernel_create_checkpoint`]
+    checkpoints: Vec<KernelCheckpoint<M::LockData>>,
+}
+
+/// The call frames and the heap at a checkpoint.
+struct KernelCheckpoint<L> {
+    current_frame: CallFrame<L>,
+    prev_frame_stack: Vec<CallFrame<L>>,
+    heap: Heap,
 }
 
 impl<'g, M, S> Kernel<'g, M, S>
@@ -401,6 +413,46 @@ where
 
         Ok(())
     }
+
+    #[trace_resources]
+    fn kernel_create_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        M::on_create_checkpoint(self)?;
+
+        self.store.create_checkpoint();
+        self.checkpoints.push(KernelCheckpoint {
+            current_frame: self.current_frame.clone(),
+            prev_frame_stack: self.prev_frame_stack.clone(),
+            heap: self.heap.clone(),
+        });
+
+        Ok(())
+    }
+
+    #[trace_resources]
+    fn kernel_revert_to_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        let checkpoint = self
+            .checkpoints
+            .pop()
+            .ok_or(RuntimeError::KernelError(KernelError::CheckpointNotFound))?;
+
+        self.current_frame = checkpoint.current_frame;
+        self.prev_frame_stack = checkpoint.prev_frame_stack;
+        self.heap = checkpoint.heap;
+        self.store.revert_to_checkpoint();
+
+        M::on_revert_to_checkpoint(self)
+    }
+
+    #[trace_resources]
+    fn kernel_release_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        self.checkpoints
+            .pop()
+            .ok_or(RuntimeError::KernelError(KernelError::CheckpointNotFound))?;
+
+        self.store.release_checkpoint();
+
+        M::on_release_checkpoint(self)
+    }
 }
 
 impl<'g, M, S> KernelInternalApi<M> for Kernel<'g, M, S>
@@ -416,6 +468,10 @@ where
         self.current_frame.depth()
     }
 
+    fn kernel_get_heap_usage(&self) -> HeapUsage {
+        self.heap.usage()
+    }
+
     fn kernel_get_system_state(&mut self) -> SystemState<'_, M> {
         let caller = match self.prev_frame_stack.last() {
             Some(call_frame) => call_frame.actor(),
@@ -576,6 +632,13 @@ where
             })
         }
     }
+
+    fn kernel_peek_substate(&mut self, lock_handle: LockHandle) -> Option<IndexedScryptoValue> {
+        self.current_frame
+            .read_substate(&mut self.heap, self.store, lock_handle)
+            .ok()
+            .map(|(value, _)| value.clone())
+    }
 }
 
 impl<'g, M, S> KernelSubstateApi<M::LockData> for Kernel<'g, M, S>
@@ -740,7 +803,7 @@ where
             .set_substate(
                 node_id,
                 partition_num,
-                substate_key,
+                substate_key.clone(),
                 value,
                 &mut self.heap,
                 self.store,
@@ -749,7 +812,7 @@ where
             .map_err(KernelError::CallFrameError)
             .map_err(RuntimeError::KernelError)?;
 
-        M::on_set_substate(value_size, &store_access, self)?;
+        M::on_set_substate(node_id, &substate_key, value_size, &store_access, self)?;
 
         Ok(())
     }
@@ -817,6 +880,33 @@ where
         Ok(substeates)
     }
 
+    #[trace_resources]
+    fn kernel_scan_substates_after(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        cursor: Option<&SubstateKey>,
+        count: u32,
+    ) -> Result<Vec<IndexedScryptoValue>, RuntimeError> {
+        let (substates, store_access) = self
+            .current_frame
+            .scan_substates_after(
+                node_id,
+                partition_num,
+                cursor,
+                count,
+                &mut self.heap,
+                self.store,
+            )
+            .map_err(CallFrameError::ScanSubstatesError)
+            .map_err(KernelError::CallFrameError)
+            .map_err(RuntimeError::KernelError)?;
+
+        M::on_scan_substates(&store_access, self)?;
+
+        Ok(substates)
+    }
+
     #[trace_resources]
     fn kernel_take_substates(
         &mut self,
@@ -1,4 +1,5 @@
 use super::call_frame::NodeVisibility;
+use super::heap::HeapUsage;
 use crate::errors::*;
 use crate::kernel::actor::Actor;
 use crate::kernel::kernel_callback_api::KernelCallbackObject;
@@ -41,6 +42,18 @@ pub trait KernelNodeApi {
         dest_node_id: &NodeId,
         dest_partition_number: PartitionNumber,
     ) -> Result<(), RuntimeError>;
+
+    /// Creates a checkpoint of the call frames, the heap and the store, which the execution can
+    /// later be reverted to.
+    fn kernel_create_checkpoint(&mut self) -> Result<(), RuntimeError>;
+
+    /// Reverts the call frames, the heap and the store to the last checkpoint, and removes it.
+    ///
+    /// Substates written with the `FORCE_WRITE` flag since the checkpoint are kept.
+    fn kernel_revert_to_checkpoint(&mut self) -> Result<(), RuntimeError>;
+
+    /// Removes the last checkpoint, keeping all the changes made since.
+    fn kernel_release_checkpoint(&mut self) -> Result<(), RuntimeError>;
 }
 
 /// Info regarding the substate locked as well as what type of lock
@@ -147,6 +160,15 @@ pub trait KernelSubstateApi<L> {
         count: u32,
     ) -> Result<Vec<IndexedScryptoValue>, RuntimeError>;
 
+    /// Reads substates under a node page by page, starting after the substate with the given key
+    fn kernel_scan_substates_after(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        cursor: Option<&SubstateKey>,
+        count: u32,
+    ) -> Result<Vec<IndexedScryptoValue>, RuntimeError>;
+
     fn kernel_take_substates(
         &mut self,
         node_id: &NodeId,
@@ -197,12 +219,20 @@ pub trait KernelInternalApi<M: KernelCallbackObject> {
     /// Gets the number of call frames that are currently in the call frame stack
     fn kernel_get_current_depth(&self) -> usize;
 
+    /// Gets the number of nodes on the heap, shared by all call frames, and their total size
+    fn kernel_get_heap_usage(&self) -> HeapUsage;
+
     // TODO: Cleanup
     fn kernel_get_node_visibility(&self, node_id: &NodeId) -> NodeVisibility;
 
     /* Super unstable interface, specifically for `ExecutionTrace` kernel module */
     fn kernel_read_bucket(&mut self, bucket_id: &NodeId) -> Option<BucketSnapshot>;
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot>;
+
+    /* Super unstable interface, specifically for `InvariantChecks` kernel module */
+    /// Reads the value of the substate locked by the given lock handle, without notifying the
+    /// kernel callback object.
+    fn kernel_peek_substate(&mut self, lock_handle: LockHandle) -> Option<IndexedScryptoValue>;
 }
 
 pub trait KernelApi<M: KernelCallbackObject>:
@@ -107,6 +107,8 @@ pub trait KernelCallbackObject: Sized {
         Y: KernelApi<Self>;
 
     fn on_set_substate<Y>(
+        node_id: &NodeId,
+        substate_key: &SubstateKey,
         value_size: usize,
         store_access: &StoreAccessInfo,
         api: &mut Y,
@@ -173,4 +175,16 @@ pub trait KernelCallbackObject: Sized {
     fn on_allocate_node_id<Y>(entity_type: EntityType, api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: KernelApi<Self>;
+
+    fn on_create_checkpoint<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>;
+
+    fn on_revert_to_checkpoint<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>;
+
+    fn on_release_checkpoint<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>;
 }
@@ -0,0 +1,52 @@
+use crate::errors::RuntimeError;
+use crate::types::*;
+use sbor::rust::sync::Arc;
+
+/// A stand-in for the code of a blueprint, which receives the name of the called function or
+/// method along with its input and returns the output.
+pub type BlueprintMockHandler =
+    dyn Fn(&str, &IndexedScryptoValue) -> Result<IndexedScryptoValue, RuntimeError> + Send + Sync;
+
+/// Handlers which intercept the calls to some blueprints instead of running their code.
+///
+/// This is meant for tests only, so that a component can be exercised in isolation from the
+/// blueprints it depends on. The input and output of the intercepted calls are still validated
+/// against the blueprint schema, but the call frame is otherwise left untouched.
+#[derive(Clone, Default)]
+pub struct BlueprintMocks {
+    handlers: IndexMap<BlueprintId, Arc<BlueprintMockHandler>>,
+}
+
+impl BlueprintMocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert<F>(&mut self, blueprint_id: BlueprintId, handler: F)
+    where
+        F: Fn(&str, &IndexedScryptoValue) -> Result<IndexedScryptoValue, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.handlers.insert(blueprint_id, Arc::new(handler));
+    }
+
+    pub fn remove(&mut self, blueprint_id: &BlueprintId) {
+        self.handlers.shift_remove(blueprint_id);
+    }
+
+    pub fn get(&self, blueprint_id: &BlueprintId) -> Option<Arc<BlueprintMockHandler>> {
+        self.handlers.get(blueprint_id).cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+}
+
+impl fmt::Debug for BlueprintMocks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.handlers.keys()).finish()
+    }
+}
@@ -21,7 +21,7 @@ use crate::transaction::{
     TransactionOutcome, TransactionReceipt, TransactionResult,
 };
 use crate::types::*;
-use crate::vm::wasm::WasmEngine;
+use crate::vm::wasm::{WasmEngine, WasmValidatorConfigV1};
 use crate::vm::ScryptoVm;
 use lazy_static::lazy_static;
 use radix_engine_common::crypto::Secp256k1PublicKey;
@@ -131,6 +131,30 @@ pub enum GenesisDataChunk {
     XrdBalances(Vec<(ComponentAddress, Decimal)>),
 }
 
+impl GenesisDataChunk {
+    /// Lazily splits the given XRD balances into [`GenesisDataChunk::XrdBalances`] chunks of at
+    /// most `chunk_size` balances each, for use with
+    /// [`Bootstrapper::bootstrap_with_genesis_data_stream`].
+    pub fn xrd_balances_in_chunks<I>(
+        balances: I,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = GenesisDataChunk>
+    where
+        I: IntoIterator<Item = (ComponentAddress, Decimal)>,
+    {
+        assert!(chunk_size > 0, "Chunk size must be positive");
+        let mut balances = balances.into_iter();
+        sbor::rust::iter::from_fn(move || {
+            let chunk: Vec<_> = balances.by_ref().take(chunk_size).collect();
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(GenesisDataChunk::XrdBalances(chunk))
+            }
+        })
+    }
+}
+
 //==========================================================================================
 // MANIFEST-SPECIFIC GENESIS CHUNK MODELS
 // - These must match the corresponding models in the `genesis_helper` component
@@ -272,6 +296,7 @@ where
                 num_owner_stake_units_unlock_epochs: 2,
                 num_fee_increase_delay_epochs: 1,
                 validator_creation_usd_cost: *DEFAULT_VALIDATOR_USD_COST,
+                usd_price_in_xrd: Decimal::try_from(DEFAULT_USD_PRICE_IN_XRD).unwrap(),
             },
             1,
             Some(0),
@@ -288,6 +313,44 @@ where
         initial_current_leader: Option<ValidatorIndex>,
         faucet_supply: Decimal,
     ) -> Option<GenesisReceipts> {
+        let mut data_ingestion_receipts = vec![];
+        self.bootstrap_with_genesis_data_stream(
+            genesis_data_chunks,
+            genesis_epoch,
+            initial_config,
+            initial_time_ms,
+            initial_current_leader,
+            faucet_supply,
+            |_, receipt| data_ingestion_receipts.push(receipt),
+        )
+        .map(|receipts| GenesisReceipts {
+            data_ingestion_receipts,
+            ..receipts
+        })
+    }
+
+    /// Bootstraps the system like [`Self::bootstrap_with_genesis_data`], but pulls the genesis
+    /// data chunks lazily and hands over each data ingestion receipt to
+    /// `on_data_ingestion_receipt` (along with the chunk index) instead of retaining it.
+    ///
+    /// Only a single chunk and its receipt are held in memory at any time, so this is suitable
+    /// for large genesis data read from disk. The `data_ingestion_receipts` of the returned
+    /// [`GenesisReceipts`] are always empty.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bootstrap_with_genesis_data_stream<I, F>(
+        &mut self,
+        genesis_data_chunks: I,
+        genesis_epoch: Epoch,
+        initial_config: ConsensusManagerConfig,
+        initial_time_ms: i64,
+        initial_current_leader: Option<ValidatorIndex>,
+        faucet_supply: Decimal,
+        mut on_data_ingestion_receipt: F,
+    ) -> Option<GenesisReceipts>
+    where
+        I: IntoIterator<Item = GenesisDataChunk>,
+        F: FnMut(usize, TransactionReceipt),
+    {
         let flash_receipt = create_substate_flash_for_genesis();
         let first_package = flash_receipt.state_update_summary.new_packages[0];
         let first_typed_info = self
@@ -312,17 +375,16 @@ where
             flash_receipt
                 .merge_genesis_flash_into_transaction_receipt(&mut system_bootstrap_receipt);
 
-            let mut data_ingestion_receipts = vec![];
             for (chunk_index, chunk) in genesis_data_chunks.into_iter().enumerate() {
                 let receipt = self.ingest_genesis_data_chunk(chunk, chunk_index);
-                data_ingestion_receipts.push(receipt);
+                on_data_ingestion_receipt(chunk_index, receipt);
             }
 
             let genesis_wrap_up_receipt = self.execute_genesis_wrap_up();
 
             Some(GenesisReceipts {
                 system_bootstrap_receipt,
-                data_ingestion_receipts,
+                data_ingestion_receipts: vec![],
                 wrap_up_receipt: genesis_wrap_up_receipt,
             })
         } else {
@@ -479,6 +541,7 @@ pub fn create_system_bootstrap_flash(
                 definition,
                 VmType::Native,
                 native_code_id.to_be_bytes().to_vec(),
+                &WasmValidatorConfigV1::new(),
             )
             .expect("Invalid Package Package definition");
 
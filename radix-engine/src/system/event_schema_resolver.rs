@@ -0,0 +1,107 @@
+use crate::system::system_db_reader::{SystemDatabaseReader, SystemReaderError};
+use crate::types::*;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_store_interface::interface::SubstateDatabase;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventDecodeError {
+    SchemaResolutionError(SystemReaderError),
+    DecodeError(DecodeError),
+    /// The payload doesn't match the schema of the event, with the rendered validation error.
+    PayloadValidationError(String),
+}
+
+/// An event payload, decoded with the schema of its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEvent {
+    /// The name of the type of the event, if the schema has one.
+    pub name: Option<String>,
+    pub value: ScryptoValue,
+}
+
+/// Resolves the schemas of events from the blueprint definitions and instance schemas stored on
+/// ledger, so that events can be named and decoded without knowing their Rust types.
+pub struct EventSchemaResolver<'a, S: SubstateDatabase> {
+    reader: SystemDatabaseReader<'a, S>,
+}
+
+impl<'a, S: SubstateDatabase> EventSchemaResolver<'a, S> {
+    pub fn new(substate_db: &'a S) -> Self {
+        Self {
+            reader: SystemDatabaseReader::new(substate_db),
+        }
+    }
+
+    /// Returns the schema of the event and the index of the event's type within it.
+    pub fn resolve_event_schema(
+        &self,
+        event_type_identifier: &EventTypeIdentifier,
+    ) -> Result<(ScryptoSchema, LocalTypeIndex), SystemReaderError> {
+        let EventTypeIdentifier(emitter, type_pointer) = event_type_identifier;
+        let (package_address, instance_schema) = match emitter {
+            Emitter::Method(_, ObjectModuleId::AccessRules) => (ACCESS_RULES_MODULE_PACKAGE, None),
+            Emitter::Method(_, ObjectModuleId::Royalty) => (ROYALTY_MODULE_PACKAGE, None),
+            Emitter::Method(_, ObjectModuleId::Metadata) => (METADATA_MODULE_PACKAGE, None),
+            Emitter::Method(node_id, ObjectModuleId::Main) => {
+                let info = self.reader.get_object_info(node_id)?;
+                (info.blueprint_id.package_address, info.instance_schema)
+            }
+            Emitter::Function(node_id, ..) => (PackageAddress::new_or_panic(node_id.0), None),
+        };
+
+        match type_pointer {
+            TypePointer::Package(schema_hash, type_index) => {
+                let schema = self.reader.get_schema(&package_address, schema_hash)?;
+                Ok((schema, *type_index))
+            }
+            TypePointer::Instance(instance_index) => {
+                let instance_schema =
+                    instance_schema.ok_or(SystemReaderError::InstanceSchemaDoesNotExist)?;
+                let type_index = instance_schema
+                    .type_index
+                    .get(*instance_index as usize)
+                    .cloned()
+                    .ok_or(SystemReaderError::InstanceSchemaDoesNotExist)?;
+                Ok((instance_schema.schema, type_index))
+            }
+        }
+    }
+
+    /// Returns the name of the type of the event, if its schema has one.
+    pub fn event_name(
+        &self,
+        event_type_identifier: &EventTypeIdentifier,
+    ) -> Result<Option<String>, SystemReaderError> {
+        let (schema, type_index) = self.resolve_event_schema(event_type_identifier)?;
+        Ok(schema
+            .resolve_type_metadata(type_index)
+            .and_then(|metadata| metadata.get_name_string()))
+    }
+
+    /// Validates an event payload against the schema of the event, and decodes it.
+    pub fn decode_event_to_value(
+        &self,
+        event_type_identifier: &EventTypeIdentifier,
+        event_data: &[u8],
+    ) -> Result<DecodedEvent, EventDecodeError> {
+        let (schema, type_index) = self
+            .resolve_event_schema(event_type_identifier)
+            .map_err(EventDecodeError::SchemaResolutionError)?;
+        let value =
+            scrypto_decode::<ScryptoValue>(event_data).map_err(EventDecodeError::DecodeError)?;
+        validate_payload_against_schema::<ScryptoCustomExtension, _>(
+            event_data,
+            &schema,
+            type_index,
+            &(),
+        )
+        .map_err(|error| EventDecodeError::PayloadValidationError(error.error_message(&schema)))?;
+
+        Ok(DecodedEvent {
+            name: schema
+                .resolve_type_metadata(type_index)
+                .and_then(|metadata| metadata.get_name_string()),
+            value,
+        })
+    }
+}
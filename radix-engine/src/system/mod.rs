@@ -1,4 +1,6 @@
+pub mod blueprint_mocks;
 pub mod bootstrap;
+pub mod event_schema_resolver;
 pub mod id_allocation;
 pub mod module;
 pub mod node_init;
@@ -7,4 +9,5 @@ pub mod payload_validation;
 pub mod system;
 pub mod system_callback;
 pub mod system_callback_api;
+pub mod system_db_reader;
 pub mod system_modules;
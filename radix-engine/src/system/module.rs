@@ -203,6 +203,8 @@ pub trait SystemModule<M: KernelCallbackObject> {
     #[inline(always)]
     fn on_set_substate<Y: KernelApi<M>>(
         _api: &mut Y,
+        _node_id: &NodeId,
+        _substate_key: &SubstateKey,
         _value_size: usize,
         _store_access: &StoreAccessInfo,
     ) -> Result<(), RuntimeError> {
@@ -10,6 +10,7 @@ use crate::errors::{
 use crate::errors::{EventError, SystemUpstreamError};
 use crate::kernel::actor::{Actor, InstanceContext, MethodActor};
 use crate::kernel::call_frame::{NodeVisibility, Visibility};
+use crate::kernel::heap::HeapUsage;
 use crate::kernel::kernel_api::*;
 use crate::system::node_init::type_info_partition;
 use crate::system::node_modules::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
@@ -28,6 +29,7 @@ use radix_engine_interface::api::key_value_entry_api::{
     ClientKeyValueEntryApi, KeyValueEntryHandle,
 };
 use radix_engine_interface::api::key_value_store_api::ClientKeyValueStoreApi;
+use radix_engine_interface::api::node_modules::metadata::MetadataValue;
 use radix_engine_interface::api::object_api::ObjectModuleId;
 use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::package::*;
@@ -1517,6 +1519,92 @@ where
         Ok(address)
     }
 
+    // Costing through kernel
+    #[trace_resources]
+    fn get_metadata(&mut self, address: GlobalAddress, key: &str) -> Result<Vec<u8>, RuntimeError> {
+        let handle = self.api.kernel_open_substate_with_default(
+            address.as_node_id(),
+            METADATA_KV_STORE_PARTITION,
+            &SubstateKey::Map(scrypto_encode(key).unwrap()),
+            LockFlags::read_only(),
+            Some(|| {
+                let kv_entry = KeyValueEntrySubstate::<()>::default();
+                IndexedScryptoValue::from_typed(&kv_entry)
+            }),
+            SystemLockData::KeyValueEntry(KeyValueEntryLockData::Read),
+        )?;
+        let substate: KeyValueEntrySubstate<MetadataValue> =
+            self.api.kernel_read_substate(handle)?.as_typed().unwrap();
+        self.api.kernel_close_substate(handle)?;
+
+        Ok(scrypto_encode(&substate.value).unwrap())
+    }
+
+    // Costing through kernel
+    #[trace_resources]
+    fn list_owned_nodes(
+        &mut self,
+        node_id: &NodeId,
+        offset: u32,
+        count: u32,
+    ) -> Result<Vec<NodeId>, RuntimeError> {
+        let type_info = TypeInfoBlueprint::get_type(node_id, self.api)?;
+        let mut partitions = Vec::new();
+        match type_info {
+            TypeInfoSubstate::KeyValueStore(..) => partitions.push(MAIN_BASE_PARTITION),
+            TypeInfoSubstate::Object(info) => {
+                let main_interface = self
+                    .get_blueprint_definition(
+                        info.blueprint_id.package_address,
+                        &info.blueprint_version_key(),
+                    )?
+                    .interface;
+                let mut modules = vec![(ObjectModuleId::Main, main_interface)];
+                if info.global {
+                    for module_id in [
+                        ObjectModuleId::Metadata,
+                        ObjectModuleId::Royalty,
+                        ObjectModuleId::AccessRules,
+                    ] {
+                        let blueprint_id = module_id.static_blueprint().unwrap();
+                        let interface = self.get_blueprint_default_interface(
+                            blueprint_id.package_address,
+                            blueprint_id.blueprint_name.as_str(),
+                        )?;
+                        modules.push((module_id, interface));
+                    }
+                }
+
+                for (module_id, interface) in modules {
+                    for partition_offset in 0..interface.state.num_partitions() {
+                        let partition_num = module_id
+                            .base_partition_num()
+                            .at_offset(PartitionOffset(partition_offset))
+                            .expect("Module number overflow");
+                        partitions.push(partition_num);
+                    }
+                }
+            }
+            _ => return Err(RuntimeError::SystemError(SystemError::NotAnObject)),
+        }
+
+        let mut owned_nodes = Vec::new();
+        for partition_num in partitions {
+            for substate in self
+                .api
+                .kernel_scan_substates(node_id, partition_num, u32::MAX)?
+            {
+                owned_nodes.extend(substate.owned_nodes().iter().cloned());
+            }
+        }
+
+        Ok(owned_nodes
+            .into_iter()
+            .skip(offset as usize)
+            .take(count as usize)
+            .collect())
+    }
+
     // Costing through kernel
     #[trace_resources]
     fn drop_object(&mut self, node_id: &NodeId) -> Result<Vec<Vec<u8>>, RuntimeError> {
@@ -1912,6 +2000,29 @@ where
         Ok(substates)
     }
 
+    // Costing through kernel
+    fn actor_index_scan_after(
+        &mut self,
+        object_handle: ObjectHandle,
+        collection_index: CollectionIndex,
+        cursor: Option<Vec<u8>>,
+        count: u32,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        let actor_object_type: ActorObjectType = object_handle.try_into()?;
+
+        let (node_id, partition_num) = self.get_actor_index(actor_object_type, collection_index)?;
+
+        let cursor = cursor.map(SubstateKey::Map);
+        let substates = self
+            .api
+            .kernel_scan_substates_after(&node_id, partition_num, cursor.as_ref(), count)?
+            .into_iter()
+            .map(|value| value.into())
+            .collect();
+
+        Ok(substates)
+    }
+
     // Costing through kernel
     fn actor_index_take(
         &mut self,
@@ -2491,6 +2602,22 @@ where
             .update_instruction_index(new_index);
         Ok(())
     }
+
+    // No costing should be applied
+    #[trace_resources]
+    fn update_wasm_memory_usage(&mut self, consumed_memory: usize) -> Result<(), RuntimeError> {
+        let blueprint_id = self
+            .api
+            .kernel_get_system_state()
+            .current
+            .blueprint_id()
+            .clone();
+        self.api
+            .kernel_get_system()
+            .modules
+            .update_wasm_memory_usage(blueprint_id, consumed_memory);
+        Ok(())
+    }
 }
 
 impl<'a, Y, V> ClientTransactionRuntimeApi<RuntimeError> for SystemService<'a, Y, V>
@@ -2603,6 +2730,21 @@ where
         Ok(())
     }
 
+    #[trace_resources]
+    fn emit_metric(&mut self, name: String, value: Decimal) -> Result<(), RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(CostingEntry::EmitMetric { size: name.len() })?;
+
+        self.api
+            .kernel_get_system()
+            .modules
+            .add_metric(name, value)?;
+
+        Ok(())
+    }
+
     fn panic(&mut self, message: String) -> Result<(), RuntimeError> {
         self.api
             .kernel_get_system()
@@ -2696,6 +2838,18 @@ where
             dest_partition_number,
         )
     }
+
+    fn kernel_create_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        self.api.kernel_create_checkpoint()
+    }
+
+    fn kernel_revert_to_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        self.api.kernel_revert_to_checkpoint()
+    }
+
+    fn kernel_release_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        self.api.kernel_release_checkpoint()
+    }
 }
 
 impl<'a, Y, V> KernelSubstateApi<SystemLockData> for SystemService<'a, Y, V>
@@ -2789,6 +2943,17 @@ where
             .kernel_scan_substates(node_id, partition_num, count)
     }
 
+    fn kernel_scan_substates_after(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        cursor: Option<&SubstateKey>,
+        count: u32,
+    ) -> Result<Vec<IndexedScryptoValue>, RuntimeError> {
+        self.api
+            .kernel_scan_substates_after(node_id, partition_num, cursor, count)
+    }
+
     fn kernel_take_substates(
         &mut self,
         node_id: &NodeId,
@@ -2813,6 +2978,10 @@ where
         self.api.kernel_get_current_depth()
     }
 
+    fn kernel_get_heap_usage(&self) -> HeapUsage {
+        self.api.kernel_get_heap_usage()
+    }
+
     fn kernel_get_node_visibility(&self, node_id: &NodeId) -> NodeVisibility {
         self.api.kernel_get_node_visibility(node_id)
     }
@@ -2824,4 +2993,8 @@ where
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot> {
         self.api.kernel_read_proof(proof_id)
     }
+
+    fn kernel_peek_substate(&mut self, lock_handle: LockHandle) -> Option<IndexedScryptoValue> {
+        self.api.kernel_peek_substate(lock_handle)
+    }
 }
@@ -7,6 +7,7 @@ use crate::kernel::call_frame::Message;
 use crate::kernel::kernel_api::KernelSubstateApi;
 use crate::kernel::kernel_api::{KernelApi, KernelInvocation};
 use crate::kernel::kernel_callback_api::KernelCallbackObject;
+use crate::system::blueprint_mocks::BlueprintMocks;
 use crate::system::module::SystemModule;
 use crate::system::system::KeyValueEntrySubstate;
 use crate::system::system::SystemService;
@@ -80,6 +81,7 @@ pub struct SystemConfig<C: SystemCallbackObject> {
     pub blueprint_cache: NonIterMap<CanonicalBlueprintId, BlueprintDefinition>,
     pub schema_cache: NonIterMap<Hash, ScryptoSchema>,
     pub auth_cache: NonIterMap<CanonicalBlueprintId, AuthConfig>,
+    pub blueprint_mocks: BlueprintMocks,
     pub modules: SystemModuleMixer,
 }
 
@@ -194,6 +196,8 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
     }
 
     fn on_set_substate<Y>(
+        node_id: &NodeId,
+        substate_key: &SubstateKey,
         value_size: usize,
         store_access: &StoreAccessInfo,
         api: &mut Y,
@@ -201,7 +205,7 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
     where
         Y: KernelApi<Self>,
     {
-        SystemModuleMixer::on_set_substate(api, value_size, store_access)
+        SystemModuleMixer::on_set_substate(api, node_id, substate_key, value_size, store_access)
     }
 
     fn on_take_substates<Y>(store_access: &StoreAccessInfo, api: &mut Y) -> Result<(), RuntimeError>
@@ -277,6 +281,33 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
         SystemModuleMixer::on_allocate_node_id(api, entity_type)
     }
 
+    fn on_create_checkpoint<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>,
+    {
+        api.kernel_get_system().modules.create_checkpoint()
+    }
+
+    fn on_revert_to_checkpoint<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>,
+    {
+        let system = api.kernel_get_system();
+        // The caches may hold the definitions of packages which no longer exist
+        system.blueprint_cache.clear();
+        system.schema_cache.clear();
+        system.auth_cache.clear();
+        system.modules.revert_to_checkpoint()
+    }
+
+    fn on_release_checkpoint<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>,
+    {
+        api.kernel_get_system().modules.release_checkpoint();
+        Ok(())
+    }
+
     fn after_move_modules<Y>(
         src_node_id: &NodeId,
         dest_node_id: &NodeId,
@@ -396,8 +427,16 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                 }
             };
 
-            // Execute
-            let output = { C::invoke(&blueprint_id.package_address, export, input, &mut system)? };
+            // Execute, unless the blueprint is mocked
+            let mock = system
+                .api
+                .kernel_get_system()
+                .blueprint_mocks
+                .get(&blueprint_id);
+            let output = match (&ident, mock) {
+                (FnIdent::Application(ident), Some(mock)) => mock(ident.as_str(), input)?,
+                _ => C::invoke(&blueprint_id.package_address, export, input, &mut system)?,
+            };
 
             // Validate output
             match ident {
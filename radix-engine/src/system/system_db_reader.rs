@@ -0,0 +1,247 @@
+use crate::system::node_modules::type_info::TypeInfoSubstate;
+use crate::system::system::KeyValueEntrySubstate;
+use crate::types::*;
+use radix_engine_interface::api::{CollectionIndex, ObjectModuleId};
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::schema::BlueprintCollectionSchema;
+use radix_engine_store_interface::{
+    db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper},
+    interface::SubstateDatabase,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SystemReaderError {
+    NodeDoesNotExist,
+    NotAnObject,
+    ModuleDoesNotExist,
+    BlueprintDoesNotExist,
+    SchemaDoesNotExist,
+    InstanceSchemaDoesNotExist,
+    CollectionDoesNotExist,
+}
+
+/// A field of an object, decoded with the schema of its blueprint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedField {
+    pub index: u8,
+    /// The name of the type of the field, if the schema has one.
+    pub name: Option<String>,
+    pub value: ScryptoValue,
+    pub schema: ScryptoSchema,
+    pub type_index: LocalTypeIndex,
+}
+
+/// An entry of a collection of an object. Empty key value store entries are not included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionEntry {
+    pub key: SubstateKey,
+    pub value: ScryptoValue,
+}
+
+/// Reads the state of objects directly from a substate database, decoding it with the
+/// blueprint and instance schemas stored on ledger.
+pub struct SystemDatabaseReader<'a, S: SubstateDatabase> {
+    substate_db: &'a S,
+}
+
+impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
+    pub fn new(substate_db: &'a S) -> Self {
+        Self { substate_db }
+    }
+
+    pub fn get_type_info(&self, node_id: &NodeId) -> Result<TypeInfoSubstate, SystemReaderError> {
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+                node_id,
+                TYPE_INFO_FIELD_PARTITION,
+                &TypeInfoField::TypeInfo.into(),
+            )
+            .ok_or(SystemReaderError::NodeDoesNotExist)
+    }
+
+    pub fn get_object_info(&self, node_id: &NodeId) -> Result<ObjectInfo, SystemReaderError> {
+        match self.get_type_info(node_id)? {
+            TypeInfoSubstate::Object(info) => Ok(info),
+            _ => Err(SystemReaderError::NotAnObject),
+        }
+    }
+
+    pub fn get_blueprint_definition(
+        &self,
+        package_address: &PackageAddress,
+        bp_version_key: &BlueprintVersionKey,
+    ) -> Result<BlueprintDefinition, SystemReaderError> {
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<BlueprintDefinition>>(
+                package_address.as_node_id(),
+                MAIN_BASE_PARTITION
+                    .at_offset(PACKAGE_BLUEPRINTS_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Map(scrypto_encode(bp_version_key).unwrap()),
+            )
+            .and_then(|entry| entry.value)
+            .ok_or(SystemReaderError::BlueprintDoesNotExist)
+    }
+
+    pub fn get_schema(
+        &self,
+        package_address: &PackageAddress,
+        schema_hash: &Hash,
+    ) -> Result<ScryptoSchema, SystemReaderError> {
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<ScryptoSchema>>(
+                package_address.as_node_id(),
+                MAIN_BASE_PARTITION
+                    .at_offset(PACKAGE_SCHEMAS_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Map(scrypto_encode(schema_hash).unwrap()),
+            )
+            .and_then(|entry| entry.value)
+            .ok_or(SystemReaderError::SchemaDoesNotExist)
+    }
+
+    /// Reads and decodes all the fields of the given module of an object. Fields which are
+    /// disabled by a feature of the object are skipped.
+    pub fn read_object_fields(
+        &self,
+        node_id: &NodeId,
+        module_id: ObjectModuleId,
+    ) -> Result<Vec<DecodedField>, SystemReaderError> {
+        let (info, blueprint_id, interface) = self.get_module_interface(node_id, module_id)?;
+
+        let mut fields = Vec::new();
+        let (partition_offset, field_schemas) = match &interface.state.fields {
+            Some(fields) => fields,
+            None => return Ok(fields),
+        };
+        let partition_num = module_id
+            .base_partition_num()
+            .at_offset(*partition_offset)
+            .expect("Module number overflow");
+
+        for (index, field_schema) in field_schemas.iter().enumerate() {
+            let index = index as u8;
+            let value = match self
+                .substate_db
+                .get_mapped::<SpreadPrefixKeyMapper, ScryptoValue>(
+                    node_id,
+                    partition_num,
+                    &SubstateKey::Field(index),
+                ) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let (schema, type_index) =
+                self.resolve_type_pointer(&info, &blueprint_id, &field_schema.field)?;
+            let name = schema
+                .resolve_type_metadata(type_index)
+                .and_then(|metadata| metadata.get_name_string());
+
+            fields.push(DecodedField {
+                index,
+                name,
+                value,
+                schema,
+                type_index,
+            });
+        }
+
+        Ok(fields)
+    }
+
+    /// Reads and decodes all the entries of a collection of the given module of an object.
+    pub fn read_object_collection(
+        &self,
+        node_id: &NodeId,
+        module_id: ObjectModuleId,
+        collection_index: CollectionIndex,
+    ) -> Result<Vec<CollectionEntry>, SystemReaderError> {
+        let (_, _, interface) = self.get_module_interface(node_id, module_id)?;
+
+        let (partition_offset, collection_schema) = interface
+            .state
+            .collections
+            .get(collection_index as usize)
+            .ok_or(SystemReaderError::CollectionDoesNotExist)?;
+        let partition_num = module_id
+            .base_partition_num()
+            .at_offset(*partition_offset)
+            .expect("Module number overflow");
+
+        let entries = match collection_schema {
+            BlueprintCollectionSchema::KeyValueStore(..) => self
+                .substate_db
+                .list_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<ScryptoValue>, MapKey>(
+                    node_id,
+                    partition_num,
+                )
+                .filter_map(|(key, entry)| entry.value.map(|value| CollectionEntry { key, value }))
+                .collect(),
+            BlueprintCollectionSchema::Index(..) => self
+                .substate_db
+                .list_mapped::<SpreadPrefixKeyMapper, ScryptoValue, MapKey>(node_id, partition_num)
+                .map(|(key, value)| CollectionEntry { key, value })
+                .collect(),
+            BlueprintCollectionSchema::SortedIndex(..) => self
+                .substate_db
+                .list_mapped::<SpreadPrefixKeyMapper, ScryptoValue, SortedU16Key>(
+                    node_id,
+                    partition_num,
+                )
+                .map(|(key, value)| CollectionEntry { key, value })
+                .collect(),
+        };
+
+        Ok(entries)
+    }
+
+    fn get_module_interface(
+        &self,
+        node_id: &NodeId,
+        module_id: ObjectModuleId,
+    ) -> Result<(ObjectInfo, BlueprintId, BlueprintInterface), SystemReaderError> {
+        let info = self.get_object_info(node_id)?;
+        let (blueprint_id, bp_version_key) = match module_id.static_blueprint() {
+            Some(blueprint_id) => {
+                if !info.global {
+                    return Err(SystemReaderError::ModuleDoesNotExist);
+                }
+                let bp_version_key =
+                    BlueprintVersionKey::new_default(blueprint_id.blueprint_name.as_str());
+                (blueprint_id, bp_version_key)
+            }
+            None => (info.blueprint_id.clone(), info.blueprint_version_key()),
+        };
+
+        let definition =
+            self.get_blueprint_definition(&blueprint_id.package_address, &bp_version_key)?;
+
+        Ok((info, blueprint_id, definition.interface))
+    }
+
+    fn resolve_type_pointer(
+        &self,
+        info: &ObjectInfo,
+        blueprint_id: &BlueprintId,
+        type_pointer: &TypePointer,
+    ) -> Result<(ScryptoSchema, LocalTypeIndex), SystemReaderError> {
+        match type_pointer {
+            TypePointer::Package(schema_hash, type_index) => {
+                let schema = self.get_schema(&blueprint_id.package_address, schema_hash)?;
+                Ok((schema, *type_index))
+            }
+            TypePointer::Instance(instance_index) => {
+                let instance_schema = info
+                    .instance_schema
+                    .as_ref()
+                    .ok_or(SystemReaderError::InstanceSchemaDoesNotExist)?;
+                let type_index = instance_schema
+                    .type_index
+                    .get(*instance_index as usize)
+                    .ok_or(SystemReaderError::InstanceSchemaDoesNotExist)?;
+                Ok((instance_schema.schema.clone(), *type_index))
+            }
+        }
+    }
+}
@@ -13,6 +13,7 @@ use crate::system::system::SystemService;
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_modules::auth::ActingLocation;
+use crate::track::interface::StoreAccessInfo;
 use crate::types::*;
 use radix_engine_interface::api::{ClientObjectApi, ObjectModuleId};
 use radix_engine_interface::blueprints::package::{
@@ -30,6 +31,9 @@ pub enum AuthError {
     Unauthorized(Box<Unauthorized>),
     InnerBlueprintDoesNotExist(String),
     InvalidOuterObjectMapping,
+    StateGateDepthExceeded(StateGate),
+    StateGateWriteNotAllowed(StateGate),
+    InvalidStateGateOutput(StateGate),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -52,6 +56,8 @@ pub struct AuthModule {
     /// - An auth zone is created for every non-frame.
     /// - Auth zones are created by the caller frame and moved to the callee
     pub auth_zone_stack: Vec<NodeId>,
+    /// Stack of the state gates being evaluated, innermost last
+    pub state_gate_stack: Vec<StateGate>,
 }
 
 pub enum AuthorizationCheckResult {
@@ -74,6 +80,10 @@ pub enum ResolvedPermission {
     AllowAll,
 }
 
+/// The maximum number of nested state gate evaluations, so that a state gate may not depend on
+/// another one.
+pub const MAX_STATE_GATE_DEPTH: usize = 1;
+
 impl AuthModule {
     pub fn last_auth_zone(&self) -> Option<NodeId> {
         self.auth_zone_stack.last().cloned()
@@ -286,6 +296,20 @@ impl AuthModule {
         }
     }
 
+    /// State gates are read-only, so no substate may be written while one is being evaluated.
+    fn check_state_is_writable<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        api: &mut Y,
+    ) -> Result<(), RuntimeError> {
+        match api.kernel_get_system().modules.auth.state_gate_stack.last() {
+            Some(state_gate) => Err(RuntimeError::SystemModuleError(
+                SystemModuleError::AuthError(AuthError::StateGateWriteNotAllowed(
+                    state_gate.clone(),
+                )),
+            )),
+            None => Ok(()),
+        }
+    }
+
     /// Create a new auth zone and move it to next frame.
     ///
     /// Must be done before a new frame is created, as
@@ -390,4 +414,30 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for AuthModule {
         api.kernel_get_system().modules.auth.auth_zone_stack.pop();
         Ok(())
     }
+
+    fn on_write_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _lock_handle: LockHandle,
+        _value_size: usize,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        AuthModule::check_state_is_writable(api)
+    }
+
+    fn on_set_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _node_id: &NodeId,
+        _substate_key: &SubstateKey,
+        _value_size: usize,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        AuthModule::check_state_is_writable(api)
+    }
+
+    fn on_take_substates<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        AuthModule::check_state_is_writable(api)
+    }
 }
@@ -1,12 +1,14 @@
 use crate::blueprints::resource::AuthZone;
-use crate::errors::RuntimeError;
-use crate::kernel::kernel_api::KernelSubstateApi;
+use crate::errors::{RuntimeError, SystemModuleError};
+use crate::kernel::kernel_api::{KernelInternalApi, KernelSubstateApi};
 use crate::system::node_modules::access_rules::OwnerRoleSubstate;
 use crate::system::system::KeyValueEntrySubstate;
-use crate::system::system_callback::SystemLockData;
+use crate::system::system_callback::{SystemConfig, SystemLockData};
+use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_modules::auth::{
-    AuthorityListAuthorizationResult, AuthorizationCheckResult,
+    AuthError, AuthorityListAuthorizationResult, AuthorizationCheckResult, MAX_STATE_GATE_DEPTH,
 };
+use crate::system::system_modules::costing::CostingEntry;
 use crate::types::*;
 use native_sdk::resource::{NativeNonFungibleProof, NativeProof};
 use radix_engine_interface::api::{ClientApi, ClientObjectApi, LockFlags, ObjectModuleId};
@@ -323,7 +325,10 @@ impl Authorization {
     }
 
     pub fn check_authorization_against_role_key_internal<
-        Y: KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<SystemLockData>
+            + KernelInternalApi<SystemConfig<V>>
+            + ClientApi<RuntimeError>,
+        V: SystemCallbackObject,
     >(
         acting_location: ActingLocation,
         auth_zone_id: NodeId,
@@ -378,16 +383,21 @@ impl Authorization {
         Self::check_authorization_against_access_rule_internal(
             acting_location,
             auth_zone_id,
+            Some(access_rules_of),
             &access_rule,
             api,
         )
     }
 
     fn check_authorization_against_access_rule_internal<
-        Y: KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<SystemLockData>
+            + KernelInternalApi<SystemConfig<V>>
+            + ClientApi<RuntimeError>,
+        V: SystemCallbackObject,
     >(
         acting_location: ActingLocation,
         auth_zone_id: NodeId,
+        protected_object: Option<&NodeId>,
         rule: &AccessRule,
         api: &mut Y,
     ) -> Result<AuthorizationCheckResult, RuntimeError> {
@@ -405,11 +415,65 @@ impl Authorization {
             }
             AccessRule::AllowAll => Ok(AuthorizationCheckResult::Authorized),
             AccessRule::DenyAll => Ok(AuthorizationCheckResult::Failed(vec![rule.clone()])),
+            AccessRule::StateGated(state_gate) => {
+                // State gates depend on the state of an object, so rules not attached to one fail
+                let authorized = match protected_object {
+                    Some(node_id) => Self::evaluate_state_gate(state_gate, node_id, api)?,
+                    None => false,
+                };
+                if authorized {
+                    Ok(AuthorizationCheckResult::Authorized)
+                } else {
+                    Ok(AuthorizationCheckResult::Failed(vec![rule.clone()]))
+                }
+            }
+        }
+    }
+
+    fn evaluate_state_gate<
+        Y: KernelSubstateApi<SystemLockData>
+            + KernelInternalApi<SystemConfig<V>>
+            + ClientApi<RuntimeError>,
+        V: SystemCallbackObject,
+    >(
+        state_gate: &StateGate,
+        protected_object: &NodeId,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError> {
+        if api.kernel_get_system().modules.auth.state_gate_stack.len() >= MAX_STATE_GATE_DEPTH {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::AuthError(AuthError::StateGateDepthExceeded(state_gate.clone())),
+            ));
         }
+        api.kernel_get_system()
+            .modules
+            .apply_execution_cost(CostingEntry::EvaluateStateGate)?;
+
+        api.kernel_get_system()
+            .modules
+            .auth
+            .state_gate_stack
+            .push(state_gate.clone());
+        let rtn = api.call_function(
+            state_gate.package_address,
+            state_gate.blueprint_name.as_str(),
+            state_gate.function_name.as_str(),
+            scrypto_encode(&(GlobalAddress::new_or_panic(protected_object.0),)).unwrap(),
+        );
+        api.kernel_get_system().modules.auth.state_gate_stack.pop();
+
+        scrypto_decode::<bool>(&rtn?).map_err(|_| {
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::InvalidStateGateOutput(state_gate.clone()),
+            ))
+        })
     }
 
     pub fn check_authorization_against_access_rule<
-        Y: KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<SystemLockData>
+            + KernelInternalApi<SystemConfig<V>>
+            + ClientApi<RuntimeError>,
+        V: SystemCallbackObject,
     >(
         acting_location: ActingLocation,
         auth_zone_id: NodeId,
@@ -419,13 +483,17 @@ impl Authorization {
         Self::check_authorization_against_access_rule_internal(
             acting_location,
             auth_zone_id,
+            None,
             rule,
             api,
         )
     }
 
     pub fn check_authorization_against_role_list<
-        Y: KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<SystemLockData>
+            + KernelInternalApi<SystemConfig<V>>
+            + ClientApi<RuntimeError>,
+        V: SystemCallbackObject,
     >(
         acting_location: ActingLocation,
         auth_zone_id: NodeId,
@@ -4,6 +4,14 @@ use crate::track::interface::{StoreAccessInfo, StoreCommit};
 use crate::types::*;
 use radix_engine_interface::*;
 
+/// A cryptographic operation performed on behalf of the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+pub enum CryptoOp {
+    Blake2b256Hash,
+    Secp256k1Verify,
+    Ed25519Verify,
+}
+
 #[derive(Debug, IntoStaticStr)]
 pub enum CostingEntry<'a> {
     /* TX */
@@ -98,6 +106,7 @@ pub enum CostingEntry<'a> {
     QueryActor,
     QueryAuthZone,
     AssertAccessRule,
+    EvaluateStateGate,
     QueryTransactionHash,
     GenerateRuid,
     EmitEvent {
@@ -106,10 +115,23 @@ pub enum CostingEntry<'a> {
     EmitLog {
         size: usize,
     },
+    EmitMetric {
+        size: usize,
+    },
     Panic {
         size: usize,
     },
 
+    /* crypto */
+    Crypto {
+        op: CryptoOp,
+        input_size: usize,
+    },
+
+    /* checkpoints */
+    CreateCheckpoint,
+    RevertToCheckpoint,
+
     /* system modules */
     RoyaltyModule {
         direct_charge: u32,
@@ -182,11 +204,16 @@ impl<'a> CostingEntry<'a> {
             CostingEntry::QueryActor => ft.query_actor_cost(),
             CostingEntry::QueryAuthZone => ft.query_auth_zone_cost(),
             CostingEntry::AssertAccessRule => ft.assert_access_rule_cost(),
+            CostingEntry::EvaluateStateGate => ft.evaluate_state_gate_cost(),
             CostingEntry::QueryTransactionHash => ft.query_transaction_hash_cost(),
             CostingEntry::GenerateRuid => ft.generate_ruid_cost(),
             CostingEntry::EmitEvent { size } => ft.emit_event_cost(*size),
             CostingEntry::EmitLog { size } => ft.emit_log_cost(*size),
+            CostingEntry::EmitMetric { size } => ft.emit_metric_cost(*size),
             CostingEntry::Panic { size } => ft.panic_cost(*size),
+            CostingEntry::Crypto { op, input_size } => ft.crypto_cost(op, *input_size),
+            CostingEntry::CreateCheckpoint => ft.create_checkpoint_cost(),
+            CostingEntry::RevertToCheckpoint => ft.revert_to_checkpoint_cost(),
             CostingEntry::RoyaltyModule { direct_charge } => *direct_charge,
             CostingEntry::AuthModule { direct_charge } => *direct_charge,
         }
@@ -208,6 +235,9 @@ impl<'a> CostingEntry<'a> {
                     node_id.entity_type().map(|x| x.into()).unwrap_or("?")
                 )
             }
+            CostingEntry::Crypto { op, .. } => {
+                format!("Crypto::{}", Into::<&'static str>::into(op))
+            }
             CostingEntry::Commit { store_commit } => {
                 format!(
                     "Commit::{}",
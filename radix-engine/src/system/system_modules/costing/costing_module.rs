@@ -379,6 +379,8 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for CostingModule {
 
     fn on_set_substate<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
+        _node_id: &NodeId,
+        _substate_key: &SubstateKey,
         value_size: usize,
         store_access: &StoreAccessInfo,
     ) -> Result<(), RuntimeError> {
@@ -1,4 +1,5 @@
 use super::FeeSummary;
+use crate::system::system_modules::limits::SubstateUsage;
 use crate::{
     errors::CanBeAbortion, track::interface::StoreCommit, transaction::AbortReason, types::*,
 };
@@ -76,6 +77,15 @@ pub enum RoyaltyRecipient {
     Component(ComponentAddress),
 }
 
+impl From<RoyaltyRecipient> for GlobalAddress {
+    fn from(value: RoyaltyRecipient) -> Self {
+        match value {
+            RoyaltyRecipient::Package(address) => address.into(),
+            RoyaltyRecipient::Component(address) => address.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, ScryptoSbor)]
 pub struct SystemLoanFeeReserve {
     /// The price of cost unit
@@ -109,6 +119,8 @@ pub struct SystemLoanFeeReserve {
     /// Royalty costs
     royalty_committed: BTreeMap<RoyaltyRecipient, (NodeId, u128)>,
     royalty_committed_sum: u128,
+    /// Whether any royalty was denominated in USD and converted at `usd_price`
+    usd_royalty_committed: bool,
 
     /// State expansion costs
     state_expansion_committed: u128,
@@ -172,6 +184,7 @@ impl SystemLoanFeeReserve {
 
             royalty_committed: BTreeMap::new(),
             royalty_committed_sum: 0,
+            usd_royalty_committed: false,
 
             state_expansion_committed: 0,
 
@@ -184,6 +197,12 @@ impl SystemLoanFeeReserve {
         self
     }
 
+    /// Overrides the price of USD, e.g. with the protocol-level price of the consensus manager.
+    pub fn with_usd_price(mut self, usd_price: Decimal) -> Self {
+        self.usd_price = transmute_decimal_as_u128(usd_price).unwrap();
+        self
+    }
+
     pub fn cost_unit_limit(&self) -> u32 {
         self.cost_unit_limit
     }
@@ -241,15 +260,16 @@ impl SystemLoanFeeReserve {
         recipient: RoyaltyRecipient,
         recipient_vault_id: NodeId,
     ) -> Result<(), FeeReserveError> {
-        let amount = match royalty_amount {
-            RoyaltyAmount::Xrd(xrd_amount) => transmute_decimal_as_u128(xrd_amount)?,
-            RoyaltyAmount::Usd(usd_amount) => {
+        let (amount, is_usd) = match royalty_amount {
+            RoyaltyAmount::Xrd(xrd_amount) => (transmute_decimal_as_u128(xrd_amount)?, false),
+            RoyaltyAmount::Usd(usd_amount) => (
                 transmute_decimal_as_u128(usd_amount)?
                     .checked_mul(self.usd_price)
                     .ok_or(FeeReserveError::Overflow)?
-                    / 1_000_000_000_000_000_000
-            }
-            RoyaltyAmount::Free => 0u128,
+                    / 1_000_000_000_000_000_000,
+                true,
+            ),
+            RoyaltyAmount::Free => (0u128, false),
         };
         if self.xrd_balance < amount {
             return Err(FeeReserveError::InsufficientBalance {
@@ -264,6 +284,7 @@ impl SystemLoanFeeReserve {
                 .1
                 .add_assign(amount);
             self.royalty_committed_sum += amount;
+            self.usd_royalty_committed |= is_usd;
             Ok(())
         }
     }
@@ -296,6 +317,7 @@ impl SystemLoanFeeReserve {
         self.xrd_balance += self.royalty_committed.values().map(|x| x.1).sum::<u128>();
         self.royalty_committed.clear();
         self.royalty_committed_sum = 0;
+        self.usd_royalty_committed = false;
     }
 
     pub fn royalty_cost(&self) -> BTreeMap<RoyaltyRecipient, (NodeId, Decimal)> {
@@ -425,7 +447,14 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             execution_cost_breakdown: BTreeMap::new(),
             execution_cost_sum: self.execution_committed_sum,
             royalty_cost_breakdown,
+            usd_price_applied: if self.usd_royalty_committed {
+                Some(transmute_u128_as_decimal(self.usd_price))
+            } else {
+                None
+            },
             fee_payments: index_map_new(),
+            substate_usage: SubstateUsage::default(),
+            wasm_invocation_count: 0,
         };
 
         // Sanity check
@@ -593,6 +622,28 @@ mod tests {
                 RoyaltyRecipient::Package(PACKAGE_PACKAGE) => (TEST_VAULT_ID, dec!("16"))
             )
         );
+        assert_eq!(summary.usd_price_applied, Some(dec!("2")));
+    }
+
+    #[test]
+    fn test_usd_royalty_uses_overridden_usd_price() {
+        let mut fee_reserve =
+            SystemLoanFeeReserve::new(dec!(5), dec!(2), dec!(0), 1, 100, 50, false)
+                .with_usd_price(dec!(3));
+        fee_reserve
+            .consume_royalty(
+                RoyaltyAmount::Usd(7.into()),
+                RoyaltyRecipient::Package(PACKAGE_PACKAGE),
+                TEST_VAULT_ID,
+            )
+            .unwrap();
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .unwrap();
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.total_royalty_cost_xrd, dec!("21"));
+        assert_eq!(summary.usd_price_applied, Some(dec!("3")));
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use super::RoyaltyRecipient;
+use crate::system::system_modules::limits::SubstateUsage;
 use crate::types::*;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use sbor::rust::collections::*;
@@ -29,8 +30,14 @@ pub struct FeeSummary {
     pub execution_cost_sum: u32,
     /// The royalty cost breakdown
     pub royalty_cost_breakdown: BTreeMap<RoyaltyRecipient, (NodeId, Decimal)>,
+    /// The USD price in XRD used to convert royalties denominated in USD, if any were charged.
+    pub usd_price_applied: Option<Decimal>,
     /// The actual fee payments
     pub fee_payments: IndexMap<NodeId, Decimal>,
+    /// The substates read from the database and written
+    pub substate_usage: SubstateUsage,
+    /// The number of WASM invocations, including the nested ones
+    pub wasm_invocation_count: usize,
 }
 
 impl FeeSummary {
@@ -53,6 +60,14 @@ impl FeeSummary {
             + self.total_royalty_cost_xrd
     }
 
+    /// The royalty paid to each recipient. Royalties are always paid in XRD.
+    pub fn royalty_distributions(&self) -> Vec<(GlobalAddress, ResourceAddress, Decimal)> {
+        self.royalty_cost_breakdown
+            .iter()
+            .map(|(recipient, (_, amount))| (recipient.clone().into(), XRD, amount.clone()))
+            .collect()
+    }
+
     //===================
     // For testing only
     //===================
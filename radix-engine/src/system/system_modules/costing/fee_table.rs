@@ -1,3 +1,4 @@
+use super::CryptoOp;
 use crate::{
     blueprints::package::*,
     kernel::actor::Actor,
@@ -5,6 +6,7 @@ use crate::{
     types::*,
 };
 use lazy_static::lazy_static;
+use radix_engine_constants::DEFAULT_CRYPTO_COST_MULTIPLIER_PERCENTAGE;
 
 lazy_static! {
     pub static ref NATIVE_FUNCTION_BASE_COSTS: IndexMap<PackageAddress, IndexMap<&'static str, u32>> = {
@@ -53,11 +55,21 @@ lazy_static! {
 ///
 /// FIXME: fee table is actively adjusted at this point of time!
 #[derive(Debug, Clone, ScryptoSbor)]
-pub struct FeeTable;
+pub struct FeeTable {
+    /// The multiplier applied to the costs of cryptographic operations, in percentage.
+    crypto_cost_multiplier_percentage: u32,
+}
 
 impl FeeTable {
     pub fn new() -> Self {
-        Self
+        Self {
+            crypto_cost_multiplier_percentage: DEFAULT_CRYPTO_COST_MULTIPLIER_PERCENTAGE,
+        }
+    }
+
+    pub fn with_crypto_cost_multiplier_percentage(mut self, percentage: u32) -> Self {
+        self.crypto_cost_multiplier_percentage = percentage;
+        self
     }
 
     fn transient_data_cost(size: usize) -> u32 {
@@ -334,6 +346,12 @@ impl FeeTable {
         500
     }
 
+    #[inline]
+    pub fn evaluate_state_gate_cost(&self) -> u32 {
+        // On top of the cost of invoking the gate function
+        5000
+    }
+
     #[inline]
     pub fn query_transaction_hash_cost(&self) -> u32 {
         500
@@ -354,11 +372,62 @@ impl FeeTable {
         500 + Self::data_processing_cost(size) + Self::transient_data_cost(size)
     }
 
+    #[inline]
+    pub fn emit_metric_cost(&self, size: usize) -> u32 {
+        500 + Self::data_processing_cost(size) + Self::transient_data_cost(size)
+    }
+
     #[inline]
     pub fn panic_cost(&self, size: usize) -> u32 {
         500 + Self::data_processing_cost(size) + Self::transient_data_cost(size)
     }
 
+    //======================
+    // Crypto costs
+    //======================
+
+    #[inline]
+    pub fn crypto_cost(&self, op: &CryptoOp, input_size: usize) -> u32 {
+        let cost = match op {
+            CryptoOp::Blake2b256Hash => {
+                // FIXME: calibrate with benchmark `bench_hash_blake2b`
+                // Estimated execution time (µs): 0.0014 * size + 0.4
+                // Execution cost: (0.0014 * size + 0.4) * 100 = 0.14 * size + 40
+                add(cast(input_size) / 7, 40)
+            }
+            CryptoOp::Secp256k1Verify => {
+                // Based on benchmark `bench_validate_secp256k1`
+                // The input is the hash of the message, so only the base cost applies.
+                7_000
+            }
+            CryptoOp::Ed25519Verify => {
+                // FIXME: calibrate with benchmark `bench_validate_ed25519`
+                // The input is the hash of the message, so only the base cost applies.
+                // Estimated execution time (µs): 52, which is 52 * 100 = 5,200 cost units
+                5_200
+            }
+        };
+
+        apply_percentage(cost, self.crypto_cost_multiplier_percentage)
+    }
+
+    //======================
+    // Checkpoint costs
+    //======================
+    // The costs of the execution being reverted are not refunded.
+
+    #[inline]
+    pub fn create_checkpoint_cost(&self) -> u32 {
+        // FIXME: calibrate with benchmark, as the whole tracked state is copied
+        5_000
+    }
+
+    #[inline]
+    pub fn revert_to_checkpoint_cost(&self) -> u32 {
+        // FIXME: calibrate with benchmark, as the whole tracked state is restored
+        5_000
+    }
+
     //======================
     // System module costs
     //======================
@@ -385,3 +454,43 @@ fn add3(a: u32, b: u32, c: u32) -> u32 {
 fn mul(a: u32, b: u32) -> u32 {
     a.checked_mul(b).unwrap_or(u32::MAX)
 }
+
+#[inline]
+fn apply_percentage(a: u32, percentage: u32) -> u32 {
+    u32::try_from(a as u64 * percentage as u64 / 100).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crypto_cost_is_proportional_to_input_size() {
+        let fee_table = FeeTable::new();
+
+        let small = fee_table.crypto_cost(&CryptoOp::Blake2b256Hash, 1_000);
+        let large = fee_table.crypto_cost(&CryptoOp::Blake2b256Hash, 1_000_000);
+        assert!(large > small * 100);
+        assert_eq!(
+            fee_table.crypto_cost(&CryptoOp::Ed25519Verify, 32),
+            fee_table.crypto_cost(&CryptoOp::Ed25519Verify, 64)
+        );
+    }
+
+    #[test]
+    fn test_crypto_cost_multiplier_is_applied() {
+        let cost = FeeTable::new().crypto_cost(&CryptoOp::Secp256k1Verify, 32);
+
+        let fee_table = FeeTable::new().with_crypto_cost_multiplier_percentage(250);
+        assert_eq!(
+            fee_table.crypto_cost(&CryptoOp::Secp256k1Verify, 32),
+            cost * 5 / 2
+        );
+
+        let fee_table = FeeTable::new().with_crypto_cost_multiplier_percentage(u32::MAX);
+        assert_eq!(
+            fee_table.crypto_cost(&CryptoOp::Secp256k1Verify, 32),
+            u32::MAX
+        );
+    }
+}
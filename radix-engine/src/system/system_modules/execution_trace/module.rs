@@ -2,6 +2,7 @@ use crate::blueprints::resource::VaultUtil;
 use crate::errors::*;
 use crate::kernel::actor::{Actor, MethodActor};
 use crate::kernel::call_frame::Message;
+use crate::kernel::heap::HeapUsage;
 use crate::kernel::kernel_api::KernelApi;
 use crate::kernel::kernel_callback_api::KernelCallbackObject;
 use crate::system::module::SystemModule;
@@ -40,12 +41,21 @@ pub struct ExecutionTraceModule {
 
     /// Vault operations: (Caller, Vault ID, operation, instruction index)
     vault_ops: Vec<(TraceActor, NodeId, VaultOp, usize)>,
+
+    /// The peak memory of each WASM invocation, in the order the invocations returned
+    wasm_memory_peaks: Vec<(BlueprintId, usize)>,
 }
 
 impl ExecutionTraceModule {
     pub fn update_instruction_index(&mut self, new_index: usize) {
         self.current_instruction_index = new_index;
     }
+
+    /// WASM memory only ever grows, so the memory consumed by an instance once its invocation
+    /// returns is also its peak.
+    pub fn update_wasm_memory_usage(&mut self, blueprint_id: BlueprintId, consumed_memory: usize) {
+        self.wasm_memory_peaks.push((blueprint_id, consumed_memory));
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -56,6 +66,18 @@ pub struct ResourceChange {
     pub amount: Decimal,
 }
 
+/// The resource movements of a single manifest instruction.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct InstructionTrace {
+    pub instruction_index: usize,
+    /// The buckets taken from and put on the worktop, in order.
+    pub worktop_changes: Vec<WorktopChange>,
+    /// The proofs created, whether returned to the transaction processor or an application.
+    pub proofs_created: Vec<ProofSnapshot>,
+    /// The net change of each vault touched by the instruction.
+    pub vault_changes: Vec<ResourceChange>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum WorktopChange {
     Take(ResourceSpecifier),
@@ -240,6 +262,33 @@ impl ExecutionTrace {
             child.worktop_changes(worktop_changes_aggregator)
         }
     }
+
+    /// Proofs are often created by nested calls (e.g. an account creating a proof from one of
+    /// its vaults), so the same proof may be output by several traces; it's only counted once.
+    pub fn proofs_created(
+        &self,
+        proofs_created_aggregator: &mut IndexMap<usize, IndexMap<NodeId, ProofSnapshot>>,
+    ) {
+        let ident = match &self.origin {
+            TraceOrigin::ScryptoMethod(fn_identifier)
+            | TraceOrigin::ScryptoFunction(fn_identifier) => Some(&fn_identifier.ident),
+            TraceOrigin::CreateNode | TraceOrigin::DropNode => None,
+        };
+        if ident.map_or(false, |ident| ident.starts_with("create_proof")) {
+            for (proof_id, proof_snapshot) in self.output.proofs.iter() {
+                proofs_created_aggregator
+                    .entry(self.instruction_index)
+                    .or_default()
+                    .entry(*proof_id)
+                    .or_insert_with(|| proof_snapshot.clone());
+            }
+        }
+
+        // Aggregate the created proofs for all children traces
+        for child in self.children.iter() {
+            child.proofs_created(proofs_created_aggregator)
+        }
+    }
 }
 
 impl ResourceSummary {
@@ -396,6 +445,7 @@ impl ExecutionTraceModule {
             traced_kernel_call_inputs_stack: vec![],
             kernel_call_traces_stacks: index_map_new(),
             vault_ops: Vec::new(),
+            wasm_memory_peaks: Vec::new(),
         }
     }
 
@@ -611,11 +661,15 @@ impl ExecutionTraceModule {
 
         let fee_locks = calculate_fee_locks(&self.vault_ops);
         let resource_changes = calculate_resource_changes(self.vault_ops, fee_payments, is_success);
+        let instruction_traces = calculate_instruction_traces(&execution_traces, &resource_changes);
 
         TransactionExecutionTrace {
             execution_traces,
             resource_changes,
+            instruction_traces,
             fee_locks,
+            wasm_memory_peaks: self.wasm_memory_peaks,
+            heap_peak_usage: HeapUsage::default(),
         }
     }
 
@@ -748,6 +802,46 @@ pub fn calculate_resource_changes(
     resource_changes
 }
 
+/// Groups the worktop changes, created proofs and vault changes by instruction. Instructions
+/// without any resource movement are omitted.
+pub fn calculate_instruction_traces(
+    execution_traces: &Vec<ExecutionTrace>,
+    resource_changes: &IndexMap<usize, Vec<ResourceChange>>,
+) -> Vec<InstructionTrace> {
+    let mut worktop_changes = index_map_new::<usize, Vec<WorktopChange>>();
+    let mut proofs_created = index_map_new::<usize, IndexMap<NodeId, ProofSnapshot>>();
+    for trace in execution_traces {
+        trace.worktop_changes(&mut worktop_changes);
+        trace.proofs_created(&mut proofs_created);
+    }
+
+    let instruction_indices: BTreeSet<usize> = worktop_changes
+        .keys()
+        .chain(proofs_created.keys())
+        .chain(resource_changes.keys())
+        .cloned()
+        .collect();
+
+    instruction_indices
+        .into_iter()
+        .map(|instruction_index| InstructionTrace {
+            instruction_index,
+            worktop_changes: worktop_changes
+                .get(&instruction_index)
+                .cloned()
+                .unwrap_or_default(),
+            proofs_created: proofs_created
+                .get(&instruction_index)
+                .map(|proofs| proofs.values().cloned().collect())
+                .unwrap_or_default(),
+            vault_changes: resource_changes
+                .get(&instruction_index)
+                .cloned()
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
 pub fn calculate_fee_locks(vault_ops: &Vec<(TraceActor, NodeId, VaultOp, usize)>) -> FeeLocks {
     let mut fee_locks = FeeLocks {
         lock: Decimal::ZERO,
@@ -0,0 +1,475 @@
+use crate::blueprints::resource::FungibleProofSubstate;
+use crate::errors::*;
+use crate::kernel::actor::{Actor, MethodActor};
+use crate::kernel::kernel_api::KernelApi;
+use crate::system::module::SystemModule;
+use crate::system::node_modules::type_info::TypeInfoSubstate;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::track::interface::{NodeSubstates, StoreAccessInfo};
+use crate::types::*;
+use radix_engine_interface::blueprints::resource::*;
+
+//===================================================================================
+// Note: InvariantChecks is for engine development and fuzzing only. A violation is a
+// bug in the engine, so it panics rather than failing the transaction!
+//===================================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FungibleContainerKind {
+    Vault,
+    Bucket,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FungibleBalanceField {
+    Liquid,
+    Locked,
+}
+
+impl FungibleContainerKind {
+    fn balance_field(&self, substate_key: &SubstateKey) -> Option<FungibleBalanceField> {
+        let (liquid, locked): (SubstateKey, SubstateKey) = match self {
+            FungibleContainerKind::Vault => (
+                FungibleVaultField::LiquidFungible.into(),
+                FungibleVaultField::LockedFungible.into(),
+            ),
+            FungibleContainerKind::Bucket => (
+                FungibleBucketField::Liquid.into(),
+                FungibleBucketField::Locked.into(),
+            ),
+        };
+        if substate_key.eq(&liquid) {
+            Some(FungibleBalanceField::Liquid)
+        } else if substate_key.eq(&locked) {
+            Some(FungibleBalanceField::Locked)
+        } else {
+            None
+        }
+    }
+}
+
+/// A fungible vault or bucket, with each balance field as it was when first seen in the
+/// transaction and as it is now.
+#[derive(Debug, Clone)]
+struct FungibleContainer {
+    kind: FungibleContainerKind,
+    resource_address: ResourceAddress,
+    liquid: Option<(Decimal, Decimal)>,
+    locked: Option<(LockedFungibleResource, LockedFungibleResource)>,
+}
+
+impl FungibleContainer {
+    fn balance_change(&self) -> Decimal {
+        let liquid_change = self
+            .liquid
+            .map(|(initial, current)| current - initial)
+            .unwrap_or(Decimal::zero());
+        let locked_change = self
+            .locked
+            .as_ref()
+            .map(|(initial, current)| current.amount() - initial.amount())
+            .unwrap_or(Decimal::zero());
+        liquid_change + locked_change
+    }
+
+    fn lock_count(&self) -> usize {
+        self.locked
+            .as_ref()
+            .map(|(_, current)| current.amounts.values().sum())
+            .unwrap_or(0)
+    }
+
+    fn record(&mut self, field: FungibleBalanceField, value: &IndexedScryptoValue) {
+        match field {
+            FungibleBalanceField::Liquid => {
+                let amount = value.as_typed::<LiquidFungibleResource>().unwrap().amount();
+                assert!(
+                    !amount.is_negative(),
+                    "Negative liquid balance {} in {:?} of {:?}",
+                    amount,
+                    self.kind,
+                    self.resource_address
+                );
+                self.liquid = Some((self.liquid.map_or(amount, |(initial, _)| initial), amount));
+            }
+            FungibleBalanceField::Locked => {
+                let locked = value.as_typed::<LockedFungibleResource>().unwrap();
+                for (amount, count) in &locked.amounts {
+                    assert!(
+                        !amount.is_negative() && *count > 0,
+                        "Invalid locked balance {} x {} in {:?} of {:?}",
+                        amount,
+                        count,
+                        self.kind,
+                        self.resource_address
+                    );
+                }
+                let initial = match self.locked.take() {
+                    Some((initial, _)) => initial,
+                    None => locked.clone(),
+                };
+                self.locked = Some((initial, locked));
+            }
+        }
+    }
+}
+
+/// Asserts global resource invariants while a transaction executes:
+/// * no fungible vault or bucket ever holds a negative balance;
+/// * the balances of all fungible vaults and buckets of a resource which tracks its total supply
+///   only change as much as its total supply does;
+/// * the number of times an amount is locked in a fungible vault or bucket matches the number of
+///   live fungible proofs backed by it.
+///
+/// Balances are checked on every write, the other invariants after every invocation made by the
+/// transaction processor, when no resource operation can be half done.
+#[derive(Debug, Clone)]
+pub struct InvariantChecksModule {
+    containers: IndexMap<NodeId, FungibleContainer>,
+    /// The initial and current total supply of each supply-tracking fungible resource touched.
+    total_supplies: IndexMap<ResourceAddress, (Decimal, Decimal)>,
+    /// The supply-tracking fungible resource managers created but not yet globalized.
+    new_resource_managers: IndexMap<NodeId, Decimal>,
+    /// The live fungible proofs, with the containers backing them.
+    proofs: IndexMap<NodeId, Vec<NodeId>>,
+}
+
+impl InvariantChecksModule {
+    pub fn new() -> Self {
+        Self {
+            containers: index_map_new(),
+            total_supplies: index_map_new(),
+            new_resource_managers: index_map_new(),
+            proofs: index_map_new(),
+        }
+    }
+
+    fn handle_before_create_node(&mut self, node_id: &NodeId, node_substates: &NodeSubstates) {
+        let info = match node_substates
+            .get(&TYPE_INFO_FIELD_PARTITION)
+            .and_then(|partition| partition.get(&TypeInfoField::TypeInfo.into()))
+            .map(|value| value.as_typed::<TypeInfoSubstate>().unwrap())
+        {
+            Some(TypeInfoSubstate::Object(info))
+                if info.blueprint_id.package_address.eq(&RESOURCE_PACKAGE) =>
+            {
+                info
+            }
+            _ => return,
+        };
+        let fields = match node_substates.get(&MAIN_BASE_PARTITION) {
+            Some(fields) => fields,
+            None => return,
+        };
+
+        let kind = match info.blueprint_id.blueprint_name.as_str() {
+            FUNGIBLE_VAULT_BLUEPRINT => FungibleContainerKind::Vault,
+            FUNGIBLE_BUCKET_BLUEPRINT => FungibleContainerKind::Bucket,
+            FUNGIBLE_PROOF_BLUEPRINT => {
+                if let Some(value) = fields.get(&FungibleProofField::ProofRefs.into()) {
+                    let proof = value.as_typed::<FungibleProofSubstate>().unwrap();
+                    self.proofs.insert(
+                        node_id.clone(),
+                        proof
+                            .evidence
+                            .keys()
+                            .map(|container| container.as_node_id().clone())
+                            .collect(),
+                    );
+                }
+                return;
+            }
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT
+                if info.features.contains(TRACK_TOTAL_SUPPLY_FEATURE) =>
+            {
+                if let Some(value) = fields.get(&FungibleResourceManagerField::TotalSupply.into()) {
+                    let total_supply = value.as_typed::<Decimal>().unwrap();
+                    self.new_resource_managers
+                        .insert(node_id.clone(), total_supply);
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        // Containers created within the transaction start out empty
+        let mut container = FungibleContainer {
+            kind,
+            resource_address: ResourceAddress::new_or_panic(info.get_outer_object().into()),
+            liquid: Some((Decimal::zero(), Decimal::zero())),
+            locked: Some((
+                LockedFungibleResource::default(),
+                LockedFungibleResource::default(),
+            )),
+        };
+        for (substate_key, value) in fields {
+            if let Some(field) = kind.balance_field(substate_key) {
+                container.record(field, value);
+            }
+        }
+        self.containers.insert(node_id.clone(), container);
+    }
+
+    fn handle_after_move_modules(&mut self, src_node_id: &NodeId, dest_node_id: &NodeId) {
+        if let Some(total_supply) = self.new_resource_managers.remove(src_node_id) {
+            self.total_supplies.insert(
+                ResourceAddress::new_or_panic(dest_node_id.0),
+                (Decimal::zero(), total_supply),
+            );
+        }
+    }
+
+    fn handle_before_drop_node(&mut self, node_id: &NodeId) {
+        // Whatever a dropped container held has been moved elsewhere or burnt
+        if let Some(container) = self.containers.get_mut(node_id) {
+            container.liquid = container
+                .liquid
+                .map(|(initial, _)| (initial, Decimal::zero()));
+            container.locked = container
+                .locked
+                .take()
+                .map(|(initial, _)| (initial, LockedFungibleResource::default()));
+        }
+        self.proofs.remove(node_id);
+    }
+
+    fn check_resource_conservation(&self) {
+        for (resource_address, (initial, current)) in &self.total_supplies {
+            let balance_change = self
+                .containers
+                .values()
+                .filter(|container| container.resource_address.eq(resource_address))
+                .fold(Decimal::zero(), |sum, container| {
+                    sum + container.balance_change()
+                });
+            assert_eq!(
+                balance_change,
+                *current - *initial,
+                "Balances of {:?} changed by {} while its total supply changed from {} to {}",
+                resource_address,
+                balance_change,
+                initial,
+                current
+            );
+        }
+    }
+
+    fn check_proof_references(&self) {
+        let mut proof_counts: IndexMap<NodeId, usize> = index_map_new();
+        for container_id in self.proofs.values().flatten() {
+            *proof_counts.entry(container_id.clone()).or_default() += 1;
+        }
+        for (container_id, container) in &self.containers {
+            let proof_count = proof_counts.remove(container_id).unwrap_or(0);
+            assert_eq!(
+                container.lock_count(),
+                proof_count,
+                "{:?} {:?} is locked {} times but backs {} proofs",
+                container.kind,
+                container_id,
+                container.lock_count(),
+                proof_count
+            );
+        }
+        assert!(
+            proof_counts.is_empty(),
+            "Proofs are backed by containers which were never locked: {:?}",
+            proof_counts.keys().collect::<Vec<_>>()
+        );
+    }
+}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for InvariantChecksModule {
+    fn after_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _output_size: usize,
+    ) -> Result<(), RuntimeError> {
+        // Only check once control is back to the transaction processor (or the root)
+        if api.kernel_get_current_depth() <= 1 {
+            let system_state = api.kernel_get_system_state();
+            let module = &system_state.system.modules.invariant_checks;
+            module.check_resource_conservation();
+            module.check_proof_references();
+        }
+        Ok(())
+    }
+
+    fn before_create_node<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        node_id: &NodeId,
+        node_substates: &NodeSubstates,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system_state()
+            .system
+            .modules
+            .invariant_checks
+            .handle_before_create_node(node_id, node_substates);
+        Ok(())
+    }
+
+    fn after_move_modules<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        src_node_id: &NodeId,
+        dest_node_id: &NodeId,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system_state()
+            .system
+            .modules
+            .invariant_checks
+            .handle_after_move_modules(src_node_id, dest_node_id);
+        Ok(())
+    }
+
+    fn before_drop_node<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        node_id: &NodeId,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system_state()
+            .system
+            .modules
+            .invariant_checks
+            .handle_before_drop_node(node_id);
+        Ok(())
+    }
+
+    fn after_open_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        lock_handle: LockHandle,
+        node_id: &NodeId,
+        _store_access: &StoreAccessInfo,
+        _size: usize,
+    ) -> Result<(), RuntimeError> {
+        // Substates which already existed are first seen when opened by their own object, which
+        // is also the only way to know which resource they belong to
+        let info = match api.kernel_get_system_state().current {
+            Actor::Method(MethodActor {
+                node_id: receiver,
+                module_object_info,
+                ..
+            }) if receiver.eq(node_id)
+                && module_object_info
+                    .blueprint_id
+                    .package_address
+                    .eq(&RESOURCE_PACKAGE) =>
+            {
+                module_object_info.clone()
+            }
+            _ => return Ok(()),
+        };
+        let lock_info = api.kernel_get_lock_info(lock_handle)?;
+        if lock_info.partition_num != MAIN_BASE_PARTITION {
+            return Ok(());
+        }
+
+        match info.blueprint_id.blueprint_name.as_str() {
+            FUNGIBLE_VAULT_BLUEPRINT => {
+                let kind = FungibleContainerKind::Vault;
+                let field = match kind.balance_field(&lock_info.substate_key) {
+                    Some(field) => field,
+                    None => return Ok(()),
+                };
+                let resource_address =
+                    ResourceAddress::new_or_panic(info.get_outer_object().into());
+                if let Some(value) = api.kernel_peek_substate(lock_handle) {
+                    let system_state = api.kernel_get_system_state();
+                    let container = system_state
+                        .system
+                        .modules
+                        .invariant_checks
+                        .containers
+                        .entry(node_id.clone())
+                        .or_insert(FungibleContainer {
+                            kind,
+                            resource_address,
+                            liquid: None,
+                            locked: None,
+                        });
+                    let seen = match field {
+                        FungibleBalanceField::Liquid => container.liquid.is_some(),
+                        FungibleBalanceField::Locked => container.locked.is_some(),
+                    };
+                    if !seen {
+                        container.record(field, &value);
+                    }
+                }
+            }
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT
+                if info.features.contains(TRACK_TOTAL_SUPPLY_FEATURE)
+                    && lock_info
+                        .substate_key
+                        .eq(&FungibleResourceManagerField::TotalSupply.into()) =>
+            {
+                if let Some(value) = api.kernel_peek_substate(lock_handle) {
+                    let total_supply = value.as_typed::<Decimal>().unwrap();
+                    api.kernel_get_system_state()
+                        .system
+                        .modules
+                        .invariant_checks
+                        .total_supplies
+                        .entry(ResourceAddress::new_or_panic(node_id.0))
+                        .or_insert((total_supply, total_supply));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn on_write_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        lock_handle: LockHandle,
+        _value_size: usize,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        let lock_info = api.kernel_get_lock_info(lock_handle)?;
+        if lock_info.partition_num != MAIN_BASE_PARTITION {
+            return Ok(());
+        }
+
+        let system_state = api.kernel_get_system_state();
+        let module = &system_state.system.modules.invariant_checks;
+        let field = module
+            .containers
+            .get(&lock_info.node_id)
+            .and_then(|container| container.kind.balance_field(&lock_info.substate_key));
+        let is_total_supply = ResourceAddress::try_from(lock_info.node_id.0)
+            .map(|resource_address| module.total_supplies.contains_key(&resource_address))
+            .unwrap_or(false)
+            && lock_info
+                .substate_key
+                .eq(&FungibleResourceManagerField::TotalSupply.into());
+        if field.is_none() && !is_total_supply {
+            return Ok(());
+        }
+
+        if let Some(value) = api.kernel_peek_substate(lock_handle) {
+            let system_state = api.kernel_get_system_state();
+            let module = &mut system_state.system.modules.invariant_checks;
+            if let Some(field) = field {
+                module
+                    .containers
+                    .get_mut(&lock_info.node_id)
+                    .unwrap()
+                    .record(field, &value);
+            } else {
+                let total_supply = value.as_typed::<Decimal>().unwrap();
+                assert!(
+                    !total_supply.is_negative(),
+                    "Negative total supply {} of {:?}",
+                    total_supply,
+                    lock_info.node_id
+                );
+                let entry = module
+                    .total_supplies
+                    .get_mut(&ResourceAddress::new_or_panic(lock_info.node_id.0))
+                    .unwrap();
+                entry.1 = total_supply;
+            }
+        }
+
+        Ok(())
+    }
+}
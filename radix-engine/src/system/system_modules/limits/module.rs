@@ -1,3 +1,6 @@
+use crate::kernel::actor::Actor;
+use crate::kernel::call_frame::Message;
+use crate::kernel::heap::HeapUsage;
 use crate::kernel::kernel_api::KernelInvocation;
 use crate::system::module::SystemModule;
 use crate::system::system_callback::SystemConfig;
@@ -8,37 +11,112 @@ use crate::{errors::RuntimeError, errors::SystemModuleError, kernel::kernel_api:
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum TransactionLimitsError {
-    MaxSubstateSizeExceeded(usize),
+    /// A substate value is larger than the configured limit.
+    ///
+    /// Field substates are loaded as a whole, so state which grows over time, such as a map or a
+    /// list, should be kept in a `KeyValueStore` rather than in a component field.
+    SubstateSizeExceeded {
+        actual: usize,
+        limit: usize,
+        node: NodeId,
+        substate: SubstateKey,
+    },
     MaxInvokePayloadSizeExceeded(usize),
     MaxCallDepthLimitReached,
     TooManyEntriesInTrack,
-    LogSizeTooLarge { actual: usize, max: usize },
-    EventSizeTooLarge { actual: usize, max: usize },
-    PanicMessageSizeTooLarge { actual: usize, max: usize },
+    LogSizeTooLarge {
+        actual: usize,
+        max: usize,
+    },
+    PanicMessageSizeTooLarge {
+        actual: usize,
+        max: usize,
+    },
+    MetricNameSizeTooLarge {
+        actual: usize,
+        max: usize,
+    },
     TooManyLogs,
-    TooManyEvents,
+    TooManyMetrics,
+    TooManySubstateReads {
+        max: usize,
+    },
+    TooManySubstateWrites {
+        max: usize,
+    },
+    SubstateReadSizeTooLarge {
+        actual: usize,
+        max: usize,
+    },
+    SubstateWriteSizeTooLarge {
+        actual: usize,
+        max: usize,
+    },
+    /// A call frame, including its callees, added too many nodes to the heap.
+    TooManyHeapNodes {
+        actual: usize,
+        max: usize,
+    },
+    /// A call frame, including its callees, added too many substate bytes to the heap.
+    HeapSubstateSizeTooLarge {
+        actual: usize,
+        max: usize,
+    },
+    /// The transaction invoked WASM more times than allowed, e.g. through recursive calls.
+    TooManyWasmInvocations {
+        max: usize,
+    },
 }
 
+#[derive(Clone)]
 pub struct TransactionLimitsConfig {
     pub max_number_of_substates_in_track: usize,
     pub max_number_of_substates_in_heap: usize, // FIXME: enforce this limits in heap!
     pub max_substate_size: usize,
     pub max_invoke_payload_size: usize,
-    pub max_event_size: usize,
     pub max_log_size: usize,
     pub max_panic_message_size: usize,
     pub max_number_of_logs: usize,
-    pub max_number_of_events: usize,
+    pub max_event_count: usize,
+    pub max_event_payload_size: usize,
+    pub max_total_event_size: usize,
+    pub max_metric_name_size: usize,
+    pub max_number_of_metrics: usize,
+    pub max_number_of_substate_reads: usize,
+    pub max_number_of_substate_writes: usize,
+    pub max_total_substate_read_size: usize,
+    pub max_total_substate_write_size: usize,
+    pub max_heap_nodes_per_call_frame: usize,
+    pub max_heap_substate_size_per_call_frame: usize,
+    pub max_wasm_invocations: usize,
+}
+
+/// The substates read from the database and written by a transaction.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct SubstateUsage {
+    /// The number of substates read from the database, including the non-existent ones.
+    pub read_count: usize,
+    /// The total size of the substates read from the database, in bytes.
+    pub read_size: usize,
+    /// The number of substates written, including those of the created nodes.
+    pub write_count: usize,
+    /// The total size of the substates written, in bytes.
+    pub write_size: usize,
 }
 
 /// Tracks and verifies transaction limits during transactino execution,
 /// if exceeded breaks execution with appropriate error.
 /// Default limits values are defined in radix-engine-constants lib.
 /// Stores boundary values of the limits and returns them in transaction receipt.
+#[derive(Clone)]
 pub struct LimitsModule {
     config: TransactionLimitsConfig,
     number_of_substates_in_track: usize,
     _number_of_substates_in_heap: usize,
+    substate_usage: SubstateUsage,
+    /// The heap usage when each call frame of the stack was entered, and the peak since.
+    call_frame_heap_usages: Vec<(HeapUsage, HeapUsage)>,
+    wasm_invocation_count: usize,
 }
 
 impl LimitsModule {
@@ -47,6 +125,10 @@ impl LimitsModule {
             config: limits_config,
             number_of_substates_in_track: 0,
             _number_of_substates_in_heap: 0,
+            substate_usage: SubstateUsage::default(),
+            // The root call frame starts with an empty heap
+            call_frame_heap_usages: vec![(HeapUsage::default(), HeapUsage::default())],
+            wasm_invocation_count: 0,
         }
     }
 
@@ -54,13 +136,140 @@ impl LimitsModule {
         &self.config
     }
 
+    pub fn substate_usage(&self) -> SubstateUsage {
+        self.substate_usage
+    }
+
+    /// The number of WASM invocations so far, including the nested ones.
+    pub fn wasm_invocation_count(&self) -> usize {
+        self.wasm_invocation_count
+    }
+
+    pub fn process_wasm_invocation(&mut self) -> Result<(), RuntimeError> {
+        self.wasm_invocation_count += 1;
+
+        if self.wasm_invocation_count > self.config.max_wasm_invocations {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::TooManyWasmInvocations {
+                        max: self.config.max_wasm_invocations,
+                    },
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The peak heap usage of the transaction.
+    pub fn heap_peak_usage(&self) -> HeapUsage {
+        self.call_frame_heap_usages
+            .iter()
+            .fold(HeapUsage::default(), |peak, (_, frame_peak)| {
+                peak.max(frame_peak)
+            })
+    }
+
+    /// Checks the heap usage added by each call frame of the stack, including its callees, as the
+    /// heap only grows when a node is created or a substate is written.
+    fn process_heap_usage(&mut self, heap_usage: HeapUsage) -> Result<(), RuntimeError> {
+        let (_, peak_usage) = self
+            .call_frame_heap_usages
+            .last_mut()
+            .expect("Root call frame heap usage missing");
+        *peak_usage = peak_usage.max(&heap_usage);
+        // The frame which has added the most is the one which entered with the least
+        let added = self
+            .call_frame_heap_usages
+            .iter()
+            .fold(HeapUsage::default(), |added, (entry_usage, _)| {
+                added.max(&heap_usage.saturating_sub(entry_usage))
+            });
+
+        if added.node_count > self.config.max_heap_nodes_per_call_frame {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::TooManyHeapNodes {
+                        actual: added.node_count,
+                        max: self.config.max_heap_nodes_per_call_frame,
+                    },
+                ),
+            ))
+        } else if added.substate_size > self.config.max_heap_substate_size_per_call_frame {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::HeapSubstateSizeTooLarge {
+                        actual: added.substate_size,
+                        max: self.config.max_heap_substate_size_per_call_frame,
+                    },
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_substate_size(
+        &self,
+        node_id: &NodeId,
+        substate_key: &SubstateKey,
+        value_size: usize,
+    ) -> Result<(), RuntimeError> {
+        if value_size > self.config.max_substate_size {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::SubstateSizeExceeded {
+                        actual: value_size,
+                        limit: self.config.max_substate_size,
+                        node: *node_id,
+                        substate: substate_key.clone(),
+                    },
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn process_substate_write(&mut self, value_size: usize) -> Result<(), RuntimeError> {
+        self.substate_usage.write_count += 1;
+        self.substate_usage.write_size += value_size;
+
+        if self.substate_usage.write_count > self.config.max_number_of_substate_writes {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::TooManySubstateWrites {
+                        max: self.config.max_number_of_substate_writes,
+                    },
+                ),
+            ))
+        } else if self.substate_usage.write_size > self.config.max_total_substate_write_size {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::SubstateWriteSizeTooLarge {
+                        actual: self.substate_usage.write_size,
+                        max: self.config.max_total_substate_write_size,
+                    },
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn process_store_access(
         &mut self,
         store_access: &StoreAccessInfo,
     ) -> Result<(), RuntimeError> {
         for access in store_access {
             match access {
-                StoreAccess::ReadFromDb(_) | StoreAccess::ReadFromDbNotFound => {}
+                StoreAccess::ReadFromDb(size) => {
+                    self.substate_usage.read_count += 1;
+                    self.substate_usage.read_size += *size;
+                }
+                StoreAccess::ReadFromDbNotFound => {
+                    self.substate_usage.read_count += 1;
+                }
                 StoreAccess::NewEntryInTrack => {
                     self.number_of_substates_in_track += 1;
                 }
@@ -73,6 +282,23 @@ impl LimitsModule {
                     TransactionLimitsError::TooManyEntriesInTrack,
                 ),
             ))
+        } else if self.substate_usage.read_count > self.config.max_number_of_substate_reads {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::TooManySubstateReads {
+                        max: self.config.max_number_of_substate_reads,
+                    },
+                ),
+            ))
+        } else if self.substate_usage.read_size > self.config.max_total_substate_read_size {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::SubstateReadSizeTooLarge {
+                        actual: self.substate_usage.read_size,
+                        max: self.config.max_total_substate_read_size,
+                    },
+                ),
+            ))
         } else {
             Ok(())
         }
@@ -108,22 +334,50 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
         Ok(())
     }
 
+    fn before_push_frame<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _callee: &Actor,
+        _message: &mut Message,
+        _args: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        let heap_usage = api.kernel_get_heap_usage();
+        api.kernel_get_system()
+            .modules
+            .limits
+            .call_frame_heap_usages
+            .push((heap_usage, heap_usage));
+        Ok(())
+    }
+
+    fn after_pop_frame<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _dropped_actor: &Actor,
+    ) -> Result<(), RuntimeError> {
+        let call_frame_heap_usages = &mut api
+            .kernel_get_system()
+            .modules
+            .limits
+            .call_frame_heap_usages;
+        let (_, callee_peak_usage) = call_frame_heap_usages
+            .pop()
+            .expect("Call frame heap usage missing");
+        if let Some((_, peak_usage)) = call_frame_heap_usages.last_mut() {
+            *peak_usage = peak_usage.max(&callee_peak_usage);
+        }
+        Ok(())
+    }
+
     fn before_create_node<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
-        _node_id: &NodeId,
+        node_id: &NodeId,
         node_substates: &NodeSubstates,
     ) -> Result<(), RuntimeError> {
-        let limits = &mut api.kernel_get_system().modules.limits.config;
+        let limits = &mut api.kernel_get_system().modules.limits;
 
         for partitions in node_substates.values() {
-            for (_, value) in partitions {
-                if value.len() > limits.max_substate_size {
-                    return Err(RuntimeError::SystemModuleError(
-                        SystemModuleError::TransactionLimitsError(
-                            TransactionLimitsError::MaxSubstateSizeExceeded(value.len()),
-                        ),
-                    ));
-                }
+            for (substate_key, value) in partitions {
+                limits.check_substate_size(node_id, substate_key, value.len())?;
+                limits.process_substate_write(value.len())?;
             }
         }
 
@@ -136,10 +390,10 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
         _total_substate_size: usize,
         store_access: &StoreAccessInfo,
     ) -> Result<(), RuntimeError> {
-        api.kernel_get_system()
-            .modules
-            .limits
-            .process_store_access(store_access)
+        let heap_usage = api.kernel_get_heap_usage();
+        let limits = &mut api.kernel_get_system().modules.limits;
+        limits.process_heap_usage(heap_usage)?;
+        limits.process_store_access(store_access)
     }
 
     #[inline(always)]
@@ -182,24 +436,31 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
 
     fn on_write_substate<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
-        _lock_handle: LockHandle,
+        lock_handle: LockHandle,
         value_size: usize,
         store_access: &StoreAccessInfo,
     ) -> Result<(), RuntimeError> {
-        let limits = &mut api.kernel_get_system().modules.limits.config;
-
-        if value_size > limits.max_substate_size {
-            return Err(RuntimeError::SystemModuleError(
-                SystemModuleError::TransactionLimitsError(
-                    TransactionLimitsError::MaxSubstateSizeExceeded(value_size),
-                ),
-            ));
+        if value_size
+            > api
+                .kernel_get_system()
+                .modules
+                .limits
+                .config
+                .max_substate_size
+        {
+            let lock_info = api.kernel_get_lock_info(lock_handle)?;
+            api.kernel_get_system().modules.limits.check_substate_size(
+                &lock_info.node_id,
+                &lock_info.substate_key,
+                value_size,
+            )?;
         }
 
-        api.kernel_get_system()
-            .modules
-            .limits
-            .process_store_access(store_access)
+        let heap_usage = api.kernel_get_heap_usage();
+        let limits = &mut api.kernel_get_system().modules.limits;
+        limits.process_substate_write(value_size)?;
+        limits.process_heap_usage(heap_usage)?;
+        limits.process_store_access(store_access)
     }
 
     fn on_close_substate<Y: KernelApi<SystemConfig<V>>>(
@@ -225,13 +486,17 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
 
     fn on_set_substate<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
-        _value_size: usize,
+        node_id: &NodeId,
+        substate_key: &SubstateKey,
+        value_size: usize,
         store_access: &StoreAccessInfo,
     ) -> Result<(), RuntimeError> {
-        api.kernel_get_system()
-            .modules
-            .limits
-            .process_store_access(store_access)
+        let heap_usage = api.kernel_get_heap_usage();
+        let limits = &mut api.kernel_get_system().modules.limits;
+        limits.check_substate_size(node_id, substate_key, value_size)?;
+        limits.process_substate_write(value_size)?;
+        limits.process_heap_usage(heap_usage)?;
+        limits.process_store_access(store_access)
     }
 
     fn on_take_substates<Y: KernelApi<SystemConfig<V>>>(
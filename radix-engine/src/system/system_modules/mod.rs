@@ -1,9 +1,12 @@
 pub mod auth;
 pub mod costing;
 pub mod execution_trace;
+pub mod invariant_checks;
 pub mod kernel_trace;
 pub mod limits;
+pub mod node_audit;
 pub mod node_move;
+pub mod profiling;
 pub mod transaction_runtime;
 
 mod module_mixer;
@@ -13,9 +13,12 @@ use crate::system::system_modules::costing::CostingModule;
 use crate::system::system_modules::costing::FeeTable;
 use crate::system::system_modules::costing::SystemLoanFeeReserve;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
+use crate::system::system_modules::invariant_checks::InvariantChecksModule;
 use crate::system::system_modules::kernel_trace::KernelTraceModule;
 use crate::system::system_modules::limits::{LimitsModule, TransactionLimitsConfig};
+use crate::system::system_modules::node_audit::NodeAuditModule;
 use crate::system::system_modules::node_move::NodeMoveModule;
+use crate::system::system_modules::profiling::{ProfileFrameKind, ProfilingModule};
 use crate::system::system_modules::transaction_runtime::TransactionRuntimeModule;
 use crate::track::interface::StoreCommit;
 use crate::track::interface::{NodeSubstates, StoreAccessInfo};
@@ -45,6 +48,15 @@ bitflags! {
 
         // Execution trace, for preview only
         const EXECUTION_TRACE = 0x01 << 6;
+
+        // Node allocation audit, for debugging only
+        const NODE_AUDIT = 0x01 << 7;
+
+        // Invariant checks, for engine development and fuzzing only
+        const INVARIANT_CHECKS = 0x01 << 8;
+
+        // Wall-time profiling, for simulator and benchmarking only
+        const PROFILING = 0x01 << 9;
     }
 }
 
@@ -64,7 +76,7 @@ impl EnabledModules {
     }
 
     pub fn for_test_transaction() -> Self {
-        Self::for_notarized_transaction() | Self::KERNEL_TRACE
+        Self::for_notarized_transaction() | Self::KERNEL_TRACE | Self::NODE_AUDIT
     }
 
     pub fn for_preview() -> Self {
@@ -88,6 +100,24 @@ pub struct SystemModuleMixer {
     pub(super) node_move: NodeMoveModule,
     pub(super) transaction_runtime: TransactionRuntimeModule,
     pub(super) execution_trace: ExecutionTraceModule,
+    pub(super) node_audit: NodeAuditModule,
+    pub(super) invariant_checks: InvariantChecksModule,
+    pub(super) profiling: ProfilingModule,
+
+    /* checkpoints */
+    checkpoints: Vec<SystemModuleCheckpoint>,
+}
+
+/// The states of the system modules at a checkpoint.
+///
+/// Costing is never reverted, so that the execution being reverted is still paid for.
+struct SystemModuleCheckpoint {
+    limits: LimitsModule,
+    auth: AuthModule,
+    transaction_runtime: TransactionRuntimeModule,
+    execution_trace: ExecutionTraceModule,
+    node_audit: NodeAuditModule,
+    invariant_checks: InvariantChecksModule,
 }
 
 // Macro generates default modules dispatches call based on passed function name and arguments.
@@ -117,6 +147,15 @@ macro_rules! internal_call_dispatch {
             if modules.contains(EnabledModules::EXECUTION_TRACE) {
                 ExecutionTraceModule::[< $fn >]($($param, )*)?;
             }
+            if modules.contains(EnabledModules::NODE_AUDIT) {
+                NodeAuditModule::[< $fn >]($($param, )*)?;
+            }
+            if modules.contains(EnabledModules::INVARIANT_CHECKS) {
+                InvariantChecksModule::[< $fn >]($($param, )*)?;
+            }
+            if modules.contains(EnabledModules::PROFILING) {
+                ProfilingModule::[< $fn >]($($param, )*)?;
+            }
             Ok(())
         }
     }};
@@ -150,6 +189,7 @@ impl SystemModuleMixer {
             auth: AuthModule {
                 params: auth_zone_params.clone(),
                 auth_zone_stack: Vec::new(),
+                state_gate_stack: Vec::new(),
             },
             limits: LimitsModule::new(TransactionLimitsConfig {
                 max_number_of_substates_in_track: execution_config.max_number_of_substates_in_track,
@@ -157,19 +197,37 @@ impl SystemModuleMixer {
                 max_substate_size: execution_config.max_substate_size,
                 max_invoke_payload_size: execution_config.max_invoke_input_size,
                 max_number_of_logs: execution_config.max_number_of_logs,
-                max_number_of_events: execution_config.max_number_of_events,
-                max_event_size: execution_config.max_event_size,
+                max_event_count: execution_config.max_event_count,
+                max_event_payload_size: execution_config.max_event_payload_size,
+                max_total_event_size: execution_config.max_total_event_size,
                 max_log_size: execution_config.max_log_size,
                 max_panic_message_size: execution_config.max_panic_message_size,
+                max_metric_name_size: execution_config.max_metric_name_size,
+                max_number_of_metrics: execution_config.max_number_of_metrics,
+                max_number_of_substate_reads: execution_config.max_number_of_substate_reads,
+                max_number_of_substate_writes: execution_config.max_number_of_substate_writes,
+                max_total_substate_read_size: execution_config.max_total_substate_read_size,
+                max_total_substate_write_size: execution_config.max_total_substate_write_size,
+                max_heap_nodes_per_call_frame: execution_config.max_heap_nodes_per_call_frame,
+                max_heap_substate_size_per_call_frame: execution_config
+                    .max_heap_substate_size_per_call_frame,
+                max_wasm_invocations: execution_config.max_wasm_invocations,
             }),
             execution_trace: ExecutionTraceModule::new(execution_config.max_execution_trace_depth),
+            node_audit: NodeAuditModule::new(),
+            invariant_checks: InvariantChecksModule::new(),
+            profiling: ProfilingModule::new(),
             transaction_runtime: TransactionRuntimeModule {
                 tx_hash,
+                ruid_seed: execution_config.ruid_seed,
                 next_id: 0,
                 logs: Vec::new(),
+                metrics: Vec::new(),
                 events: Vec::new(),
+                events_size: 0,
                 replacements: index_map_new(),
             },
+            checkpoints: Vec::new(),
         }
     }
 
@@ -179,8 +237,18 @@ impl SystemModuleMixer {
         CostingModule,
         TransactionRuntimeModule,
         ExecutionTraceModule,
+        NodeAuditModule,
+        LimitsModule,
+        ProfilingModule,
     ) {
-        (self.costing, self.transaction_runtime, self.execution_trace)
+        (
+            self.costing,
+            self.transaction_runtime,
+            self.execution_trace,
+            self.node_audit,
+            self.limits,
+            self.profiling,
+        )
     }
 }
 
@@ -417,10 +485,15 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
     #[trace_resources]
     fn on_set_substate<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
+        node_id: &NodeId,
+        substate_key: &SubstateKey,
         value_size: usize,
         store_access: &StoreAccessInfo,
     ) -> Result<(), RuntimeError> {
-        internal_call_dispatch!(api, on_set_substate(api, value_size, store_access))
+        internal_call_dispatch!(
+            api,
+            on_set_substate(api, node_id, substate_key, value_size, store_access)
+        )
     }
 
     #[trace_resources]
@@ -466,31 +539,72 @@ impl SystemModuleMixer {
         Ok(())
     }
 
-    pub fn add_event(
-        &mut self,
-        identifier: EventTypeIdentifier,
-        data: Vec<u8>,
-    ) -> Result<(), RuntimeError> {
+    pub fn add_metric(&mut self, name: String, value: Decimal) -> Result<(), RuntimeError> {
         if self.enabled_modules.contains(EnabledModules::LIMITS) {
-            if self.transaction_runtime.events.len() >= self.limits.config().max_number_of_events {
+            if self.transaction_runtime.metrics.len() >= self.limits.config().max_number_of_metrics
+            {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::TransactionLimitsError(
-                        TransactionLimitsError::TooManyEvents,
+                        TransactionLimitsError::TooManyMetrics,
                     ),
                 ));
             }
-            if data.len() > self.limits.config().max_event_size {
+            if name.len() > self.limits.config().max_metric_name_size {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::TransactionLimitsError(
-                        TransactionLimitsError::EventSizeTooLarge {
-                            actual: data.len(),
-                            max: self.limits.config().max_event_size,
+                        TransactionLimitsError::MetricNameSizeTooLarge {
+                            actual: name.len(),
+                            max: self.limits.config().max_metric_name_size,
                         },
                     ),
                 ));
             }
         }
 
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime.add_metric(name, value);
+        }
+
+        Ok(())
+    }
+
+    pub fn add_event(
+        &mut self,
+        identifier: EventTypeIdentifier,
+        data: Vec<u8>,
+    ) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            let config = self.limits.config();
+            let event_count = self.transaction_runtime.events.len() + 1;
+            let total_event_size = self.transaction_runtime.events_size + data.len();
+            for (limit, actual, max) in [
+                (EventLimit::EventCount, event_count, config.max_event_count),
+                (
+                    EventLimit::EventPayloadSize,
+                    data.len(),
+                    config.max_event_payload_size,
+                ),
+                (
+                    EventLimit::TotalEventSize,
+                    total_event_size,
+                    config.max_total_event_size,
+                ),
+            ] {
+                if actual > max {
+                    return Err(RuntimeError::SystemModuleError(
+                        SystemModuleError::EventError(Box::new(EventError::LimitExceeded {
+                            limit,
+                            actual,
+                            max,
+                        })),
+                    ));
+                }
+            }
+        }
+
         if self
             .enabled_modules
             .contains(EnabledModules::TRANSACTION_RUNTIME)
@@ -584,6 +698,36 @@ impl SystemModuleMixer {
         {
             self.execution_trace.update_instruction_index(new_index)
         }
+        if self.enabled_modules.contains(EnabledModules::NODE_AUDIT) {
+            self.node_audit.update_instruction_index(new_index)
+        }
+    }
+
+    pub fn update_wasm_memory_usage(&mut self, blueprint_id: BlueprintId, consumed_memory: usize) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::EXECUTION_TRACE)
+        {
+            self.execution_trace
+                .update_wasm_memory_usage(blueprint_id, consumed_memory)
+        }
+    }
+
+    pub fn on_wasm_invocation_start(&mut self, export_name: &str) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            self.limits.process_wasm_invocation()?;
+        }
+        if self.enabled_modules.contains(EnabledModules::PROFILING) {
+            self.profiling
+                .enter(ProfileFrameKind::Wasm, format!("wasm:{}", export_name))
+        }
+        Ok(())
+    }
+
+    pub fn on_wasm_invocation_end(&mut self) {
+        if self.enabled_modules.contains(EnabledModules::PROFILING) {
+            self.profiling.exit(ProfileFrameKind::Wasm)
+        }
     }
 
     pub fn apply_execution_cost(
@@ -608,6 +752,38 @@ impl SystemModuleMixer {
         }
     }
 
+    pub fn create_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        self.apply_execution_cost(CostingEntry::CreateCheckpoint)?;
+        self.checkpoints.push(SystemModuleCheckpoint {
+            limits: self.limits.clone(),
+            auth: self.auth.clone(),
+            transaction_runtime: self.transaction_runtime.clone(),
+            execution_trace: self.execution_trace.clone(),
+            node_audit: self.node_audit.clone(),
+            invariant_checks: self.invariant_checks.clone(),
+        });
+        Ok(())
+    }
+
+    pub fn revert_to_checkpoint(&mut self) -> Result<(), RuntimeError> {
+        self.apply_execution_cost(CostingEntry::RevertToCheckpoint)?;
+        let checkpoint = self.checkpoints.pop().expect("No checkpoint to revert to");
+        // RUIDs must stay unique across the whole transaction
+        let next_id = self.transaction_runtime.next_id;
+        self.limits = checkpoint.limits;
+        self.auth = checkpoint.auth;
+        self.transaction_runtime = checkpoint.transaction_runtime;
+        self.transaction_runtime.next_id = next_id;
+        self.execution_trace = checkpoint.execution_trace;
+        self.node_audit = checkpoint.node_audit;
+        self.invariant_checks = checkpoint.invariant_checks;
+        Ok(())
+    }
+
+    pub fn release_checkpoint(&mut self) {
+        self.checkpoints.pop().expect("No checkpoint to release");
+    }
+
     pub fn credit_cost_units(
         &mut self,
         vault_id: NodeId,
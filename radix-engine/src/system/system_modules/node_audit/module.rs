@@ -0,0 +1,148 @@
+use crate::errors::*;
+use crate::kernel::actor::{Actor, MethodActor};
+use crate::kernel::kernel_api::KernelApi;
+use crate::system::module::SystemModule;
+use crate::system::node_modules::type_info::TypeInfoSubstate;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::track::interface::NodeSubstates;
+use crate::types::*;
+use radix_engine_interface::blueprints::resource::*;
+
+//===================================================================================
+// Note: NodeAudit is for debugging only, and must not produce any error or
+// transactional side effect!
+//===================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum AuditedNodeKind {
+    Bucket,
+    Proof,
+    Object(BlueprintId),
+    Other,
+}
+
+/// Where and by whom a node was allocated.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct NodeAllocation {
+    pub node_id: NodeId,
+    pub kind: AuditedNodeKind,
+    /// The function or method which created the node, or `None` if created by the root frame.
+    pub created_by: Option<FnIdentifier>,
+    /// The receiver of the method which created the node, if any.
+    pub receiver: Option<NodeId>,
+    pub instruction_index: usize,
+}
+
+/// Records the allocation site of every node created during a transaction, so that any node
+/// which is neither dropped nor persisted can be reported with its allocation backtrace.
+#[derive(Debug, Clone)]
+pub struct NodeAuditModule {
+    current_instruction_index: usize,
+    allocations: IndexMap<NodeId, NodeAllocation>,
+}
+
+impl NodeAuditModule {
+    pub fn new() -> Self {
+        Self {
+            current_instruction_index: 0,
+            allocations: index_map_new(),
+        }
+    }
+
+    pub fn update_instruction_index(&mut self, new_index: usize) {
+        self.current_instruction_index = new_index;
+    }
+
+    /// Returns the nodes which were neither dropped nor persisted, in allocation order.
+    ///
+    /// This must be called before the track changes are reverted, so that `is_persisted`
+    /// reflects the end state of the execution.
+    pub fn finalize<F: Fn(&NodeId) -> bool>(self, is_persisted: F) -> Vec<NodeAllocation> {
+        self.allocations
+            .into_values()
+            .filter(|allocation| !is_persisted(&allocation.node_id))
+            .collect()
+    }
+
+    fn handle_before_create_node(
+        &mut self,
+        current_actor: &Actor,
+        node_id: &NodeId,
+        node_substates: &NodeSubstates,
+    ) {
+        let kind = match node_substates
+            .get(&TYPE_INFO_FIELD_PARTITION)
+            .and_then(|partition| partition.get(&TypeInfoField::TypeInfo.into()))
+            .map(|value| value.as_typed::<TypeInfoSubstate>().unwrap())
+        {
+            Some(TypeInfoSubstate::Object(ObjectInfo { blueprint_id, .. })) => {
+                if blueprint_id.package_address.eq(&RESOURCE_PACKAGE) {
+                    match blueprint_id.blueprint_name.as_str() {
+                        FUNGIBLE_BUCKET_BLUEPRINT | NON_FUNGIBLE_BUCKET_BLUEPRINT => {
+                            AuditedNodeKind::Bucket
+                        }
+                        FUNGIBLE_PROOF_BLUEPRINT | NON_FUNGIBLE_PROOF_BLUEPRINT => {
+                            AuditedNodeKind::Proof
+                        }
+                        _ => AuditedNodeKind::Object(blueprint_id),
+                    }
+                } else {
+                    AuditedNodeKind::Object(blueprint_id)
+                }
+            }
+            _ => AuditedNodeKind::Other,
+        };
+
+        let (created_by, receiver) = match current_actor {
+            Actor::Root => (None, None),
+            Actor::Method(MethodActor { node_id, .. }) => {
+                (Some(current_actor.fn_identifier()), Some(node_id.clone()))
+            }
+            _ => (Some(current_actor.fn_identifier()), None),
+        };
+
+        self.allocations.insert(
+            node_id.clone(),
+            NodeAllocation {
+                node_id: node_id.clone(),
+                kind,
+                created_by,
+                receiver,
+                instruction_index: self.current_instruction_index,
+            },
+        );
+    }
+
+    fn handle_before_drop_node(&mut self, node_id: &NodeId) {
+        self.allocations.remove(node_id);
+    }
+}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for NodeAuditModule {
+    fn before_create_node<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        node_id: &NodeId,
+        node_substates: &NodeSubstates,
+    ) -> Result<(), RuntimeError> {
+        let system_state = api.kernel_get_system_state();
+        system_state
+            .system
+            .modules
+            .node_audit
+            .handle_before_create_node(system_state.current, node_id, node_substates);
+        Ok(())
+    }
+
+    fn before_drop_node<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        node_id: &NodeId,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system_state()
+            .system
+            .modules
+            .node_audit
+            .handle_before_drop_node(node_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+use crate::errors::*;
+use crate::kernel::actor::Actor;
+use crate::kernel::kernel_api::{KernelApi, KernelInvocation};
+use crate::system::module::SystemModule;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::track::interface::StoreAccessInfo;
+use crate::types::*;
+use radix_engine_interface::api::field_lock_api::LockFlags;
+
+//===================================================================================
+// Note: Profiling is for debugging only, and must not produce any error or
+// transactional side effect!
+//===================================================================================
+
+/// The wall-time profile of a transaction, in collapsed-stacks form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct ExecutionProfile {
+    /// The self time of each call stack in nanoseconds, keyed by the `;`-separated frame names.
+    pub stacks: IndexMap<String, u64>,
+    pub substate_reads: u64,
+    pub substate_read_bytes: u64,
+    pub substate_writes: u64,
+    pub substate_write_bytes: u64,
+}
+
+impl ExecutionProfile {
+    /// Returns the total profiled time in nanoseconds.
+    pub fn total_time(&self) -> u64 {
+        self.stacks.values().sum()
+    }
+
+    /// Renders the profile in the collapsed-stacks format, one `<stack> <nanos>` line per stack,
+    /// as consumed by flamegraph tools.
+    pub fn to_collapsed_stacks(&self) -> String {
+        let mut output = String::new();
+        for (stack, nanos) in &self.stacks {
+            output.push_str(&format!("{} {}\n", stack, nanos));
+        }
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileFrameKind {
+    Invocation,
+    Wasm,
+    SubstateIo,
+}
+
+#[derive(Debug, Clone)]
+struct ProfileFrame {
+    kind: ProfileFrameKind,
+    name: String,
+    start: u64,
+    children_time: u64,
+}
+
+/// Records the wall time spent in kernel invocations, WASM executions and substate opens.
+#[derive(Debug, Clone)]
+pub struct ProfilingModule {
+    #[cfg(not(feature = "alloc"))]
+    started_at: std::time::Instant,
+    frames: Vec<ProfileFrame>,
+    profile: ExecutionProfile,
+}
+
+impl ProfilingModule {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(not(feature = "alloc"))]
+            started_at: std::time::Instant::now(),
+            frames: Vec::new(),
+            profile: ExecutionProfile::default(),
+        }
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn now(&self) -> u64 {
+        self.started_at.elapsed().as_nanos() as u64
+    }
+
+    #[cfg(feature = "alloc")]
+    fn now(&self) -> u64 {
+        0
+    }
+
+    pub fn enter(&mut self, kind: ProfileFrameKind, name: String) {
+        let start = self.now();
+        self.frames.push(ProfileFrame {
+            kind,
+            name,
+            start,
+            children_time: 0,
+        });
+    }
+
+    /// Closes the innermost frame of the given kind, along with any frame above it which
+    /// was left open by a failed call.
+    pub fn exit(&mut self, kind: ProfileFrameKind) {
+        if !self.frames.iter().any(|frame| frame.kind == kind) {
+            return;
+        }
+        while let Some(frame) = self.pop_frame() {
+            if frame == kind {
+                break;
+            }
+        }
+    }
+
+    fn pop_frame(&mut self) -> Option<ProfileFrameKind> {
+        let now = self.now();
+        let stack = self
+            .frames
+            .iter()
+            .map(|frame| frame.name.as_str())
+            .collect::<Vec<&str>>()
+            .join(";");
+        let frame = self.frames.pop()?;
+
+        let elapsed = now.saturating_sub(frame.start);
+        *self.profile.stacks.entry(stack).or_default() +=
+            elapsed.saturating_sub(frame.children_time);
+        if let Some(parent) = self.frames.last_mut() {
+            parent.children_time += elapsed;
+        }
+
+        Some(frame.kind)
+    }
+
+    pub fn finalize(mut self) -> ExecutionProfile {
+        while self.pop_frame().is_some() {}
+        self.profile
+    }
+}
+
+fn invocation_frame_name(actor: &Actor) -> String {
+    match actor {
+        Actor::Root => "root".to_string(),
+        _ => {
+            let FnIdentifier {
+                blueprint_id,
+                ident,
+            } = actor.fn_identifier();
+            match ident {
+                FnIdent::Application(ident) => {
+                    format!("{}::{}", blueprint_id.blueprint_name, ident)
+                }
+                FnIdent::System(ident) => {
+                    format!("{}::<system {}>", blueprint_id.blueprint_name, ident)
+                }
+            }
+        }
+    }
+}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ProfilingModule {
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        invocation: &KernelInvocation,
+    ) -> Result<(), RuntimeError> {
+        let name = invocation_frame_name(&invocation.actor);
+        api.kernel_get_system()
+            .modules
+            .profiling
+            .enter(ProfileFrameKind::Invocation, name);
+        Ok(())
+    }
+
+    fn after_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _output_size: usize,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .profiling
+            .exit(ProfileFrameKind::Invocation);
+        Ok(())
+    }
+
+    fn before_open_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _node_id: &NodeId,
+        _partition_num: &PartitionNumber,
+        _offset: &SubstateKey,
+        _flags: &LockFlags,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .profiling
+            .enter(ProfileFrameKind::SubstateIo, "open_substate".to_string());
+        Ok(())
+    }
+
+    fn after_open_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _lock_handle: LockHandle,
+        _node_id: &NodeId,
+        _store_access: &StoreAccessInfo,
+        _size: usize,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .profiling
+            .exit(ProfileFrameKind::SubstateIo);
+        Ok(())
+    }
+
+    fn on_read_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _lock_handle: LockHandle,
+        value_size: usize,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        let profile = &mut api.kernel_get_system().modules.profiling.profile;
+        profile.substate_reads += 1;
+        profile.substate_read_bytes += value_size as u64;
+        Ok(())
+    }
+
+    fn on_write_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _lock_handle: LockHandle,
+        value_size: usize,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        let profile = &mut api.kernel_get_system().modules.profiling.profile;
+        profile.substate_writes += 1;
+        profile.substate_write_bytes += value_size as u64;
+        Ok(())
+    }
+
+    fn on_set_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _node_id: &NodeId,
+        _substate_key: &SubstateKey,
+        value_size: usize,
+        _store_access: &StoreAccessInfo,
+    ) -> Result<(), RuntimeError> {
+        let profile = &mut api.kernel_get_system().modules.profiling.profile;
+        profile.substate_writes += 1;
+        profile.substate_write_bytes += value_size as u64;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_time_is_attributed_to_the_innermost_stack() {
+        let mut module = ProfilingModule::new();
+        module.enter(ProfileFrameKind::Invocation, "A::a".to_string());
+        module.enter(ProfileFrameKind::Wasm, "wasm:a".to_string());
+        module.enter(ProfileFrameKind::Invocation, "B::b".to_string());
+        module.exit(ProfileFrameKind::Invocation);
+        // The WASM frame is left open, as if the execution failed
+        module.exit(ProfileFrameKind::Invocation);
+        let profile = module.finalize();
+
+        assert_eq!(
+            profile.stacks.keys().collect::<Vec<_>>(),
+            vec!["A::a;wasm:a;B::b", "A::a;wasm:a", "A::a"]
+        );
+        assert!(profile.to_collapsed_stacks().lines().all(|line| line
+            .rsplit_once(' ')
+            .unwrap()
+            .1
+            .parse::<u64>()
+            .is_ok()));
+    }
+}
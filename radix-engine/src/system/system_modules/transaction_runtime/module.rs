@@ -7,9 +7,15 @@ use radix_engine_interface::crypto::Hash;
 #[derive(Debug, Clone)]
 pub struct TransactionRuntimeModule {
     pub tx_hash: Hash,
+    /// Overrides the transaction hash as the seed of generated RUIDs, for deterministic tests and
+    /// previews.
+    pub ruid_seed: Option<Hash>,
     pub next_id: u32,
     pub logs: Vec<(Level, String)>,
+    pub metrics: Vec<(String, Decimal)>,
     pub events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+    /// The total payload size of `events`.
+    pub events_size: usize,
     pub replacements: IndexMap<(NodeId, ObjectModuleId), (NodeId, ObjectModuleId)>,
 }
 
@@ -20,7 +26,8 @@ impl TransactionRuntimeModule {
 
     pub fn generate_ruid(&mut self) -> [u8; 32] {
         let mut bytes = [0u8; 36];
-        (&mut bytes[..32]).copy_from_slice(self.tx_hash.as_slice());
+        let seed = self.ruid_seed.unwrap_or(self.tx_hash);
+        (&mut bytes[..32]).copy_from_slice(seed.as_slice());
         bytes[32..].copy_from_slice(&self.next_id.to_le_bytes());
 
         self.next_id += 1;
@@ -32,7 +39,12 @@ impl TransactionRuntimeModule {
         self.logs.push((level, message))
     }
 
+    pub fn add_metric(&mut self, name: String, value: Decimal) {
+        self.metrics.push((name, value))
+    }
+
     pub fn add_event(&mut self, identifier: EventTypeIdentifier, data: Vec<u8>) {
+        self.events_size += data.len();
         self.events.push((identifier, data))
     }
 
@@ -52,9 +64,13 @@ impl TransactionRuntimeModule {
     pub fn finalize(
         self,
         is_success: bool,
-    ) -> (Vec<(EventTypeIdentifier, Vec<u8>)>, Vec<(Level, String)>) {
+    ) -> (
+        Vec<(EventTypeIdentifier, Vec<u8>)>,
+        Vec<(Level, String)>,
+        Vec<(String, Decimal)>,
+    ) {
         if !is_success {
-            return (Vec::new(), self.logs);
+            return (Vec::new(), self.logs, self.metrics);
         }
 
         let mut events = self.events;
@@ -74,7 +90,7 @@ impl TransactionRuntimeModule {
             }
         }
 
-        (events, self.logs)
+        (events, self.logs, self.metrics)
     }
 }
 
@@ -91,9 +107,12 @@ mod tests {
                 "71f26aab5eec6679f67c71211aba9a3486cc8d24194d339385ee91ee5ca7b30d",
             )
             .unwrap(),
+            ruid_seed: None,
             next_id: 5,
             logs: Vec::new(),
+            metrics: Vec::new(),
             events: Vec::new(),
+            events_size: 0,
             replacements: index_map_new(),
         };
         assert_eq!(
@@ -103,9 +122,12 @@ mod tests {
 
         let mut id = TransactionRuntimeModule {
             tx_hash: Hash([0u8; 32]),
+            ruid_seed: None,
             next_id: 5,
             logs: Vec::new(),
+            metrics: Vec::new(),
             events: Vec::new(),
+            events_size: 0,
             replacements: index_map_new(),
         };
         assert_eq!(
@@ -115,9 +137,12 @@ mod tests {
 
         let mut id = TransactionRuntimeModule {
             tx_hash: Hash([255u8; 32]),
+            ruid_seed: None,
             next_id: 5,
             logs: Vec::new(),
+            metrics: Vec::new(),
             events: Vec::new(),
+            events_size: 0,
             replacements: index_map_new(),
         };
         assert_eq!(
@@ -71,6 +71,18 @@ pub trait SubstateStore {
         count: u32,
     ) -> (Vec<IndexedScryptoValue>, StoreAccessInfo);
 
+    /// Scans substates in the order of their database sort keys, starting after the substate
+    /// with the given key, so that large partitions can be read page by page.
+    ///
+    /// Returns tuple of substate vector and boolean which is true for the first database access.
+    fn scan_substates_after(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        cursor: Option<&SubstateKey>,
+        count: u32,
+    ) -> (Vec<IndexedScryptoValue>, StoreAccessInfo);
+
     /// Returns tuple of substate vector and boolean which is true for the first database access.
     fn take_substates(
         &mut self,
@@ -132,6 +144,21 @@ pub trait SubstateStore {
         substate_value: IndexedScryptoValue,
     ) -> StoreAccessInfo;
 
+    /// Takes a checkpoint of the tracked state, which can later be reverted to.
+    fn create_checkpoint(&mut self);
+
+    /// Reverts all changes made since the last checkpoint, except force writes, and discards it.
+    ///
+    /// # Panics
+    /// - If there is no checkpoint
+    fn revert_to_checkpoint(&mut self);
+
+    /// Discards the last checkpoint, keeping all changes made since.
+    ///
+    /// # Panics
+    /// - If there is no checkpoint
+    fn release_checkpoint(&mut self);
+
     /// Note: unstable interface, for intent transaction tracker only
     fn delete_partition(&mut self, node_id: &NodeId, partition_num: PartitionNumber);
 
@@ -46,3 +46,118 @@ fn overlaying_iterator_returns_upserted_values_when_no_underlying() {
     let overlying = OverlayingIterator::new(underlying, overlaid);
     assert_eq!(overlying.collect::<Vec<_>>(), vec![(0, "bee"), (5, "ant")]);
 }
+
+mod checkpoints {
+    use crate::track::interface::SubstateStore;
+    use crate::track::Track;
+    use crate::types::*;
+    use radix_engine_interface::api::field_lock_api::LockFlags;
+    use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+    use radix_engine_store_interface::interface::{
+        DbPartitionKey, DbSortKey, DbSubstateValue, PartitionEntry, SubstateDatabase,
+    };
+
+    struct EmptySubstateDatabase;
+
+    impl SubstateDatabase for EmptySubstateDatabase {
+        fn get_substate(
+            &self,
+            _partition_key: &DbPartitionKey,
+            _sort_key: &DbSortKey,
+        ) -> Option<DbSubstateValue> {
+            None
+        }
+
+        fn list_entries(
+            &self,
+            _partition_key: &DbPartitionKey,
+        ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+            Box::new(core::iter::empty())
+        }
+    }
+
+    const NODE_ID: NodeId = NodeId([1u8; NodeId::LENGTH]);
+
+    fn new_track(
+        db: &EmptySubstateDatabase,
+    ) -> Track<EmptySubstateDatabase, SpreadPrefixKeyMapper> {
+        let mut track = Track::new(db);
+        track.create_node(
+            NODE_ID,
+            btreemap!(
+                MAIN_BASE_PARTITION => btreemap!(
+                    SubstateKey::Field(0u8) => IndexedScryptoValue::from_typed(&0u32)
+                )
+            ),
+        );
+        track
+    }
+
+    fn write(
+        track: &mut Track<EmptySubstateDatabase, SpreadPrefixKeyMapper>,
+        value: u32,
+        flags: LockFlags,
+    ) {
+        let (handle, _) = track
+            .acquire_lock(
+                &NODE_ID,
+                MAIN_BASE_PARTITION,
+                &SubstateKey::Field(0u8),
+                flags,
+            )
+            .unwrap();
+        track.update_substate(handle, IndexedScryptoValue::from_typed(&value));
+        track.close_substate(handle);
+    }
+
+    fn read(track: &mut Track<EmptySubstateDatabase, SpreadPrefixKeyMapper>) -> u32 {
+        let (handle, _) = track
+            .acquire_lock(
+                &NODE_ID,
+                MAIN_BASE_PARTITION,
+                &SubstateKey::Field(0u8),
+                LockFlags::read_only(),
+            )
+            .unwrap();
+        let value = track.read_substate(handle).0.as_typed().unwrap();
+        track.close_substate(handle);
+        value
+    }
+
+    #[test]
+    fn revert_to_checkpoint_reverts_writes_made_since() {
+        let db = EmptySubstateDatabase;
+        let mut track = new_track(&db);
+        write(&mut track, 1, LockFlags::MUTABLE);
+        track.create_checkpoint();
+        write(&mut track, 2, LockFlags::MUTABLE);
+
+        track.revert_to_checkpoint();
+
+        assert_eq!(read(&mut track), 1);
+    }
+
+    #[test]
+    fn revert_to_checkpoint_keeps_force_writes() {
+        let db = EmptySubstateDatabase;
+        let mut track = new_track(&db);
+        track.create_checkpoint();
+        write(&mut track, 2, LockFlags::MUTABLE | LockFlags::FORCE_WRITE);
+
+        track.revert_to_checkpoint();
+
+        assert_eq!(read(&mut track), 2);
+    }
+
+    #[test]
+    fn release_checkpoint_keeps_writes_made_since() {
+        let db = EmptySubstateDatabase;
+        let mut track = new_track(&db);
+        track.create_checkpoint();
+        write(&mut track, 2, LockFlags::MUTABLE);
+
+        track.release_checkpoint();
+
+        assert_eq!(read(&mut track), 2);
+    }
+}
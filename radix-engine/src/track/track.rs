@@ -256,7 +256,7 @@ impl TrackedSubstateValue {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct TrackedPartition {
     pub substates: BTreeMap<DbSortKey, TrackedSubstate>,
     pub range_read: u32,
@@ -284,7 +284,7 @@ impl TrackedPartition {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct TrackedNode {
     pub tracked_partitions: IndexMap<PartitionNumber, TrackedPartition>,
     // If true, then all SubstateUpdates under this NodeUpdate must be inserts
@@ -391,9 +391,17 @@ pub struct Track<'s, S: SubstateDatabase, M: DatabaseKeyMapper> {
 
     locks: IndexMap<u32, (NodeId, PartitionNumber, SubstateKey, LockFlags)>,
     next_lock_id: u32,
+    checkpoints: Vec<TrackCheckpoint>,
     phantom_data: PhantomData<M>,
 }
 
+/// The tracked state at a checkpoint, see [`SubstateStore::create_checkpoint`].
+struct TrackCheckpoint {
+    tracked_nodes: IndexMap<NodeId, TrackedNode>,
+    deleted_partitions: IndexSet<(NodeId, PartitionNumber)>,
+    locks: IndexMap<u32, (NodeId, PartitionNumber, SubstateKey, LockFlags)>,
+}
+
 impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
     pub fn new(substate_db: &'s S) -> Self {
         Self {
@@ -403,6 +411,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
             deleted_partitions: index_set_new(),
             locks: index_map_new(),
             next_lock_id: 0,
+            checkpoints: Vec::new(),
             phantom_data: PhantomData::default(),
         }
     }
@@ -427,6 +436,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
     fn list_entries_from_db<'x>(
         substate_db: &'x S,
         partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
         store_access: &'x mut StoreAccessInfo,
     ) -> Box<dyn Iterator<Item = (DbSortKey, IndexedScryptoValue)> + 'x> {
         struct TracedIterator<'a, 'b> {
@@ -452,8 +462,13 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
             }
         }
 
+        let iterator = match from_sort_key {
+            Some(from_sort_key) => substate_db.list_entries_from(partition_key, from_sort_key),
+            None => substate_db.list_entries(partition_key),
+        };
+
         Box::new(TracedIterator {
-            iterator: substate_db.list_entries(partition_key),
+            iterator,
             store_access,
         })
     }
@@ -477,6 +492,14 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
     /// Reverts all non force write changes.
     ///
     /// Note that dependencies will never be reverted.
+    /// Returns whether the node has been created, and not yet reverted, in this store.
+    pub fn is_new_node(&self, node_id: &NodeId) -> bool {
+        self.tracked_nodes
+            .get(node_id)
+            .map(|tracked_node| tracked_node.is_new)
+            .unwrap_or(false)
+    }
+
     pub fn revert_non_force_write_changes(&mut self) {
         self.tracked_nodes
             .retain(|_, tracked_node| !tracked_node.is_new);
@@ -794,6 +817,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         let mut tracked_iter = TrackedIter::new(Self::list_entries_from_db(
             self.substate_db,
             &db_partition_key,
+            None,
             &mut store_access,
         ));
         for (db_sort_key, value) in &mut tracked_iter {
@@ -820,6 +844,65 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         (items, store_access)
     }
 
+    fn scan_substates_after(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        cursor: Option<&SubstateKey>,
+        count: u32,
+    ) -> (Vec<IndexedScryptoValue>, StoreAccessInfo) {
+        let mut store_access = Vec::new();
+
+        let count: usize = count.try_into().unwrap();
+        let cursor = cursor.map(M::to_db_sort_key);
+        let is_after_cursor = |db_sort_key: &DbSortKey| match &cursor {
+            Some(cursor) => db_sort_key > cursor,
+            None => true,
+        };
+
+        let node_updates = self.tracked_nodes.get(node_id);
+        let is_new = node_updates
+            .map(|tracked_node| tracked_node.is_new)
+            .unwrap_or(false);
+        let tracked_partition = node_updates.and_then(|n| n.tracked_partitions.get(&partition_num));
+
+        // The tracked substates are kept in the order they were first accessed, so need sorting
+        // before they can be overlaid over the database entries
+        let mut tracked_entry_changes: Vec<(DbSortKey, Option<IndexedScryptoValue>)> =
+            tracked_partition
+                .into_iter()
+                .flat_map(|tracked_partition| tracked_partition.substates.iter())
+                .filter(|(db_sort_key, _)| is_after_cursor(db_sort_key))
+                .map(|(db_sort_key, tracked)| {
+                    (db_sort_key.clone(), tracked.substate_value.get().cloned())
+                })
+                .collect();
+        tracked_entry_changes.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        // Optimization, no need to go into database if the node is just created
+        let db_entries: Box<dyn Iterator<Item = (DbSortKey, IndexedScryptoValue)>> = if is_new {
+            Box::new(empty())
+        } else {
+            let db_partition_key = M::to_db_partition_key(node_id, partition_num);
+            Box::new(
+                Self::list_entries_from_db(
+                    self.substate_db,
+                    &db_partition_key,
+                    cursor.as_ref(),
+                    &mut store_access,
+                )
+                .skip_while(|(db_sort_key, _)| !is_after_cursor(db_sort_key)),
+            )
+        };
+
+        let items = OverlayingIterator::new(db_entries, tracked_entry_changes.into_iter())
+            .map(|(_key, value)| value)
+            .take(count)
+            .collect();
+
+        (items, store_access)
+    }
+
     fn take_substates(
         &mut self,
         node_id: &NodeId,
@@ -863,6 +946,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         let mut tracked_iter = TrackedIter::new(Self::list_entries_from_db(
             self.substate_db,
             &db_partition_key,
+            None,
             &mut store_access,
         ));
         let new_updates = {
@@ -947,6 +1031,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
                 Box::new(Self::list_entries_from_db(
                     self.substate_db,
                     &partition_key,
+                    None,
                     &mut store_access,
                 ))
             };
@@ -1168,6 +1253,63 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         store_access
     }
 
+    fn create_checkpoint(&mut self) {
+        self.checkpoints.push(TrackCheckpoint {
+            tracked_nodes: self.tracked_nodes.clone(),
+            deleted_partitions: self.deleted_partitions.clone(),
+            locks: self.locks.clone(),
+        });
+    }
+
+    fn revert_to_checkpoint(&mut self) {
+        let checkpoint = self.checkpoints.pop().expect("No checkpoint to revert to");
+        self.tracked_nodes = checkpoint.tracked_nodes;
+        self.deleted_partitions = checkpoint.deleted_partitions;
+        self.locks = checkpoint.locks;
+
+        // Re-apply the force writes, which may have been made after the checkpoint
+        for (node_id, force_track_node) in &self.force_write_tracked_nodes {
+            for (partition_num, force_track_partition) in &force_track_node.tracked_partitions {
+                for (db_sort_key, force_track_key) in &force_track_partition.substates {
+                    let tracked_substates = &mut self
+                        .tracked_nodes
+                        .entry(*node_id)
+                        .or_insert(TrackedNode::new(false))
+                        .tracked_partitions
+                        .entry(*partition_num)
+                        .or_insert(TrackedPartition::new())
+                        .substates;
+                    match tracked_substates.entry(db_sort_key.clone()) {
+                        Entry::Occupied(mut entry) => {
+                            let tracked = &mut entry.get_mut().substate_value;
+                            let lock_state = tracked
+                                .get_runtime_substate_mut()
+                                .map(|substate| substate.lock_state)
+                                .unwrap_or(SubstateLockState::no_lock());
+                            *tracked = force_track_key.substate_value.clone();
+                            if let Some(substate) = tracked.get_runtime_substate_mut() {
+                                substate.lock_state = lock_state;
+                            }
+                        }
+                        Entry::Vacant(entry) => {
+                            let mut tracked = force_track_key.clone();
+                            if let Some(substate) =
+                                tracked.substate_value.get_runtime_substate_mut()
+                            {
+                                substate.lock_state = SubstateLockState::no_lock();
+                            }
+                            entry.insert(tracked);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn release_checkpoint(&mut self) {
+        self.checkpoints.pop().expect("No checkpoint to release");
+    }
+
     fn delete_partition(&mut self, node_id: &NodeId, partition_num: PartitionNumber) {
         // This is used for transaction tracker only, for which we don't account for store access.
 
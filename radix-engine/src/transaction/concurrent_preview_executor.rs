@@ -0,0 +1,193 @@
+use crate::track::utils::OverlayingIterator;
+use crate::transaction::*;
+use crate::types::*;
+use crate::vm::wasm::WasmEngine;
+use crate::vm::ScryptoVm;
+use radix_engine_store_interface::interface::*;
+use sbor::rust::sync::Arc;
+use transaction::model::PreviewIntentV1;
+
+/// A substate database which stages updates in memory on top of a read-only substate database,
+/// without ever writing them to it.
+///
+/// Reads are served from the staged updates first, falling back to the underlying database.
+pub struct StagedSubstateDatabase<'s, S: SubstateDatabase> {
+    substate_db: &'s S,
+    staged_updates: DatabaseUpdates,
+}
+
+impl<'s, S: SubstateDatabase> StagedSubstateDatabase<'s, S> {
+    pub fn new(substate_db: &'s S) -> Self {
+        Self {
+            substate_db,
+            staged_updates: index_map_new(),
+        }
+    }
+
+    pub fn staged_updates(&self) -> &DatabaseUpdates {
+        &self.staged_updates
+    }
+
+    pub fn into_staged_updates(self) -> DatabaseUpdates {
+        self.staged_updates
+    }
+}
+
+impl<'s, S: SubstateDatabase> SubstateDatabase for StagedSubstateDatabase<'s, S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        let staged_update = self
+            .staged_updates
+            .get(partition_key)
+            .and_then(|partition_updates| partition_updates.get(sort_key));
+        match staged_update {
+            Some(DatabaseUpdate::Set(value)) => Some(value.clone()),
+            Some(DatabaseUpdate::Delete) => None,
+            None => self.substate_db.get_substate(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let underlying = self.substate_db.list_entries(partition_key);
+        let Some(partition_updates) = self.staged_updates.get(partition_key) else {
+            return underlying;
+        };
+        // The overlaid changes must be ordered the same way as the underlying entries
+        let mut overlaid = partition_updates
+            .iter()
+            .map(|(sort_key, update)| {
+                let value = match update {
+                    DatabaseUpdate::Set(value) => Some(value.clone()),
+                    DatabaseUpdate::Delete => None,
+                };
+                (sort_key.clone(), value)
+            })
+            .collect::<Vec<_>>();
+        overlaid.sort_by(|(left, _), (right, _)| left.cmp(right));
+        Box::new(OverlayingIterator::new(underlying, overlaid.into_iter()))
+    }
+}
+
+impl<'s, S: SubstateDatabase> CommittableSubstateDatabase for StagedSubstateDatabase<'s, S> {
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        for (partition_key, partition_updates) in database_updates {
+            let staged_partition_updates = self
+                .staged_updates
+                .entry(partition_key.clone())
+                .or_insert_with(index_map_new);
+            for (sort_key, update) in partition_updates {
+                staged_partition_updates.insert(sort_key.clone(), update.clone());
+            }
+        }
+    }
+}
+
+/// A transaction preview facade which can be shared between threads, e.g. by a preview server.
+///
+/// All previews read the same substate database, which is never written to. The state changes
+/// of each preview are staged in its own [`StagedSubstateDatabase`], so that concurrent previews
+/// don't observe each other, and the database doesn't need to be cloned for each request.
+///
+/// The facade is `Send + Sync` as long as the database and the WASM engine are; for the
+/// [`WasmiEngine`](crate::vm::wasm::WasmiEngine), this requires the `moka` feature.
+pub struct ConcurrentPreviewExecutor<S: SubstateDatabase, W: WasmEngine> {
+    substate_db: Arc<S>,
+    scrypto_interpreter: Arc<ScryptoVm<W>>,
+    network: NetworkDefinition,
+}
+
+impl<S: SubstateDatabase, W: WasmEngine> Clone for ConcurrentPreviewExecutor<S, W> {
+    fn clone(&self) -> Self {
+        Self {
+            substate_db: self.substate_db.clone(),
+            scrypto_interpreter: self.scrypto_interpreter.clone(),
+            network: self.network.clone(),
+        }
+    }
+}
+
+impl<S: SubstateDatabase, W: WasmEngine> ConcurrentPreviewExecutor<S, W> {
+    pub fn new(
+        substate_db: Arc<S>,
+        scrypto_interpreter: Arc<ScryptoVm<W>>,
+        network: NetworkDefinition,
+    ) -> Self {
+        Self {
+            substate_db,
+            scrypto_interpreter,
+            network,
+        }
+    }
+
+    pub fn substate_db(&self) -> &S {
+        &self.substate_db
+    }
+
+    /// Previews a transaction against the shared state.
+    pub fn preview(
+        &self,
+        preview_intent: PreviewIntentV1,
+        with_kernel_trace: bool,
+    ) -> Result<TransactionReceipt, PreviewError> {
+        execute_preview(
+            self.substate_db.as_ref(),
+            &self.scrypto_interpreter,
+            &self.network,
+            preview_intent,
+            with_kernel_trace,
+        )
+    }
+
+    /// Starts a session in which every committed preview is staged on top of the shared state,
+    /// so that it is observed by the following previews of the same session only.
+    pub fn session(&self) -> PreviewSession<'_, S, W> {
+        PreviewSession {
+            executor: self,
+            staged_db: StagedSubstateDatabase::new(self.substate_db.as_ref()),
+        }
+    }
+}
+
+/// A sequence of previews against the shared state of a [`ConcurrentPreviewExecutor`], each
+/// observing the state changes of the previous ones.
+pub struct PreviewSession<'e, S: SubstateDatabase, W: WasmEngine> {
+    executor: &'e ConcurrentPreviewExecutor<S, W>,
+    staged_db: StagedSubstateDatabase<'e, S>,
+}
+
+impl<'e, S: SubstateDatabase, W: WasmEngine> PreviewSession<'e, S, W> {
+    /// Previews a transaction, staging its state changes if it is committed.
+    pub fn preview(
+        &mut self,
+        preview_intent: PreviewIntentV1,
+        with_kernel_trace: bool,
+    ) -> Result<TransactionReceipt, PreviewError> {
+        let receipt = execute_preview(
+            &self.staged_db,
+            &self.executor.scrypto_interpreter,
+            &self.executor.network,
+            preview_intent,
+            with_kernel_trace,
+        )?;
+        if let TransactionResult::Commit(commit) = &receipt.transaction_result {
+            self.staged_db
+                .commit(&commit.state_updates.database_updates);
+        }
+        Ok(receipt)
+    }
+
+    /// Stages arbitrary updates, e.g. to preview against a pending, not yet committed state.
+    pub fn stage(&mut self, database_updates: &DatabaseUpdates) {
+        self.staged_db.commit(database_updates);
+    }
+
+    pub fn staged_updates(&self) -> &DatabaseUpdates {
+        self.staged_db.staged_updates()
+    }
+}
@@ -1,9 +1,13 @@
+mod concurrent_preview_executor;
 mod preview_executor;
+mod receipt_diff;
 mod state_update_summary;
 mod transaction_executor;
 mod transaction_receipt;
 
+pub use concurrent_preview_executor::*;
 pub use preview_executor::*;
+pub use receipt_diff::*;
 pub use state_update_summary::*;
 pub use transaction_executor::*;
 pub use transaction_receipt::*;
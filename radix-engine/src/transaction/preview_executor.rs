@@ -12,6 +12,7 @@ use transaction::validation::ValidationConfig;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PreviewError {
     TransactionValidationError(TransactionValidationError),
+    StateVersionError(StateVersionError),
 }
 
 pub fn execute_preview<S: SubstateDatabase, W: WasmEngine>(
@@ -22,6 +23,7 @@ pub fn execute_preview<S: SubstateDatabase, W: WasmEngine>(
     with_kernel_trace: bool,
 ) -> Result<TransactionReceipt, PreviewError> {
     let validation_config = ValidationConfig::default(network.id);
+    let ruid_seed = preview_intent.flags.ruid_seed;
 
     let validator = NotarizedTransactionValidator::new(validation_config);
 
@@ -33,7 +35,31 @@ pub fn execute_preview<S: SubstateDatabase, W: WasmEngine>(
         substate_db,
         scrypto_interpreter,
         &FeeReserveConfig::default(),
-        &ExecutionConfig::for_preview().with_kernel_trace(with_kernel_trace),
+        &ExecutionConfig::for_preview()
+            .with_kernel_trace(with_kernel_trace)
+            .with_ruid_seed(ruid_seed),
         &validated.get_executable(),
     ))
 }
+
+/// Previews a transaction against the state as it was at a past state version, e.g. to reproduce
+/// an issue reported against that state.
+pub fn execute_preview_at_version<S: VersionedSubstateDatabase, W: WasmEngine>(
+    substate_db: &S,
+    state_version: u64,
+    scrypto_interpreter: &ScryptoVm<W>,
+    network: &NetworkDefinition,
+    preview_intent: PreviewIntentV1,
+    with_kernel_trace: bool,
+) -> Result<TransactionReceipt, PreviewError> {
+    let substate_db = SubstateDatabaseAtVersion::new(substate_db, state_version)
+        .map_err(PreviewError::StateVersionError)?;
+
+    execute_preview(
+        &substate_db,
+        scrypto_interpreter,
+        network,
+        preview_intent,
+        with_kernel_trace,
+    )
+}
@@ -0,0 +1,298 @@
+use super::{BalanceChange, TransactionOutcome, TransactionReceipt, TransactionResult};
+use crate::types::*;
+use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
+use sbor::representations::*;
+
+/// The fields of a [`ReceiptSnapshot`] which hold costs, and which are compared within the
+/// tolerance of [`ReceiptDiffOptions::cost_tolerance`] rather than exactly.
+const COST_FIELDS: [&'static str; 1] = ["cost_units_consumed"];
+
+/// Options controlling how two receipts are compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptDiffOptions {
+    /// The relative difference in costs which is still considered equal, e.g. `0.05` allows the
+    /// costs of the two receipts to be up to 5% apart.
+    pub cost_tolerance: Decimal,
+}
+
+impl Default for ReceiptDiffOptions {
+    fn default() -> Self {
+        Self {
+            cost_tolerance: dec!("0.05"),
+        }
+    }
+}
+
+/// A human-readable and normalized view of a [`TransactionReceipt`].
+///
+/// Fields which are volatile across otherwise identical executions, such as hashes and the exact
+/// fee breakdown, are left out. Each entry is a `(field, value)` pair and the snapshot is rendered
+/// as one `field: value` line per entry, which makes it suitable for storing in golden files.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReceiptSnapshot {
+    pub entries: Vec<(String, String)>,
+}
+
+impl ReceiptSnapshot {
+    pub fn from_receipt(receipt: &TransactionReceipt) -> Self {
+        let mut entries = Vec::new();
+        let mut push = |field: String, value: String| entries.push((field, value));
+
+        match &receipt.transaction_result {
+            TransactionResult::Commit(c) => {
+                match &c.outcome {
+                    TransactionOutcome::Success(_) => {
+                        push("status".to_string(), "COMMITTED SUCCESS".to_string())
+                    }
+                    TransactionOutcome::Failure(e) => {
+                        push("status".to_string(), "COMMITTED FAILURE".to_string());
+                        push("error".to_string(), format!("{:?}", e));
+                    }
+                }
+                push(
+                    "cost_units_consumed".to_string(),
+                    c.fee_summary.execution_cost_sum.to_string(),
+                );
+                for (i, (level, message)) in c.application_logs.iter().enumerate() {
+                    push(format!("log[{}]", i), format!("[{:?}] {}", level, message));
+                }
+                for (i, (event_type_identifier, event_data)) in
+                    c.application_events.iter().enumerate()
+                {
+                    push(
+                        format!("event[{}]", i),
+                        format!("{:?} {}", event_type_identifier, hex::encode(event_data)),
+                    );
+                }
+                if let TransactionOutcome::Success(outputs) = &c.outcome {
+                    for (i, output) in outputs.iter().enumerate() {
+                        let value = match output {
+                            InstructionOutput::CallReturn(x) => IndexedScryptoValue::from_slice(x)
+                                .expect("Impossible case! Instruction output can't be decoded")
+                                .to_string(ValueDisplayParameters::Schemaless {
+                                    display_mode: DisplayMode::RustLike,
+                                    print_mode: PrintMode::SingleLine,
+                                    custom_context: Default::default(),
+                                }),
+                            InstructionOutput::None => "None".to_string(),
+                            InstructionOutput::RolledBack {
+                                failed_instruction_index,
+                                error,
+                            } => format!("RolledBack({}, {})", failed_instruction_index, error),
+                        };
+                        push(format!("output[{}]", i), value);
+                    }
+                }
+                let mut i = 0;
+                for (address, map) in c.balance_changes() {
+                    for (resource, delta) in map {
+                        let delta = match delta {
+                            BalanceChange::Fungible(d) => format!("{}", d),
+                            BalanceChange::NonFungible { added, removed } => {
+                                format!("+{:?}, -{:?}", added, removed)
+                            }
+                        };
+                        push(
+                            format!("balance_change[{}]", i),
+                            format!("{:?} {:?} {}", address, resource, delta),
+                        );
+                        i += 1;
+                    }
+                }
+            }
+            TransactionResult::Reject(r) => {
+                push("status".to_string(), "REJECTED".to_string());
+                push("error".to_string(), format!("{:?}", r.error));
+            }
+            TransactionResult::Abort(a) => {
+                push("status".to_string(), "ABORTED".to_string());
+                push("error".to_string(), format!("{:?}", a.reason));
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Parses a snapshot previously rendered with [`ToString`], returning `None` on a malformed
+    /// line.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut entries = Vec::new();
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let (field, value) = line.split_once(": ")?;
+            entries.push((field.to_string(), unescape(value)?));
+        }
+        Some(Self { entries })
+    }
+
+    pub fn diff(&self, other: &Self, options: &ReceiptDiffOptions) -> ReceiptDiff {
+        let this = self.entries.iter().cloned().collect::<IndexMap<_, _>>();
+        let mut other = other.entries.iter().cloned().collect::<IndexMap<_, _>>();
+
+        let mut differences = Vec::new();
+        for (field, expected) in this {
+            let actual = other.shift_remove(&field);
+            let matches = match &actual {
+                Some(actual) if COST_FIELDS.contains(&field.as_str()) => {
+                    costs_within_tolerance(&expected, actual, options.cost_tolerance)
+                }
+                Some(actual) => expected == *actual,
+                None => false,
+            };
+            if !matches {
+                differences.push(ReceiptDifference {
+                    field,
+                    expected: Some(expected),
+                    actual,
+                });
+            }
+        }
+        for (field, actual) in other {
+            differences.push(ReceiptDifference {
+                field,
+                expected: None,
+                actual: Some(actual),
+            });
+        }
+
+        ReceiptDiff { differences }
+    }
+}
+
+impl fmt::Display for ReceiptSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (field, value) in &self.entries {
+            writeln!(f, "{}: {}", field, escape(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// A single field which differs between two receipts; a side is `None` if the field is absent
+/// from that receipt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptDifference {
+    pub field: String,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+/// The differences between two receipts, as returned by [`TransactionReceipt::diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReceiptDiff {
+    pub differences: Vec<ReceiptDifference>,
+}
+
+impl ReceiptDiff {
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl fmt::Display for ReceiptDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ReceiptDifference {
+            field,
+            expected,
+            actual,
+        } in &self.differences
+        {
+            if let Some(expected) = expected {
+                writeln!(f, "- {}: {}", field, escape(expected))?;
+            }
+            if let Some(actual) = actual {
+                writeln!(f, "+ {}: {}", field, escape(actual))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TransactionReceipt {
+    /// Compares this receipt against another one, ignoring volatile fields and allowing costs to
+    /// differ within the default tolerance. This receipt is considered the expected one.
+    pub fn diff(&self, other: &TransactionReceipt) -> ReceiptDiff {
+        self.diff_with_options(other, &ReceiptDiffOptions::default())
+    }
+
+    pub fn diff_with_options(
+        &self,
+        other: &TransactionReceipt,
+        options: &ReceiptDiffOptions,
+    ) -> ReceiptDiff {
+        ReceiptSnapshot::from_receipt(self).diff(&ReceiptSnapshot::from_receipt(other), options)
+    }
+}
+
+fn costs_within_tolerance(expected: &str, actual: &str, tolerance: Decimal) -> bool {
+    match (expected.parse::<u32>(), actual.parse::<u32>()) {
+        (Ok(expected), Ok(actual)) => {
+            Decimal::from(expected.abs_diff(actual)) <= Decimal::from(expected) * tolerance
+        }
+        _ => expected == actual,
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(value: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next()? {
+                '\\' => unescaped.push('\\'),
+                'n' => unescaped.push('\n'),
+                _ => return None,
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_round_trips_through_text() {
+        let snapshot = ReceiptSnapshot {
+            entries: vec![
+                ("status".to_string(), "COMMITTED SUCCESS".to_string()),
+                ("log[0]".to_string(), "[Info] a\\b\nc: d".to_string()),
+            ],
+        };
+
+        assert_eq!(
+            ReceiptSnapshot::parse(&snapshot.to_string()),
+            Some(snapshot)
+        );
+    }
+
+    #[test]
+    fn costs_are_compared_within_tolerance() {
+        let expected = ReceiptSnapshot {
+            entries: vec![("cost_units_consumed".to_string(), "1000".to_string())],
+        };
+        let within = ReceiptSnapshot {
+            entries: vec![("cost_units_consumed".to_string(), "1040".to_string())],
+        };
+        let outside = ReceiptSnapshot {
+            entries: vec![("cost_units_consumed".to_string(), "1060".to_string())],
+        };
+
+        let options = ReceiptDiffOptions::default();
+        assert!(expected.diff(&within, &options).is_empty());
+        assert_eq!(
+            expected.diff(&outside, &options).differences,
+            vec![ReceiptDifference {
+                field: "cost_units_consumed".to_string(),
+                expected: Some("1000".to_string()),
+                actual: Some("1060".to_string()),
+            }]
+        );
+    }
+}
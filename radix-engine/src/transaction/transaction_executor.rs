@@ -1,13 +1,19 @@
-use crate::blueprints::consensus_manager::{ConsensusManagerSubstate, ValidatorRewardsSubstate};
+use crate::blueprints::consensus_manager::{
+    ConsensusManagerConfigSubstate, ConsensusManagerSubstate, ValidatorRewardsSubstate,
+};
 use crate::blueprints::transaction_processor::TransactionProcessorError;
 use crate::blueprints::transaction_tracker::{TransactionStatus, TransactionTrackerSubstate};
 use crate::errors::*;
 use crate::kernel::id_allocator::IdAllocator;
 use crate::kernel::kernel::KernelBoot;
+use crate::system::blueprint_mocks::BlueprintMocks;
 use crate::system::system::{KeyValueEntrySubstate, SubstateMutability};
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_modules::costing::*;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
+use crate::system::system_modules::limits::LimitsModule;
+use crate::system::system_modules::node_audit::NodeAuditModule;
+use crate::system::system_modules::profiling::ProfilingModule;
 use crate::system::system_modules::transaction_runtime::TransactionRuntimeModule;
 use crate::system::system_modules::{EnabledModules, SystemModuleMixer};
 use crate::track::interface::SubstateStore;
@@ -26,9 +32,13 @@ use transaction::model::*;
 #[derive(Debug, Clone)]
 pub struct FeeReserveConfig {
     pub cost_unit_price: Decimal,
+    /// The USD price in XRD, used only when the consensus manager is not yet available, i.e. during
+    /// genesis; otherwise the protocol-level price of the consensus manager config applies.
     pub usd_price: Decimal,
     pub state_expansion_price: Decimal,
     pub system_loan: u32,
+    /// The multiplier applied to the costs of cryptographic operations, in percentage.
+    pub crypto_cost_multiplier_percentage: u32,
 }
 
 impl Default for FeeReserveConfig {
@@ -38,6 +48,7 @@ impl Default for FeeReserveConfig {
             usd_price: DEFAULT_USD_PRICE_IN_XRD.try_into().unwrap(),
             state_expansion_price: DEFAULT_STATE_EXPANSION_PRICE_IN_XRD.try_into().unwrap(),
             system_loan: DEFAULT_SYSTEM_LOAN,
+            crypto_cost_multiplier_percentage: DEFAULT_CRYPTO_COST_MULTIPLIER_PERCENTAGE,
         }
     }
 }
@@ -54,12 +65,26 @@ pub struct ExecutionConfig {
     pub max_substate_size: usize,
     pub max_invoke_input_size: usize,
     pub enable_cost_breakdown: bool,
-    pub max_event_size: usize,
     pub max_log_size: usize,
     pub max_panic_message_size: usize,
     pub max_number_of_logs: usize,
-    pub max_number_of_events: usize,
+    pub max_event_count: usize,
+    pub max_event_payload_size: usize,
+    pub max_total_event_size: usize,
+    pub max_metric_name_size: usize,
+    pub max_number_of_metrics: usize,
+    pub max_number_of_substate_reads: usize,
+    pub max_number_of_substate_writes: usize,
+    pub max_total_substate_read_size: usize,
+    pub max_total_substate_write_size: usize,
+    pub max_heap_nodes_per_call_frame: usize,
+    pub max_heap_substate_size_per_call_frame: usize,
+    pub max_wasm_invocations: usize,
     pub max_per_function_royalty_in_xrd: Decimal,
+    /// Optional, for tests and previews only. Seeds RUID generation instead of the transaction hash.
+    pub ruid_seed: Option<Hash>,
+    /// For tests only. Handlers which intercept the calls to some blueprints.
+    pub blueprint_mocks: BlueprintMocks,
 }
 
 impl ExecutionConfig {
@@ -77,15 +102,27 @@ impl ExecutionConfig {
             max_substate_size: DEFAULT_MAX_SUBSTATE_SIZE,
             max_invoke_input_size: DEFAULT_MAX_INVOKE_INPUT_SIZE,
             enable_cost_breakdown: false,
-            max_event_size: DEFAULT_MAX_EVENT_SIZE,
             max_log_size: DEFAULT_MAX_LOG_SIZE,
             max_panic_message_size: DEFAULT_MAX_PANIC_MESSAGE_SIZE,
             max_number_of_logs: DEFAULT_MAX_NUMBER_OF_LOGS,
-            max_number_of_events: DEFAULT_MAX_NUMBER_OF_EVENTS,
+            max_event_count: DEFAULT_MAX_NUMBER_OF_EVENTS,
+            max_event_payload_size: DEFAULT_MAX_EVENT_SIZE,
+            max_total_event_size: DEFAULT_MAX_TOTAL_EVENT_SIZE,
+            max_metric_name_size: DEFAULT_MAX_METRIC_NAME_SIZE,
+            max_number_of_metrics: DEFAULT_MAX_NUMBER_OF_METRICS,
+            max_number_of_substate_reads: DEFAULT_MAX_NUMBER_OF_SUBSTATE_READS,
+            max_number_of_substate_writes: DEFAULT_MAX_NUMBER_OF_SUBSTATE_WRITES,
+            max_total_substate_read_size: DEFAULT_MAX_TOTAL_SUBSTATE_READ_SIZE,
+            max_total_substate_write_size: DEFAULT_MAX_TOTAL_SUBSTATE_WRITE_SIZE,
+            max_heap_nodes_per_call_frame: DEFAULT_MAX_HEAP_NODES_PER_CALL_FRAME,
+            max_heap_substate_size_per_call_frame: DEFAULT_MAX_HEAP_SUBSTATE_SIZE_PER_CALL_FRAME,
+            max_wasm_invocations: DEFAULT_MAX_WASM_INVOCATIONS,
             max_per_function_royalty_in_xrd: Decimal::try_from(
                 DEFAULT_MAX_PER_FUNCTION_ROYALTY_IN_XRD,
             )
             .unwrap(),
+            ruid_seed: None,
+            blueprint_mocks: BlueprintMocks::default(),
         }
     }
 
@@ -94,7 +131,10 @@ impl ExecutionConfig {
             enabled_modules: EnabledModules::for_genesis_transaction(),
             max_number_of_substates_in_track: 50_000,
             max_number_of_substates_in_heap: 50_000,
-            max_number_of_events: 1_000_000,
+            max_heap_nodes_per_call_frame: usize::MAX,
+            max_heap_substate_size_per_call_frame: usize::MAX,
+            max_event_count: 1_000_000,
+            max_total_event_size: usize::MAX,
             ..Self::default()
         }
     }
@@ -137,11 +177,105 @@ impl ExecutionConfig {
         self
     }
 
+    pub fn with_node_audit(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules.insert(EnabledModules::NODE_AUDIT);
+        } else {
+            self.enabled_modules.remove(EnabledModules::NODE_AUDIT);
+        }
+        self
+    }
+
+    pub fn with_profiling(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules.insert(EnabledModules::PROFILING);
+        } else {
+            self.enabled_modules.remove(EnabledModules::PROFILING);
+        }
+        self
+    }
+
+    pub fn with_invariant_checks(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules
+                .insert(EnabledModules::INVARIANT_CHECKS);
+        } else {
+            self.enabled_modules
+                .remove(EnabledModules::INVARIANT_CHECKS);
+        }
+        self
+    }
+
+    /// Overrides the per-transaction event limits, e.g. for stress tests.
+    pub fn with_event_limits(
+        mut self,
+        max_event_count: usize,
+        max_event_payload_size: usize,
+        max_total_event_size: usize,
+    ) -> Self {
+        self.max_event_count = max_event_count;
+        self.max_event_payload_size = max_event_payload_size;
+        self.max_total_event_size = max_total_event_size;
+        self
+    }
+
+    /// Limits the number and total size of the substates read from the database and written,
+    /// e.g. for protocol tuning and DoS analysis.
+    pub fn with_substate_limits(
+        mut self,
+        max_number_of_substate_reads: usize,
+        max_number_of_substate_writes: usize,
+        max_total_substate_read_size: usize,
+        max_total_substate_write_size: usize,
+    ) -> Self {
+        self.max_number_of_substate_reads = max_number_of_substate_reads;
+        self.max_number_of_substate_writes = max_number_of_substate_writes;
+        self.max_total_substate_read_size = max_total_substate_read_size;
+        self.max_total_substate_write_size = max_total_substate_write_size;
+        self
+    }
+
+    /// Limits the number of heap nodes, and their total substate size, which a call frame,
+    /// including its callees, may add to the heap.
+    pub fn with_heap_limits(
+        mut self,
+        max_heap_nodes_per_call_frame: usize,
+        max_heap_substate_size_per_call_frame: usize,
+    ) -> Self {
+        self.max_heap_nodes_per_call_frame = max_heap_nodes_per_call_frame;
+        self.max_heap_substate_size_per_call_frame = max_heap_substate_size_per_call_frame;
+        self
+    }
+
+    /// Limits the number of WASM invocations, including the nested ones, so that recursive calls
+    /// between packages cannot run unbounded (e.g. in previews).
+    pub fn with_max_wasm_invocations(mut self, max_wasm_invocations: usize) -> Self {
+        self.max_wasm_invocations = max_wasm_invocations;
+        self
+    }
+
+    /// Overrides the maximum size of a substate value, e.g. to catch unbounded state growth in
+    /// tests with a lower limit.
+    pub fn with_max_substate_size(mut self, max_substate_size: usize) -> Self {
+        self.max_substate_size = max_substate_size;
+        self
+    }
+
     pub fn with_cost_unit_limit(mut self, cost_unit_limit: u32) -> Self {
         self.cost_unit_limit = cost_unit_limit;
         self
     }
 
+    pub fn with_ruid_seed(mut self, ruid_seed: Option<Hash>) -> Self {
+        self.ruid_seed = ruid_seed;
+        self
+    }
+
+    pub fn with_blueprint_mocks(mut self, blueprint_mocks: BlueprintMocks) -> Self {
+        self.blueprint_mocks = blueprint_mocks;
+        self
+    }
+
     pub fn up_to_loan_repayment(mut self, enabled: bool) -> Self {
         self.abort_when_loan_repaid = enabled;
         self
@@ -189,14 +323,18 @@ where
         )
         .with_free_credit(transaction.fee_payment().free_credit_in_xrd);
 
-        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve, FeeTable::new())
+        let fee_table = FeeTable::new().with_crypto_cost_multiplier_percentage(
+            fee_reserve_config.crypto_cost_multiplier_percentage,
+        );
+
+        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve, fee_table)
     }
 
     fn execute_with_fee_reserve(
         &mut self,
         executable: &Executable,
         execution_config: &ExecutionConfig,
-        fee_reserve: SystemLoanFeeReserve,
+        mut fee_reserve: SystemLoanFeeReserve,
         fee_table: FeeTable,
     ) -> TransactionReceipt {
         // Dump executable
@@ -220,6 +358,9 @@ where
         // TODO: the following assumptions can be removed with better interface.
         // We are assuming that intent hash store is ready when epoch manager is ready.
         let current_epoch = Self::read_epoch(&mut track);
+        if let Some(usd_price) = Self::read_usd_price(&mut track) {
+            fee_reserve = fee_reserve.with_usd_price(usd_price);
+        }
         let validation_result = if let Some(current_epoch) = current_epoch {
             if let Some(range) = executable.epoch_range() {
                 Self::validate_epoch_range(
@@ -242,11 +383,19 @@ where
         };
 
         // Run manifest
+        let mut execution_profile = None;
         let result = match validation_result {
             Ok(()) => {
                 let (
                     interpretation_result,
-                    (mut costing_module, runtime_module, execution_trace_module),
+                    (
+                        mut costing_module,
+                        runtime_module,
+                        execution_trace_module,
+                        node_audit_module,
+                        limits_module,
+                        profiling_module,
+                    ),
                 ) = self.interpret_manifest(
                     &mut track,
                     executable,
@@ -264,6 +413,23 @@ where
                     println!("{:?}", interpretation_result);
                 }
 
+                if execution_config
+                    .enabled_modules
+                    .contains(EnabledModules::PROFILING)
+                {
+                    execution_profile = Some(profiling_module.finalize());
+                }
+
+                // Audit nodes before any changes are reverted
+                let leaked_nodes = if execution_config
+                    .enabled_modules
+                    .contains(EnabledModules::NODE_AUDIT)
+                {
+                    node_audit_module.finalize(|node_id| track.is_new_node(node_id))
+                } else {
+                    Vec::new()
+                };
+
                 let result_type = Self::determine_result_type(
                     interpretation_result,
                     &mut costing_module.fee_reserve,
@@ -287,6 +453,9 @@ where
                             .map(|(k, v)| (k.to_string(), v))
                             .collect();
                         fee_summary.fee_payments = fee_payments.clone();
+                        fee_summary.substate_usage = limits_module.substate_usage();
+                        fee_summary.wasm_invocation_count = limits_module.wasm_invocation_count();
+                        let royalty_distributions = fee_summary.royalty_distributions();
 
                         // Update intent hash status
                         if let Some(next_epoch) = Self::read_epoch(&mut track) {
@@ -299,10 +468,16 @@ where
                         }
 
                         // Finalize everything
-                        let (application_events, application_logs) =
+                        let (application_events, application_logs, application_metrics) =
                             runtime_module.finalize(is_success);
-                        let execution_trace =
+                        let mut execution_trace =
                             execution_trace_module.finalize(&fee_payments, is_success);
+                        if execution_config
+                            .enabled_modules
+                            .contains(EnabledModules::EXECUTION_TRACE)
+                        {
+                            execution_trace.heap_peak_usage = limits_module.heap_peak_usage();
+                        }
                         let (tracked_nodes, deleted_partitions) = track.finalize();
                         let state_update_summary =
                             StateUpdateSummary::new(self.substate_db, &tracked_nodes);
@@ -321,7 +496,11 @@ where
                             fee_summary,
                             application_events,
                             application_logs,
+                            application_metrics,
+                            royalty_distributions,
                             execution_trace,
+                            leaked_nodes,
+                            message: executable.message().clone(),
                         })
                     }
                     TransactionResultType::Reject(error) => {
@@ -347,6 +526,7 @@ where
         let receipt = TransactionReceipt {
             transaction_result: result,
             resources_usage,
+            execution_profile,
         };
 
         // Dump summary
@@ -380,6 +560,24 @@ where
         Some(substate.epoch)
     }
 
+    fn read_usd_price(track: &mut Track<S, SpreadPrefixKeyMapper>) -> Option<Decimal> {
+        let handle = match track.acquire_lock(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::Config.into(),
+            LockFlags::read_only(),
+        ) {
+            Ok(x) => x.0,
+            Err(_) => {
+                return None;
+            }
+        };
+        let substate: ConsensusManagerConfigSubstate =
+            track.read_substate(handle).0.as_typed().unwrap();
+        track.close_substate(handle);
+        Some(substate.config.usd_price_in_xrd)
+    }
+
     fn validate_epoch_range(
         current_epoch: Epoch,
         start_epoch_inclusive: Epoch,
@@ -470,6 +668,9 @@ where
             CostingModule,
             TransactionRuntimeModule,
             ExecutionTraceModule,
+            NodeAuditModule,
+            LimitsModule,
+            ProfilingModule,
         ),
     ) {
         let mut id_allocator = IdAllocator::new(executable.intent_hash().to_hash());
@@ -477,6 +678,7 @@ where
             blueprint_cache: NonIterMap::new(),
             auth_cache: NonIterMap::new(),
             schema_cache: NonIterMap::new(),
+            blueprint_mocks: execution_config.blueprint_mocks.clone(),
             callback_obj: Vm {
                 scrypto_vm: self.scrypto_vm,
             },
@@ -1,10 +1,13 @@
 use super::{BalanceChange, StateUpdateSummary};
 use crate::blueprints::consensus_manager::EpochChangeEvent;
 use crate::errors::*;
+use crate::kernel::heap::HeapUsage;
 use crate::system::system_modules::costing::FeeSummary;
 use crate::system::system_modules::execution_trace::{
-    ExecutionTrace, ResourceChange, WorktopChange,
+    ExecutionTrace, InstructionTrace, ResourceChange, WorktopChange,
 };
+use crate::system::system_modules::node_audit::NodeAllocation;
+use crate::system::system_modules::profiling::ExecutionProfile;
 use crate::track::StateUpdates;
 use crate::types::*;
 use colored::*;
@@ -14,6 +17,7 @@ use radix_engine_interface::blueprints::transaction_processor::InstructionOutput
 use radix_engine_interface::data::scrypto::ScryptoDecode;
 use radix_engine_interface::types::*;
 use sbor::representations::*;
+use transaction::model::{MessageContentsV1, MessageV1, PlaintextMessageV1};
 use utils::ContextualDisplay;
 
 #[derive(Debug, Clone, Default, ScryptoSbor)]
@@ -27,7 +31,13 @@ pub struct ResourcesUsage {
 pub struct TransactionExecutionTrace {
     pub execution_traces: Vec<ExecutionTrace>,
     pub resource_changes: IndexMap<usize, Vec<ResourceChange>>,
+    /// The resource movements of each instruction, for instruction-by-instruction previews.
+    pub instruction_traces: Vec<InstructionTrace>,
     pub fee_locks: FeeLocks,
+    /// The peak memory, in bytes, of each WASM invocation.
+    pub wasm_memory_peaks: Vec<(BlueprintId, usize)>,
+    /// The peak number of nodes on the heap, and the peak size of their substates.
+    pub heap_peak_usage: HeapUsage,
 }
 
 impl TransactionExecutionTrace {
@@ -71,9 +81,18 @@ pub struct CommitResult {
     pub fee_summary: FeeSummary,
     pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
     pub application_logs: Vec<(Level, String)>,
+    /// Metrics emitted by blueprints, for previews and tests only; these are not committed.
+    pub application_metrics: Vec<(String, Decimal)>,
+    /// The royalties paid by this transaction, per recipient.
+    pub royalty_distributions: Vec<(GlobalAddress, ResourceAddress, Decimal)>,
     /// Optional, only when `EnabledModule::ExecutionTrace` is ON.
     /// Mainly for transaction preview.
     pub execution_trace: TransactionExecutionTrace,
+    /// Optional, only when `EnabledModule::NodeAudit` is ON.
+    /// Nodes which were neither dropped nor persisted, with their allocation site.
+    pub leaked_nodes: Vec<NodeAllocation>,
+    /// The message attached to the transaction intent, e.g. a transfer memo.
+    pub message: MessageV1,
 }
 
 impl CommitResult {
@@ -85,7 +104,11 @@ impl CommitResult {
             fee_summary: Default::default(),
             application_events: Default::default(),
             application_logs: Default::default(),
+            application_metrics: Default::default(),
+            royalty_distributions: Default::default(),
             execution_trace: Default::default(),
+            leaked_nodes: Default::default(),
+            message: Default::default(),
         }
     }
 
@@ -210,6 +233,8 @@ pub struct TransactionReceipt {
     pub transaction_result: TransactionResult,
     /// Optional, only when compile-time feature flag `resources_usage` is ON.
     pub resources_usage: ResourcesUsage,
+    /// Optional, only when the profiling module is enabled.
+    pub execution_profile: Option<ExecutionProfile>,
 }
 
 impl TransactionReceipt {
@@ -218,6 +243,7 @@ impl TransactionReceipt {
         Self {
             transaction_result: TransactionResult::Commit(commit_result),
             resources_usage: Default::default(),
+            execution_profile: None,
         }
     }
 
@@ -498,6 +524,15 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 c.fee_summary.total_royalty_cost_xrd,
             )?;
 
+            if let Some(usd_price) = c.fee_summary.usd_price_applied {
+                write!(
+                    f,
+                    "\n{} {} XRD per USD",
+                    "Royalty USD Price:".bold().green(),
+                    usd_price
+                )?;
+            }
+
             write!(
                 f,
                 "\n{} {} limit, {} consumed, {} XRD per cost unit, {}% tip",
@@ -508,6 +543,36 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 c.fee_summary.tip_percentage
             )?;
 
+            match &c.message {
+                MessageV1::None => {}
+                MessageV1::Plaintext(PlaintextMessageV1 { mime_type, message }) => {
+                    let message = match message {
+                        MessageContentsV1::String(message) => message.clone(),
+                        MessageContentsV1::Bytes(message) => format!("{} bytes", message.len()),
+                    };
+                    write!(
+                        f,
+                        "\n{} {} ({})",
+                        "Message:".bold().green(),
+                        message,
+                        mime_type
+                    )?;
+                }
+                MessageV1::Encrypted(encrypted_message) => {
+                    write!(
+                        f,
+                        "\n{} Encrypted, {} bytes for {} decryptor(s)",
+                        "Message:".bold().green(),
+                        encrypted_message.encrypted.0.len(),
+                        encrypted_message
+                            .decryptors_by_curve
+                            .values()
+                            .map(|decryptors| decryptors.number_of_decryptors())
+                            .sum::<usize>()
+                    )?;
+                }
+            }
+
             write!(
                 f,
                 "\n{} {}",
@@ -525,6 +590,66 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 write!(f, "\n{} [{:5}] {}", prefix!(i, c.application_logs), l, m)?;
             }
 
+            if !c.application_metrics.is_empty() {
+                write!(
+                    f,
+                    "\n{} {}",
+                    "Metrics:".bold().green(),
+                    c.application_metrics.len()
+                )?;
+                for (i, (name, value)) in c.application_metrics.iter().enumerate() {
+                    write!(
+                        f,
+                        "\n{} {} = {}",
+                        prefix!(i, c.application_metrics),
+                        name,
+                        value
+                    )?;
+                }
+            }
+
+            let wasm_memory_peaks = &c.execution_trace.wasm_memory_peaks;
+            if !wasm_memory_peaks.is_empty() {
+                write!(
+                    f,
+                    "\n{} {}",
+                    "WASM Memory Peaks:".bold().green(),
+                    wasm_memory_peaks.len()
+                )?;
+                for (i, (blueprint_id, bytes)) in wasm_memory_peaks.iter().enumerate() {
+                    write!(
+                        f,
+                        "\n{} {}:{} => {} bytes",
+                        prefix!(i, wasm_memory_peaks),
+                        blueprint_id
+                            .package_address
+                            .display(address_display_context),
+                        blueprint_id.blueprint_name,
+                        bytes
+                    )?;
+                }
+            }
+
+            let heap_peak_usage = &c.execution_trace.heap_peak_usage;
+            if heap_peak_usage.node_count > 0 {
+                write!(
+                    f,
+                    "\n{} {} nodes, {} bytes",
+                    "Heap Peak Usage:".bold().green(),
+                    heap_peak_usage.node_count,
+                    heap_peak_usage.substate_size
+                )?;
+            }
+
+            if c.fee_summary.wasm_invocation_count > 0 {
+                write!(
+                    f,
+                    "\n{} {}",
+                    "WASM Invocations:".bold().green(),
+                    c.fee_summary.wasm_invocation_count
+                )?;
+            }
+
             write!(
                 f,
                 "\n{} {}",
@@ -572,6 +697,13 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                                     custom_context: scrypto_value_display_context
                                 }),
                             InstructionOutput::None => "None".to_string(),
+                            InstructionOutput::RolledBack {
+                                failed_instruction_index,
+                                error,
+                            } => format!(
+                                "Rolled back, as instruction {} failed: {}",
+                                failed_instruction_index, error
+                            ),
                         }
                     )?;
                 }
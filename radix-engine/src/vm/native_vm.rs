@@ -14,6 +14,7 @@ use crate::system::node_modules::metadata::MetadataNativePackage;
 use crate::system::node_modules::royalty::RoyaltyNativePackage;
 use crate::system::system_callback::SystemLockData;
 use crate::types::*;
+use crate::vm::wasm::WasmValidatorConfigV1;
 use crate::vm::VmInvoke;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::package::*;
@@ -25,6 +26,7 @@ impl NativeVm {
     pub fn create_instance(
         package_address: &PackageAddress,
         code: &[u8],
+        wasm_validator_config: WasmValidatorConfigV1,
     ) -> Result<NativeVmInstance, RuntimeError> {
         let code: [u8; 8] = match code.clone().try_into() {
             Ok(code) => code,
@@ -40,6 +42,7 @@ impl NativeVm {
         let instance = NativeVmInstance {
             package_address: *package_address,
             native_package_code_id,
+            wasm_validator_config,
         };
 
         Ok(instance)
@@ -51,6 +54,8 @@ pub struct NativeVmInstance {
     #[allow(dead_code)]
     package_address: PackageAddress,
     native_package_code_id: u64,
+    // Used by the package blueprint to validate the code of published packages
+    wasm_validator_config: WasmValidatorConfigV1,
 }
 
 impl VmInvoke for NativeVmInstance {
@@ -71,7 +76,12 @@ impl VmInvoke for NativeVmInstance {
         })?;
 
         match self.native_package_code_id {
-            PACKAGE_CODE_ID => PackageNativePackage::invoke_export(export_name, input, api),
+            PACKAGE_CODE_ID => PackageNativePackage::invoke_export(
+                export_name,
+                input,
+                &self.wasm_validator_config,
+                api,
+            ),
             RESOURCE_CODE_ID => ResourceNativePackage::invoke_export(export_name, input, api),
             CONSENSUS_MANAGER_CODE_ID => {
                 ConsensusManagerNativePackage::invoke_export(export_name, input, api)
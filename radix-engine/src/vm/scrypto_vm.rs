@@ -67,6 +67,9 @@ impl<I: WasmInstance> VmInvoke for ScryptoVmInstance<I> {
                 .invoke_export(export_name, input, &mut runtime)?
         };
 
+        let consumed_memory = self.instance.consumed_memory()?;
+        api.update_wasm_memory_usage(consumed_memory)?;
+
         let output = IndexedScryptoValue::from_vec(rtn).map_err(|e| {
             RuntimeError::SystemUpstreamError(SystemUpstreamError::OutputDecodeError(e))
         })?;
@@ -5,7 +5,7 @@ use crate::system::system::KeyValueEntrySubstate;
 use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::types::*;
-use crate::vm::wasm::{WasmEngine, WasmValidator};
+use crate::vm::wasm::{WasmEngine, WasmValidator, WasmValidatorConfigV1};
 use crate::vm::{NativeVm, ScryptoVm};
 use radix_engine_interface::api::field_lock_api::LockFlags;
 use radix_engine_interface::api::ClientApi;
@@ -76,7 +76,15 @@ impl<'g, W: WasmEngine + 'g> SystemCallbackObject for Vm<'g, W> {
                         .expect(&format!("Original code not found: {:?}", export))
                 };
 
-                let mut vm_instance = { NativeVm::create_instance(address, &original_code.code)? };
+                let wasm_validator_config = api
+                    .kernel_get_system()
+                    .callback_obj
+                    .scrypto_vm
+                    .wasm_validator_config
+                    .clone();
+                let mut vm_instance = {
+                    NativeVm::create_instance(address, &original_code.code, wasm_validator_config)?
+                };
                 let output = { vm_instance.invoke(export.export_name.as_str(), input, api)? };
 
                 output
@@ -116,8 +124,12 @@ impl<'g, W: WasmEngine + 'g> SystemCallbackObject for Vm<'g, W> {
                     size: instrumented_code.code.len(),
                 })?;
 
+                api.kernel_get_system()
+                    .modules
+                    .on_wasm_invocation_start(export.export_name.as_str())?;
                 let output =
                     { scrypto_vm_instance.invoke(export.export_name.as_str(), input, api)? };
+                api.kernel_get_system().modules.on_wasm_invocation_end();
 
                 output
             }
@@ -146,12 +158,13 @@ impl VmPackageValidation {
         definition: &PackageDefinition,
         vm_type: VmType,
         code: &[u8],
+        wasm_validator_config: &WasmValidatorConfigV1,
     ) -> Result<Option<Vec<u8>>, RuntimeError> {
         match vm_type {
             VmType::Native => Ok(None),
             VmType::ScryptoV1 => {
                 // Validate WASM
-                let instrumented_code = WasmValidator::default()
+                let instrumented_code = WasmValidator::new(wasm_validator_config.clone())
                     .validate(&code, definition.blueprints.values())
                     .map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::PackageError(
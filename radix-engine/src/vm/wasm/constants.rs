@@ -21,6 +21,9 @@ pub const GLOBALIZE_FUNCTION_NAME: &str = "globalize";
 pub const CALL_METHOD_FUNCTION_NAME: &str = "call_method";
 pub const CALL_FUNCTION_FUNCTION_NAME: &str = "call_function";
 pub const GET_OBJECT_INFO_FUNCTION_NAME: &str = "get_object_info";
+pub const GET_RESERVATION_ADDRESS_FUNCTION_NAME: &str = "get_reservation_address";
+pub const GET_METADATA_FUNCTION_NAME: &str = "get_metadata";
+pub const BATCH_INVOKE_FUNCTION_NAME: &str = "batch_invoke";
 pub const DROP_OBJECT_FUNCTION_NAME: &str = "drop_object";
 
 //=================
@@ -61,6 +64,7 @@ pub const GET_NODE_ID_FUNCTION_NAME: &str = "get_node_id";
 //=================
 pub const EMIT_EVENT_FUNCTION_NAME: &str = "emit_event";
 pub const EMIT_LOG_FUNCTION_NAME: &str = "emit_log";
+pub const EMIT_METRIC_FUNCTION_NAME: &str = "emit_metric";
 pub const GET_TRANSACTION_HASH_FUNCTION_NAME: &str = "get_transaction_hash";
 pub const GENERATE_RUID_FUNCTION_NAME: &str = "generate_ruid";
 pub const PANIC_FUNCTION_NAME: &str = "panic";
@@ -75,6 +79,12 @@ pub const EXPORT_MEMORY: &str = "memory";
 /// The maximum memory size (per call frame): 64 * 64KiB = 4MiB
 pub const DEFAULT_MAX_MEMORY_SIZE_IN_PAGES: u32 = 64;
 
+/// The maximum initial memory size, before any `memory.grow`
+pub const DEFAULT_MAX_INITIAL_MEMORY_SIZE_IN_PAGES: u32 = DEFAULT_MAX_MEMORY_SIZE_IN_PAGES;
+
+/// The maximum stack height of a call, as measured by the injected stack limiter
+pub const DEFAULT_MAX_STACK_SIZE: u32 = 1024;
+
 /// The maximum initial table size
 pub const DEFAULT_MAX_INITIAL_TABLE_SIZE: u32 = 1024;
 
@@ -65,6 +65,8 @@ pub enum InvalidMemory {
     TooManyMemoryDefinition,
     /// The memory size exceeds the limit.
     MemorySizeLimitExceeded,
+    /// The initial memory size exceeds the limit.
+    InitialMemorySizeLimitExceeded,
     /// The wasm module does not have the `memory` export.
     MemoryNotExported,
 }
@@ -136,6 +138,12 @@ pub enum WasmRuntimeError {
     /// Invalid log level
     InvalidLogLevel(DecodeError),
 
+    /// Invalid metric value
+    InvalidMetricValue(DecodeError),
+
+    /// Invalid batch of calls
+    InvalidBatchCalls(DecodeError),
+
     /// Costing error (no-op runtime only!)
     FeeReserveError(FeeReserveError),
 }
@@ -6,7 +6,7 @@ use parity_wasm::elements::{
     Instruction::{self, *},
     Internal, Module, Type, ValueType,
 };
-use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
+use radix_engine_interface::blueprints::package::{BlueprintDefinitionInit, PackageDefinition};
 use wasm_instrument::{
     gas_metering::{self, Rules},
     inject_stack_limiter,
@@ -19,6 +19,21 @@ pub struct WasmModule {
     module: Module,
 }
 
+/// The dead code found in a package's WASM, relative to the package definition.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasmDeadCodeReport {
+    /// Function exports which are not an entry point of any blueprint, and so can never be invoked.
+    pub unreachable_exports: Vec<String>,
+    /// Function imports, as `module::field`, which are never called from any entry point.
+    pub unused_imports: Vec<String>,
+}
+
+impl WasmDeadCodeReport {
+    pub fn is_empty(&self) -> bool {
+        self.unreachable_exports.is_empty() && self.unused_imports.is_empty()
+    }
+}
+
 impl WasmModule {
     pub fn init(code: &[u8]) -> Result<Self, PrepareError> {
         // deserialize
@@ -720,6 +735,62 @@ impl WasmModule {
                                 ));
                             }
                         }
+                        GET_RESERVATION_ADDRESS_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        GET_RESERVATION_ADDRESS_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        GET_METADATA_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        GET_METADATA_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
+                        BATCH_INVOKE_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+                                return Err(PrepareError::InvalidImport(
+                                    InvalidImport::InvalidFunctionType(
+                                        BATCH_INVOKE_FUNCTION_NAME.to_string(),
+                                    ),
+                                ));
+                            }
+                        }
                         KEY_VALUE_STORE_GET_INFO_FUNCTION_NAME => {
                             if let External::Function(type_index) = entry.external() {
                                 if Self::function_type_matches(
@@ -771,6 +842,23 @@ impl WasmModule {
                                 }
                             }
                         }
+                        EMIT_METRIC_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                        ValueType::I32,
+                                    ],
+                                    vec![],
+                                ) {
+                                    continue;
+                                }
+                            }
+                        }
                         PANIC_FUNCTION_NAME => {
                             if let External::Function(type_index) = entry.external() {
                                 if Self::function_type_matches(
@@ -820,6 +908,7 @@ impl WasmModule {
 
     pub fn enforce_memory_limit_and_inject_max(
         mut self,
+        max_initial_memory_size_in_pages: u32,
         max_memory_size_in_pages: u32,
     ) -> Result<Self, PrepareError> {
         // Check if memory section exists
@@ -847,6 +936,11 @@ impl WasmModule {
                 InvalidMemory::MemorySizeLimitExceeded,
             ));
         }
+        if memory.limits().initial() > max_initial_memory_size_in_pages {
+            return Err(PrepareError::InvalidMemory(
+                InvalidMemory::InitialMemorySizeLimitExceeded,
+            ));
+        }
         if let Some(max) = memory.limits().maximum() {
             if max > max_memory_size_in_pages {
                 return Err(PrepareError::InvalidMemory(
@@ -920,7 +1014,7 @@ impl WasmModule {
     ) -> Result<Self, PrepareError> {
         if let Some(section) = self.module.function_section() {
             if section.entries().len() > max_number_of_functions as usize {
-                return Err(PrepareError::TooManyGlobals);
+                return Err(PrepareError::TooManyFunctions);
             }
         }
 
@@ -972,6 +1066,95 @@ impl WasmModule {
         Ok(self)
     }
 
+    /// Finds the function exports and imports which can't be reached from the entry points of
+    /// the given package definition.
+    ///
+    /// The `<blueprint>_schema` exports are treated as entry points, as they are how tooling
+    /// extracts the package definition from the code.
+    pub fn analyze_dead_code(&self, definition: &PackageDefinition) -> WasmDeadCodeReport {
+        let imported_functions: Vec<_> = self
+            .module
+            .import_section()
+            .map(|s| s.entries())
+            .unwrap_or(&[])
+            .iter()
+            .filter(|e| matches!(e.external(), External::Function(_)))
+            .collect();
+        let function_exports: Vec<(&str, u32)> = self
+            .module
+            .export_section()
+            .map(|s| s.entries())
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|e| match e.internal() {
+                Internal::Function(func_index) => Some((e.field(), *func_index)),
+                _ => None,
+            })
+            .collect();
+        let table_functions: Vec<u32> = self
+            .module
+            .elements_section()
+            .map(|s| s.entries())
+            .unwrap_or(&[])
+            .iter()
+            .flat_map(|e| e.members().iter().cloned())
+            .collect();
+        let bodies = self
+            .module
+            .code_section()
+            .map(|s| s.bodies())
+            .unwrap_or(&[]);
+
+        let mut entry_points = BTreeSet::new();
+        for (blueprint_name, blueprint_def_init) in &definition.blueprints {
+            entry_points.insert(format!("{}_schema", blueprint_name));
+            entry_points.extend(blueprint_def_init.schema.functions.exports());
+        }
+
+        // Walk the call graph from the entry points; an indirect call may reach any table element
+        let mut reachable = BTreeSet::new();
+        let mut table_reached = false;
+        let mut pending: Vec<u32> = function_exports
+            .iter()
+            .filter(|(name, _)| entry_points.contains(*name))
+            .map(|(_, func_index)| *func_index)
+            .collect();
+        while let Some(func_index) = pending.pop() {
+            if !reachable.insert(func_index) {
+                continue;
+            }
+            let body = (func_index as usize)
+                .checked_sub(imported_functions.len())
+                .and_then(|i| bodies.get(i));
+            if let Some(body) = body {
+                for instruction in body.code().elements() {
+                    match instruction {
+                        Call(callee) => pending.push(*callee),
+                        CallIndirect(..) if !table_reached => {
+                            table_reached = true;
+                            pending.extend(table_functions.iter().cloned());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        WasmDeadCodeReport {
+            unreachable_exports: function_exports
+                .iter()
+                .filter(|(name, _)| !entry_points.contains(*name))
+                .map(|(name, _)| name.to_string())
+                .collect(),
+            unused_imports: imported_functions
+                .iter()
+                .enumerate()
+                .filter(|(func_index, _)| !reachable.contains(&(*func_index as u32)))
+                .map(|(_, e)| format!("{}::{}", e.module(), e.field()))
+                .collect(),
+        }
+    }
+
     pub fn inject_instruction_metering<R: Rules>(
         mut self,
         rules: &R,
@@ -1193,7 +1376,7 @@ mod tests {
             )
             "#,
             PrepareError::InvalidMemory(InvalidMemory::MissingMemorySection),
-            |x| WasmModule::enforce_memory_limit_and_inject_max(x, 5)
+            |x| WasmModule::enforce_memory_limit_and_inject_max(x, 5, 5)
         );
         // NOTE: Disabled as MVP only allow 1 memory definition
         // assert_invalid_wasm!(
@@ -1213,7 +1396,16 @@ mod tests {
             )
             "#,
             PrepareError::InvalidMemory(InvalidMemory::MemorySizeLimitExceeded),
-            |x| WasmModule::enforce_memory_limit_and_inject_max(x, 5)
+            |x| WasmModule::enforce_memory_limit_and_inject_max(x, 5, 5)
+        );
+        assert_invalid_wasm!(
+            r#"
+            (module
+                (memory 3)
+            )
+            "#,
+            PrepareError::InvalidMemory(InvalidMemory::InitialMemorySizeLimitExceeded),
+            |x| WasmModule::enforce_memory_limit_and_inject_max(x, 2, 5)
         );
         assert_invalid_wasm!(
             r#"
@@ -1222,7 +1414,7 @@ mod tests {
             )
             "#,
             PrepareError::InvalidMemory(InvalidMemory::MemoryNotExported),
-            |x| WasmModule::enforce_memory_limit_and_inject_max(x, 5)
+            |x| WasmModule::enforce_memory_limit_and_inject_max(x, 5, 5)
         );
     }
 
@@ -1261,6 +1453,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_functions() {
+        assert_invalid_wasm!(
+            r#"
+            (module
+                (func)
+                (func)
+                (func)
+            )
+            "#,
+            PrepareError::TooManyFunctions,
+            |x| WasmModule::enforce_function_limit(x, 2)
+        );
+    }
+
+    #[test]
+    fn test_globals() {
+        assert_invalid_wasm!(
+            r#"
+            (module
+                (global i32 (i32.const 0))
+                (global i32 (i32.const 1))
+                (global i32 (i32.const 2))
+            )
+            "#,
+            PrepareError::TooManyGlobals,
+            |x| WasmModule::enforce_global_limit(x, 2)
+        );
+    }
+
     #[test]
     fn test_blueprint_constraints() {
         let mut blueprints = BTreeMap::new();
@@ -1340,4 +1562,45 @@ mod tests {
             |x| WasmModule::enforce_export_constraints(x, blueprints.values())
         );
     }
+
+    #[test]
+    fn test_dead_code_analysis() {
+        let code = wat2wasm(
+            r#"
+            (module
+                (import "env" "consume_buffer" (func $consume_buffer (param i32 i32)))
+                (import "env" "emit_log" (func $emit_log (param i32 i32 i32 i32)))
+                (func $helper
+                    (call $consume_buffer (i32.const 0) (i32.const 0))
+                )
+                (func (export "Test_f") (param i64) (result i64)
+                    (call $helper)
+                    (i64.const 0)
+                )
+                (func (export "Test_schema") (result i64)
+                    (i64.const 0)
+                )
+                (func (export "Test_unused") (param i64) (result i64)
+                    (call $emit_log (i32.const 0) (i32.const 0) (i32.const 0) (i32.const 0))
+                    (i64.const 0)
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let definition = PackageDefinition::single_test_function("Test", "f");
+
+        let report = WasmModule::init(&code)
+            .unwrap()
+            .analyze_dead_code(&definition);
+
+        assert_eq!(
+            report,
+            WasmDeadCodeReport {
+                unreachable_exports: vec!["Test_unused".to_string()],
+                unused_imports: vec!["env::emit_log".to_string()],
+            }
+        );
+        assert!(!report.is_empty());
+    }
 }
@@ -3,19 +3,35 @@ use parity_wasm::elements::Instruction::{self, *};
 use wasm_instrument::gas_metering::MemoryGrowCost;
 use wasm_instrument::gas_metering::Rules;
 
-use super::InstructionWeights;
+use super::{
+    InstructionWeights, DEFAULT_MAX_INITIAL_MEMORY_SIZE_IN_PAGES, DEFAULT_MAX_INITIAL_TABLE_SIZE,
+    DEFAULT_MAX_MEMORY_SIZE_IN_PAGES, DEFAULT_MAX_NUMBER_OF_BR_TABLE_TARGETS,
+    DEFAULT_MAX_NUMBER_OF_FUNCTIONS, DEFAULT_MAX_NUMBER_OF_GLOBALS, DEFAULT_MAX_STACK_SIZE,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WasmValidatorConfigV1 {
     weights: InstructionWeights,
     max_stack_size: u32,
+    max_initial_memory_size_in_pages: u32,
+    max_memory_size_in_pages: u32,
+    max_initial_table_size: u32,
+    max_number_of_br_table_targets: u32,
+    max_number_of_functions: u32,
+    max_number_of_globals: u32,
 }
 
 impl WasmValidatorConfigV1 {
     pub fn new() -> Self {
         Self {
             weights: InstructionWeights::default(),
-            max_stack_size: 1024,
+            max_stack_size: DEFAULT_MAX_STACK_SIZE,
+            max_initial_memory_size_in_pages: DEFAULT_MAX_INITIAL_MEMORY_SIZE_IN_PAGES,
+            max_memory_size_in_pages: DEFAULT_MAX_MEMORY_SIZE_IN_PAGES,
+            max_initial_table_size: DEFAULT_MAX_INITIAL_TABLE_SIZE,
+            max_number_of_br_table_targets: DEFAULT_MAX_NUMBER_OF_BR_TABLE_TARGETS,
+            max_number_of_functions: DEFAULT_MAX_NUMBER_OF_FUNCTIONS,
+            max_number_of_globals: DEFAULT_MAX_NUMBER_OF_GLOBALS,
         }
     }
 
@@ -26,6 +42,73 @@ impl WasmValidatorConfigV1 {
     pub fn max_stack_size(&self) -> u32 {
         self.max_stack_size
     }
+
+    pub fn max_initial_memory_size_in_pages(&self) -> u32 {
+        self.max_initial_memory_size_in_pages
+    }
+
+    pub fn max_memory_size_in_pages(&self) -> u32 {
+        self.max_memory_size_in_pages
+    }
+
+    pub fn max_initial_table_size(&self) -> u32 {
+        self.max_initial_table_size
+    }
+
+    pub fn max_number_of_br_table_targets(&self) -> u32 {
+        self.max_number_of_br_table_targets
+    }
+
+    pub fn max_number_of_functions(&self) -> u32 {
+        self.max_number_of_functions
+    }
+
+    pub fn max_number_of_globals(&self) -> u32 {
+        self.max_number_of_globals
+    }
+
+    /// Sets the maximum stack height of a call, which is enforced by the stack limiter injected
+    /// into the code when it's published.
+    pub fn with_max_stack_size(mut self, max_stack_size: u32) -> Self {
+        self.max_stack_size = max_stack_size;
+        self
+    }
+
+    pub fn with_max_initial_memory_size_in_pages(
+        mut self,
+        max_initial_memory_size_in_pages: u32,
+    ) -> Self {
+        self.max_initial_memory_size_in_pages = max_initial_memory_size_in_pages;
+        self
+    }
+
+    pub fn with_max_memory_size_in_pages(mut self, max_memory_size_in_pages: u32) -> Self {
+        self.max_memory_size_in_pages = max_memory_size_in_pages;
+        self
+    }
+
+    pub fn with_max_initial_table_size(mut self, max_initial_table_size: u32) -> Self {
+        self.max_initial_table_size = max_initial_table_size;
+        self
+    }
+
+    pub fn with_max_number_of_br_table_targets(
+        mut self,
+        max_number_of_br_table_targets: u32,
+    ) -> Self {
+        self.max_number_of_br_table_targets = max_number_of_br_table_targets;
+        self
+    }
+
+    pub fn with_max_number_of_functions(mut self, max_number_of_functions: u32) -> Self {
+        self.max_number_of_functions = max_number_of_functions;
+        self
+    }
+
+    pub fn with_max_number_of_globals(mut self, max_number_of_globals: u32) -> Self {
+        self.max_number_of_globals = max_number_of_globals;
+        self
+    }
 }
 
 impl Rules for WasmValidatorConfigV1 {
@@ -221,6 +304,6 @@ mod tests {
 
     #[test]
     fn print_params() {
-        assert_eq!(format!("{:?}", WasmValidatorConfigV1::new()), "WasmValidatorConfigV1 { weights: InstructionWeights { version: 4, fallback: 0, i64const: 1372, i64load: 3597, i64store: 3905, select: 3434, if: 8054, br: 3529, br_if: 4706, br_table: 8198, br_table_per_entry: 29, call: 14340, call_indirect: 19936, call_per_local: 1651, local_get: 2816, local_set: 2822, local_tee: 2087, global_get: 7002, global_set: 7806, memory_current: 2555, memory_grow: 14764221, i64clz: 1509, i64ctz: 2035, i64popcnt: 1499, i64eqz: 1889, i64extendsi32: 1478, i64extendui32: 1939, i32wrapi64: 1505, i64eq: 2149, i64ne: 1628, i64lts: 1654, i64ltu: 2088, i64gts: 2205, i64gtu: 1661, i64les: 1648, i64leu: 2135, i64ges: 2226, i64geu: 1661, i64add: 1623, i64sub: 2212, i64mul: 1640, i64divs: 2678, i64divu: 1751, i64rems: 2659, i64remu: 1681, i64and: 2045, i64or: 1641, i64xor: 2196, i64shl: 1662, i64shrs: 2124, i64shru: 1646, i64rotl: 1658, i64rotr: 2062 }, max_stack_size: 1024 }")
+        assert_eq!(format!("{:?}", WasmValidatorConfigV1::new()), "WasmValidatorConfigV1 { weights: InstructionWeights { version: 4, fallback: 0, i64const: 1372, i64load: 3597, i64store: 3905, select: 3434, if: 8054, br: 3529, br_if: 4706, br_table: 8198, br_table_per_entry: 29, call: 14340, call_indirect: 19936, call_per_local: 1651, local_get: 2816, local_set: 2822, local_tee: 2087, global_get: 7002, global_set: 7806, memory_current: 2555, memory_grow: 14764221, i64clz: 1509, i64ctz: 2035, i64popcnt: 1499, i64eqz: 1889, i64extendsi32: 1478, i64extendui32: 1939, i32wrapi64: 1505, i64eq: 2149, i64ne: 1628, i64lts: 1654, i64ltu: 2088, i64gts: 2205, i64gtu: 1661, i64les: 1648, i64leu: 2135, i64ges: 2226, i64geu: 1661, i64add: 1623, i64sub: 2212, i64mul: 1640, i64divs: 2678, i64divu: 1751, i64rems: 2659, i64remu: 1681, i64and: 2045, i64or: 1641, i64xor: 2196, i64shl: 1662, i64shrs: 2124, i64shru: 1646, i64rotl: 1658, i64rotr: 2062 }, max_stack_size: 1024, max_initial_memory_size_in_pages: 64, max_memory_size_in_pages: 64, max_initial_table_size: 1024, max_number_of_br_table_targets: 256, max_number_of_functions: 65536, max_number_of_globals: 512 }")
     }
 }
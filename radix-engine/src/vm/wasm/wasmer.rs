@@ -352,6 +352,55 @@ impl WasmerModule {
             Ok(buffer.0)
         }
 
+        pub fn get_reservation_address(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let buffer = runtime
+                .get_reservation_address(read_memory(&instance, node_id_ptr, node_id_len)?)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn get_metadata(
+            env: &WasmerInstanceEnv,
+            address_ptr: u32,
+            address_len: u32,
+            key_ptr: u32,
+            key_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let address = read_memory(&instance, address_ptr, address_len)?;
+            let key = read_memory(&instance, key_ptr, key_len)?;
+
+            let buffer = runtime
+                .get_metadata(address, key)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn batch_invoke(
+            env: &WasmerInstanceEnv,
+            calls_ptr: u32,
+            calls_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let calls = read_memory(&instance, calls_ptr, calls_len)?;
+
+            let buffer = runtime
+                .batch_invoke(calls)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
         pub fn key_value_store_new(
             env: &WasmerInstanceEnv,
             schema_id_ptr: u32,
@@ -614,6 +663,21 @@ impl WasmerModule {
             runtime.emit_log(level, message)
         }
 
+        fn emit_metric(
+            env: &WasmerInstanceEnv,
+            name_ptr: u32,
+            name_len: u32,
+            value_ptr: u32,
+            value_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let name = read_memory(&instance, name_ptr, name_len)?;
+            let value = read_memory(&instance, value_ptr, value_len)?;
+
+            runtime.emit_metric(name, value)
+        }
+
         fn panic(
             env: &WasmerInstanceEnv,
             message_ptr: u32,
@@ -668,6 +732,9 @@ impl WasmerModule {
                 FEE_BALANCE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), fee_balance),
                 GLOBALIZE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), globalize_object),
                 GET_OBJECT_INFO_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_type_info),
+                GET_RESERVATION_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_reservation_address),
+                GET_METADATA_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_metadata),
+                BATCH_INVOKE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), batch_invoke),
                 DROP_OBJECT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), drop_object),
                 ACTOR_OPEN_FIELD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_open_field),
                 ACTOR_CALL_MODULE_METHOD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_call_module_method),
@@ -688,6 +755,7 @@ impl WasmerModule {
                 CONSUME_WASM_EXECUTION_UNITS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_wasm_execution_units),
                 EMIT_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), emit_event),
                 EMIT_LOG_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), emit_log),
+                EMIT_METRIC_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), emit_metric),
                 PANIC_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), panic),
                 GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_transaction_hash),
                 GENERATE_RUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), generate_ruid),
@@ -301,6 +301,50 @@ fn get_object_info(
         .map(|buffer| buffer.0)
 }
 
+fn get_reservation_address(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    runtime
+        .get_reservation_address(read_memory(
+            caller.as_context_mut(),
+            memory,
+            node_id_ptr,
+            node_id_len,
+        )?)
+        .map(|buffer| buffer.0)
+}
+
+fn get_metadata(
+    mut caller: Caller<'_, HostState>,
+    address_ptr: u32,
+    address_len: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let address = read_memory(caller.as_context_mut(), memory, address_ptr, address_len)?;
+    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
+
+    runtime.get_metadata(address, key).map(|buffer| buffer.0)
+}
+
+fn batch_invoke(
+    mut caller: Caller<'_, HostState>,
+    calls_ptr: u32,
+    calls_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let calls = read_memory(caller.as_context_mut(), memory, calls_ptr, calls_len)?;
+
+    runtime.batch_invoke(calls).map(|buffer| buffer.0)
+}
+
 fn drop_object(
     mut caller: Caller<'_, HostState>,
     node_id_ptr: u32,
@@ -511,6 +555,21 @@ fn emit_log(
     runtime.emit_log(level, message)
 }
 
+fn emit_metric(
+    mut caller: Caller<'_, HostState>,
+    name_ptr: u32,
+    name_len: u32,
+    value_ptr: u32,
+    value_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let name = read_memory(caller.as_context_mut(), memory, name_ptr, name_len)?;
+    let value = read_memory(caller.as_context_mut(), memory, value_ptr, value_len)?;
+
+    runtime.emit_metric(name, value)
+}
+
 fn panic(
     mut caller: Caller<'_, HostState>,
     message_ptr: u32,
@@ -741,6 +800,36 @@ impl WasmiModule {
             },
         );
 
+        let host_get_reservation_address = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32|
+             -> Result<u64, Trap> {
+                get_reservation_address(caller, node_id_ptr, node_id_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_get_metadata = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             address_ptr: u32,
+             address_len: u32,
+             key_ptr: u32,
+             key_len: u32|
+             -> Result<u64, Trap> {
+                get_metadata(caller, address_ptr, address_len, key_ptr, key_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
+        let host_batch_invoke = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, calls_ptr: u32, calls_len: u32| -> Result<u64, Trap> {
+                batch_invoke(caller, calls_ptr, calls_len).map_err(|e| e.into())
+            },
+        );
+
         let host_drop_node = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -920,6 +1009,18 @@ impl WasmiModule {
             },
         );
 
+        let host_emit_metric = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             name_ptr: u32,
+             name_len: u32,
+             value_ptr: u32,
+             value_len: u32|
+             -> Result<(), Trap> {
+                emit_metric(caller, name_ptr, name_len, value_ptr, value_len).map_err(|e| e.into())
+            },
+        );
+
         let host_panic = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -962,6 +1063,13 @@ impl WasmiModule {
         linker_define!(linker, FEE_BALANCE_FUNCTION_NAME, host_fee_balance);
         linker_define!(linker, GLOBALIZE_FUNCTION_NAME, host_globalize_object);
         linker_define!(linker, GET_OBJECT_INFO_FUNCTION_NAME, host_get_object_info);
+        linker_define!(
+            linker,
+            GET_RESERVATION_ADDRESS_FUNCTION_NAME,
+            host_get_reservation_address
+        );
+        linker_define!(linker, GET_METADATA_FUNCTION_NAME, host_get_metadata);
+        linker_define!(linker, BATCH_INVOKE_FUNCTION_NAME, host_batch_invoke);
         linker_define!(linker, DROP_OBJECT_FUNCTION_NAME, host_drop_node);
         linker_define!(linker, ACTOR_OPEN_FIELD_FUNCTION_NAME, host_lock_field);
         linker_define!(
@@ -1032,6 +1140,7 @@ impl WasmiModule {
         );
         linker_define!(linker, EMIT_EVENT_FUNCTION_NAME, host_emit_event);
         linker_define!(linker, EMIT_LOG_FUNCTION_NAME, host_emit_log);
+        linker_define!(linker, EMIT_METRIC_FUNCTION_NAME, host_emit_metric);
         linker_define!(linker, PANIC_FUNCTION_NAME, host_panic);
         linker_define!(
             linker,
@@ -4,6 +4,7 @@ use crate::types::*;
 use crate::vm::wasm::*;
 use radix_engine_interface::api::field_lock_api::LockFlags;
 use radix_engine_interface::api::object_api::ObjectModuleId;
+use radix_engine_interface::api::BatchCall;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::resource::AccessRule;
 use radix_engine_interface::schema::KeyValueStoreSchema;
@@ -335,6 +336,46 @@ where
         self.allocate_buffer(buffer)
     }
 
+    fn get_metadata(
+        &mut self,
+        address: Vec<u8>,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let address = GlobalAddress::try_from(address.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidNodeId)?;
+        let key = String::from_utf8(key).map_err(|_| WasmRuntimeError::InvalidString)?;
+        let buffer = self.api.get_metadata(address, &key)?;
+
+        self.allocate_buffer(buffer)
+    }
+
+    fn batch_invoke(&mut self, calls: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let calls = scrypto_decode::<Vec<BatchCall>>(&calls)
+            .map_err(WasmRuntimeError::InvalidBatchCalls)?;
+
+        let mut results = Vec::with_capacity(calls.len());
+        for call in calls {
+            let result = match call {
+                BatchCall::ActorFieldRead {
+                    object_handle,
+                    field,
+                } => {
+                    let handle =
+                        self.api
+                            .actor_open_field(object_handle, field, LockFlags::read_only())?;
+                    let substate = self.api.field_lock_read(handle)?;
+                    self.api.field_lock_release(handle)?;
+                    substate
+                }
+                BatchCall::GetMetadata { address, key } => self.api.get_metadata(address, &key)?,
+            };
+            results.push(result);
+        }
+
+        let buffer = scrypto_encode(&results).expect("Failed to encode batch results");
+        self.allocate_buffer(buffer)
+    }
+
     fn get_blueprint(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let actor = self.api.actor_get_blueprint()?;
 
@@ -396,6 +437,20 @@ where
         self.allocate_buffer(buffer)
     }
 
+    fn get_reservation_address(
+        &mut self,
+        node_id: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(node_id.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let address = self.api.get_reservation_address(&node_id)?;
+
+        let buffer = scrypto_encode(&address).expect("Failed to encode address");
+        self.allocate_buffer(buffer)
+    }
+
     fn emit_event(
         &mut self,
         event_name: Vec<u8>,
@@ -420,6 +475,18 @@ where
         Ok(())
     }
 
+    fn emit_metric(
+        &mut self,
+        name: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.emit_metric(
+            String::from_utf8(name).map_err(|_| WasmRuntimeError::InvalidString)?,
+            scrypto_decode::<Decimal>(&value).map_err(WasmRuntimeError::InvalidMetricValue)?,
+        )?;
+        Ok(())
+    }
+
     fn panic(&mut self, message: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
         self.api
             .panic(String::from_utf8(message).map_err(|_| WasmRuntimeError::InvalidString)?)?;
@@ -51,24 +51,26 @@ pub fn handle_transparent_encode(
         Data::Struct(s) => {
             let FieldsData {
                 unskipped_field_names,
+                unskipped_field_types,
                 ..
             } = process_fields_for_encode(&s.fields)?;
             if unskipped_field_names.len() != 1 {
                 return Err(Error::new(Span::call_site(), "The transparent attribute is only supported for structs with a single unskipped field."));
             }
             let field_name = &unskipped_field_names[0];
+            let field_type = &unskipped_field_types[0];
             quote! {
                 impl #impl_generics ::sbor::Encode <#custom_value_kind_generic, #encoder_generic> for #ident #ty_generics #where_clause {
                     #[inline]
                     fn encode_value_kind(&self, encoder: &mut #encoder_generic) -> Result<(), ::sbor::EncodeError> {
                         use ::sbor::{self, Encode};
-                        self.#field_name.encode_value_kind(encoder)
+                        <#field_type as ::sbor::Encode<#custom_value_kind_generic, #encoder_generic>>::encode_value_kind(&self.#field_name, encoder)
                     }
 
                     #[inline]
                     fn encode_body(&self, encoder: &mut #encoder_generic) -> Result<(), ::sbor::EncodeError> {
                         use ::sbor::{self, Encode};
-                        self.#field_name.encode_body(encoder)
+                        <#field_type as ::sbor::Encode<#custom_value_kind_generic, #encoder_generic>>::encode_body(&self.#field_name, encoder)
                     }
                 }
             }
@@ -80,6 +80,13 @@ pub fn basic_decode<T: BasicDecode>(buf: &[u8]) -> Result<T, DecodeError> {
     BasicDecoder::new(buf, BASIC_SBOR_V1_MAX_DEPTH).decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
 }
 
+/// Decode an instance of `T` from a slice, which `T` may borrow from (e.g. `&str` or `&[u8]`).
+pub fn basic_decode_borrowed<'de, T: Decode<NoCustomValueKind, BasicDecoder<'de>>>(
+    buf: &'de [u8],
+) -> Result<T, DecodeError> {
+    BasicDecoder::new(buf, BASIC_SBOR_V1_MAX_DEPTH).decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+}
+
 impl CustomValueKind for NoCustomValueKind {
     fn as_u8(&self) -> u8 {
         panic!("No custom type")
@@ -52,6 +52,20 @@ impl<X: CustomValueKind, E: Encoder<X>, T: Encode<X, E> + Categorize<X>, const N
     }
 }
 
+/// Decodes an array of bytes without allocation, by borrowing it from the payload.
+impl<'de, X: CustomValueKind, D: BorrowingDecoder<'de, X>> Decode<X, D> for &'de [u8] {
+    #[inline]
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<X>,
+    ) -> Result<Self, DecodeError> {
+        decoder.check_preloaded_value_kind(value_kind, Self::value_kind())?;
+        decoder.read_and_check_value_kind(ValueKind::U8)?;
+        let len = decoder.read_size()?;
+        decoder.read_slice_from_payload(len)
+    }
+}
+
 impl<X: CustomValueKind, D: Decoder<X>, T: Decode<X, D> + Categorize<X>, const N: usize>
     Decode<X, D> for [T; N]
 {
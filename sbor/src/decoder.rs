@@ -451,6 +451,37 @@ mod tests {
         assert_eq!(Box::new(5u8), x);
     }
 
+    #[test]
+    pub fn test_decode_borrowed_str_and_bytes() {
+        let bytes = vec![
+            12, 5, 104, 101, 108, 108, 111, // string
+            32, 7, 3, 1, 2, 3, // array of u8
+        ];
+        let mut dec = BasicDecoder::new(&bytes, 256);
+        let string = dec.decode::<&str>().unwrap();
+        let slice = dec.decode::<&[u8]>().unwrap();
+        dec.check_end().unwrap();
+
+        assert_eq!(string, "hello");
+        assert_eq!(slice, &[1u8, 2, 3]);
+        // Both are borrowed from the payload
+        assert_eq!(string.as_ptr(), bytes[2..].as_ptr());
+        assert_eq!(slice.as_ptr(), bytes[10..].as_ptr());
+    }
+
+    #[test]
+    pub fn test_decode_borrowed_bytes_of_other_element_kind_fails() {
+        let bytes = vec![32, 2, 1, 1]; // array of i8
+        let mut dec = BasicDecoder::new(&bytes, 256);
+        assert_eq!(
+            dec.decode::<&[u8]>(),
+            Err(DecodeError::UnexpectedValueKind {
+                expected: 7,
+                actual: 2
+            })
+        );
+    }
+
     #[test]
     pub fn test_decode_rc() {
         let bytes = vec![7u8, 5u8];
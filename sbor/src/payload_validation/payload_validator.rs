@@ -80,6 +80,93 @@ impl<'s, E: CustomExtension> LocatedValidationError<'s, E> {
             self.location.path_to_string(schema)
         )
     }
+
+    /// Resolves the location of the error against the schema, into an error which can be kept
+    /// and reported without it.
+    pub fn into_detailed(self, schema: &Schema<E::CustomSchema>) -> DetailedValidationError<E> {
+        let mismatch = match &self.error {
+            PayloadValidationError::TraversalError(TypedTraversalError::ValueMismatchWithType(
+                TypeMismatchError::MismatchingType {
+                    expected_type_index,
+                    actual_value_kind,
+                    ..
+                }
+                | TypeMismatchError::MismatchingChildElementType {
+                    expected_type_index,
+                    actual_value_kind,
+                    ..
+                }
+                | TypeMismatchError::MismatchingChildKeyType {
+                    expected_type_index,
+                    actual_value_kind,
+                    ..
+                }
+                | TypeMismatchError::MismatchingChildValueType {
+                    expected_type_index,
+                    actual_value_kind,
+                    ..
+                },
+            )) => Some((*expected_type_index, *actual_value_kind)),
+            _ => None,
+        };
+        let (expected_type_index, actual_value_kind) = match mismatch {
+            Some((expected_type_index, actual_value_kind)) => {
+                (Some(expected_type_index), Some(actual_value_kind))
+            }
+            None => match &self.location.current_value_info {
+                Some(info) => (Some(info.type_index), Some(info.value_kind)),
+                None => (None, None),
+            },
+        };
+
+        DetailedValidationError {
+            path: self.location.value_path(schema),
+            expected_type_name: expected_type_index.and_then(|type_index| {
+                schema
+                    .resolve_type_metadata(type_index)
+                    .and_then(|metadata| metadata.get_name_string())
+                    .or_else(|| {
+                        schema
+                            .resolve_type_kind(type_index)
+                            .map(|type_kind| format!("{:?}", type_kind))
+                    })
+            }),
+            expected_type_index,
+            actual_value_kind,
+            start_offset: self.location.start_offset,
+            end_offset: self.location.end_offset,
+            error: self.error,
+        }
+    }
+}
+
+/// A payload validation error with the full path to the invalid value, and the type it was
+/// expected to match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetailedValidationError<E: CustomExtension> {
+    pub error: PayloadValidationError<E>,
+    pub path: ValuePath,
+    /// The type the invalid value was expected to match, if it could be determined.
+    pub expected_type_index: Option<LocalTypeIndex>,
+    /// The name of the expected type, or its kind if it's unnamed.
+    pub expected_type_name: Option<String>,
+    /// The kind of the invalid value, if it could be decoded.
+    pub actual_value_kind: Option<ValueKind<E::CustomValueKind>>,
+    pub start_offset: usize,
+    pub end_offset: usize,
+}
+
+impl<E: CustomExtension> Display for DetailedValidationError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} at {}", self.error, self.path)?;
+        if let Some(expected_type_name) = &self.expected_type_name {
+            write!(f, ", expected {}", expected_type_name)?;
+        }
+        if let Some(actual_value_kind) = &self.actual_value_kind {
+            write!(f, ", found {}", actual_value_kind)?;
+        }
+        Ok(())
+    }
 }
 
 #[macro_export]
@@ -123,6 +210,20 @@ pub fn validate_payload_against_schema<'s, E: ValidatableCustomExtension<T>, T>(
     }
 }
 
+/// Validates a payload against a type of a schema, and on failure returns the full path to the
+/// invalid value (with indices and field names) and the type it was expected to match.
+///
+/// Unlike [`validate_payload_against_schema`], the error doesn't borrow the schema.
+pub fn validate_payload_against_schema_detailed<E: ValidatableCustomExtension<T>, T>(
+    payload: &[u8],
+    schema: &Schema<E::CustomSchema>,
+    index: LocalTypeIndex,
+    context: &T,
+) -> Result<(), DetailedValidationError<E>> {
+    validate_payload_against_schema::<E, T>(payload, schema, index, context)
+        .map_err(|error| error.into_detailed(schema))
+}
+
 fn validate_event_with_type<E: ValidatableCustomExtension<T>, T>(
     schema: &Schema<E::CustomSchema>,
     event: &TypedTraversalEvent<E>,
@@ -553,7 +654,7 @@ mod tests {
             &cut_off_payload,
             &schema,
             type_index,
-            &mut ()
+            &mut (),
         ) else {
             panic!("Validation did not error with too short a payload");
         };
@@ -589,7 +690,7 @@ mod tests {
             &payload,
             &schema,
             type_index,
-            &mut()
+            &mut (),
         ) else {
             panic!("Validation did not error with too short a payload");
         };
@@ -601,6 +702,69 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn mismatched_type_detailed_error_has_value_path_and_types() {
+        let value = BasicValue::Tuple {
+            fields: vec![BasicValue::U16 { value: 2 }, BasicValue::U8 { value: 1 }],
+        };
+        let payload = basic_encode(&value).unwrap();
+
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<MyStruct2, NoCustomSchema>();
+
+        let Err(error) = validate_payload_against_schema_detailed::<NoCustomExtension, _>(
+            &payload,
+            &schema,
+            type_index,
+            &(),
+        ) else {
+            panic!("Validation did not error with mismatching field types");
+        };
+
+        assert_eq!(
+            error.path,
+            ValuePath(vec![ValuePathStep::Field {
+                index: 0,
+                name: Some("field1".to_string())
+            }])
+        );
+        assert_eq!(error.expected_type_name, Some("U8".to_string()));
+        assert_eq!(error.actual_value_kind, Some(ValueKind::U16));
+        assert!(error
+            .to_string()
+            .ends_with("at $.field1, expected U8, found U16"));
+    }
+
+    #[test]
+    pub fn detailed_error_path_has_variant_and_field_names() {
+        let value = MyStruct {
+            hello: MyEnum::Option2 {
+                inner: Box::new(MyEnum::Option1(hashmap!(
+                    "test".to_string() => vec![(BasicValue::U8 { value: 1 },)]
+                ))),
+            },
+        };
+        let payload = basic_encode(&value).unwrap();
+        let cut_off_payload = &payload[0..payload.len() - 1];
+
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<MyStruct, NoCustomSchema>();
+
+        let Err(error) = validate_payload_against_schema_detailed::<NoCustomExtension, _>(
+            &cut_off_payload,
+            &schema,
+            type_index,
+            &(),
+        ) else {
+            panic!("Validation did not error with too short a payload");
+        };
+
+        assert_eq!(
+            error.path.to_string(),
+            "$.hello::Option2.inner::Option1.0[0].value[0].0"
+        );
+    }
+
     #[test]
     pub fn mismatched_enum_variant_full_location_path_is_readable() {
         let value = BasicValue::Tuple {
@@ -621,7 +785,7 @@ mod tests {
             &payload,
             &schema,
             type_index,
-            &mut()
+            &mut (),
         ) else {
             panic!("Validation did not error with too short a payload");
         };
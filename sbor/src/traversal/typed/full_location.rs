@@ -14,7 +14,118 @@ pub struct FullLocation<'s, E: CustomExtension> {
     pub error: Option<TypedTraversalError<E>>,
 }
 
+/// A step from a container value into one of its children, as part of a [`ValuePath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValuePathStep {
+    /// A field of a tuple, with its name if the type has named fields.
+    Field {
+        index: usize,
+        name: Option<String>,
+    },
+    /// A field of an enum variant, with the variant and field names if the type has them.
+    VariantField {
+        discriminator: u8,
+        variant_name: Option<String>,
+        index: usize,
+        name: Option<String>,
+    },
+    ArrayElement {
+        index: usize,
+    },
+    MapKey {
+        entry_index: usize,
+    },
+    MapValue {
+        entry_index: usize,
+    },
+}
+
+/// The path from the root of a payload to one of its values, which can be kept and displayed
+/// without the schema. EG: `$.hello::Option2.inner::Option1.0[3].value`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValuePath(pub Vec<ValuePathStep>);
+
+impl Display for ValuePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "$")?;
+        for step in &self.0 {
+            match step {
+                ValuePathStep::Field { index, name } => match name {
+                    Some(name) => write!(f, ".{}", name)?,
+                    None => write!(f, ".{}", index)?,
+                },
+                ValuePathStep::VariantField {
+                    discriminator,
+                    variant_name,
+                    index,
+                    name,
+                } => {
+                    match variant_name {
+                        Some(variant_name) => write!(f, "::{}", variant_name)?,
+                        None => write!(f, "::{{{}}}", discriminator)?,
+                    }
+                    match name {
+                        Some(name) => write!(f, ".{}", name)?,
+                        None => write!(f, ".{}", index)?,
+                    }
+                }
+                ValuePathStep::ArrayElement { index } => write!(f, "[{}]", index)?,
+                ValuePathStep::MapKey { entry_index } => write!(f, "[{}].key", entry_index)?,
+                ValuePathStep::MapValue { entry_index } => write!(f, "[{}].value", entry_index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<'s, E: CustomExtension> FullLocation<'s, E> {
+    /// Resolves the path from the root value to the current value, with field and variant names
+    /// taken from the schema where it has them.
+    pub fn value_path(&self, schema: &Schema<E::CustomSchema>) -> ValuePath {
+        let field_name = |metadata: Option<&TypeMetadata>, index: usize| {
+            metadata
+                .and_then(|m| m.get_field_names())
+                .and_then(|names| names.get(index))
+                .map(|name| name.to_string())
+        };
+        let steps = self
+            .ancestor_path
+            .iter()
+            .map(|(container_state, container_type)| {
+                let metadata = schema.resolve_type_metadata(container_type.self_type());
+                let index = container_state.current_child_index();
+                match container_state.container_header {
+                    ContainerHeader::Tuple(_) => ValuePathStep::Field {
+                        index,
+                        name: field_name(metadata, index),
+                    },
+                    ContainerHeader::EnumVariant(variant_header) => {
+                        let variant_metadata = metadata.and_then(|m| match &m.child_names {
+                            Some(ChildNames::EnumVariants(variants)) => {
+                                variants.get(&variant_header.variant)
+                            }
+                            _ => None,
+                        });
+                        ValuePathStep::VariantField {
+                            discriminator: variant_header.variant,
+                            variant_name: variant_metadata.and_then(|m| m.get_name_string()),
+                            index,
+                            name: field_name(variant_metadata, index),
+                        }
+                    }
+                    ContainerHeader::Array(_) => ValuePathStep::ArrayElement { index },
+                    ContainerHeader::Map(_) if index % 2 == 0 => ValuePathStep::MapKey {
+                        entry_index: index / 2,
+                    },
+                    ContainerHeader::Map(_) => ValuePathStep::MapValue {
+                        entry_index: index / 2,
+                    },
+                }
+            })
+            .collect();
+        ValuePath(steps)
+    }
+
     /// This enables a full path to be provided in an error message, which can have a debug such as:
     /// EG: `MyStruct.hello[0]->MyEnum::Option2{1}.inner[0]->MyEnum::Option1{0}.[0]->Map[0].Value->Array[0]->Tuple.[0]->Enum::{6}.[0]->Tuple.[1]->Map[0].Key`
     ///
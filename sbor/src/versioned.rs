@@ -0,0 +1,282 @@
+/// A model which is kept as one of its versions, and can be upgraded to its latest version.
+///
+/// Persisted models (such as substates or component states) should be versioned, so that they
+/// can evolve while the older versions still decode. See [`define_versioned!`] to create one.
+pub trait Versioned: Sized {
+    /// The latest version of the model.
+    type Latest;
+
+    /// Whether the model is at its latest version.
+    fn is_latest(&self) -> bool;
+
+    /// Wraps the latest version of the model.
+    fn from_latest(latest: Self::Latest) -> Self;
+
+    /// Upgrades the model, through each of its later versions, to its latest version.
+    fn into_latest(self) -> Self::Latest;
+
+    /// Returns the latest version of the model, or `None` if it is at an earlier version.
+    fn as_latest_ref(&self) -> Option<&Self::Latest>;
+}
+
+/// Defines a versioned model: an enum with one single-field variant per version, oldest first,
+/// which implements [`Versioned`].
+///
+/// Each version but the latest must convert into the next one with [`From`], so that an old
+/// version is upgraded through all the later ones. As the variant discriminators follow the
+/// declaration order, new versions must only ever be appended.
+///
+/// # Example
+/// ```
+/// use sbor::*;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+/// pub struct CounterV1 {
+///     count: u32,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+/// pub struct CounterV2 {
+///     count: u64,
+///     step: u64,
+/// }
+///
+/// impl From<CounterV1> for CounterV2 {
+///     fn from(counter: CounterV1) -> Self {
+///         Self {
+///             count: counter.count.into(),
+///             step: 1,
+///         }
+///     }
+/// }
+///
+/// define_versioned! {
+///     #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+///     pub enum VersionedCounter {
+///         V1(CounterV1),
+///         V2(CounterV2),
+///     }
+/// }
+///
+/// let counter = VersionedCounter::V1(CounterV1 { count: 5 });
+/// assert_eq!(counter.into_latest(), CounterV2 { count: 5, step: 1 });
+/// ```
+#[macro_export]
+macro_rules! define_versioned {
+    // Finds the latest version, which is the last one
+    (
+        @impl $name:ident;
+        [$($versions:tt)+];
+        $first:ident, $($rest:ident),+
+    ) => {
+        $crate::define_versioned!(@impl $name; [$($versions)+]; $($rest),+);
+    };
+    (
+        @impl $name:ident;
+        [$($version:ident($version_type:ty)),+];
+        $latest:ident
+    ) => {
+        impl $crate::Versioned for $name {
+            type Latest = $crate::define_versioned!(@latest_type $($version_type),+);
+
+            fn is_latest(&self) -> bool {
+                matches!(self, $name::$latest(_))
+            }
+
+            fn from_latest(latest: Self::Latest) -> Self {
+                $name::$latest(latest)
+            }
+
+            #[allow(unreachable_code, unused_assignments)]
+            fn into_latest(self) -> Self::Latest {
+                let mut value = self;
+                loop {
+                    value = $crate::define_versioned!(@upgrade value; $name; []; $($version),+);
+                }
+            }
+
+            fn as_latest_ref(&self) -> Option<&Self::Latest> {
+                match self {
+                    $name::$latest(latest) => Some(latest),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    // Builds the match which upgrades a value by one version, or returns it if it is the latest
+    (
+        @upgrade $value:ident; $name:ident; [$($arms:tt)*];
+        $from:ident, $to:ident $(, $rest:ident)*
+    ) => {
+        $crate::define_versioned!(
+            @upgrade $value; $name; [$($arms)* $name::$from(from) => $name::$to(from.into()),];
+            $to $(, $rest)*
+        )
+    };
+    (
+        @upgrade $value:ident; $name:ident; [$($arms:tt)*];
+        $latest:ident
+    ) => {
+        match $value {
+            $($arms)*
+            $name::$latest(latest) => return latest,
+        }
+    };
+
+    (@latest_type $first:ty, $($rest:ty),+) => {
+        $crate::define_versioned!(@latest_type $($rest),+)
+    };
+    (@latest_type $latest:ty) => {
+        $latest
+    };
+
+    (
+        $(#[$attributes:meta])*
+        $vis:vis enum $name:ident {
+            $($version:ident($version_type:ty)),+
+            $(,)?
+        }
+    ) => {
+        $(#[$attributes])*
+        $vis enum $name {
+            $($version($version_type)),+
+        }
+
+        $crate::define_versioned!(
+            @impl $name;
+            [$($version($version_type)),+];
+            $($version),+
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust::prelude::*;
+    use crate::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+    struct StateV1 {
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+    struct StateV2 {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+    struct StateV3 {
+        names: Vec<String>,
+        count: u64,
+    }
+
+    impl From<StateV1> for StateV2 {
+        fn from(state: StateV1) -> Self {
+            Self {
+                name: state.name,
+                count: 0,
+            }
+        }
+    }
+
+    impl From<StateV2> for StateV3 {
+        fn from(state: StateV2) -> Self {
+            Self {
+                names: vec![state.name],
+                count: state.count.into(),
+            }
+        }
+    }
+
+    define_versioned! {
+        #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+        enum VersionedState {
+            V1(StateV1),
+            V2(StateV2),
+            V3(StateV3),
+        }
+    }
+
+    define_versioned! {
+        #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+        enum VersionedSingleState {
+            V1(StateV1),
+        }
+    }
+
+    #[test]
+    fn test_earlier_versions_are_upgraded_through_the_chain() {
+        let v1 = VersionedState::V1(StateV1 {
+            name: "a".to_string(),
+        });
+        let v2 = VersionedState::V2(StateV2 {
+            name: "b".to_string(),
+            count: 2,
+        });
+
+        assert!(!v1.is_latest());
+        assert_eq!(v1.as_latest_ref(), None);
+        assert_eq!(
+            v1.into_latest(),
+            StateV3 {
+                names: vec!["a".to_string()],
+                count: 0,
+            }
+        );
+        assert_eq!(
+            v2.into_latest(),
+            StateV3 {
+                names: vec!["b".to_string()],
+                count: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_latest_version_is_kept() {
+        let latest = StateV3 {
+            names: vec!["c".to_string()],
+            count: 3,
+        };
+
+        let versioned = VersionedState::from_latest(latest.clone());
+
+        assert_eq!(versioned, VersionedState::V3(latest.clone()));
+        assert!(versioned.is_latest());
+        assert_eq!(versioned.as_latest_ref(), Some(&latest));
+        assert_eq!(versioned.into_latest(), latest);
+
+        let single = VersionedSingleState::V1(StateV1 {
+            name: "d".to_string(),
+        });
+        assert!(single.is_latest());
+        assert_eq!(
+            single.into_latest(),
+            StateV1 {
+                name: "d".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_earlier_version_payload_decodes_and_upgrades() {
+        let payload = basic_encode(&VersionedState::V1(StateV1 {
+            name: "e".to_string(),
+        }))
+        .unwrap();
+
+        let decoded: VersionedState = basic_decode(&payload).unwrap();
+
+        assert_eq!(
+            decoded.into_latest(),
+            StateV3 {
+                names: vec!["e".to_string()],
+                count: 0,
+            }
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote};
 use radix_engine_common::address::AddressBech32Decoder;
 use syn::parse::{Parse, ParseStream, Parser};
@@ -109,6 +109,33 @@ pub fn replace_macros_in_body(block: &mut Block, dependency_exprs: &mut Vec<Expr
     Ok(())
 }
 
+/// Parses the content of an `address!` macro, which is either a Bech32 string literal, or an
+/// `env!("VAR")` reading it from an environment variable at compile time.
+///
+/// Returns the address along with the environment variable name, if any.
+fn parse_address_literal(tokens: TokenStream) -> Result<(String, Option<LitStr>)> {
+    match parse2::<Expr>(tokens)? {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) => Ok((lit_str.value(), None)),
+        Expr::Macro(m) if m.mac.path.is_ident("env") => {
+            let env_var: LitStr = m.mac.parse_body()?;
+            let address = std::env::var(env_var.value()).map_err(|_| {
+                Error::new(
+                    env_var.span(),
+                    format!("Environment variable {} is not set", env_var.value()),
+                )
+            })?;
+            Ok((address, Some(env_var)))
+        }
+        expr => Err(Error::new(
+            expr.span(),
+            "Expected a Bech32 address string literal or `env!(\"..\")`",
+        )),
+    }
+}
+
 pub fn replace_macros(expr: &mut Expr, dependency_exprs: &mut Vec<Expr>) -> Result<()> {
     match expr {
         Expr::Macro(m) => {
@@ -223,6 +250,14 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     let bp_items = &mut bp_impl.items;
     let bp_name = bp_ident.to_string();
 
+    let is_pausable = blueprint
+        .attributes
+        .iter()
+        .any(|attribute| attribute.path.is_ident("pausable"));
+    if is_pausable {
+        bp_items.extend(pausable_methods());
+    }
+
     trace!("Blueprint name: {}", bp_name);
 
     let impl_ident_matches = match &*bp_impl.self_ty {
@@ -304,17 +339,23 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                             continue;
                         }
 
-                        let tokens = &m.mac.tokens;
-                        let lit_str: LitStr = parse_quote!( #tokens );
+                        let (address, env_var) = parse_address_literal(m.mac.tokens.clone())?;
 
                         let (_hrp, _entity_type, address) =
-                            AddressBech32Decoder::validate_and_decode_ignore_hrp(
-                                lit_str.value().as_str(),
-                            )
-                            .unwrap();
+                            AddressBech32Decoder::validate_and_decode_ignore_hrp(address.as_str())
+                                .unwrap();
 
-                        let expr: Expr = parse_quote! {
-                            #ty :: new_or_panic([ #(#address),* ])
+                        let expr: Expr = match env_var {
+                            // Keep the `env!` so that the package is rebuilt when the variable changes
+                            Some(env_var) => parse_quote! {
+                                {
+                                    const _: &str = env!(#env_var);
+                                    #ty :: new_or_panic([ #(#address),* ])
+                                }
+                            },
+                            None => parse_quote! {
+                                #ty :: new_or_panic([ #(#address),* ])
+                            },
                         };
                         item.expr = Box::new(expr);
                     }
@@ -351,10 +392,26 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                 .eq(&Ident::new("enable_method_auth", Span::call_site()))
         });
         if let Some(method_auth_index) = method_auth_index {
-            let auth_macro = macro_statements.remove(method_auth_index);
+            let mut auth_macro = macro_statements.remove(method_auth_index);
+            if is_pausable {
+                auth_macro.mac.tokens = add_pausable_method_auth(auth_macro.mac.tokens);
+            }
             quote! {
                 #auth_macro
             }
+        } else if is_pausable {
+            // Other methods stay public, as they would be without method auth
+            let public_methods = method_idents
+                .iter()
+                .filter(|ident| !PAUSABLE_METHODS.contains(&ident.to_string().as_str()));
+            let method_auth = add_pausable_method_auth(quote! {
+                methods {
+                    #(#public_methods => PUBLIC;)*
+                }
+            });
+            quote! {
+                enable_method_auth! { #method_auth }
+            }
         } else {
             quote! {
                 fn method_auth_template() -> scrypto::blueprints::package::MethodAuthTemplate {
@@ -504,6 +561,12 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         // Getting the event types if the event attribute is defined for the type
         let (event_type_names, event_type_paths) = {
             let mut paths = std::collections::BTreeMap::<String, Path>::new();
+            if is_pausable {
+                for event in ["PausedEvent", "UnpausedEvent"] {
+                    let ident = format_ident!("{}", event);
+                    paths.insert(event.to_owned(), parse_quote! { scrypto::prelude::#ident });
+                }
+            }
             for attribute in blueprint.attributes {
                 if attribute.path.is_ident("events") {
                     let events_inner = parse2::<ast::EventsInner>(attribute.tokens)?;
@@ -700,6 +763,81 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     Ok(output)
 }
 
+/// The methods added to a `#[pausable]` blueprint.
+const PAUSABLE_METHODS: [&str; 3] = ["pause", "unpause", "is_paused"];
+
+/// Generates the methods added to a `#[pausable]` blueprint, which act on its `pausable` field.
+fn pausable_methods() -> Vec<ImplItem> {
+    vec![
+        parse_quote! {
+            pub fn pause(&mut self) {
+                self.pausable.pause();
+            }
+        },
+        parse_quote! {
+            pub fn unpause(&mut self) {
+                self.pausable.unpause();
+            }
+        },
+        parse_quote! {
+            pub fn is_paused(&self) -> bool {
+                self.pausable.is_paused()
+            }
+        },
+    ]
+}
+
+/// Adds the `pauser` role, updatable by the owner, and the accessibility of the methods of a
+/// `#[pausable]` blueprint to the content of an `enable_method_auth!` invocation.
+fn add_pausable_method_auth(tokens: TokenStream) -> TokenStream {
+    let mut has_roles = false;
+    let mut previous_ident = None;
+    let mut trees = Vec::new();
+    for tree in tokens {
+        match tree {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                let addition = match previous_ident.as_deref() {
+                    Some("roles") => {
+                        has_roles = true;
+                        quote! { pauser => updatable_by: [OWNER]; }
+                    }
+                    Some("methods") => quote! {
+                        pause => restrict_to: [pauser];
+                        unpause => restrict_to: [pauser];
+                        is_paused => PUBLIC;
+                    },
+                    _ => quote! {},
+                };
+                let mut stream = group.stream();
+                stream.extend(addition);
+                let mut extended_group = Group::new(Delimiter::Brace, stream);
+                extended_group.set_span(group.span());
+                trees.push(TokenTree::Group(extended_group));
+                previous_ident = None;
+            }
+            TokenTree::Ident(ident) => {
+                previous_ident = Some(ident.to_string());
+                trees.push(TokenTree::Ident(ident));
+            }
+            tree => {
+                previous_ident = None;
+                trees.push(tree);
+            }
+        }
+    }
+
+    let mut output = TokenStream::new();
+    if !has_roles {
+        output.extend(quote! {
+            roles {
+                pauser => updatable_by: [OWNER];
+            },
+        });
+    }
+    output.extend(trees);
+    output
+}
+
 fn generate_methods_struct(method_idents: Vec<Ident>) -> TokenStream {
     let method_names: Vec<String> = method_idents.iter().map(|i| i.to_string()).collect();
 
@@ -1270,6 +1408,74 @@ mod tests {
         assert_eq!(a.to_string(), b.to_string());
     }
 
+    #[test]
+    fn test_address_literal_parsing() {
+        let (address, env_var) = parse_address_literal(
+            TokenStream::from_str(
+                r#""package_rdx1pkgxxxxxxxxxfaucetxxxxxxxxx000034355863xxxxxxxxxfaucet""#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            address,
+            "package_rdx1pkgxxxxxxxxxfaucetxxxxxxxxx000034355863xxxxxxxxxfaucet"
+        );
+        assert!(env_var.is_none());
+
+        let result = parse_address_literal(
+            TokenStream::from_str(r#"env!("SCRYPTO_TEST_ADDRESS_WHICH_IS_NOT_SET")"#).unwrap(),
+        );
+        assert!(matches!(result, Err(_)));
+    }
+
+    #[test]
+    fn test_pausable_method_auth_is_added() {
+        let with_roles = add_pausable_method_auth(quote! {
+            roles {
+                admin => updatable_by: [OWNER];
+            },
+            methods {
+                x => restrict_to: [admin];
+            }
+        });
+        assert_code_eq(
+            with_roles,
+            quote! {
+                roles {
+                    admin => updatable_by: [OWNER];
+                    pauser => updatable_by: [OWNER];
+                },
+                methods {
+                    x => restrict_to: [admin];
+                    pause => restrict_to: [pauser];
+                    unpause => restrict_to: [pauser];
+                    is_paused => PUBLIC;
+                }
+            },
+        );
+
+        let without_roles = add_pausable_method_auth(quote! {
+            methods {
+                x => PUBLIC;
+            }
+        });
+        assert_code_eq(
+            without_roles,
+            quote! {
+                roles {
+                    pauser => updatable_by: [OWNER];
+                },
+                methods {
+                    x => PUBLIC;
+                    pause => restrict_to: [pauser];
+                    unpause => restrict_to: [pauser];
+                    is_paused => PUBLIC;
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_inconsistent_names_should_fail() {
         let input = TokenStream::from_str("struct A {} impl B { }").unwrap();
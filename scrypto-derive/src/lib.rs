@@ -40,6 +40,11 @@ use proc_macro::TokenStream;
 ///     }
 /// }
 /// ```
+///
+/// A blueprint marked with `#[pausable]` (below `#[blueprint]`) must keep a `Pausable` in a
+/// `pausable` field. It gets the `pause` and `unpause` methods, restricted to a `pauser` role
+/// which the owner can update, and a public `is_paused` method; the methods guarded with
+/// `when_not_paused!(self)` then panic while the component is paused.
 #[proc_macro_attribute]
 pub fn blueprint(_: TokenStream, input: TokenStream) -> TokenStream {
     blueprint::handle_blueprint(proc_macro2::TokenStream::from(input))
@@ -49,6 +54,10 @@ pub fn blueprint(_: TokenStream, input: TokenStream) -> TokenStream {
 
 /// Derive code that describe a non-fungible data structure.
 ///
+/// Each `#[mutable]` field also gets a constant named after it, e.g. `MyStruct::FIELD_2`, with
+/// which the field can be updated through `ResourceManager::update_non_fungible_data_field`.
+/// Updating an immutable field, or with data of the wrong type, then fails to compile.
+///
 /// # Example
 ///
 /// ```ignore
@@ -60,6 +69,9 @@ pub fn blueprint(_: TokenStream, input: TokenStream) -> TokenStream {
 ///     #[mutable]
 ///     pub field_2: String,
 /// }
+///
+/// // Where `resource_manager` manages non-fungibles of `MyStruct`
+/// resource_manager.update_non_fungible_data_field(&id, MyStruct::FIELD_2, "new".to_owned());
 /// ```
 #[proc_macro_derive(NonFungibleData, attributes(mutable))]
 pub fn non_fungible_data(input: TokenStream) -> TokenStream {
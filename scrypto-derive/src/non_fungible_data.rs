@@ -57,7 +57,9 @@ pub fn is_mutable(f: &Field) -> bool {
 pub fn handle_non_fungible_data(input: TokenStream) -> Result<TokenStream> {
     trace!("handle_non_fungible_data() starts");
 
-    let DeriveInput { ident, data, .. } = parse2(input)?;
+    let DeriveInput {
+        ident, vis, data, ..
+    } = parse2(input)?;
     trace!("Processing: {}", ident.to_string());
 
     let output = match data {
@@ -68,11 +70,31 @@ pub fn handle_non_fungible_data(input: TokenStream) -> Result<TokenStream> {
                     .filter(|f| is_mutable(f))
                     .filter_map(|f| f.ident.as_ref().map(|f| f.to_string()))
                     .collect();
+                // One constant per mutable field, so that updates can be checked at compile time
+                let mutable_field_constants = named
+                    .iter()
+                    .filter(|f| is_mutable(f))
+                    .filter_map(|f| f.ident.as_ref().map(|ident| (ident, &f.ty)))
+                    .map(|(field_ident, field_type)| {
+                        let field_name = field_ident.to_string();
+                        let field_name = field_name.trim_start_matches("r#");
+                        let constant_ident =
+                            Ident::new(&field_name.to_uppercase(), field_ident.span());
+                        quote! {
+                            #vis const #constant_ident: ::scrypto::prelude::MutableNonFungibleDataField<#ident, #field_type> =
+                                ::scrypto::prelude::MutableNonFungibleDataField::new(#field_name);
+                        }
+                    });
 
                 quote! {
                     impl ::scrypto::prelude::NonFungibleData for #ident {
                         const MUTABLE_FIELDS: &'static [&'static str] = &[#mutable_fields];
                     }
+
+                    #[allow(dead_code)]
+                    impl #ident {
+                        #(#mutable_field_constants)*
+                    }
                 }
             }
             syn::Fields::Unnamed(_) => {
@@ -128,6 +150,12 @@ mod tests {
                 impl ::scrypto::prelude::NonFungibleData for MyStruct {
                     const MUTABLE_FIELDS : & 'static [& 'static str] = & ["field_2"] ;
                 }
+
+                #[allow(dead_code)]
+                impl MyStruct {
+                    pub const FIELD_2: ::scrypto::prelude::MutableNonFungibleDataField<MyStruct, String> =
+                        ::scrypto::prelude::MutableNonFungibleDataField::new("field_2");
+                }
             },
         );
     }
@@ -14,3 +14,9 @@ fn test_non_fungible_data() {
     let mutable_fields = Sample::MUTABLE_FIELDS;
     assert_eq!(mutable_fields, ["b"]);
 }
+
+#[test]
+fn test_mutable_non_fungible_data_fields() {
+    let field: MutableNonFungibleDataField<Sample, String> = Sample::B;
+    assert_eq!(field.name(), "b");
+}
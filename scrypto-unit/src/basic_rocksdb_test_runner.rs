@@ -1,6 +1,7 @@
 use radix_engine::system::bootstrap::Bootstrapper;
 use radix_engine::transaction::{
-    execute_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt, TransactionResult,
+    execute_preview_at_version, execute_transaction, ExecutionConfig, FeeReserveConfig,
+    PreviewError, TransactionReceipt, TransactionResult,
 };
 use radix_engine::types::*;
 use radix_engine::vm::wasm::DefaultWasmEngine;
@@ -244,6 +245,22 @@ impl BasicRocksdbTestRunner {
         transaction_receipt
     }
 
+    pub fn preview_at_version(
+        &mut self,
+        preview_intent: PreviewIntentV1,
+        network: &NetworkDefinition,
+        state_version: u64,
+    ) -> Result<TransactionReceipt, PreviewError> {
+        execute_preview_at_version(
+            &self.substate_db,
+            state_version,
+            &self.scrypto_interpreter,
+            network,
+            preview_intent,
+            self.trace,
+        )
+    }
+
     pub fn create_fungible_resource(
         &mut self,
         amount: Decimal,
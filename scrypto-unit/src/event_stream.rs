@@ -0,0 +1,130 @@
+use radix_engine::types::*;
+
+/// The application events of a committed transaction, in emission order, as
+/// `(emitter, event name, value)` tuples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventStream {
+    pub events: Vec<(Emitter, String, ScryptoValue)>,
+}
+
+impl EventStream {
+    pub fn new(events: Vec<(Emitter, String, ScryptoValue)>) -> Self {
+        Self { events }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(Emitter, String, ScryptoValue)> {
+        self.events.iter()
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.events
+            .iter()
+            .map(|(_, name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Asserts that the events match the given matchers one-to-one, in order.
+    ///
+    /// See [`expect_sequence!`](crate::expect_sequence) for building the matchers.
+    pub fn assert_sequence(&self, matchers: Vec<EventMatcher>) {
+        if self.events.len() != matchers.len() {
+            panic!(
+                "Expected {} events {:?} but got {} events {:?}",
+                matchers.len(),
+                matchers.iter().map(|m| m.description).collect::<Vec<_>>(),
+                self.events.len(),
+                self.names()
+            );
+        }
+        for (index, (matcher, (_, name, value))) in
+            matchers.iter().zip(self.events.iter()).enumerate()
+        {
+            if !matcher.matches(name, value) {
+                panic!(
+                    "Event {} does not match `{}`: {} {:?}",
+                    index, matcher.description, name, value
+                );
+            }
+        }
+    }
+}
+
+impl IntoIterator for EventStream {
+    type Item = (Emitter, String, ScryptoValue);
+    type IntoIter = sbor::rust::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.into_iter()
+    }
+}
+
+/// Matches a single event of a given type, by name and by a predicate on the decoded event.
+pub struct EventMatcher<'a> {
+    description: &'static str,
+    event_name: String,
+    predicate: Box<dyn Fn(&ScryptoValue) -> bool + 'a>,
+}
+
+impl<'a> EventMatcher<'a> {
+    pub fn new<T: ScryptoDecode + ScryptoDescribe>(
+        description: &'static str,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> Self {
+        let (local_type_index, schema) =
+            sbor::generate_full_schema_from_single_type::<T, ScryptoCustomSchema>();
+        let event_name = schema
+            .resolve_type_metadata(local_type_index)
+            .unwrap()
+            .get_name_string()
+            .unwrap();
+        Self {
+            description,
+            event_name,
+            predicate: Box::new(move |value| {
+                scrypto_encode(value)
+                    .ok()
+                    .and_then(|payload| scrypto_decode::<T>(&payload).ok())
+                    .map_or(false, |event| predicate(&event))
+            }),
+        }
+    }
+
+    pub fn matches(&self, event_name: &str, value: &ScryptoValue) -> bool {
+        self.event_name == event_name && (self.predicate)(value)
+    }
+}
+
+/// Builds the [`EventMatcher`]s for [`EventStream::assert_sequence`], from a list of event
+/// patterns with an optional guard.
+///
+/// Each pattern must start with the name of the event type in scope.
+///
+/// # Example
+/// ```ignore
+/// use scrypto_unit::*;
+///
+/// test_runner.event_stream(&receipt).assert_sequence(expect_sequence![
+///     LockFeeEvent { .. },
+///     WithdrawResourceEvent::Amount(amount) if *amount == dec!("1"),
+///     DepositResourceEvent::Amount(..),
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! expect_sequence {
+    ($($ty:ident $(:: $variant:ident)? $({ $($fields:tt)* })? $(( $($elements:tt)* ))? $(if $guard:expr)?),* $(,)?) => {
+        vec![$(
+            $crate::EventMatcher::new::<$ty>(
+                stringify!($ty $(:: $variant)? $({ $($fields)* })? $(( $($elements)* ))? $(if $guard)?),
+                |event: &$ty| matches!(event, $ty $(:: $variant)? $({ $($fields)* })? $(( $($elements)* ))? $(if $guard)?),
+            )
+        ),*]
+    };
+}
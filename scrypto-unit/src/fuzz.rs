@@ -0,0 +1,1024 @@
+//! A property-testing harness which calls the functions and methods of a published package with
+//! random, but schema-valid, arguments and shrinks the manifests which break a property.
+//!
+//! Example usage:
+//! ```ignore
+//! let mut fuzzer = ManifestFuzzer::new(&test_runner, package_address, 1234)
+//!     .with_account(account, NonFungibleGlobalId::from_public_key(&public_key))
+//!     .with_bucket_resource(XRD, dec!(100));
+//! let result = fuzzer.run(&mut test_runner, 100, |_, receipt| {
+//!     if receipt.is_commit_success() {
+//!         Ok(())
+//!     } else {
+//!         Err(format!("{:?}", receipt))
+//!     }
+//! });
+//! ```
+
+use crate::{TestRunner, TestRunnerSnapshot};
+use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
+use radix_engine::transaction::{TransactionReceipt, TransactionResult};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::package::{BlueprintDefinition, TypePointer};
+use radix_engine_interface::schema::RefTypes;
+use radix_engine_store_interface::db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper};
+use transaction::prelude::*;
+
+/// The maximum nesting depth of generated values, which bounds recursive types.
+const MAX_VALUE_DEPTH: usize = 8;
+/// The maximum number of elements generated for an array or map, beyond its minimum length.
+const MAX_EXTRA_ELEMENTS: u32 = 4;
+/// The maximum number of characters generated for a string, beyond its minimum length.
+const MAX_EXTRA_CHARACTERS: u32 = 16;
+/// The number of targets tried for each call before giving up on it.
+const MAX_GENERATION_ATTEMPTS: usize = 16;
+/// The maximum number of replays spent on shrinking a failing manifest.
+const MAX_SHRINK_REPLAYS: usize = 256;
+
+const STRING_CHARACTERS: &[u8] =
+    b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 _-";
+
+/// A single call of a fuzzed manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzCall {
+    pub blueprint_name: String,
+    /// The component called, or `None` for a function call.
+    pub component_address: Option<ComponentAddress>,
+    pub ident: String,
+    /// The arguments, where each bucket refers to the entry of `buckets` with the same index.
+    pub args: ManifestValue,
+    /// The resources and amounts withdrawn from the fuzzer account for the bucket arguments.
+    pub buckets: Vec<(ResourceAddress, Decimal)>,
+}
+
+/// A manifest which broke the property under test, shrunk to a minimal reproduction.
+#[derive(Debug, Clone)]
+pub struct FuzzFailure {
+    /// The iteration of the fuzz run at which the property was first broken.
+    pub iteration: usize,
+    pub calls: Vec<FuzzCall>,
+    pub manifest: TransactionManifestV1,
+    /// The error returned by the property for the shrunk manifest.
+    pub message: String,
+}
+
+/// Generates random manifests calling into a package, using the blueprint schemas to produce
+/// typed arguments.
+///
+/// Each run starts out calling only the functions of the package. Components and resources
+/// created by successful transactions are remembered, so that later manifests can call the
+/// methods of the components and pass both as references.
+pub struct ManifestFuzzer {
+    package_address: PackageAddress,
+    blueprints: IndexMap<String, BlueprintDefinition>,
+    schemas: IndexMap<Hash, ScryptoSchema>,
+    components: Vec<ComponentAddress>,
+    references: Vec<GlobalAddress>,
+    account: Option<(ComponentAddress, NonFungibleGlobalId)>,
+    bucket_resources: Vec<(ResourceAddress, Decimal)>,
+    excluded_idents: BTreeSet<String>,
+    max_calls: usize,
+    rng: FuzzRng,
+}
+
+impl ManifestFuzzer {
+    pub fn new(test_runner: &TestRunner, package_address: PackageAddress, seed: u64) -> Self {
+        let blueprints = test_runner
+            .get_package_blueprint_definitions(&package_address)
+            .into_iter()
+            .map(|(key, definition)| (key.blueprint, definition))
+            .collect();
+
+        Self {
+            package_address,
+            blueprints,
+            schemas: test_runner.get_package_scrypto_schemas(&package_address),
+            components: Vec::new(),
+            references: vec![package_address.into()],
+            account: None,
+            bucket_resources: Vec::new(),
+            excluded_idents: BTreeSet::new(),
+            max_calls: 4,
+            rng: FuzzRng(seed),
+        }
+    }
+
+    /// Adds an existing component of the package whose methods should be called.
+    pub fn with_component(mut self, component_address: ComponentAddress) -> Self {
+        self.components.push(component_address);
+        self.references.push(component_address.into());
+        self
+    }
+
+    /// Adds an address which may be passed wherever a reference is expected.
+    pub fn with_reference(mut self, address: impl Into<GlobalAddress>) -> Self {
+        self.references.push(address.into());
+        self
+    }
+
+    /// Signs every manifest with the given badge and deposits everything left on the worktop
+    /// into the account at the end. The account also funds bucket arguments.
+    pub fn with_account(
+        mut self,
+        account_address: ComponentAddress,
+        signer: NonFungibleGlobalId,
+    ) -> Self {
+        self.account = Some((account_address, signer));
+        self.references.push(account_address.into());
+        self
+    }
+
+    /// Allows bucket arguments of the given resource, with whole amounts of up to `max_amount`
+    /// withdrawn from the account.
+    pub fn with_bucket_resource(
+        mut self,
+        resource_address: ResourceAddress,
+        max_amount: Decimal,
+    ) -> Self {
+        self.bucket_resources.push((resource_address, max_amount));
+        self.references.push(resource_address.into());
+        self
+    }
+
+    /// Never calls functions or methods with the given name.
+    pub fn without_call(mut self, ident: &str) -> Self {
+        self.excluded_idents.insert(ident.to_string());
+        self
+    }
+
+    /// Sets the maximum number of calls in each manifest.
+    pub fn with_max_calls(mut self, max_calls: usize) -> Self {
+        self.max_calls = max_calls.max(1);
+        self
+    }
+
+    /// Executes `iterations` random manifests, checking the property after each one.
+    ///
+    /// The property is given the test runner after the manifest has been executed and its
+    /// receipt, and returns an error message if it does not hold. The first failing manifest is
+    /// shrunk by replaying simpler variants against the state it was executed on, and the test
+    /// runner is left in the state after executing the shrunk manifest.
+    pub fn run<F>(
+        &mut self,
+        test_runner: &mut TestRunner,
+        iterations: usize,
+        mut check: F,
+    ) -> Result<(), FuzzFailure>
+    where
+        F: FnMut(&mut TestRunner, &TransactionReceipt) -> Result<(), String>,
+    {
+        for iteration in 0..iterations {
+            let calls = self.generate_calls(test_runner);
+            if calls.is_empty() {
+                continue;
+            }
+
+            let snapshot = test_runner.create_snapshot();
+            let receipt =
+                test_runner.execute_manifest(self.build_manifest(&calls), self.initial_proofs());
+            if let Err(message) = check(test_runner, &receipt) {
+                let (calls, message) =
+                    self.shrink(test_runner, &snapshot, calls, message, &mut check);
+                return Err(FuzzFailure {
+                    iteration,
+                    manifest: self.build_manifest(&calls),
+                    calls,
+                    message,
+                });
+            }
+
+            if let TransactionResult::Commit(commit) = &receipt.transaction_result {
+                if commit.outcome.is_success() {
+                    for component_address in commit.new_component_addresses() {
+                        self.components.push(*component_address);
+                        self.references.push((*component_address).into());
+                    }
+                    for resource_address in commit.new_resource_addresses() {
+                        self.references.push((*resource_address).into());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds the manifest for the given calls, withdrawing the bucket arguments from the account
+    /// before each call.
+    pub fn build_manifest(&self, calls: &[FuzzCall]) -> TransactionManifestV1 {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for call in calls {
+            let mut buckets = Vec::new();
+            for (resource_address, amount) in &call.buckets {
+                let (account_address, _) = self
+                    .account
+                    .as_ref()
+                    .expect("Bucket arguments require an account");
+                let (next, symbols) = builder
+                    .withdraw_from_account(*account_address, *resource_address, *amount)
+                    .add_instruction_advanced(InstructionV1::TakeFromWorktop {
+                        resource_address: *resource_address,
+                        amount: *amount,
+                    });
+                builder = next;
+                buckets.push(symbols.new_bucket.unwrap());
+            }
+
+            let args = replace_buckets(&call.args, &buckets);
+            builder = match call.component_address {
+                Some(component_address) => {
+                    builder.call_method_raw(component_address, &call.ident, args)
+                }
+                None => builder.call_function_raw(
+                    self.package_address,
+                    &call.blueprint_name,
+                    &call.ident,
+                    args,
+                ),
+            };
+        }
+        if let Some((account_address, _)) = &self.account {
+            builder = builder.try_deposit_batch_or_abort(*account_address);
+        }
+        builder.build()
+    }
+
+    fn initial_proofs(&self) -> Vec<NonFungibleGlobalId> {
+        self.account
+            .iter()
+            .map(|(_, signer)| signer.clone())
+            .collect()
+    }
+
+    fn generate_calls(&mut self, test_runner: &TestRunner) -> Vec<FuzzCall> {
+        let targets = self.targets(test_runner);
+        if targets.is_empty() {
+            return Vec::new();
+        }
+
+        let mut calls = Vec::new();
+        let count = 1 + self.rng.below(self.max_calls);
+        for _ in 0..count {
+            for _ in 0..MAX_GENERATION_ATTEMPTS {
+                let (component_address, blueprint_name, ident) =
+                    targets[self.rng.below(targets.len())].clone();
+                if let Some(call) =
+                    self.generate_call(test_runner, component_address, blueprint_name, ident)
+                {
+                    calls.push(call);
+                    break;
+                }
+            }
+        }
+        calls
+    }
+
+    /// Lists the callable functions, and the methods of the known components.
+    fn targets(&self, test_runner: &TestRunner) -> Vec<(Option<ComponentAddress>, String, String)> {
+        let mut component_blueprints: Vec<(ComponentAddress, String)> = Vec::new();
+        for component_address in &self.components {
+            if let Some(blueprint_id) = blueprint_id_of(test_runner, &(*component_address).into()) {
+                if blueprint_id.package_address == self.package_address {
+                    component_blueprints.push((*component_address, blueprint_id.blueprint_name));
+                }
+            }
+        }
+
+        let mut targets = Vec::new();
+        for (blueprint_name, definition) in &self.blueprints {
+            for (ident, function) in &definition.interface.functions {
+                if self.excluded_idents.contains(ident) {
+                    continue;
+                }
+                match &function.receiver {
+                    None => targets.push((None, blueprint_name.clone(), ident.clone())),
+                    Some(receiver) if receiver.ref_types == RefTypes::NORMAL => {
+                        for (component_address, _) in component_blueprints
+                            .iter()
+                            .filter(|(_, name)| name == blueprint_name)
+                        {
+                            targets.push((
+                                Some(*component_address),
+                                blueprint_name.clone(),
+                                ident.clone(),
+                            ));
+                        }
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        targets
+    }
+
+    fn generate_call(
+        &mut self,
+        test_runner: &TestRunner,
+        component_address: Option<ComponentAddress>,
+        blueprint_name: String,
+        ident: String,
+    ) -> Option<FuzzCall> {
+        let (schema_hash, type_index) = self.input_type(&blueprint_name, &ident)?;
+        let mut generator = ValueGenerator {
+            rng: &mut self.rng,
+            test_runner,
+            schema: self.schemas.get(&schema_hash)?,
+            references: &self.references,
+            bucket_resources: if self.account.is_some() {
+                &self.bucket_resources
+            } else {
+                &[]
+            },
+            buckets: Vec::new(),
+        };
+        let args = generator.generate(type_index, 0)?;
+        let buckets = generator.buckets;
+
+        Some(FuzzCall {
+            blueprint_name,
+            component_address,
+            ident,
+            args,
+            buckets,
+        })
+    }
+
+    fn input_type(&self, blueprint_name: &str, ident: &str) -> Option<(Hash, LocalTypeIndex)> {
+        let function = self
+            .blueprints
+            .get(blueprint_name)?
+            .interface
+            .functions
+            .get(ident)?;
+        match function.input {
+            TypePointer::Package(schema_hash, type_index) => Some((schema_hash, type_index)),
+            TypePointer::Instance(_) => None,
+        }
+    }
+
+    /// Shrinks a failing manifest by first removing calls and then simplifying arguments, for as
+    /// long as the property keeps failing.
+    fn shrink<F>(
+        &self,
+        test_runner: &mut TestRunner,
+        snapshot: &TestRunnerSnapshot,
+        mut calls: Vec<FuzzCall>,
+        mut message: String,
+        check: &mut F,
+    ) -> (Vec<FuzzCall>, String)
+    where
+        F: FnMut(&mut TestRunner, &TransactionReceipt) -> Result<(), String>,
+    {
+        let mut replays = 0;
+        let mut progressed = true;
+        while progressed && replays < MAX_SHRINK_REPLAYS {
+            progressed = false;
+
+            let mut i = 0;
+            while i < calls.len() && calls.len() > 1 && replays < MAX_SHRINK_REPLAYS {
+                let mut candidate = calls.clone();
+                candidate.remove(i);
+                replays += 1;
+                match self.replay(test_runner, snapshot, &candidate, check) {
+                    Some(m) => {
+                        calls = candidate;
+                        message = m;
+                        progressed = true;
+                    }
+                    None => i += 1,
+                }
+            }
+
+            for i in 0..calls.len() {
+                let simplified_args =
+                    match self.input_type(&calls[i].blueprint_name, &calls[i].ident) {
+                        Some((schema_hash, type_index)) => match self.schemas.get(&schema_hash) {
+                            Some(schema) => simplify(schema, type_index, &calls[i].args),
+                            None => Vec::new(),
+                        },
+                        None => Vec::new(),
+                    };
+                for args in simplified_args {
+                    if replays >= MAX_SHRINK_REPLAYS {
+                        break;
+                    }
+                    let mut candidate = calls.clone();
+                    candidate[i].args = args;
+                    replays += 1;
+                    if let Some(m) = self.replay(test_runner, snapshot, &candidate, check) {
+                        calls = candidate;
+                        message = m;
+                        progressed = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Leave the test runner in the state of the shrunk failure
+        if let Some(m) = self.replay(test_runner, snapshot, &calls, check) {
+            message = m;
+        }
+        (calls, message)
+    }
+
+    /// Executes the calls against the snapshot, returning the error if the property fails.
+    fn replay<F>(
+        &self,
+        test_runner: &mut TestRunner,
+        snapshot: &TestRunnerSnapshot,
+        calls: &[FuzzCall],
+        check: &mut F,
+    ) -> Option<String>
+    where
+        F: FnMut(&mut TestRunner, &TransactionReceipt) -> Result<(), String>,
+    {
+        test_runner.restore_snapshot(snapshot.clone());
+        let receipt =
+            test_runner.execute_manifest(self.build_manifest(calls), self.initial_proofs());
+        check(test_runner, &receipt).err()
+    }
+}
+
+/// A SplitMix64 generator, so that fuzz runs can be reproduced from their seed.
+struct FuzzRng(u64);
+
+impl FuzzRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+    }
+
+    /// Returns a number in `0..n`, where `n` must not be zero.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+macro_rules! integer_bounds {
+    ($validation:expr, $variant:ident, $t:ty) => {
+        match $validation {
+            Some(TypeValidation::$variant(v)) => {
+                (v.min.unwrap_or(<$t>::MIN), v.max.unwrap_or(<$t>::MAX))
+            }
+            _ => (<$t>::MIN, <$t>::MAX),
+        }
+    };
+}
+
+macro_rules! random_integer {
+    ($rng:expr, $validation:expr, $variant:ident, $t:ty) => {{
+        let (min, max) = integer_bounds!($validation, $variant, $t);
+        // Favour the bounds and small numbers, which are where most edge cases are
+        let value = match $rng.next_u64() % 4 {
+            0 => min,
+            1 => max,
+            2 => ($rng.next_u64() % 16) as $t,
+            _ => $rng.next_u128() as $t,
+        };
+        ManifestValue::$variant {
+            value: if value < min || value > max {
+                min
+            } else {
+                value
+            },
+        }
+    }};
+}
+
+macro_rules! simplify_integer {
+    ($value:expr, $validation:expr, $variant:ident, $t:ty) => {{
+        let (min, max) = integer_bounds!($validation, $variant, $t);
+        let simplest = (0 as $t).clamp(min, max);
+        if *$value != simplest {
+            vec![ManifestValue::$variant { value: simplest }]
+        } else {
+            vec![]
+        }
+    }};
+}
+
+struct ValueGenerator<'a> {
+    rng: &'a mut FuzzRng,
+    test_runner: &'a TestRunner,
+    schema: &'a ScryptoSchema,
+    references: &'a [GlobalAddress],
+    bucket_resources: &'a [(ResourceAddress, Decimal)],
+    buckets: Vec<(ResourceAddress, Decimal)>,
+}
+
+impl<'a> ValueGenerator<'a> {
+    /// Generates a value of the given type, or `None` if it contains a type which the fuzzer
+    /// can't produce, such as proofs or internal references.
+    fn generate(&mut self, type_index: LocalTypeIndex, depth: usize) -> Option<ManifestValue> {
+        if depth > MAX_VALUE_DEPTH {
+            return None;
+        }
+
+        let schema = self.schema;
+        let validation = schema.resolve_type_validation(type_index);
+        let value = match schema.resolve_type_kind(type_index)? {
+            TypeKind::Any => return None,
+            TypeKind::Bool => ManifestValue::Bool {
+                value: self.rng.next_u64() % 2 == 0,
+            },
+            TypeKind::I8 => random_integer!(self.rng, validation, I8, i8),
+            TypeKind::I16 => random_integer!(self.rng, validation, I16, i16),
+            TypeKind::I32 => random_integer!(self.rng, validation, I32, i32),
+            TypeKind::I64 => random_integer!(self.rng, validation, I64, i64),
+            TypeKind::I128 => random_integer!(self.rng, validation, I128, i128),
+            TypeKind::U8 => random_integer!(self.rng, validation, U8, u8),
+            TypeKind::U16 => random_integer!(self.rng, validation, U16, u16),
+            TypeKind::U32 => random_integer!(self.rng, validation, U32, u32),
+            TypeKind::U64 => random_integer!(self.rng, validation, U64, u64),
+            TypeKind::U128 => random_integer!(self.rng, validation, U128, u128),
+            TypeKind::String => {
+                let length = self.length(validation, MAX_EXTRA_CHARACTERS);
+                ManifestValue::String {
+                    value: (0..length)
+                        .map(|_| STRING_CHARACTERS[self.rng.below(STRING_CHARACTERS.len())] as char)
+                        .collect(),
+                }
+            }
+            TypeKind::Array { element_type } => {
+                let length = self.length(validation, MAX_EXTRA_ELEMENTS);
+                let mut elements = Vec::new();
+                for _ in 0..length {
+                    elements.push(self.generate(*element_type, depth + 1)?);
+                }
+                ManifestValue::Array {
+                    element_value_kind: value_kind(schema, *element_type)?,
+                    elements,
+                }
+            }
+            TypeKind::Tuple { field_types } => ManifestValue::Tuple {
+                fields: self.generate_fields(field_types, depth)?,
+            },
+            TypeKind::Enum { variants } => {
+                if variants.is_empty() {
+                    return None;
+                }
+                let (discriminator, field_types) =
+                    variants.iter().nth(self.rng.below(variants.len()))?;
+                ManifestValue::Enum {
+                    discriminator: *discriminator,
+                    fields: self.generate_fields(field_types, depth)?,
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let length = self.length(validation, MAX_EXTRA_ELEMENTS);
+                let mut entries: Vec<(ManifestValue, ManifestValue)> = Vec::new();
+                for _ in 0..length {
+                    let key = self.generate(*key_type, depth + 1)?;
+                    if entries.iter().any(|(existing, _)| *existing == key) {
+                        continue;
+                    }
+                    let value = self.generate(*value_type, depth + 1)?;
+                    entries.push((key, value));
+                }
+                ManifestValue::Map {
+                    key_value_kind: value_kind(schema, *key_type)?,
+                    value_value_kind: value_kind(schema, *value_type)?,
+                    entries,
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+                let candidates: Vec<GlobalAddress> = self
+                    .references
+                    .iter()
+                    .filter(|address| self.reference_matches(address, validation))
+                    .cloned()
+                    .collect();
+                if candidates.is_empty() {
+                    return None;
+                }
+                let address = candidates[self.rng.below(candidates.len())];
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Address(ManifestAddress::Static(
+                        *address.as_node_id(),
+                    )),
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+                let candidates: Vec<(ResourceAddress, Decimal)> = self
+                    .bucket_resources
+                    .iter()
+                    .filter(|(resource_address, _)| bucket_matches(resource_address, validation))
+                    .cloned()
+                    .collect();
+                if candidates.is_empty() {
+                    return None;
+                }
+                let (resource_address, max_amount) = candidates[self.rng.below(candidates.len())];
+                let amount = (max_amount * Decimal::from(self.rng.below(101) as i64) / 100).floor();
+                self.buckets.push((resource_address, amount));
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Bucket(ManifestBucket(
+                        (self.buckets.len() - 1) as u32,
+                    )),
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => {
+                let value = match self.rng.next_u64() % 4 {
+                    0 => Decimal::ZERO,
+                    1 => Decimal::MAX,
+                    2 => Decimal::from(self.rng.below(16) as i64 - 8),
+                    _ => Decimal::from(self.rng.below(100_000) as i64) / Decimal::from(100),
+                };
+                to_manifest_value(&value).ok()?
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+                to_manifest_value(&PreciseDecimal::from(self.rng.below(16) as i64 - 8)).ok()?
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+                to_manifest_value(&NonFungibleLocalId::integer(self.rng.below(8) as u64 + 1))
+                    .ok()?
+            }
+        };
+        Some(value)
+    }
+
+    fn generate_fields(
+        &mut self,
+        field_types: &[LocalTypeIndex],
+        depth: usize,
+    ) -> Option<Vec<ManifestValue>> {
+        let mut fields = Vec::new();
+        for field_type in field_types {
+            fields.push(self.generate(*field_type, depth + 1)?);
+        }
+        Some(fields)
+    }
+
+    fn length(
+        &mut self,
+        validation: Option<&TypeValidation<ScryptoCustomTypeValidation>>,
+        max_extra: u32,
+    ) -> u32 {
+        let (min, max) = match validation {
+            Some(TypeValidation::String(v))
+            | Some(TypeValidation::Array(v))
+            | Some(TypeValidation::Map(v)) => (v.min.unwrap_or(0), v.max.unwrap_or(u32::MAX)),
+            _ => (0, u32::MAX),
+        };
+        let max = max.min(min.saturating_add(max_extra));
+        min + self.rng.below((max - min) as usize + 1) as u32
+    }
+
+    fn reference_matches(
+        &self,
+        address: &GlobalAddress,
+        validation: Option<&TypeValidation<ScryptoCustomTypeValidation>>,
+    ) -> bool {
+        let node_id = address.as_node_id();
+        match validation {
+            Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(validation))) => {
+                match validation {
+                    ReferenceValidation::IsGlobal => true,
+                    ReferenceValidation::IsGlobalPackage => node_id.is_global_package(),
+                    ReferenceValidation::IsGlobalComponent => node_id.is_global_component(),
+                    ReferenceValidation::IsGlobalResourceManager => {
+                        node_id.is_global_resource_manager()
+                    }
+                    ReferenceValidation::IsGlobalTyped(package_address, blueprint_name) => {
+                        match blueprint_id_of(self.test_runner, address) {
+                            Some(blueprint_id) => {
+                                package_address
+                                    .map(|p| p == blueprint_id.package_address)
+                                    .unwrap_or(true)
+                                    && *blueprint_name == blueprint_id.blueprint_name
+                            }
+                            None => false,
+                        }
+                    }
+                    ReferenceValidation::IsInternal | ReferenceValidation::IsInternalTyped(..) => {
+                        false
+                    }
+                }
+            }
+            _ => true,
+        }
+    }
+}
+
+fn bucket_matches(
+    resource_address: &ResourceAddress,
+    validation: Option<&TypeValidation<ScryptoCustomTypeValidation>>,
+) -> bool {
+    let node_id = resource_address.as_node_id();
+    match validation {
+        Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Own(validation))) => {
+            match validation {
+                OwnValidation::IsBucket => true,
+                OwnValidation::IsTypedObject(_, blueprint_name) => match blueprint_name.as_str() {
+                    FUNGIBLE_BUCKET_BLUEPRINT => node_id.is_global_fungible_resource_manager(),
+                    NON_FUNGIBLE_BUCKET_BLUEPRINT => {
+                        node_id.is_global_non_fungible_resource_manager()
+                    }
+                    _ => false,
+                },
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn blueprint_id_of(test_runner: &TestRunner, address: &GlobalAddress) -> Option<BlueprintId> {
+    let type_info = test_runner
+        .substate_db()
+        .get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+            address.as_node_id(),
+            TYPE_INFO_FIELD_PARTITION,
+            &TypeInfoField::TypeInfo.into(),
+        )?;
+    match type_info {
+        TypeInfoSubstate::Object(ObjectInfo { blueprint_id, .. }) => Some(blueprint_id),
+        _ => None,
+    }
+}
+
+/// The value kind of the given type, as needed for the element kinds of arrays and maps.
+fn value_kind(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> Option<ManifestValueKind> {
+    let value_kind = match schema.resolve_type_kind(type_index)? {
+        TypeKind::Any => return None,
+        TypeKind::Bool => ValueKind::Bool,
+        TypeKind::I8 => ValueKind::I8,
+        TypeKind::I16 => ValueKind::I16,
+        TypeKind::I32 => ValueKind::I32,
+        TypeKind::I64 => ValueKind::I64,
+        TypeKind::I128 => ValueKind::I128,
+        TypeKind::U8 => ValueKind::U8,
+        TypeKind::U16 => ValueKind::U16,
+        TypeKind::U32 => ValueKind::U32,
+        TypeKind::U64 => ValueKind::U64,
+        TypeKind::U128 => ValueKind::U128,
+        TypeKind::String => ValueKind::String,
+        TypeKind::Array { .. } => ValueKind::Array,
+        TypeKind::Tuple { .. } => ValueKind::Tuple,
+        TypeKind::Enum { .. } => ValueKind::Enum,
+        TypeKind::Map { .. } => ValueKind::Map,
+        TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+            ValueKind::Custom(ManifestCustomValueKind::Address)
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+            ValueKind::Custom(ManifestCustomValueKind::Bucket)
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => {
+            ValueKind::Custom(ManifestCustomValueKind::Decimal)
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+            ValueKind::Custom(ManifestCustomValueKind::PreciseDecimal)
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+            ValueKind::Custom(ManifestCustomValueKind::NonFungibleLocalId)
+        }
+    };
+    Some(value_kind)
+}
+
+/// Returns the variants of a value which are one step simpler and still valid for its type.
+///
+/// Elements holding buckets are never removed, as the withdrawn bucket would then be left over.
+fn simplify(
+    schema: &ScryptoSchema,
+    type_index: LocalTypeIndex,
+    value: &ManifestValue,
+) -> Vec<ManifestValue> {
+    let kind = match schema.resolve_type_kind(type_index) {
+        Some(kind) => kind,
+        None => return Vec::new(),
+    };
+    let validation = schema.resolve_type_validation(type_index);
+    let min_length = match validation {
+        Some(TypeValidation::String(v))
+        | Some(TypeValidation::Array(v))
+        | Some(TypeValidation::Map(v)) => v.min.unwrap_or(0) as usize,
+        _ => 0,
+    };
+
+    match (kind, value) {
+        (TypeKind::Bool, ManifestValue::Bool { value: true }) => {
+            vec![ManifestValue::Bool { value: false }]
+        }
+        (TypeKind::I8, ManifestValue::I8 { value }) => simplify_integer!(value, validation, I8, i8),
+        (TypeKind::I16, ManifestValue::I16 { value }) => {
+            simplify_integer!(value, validation, I16, i16)
+        }
+        (TypeKind::I32, ManifestValue::I32 { value }) => {
+            simplify_integer!(value, validation, I32, i32)
+        }
+        (TypeKind::I64, ManifestValue::I64 { value }) => {
+            simplify_integer!(value, validation, I64, i64)
+        }
+        (TypeKind::I128, ManifestValue::I128 { value }) => {
+            simplify_integer!(value, validation, I128, i128)
+        }
+        (TypeKind::U8, ManifestValue::U8 { value }) => simplify_integer!(value, validation, U8, u8),
+        (TypeKind::U16, ManifestValue::U16 { value }) => {
+            simplify_integer!(value, validation, U16, u16)
+        }
+        (TypeKind::U32, ManifestValue::U32 { value }) => {
+            simplify_integer!(value, validation, U32, u32)
+        }
+        (TypeKind::U64, ManifestValue::U64 { value }) => {
+            simplify_integer!(value, validation, U64, u64)
+        }
+        (TypeKind::U128, ManifestValue::U128 { value }) => {
+            simplify_integer!(value, validation, U128, u128)
+        }
+        (TypeKind::String, ManifestValue::String { value }) if value.len() > min_length => {
+            vec![ManifestValue::String {
+                value: value[..min_length].to_string(),
+            }]
+        }
+        (
+            TypeKind::Array { element_type },
+            ManifestValue::Array {
+                element_value_kind,
+                elements,
+            },
+        ) => {
+            let mut simplified = Vec::new();
+            for i in 0..elements.len() {
+                if elements.len() > min_length && !contains_bucket(&elements[i]) {
+                    let mut elements = elements.clone();
+                    elements.remove(i);
+                    simplified.push(ManifestValue::Array {
+                        element_value_kind: *element_value_kind,
+                        elements,
+                    });
+                }
+            }
+            for i in 0..elements.len() {
+                for element in simplify(schema, *element_type, &elements[i]) {
+                    let mut elements = elements.clone();
+                    elements[i] = element;
+                    simplified.push(ManifestValue::Array {
+                        element_value_kind: *element_value_kind,
+                        elements,
+                    });
+                }
+            }
+            simplified
+        }
+        (
+            TypeKind::Map { value_type, .. },
+            ManifestValue::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            },
+        ) => {
+            let mut simplified = Vec::new();
+            for i in 0..entries.len() {
+                if entries.len() > min_length
+                    && !contains_bucket(&entries[i].0)
+                    && !contains_bucket(&entries[i].1)
+                {
+                    let mut entries = entries.clone();
+                    entries.remove(i);
+                    simplified.push(ManifestValue::Map {
+                        key_value_kind: *key_value_kind,
+                        value_value_kind: *value_value_kind,
+                        entries,
+                    });
+                }
+            }
+            for i in 0..entries.len() {
+                for value in simplify(schema, *value_type, &entries[i].1) {
+                    let mut entries = entries.clone();
+                    entries[i].1 = value;
+                    simplified.push(ManifestValue::Map {
+                        key_value_kind: *key_value_kind,
+                        value_value_kind: *value_value_kind,
+                        entries,
+                    });
+                }
+            }
+            simplified
+        }
+        (TypeKind::Tuple { field_types }, ManifestValue::Tuple { fields }) => {
+            simplify_fields(schema, field_types, fields)
+                .into_iter()
+                .map(|fields| ManifestValue::Tuple { fields })
+                .collect()
+        }
+        (
+            TypeKind::Enum { variants },
+            ManifestValue::Enum {
+                discriminator,
+                fields,
+            },
+        ) => match variants.get(discriminator) {
+            Some(field_types) => simplify_fields(schema, field_types, fields)
+                .into_iter()
+                .map(|fields| ManifestValue::Enum {
+                    discriminator: *discriminator,
+                    fields,
+                })
+                .collect(),
+            None => Vec::new(),
+        },
+        (
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal),
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Decimal(_),
+            },
+        ) => {
+            let zero = to_manifest_value(&Decimal::ZERO).unwrap();
+            if *value != zero {
+                vec![zero]
+            } else {
+                vec![]
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn simplify_fields(
+    schema: &ScryptoSchema,
+    field_types: &[LocalTypeIndex],
+    fields: &[ManifestValue],
+) -> Vec<Vec<ManifestValue>> {
+    let mut simplified = Vec::new();
+    for (i, (field_type, field)) in field_types.iter().zip(fields).enumerate() {
+        for field in simplify(schema, *field_type, field) {
+            let mut fields = fields.to_vec();
+            fields[i] = field;
+            simplified.push(fields);
+        }
+    }
+    simplified
+}
+
+fn contains_bucket(value: &ManifestValue) -> bool {
+    match value {
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Bucket(_),
+        } => true,
+        ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => {
+            fields.iter().any(contains_bucket)
+        }
+        ManifestValue::Array { elements, .. } => elements.iter().any(contains_bucket),
+        ManifestValue::Map { entries, .. } => entries
+            .iter()
+            .any(|(key, value)| contains_bucket(key) || contains_bucket(value)),
+        _ => false,
+    }
+}
+
+/// Maps the bucket placeholders of generated arguments to the buckets of the manifest.
+fn replace_buckets(value: &ManifestValue, buckets: &[ManifestBucket]) -> ManifestValue {
+    match value {
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Bucket(ManifestBucket(index)),
+        } => ManifestValue::Custom {
+            value: ManifestCustomValue::Bucket(buckets[*index as usize]),
+        },
+        ManifestValue::Enum {
+            discriminator,
+            fields,
+        } => ManifestValue::Enum {
+            discriminator: *discriminator,
+            fields: fields.iter().map(|f| replace_buckets(f, buckets)).collect(),
+        },
+        ManifestValue::Tuple { fields } => ManifestValue::Tuple {
+            fields: fields.iter().map(|f| replace_buckets(f, buckets)).collect(),
+        },
+        ManifestValue::Array {
+            element_value_kind,
+            elements,
+        } => ManifestValue::Array {
+            element_value_kind: *element_value_kind,
+            elements: elements
+                .iter()
+                .map(|e| replace_buckets(e, buckets))
+                .collect(),
+        },
+        ManifestValue::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        } => ManifestValue::Map {
+            key_value_kind: *key_value_kind,
+            value_value_kind: *value_value_kind,
+            entries: entries
+                .iter()
+                .map(|(k, v)| (replace_buckets(k, buckets), replace_buckets(v, buckets)))
+                .collect(),
+        },
+        _ => value.clone(),
+    }
+}
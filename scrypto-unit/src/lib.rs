@@ -1,9 +1,19 @@
 #[cfg(feature = "rocksdb")]
 mod basic_rocksdb_test_runner;
+mod event_stream;
+pub mod fuzz;
+mod macros;
+#[cfg(feature = "std")]
+mod snapshot;
 mod test_runner;
+mod test_runner_cluster;
 mod utils;
 
 pub use crate::utils::*;
 #[cfg(feature = "rocksdb")]
 pub use basic_rocksdb_test_runner::*;
+pub use event_stream::*;
+#[cfg(feature = "std")]
+pub use snapshot::*;
 pub use test_runner::*;
+pub use test_runner_cluster::*;
@@ -0,0 +1,31 @@
+/// Includes the prebuilt artifacts of a Scrypto package, i.e. its WASM code and its manifest
+/// encoded package definition, as a `(Vec<u8>, PackageDefinition)` tuple.
+///
+/// Unlike [`Compile::compile`](crate::Compile::compile), this macro will NOT compile the package,
+/// so that packages can be built once and reused across test binaries.
+///
+/// The path is relative to the current file, and is either the path of the artifacts without their
+/// `.wasm`/`.rpd` extension, or the directory of the artifacts along with their binary name.
+///
+/// # Example
+/// ```ignore
+/// use scrypto_unit::*;
+///
+/// let (code, definition) = include_package!("../artifacts/radiswap");
+/// let (code, definition) = include_package!("../artifacts", "radiswap");
+/// ```
+#[macro_export]
+macro_rules! include_package {
+    ($artifacts_path: expr) => {
+        $crate::decode_prebuilt_package(
+            include_bytes!(concat!($artifacts_path, ".wasm")),
+            include_bytes!(concat!($artifacts_path, ".rpd")),
+        )
+    };
+    ($artifacts_dir: expr, $bin_name: expr) => {
+        $crate::decode_prebuilt_package(
+            include_bytes!(concat!($artifacts_dir, "/", $bin_name, ".wasm")),
+            include_bytes!(concat!($artifacts_dir, "/", $bin_name, ".rpd")),
+        )
+    };
+}
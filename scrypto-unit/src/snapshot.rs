@@ -0,0 +1,48 @@
+use radix_engine::transaction::{ReceiptDiffOptions, ReceiptSnapshot, TransactionReceipt};
+use std::path::Path;
+
+/// The environment variable which, when set to `1`, causes snapshot assertions to overwrite the
+/// stored snapshots with the current receipts instead of comparing against them.
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &'static str = "UPDATE_SNAPSHOTS";
+
+/// Asserts that a receipt matches the snapshot stored in the given golden file, ignoring volatile
+/// fields and allowing costs to differ within the default tolerance.
+///
+/// The path is relative to the manifest directory of the crate containing the test. If the file
+/// does not exist yet, or if `UPDATE_SNAPSHOTS=1` is set, the snapshot is written instead.
+#[macro_export]
+macro_rules! assert_receipt_matches_snapshot {
+    ($receipt:expr, $path:expr) => {
+        $crate::assert_receipt_matches_snapshot_file(
+            &$receipt,
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+    };
+}
+
+pub fn assert_receipt_matches_snapshot_file<P: AsRef<Path>>(receipt: &TransactionReceipt, path: P) {
+    let path = path.as_ref();
+    let actual = ReceiptSnapshot::from_receipt(receipt);
+
+    let update = std::env::var(UPDATE_SNAPSHOTS_ENV_VAR).map_or(false, |value| value == "1");
+    if update || !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(path, actual.to_string()).unwrap();
+        return;
+    }
+
+    let text = std::fs::read_to_string(path).unwrap();
+    let expected = ReceiptSnapshot::parse(&text)
+        .unwrap_or_else(|| panic!("Malformed receipt snapshot: {}", path.display()));
+    let diff = expected.diff(&actual, &ReceiptDiffOptions::default());
+    if !diff.is_empty() {
+        panic!(
+            "Receipt does not match snapshot {} (run with {}=1 to update):\n{}",
+            path.display(),
+            UPDATE_SNAPSHOTS_ENV_VAR,
+            diff
+        );
+    }
+}
@@ -5,8 +5,9 @@ use std::process::Command;
 
 use radix_engine::blueprints::consensus_manager::*;
 use radix_engine::errors::*;
+use radix_engine::system::blueprint_mocks::BlueprintMocks;
 use radix_engine::system::bootstrap::*;
-use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
+use radix_engine::system::event_schema_resolver::EventSchemaResolver;
 use radix_engine::system::system::KeyValueEntrySubstate;
 use radix_engine::transaction::{
     execute_preview, execute_transaction, CommitResult, ExecutionConfig, FeeReserveConfig,
@@ -20,11 +21,18 @@ use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::node_modules::royalty::ComponentRoyaltySubstate;
 use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::account::{
+    AccountBalancesInput, AccountGetResourcePreferenceInput, AccountNonFungibleIdsOfInput,
+    ResourceDepositRule, ACCOUNT_BALANCES_IDENT, ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT,
+    ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT,
+};
 use radix_engine_interface::blueprints::consensus_manager::{
     ConsensusManagerConfig, ConsensusManagerGetCurrentEpochInput,
     ConsensusManagerGetCurrentTimeInput, ConsensusManagerNextRoundInput, EpochChangeCondition,
-    LeaderProposalHistory, TimePrecision, CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
+    LeaderProposalHistory, TimePrecision, ValidatorFeeChangeRequest,
+    ValidatorGetPendingFeeChangeInput, CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
     CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT, CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+    VALIDATOR_GET_PENDING_FEE_CHANGE_IDENT,
 };
 use radix_engine_interface::blueprints::package::{
     BlueprintDefinitionInit, PackageDefinition, PackagePublishWasmAdvancedManifestInput,
@@ -36,7 +44,9 @@ use radix_engine_interface::math::Decimal;
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_interface::time::Instant;
 use radix_engine_interface::{dec, freeze_roles, rule};
-use radix_engine_queries::query::{ResourceAccounter, StateTreeTraverser, VaultFinder};
+use radix_engine_queries::query::{
+    OwnedNodeFinder, ResourceAccounter, StateTreeTraverser, VaultFinder,
+};
 use radix_engine_queries::typed_substate_layout::{
     BlueprintDefinition, BlueprintVersionKey, PACKAGE_BLUEPRINTS_PARTITION_OFFSET,
 };
@@ -48,24 +58,41 @@ use radix_engine_store_interface::{
     },
     interface::{CommittableSubstateDatabase, DatabaseUpdate, DatabaseUpdates},
 };
+use radix_engine_stores::fault_injecting_db::{
+    FaultInjectingSubstateDatabase, SubstateDatabaseFaults,
+};
 use radix_engine_stores::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
 use radix_engine_stores::hash_tree::{put_at_next_version, SubstateHashChange};
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use scrypto::prelude::*;
+use transaction::errors::EpochWindowValidationError;
 use transaction::prelude::*;
 use transaction::signing::secp256k1::Secp256k1PrivateKey;
 use transaction::validation::{
-    NotarizedTransactionValidator, TransactionValidator, ValidationConfig,
+    validate_header_epoch_window, NotarizedTransactionValidator, TransactionValidator,
+    ValidationConfig,
 };
 
+use crate::EventStream;
+
 pub struct Compile;
 
 impl Compile {
     pub fn compile<P: AsRef<Path>>(package_dir: P) -> (Vec<u8>, PackageDefinition) {
+        Self::compile_with_env_vars(package_dir, &BTreeMap::new())
+    }
+
+    /// Compiles a package with the given environment variables set, e.g. the addresses of the
+    /// packages it depends on, see [`package_address_env_var`].
+    pub fn compile_with_env_vars<P: AsRef<Path>>(
+        package_dir: P,
+        env_vars: &BTreeMap<String, String>,
+    ) -> (Vec<u8>, PackageDefinition) {
         // Build
         let status = Command::new("cargo")
             .current_dir(package_dir.as_ref())
             .args(["build", "--target", "wasm32-unknown-unknown", "--release"])
+            .envs(env_vars)
             .status()
             .unwrap();
         if !status.success() {
@@ -109,6 +136,81 @@ impl Compile {
         (code, definition)
     }
 
+    /// Lists the packages of a cargo workspace, ordered so that each package comes after the
+    /// packages it depends on.
+    ///
+    /// A package declares which other workspace packages it depends on, i.e. calls at a known
+    /// address, in its `Cargo.toml`:
+    ///
+    /// ```toml
+    /// [package.metadata.scrypto]
+    /// dependencies = ["token"]
+    /// ```
+    ///
+    /// These are not cargo dependencies, as linking another package would export its functions too.
+    pub fn workspace_packages<P: AsRef<Path>>(workspace_dir: P) -> Vec<WorkspacePackage> {
+        let mut manifest_path = workspace_dir.as_ref().to_owned();
+        manifest_path.push("Cargo.toml");
+        let metadata = cargo_metadata(&manifest_path);
+
+        let mut packages: Vec<WorkspacePackage> = metadata["packages"]
+            .as_array()
+            .expect("Failed to parse packages from cargo metadata")
+            .iter()
+            .map(|package| {
+                let name = package["name"].as_str().unwrap().to_owned();
+                let dir = PathBuf::from(package["manifest_path"].as_str().unwrap())
+                    .parent()
+                    .unwrap()
+                    .to_owned();
+                let dependencies = package["metadata"]["scrypto"]["dependencies"]
+                    .as_array()
+                    .map(|dependencies| {
+                        dependencies
+                            .iter()
+                            .map(|d| d.as_str().unwrap().to_owned())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                WorkspacePackage {
+                    name,
+                    dir,
+                    dependencies,
+                }
+            })
+            .collect();
+        for package in &packages {
+            for dependency in &package.dependencies {
+                if !packages.iter().any(|p| p.name.eq(dependency)) {
+                    panic!(
+                        "Package {} depends on {}, which is not in the workspace",
+                        package.name, dependency
+                    );
+                }
+            }
+        }
+
+        let mut ordered: Vec<WorkspacePackage> = Vec::new();
+        while !packages.is_empty() {
+            let next = packages
+                .iter()
+                .position(|package| {
+                    package
+                        .dependencies
+                        .iter()
+                        .all(|dependency| ordered.iter().any(|p| p.name.eq(dependency)))
+                })
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Cyclic dependencies between packages {:?}",
+                        packages.iter().map(|p| &p.name).collect::<Vec<_>>()
+                    )
+                });
+            ordered.push(packages.remove(next));
+        }
+        ordered
+    }
+
     // Naive pattern matching to find the crate name.
     fn extract_crate_name(mut content: &str) -> Result<String, ()> {
         let idx = content.find("name").ok_or(())?;
@@ -122,6 +224,33 @@ impl Compile {
     }
 }
 
+/// A package of a cargo workspace, see [`Compile::workspace_packages`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspacePackage {
+    pub name: String,
+    pub dir: PathBuf,
+    /// The names of the workspace packages this package depends on.
+    pub dependencies: Vec<String>,
+}
+
+/// The environment variable through which the address of a published workspace package is
+/// passed to the packages depending on it, e.g. `SCRYPTO_PACKAGE_ADDRESS_MY_TOKEN` for `my-token`.
+///
+/// A dependent package reads it at compile time with
+/// `const MY_TOKEN: PackageAddress = address!(env!("SCRYPTO_PACKAGE_ADDRESS_MY_TOKEN"));`.
+pub fn package_address_env_var(package_name: &str) -> String {
+    format!(
+        "SCRYPTO_PACKAGE_ADDRESS_{}",
+        package_name.to_uppercase().replace("-", "_")
+    )
+}
+
+/// Decodes the artifacts of a package built ahead of time, see [`include_package!`].
+pub fn decode_prebuilt_package(code: &[u8], definition: &[u8]) -> (Vec<u8>, PackageDefinition) {
+    let definition = manifest_decode(definition).expect("Failed to decode the package definition");
+    (code.to_vec(), definition)
+}
+
 pub struct CustomGenesis {
     pub genesis_data_chunks: Vec<GenesisDataChunk>,
     pub genesis_epoch: Epoch,
@@ -157,6 +286,24 @@ impl CustomGenesis {
             num_owner_stake_units_unlock_epochs: 2,
             num_fee_increase_delay_epochs: 4,
             validator_creation_usd_cost: *DEFAULT_VALIDATOR_USD_COST,
+            usd_price_in_xrd: Decimal::try_from(DEFAULT_USD_PRICE_IN_XRD).unwrap(),
+        }
+    }
+
+    /// Creates a genesis from the given data chunks, e.g. decoded from the output of
+    /// `resim ledger export-genesis`.
+    pub fn from_data_chunks(
+        genesis_data_chunks: Vec<GenesisDataChunk>,
+        genesis_epoch: Epoch,
+        initial_config: ConsensusManagerConfig,
+    ) -> CustomGenesis {
+        CustomGenesis {
+            genesis_data_chunks,
+            genesis_epoch,
+            initial_config,
+            initial_time_ms: 0,
+            initial_current_leader: Some(0),
+            faucet_supply: *DEFAULT_TESTING_FAUCET_SUPPLY,
         }
     }
 
@@ -238,6 +385,10 @@ pub struct TestRunnerBuilder {
     custom_genesis: Option<CustomGenesis>,
     trace: bool,
     state_hashing: bool,
+    deterministic_ruid_seed: Option<u64>,
+    invariant_checks: bool,
+    max_substate_size: Option<usize>,
+    wasm_validator_config: WasmValidatorConfigV1,
 }
 
 impl TestRunnerBuilder {
@@ -256,10 +407,42 @@ impl TestRunnerBuilder {
         self
     }
 
+    /// Derives the RUIDs generated by each transaction from the given seed and the number of
+    /// transactions executed so far, rather than from the transaction hash, so that runtime
+    /// generated non-fungible ids are stable across runs.
+    pub fn with_deterministic_ruids(mut self, seed: u64) -> Self {
+        self.deterministic_ruid_seed = Some(seed);
+        self
+    }
+
+    /// Asserts global resource invariants, such as total supply conservation, throughout every
+    /// transaction executed, panicking on the first violation.
+    pub fn with_invariant_checks(mut self) -> Self {
+        self.invariant_checks = true;
+        self
+    }
+
+    /// Lowers the maximum size of a substate value in every transaction executed, so that the
+    /// state of a blueprint which grows without bound is caught early by its tests.
+    pub fn with_max_substate_size(mut self, max_substate_size: usize) -> Self {
+        self.max_substate_size = Some(max_substate_size);
+        self
+    }
+
+    /// Validates the code of published packages against the given limits instead of the default
+    /// ones, so that adversarial code can be exercised without building very large modules.
+    pub fn with_wasm_validator_config(
+        mut self,
+        wasm_validator_config: WasmValidatorConfigV1,
+    ) -> Self {
+        self.wasm_validator_config = wasm_validator_config;
+        self
+    }
+
     pub fn build_and_get_epoch(self) -> (TestRunner, ActiveValidatorSet) {
         let scrypto_interpreter = ScryptoVm {
             wasm_engine: DefaultWasmEngine::default(),
-            wasm_validator_config: WasmValidatorConfigV1::new(),
+            wasm_validator_config: self.wasm_validator_config,
         };
         let mut substate_db = InMemorySubstateDatabase::standard();
 
@@ -295,6 +478,11 @@ impl TestRunnerBuilder {
             next_private_key,
             next_transaction_nonce,
             trace: self.trace,
+            deterministic_ruid_seed: self.deterministic_ruid_seed,
+            invariant_checks: self.invariant_checks,
+            max_substate_size: self.max_substate_size,
+            executed_transaction_count: 0,
+            blueprint_mocks: BlueprintMocks::new(),
         };
 
         let next_epoch = wrap_up_receipt
@@ -316,6 +504,11 @@ pub struct TestRunner {
     next_transaction_nonce: u32,
     trace: bool,
     state_hash_support: Option<StateHashSupport>,
+    deterministic_ruid_seed: Option<u64>,
+    invariant_checks: bool,
+    max_substate_size: Option<usize>,
+    executed_transaction_count: u64,
+    blueprint_mocks: BlueprintMocks,
 }
 
 #[derive(Clone)]
@@ -324,6 +517,7 @@ pub struct TestRunnerSnapshot {
     next_private_key: u64,
     next_transaction_nonce: u32,
     state_hash_support: Option<StateHashSupport>,
+    executed_transaction_count: u64,
 }
 
 impl TestRunner {
@@ -335,6 +529,10 @@ impl TestRunner {
             #[cfg(feature = "resource_tracker")]
             trace: false,
             state_hashing: false,
+            deterministic_ruid_seed: None,
+            invariant_checks: false,
+            max_substate_size: None,
+            wasm_validator_config: WasmValidatorConfigV1::new(),
         }
     }
 
@@ -344,6 +542,7 @@ impl TestRunner {
             next_private_key: self.next_private_key,
             next_transaction_nonce: self.next_transaction_nonce,
             state_hash_support: self.state_hash_support.clone(),
+            executed_transaction_count: self.executed_transaction_count,
         }
     }
 
@@ -352,6 +551,42 @@ impl TestRunner {
         self.next_private_key = snapshot.next_private_key;
         self.next_transaction_nonce = snapshot.next_transaction_nonce;
         self.state_hash_support = snapshot.state_hash_support;
+        self.executed_transaction_count = snapshot.executed_transaction_count;
+    }
+
+    /// Overrides the limits which the code of packages published in all subsequent transactions is
+    /// validated against.
+    pub fn set_wasm_validator_config(&mut self, wasm_validator_config: WasmValidatorConfigV1) {
+        self.scrypto_interpreter.wasm_validator_config = wasm_validator_config;
+    }
+
+    /// Overrides the maximum size of a substate value in all subsequent transactions, or restores
+    /// the default limit if `None`.
+    pub fn set_max_substate_size(&mut self, max_substate_size: Option<usize>) {
+        self.max_substate_size = max_substate_size;
+    }
+
+    /// Intercepts the calls to the given blueprint in all subsequent transactions, so that the
+    /// handler is invoked with the function or method name and input instead of the blueprint
+    /// code. The returned output is still validated against the blueprint schema.
+    pub fn mock_blueprint<F>(
+        &mut self,
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        handler: F,
+    ) where
+        F: Fn(&str, &IndexedScryptoValue) -> Result<IndexedScryptoValue, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.blueprint_mocks
+            .insert(BlueprintId::new(&package_address, blueprint_name), handler);
+    }
+
+    pub fn unmock_blueprint(&mut self, package_address: PackageAddress, blueprint_name: &str) {
+        self.blueprint_mocks
+            .remove(&BlueprintId::new(&package_address, blueprint_name));
     }
 
     pub fn faucet_component(&self) -> GlobalAddress {
@@ -484,6 +719,66 @@ impl TestRunner {
             .map_or(None, |vault_id| self.inspect_vault_balance(*vault_id))
     }
 
+    /// Queries the balances of the given resources through the `balances` method of the account,
+    /// in a preview which is not committed.
+    pub fn account_balances(
+        &mut self,
+        account_address: ComponentAddress,
+        resources: Vec<ResourceAddress>,
+    ) -> Vec<(ResourceAddress, Decimal)> {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                account_address,
+                ACCOUNT_BALANCES_IDENT,
+                AccountBalancesInput { resources },
+            )
+            .build();
+        self.preview_manifest(manifest, vec![], 0, PreviewFlags::default())
+            .expect_commit_success()
+            .output(1)
+    }
+
+    /// Queries the ids of the given non-fungible resource held by the account, through its
+    /// `non_fungible_ids_of` method, in a preview which is not committed.
+    pub fn account_non_fungible_ids(
+        &mut self,
+        account_address: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> BTreeSet<NonFungibleLocalId> {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                account_address,
+                ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT,
+                AccountNonFungibleIdsOfInput { resource_address },
+            )
+            .build();
+        self.preview_manifest(manifest, vec![], 0, PreviewFlags::default())
+            .expect_commit_success()
+            .output(1)
+    }
+
+    /// Queries the deposit rule configured for the given resource by the account, through its
+    /// `get_resource_preference` method, in a preview which is not committed.
+    pub fn account_resource_preference(
+        &mut self,
+        account_address: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> ResourceDepositRule {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                account_address,
+                ACCOUNT_GET_RESOURCE_PREFERENCE_IDENT,
+                AccountGetResourcePreferenceInput { resource_address },
+            )
+            .build();
+        self.preview_manifest(manifest, vec![], 0, PreviewFlags::default())
+            .expect_commit_success()
+            .output(1)
+    }
+
     pub fn find_all_nodes(&self) -> IndexSet<NodeId> {
         let mut node_ids = index_set_new();
         for pk in self.substate_db.list_partition_keys() {
@@ -583,6 +878,15 @@ impl TestRunner {
         vault_finder.to_vaults()
     }
 
+    /// Lists the nodes directly owned by the given node, such as the vaults and key value stores
+    /// of a component, by traversing the committed state.
+    pub fn list_owned_nodes(&mut self, node_id: NodeId) -> Vec<NodeId> {
+        let mut owned_node_finder = OwnedNodeFinder::new();
+        let mut traverser = StateTreeTraverser::new(&self.substate_db, &mut owned_node_finder, 1);
+        traverser.traverse_all_descendents(None, node_id);
+        owned_node_finder.to_owned_nodes()
+    }
+
     pub fn inspect_vault_balance(&mut self, vault_id: NodeId) -> Option<Decimal> {
         if vault_id.is_internal_fungible_vault() {
             self.inspect_fungible_vault(vault_id)
@@ -693,6 +997,23 @@ impl TestRunner {
             .unwrap()
     }
 
+    /// Returns the fee change requested by the validator's owner which is not yet effective.
+    pub fn get_validator_pending_fee_change(
+        &mut self,
+        address: ComponentAddress,
+    ) -> Option<ValidatorFeeChangeRequest> {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                address,
+                VALIDATOR_GET_PENDING_FEE_CHANGE_IDENT,
+                ValidatorGetPendingFeeChangeInput {},
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest, vec![]);
+        receipt.expect_commit(true).output(1)
+    }
+
     pub fn get_active_validator_with_key(&self, key: &Secp256k1PublicKey) -> ComponentAddress {
         let substate = self
             .substate_db()
@@ -761,6 +1082,43 @@ impl TestRunner {
         component_address
     }
 
+    /// Securifies the virtual identity of the given public key, depositing its owner badge into
+    /// the account, and returns the receipt so that the emitted events can be inspected.
+    pub fn securify_identity(
+        &mut self,
+        pk: Secp256k1PublicKey,
+        account: ComponentAddress,
+    ) -> TransactionReceipt {
+        let identity = ComponentAddress::virtual_identity_from_public_key(&pk);
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .securify_identity(identity)
+            .try_deposit_batch_or_abort(account)
+            .build();
+        self.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&pk)])
+    }
+
+    /// Creates a virtual identity for the given public key and securifies it, returning the
+    /// identity address and the global id of its owner badge, now held by the account.
+    pub fn new_virtual_then_securified_identity(
+        &mut self,
+        pk: Secp256k1PublicKey,
+        account: ComponentAddress,
+    ) -> (ComponentAddress, NonFungibleGlobalId) {
+        let identity = self.new_identity(pk.clone(), true);
+        self.securify_identity(pk, account).expect_commit_success();
+
+        (identity, Self::identity_owner_badge(identity))
+    }
+
+    /// The global id of the owner badge minted when the identity is securified.
+    pub fn identity_owner_badge(identity: ComponentAddress) -> NonFungibleGlobalId {
+        NonFungibleGlobalId::new(
+            IDENTITY_OWNER_BADGE,
+            NonFungibleLocalId::bytes(identity.as_node_id().0).unwrap(),
+        )
+    }
+
     pub fn new_validator_with_pub_key(
         &mut self,
         pub_key: Secp256k1PublicKey,
@@ -851,6 +1209,15 @@ impl TestRunner {
         receipt.expect_commit(true).new_package_addresses()[0]
     }
 
+    /// Publishes a package built ahead of time, e.g. one included with [`include_package!`].
+    pub fn publish_prebuilt_package(
+        &mut self,
+        code: Vec<u8>,
+        definition: PackageDefinition,
+    ) -> PackageAddress {
+        self.publish_package(code, definition, BTreeMap::new(), OwnerRole::None)
+    }
+
     pub fn compile<P: AsRef<Path>>(&mut self, package_dir: P) -> (Vec<u8>, PackageDefinition) {
         Compile::compile(package_dir)
     }
@@ -860,6 +1227,31 @@ impl TestRunner {
         self.publish_package(code, definition, BTreeMap::new(), OwnerRole::None)
     }
 
+    /// Compiles and publishes all packages of a cargo workspace, in dependency order, see
+    /// [`Compile::workspace_packages`]. Each package is compiled with the addresses of the packages
+    /// published before it, see [`package_address_env_var`].
+    ///
+    /// Returns the address of each package, by package name.
+    pub fn compile_and_publish_workspace<P: AsRef<Path>>(
+        &mut self,
+        workspace_dir: P,
+    ) -> IndexMap<String, PackageAddress> {
+        let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
+        let mut env_vars = BTreeMap::new();
+        let mut package_addresses = index_map_new();
+        for package in Compile::workspace_packages(workspace_dir) {
+            let (code, definition) = Compile::compile_with_env_vars(&package.dir, &env_vars);
+            let package_address =
+                self.publish_package(code, definition, BTreeMap::new(), OwnerRole::None);
+            env_vars.insert(
+                package_address_env_var(&package.name),
+                package_address.to_string(&address_bech32_encoder),
+            );
+            package_addresses.insert(package.name, package_address);
+        }
+        package_addresses
+    }
+
     pub fn compile_and_publish_at_address<P: AsRef<Path>>(
         &mut self,
         package_dir: P,
@@ -926,6 +1318,64 @@ impl TestRunner {
         )
     }
 
+    /// Fast-forwards the ledger to the given epoch, then validates and executes the notarized
+    /// transaction, asserting that the engine agrees with [`validate_header_epoch_window`] on
+    /// whether (and why) the transaction is rejected because of its epoch window.
+    pub fn execute_notarized_transaction_at_epoch(
+        &mut self,
+        transaction: &NotarizedTransactionV1,
+        epoch: Epoch,
+    ) -> TransactionReceipt {
+        self.set_current_epoch(epoch);
+
+        let header = &transaction.signed_intent.intent.header;
+        let config = ValidationConfig::default(header.network_id);
+        let expected_rejection = match validate_header_epoch_window(header, epoch, &config) {
+            Ok(()) => None,
+            Err(EpochWindowValidationError::HeaderValidationError(err)) => {
+                panic!("Expected transaction header to be valid: {:?}", err)
+            }
+            Err(EpochWindowValidationError::TransactionEpochNotYetValid {
+                valid_from,
+                current_epoch,
+            }) => Some(RejectionError::TransactionEpochNotYetValid {
+                valid_from,
+                current_epoch,
+            }),
+            Err(EpochWindowValidationError::TransactionEpochNoLongerValid {
+                valid_until,
+                current_epoch,
+            }) => Some(RejectionError::TransactionEpochNoLongerValid {
+                valid_until,
+                current_epoch,
+            }),
+        };
+
+        let validated = NotarizedTransactionValidator::new(config)
+            .validate(
+                transaction
+                    .prepare()
+                    .expect("Expected transaction to be preparable"),
+            )
+            .expect("Expected transaction to be valid");
+        let receipt = self.execute_transaction(
+            validated.get_executable(),
+            FeeReserveConfig::default(),
+            ExecutionConfig::for_notarized_transaction(),
+        );
+
+        match expected_rejection {
+            Some(expected_rejection) => assert_eq!(receipt.expect_rejection(), &expected_rejection),
+            None if receipt.is_rejection() => assert!(!matches!(
+                receipt.expect_rejection(),
+                RejectionError::TransactionEpochNotYetValid { .. }
+                    | RejectionError::TransactionEpochNoLongerValid { .. }
+            )),
+            None => {}
+        }
+        receipt
+    }
+
     pub fn execute_manifest<T>(
         &mut self,
         manifest: TransactionManifestV1,
@@ -969,10 +1419,9 @@ impl TestRunner {
         &mut self,
         executable: Executable,
         fee_reserve_config: FeeReserveConfig,
-        mut execution_config: ExecutionConfig,
+        execution_config: ExecutionConfig,
     ) -> TransactionReceipt {
-        // Override the kernel trace config
-        execution_config = execution_config.with_kernel_trace(self.trace);
+        let execution_config = self.configure_execution(execution_config);
 
         let transaction_receipt = execute_transaction(
             &mut self.substate_db,
@@ -991,6 +1440,80 @@ impl TestRunner {
         transaction_receipt
     }
 
+    /// Executes the manifest with the given faults injected into the substate database, counting
+    /// its reads and commits from the start of the execution.
+    ///
+    /// Injected IO errors panic, and leave the test runner unusable.
+    pub fn execute_manifest_with_substate_db_faults<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        faults: SubstateDatabaseFaults,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let nonce = self.next_transaction_nonce();
+        self.execute_transaction_with_substate_db_faults(
+            TestTransaction::new_from_nonce(manifest, nonce)
+                .prepare()
+                .expect("expected transaction to be preparable")
+                .get_executable(initial_proofs.into_iter().collect()),
+            FeeReserveConfig::default(),
+            ExecutionConfig::for_test_transaction(),
+            faults,
+        )
+    }
+
+    pub fn execute_transaction_with_substate_db_faults(
+        &mut self,
+        executable: Executable,
+        fee_reserve_config: FeeReserveConfig,
+        execution_config: ExecutionConfig,
+        faults: SubstateDatabaseFaults,
+    ) -> TransactionReceipt {
+        let execution_config = self.configure_execution(execution_config);
+
+        let mut substate_db = FaultInjectingSubstateDatabase::new(
+            std::mem::replace(&mut self.substate_db, InMemorySubstateDatabase::standard()),
+            faults,
+        );
+        let transaction_receipt = execute_transaction(
+            &substate_db,
+            &self.scrypto_interpreter,
+            &fee_reserve_config,
+            &execution_config,
+            &executable,
+        );
+        if let TransactionResult::Commit(commit) = &transaction_receipt.transaction_result {
+            substate_db.commit(&commit.state_updates.database_updates);
+            if let Some(state_hash_support) = &mut self.state_hash_support {
+                state_hash_support.update_with(&commit.state_updates.database_updates);
+            }
+        }
+        self.substate_db = substate_db.into_database();
+        transaction_receipt
+    }
+
+    fn configure_execution(&mut self, mut execution_config: ExecutionConfig) -> ExecutionConfig {
+        // Override the kernel trace config
+        execution_config = execution_config
+            .with_kernel_trace(self.trace)
+            .with_node_audit(true)
+            .with_invariant_checks(self.invariant_checks)
+            .with_blueprint_mocks(self.blueprint_mocks.clone());
+        if let Some(seed) = self.deterministic_ruid_seed {
+            let mut bytes = seed.to_le_bytes().to_vec();
+            bytes.extend(self.executed_transaction_count.to_le_bytes());
+            execution_config = execution_config.with_ruid_seed(Some(hash(bytes)));
+        }
+        if let Some(max_substate_size) = self.max_substate_size {
+            execution_config = execution_config.with_max_substate_size(max_substate_size);
+        }
+        self.executed_transaction_count += 1;
+        execution_config
+    }
+
     pub fn preview(
         &mut self,
         preview_intent: PreviewIntentV1,
@@ -1670,6 +2193,28 @@ impl TestRunner {
         self.advance_to_round_at_timestamp(round, current_timestamp_ms)
     }
 
+    /// Overwrites the consensus manager's time substates (both the milli and the minute
+    /// resolution ones), as if the proposer of the most recent round had reported the given
+    /// timestamp. The round itself is left unchanged.
+    pub fn set_proposer_timestamp(&mut self, proposer_timestamp_ms: i64) {
+        self.substate_db.put_mapped::<SpreadPrefixKeyMapper, _>(
+            &CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::CurrentTime.into(),
+            &ProposerMilliTimestampSubstate {
+                epoch_milli: proposer_timestamp_ms,
+            },
+        );
+        self.substate_db.put_mapped::<SpreadPrefixKeyMapper, _>(
+            &CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::CurrentTimeRoundedToMinutes.into(),
+            &ProposerMinuteTimestampSubstate {
+                epoch_minute: i32::try_from(proposer_timestamp_ms / 60_000).unwrap(),
+            },
+        );
+    }
+
     /// Reads out the substate holding the "epoch milli" timestamp reported by the proposer on the
     /// most recent round change.
     pub fn get_current_proposer_timestamp_ms(&mut self) -> i64 {
@@ -1711,63 +2256,10 @@ impl TestRunner {
         &self,
         event_type_identifier: &EventTypeIdentifier,
     ) -> (LocalTypeIndex, ScryptoSchema) {
-        let (package_address, schema_pointer) = match event_type_identifier {
-            EventTypeIdentifier(Emitter::Method(node_id, node_module), schema_pointer) => {
-                match node_module {
-                    ObjectModuleId::AccessRules => {
-                        (ACCESS_RULES_MODULE_PACKAGE, schema_pointer.clone())
-                    }
-                    ObjectModuleId::Royalty => (ROYALTY_MODULE_PACKAGE, schema_pointer.clone()),
-                    ObjectModuleId::Metadata => (METADATA_MODULE_PACKAGE, schema_pointer.clone()),
-                    ObjectModuleId::Main => {
-                        let type_info = self
-                            .substate_db()
-                            .get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
-                                node_id,
-                                TYPE_INFO_FIELD_PARTITION,
-                                &TypeInfoField::TypeInfo.into(),
-                            )
-                            .unwrap();
-
-                        match type_info {
-                            TypeInfoSubstate::Object(ObjectInfo {
-                                blueprint_id: blueprint,
-                                ..
-                            }) => (blueprint.package_address, *schema_pointer),
-                            _ => {
-                                panic!("No event schema.")
-                            }
-                        }
-                    }
-                }
-            }
-            EventTypeIdentifier(Emitter::Function(node_id, ..), schema_pointer) => (
-                PackageAddress::new_or_panic(node_id.0),
-                schema_pointer.clone(),
-            ),
-        };
-
-        match schema_pointer {
-            TypePointer::Package(schema_hash, index) => {
-                let schema = self
-                    .substate_db()
-                    .get_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<ScryptoSchema>>(
-                        package_address.as_node_id(),
-                        MAIN_BASE_PARTITION
-                            .at_offset(PACKAGE_SCHEMAS_PARTITION_OFFSET)
-                            .unwrap(),
-                        &SubstateKey::Map(scrypto_encode(&schema_hash).unwrap()),
-                    )
-                    .unwrap()
-                    .value
-                    .unwrap();
-
-                (index, schema)
-            }
-            TypePointer::Instance(_instance_index) => {
-                todo!()
-            }
-        }
+        let (schema, local_type_index) = EventSchemaResolver::new(self.substate_db())
+            .resolve_event_schema(event_type_identifier)
+            .expect("No event schema.");
+        (local_type_index, schema)
     }
 
     pub fn event_name(&self, event_type_identifier: &EventTypeIdentifier) -> String {
@@ -1804,6 +2296,28 @@ impl TestRunner {
             .map(|(_id, data)| scrypto_decode::<T>(data).unwrap())
             .collect::<Vec<_>>()
     }
+
+    /// Returns the application events of a committed transaction, with their names resolved
+    /// against the current ledger state.
+    pub fn event_stream(&self, receipt: &TransactionReceipt) -> EventStream {
+        let result = match &receipt.transaction_result {
+            TransactionResult::Commit(result) => result,
+            _ => panic!("Transaction was not committed"),
+        };
+        EventStream::new(
+            result
+                .application_events
+                .iter()
+                .map(|(id, data)| {
+                    (
+                        id.0.clone(),
+                        self.event_name(id),
+                        scrypto_decode::<ScryptoValue>(data).unwrap(),
+                    )
+                })
+                .collect(),
+        )
+    }
 }
 
 #[derive(Clone)]
@@ -1883,6 +2397,15 @@ pub fn wat2wasm(wat: &str) -> Vec<u8> {
 /// Gets the default cargo directory for the given crate.
 /// This respects whether the crate is in a workspace.
 pub fn get_cargo_target_directory(manifest_path: impl AsRef<OsStr>) -> String {
+    cargo_metadata(manifest_path)
+        .as_object()
+        .and_then(|o| o.get("target_directory"))
+        .and_then(|o| o.as_str())
+        .expect("Failed to parse target_directory from cargo metadata")
+        .to_owned()
+}
+
+fn cargo_metadata(manifest_path: impl AsRef<OsStr>) -> serde_json::Value {
     let output = Command::new("cargo")
         .arg("metadata")
         .arg("--manifest-path")
@@ -1893,14 +2416,8 @@ pub fn get_cargo_target_directory(manifest_path: impl AsRef<OsStr>) -> String {
         .output()
         .expect("Failed to call cargo metadata");
     if output.status.success() {
-        let parsed = serde_json::from_slice::<serde_json::Value>(&output.stdout)
-            .expect("Failed to parse cargo metadata");
-        let target_directory = parsed
-            .as_object()
-            .and_then(|o| o.get("target_directory"))
-            .and_then(|o| o.as_str())
-            .expect("Failed to parse target_directory from cargo metadata");
-        target_directory.to_owned()
+        serde_json::from_slice::<serde_json::Value>(&output.stdout)
+            .expect("Failed to parse cargo metadata")
     } else {
         panic!("Cargo metadata call was not successful");
     }
@@ -0,0 +1,167 @@
+use crate::{Compile, TestRunner, TestRunnerBuilder};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+use std::fmt::Debug;
+use std::path::Path;
+use transaction::prelude::*;
+
+/// Runs the same stream of transactions against several independent ledgers, and asserts after
+/// every step that they all produced the same receipts and the same state.
+///
+/// This catches non-determinism in the engine or in blueprint code, such as iteration over
+/// unordered collections or dependence on memory addresses.
+pub struct TestRunnerCluster {
+    test_runners: Vec<TestRunner>,
+}
+
+impl TestRunnerCluster {
+    /// Creates a cluster of `size` ledgers built with the default [`TestRunnerBuilder`], without
+    /// tracing.
+    pub fn new(size: usize) -> Self {
+        Self::with_builder(size, || TestRunner::builder().without_trace())
+    }
+
+    /// Creates a cluster of `size` ledgers, each built from a fresh builder.
+    pub fn with_builder<F: Fn() -> TestRunnerBuilder>(size: usize, builder: F) -> Self {
+        assert!(size > 0, "A cluster needs at least one test runner");
+        let cluster = Self {
+            test_runners: (0..size).map(|_| builder().build()).collect(),
+        };
+        cluster.assert_same_state();
+        cluster
+    }
+
+    pub fn size(&self) -> usize {
+        self.test_runners.len()
+    }
+
+    /// Returns one of the ledgers, to read state from. Changes made through it directly aren't
+    /// replicated to the other ledgers.
+    pub fn test_runner(&mut self, index: usize) -> &mut TestRunner {
+        &mut self.test_runners[index]
+    }
+
+    /// Runs an operation against every ledger, asserting that the results and the resulting states
+    /// are the same, and returns the result of the first ledger.
+    pub fn run<T, F>(&mut self, mut operation: F) -> T
+    where
+        T: Debug + PartialEq,
+        F: FnMut(&mut TestRunner) -> T,
+    {
+        let mut results = self.test_runners.iter_mut().map(|r| operation(r));
+        let first = results.next().unwrap();
+        for (index, result) in results.enumerate() {
+            assert_eq!(
+                first,
+                result,
+                "Test runner {} returned a different result than test runner 0",
+                index + 1
+            );
+        }
+        self.assert_same_state();
+        first
+    }
+
+    pub fn execute_manifest<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let initial_proofs: Vec<_> = initial_proofs.into_iter().collect();
+        self.execute(|test_runner| {
+            test_runner.execute_manifest(manifest.clone(), initial_proofs.clone())
+        })
+    }
+
+    pub fn execute_manifest_ignoring_fee<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let initial_proofs: Vec<_> = initial_proofs.into_iter().collect();
+        self.execute(|test_runner| {
+            test_runner.execute_manifest_ignoring_fee(manifest.clone(), initial_proofs.clone())
+        })
+    }
+
+    /// Executes a transaction against every ledger, asserting that the results of the receipts
+    /// and the resulting states are the same, and returns the receipt of the first ledger.
+    pub fn execute<F>(&mut self, mut execute: F) -> TransactionReceipt
+    where
+        F: FnMut(&mut TestRunner) -> TransactionReceipt,
+    {
+        let mut receipts = self.test_runners.iter_mut().map(|r| execute(r));
+        let first = receipts.next().unwrap();
+        // The resources usage and execution profile vary between runs, only the result is compared
+        let first_result = scrypto_encode(&first.transaction_result).unwrap();
+        for (index, receipt) in receipts.enumerate() {
+            if scrypto_encode(&receipt.transaction_result).unwrap() != first_result {
+                panic!(
+                    "Test runner {} produced a different receipt than test runner 0\n\
+                    Test runner 0: {:?}\nTest runner {}: {:?}",
+                    index + 1,
+                    first.transaction_result,
+                    index + 1,
+                    receipt.transaction_result
+                );
+            }
+        }
+        self.assert_same_state();
+        first
+    }
+
+    pub fn new_allocated_account(
+        &mut self,
+    ) -> (Secp256k1PublicKey, Secp256k1PrivateKey, ComponentAddress) {
+        let mut accounts = self
+            .test_runners
+            .iter_mut()
+            .map(|test_runner| test_runner.new_allocated_account());
+        let first = accounts.next().unwrap();
+        for account in accounts {
+            assert_eq!((first.0, first.2), (account.0, account.2));
+        }
+        self.assert_same_state();
+        first
+    }
+
+    pub fn publish_package(
+        &mut self,
+        code: Vec<u8>,
+        definition: PackageDefinition,
+    ) -> PackageAddress {
+        self.run(|test_runner| {
+            test_runner.publish_package(
+                code.clone(),
+                definition.clone(),
+                BTreeMap::new(),
+                OwnerRole::None,
+            )
+        })
+    }
+
+    /// Compiles the package once, and publishes it to every ledger.
+    pub fn compile_and_publish<P: AsRef<Path>>(&mut self, package_dir: P) -> PackageAddress {
+        let (code, definition) = Compile::compile(package_dir);
+        self.publish_package(code, definition)
+    }
+
+    /// Asserts that every ledger holds the same substates.
+    pub fn assert_same_state(&self) {
+        let first = self.test_runners[0].substate_db();
+        for (index, test_runner) in self.test_runners.iter().enumerate().skip(1) {
+            assert!(
+                test_runner.substate_db() == first,
+                "The state of test runner {} diverged from the state of test runner 0",
+                index
+            );
+        }
+    }
+}
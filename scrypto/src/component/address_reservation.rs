@@ -0,0 +1,25 @@
+use crate::engine::scrypto_env::ScryptoEnv;
+use radix_engine_interface::api::ClientObjectApi;
+use radix_engine_interface::types::*;
+
+pub trait ScryptoAddressReservation {
+    /// Returns the address which the reservation will be globalized at, so that it can be
+    /// shared or stored before the object is globalized.
+    fn address(&self) -> GlobalAddress;
+
+    /// Returns the address which the reservation will be globalized at, as a component address.
+    ///
+    /// Panics if the reservation is not for a component.
+    fn component_address(&self) -> ComponentAddress {
+        ComponentAddress::try_from(self.address())
+            .expect("Address reservation is not for a component")
+    }
+}
+
+impl ScryptoAddressReservation for GlobalAddressReservation {
+    fn address(&self) -> GlobalAddress {
+        ScryptoEnv
+            .get_reservation_address(self.0.as_node_id())
+            .unwrap()
+    }
+}
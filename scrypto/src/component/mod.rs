@@ -1,11 +1,19 @@
+mod address_reservation;
 mod component;
 mod kv_store;
 mod object;
 mod package;
+mod pausable;
+mod queue;
+mod ring_buffer;
 mod stubs;
 
+pub use address_reservation::*;
 pub use component::*;
 pub use kv_store::*;
 pub use object::*;
 pub use package::*;
+pub use pausable::*;
+pub use queue::*;
+pub use ring_buffer::*;
 pub use stubs::*;
@@ -0,0 +1,54 @@
+use crate::prelude::{ScryptoEvent, ScryptoSbor};
+use crate::runtime::Runtime;
+
+/// The role allowed to pause and unpause a `#[pausable]` blueprint.
+pub const PAUSER_ROLE: &str = "pauser";
+
+/// Emitted when a component is paused.
+#[derive(ScryptoSbor, ScryptoEvent, Debug, Clone, PartialEq, Eq)]
+pub struct PausedEvent;
+
+/// Emitted when a component is unpaused.
+#[derive(ScryptoSbor, ScryptoEvent, Debug, Clone, PartialEq, Eq)]
+pub struct UnpausedEvent;
+
+/// The state of a pausable component.
+///
+/// A blueprint marked with `#[pausable]` keeps it in a `pausable` field, and gets the `pause` and
+/// `unpause` methods (restricted to the [`PAUSER_ROLE`], which the owner can update) and a public
+/// `is_paused` method. The methods which must not run while paused start with
+/// `when_not_paused!(self)`.
+#[derive(ScryptoSbor, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pausable {
+    paused: bool,
+}
+
+impl Pausable {
+    /// Creates the state of an unpaused component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pauses the component, emitting a [`PausedEvent`].
+    pub fn pause(&mut self) {
+        assert!(!self.paused, "Component is already paused");
+        self.paused = true;
+        Runtime::emit_event(PausedEvent);
+    }
+
+    /// Unpauses the component, emitting an [`UnpausedEvent`].
+    pub fn unpause(&mut self) {
+        assert!(self.paused, "Component is not paused");
+        self.paused = false;
+        Runtime::emit_event(UnpausedEvent);
+    }
+
+    /// Panics if the component is paused.
+    pub fn assert_not_paused(&self) {
+        assert!(!self.paused, "Component is paused");
+    }
+}
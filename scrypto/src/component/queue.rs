@@ -0,0 +1,59 @@
+use crate::component::{KeyValueEntryRef, KeyValueStore};
+use crate::prelude::ScryptoSbor;
+use radix_engine_interface::data::scrypto::{ScryptoDecode, ScryptoDescribe, ScryptoEncode};
+
+/// A scalable first-in-first-out queue, with O(1) push and pop.
+///
+/// Items are kept in a key-value store under increasing positions, so that only the items being
+/// pushed or popped are loaded.
+#[derive(ScryptoSbor)]
+pub struct Queue<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe> {
+    items: KeyValueStore<u64, T>,
+    /// The position of the front item
+    head: u64,
+    /// The position after the back item
+    tail: u64,
+}
+
+impl<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe> Queue<T> {
+    /// Creates a new empty queue.
+    pub fn new() -> Self {
+        Self {
+            items: KeyValueStore::new(),
+            head: 0,
+            tail: 0,
+        }
+    }
+
+    /// Appends an item to the back of the queue.
+    pub fn push_back(&mut self, item: T) {
+        self.items.insert(self.tail, item);
+        self.tail += 1;
+    }
+
+    /// Removes the item at the front of the queue, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.items.remove(&self.head);
+        self.head += 1;
+        item
+    }
+
+    /// Returns the item at the front of the queue, if any.
+    pub fn front(&self) -> Option<KeyValueEntryRef<'_, T>> {
+        if self.is_empty() {
+            return None;
+        }
+        self.items.get(&self.head)
+    }
+
+    pub fn len(&self) -> u64 {
+        self.tail - self.head
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head == self.tail
+    }
+}
@@ -0,0 +1,77 @@
+use crate::component::{KeyValueEntryRef, KeyValueStore};
+use crate::prelude::ScryptoSbor;
+use radix_engine_interface::data::scrypto::{ScryptoDecode, ScryptoDescribe, ScryptoEncode};
+
+/// A scalable queue of bounded capacity, which evicts its oldest item to make room for a new one
+/// once full, with O(1) push and pop.
+///
+/// Items are kept in a key-value store under their slot, so that at most `capacity` entries are
+/// ever stored and only the items being accessed are loaded.
+#[derive(ScryptoSbor)]
+pub struct RingBuffer<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe> {
+    items: KeyValueStore<u64, T>,
+    capacity: u64,
+    /// The position of the oldest item, whose slot is the position modulo the capacity
+    head: u64,
+    len: u64,
+}
+
+impl<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe> RingBuffer<T> {
+    /// Creates a new empty ring buffer of the given capacity, which must not be zero.
+    pub fn with_capacity(capacity: u64) -> Self {
+        assert!(capacity > 0, "Ring buffer capacity must not be zero");
+        Self {
+            items: KeyValueStore::new(),
+            capacity,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Appends an item as the newest one, returning the oldest item if it had to be evicted.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        let evicted = if self.is_full() { self.pop() } else { None };
+        self.items.insert(self.slot(self.head + self.len), item);
+        self.len += 1;
+        evicted
+    }
+
+    /// Removes the oldest item, if any.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.items.remove(&self.slot(self.head));
+        self.head += 1;
+        self.len -= 1;
+        item
+    }
+
+    /// Returns the item at the given index, counting from the oldest one.
+    pub fn get(&self, index: u64) -> Option<KeyValueEntryRef<'_, T>> {
+        if index >= self.len {
+            return None;
+        }
+        self.items.get(&self.slot(self.head + index))
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    fn slot(&self, position: u64) -> u64 {
+        position % self.capacity
+    }
+}
@@ -1,6 +1,7 @@
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::pool::PoolContributionProtection;
 
 use crate::prelude::*;
 
@@ -24,6 +25,7 @@ extern_blueprint_internal! {
     MultiResourcePoolFunctions
     {
         fn instantiate(owner_role: OwnerRole, pool_manager_rule: AccessRule, resource_addresses: Vec<ResourceAddress>) -> Global<MultiResourcePool>;
+        fn instantiate_with_contribution_protection(owner_role: OwnerRole, pool_manager_rule: AccessRule, resource_addresses: Vec<ResourceAddress>, contribution_protection: PoolContributionProtection) -> Global<MultiResourcePool>;
     },
     {
         fn contribute(&mut self, buckets: Vec<Bucket>) -> (Bucket, Vec<Bucket>);
@@ -56,6 +58,7 @@ extern_blueprint_internal! {
     OneResourcePoolFunctions
     {
         fn instantiate(owner_role: OwnerRole, pool_manager_rule: AccessRule, resource_address: ResourceAddress) -> Global<OneResourcePool>;
+        fn instantiate_with_contribution_protection(owner_role: OwnerRole, pool_manager_rule: AccessRule, resource_address: ResourceAddress, contribution_protection: PoolContributionProtection) -> Global<OneResourcePool>;
     },
     {
         fn contribute(&mut self, bucket: Bucket) -> Bucket;
@@ -88,6 +91,7 @@ extern_blueprint_internal! {
     TwoResourcePoolFunctions
     {
         fn instantiate(owner_role: OwnerRole, pool_manager_rule: AccessRule, resource_addresses: (ResourceAddress, ResourceAddress)) -> Global<TwoResourcePool>;
+        fn instantiate_with_contribution_protection(owner_role: OwnerRole, pool_manager_rule: AccessRule, resource_addresses: (ResourceAddress, ResourceAddress), contribution_protection: PoolContributionProtection) -> Global<TwoResourcePool>;
     },
     {
         fn contribute(&mut self, buckets: (Bucket, Bucket)) -> (Bucket, Option<Bucket>);
@@ -123,6 +127,7 @@ extern_blueprint_internal! {
         fn create_advanced(owner_role: OwnerRole) -> Global<Account>;
     },
     {
+        fn balances(&self, resources: Vec<ResourceAddress>) -> Vec<(ResourceAddress, Decimal)>;
         fn burn(&mut self, resource_address: ResourceAddress, amount: Decimal);
         fn burn_non_fungibles(&mut self, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>);
         fn change_account_default_deposit_rule(&self, default_deposit_rule: AccountDefaultDepositRule);
@@ -132,10 +137,12 @@ extern_blueprint_internal! {
         fn create_proof_of_non_fungibles(&self, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>) -> Proof;
         fn deposit(&mut self, bucket: Bucket);
         fn deposit_batch(&mut self, buckets: Vec<Bucket>);
+        fn get_resource_preference(&self, resource_address: ResourceAddress) -> ResourceDepositRule;
         fn lock_contingent_fee(&mut self, amount: Decimal);
         fn lock_fee(&mut self, amount: Decimal);
         fn lock_fee_and_withdraw(&mut self, amount_to_lock: Decimal, resource_address: ResourceAddress, amount: Decimal) -> Bucket;
         fn lock_fee_and_withdraw_non_fungibles(&mut self, amount_to_lock: Decimal, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>) -> Bucket;
+        fn non_fungible_ids_of(&self, resource_address: ResourceAddress) -> Vec<NonFungibleLocalId>;
         fn securify(&mut self) -> Bucket;
         fn try_deposit_batch_or_abort(&mut self, buckets: Vec<Bucket>);
         fn try_deposit_batch_or_refund(&mut self, buckets: Vec<Bucket>) -> Vec<Bucket>;
@@ -143,6 +150,7 @@ extern_blueprint_internal! {
         fn try_deposit_or_refund(&mut self, bucket: Bucket) -> Option<Bucket>;
         fn withdraw(&mut self, resource_address: ResourceAddress, amount: Decimal) -> Bucket;
         fn withdraw_non_fungibles(&mut self, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>) -> Bucket;
+        fn withdraw_batch(&mut self, resources: Vec<ResourceSpecifier>) -> Vec<Bucket>;
     }
 }
 
@@ -7,11 +7,11 @@ use radix_engine_interface::api::key_value_entry_api::{
 use radix_engine_interface::api::key_value_store_api::ClientKeyValueStoreApi;
 use radix_engine_interface::api::object_api::ObjectModuleId;
 use radix_engine_interface::api::system_modules::auth_api::ClientAuthApi;
+use radix_engine_interface::api::{BatchCall, KVEntry, LockFlags};
 use radix_engine_interface::api::{
     ClientActorApi, ClientCostingApi, ClientFieldLockApi, ClientObjectApi, ObjectHandle,
 };
 use radix_engine_interface::api::{ClientBlueprintApi, ClientTransactionRuntimeApi};
-use radix_engine_interface::api::{KVEntry, LockFlags};
 use radix_engine_interface::blueprints::resource::AccessRule;
 use radix_engine_interface::crypto::Hash;
 use radix_engine_interface::data::scrypto::*;
@@ -180,10 +180,40 @@ impl ClientObjectApi<ClientApiError> for ScryptoEnv {
 
     fn get_reservation_address(
         &mut self,
-        _node_id: &NodeId,
+        node_id: &NodeId,
     ) -> Result<GlobalAddress, ClientApiError> {
-        // FIXME: Implement this for Scrypto
-        todo!()
+        let bytes = copy_buffer(unsafe {
+            get_reservation_address(node_id.as_ref().as_ptr(), node_id.as_ref().len())
+        });
+
+        scrypto_decode(&bytes).map_err(ClientApiError::DecodeError)
+    }
+
+    fn get_metadata(
+        &mut self,
+        address: GlobalAddress,
+        key: &str,
+    ) -> Result<Vec<u8>, ClientApiError> {
+        let address = address.as_node_id();
+        let bytes = copy_buffer(unsafe {
+            get_metadata(
+                address.as_ref().as_ptr(),
+                address.as_ref().len(),
+                key.as_ptr(),
+                key.len(),
+            )
+        });
+
+        Ok(bytes)
+    }
+
+    fn list_owned_nodes(
+        &mut self,
+        _node_id: &NodeId,
+        _offset: u32,
+        _count: u32,
+    ) -> Result<Vec<NodeId>, ClientApiError> {
+        unimplemented!("Not available for Scrypto")
     }
 
     fn drop_object(&mut self, node_id: &NodeId) -> Result<Vec<Vec<u8>>, ClientApiError> {
@@ -457,6 +487,12 @@ impl ClientTransactionRuntimeApi<ClientApiError> for ScryptoEnv {
         Ok(())
     }
 
+    fn emit_metric(&mut self, name: String, value: Decimal) -> Result<(), ClientApiError> {
+        let value = scrypto_encode(&value).unwrap();
+        unsafe { emit_metric(name.as_ptr(), name.len(), value.as_ptr(), value.len()) }
+        Ok(())
+    }
+
     fn get_transaction_hash(&mut self) -> Result<Hash, ClientApiError> {
         let actor = copy_buffer(unsafe { get_transaction_hash() });
 
@@ -477,6 +513,17 @@ impl ClientTransactionRuntimeApi<ClientApiError> for ScryptoEnv {
     }
 }
 
+impl ScryptoEnv {
+    /// Executes a batch of read-only calls with a single host call, and returns the
+    /// encoded result of each call, in order.
+    pub fn batch_invoke(&mut self, calls: &[BatchCall]) -> Result<Vec<Vec<u8>>, ClientApiError> {
+        let calls = scrypto_encode(calls).unwrap();
+        let bytes = copy_buffer(unsafe { batch_invoke(calls.as_ptr(), calls.len()) });
+
+        scrypto_decode(&bytes).map_err(ClientApiError::DecodeError)
+    }
+}
+
 #[macro_export]
 macro_rules! scrypto_env_native_fn {
     ($($vis:vis $fn:ident $fn_name:ident ($($args:tt)*) -> $rtn:ty { $arg:expr })*) => {
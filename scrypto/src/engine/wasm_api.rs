@@ -69,6 +69,17 @@ extern "C" {
 
     pub fn get_object_info(component_id_ptr: *const u8, component_id_len: usize) -> Buffer;
 
+    pub fn get_reservation_address(node_id_ptr: *const u8, node_id_len: usize) -> Buffer;
+
+    pub fn get_metadata(
+        address_ptr: *const u8,
+        address_len: usize,
+        key_ptr: *const u8,
+        key_len: usize,
+    ) -> Buffer;
+
+    pub fn batch_invoke(calls_ptr: *const u8, calls_len: usize) -> Buffer;
+
     pub fn kv_store_new(schema_ptr: *const u8, schema_len: usize) -> Buffer;
 
     pub fn kv_store_get_info(
@@ -185,6 +196,13 @@ extern "C" {
         message_len: usize,
     );
 
+    pub fn emit_metric(
+        name_ptr: *const u8,
+        name_len: usize,
+        value_ptr: *const u8,
+        value_len: usize,
+    );
+
     pub fn panic(message_ptr: *const u8, message_len: usize);
 
     pub fn get_transaction_hash() -> Buffer;
@@ -250,6 +268,26 @@ pub unsafe fn get_object_info(_component_id_ptr: *const u8, _component_id_len: u
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn get_reservation_address(_node_id_ptr: *const u8, _node_id_len: usize) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn get_metadata(
+    _address_ptr: *const u8,
+    _address_len: usize,
+    _key_ptr: *const u8,
+    _key_len: usize,
+) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn batch_invoke(_calls_ptr: *const u8, _calls_len: usize) -> Buffer {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn kv_store_new(_schema_ptr: *const u8, _schema_len: usize) -> Buffer {
     unreachable!()
@@ -411,6 +449,16 @@ pub unsafe fn emit_log(
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn emit_metric(
+    _name_ptr: *const u8,
+    _name_len: usize,
+    _value_ptr: *const u8,
+    _value_len: usize,
+) {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn panic(_message_ptr: *const u8, _message_len: usize) {
     unreachable!()
@@ -73,6 +73,23 @@ macro_rules! trace {
     }};
 }
 
+/// Panics if a `#[pausable]` component is paused, to guard the methods which must not run while
+/// paused.
+///
+/// # Example
+/// ```ignore
+/// pub fn withdraw(&mut self, amount: Decimal) -> Bucket {
+///     when_not_paused!(self);
+///     self.vault.take(amount)
+/// }
+/// ```
+#[macro_export]
+macro_rules! when_not_paused {
+    ($component:expr) => {{
+        $component.pausable.assert_not_paused();
+    }};
+}
+
 #[macro_export]
 macro_rules! this_package {
     () => {
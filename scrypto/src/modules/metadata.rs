@@ -97,35 +97,7 @@ impl Metadata {
             .unwrap(),
         );
 
-        // Manual decoding of Option<MetadataValue> to avoid large code size
-        // TODO: to replace with EnumVariant when it's ready
-        let mut decoder =
-            VecDecoder::<ScryptoCustomValueKind>::new(&rtn, SCRYPTO_SBOR_V1_MAX_DEPTH);
-        decoder
-            .read_and_check_payload_prefix(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
-            .unwrap();
-        decoder.read_and_check_value_kind(ValueKind::Enum).unwrap();
-        match decoder.read_discriminator().unwrap() {
-            OPTION_VARIANT_NONE => {
-                return Err(MetadataError::NotFound);
-            }
-            OPTION_VARIANT_SOME => {
-                decoder.read_and_check_size(1).unwrap();
-                decoder.read_and_check_value_kind(ValueKind::Enum).unwrap();
-                let id = decoder.read_discriminator().unwrap();
-                if id == V::DISCRIMINATOR {
-                    decoder.read_and_check_size(1).unwrap();
-                    let v: V = decoder.decode().unwrap();
-                    return Ok(v);
-                } else {
-                    return Err(MetadataError::UnexpectedType {
-                        expected_type_id: V::DISCRIMINATOR,
-                        actual_type_id: id,
-                    });
-                }
-            }
-            _ => unreachable!(),
-        }
+        decode_metadata_value(&rtn)
     }
 
     pub fn get_string<K: ToString>(&self, name: K) -> Result<String, MetadataError> {
@@ -143,3 +115,42 @@ impl Metadata {
         rtn
     }
 }
+
+/// Decodes an encoded `Option<MetadataValue>`, expecting a value of the given type.
+pub(crate) fn decode_metadata_value<V: MetadataVal>(rtn: &[u8]) -> Result<V, MetadataError> {
+    // Manual decoding of Option<MetadataValue> to avoid large code size
+    // TODO: to replace with EnumVariant when it's ready
+    let mut decoder = VecDecoder::<ScryptoCustomValueKind>::new(rtn, SCRYPTO_SBOR_V1_MAX_DEPTH);
+    decoder
+        .read_and_check_payload_prefix(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
+        .unwrap();
+    decoder.read_and_check_value_kind(ValueKind::Enum).unwrap();
+    match decoder.read_discriminator().unwrap() {
+        OPTION_VARIANT_NONE => {
+            return Err(MetadataError::NotFound);
+        }
+        OPTION_VARIANT_SOME => {
+            decoder.read_and_check_size(1).unwrap();
+            decoder.read_and_check_value_kind(ValueKind::Enum).unwrap();
+            let id = decoder.read_discriminator().unwrap();
+            if id == V::DISCRIMINATOR {
+                decoder.read_and_check_size(1).unwrap();
+                match decoder.decode::<V>() {
+                    Ok(v) => return Ok(v),
+                    // Only an address of another entity type than the requested one fails to
+                    // decode, which the full conversion reports
+                    Err(_) => {
+                        let value: Option<MetadataValue> = scrypto_decode(rtn).unwrap();
+                        return V::from_metadata_value(value.unwrap());
+                    }
+                }
+            } else {
+                return Err(MetadataError::UnexpectedType {
+                    expected_type_id: V::DISCRIMINATOR,
+                    actual_type_id: id,
+                });
+            }
+        }
+        _ => unreachable!(),
+    }
+}
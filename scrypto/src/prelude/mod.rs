@@ -12,7 +12,7 @@ pub use crate::{
     enable_method_auth, enable_package_royalties, error, extern_blueprint_internal, include_code,
     include_schema, info, internal_add_role, internal_component_royalty_entry, main_accessibility,
     method_accessibilities, method_accessibility, resource_list, role_list, roles, this_package,
-    to_role_key, trace, warn, NonFungibleData,
+    to_role_key, trace, warn, when_not_paused, NonFungibleData,
 };
 
 //=========================
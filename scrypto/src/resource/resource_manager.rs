@@ -261,6 +261,21 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Updates a mutable field of a non-fungible unit, as declared with `#[mutable]` on its
+    /// non-fungible data structure, e.g. `MyData::MY_FIELD`.
+    ///
+    /// # Panics
+    /// Panics if this is not a non-fungible resource of data `T` or the specified non-fungible is
+    /// not found.
+    pub fn update_non_fungible_data_field<T: NonFungibleData, D: ScryptoEncode>(
+        &self,
+        id: &NonFungibleLocalId,
+        field: MutableNonFungibleDataField<T, D>,
+        new_data: D,
+    ) {
+        self.update_non_fungible_data(id, field.name(), new_data)
+    }
+
     pub fn amount_for_withdrawal(
         &self,
         request_amount: Decimal,
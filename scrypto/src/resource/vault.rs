@@ -65,6 +65,12 @@ pub trait ScryptoFungibleVault {
 pub trait ScryptoNonFungibleVault {
     fn non_fungible_local_ids(&self) -> BTreeSet<NonFungibleLocalId>;
 
+    fn non_fungible_local_ids_page(
+        &self,
+        limit: u32,
+        cursor: Option<&NonFungibleLocalId>,
+    ) -> Vec<NonFungibleLocalId>;
+
     fn non_fungibles<T: NonFungibleData>(&self) -> Vec<NonFungible<T>>;
 
     fn non_fungible_local_id(&self) -> NonFungibleLocalId;
@@ -429,6 +435,29 @@ impl ScryptoNonFungibleVault for NonFungibleVault {
         scrypto_decode(&rtn).unwrap()
     }
 
+    /// Returns up to `limit` ids contained, starting after the `cursor` id, which should be the
+    /// last id of the previous page. Unlike [`Self::non_fungible_local_ids`], this doesn't load
+    /// the whole vault, so it remains usable for vaults with a very large number of ids.
+    fn non_fungible_local_ids_page(
+        &self,
+        limit: u32,
+        cursor: Option<&NonFungibleLocalId>,
+    ) -> Vec<NonFungibleLocalId> {
+        let mut env = ScryptoEnv;
+        let rtn = env
+            .call_method(
+                self.0 .0.as_node_id(),
+                NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT,
+                scrypto_encode(&NonFungibleVaultGetNonFungibleLocalIdsPageInput {
+                    limit,
+                    cursor: cursor.cloned(),
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&rtn).unwrap()
+    }
+
     /// Returns all the non-fungible units contained.
     ///
     /// # Panics
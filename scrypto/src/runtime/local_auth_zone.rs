@@ -96,6 +96,30 @@ impl LocalAuthZone {
         scrypto_decode(&rtn).unwrap()
     }
 
+    /// Composes a proof of the given amount of a resource from all the proofs of that resource
+    /// currently in the auth zone, including those pushed from vaults and buckets.
+    ///
+    /// The proofs are drawn from in the order they were pushed onto the auth zone, so the
+    /// composition is deterministic for a given sequence of pushes.
+    pub fn compose_proof_of_amount<A: Into<Decimal>>(
+        resource_address: ResourceAddress,
+        amount: A,
+    ) -> Proof {
+        Self::create_proof_of_amount(amount, resource_address)
+    }
+
+    /// Composes a proof of the given non-fungibles of a resource from all the proofs of that
+    /// resource currently in the auth zone, including those pushed from vaults and buckets.
+    ///
+    /// The proofs are drawn from in the order they were pushed onto the auth zone, so the
+    /// composition is deterministic for a given sequence of pushes.
+    pub fn compose_proof_of_non_fungibles(
+        resource_address: ResourceAddress,
+        ids: BTreeSet<NonFungibleLocalId>,
+    ) -> Proof {
+        Self::create_proof_of_non_fungibles(ids, resource_address)
+    }
+
     pub fn clear() {
         let mut env = ScryptoEnv;
         let node_id = env.get_auth_zone().unwrap();
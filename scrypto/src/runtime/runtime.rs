@@ -1,7 +1,9 @@
 use crate::component::ObjectStubHandle;
+use crate::modules::decode_metadata_value;
 use crate::prelude::{AnyComponent, Global};
 use radix_engine_common::math::Decimal;
 use radix_engine_common::types::GlobalAddressReservation;
+use radix_engine_interface::api::node_modules::metadata::{MetadataError, MetadataVal};
 use radix_engine_interface::api::system_modules::auth_api::ClientAuthApi;
 use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::consensus_manager::{
@@ -65,6 +67,18 @@ impl Runtime {
         NonFungibleGlobalId::package_of_direct_caller_badge(Runtime::package_address())
     }
 
+    /// Returns the metadata entry of the given key of a global entity, read without calling it.
+    pub fn get_metadata<A: Into<GlobalAddress>, K: AsRef<str>, V: MetadataVal>(
+        address: A,
+        key: K,
+    ) -> Result<V, MetadataError> {
+        let rtn = ScryptoEnv
+            .get_metadata(address.into(), key.as_ref())
+            .unwrap();
+
+        decode_metadata_value(&rtn)
+    }
+
     /// Returns the transaction hash.
     pub fn transaction_hash() -> Hash {
         ScryptoEnv.get_transaction_hash().unwrap()
@@ -77,6 +91,11 @@ impl Runtime {
             .unwrap();
     }
 
+    /// Emits a metric, which is reported in the receipt but not committed to state.
+    pub fn emit_metric<S: Into<String>>(name: S, value: Decimal) {
+        ScryptoEnv.emit_metric(name.into(), value).unwrap();
+    }
+
     pub fn assert_access_rule(access_rule: AccessRule) {
         let mut env = ScryptoEnv;
         env.assert_access_rule(access_rule).unwrap();
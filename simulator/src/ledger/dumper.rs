@@ -4,7 +4,10 @@ use colored::*;
 use radix_engine::blueprints::resource::*;
 use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
 use radix_engine::system::system::KeyValueEntrySubstate;
+use radix_engine::system::system_db_reader::{SystemDatabaseReader, SystemReaderError};
 use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::royalty::ComponentRoyaltySubstate;
+use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_queries::query::ResourceAccounter;
@@ -21,6 +24,7 @@ pub enum EntityDumpError {
     ComponentNotFound,
     ResourceManagerNotFound,
     InvalidStore(String),
+    SystemReaderError(SystemReaderError),
 }
 
 /// Dump a package into console.
@@ -52,9 +56,98 @@ pub fn dump_package<T: SubstateDatabase, O: std::io::Write>(
         "Code size".green().bold(),
         substate.value.unwrap().code.len()
     );
+
+    let royalty_configs: Vec<(BlueprintVersionKey, PackageRoyaltyConfig)> = substate_db
+        .list_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<PackageRoyaltyConfig>, MapKey>(
+            package_address.as_node_id(),
+            MAIN_BASE_PARTITION
+                .at_offset(PACKAGE_ROYALTY_PARTITION_OFFSET)
+                .unwrap(),
+        )
+        .filter_map(|(key, entry)| match (key, entry.value) {
+            (SubstateKey::Map(key), Some(config)) => Some((scrypto_decode(&key).unwrap(), config)),
+            _ => None,
+        })
+        .collect();
+    let no_royalty_amounts = BTreeMap::new();
+    writeln!(output, "{}", "Royalties".green().bold());
+    for (last, (blueprint_version_key, config)) in royalty_configs.iter().identify_last() {
+        let royalty_amounts = match config {
+            PackageRoyaltyConfig::Enabled(royalty_amounts) => royalty_amounts,
+            PackageRoyaltyConfig::Disabled => &no_royalty_amounts,
+        };
+        writeln!(
+            output,
+            "{} {}",
+            list_item_prefix(last),
+            blueprint_version_key.blueprint
+        );
+        dump_royalty_amounts(royalty_amounts.iter(), output);
+    }
+
+    if let Some(accumulator) = substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, PackageRoyaltyAccumulatorSubstate>(
+            package_address.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &PackageField::Royalty.into(),
+        )
+    {
+        dump_unclaimed_royalties(&accumulator.royalty_vault, substate_db, output);
+    }
     Ok(())
 }
 
+fn dump_royalty_amounts<
+    'a,
+    I: Iterator<Item = (&'a String, &'a RoyaltyAmount)>,
+    O: std::io::Write,
+>(
+    royalty_amounts: I,
+    output: &mut O,
+) {
+    let royalty_amounts: Vec<_> = royalty_amounts
+        .filter(|(_, amount)| amount.is_non_zero())
+        .collect();
+    if royalty_amounts.is_empty() {
+        writeln!(output, "   {} Free", list_item_prefix(true));
+    }
+    for (last, (ident, amount)) in royalty_amounts.into_iter().identify_last() {
+        let amount = match amount {
+            RoyaltyAmount::Free => "Free".to_string(),
+            RoyaltyAmount::Xrd(amount) => format!("{} XRD", amount),
+            RoyaltyAmount::Usd(amount) => format!("{} USD", amount),
+        };
+        writeln!(
+            output,
+            "   {} {}: {}",
+            list_item_prefix(last),
+            ident,
+            amount
+        );
+    }
+}
+
+fn dump_unclaimed_royalties<T: SubstateDatabase, O: std::io::Write>(
+    royalty_vault: &Vault,
+    substate_db: &T,
+    output: &mut O,
+) {
+    let amount = substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, LiquidFungibleResource>(
+            royalty_vault.0.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &FungibleVaultField::LiquidFungible.into(),
+        )
+        .map(|liquid| liquid.amount())
+        .unwrap_or_default();
+    writeln!(
+        output,
+        "{}: {} XRD",
+        "Unclaimed Royalties".green().bold(),
+        amount
+    );
+}
+
 /// Dump a component into console.
 pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
     component_address: ComponentAddress,
@@ -107,6 +200,26 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
         blueprint_name
     );
 
+    let fields = SystemDatabaseReader::new(substate_db)
+        .read_object_fields(component_address.as_node_id(), ObjectModuleId::Main)
+        .map_err(EntityDumpError::SystemReaderError)?;
+    writeln!(output, "{}", "State".green().bold());
+    for (last, field) in fields.iter().identify_last() {
+        let payload = scrypto_encode(&field.value).unwrap();
+        let value = ScryptoRawPayload::new_from_valid_slice(&payload).to_string(
+            ValueDisplayParameters::Annotated {
+                display_mode: DisplayMode::RustLike,
+                print_mode: PrintMode::SingleLine,
+                custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                    &address_bech32_encoder,
+                )),
+                schema: &field.schema,
+                type_index: field.type_index,
+            },
+        );
+        writeln!(output, "{} {}", list_item_prefix(last), value);
+    }
+
     writeln!(output, "{}", "Fungible Resources".green().bold());
     for (last, (component_address, amount)) in resources.balances.iter().identify_last() {
         writeln!(
@@ -131,6 +244,44 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
         }
     }
 
+    if let Some(component_royalty) = substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, ComponentRoyaltySubstate>(
+            component_address.as_node_id(),
+            ROYALTY_FIELDS_PARTITION,
+            &RoyaltyField::RoyaltyAccumulator.into(),
+        )
+    {
+        let royalty_amounts: Vec<(String, RoyaltyAmount)> = substate_db
+            .list_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<RoyaltyAmount>, MapKey>(
+                component_address.as_node_id(),
+                ROYALTY_CONFIG_PARTITION,
+            )
+            .filter_map(|(key, entry)| match (key, entry.value) {
+                (SubstateKey::Map(key), Some(amount)) => {
+                    Some((scrypto_decode(&key).unwrap(), amount))
+                }
+                _ => None,
+            })
+            .collect();
+        writeln!(
+            output,
+            "{}: {}",
+            "Royalties".green().bold(),
+            if component_royalty.enabled {
+                "Enabled"
+            } else {
+                "Disabled"
+            }
+        );
+        dump_royalty_amounts(
+            royalty_amounts
+                .iter()
+                .map(|(ident, amount)| (ident, amount)),
+            output,
+        );
+        dump_unclaimed_royalties(&component_royalty.royalty_vault, substate_db, output);
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,335 @@
+use clap::{Parser, Subcommand};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::access_controller::*;
+use std::str::FromStr;
+
+use crate::resim::*;
+use crate::utils::*;
+
+/// Create and recover access controllers
+#[derive(Parser, Debug)]
+pub struct AccessController {
+    #[clap(subcommand)]
+    pub command: AccessControllerCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AccessControllerCommand {
+    Create(AccessControllerCreate),
+    InitiateRecovery(AccessControllerInitiateRecovery),
+    QuickConfirm(AccessControllerQuickConfirm),
+    TimedConfirm(AccessControllerTimedConfirm),
+}
+
+impl AccessController {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        match &self.command {
+            AccessControllerCommand::Create(cmd) => cmd.run(out),
+            AccessControllerCommand::InitiateRecovery(cmd) => cmd.run(out),
+            AccessControllerCommand::QuickConfirm(cmd) => cmd.run(out),
+            AccessControllerCommand::TimedConfirm(cmd) => cmd.run(out),
+        }
+    }
+}
+
+/// One of the roles of an access controller which can propose a recovery
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessControllerProposer {
+    Primary,
+    Recovery,
+}
+
+impl FromStr for AccessControllerProposer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "primary" => Ok(Self::Primary),
+            "recovery" => Ok(Self::Recovery),
+            _ => Err(format!("Invalid role {}, expected primary or recovery", s)),
+        }
+    }
+}
+
+/// The rule set of an access controller, where each role is satisfied by presenting a badge
+#[derive(Parser, Debug)]
+pub struct AccessControllerRuleSet {
+    /// The badge of the primary role, either a resource address or a non-fungible global id
+    #[clap(long)]
+    pub primary_badge: SimulatorResourceOrNonFungibleGlobalId,
+
+    /// The badge of the recovery role, either a resource address or a non-fungible global id
+    #[clap(long)]
+    pub recovery_badge: SimulatorResourceOrNonFungibleGlobalId,
+
+    /// The badge of the confirmation role, either a resource address or a non-fungible global id
+    #[clap(long)]
+    pub confirmation_badge: SimulatorResourceOrNonFungibleGlobalId,
+
+    /// The delay after which a recovery can be confirmed without the confirmation role
+    #[clap(long)]
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
+
+impl AccessControllerRuleSet {
+    pub fn rule_set(&self) -> RuleSet {
+        RuleSet {
+            primary_role: self.primary_badge.clone().into(),
+            recovery_role: self.recovery_badge.clone().into(),
+            confirmation_role: self.confirmation_badge.clone().into(),
+        }
+    }
+}
+
+/// The options shared by all access controller transactions
+#[derive(Parser, Debug)]
+pub struct AccessControllerTransactionOptions {
+    /// The proofs to add to the auth zone, in form of "<resource_address>:<amount>" or "<resource_address>:<nf_local_id1>,<nf_local_id2>"
+    #[clap(short, long, multiple = true)]
+    pub proofs: Option<Vec<String>>,
+
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// Output a transaction manifest without execution
+    #[clap(short, long)]
+    pub manifest: Option<PathBuf>,
+
+    /// The private keys used for signing, separated by comma
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    pub trace: bool,
+}
+
+impl AccessControllerTransactionOptions {
+    /// Starts a manifest which locks a fee and creates the requested proofs from the default
+    /// account, so that the badges of the access controller roles can be presented.
+    fn start_manifest(&self, default_account: ComponentAddress) -> Result<ManifestBuilder, Error> {
+        let address_bech32_decoder = AddressBech32Decoder::for_simulator();
+
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for resource_specifier in self.proofs.clone().unwrap_or_default() {
+            builder = create_proof_from_account(
+                builder,
+                &address_bech32_decoder,
+                default_account,
+                resource_specifier,
+            )
+            .map_err(Error::FailedToBuildArguments)?
+        }
+        Ok(builder)
+    }
+
+    fn handle_manifest<O: std::io::Write>(
+        &self,
+        manifest: TransactionManifestV1,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        handle_manifest(
+            manifest,
+            &self.signing_keys,
+            &self.network,
+            &self.manifest,
+            self.trace,
+            true,
+            out,
+        )
+        .map(|_| ())
+    }
+}
+
+/// Create an access controller controlling resources of the default account
+#[derive(Parser, Debug)]
+pub struct AccessControllerCreate {
+    /// The resources to control, in form of "<resource_address>:<amount>" or "<resource_address>:<nf_local_id1>,<nf_local_id2>"
+    pub controlled_asset: String,
+
+    #[clap(flatten)]
+    pub rule_set: AccessControllerRuleSet,
+
+    #[clap(flatten)]
+    pub options: AccessControllerTransactionOptions,
+}
+
+impl AccessControllerCreate {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let address_bech32_decoder = AddressBech32Decoder::for_simulator();
+
+        let default_account = get_default_account()?;
+        let controlled_asset =
+            parse_resource_specifier(&self.controlled_asset, &address_bech32_decoder).map_err(
+                |_| {
+                    Error::FailedToBuildArguments(BuildCallArgumentError::InvalidResourceSpecifier(
+                        self.controlled_asset.clone(),
+                    ))
+                },
+            )?;
+
+        let builder = self.options.start_manifest(default_account)?;
+        let (builder, resource_address) = match controlled_asset {
+            ResourceSpecifier::Amount(amount, resource_address) => (
+                builder.withdraw_from_account(default_account, resource_address, amount),
+                resource_address,
+            ),
+            ResourceSpecifier::Ids(ids, resource_address) => (
+                builder.withdraw_non_fungibles_from_account(
+                    default_account,
+                    resource_address,
+                    &ids,
+                ),
+                resource_address,
+            ),
+        };
+        let RuleSet {
+            primary_role,
+            recovery_role,
+            confirmation_role,
+        } = self.rule_set.rule_set();
+        let manifest = builder
+            .take_all_from_worktop(resource_address, "controlled_asset")
+            .create_access_controller(
+                "controlled_asset",
+                primary_role,
+                recovery_role,
+                confirmation_role,
+                self.rule_set.timed_recovery_delay_in_minutes,
+            )
+            .try_deposit_batch_or_refund(default_account)
+            .build();
+        self.options.handle_manifest(manifest, out)
+    }
+}
+
+/// Propose a new rule set for an access controller, as either the primary or the recovery role
+#[derive(Parser, Debug)]
+pub struct AccessControllerInitiateRecovery {
+    /// The access controller address
+    pub access_controller_address: SimulatorComponentAddress,
+
+    /// The role proposing the recovery, [primary | recovery]
+    pub proposer: AccessControllerProposer,
+
+    /// The proposed rule set
+    #[clap(flatten)]
+    pub rule_set: AccessControllerRuleSet,
+
+    #[clap(flatten)]
+    pub options: AccessControllerTransactionOptions,
+}
+
+impl AccessControllerInitiateRecovery {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let default_account = get_default_account()?;
+        let rule_set = self.rule_set.rule_set();
+        let timed_recovery_delay_in_minutes = self.rule_set.timed_recovery_delay_in_minutes;
+
+        let builder = self.options.start_manifest(default_account)?;
+        let builder = match self.proposer {
+            AccessControllerProposer::Primary => builder.call_method(
+                self.access_controller_address.0,
+                ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
+                AccessControllerInitiateRecoveryAsPrimaryInput {
+                    rule_set,
+                    timed_recovery_delay_in_minutes,
+                },
+            ),
+            AccessControllerProposer::Recovery => builder.call_method(
+                self.access_controller_address.0,
+                ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+                AccessControllerInitiateRecoveryAsRecoveryInput {
+                    rule_set,
+                    timed_recovery_delay_in_minutes,
+                },
+            ),
+        };
+        let manifest = builder.try_deposit_batch_or_refund(default_account).build();
+        self.options.handle_manifest(manifest, out)
+    }
+}
+
+/// Confirm a recovery proposal of an access controller without waiting for the timed recovery
+/// delay, using the badge of any other role
+#[derive(Parser, Debug)]
+pub struct AccessControllerQuickConfirm {
+    /// The access controller address
+    pub access_controller_address: SimulatorComponentAddress,
+
+    /// The role which proposed the recovery, [primary | recovery]
+    pub proposer: AccessControllerProposer,
+
+    /// The rule set of the proposal being confirmed
+    #[clap(flatten)]
+    pub rule_set: AccessControllerRuleSet,
+
+    #[clap(flatten)]
+    pub options: AccessControllerTransactionOptions,
+}
+
+impl AccessControllerQuickConfirm {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let default_account = get_default_account()?;
+        let rule_set = self.rule_set.rule_set();
+        let timed_recovery_delay_in_minutes = self.rule_set.timed_recovery_delay_in_minutes;
+
+        let builder = self.options.start_manifest(default_account)?;
+        let builder = match self.proposer {
+            AccessControllerProposer::Primary => builder.call_method(
+                self.access_controller_address.0,
+                ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInput {
+                    rule_set,
+                    timed_recovery_delay_in_minutes,
+                },
+            ),
+            AccessControllerProposer::Recovery => builder.call_method(
+                self.access_controller_address.0,
+                ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInput {
+                    rule_set,
+                    timed_recovery_delay_in_minutes,
+                },
+            ),
+        };
+        let manifest = builder.try_deposit_batch_or_refund(default_account).build();
+        self.options.handle_manifest(manifest, out)
+    }
+}
+
+/// Confirm the recovery proposal of the recovery role once its timed recovery delay has elapsed
+#[derive(Parser, Debug)]
+pub struct AccessControllerTimedConfirm {
+    /// The access controller address
+    pub access_controller_address: SimulatorComponentAddress,
+
+    /// The rule set of the proposal being confirmed
+    #[clap(flatten)]
+    pub rule_set: AccessControllerRuleSet,
+
+    #[clap(flatten)]
+    pub options: AccessControllerTransactionOptions,
+}
+
+impl AccessControllerTimedConfirm {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let default_account = get_default_account()?;
+
+        let manifest = self
+            .options
+            .start_manifest(default_account)?
+            .call_method(
+                self.access_controller_address.0,
+                ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+                AccessControllerTimedConfirmRecoveryInput {
+                    rule_set: self.rule_set.rule_set(),
+                    timed_recovery_delay_in_minutes: self.rule_set.timed_recovery_delay_in_minutes,
+                },
+            )
+            .try_deposit_batch_or_refund(default_account)
+            .build();
+        self.options.handle_manifest(manifest, out)
+    }
+}
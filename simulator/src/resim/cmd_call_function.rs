@@ -17,14 +17,14 @@ pub struct CallFunction {
     /// The function name
     pub function_name: String,
 
-    /// The call arguments, such as "5", "hello", "<amount>,<resource_address>" and "<resource_address>:<nf_local_id1>,<nf_local_id2>"
+    /// The call arguments, such as "5", "hello", "<amount>,<resource_address>", "<resource_address>:<nf_local_id1>,<nf_local_id2>", "None", "Some(5)", "<enum_variant>" and "1,2,3"
     pub arguments: Vec<String>,
 
     /// The proofs to add to the auth zone, in form of "<amount>,<resource_address>" or "<resource_address>:<nf_local_id1>,<nf_local_id2>"
     #[clap(short, long, multiple = true)]
     pub proofs: Option<Vec<String>>,
 
-    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet]
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     pub network: Option<String>,
 
@@ -39,6 +39,10 @@ pub struct CallFunction {
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    /// Append the addresses of the new packages, components and resources to the given JSON file
+    #[clap(long)]
+    pub export_new_entities: Option<PathBuf>,
 }
 
 impl CallFunction {
@@ -71,7 +75,7 @@ impl CallFunction {
             )?
             .try_deposit_batch_or_refund(default_account)
             .build();
-        handle_manifest(
+        let receipt = handle_manifest(
             manifest,
             &self.signing_keys,
             &self.network,
@@ -79,8 +83,12 @@ impl CallFunction {
             self.trace,
             true,
             out,
-        )
-        .map(|_| ())
+        )?;
+        if let (Some(receipt), Some(path)) = (receipt, &self.export_new_entities) {
+            let label = format!("{}::{}", self.blueprint_name, self.function_name);
+            export_new_entities(path, &label, &receipt)?;
+        }
+        Ok(())
     }
 
     /// Calls a function.
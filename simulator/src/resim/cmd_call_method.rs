@@ -15,14 +15,14 @@ pub struct CallMethod {
     /// The method name
     pub method_name: String,
 
-    /// The call arguments, such as "5", "hello", "<amount>,<resource_address>" and "<resource_address>:<nf_local_id1>,<nf_local_id2>"
+    /// The call arguments, such as "5", "hello", "<amount>,<resource_address>", "<resource_address>:<nf_local_id1>,<nf_local_id2>", "None", "Some(5)", "<enum_variant>" and "1,2,3"
     pub arguments: Vec<String>,
 
     /// The proofs to add to the auth zone, in form of "<amount>,<resource_address>" or "<resource_address>:<nf_local_id1>,<nf_local_id2>"
     #[clap(short, long, multiple = true)]
     pub proofs: Option<Vec<String>>,
 
-    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet]
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     pub network: Option<String>,
 
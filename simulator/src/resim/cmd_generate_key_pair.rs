@@ -2,17 +2,33 @@ use crate::resim::*;
 use clap::Parser;
 use colored::*;
 use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_interface::network::NetworkDefinition;
 use rand::Rng;
+use utils::ContextualDisplay;
 
-/// Generate a key pair
+/// Generate a key pair, along with its virtual account address and owner badge
 #[derive(Parser, Debug)]
-pub struct GenerateKeyPair {}
+pub struct GenerateKeyPair {
+    /// The network to use when outputting addresses, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
+    #[clap(short, long)]
+    network: Option<String>,
+}
 
 impl GenerateKeyPair {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+        let address_bech32_encoder = AddressBech32Encoder::new(&network);
+
         let secret = rand::thread_rng().gen::<[u8; 32]>();
         let private_key = Secp256k1PrivateKey::from_bytes(&secret).unwrap();
         let public_key = private_key.public_key();
+        let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+        let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+
         writeln!(out, "Public key: {}", public_key.to_string().green()).map_err(Error::IOError)?;
         writeln!(
             out,
@@ -20,6 +36,26 @@ impl GenerateKeyPair {
             hex::encode(private_key.to_bytes()).green()
         )
         .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "Account address: {}",
+            account.display(&address_bech32_encoder).to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "Account address (hex): {}",
+            hex::encode(account.as_node_id().as_bytes()).green()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "Owner badge: {}",
+            owner_badge
+                .to_canonical_string(&address_bech32_encoder)
+                .green()
+        )
+        .map_err(Error::IOError)?;
         Ok(())
     }
 }
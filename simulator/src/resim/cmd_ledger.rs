@@ -0,0 +1,294 @@
+use clap::{Parser, Subcommand};
+use radix_engine::system::bootstrap::{
+    GenesisDataChunk, GenesisResource, GenesisResourceAllocation,
+};
+use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
+use radix_engine::system::system::KeyValueEntrySubstate;
+use radix_engine_queries::query::ResourceAccounter;
+use radix_engine_store_interface::db_key_mapper::{
+    DatabaseKeyMapper, MappedSubstateDatabase, SpreadPrefixKeyMapper,
+};
+use radix_engine_store_interface::interface::ListableSubstateDatabase;
+use radix_engine_stores::ledger_export::{export_ledger, LedgerExportFormat, NodeBlueprint};
+use radix_engine_stores::ledger_pruning::prune_ledger;
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use radix_engine_stores::rocks_db::RocksdbSubstateStore;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::resim::*;
+
+/// Maintain the ledger of this simulator
+#[derive(Parser, Debug)]
+pub struct Ledger {
+    #[clap(subcommand)]
+    pub command: LedgerCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum LedgerCommand {
+    Prune(LedgerPrune),
+    Export(LedgerExport),
+    ExportGenesis(LedgerExportGenesis),
+}
+
+impl Ledger {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        match &self.command {
+            LedgerCommand::Prune(cmd) => cmd.run(out),
+            LedgerCommand::Export(cmd) => cmd.run(out),
+            LedgerCommand::ExportGenesis(cmd) => cmd.run(out),
+        }
+    }
+}
+
+/// Prune the ledger history beyond the retained state versions and compact the ledger
+#[derive(Parser, Debug)]
+pub struct LedgerPrune {
+    /// The number of most recent state versions whose history is kept
+    #[clap(long, default_value = "100")]
+    pub retained_state_versions: u64,
+}
+
+impl LedgerPrune {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let report =
+            prune_ledger(get_data_dir()?, self.retained_state_versions).map_err(Error::IOError)?;
+
+        writeln!(
+            out,
+            "Pruned {} state versions ({} merkle nodes, {} substate history entries).",
+            report.pruned_state_versions,
+            report.pruned_merkle_nodes,
+            report.pruned_substate_history_entries
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "Ledger size: {} bytes -> {} bytes, reclaimed {} bytes.",
+            report.size_before,
+            report.size_after,
+            report.reclaimed_bytes()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+/// The file format of a ledger export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportFormat(LedgerExportFormat);
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Self(LedgerExportFormat::Csv)),
+            "parquet" => Ok(Self(LedgerExportFormat::Parquet)),
+            _ => Err(format!(
+                "Invalid export format {}, expected csv or parquet",
+                s
+            )),
+        }
+    }
+}
+
+/// Export every substate of the ledger into a file, one row per substate, for offline analytics
+#[derive(Parser, Debug)]
+pub struct LedgerExport {
+    /// The file to write the export to
+    pub output: PathBuf,
+
+    /// The file format, csv or parquet
+    #[clap(long, default_value = "csv")]
+    pub format: ExportFormat,
+}
+
+impl LedgerExport {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let scrypto_interpreter = ScryptoVm::<DefaultWasmEngine>::default();
+        let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+        Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false).bootstrap_test_default();
+
+        let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
+        let rows = export_ledger(
+            &substate_db,
+            |substate_db, node_id| match substate_db
+                .get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+                    node_id,
+                    TYPE_INFO_FIELD_PARTITION,
+                    &TypeInfoField::TypeInfo.into(),
+                )? {
+                TypeInfoSubstate::Object(info) => Some(NodeBlueprint {
+                    blueprint: format!(
+                        "{}:{}",
+                        info.blueprint_id
+                            .package_address
+                            .display(&address_bech32_encoder),
+                        info.blueprint_id.blueprint_name
+                    ),
+                    version: format!(
+                        "{}.{}.{}",
+                        info.version.major, info.version.minor, info.version.patch
+                    ),
+                }),
+                _ => None,
+            },
+            self.format.0,
+            &self.output,
+        )
+        .map_err(Error::IOError)?;
+
+        writeln!(
+            out,
+            "Exported {} substates to {}.",
+            rows,
+            self.output.display()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+/// Export the fungible resources and account balances of the ledger as genesis data chunks, to
+/// bootstrap other ledgers (e.g. test runners) with the same state
+///
+/// The output file holds the SBOR-encoded `Vec<GenesisDataChunk>`. Genesis can only recreate
+/// fungible resources (without owner and with the default divisibility) and the fungible balances
+/// of virtual accounts, so allocated accounts and any other state are left out.
+#[derive(Parser, Debug)]
+pub struct LedgerExportGenesis {
+    /// The file to write the genesis data chunks to
+    pub output: PathBuf,
+}
+
+impl LedgerExportGenesis {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let scrypto_interpreter = ScryptoVm::<DefaultWasmEngine>::default();
+        let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+        Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false).bootstrap_test_default();
+
+        // The entities created by the default genesis exist on any ledger, so they aren't exported
+        let mut default_genesis_db = InMemorySubstateDatabase::standard();
+        Bootstrapper::new(&mut default_genesis_db, &scrypto_interpreter, false)
+            .bootstrap_test_default();
+        let default_genesis_node_ids = Self::list_node_ids(&default_genesis_db);
+
+        let mut resources = Vec::new();
+        let mut accounts = Vec::new();
+        let mut skipped_accounts = 0;
+        for node_id in Self::list_node_ids(&substate_db) {
+            if default_genesis_node_ids.contains(&node_id) {
+                continue;
+            }
+            match node_id.entity_type() {
+                Some(EntityType::GlobalFungibleResourceManager) => {
+                    resources.push(ResourceAddress::new_or_panic(node_id.0))
+                }
+                Some(
+                    EntityType::GlobalVirtualSecp256k1Account
+                    | EntityType::GlobalVirtualEd25519Account,
+                ) => accounts.push(ComponentAddress::new_or_panic(node_id.0)),
+                Some(EntityType::GlobalAccount) => skipped_accounts += 1,
+                _ => {}
+            }
+        }
+
+        let genesis_resources: Vec<GenesisResource> = resources
+            .iter()
+            .map(|resource_address| GenesisResource {
+                reserved_resource_address: *resource_address,
+                metadata: Self::read_metadata(&substate_db, resource_address.as_node_id()),
+                owner: None,
+            })
+            .collect();
+
+        let mut xrd_balances = Vec::new();
+        let mut resource_allocations: IndexMap<ResourceAddress, Vec<GenesisResourceAllocation>> =
+            resources.iter().map(|address| (*address, vec![])).collect();
+        for (account_index, account) in accounts.iter().enumerate() {
+            let mut accounter = ResourceAccounter::new(&substate_db);
+            accounter.traverse(account.as_node_id().clone());
+            let balances: BTreeMap<ResourceAddress, Decimal> =
+                accounter.close().balances.into_iter().collect();
+            for (resource_address, amount) in balances {
+                if amount.is_zero() {
+                    continue;
+                }
+                if resource_address == XRD {
+                    xrd_balances.push((*account, amount));
+                } else if let Some(allocations) = resource_allocations.get_mut(&resource_address) {
+                    allocations.push(GenesisResourceAllocation {
+                        account_index: account_index as u32,
+                        amount,
+                    });
+                }
+            }
+        }
+        let resource_allocations: Vec<_> = resource_allocations
+            .into_iter()
+            .filter(|(_, allocations)| !allocations.is_empty())
+            .collect();
+
+        let mut genesis_data_chunks = Vec::new();
+        if !genesis_resources.is_empty() {
+            genesis_data_chunks.push(GenesisDataChunk::Resources(genesis_resources));
+        }
+        if !resource_allocations.is_empty() {
+            genesis_data_chunks.push(GenesisDataChunk::ResourceBalances {
+                accounts: accounts.clone(),
+                allocations: resource_allocations,
+            });
+        }
+        if !xrd_balances.is_empty() {
+            genesis_data_chunks.push(GenesisDataChunk::XrdBalances(xrd_balances));
+        }
+
+        fs::write(&self.output, scrypto_encode(&genesis_data_chunks).unwrap())
+            .map_err(Error::IOError)?;
+
+        writeln!(
+            out,
+            "Exported {} resources and the balances of {} accounts to {}.",
+            resources.len(),
+            accounts.len(),
+            self.output.display()
+        )
+        .map_err(Error::IOError)?;
+        if skipped_accounts > 0 {
+            writeln!(
+                out,
+                "Skipped {} allocated accounts, which genesis can't recreate.",
+                skipped_accounts
+            )
+            .map_err(Error::IOError)?;
+        }
+        Ok(())
+    }
+
+    fn list_node_ids<S: ListableSubstateDatabase>(substate_db: &S) -> IndexSet<NodeId> {
+        substate_db
+            .list_partition_keys()
+            .map(|key| SpreadPrefixKeyMapper::from_db_partition_key(&key).0)
+            .collect()
+    }
+
+    fn read_metadata<S: SubstateDatabase>(
+        substate_db: &S,
+        node_id: &NodeId,
+    ) -> Vec<(String, MetadataValue)> {
+        substate_db
+            .list_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<MetadataValue>, MapKey>(
+                node_id,
+                METADATA_KV_STORE_PARTITION,
+            )
+            .filter_map(|(key, entry)| match (key, entry.value) {
+                (SubstateKey::Map(key), Some(value)) => {
+                    Some((scrypto_decode(&key).unwrap(), value))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -17,7 +17,7 @@ pub struct Mint {
     #[clap(short, long, multiple = true)]
     pub proofs: Option<Vec<String>>,
 
-    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet]
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     pub network: Option<String>,
 
@@ -14,7 +14,7 @@ use crate::resim::*;
 /// Create an account
 #[derive(Parser, Debug)]
 pub struct NewAccount {
-    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet]
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     network: Option<String>,
 
@@ -31,7 +31,7 @@ pub struct NewBadgeFixed {
     #[clap(long)]
     pub icon_url: Option<String>,
 
-    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet]
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     pub network: Option<String>,
 
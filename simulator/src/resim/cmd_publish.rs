@@ -35,7 +35,7 @@ pub struct Publish {
     #[clap(long)]
     pub package_address: Option<SimulatorPackageAddress>,
 
-    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet]
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     pub network: Option<String>,
 
@@ -46,6 +46,14 @@ pub struct Publish {
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    /// Append the address of the new package to the given JSON file
+    #[clap(long)]
+    pub export_addresses: Option<PathBuf>,
+
+    /// Fail instead of warning if the code has unreachable exports or unused imports
+    #[clap(long)]
+    pub strict: bool,
 }
 
 impl Publish {
@@ -66,6 +74,28 @@ impl Publish {
         )
         .map_err(Error::SborDecodeError)?;
 
+        let dead_code_report = WasmModule::init(&code)
+            .map_err(Error::InvalidPackage)?
+            .analyze_dead_code(&package_definition);
+        if !dead_code_report.is_empty() {
+            if self.strict {
+                return Err(Error::DeadCodeFound(dead_code_report));
+            }
+            for export in &dead_code_report.unreachable_exports {
+                writeln!(
+                    out,
+                    "{} unreachable export: {}",
+                    "Warning:".yellow(),
+                    export
+                )
+                .map_err(Error::IOError)?;
+            }
+            for import in &dead_code_report.unused_imports {
+                writeln!(out, "{} unused import: {}", "Warning:".yellow(), import)
+                    .map_err(Error::IOError)?;
+            }
+        }
+
         if let Some(package_address) = self.package_address.clone() {
             let scrypto_interpreter = ScryptoVm::<DefaultWasmEngine>::default();
             let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -278,6 +308,14 @@ impl Publish {
                 out,
             )?;
             if let Some(receipt) = receipt {
+                if let Some(path) = &self.export_addresses {
+                    let label = self
+                        .path
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    export_new_entities(path, &label, &receipt)?;
+                }
                 writeln!(
                     out,
                     "Success! New Package: {}",
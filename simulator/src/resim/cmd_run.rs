@@ -13,7 +13,7 @@ pub struct Run {
     /// The path to a transaction manifest file
     pub path: PathBuf,
 
-    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet]
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     pub network: Option<String>,
 
@@ -28,17 +28,60 @@ pub struct Run {
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    /// Records kernel invocations, WASM executions and substate IO timings, and writes them
+    /// to the given path; as a flamegraph if it ends with `.svg`, or as collapsed stacks otherwise
+    #[clap(long)]
+    pub profile: Option<PathBuf>,
+
+    /// A plaintext message to attach to the transaction, e.g. a transfer memo
+    #[clap(long, conflicts_with = "encrypted-message")]
+    pub message: Option<String>,
+
+    /// The path to a Manifest SBOR encoded encrypted message to attach to the transaction
+    #[clap(long)]
+    pub encrypted_message: Option<PathBuf>,
 }
 
 impl Run {
     pub fn pre_process_manifest(manifest: &str) -> String {
+        Self::pre_process_manifest_with_vars(manifest, &BTreeMap::new())
+    }
+
+    /// Substitutes the `${name}` placeholders of a manifest with the given variables, falling
+    /// back to the environment variables.
+    pub fn pre_process_manifest_with_vars(
+        manifest: &str,
+        vars: &BTreeMap<String, String>,
+    ) -> String {
         let re = Regex::new(r"\$\{(.+?)\}").unwrap();
         re.replace_all(manifest, |caps: &Captures| {
-            env::var(&caps[1].trim()).unwrap_or_default()
+            let name = caps[1].trim();
+            match vars.get(name) {
+                Some(value) => value.clone(),
+                None => env::var(name).unwrap_or_default(),
+            }
         })
         .into()
     }
 
+    fn message(&self) -> Result<MessageV1, Error> {
+        if let Some(message) = &self.message {
+            return Ok(MessageV1::Plaintext(PlaintextMessageV1 {
+                mime_type: "text/plain".to_string(),
+                message: MessageContentsV1::String(message.clone()),
+            }));
+        }
+        if let Some(path) = &self.encrypted_message {
+            let encoded =
+                std::fs::read(path).map_err(|err| Error::IOErrorAtPath(err, path.clone()))?;
+            let message =
+                manifest_decode::<EncryptedMessageV1>(&encoded).map_err(Error::SborDecodeError)?;
+            return Ok(MessageV1::Encrypted(message));
+        }
+        Ok(MessageV1::None)
+    }
+
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
         let pre_processed_manifest = Self::pre_process_manifest(&manifest);
@@ -62,12 +105,14 @@ impl Run {
         validate_call_arguments_to_native_components(&compiled_manifest.instructions)
             .map_err(Error::InstructionSchemaValidationError)?;
 
-        handle_manifest(
+        handle_manifest_with_profile(
             compiled_manifest,
+            self.message()?,
             &self.signing_keys,
             &self.network,
             &None,
             self.trace,
+            &self.profile,
             true,
             out,
         )
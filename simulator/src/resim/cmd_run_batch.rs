@@ -0,0 +1,214 @@
+use clap::Parser;
+use radix_engine::utils::validate_call_arguments_to_native_components;
+use std::path::{Path, PathBuf};
+use transaction::manifest::BlobProvider;
+
+use crate::resim::*;
+
+/// The name of the file holding the variables of a batch
+pub const BATCH_VARS_FILE_NAME: &'static str = "vars.toml";
+
+/// Compiles, signs and runs all the transaction manifests of a directory, in lexical order
+#[derive(Parser, Debug)]
+pub struct RunBatch {
+    /// The path to a directory of `.rtm` files, along with the `.blob` files they refer to, and
+    /// an optional `vars.toml` file. Top-level keys of `vars.toml` are substituted in every
+    /// manifest, and keys of a table named after a manifest (without `.rtm`) only in that one.
+    pub path: PathBuf,
+
+    /// The network to use when outputting manifest, [simulator | adapanet | nebunet | mainnet | <logical_name>:<id>:<hrp_suffix>]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// The private keys used for signing, separated by comma
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    pub trace: bool,
+}
+
+impl RunBatch {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut manifest_paths = Vec::new();
+        let mut blobs = Vec::new();
+        for entry in
+            fs::read_dir(&self.path).map_err(|err| Error::IOErrorAtPath(err, self.path.clone()))?
+        {
+            let path = entry.map_err(Error::IOError)?.path();
+            match path.extension().and_then(|ext| ext.to_str()) {
+                Some("rtm") => manifest_paths.push(path),
+                Some("blob") => {
+                    blobs.push(fs::read(&path).map_err(|err| Error::IOErrorAtPath(err, path))?)
+                }
+                _ => {}
+            }
+        }
+        manifest_paths.sort();
+
+        let vars_path = self.path.join(BATCH_VARS_FILE_NAME);
+        let vars = if vars_path.exists() {
+            let content = fs::read_to_string(&vars_path)
+                .map_err(|err| Error::IOErrorAtPath(err, vars_path.clone()))?;
+            BatchVars::parse(&content).map_err(|err| Error::InvalidVarsFile(vars_path, err))?
+        } else {
+            BatchVars::default()
+        };
+
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+
+        for manifest_path in &manifest_paths {
+            writeln!(out, "Running {}", manifest_path.display()).map_err(Error::IOError)?;
+            self.run_manifest(manifest_path, &vars, &network, &blobs, out)
+                .map_err(|err| Error::BatchManifestFailed(manifest_path.clone(), Box::new(err)))?;
+        }
+
+        writeln!(out, "Ran {} manifests", manifest_paths.len()).map_err(Error::IOError)?;
+        Ok(())
+    }
+
+    fn run_manifest<O: std::io::Write>(
+        &self,
+        manifest_path: &Path,
+        vars: &BatchVars,
+        network: &NetworkDefinition,
+        blobs: &[Vec<u8>],
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let manifest = fs::read_to_string(manifest_path)
+            .map_err(|err| Error::IOErrorAtPath(err, manifest_path.to_owned()))?;
+        let name = manifest_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        let pre_processed_manifest =
+            Run::pre_process_manifest_with_vars(&manifest, &vars.for_manifest(name));
+        let compiled_manifest = transaction::manifest::compile(
+            &pre_processed_manifest,
+            network,
+            BlobProvider::new_with_blobs(blobs.to_vec()),
+        )
+        .map_err(Error::CompileError)?;
+
+        validate_call_arguments_to_native_components(&compiled_manifest.instructions)
+            .map_err(Error::InstructionSchemaValidationError)?;
+
+        // The receipt is written out even if the transaction fails, to help finding out why
+        let mut receipt = Vec::new();
+        let result = handle_manifest(
+            compiled_manifest,
+            &self.signing_keys,
+            &self.network,
+            &None,
+            self.trace,
+            true,
+            &mut receipt,
+        );
+        let receipt_path = manifest_path.with_extension("receipt");
+        fs::write(&receipt_path, &receipt)
+            .map_err(|err| Error::IOErrorAtPath(err, receipt_path))?;
+        out.write_all(&receipt).map_err(Error::IOError)?;
+
+        result.map(|_| ())
+    }
+}
+
+/// The variables of a batch, as read from its `vars.toml` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchVars {
+    pub global: BTreeMap<String, String>,
+    pub per_manifest: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl BatchVars {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table = match content.parse::<toml::Value>() {
+            Ok(toml::Value::Table(table)) => table,
+            Ok(_) => return Err("Expected a table of variables".to_string()),
+            Err(err) => return Err(err.to_string()),
+        };
+
+        let mut vars = Self::default();
+        for (key, value) in table {
+            match value {
+                toml::Value::Table(manifest_table) => {
+                    let mut manifest_vars = BTreeMap::new();
+                    for (key, value) in manifest_table {
+                        manifest_vars.insert(key.clone(), Self::var_value(&key, value)?);
+                    }
+                    vars.per_manifest.insert(key, manifest_vars);
+                }
+                value => {
+                    vars.global
+                        .insert(key.clone(), Self::var_value(&key, value)?);
+                }
+            }
+        }
+        Ok(vars)
+    }
+
+    /// Returns the variables of the given manifest, which take precedence over the global ones.
+    pub fn for_manifest(&self, name: &str) -> BTreeMap<String, String> {
+        let mut vars = self.global.clone();
+        if let Some(manifest_vars) = self.per_manifest.get(name) {
+            vars.extend(manifest_vars.clone());
+        }
+        vars
+    }
+
+    fn var_value(key: &str, value: toml::Value) -> Result<String, String> {
+        match value {
+            toml::Value::String(value) => Ok(value),
+            toml::Value::Integer(value) => Ok(value.to_string()),
+            toml::Value::Float(value) => Ok(value.to_string()),
+            toml::Value::Boolean(value) => Ok(value.to_string()),
+            _ => Err(format!(
+                "Variable `{}` must be a string, number or boolean",
+                key
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_vars_are_overridden_per_manifest() {
+        let vars = BatchVars::parse(
+            r#"
+            account = "account_sim1"
+            amount = 10
+
+            [02_transfer]
+            amount = "2.5"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            vars.for_manifest("01_setup"),
+            btreemap!(
+                "account".to_string() => "account_sim1".to_string(),
+                "amount".to_string() => "10".to_string()
+            )
+        );
+        assert_eq!(
+            vars.for_manifest("02_transfer"),
+            btreemap!(
+                "account".to_string() => "account_sim1".to_string(),
+                "amount".to_string() => "2.5".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_batch_vars_reject_arrays() {
+        assert!(BatchVars::parse("accounts = [\"a\", \"b\"]").is_err());
+    }
+}
@@ -1,6 +1,12 @@
-use crate::{ledger::*, resim::*};
+use crate::{ledger::*, resim::*, utils::*};
 use clap::Parser;
+use colored::*;
+use radix_engine::transaction::execute_transaction;
 use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::{
+    AccountBalancesInput, AccountBalancesOutput, AccountNonFungibleIdsOfInput,
+    AccountNonFungibleIdsOfOutput, ACCOUNT_BALANCES_IDENT, ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT,
+};
 use radix_engine_stores::rocks_db::RocksdbSubstateStore;
 
 /// Show an entity in the ledger state
@@ -8,6 +14,10 @@ use radix_engine_stores::rocks_db::RocksdbSubstateStore;
 pub struct Show {
     /// The address of a package, component or resource manager
     pub address: String,
+
+    /// The resources to query the balances of, through the methods of the account at the address
+    #[clap(short, long, multiple = true)]
+    pub resources: Option<Vec<SimulatorResourceAddress>>,
 }
 
 impl Show {
@@ -16,7 +26,18 @@ impl Show {
         let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
         Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false).bootstrap_test_default();
 
-        if let Ok(a) = SimulatorPackageAddress::from_str(&self.address) {
+        if let Some(resources) = &self.resources {
+            let account = SimulatorComponentAddress::from_str(&self.address)
+                .map_err(|_| Error::InvalidId(self.address.clone()))?;
+            let resources = resources.iter().map(|resource| resource.0).collect();
+            Self::show_account_balances(
+                account.0,
+                resources,
+                &substate_db,
+                &scrypto_interpreter,
+                out,
+            )
+        } else if let Ok(a) = SimulatorPackageAddress::from_str(&self.address) {
             dump_package(a.0, &substate_db, out).map_err(Error::LedgerDumpError)
         } else if let Ok(a) = SimulatorComponentAddress::from_str(&self.address) {
             dump_component(a.0, &substate_db, out).map_err(Error::LedgerDumpError)
@@ -26,4 +47,100 @@ impl Show {
             Err(Error::InvalidId(self.address.clone()))
         }
     }
+
+    /// Shows the balances of the given resources, and the ids of the non-fungible ones, as
+    /// returned by the account methods in a transaction which is not committed.
+    fn show_account_balances<O: std::io::Write>(
+        account: ComponentAddress,
+        resources: Vec<ResourceAddress>,
+        substate_db: &RocksdbSubstateStore,
+        scrypto_interpreter: &ScryptoVm<DefaultWasmEngine>,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let non_fungible_resources: Vec<ResourceAddress> = resources
+            .iter()
+            .filter(|resource| {
+                resource
+                    .as_node_id()
+                    .is_global_non_fungible_resource_manager()
+            })
+            .cloned()
+            .collect();
+
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet().call_method(
+            account,
+            ACCOUNT_BALANCES_IDENT,
+            AccountBalancesInput { resources },
+        );
+        for resource_address in &non_fungible_resources {
+            builder = builder.call_method(
+                account,
+                ACCOUNT_NON_FUNGIBLE_IDS_OF_IDENT,
+                AccountNonFungibleIdsOfInput {
+                    resource_address: *resource_address,
+                },
+            );
+        }
+        let transaction = TestTransaction::new_from_nonce(builder.build(), get_nonce()?);
+
+        let receipt = execute_transaction(
+            substate_db,
+            scrypto_interpreter,
+            &FeeReserveConfig::default(),
+            &ExecutionConfig::for_test_transaction(),
+            &transaction
+                .prepare()
+                .map_err(Error::TransactionPrepareError)?
+                .get_executable(btreeset!()),
+        );
+        let commit = match receipt.transaction_result {
+            TransactionResult::Commit(commit) => {
+                if let TransactionOutcome::Failure(error) = &commit.outcome {
+                    return Err(Error::TransactionFailed(error.clone()));
+                }
+                commit
+            }
+            TransactionResult::Reject(rejection) => {
+                return Err(Error::TransactionRejected(rejection.error))
+            }
+            TransactionResult::Abort(result) => {
+                return Err(Error::TransactionAborted(result.reason))
+            }
+        };
+
+        let balances: AccountBalancesOutput = commit.output(1);
+        let mut non_fungible_ids = index_map_new();
+        for (i, resource_address) in non_fungible_resources.into_iter().enumerate() {
+            let ids: AccountNonFungibleIdsOfOutput = commit.output(2 + i);
+            non_fungible_ids.insert(resource_address, ids);
+        }
+
+        let address_bech32_encoder = AddressBech32Encoder::for_simulator();
+        writeln!(
+            out,
+            "{}: {}",
+            "Account".green().bold(),
+            account.display(&address_bech32_encoder),
+        )
+        .map_err(Error::IOError)?;
+        writeln!(out, "{}", "Balances".green().bold()).map_err(Error::IOError)?;
+        for (last, (resource_address, amount)) in balances.iter().identify_last() {
+            writeln!(
+                out,
+                "{} {}: {}",
+                list_item_prefix(last),
+                resource_address.display(&address_bech32_encoder),
+                amount
+            )
+            .map_err(Error::IOError)?;
+            if let Some(ids) = non_fungible_ids.get(resource_address) {
+                for (last, id) in ids.iter().identify_last() {
+                    writeln!(out, "   {} {}", list_item_prefix(last), id)
+                        .map_err(Error::IOError)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,35 @@
+use clap::Parser;
+
+use crate::resim::*;
+
+/// Show the receipt of a transaction committed by the simulator, including its message
+#[derive(Parser, Debug)]
+pub struct ShowTx {
+    /// The hash of the transaction, as printed after running it
+    pub hash: String,
+}
+
+impl ShowTx {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let transaction_hash =
+            Hash::from_str(&self.hash).map_err(|_| Error::InvalidId(self.hash.clone()))?;
+        let path = get_transaction_receipt_path(&transaction_hash)?;
+        let encoded = fs::read(&path).map_err(|err| Error::IOErrorAtPath(err, path))?;
+        let receipt: TransactionReceipt =
+            scrypto_decode(&encoded).map_err(Error::SborDecodeError)?;
+
+        let scrypto_interpreter = ScryptoVm::<DefaultWasmEngine>::default();
+        let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+        Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false).bootstrap_test_default();
+
+        let encoder = AddressBech32Encoder::for_simulator();
+        let display_context = TransactionReceiptDisplayContextBuilder::new()
+            .encoder(&encoder)
+            .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
+                get_event_schema(&substate_db, event_type_identifier)
+            })
+            .build();
+        writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
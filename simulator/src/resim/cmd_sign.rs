@@ -0,0 +1,50 @@
+use crate::resim::*;
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use transaction::prelude::*;
+
+/// Sign a transaction intent with a private key
+#[derive(Parser, Debug)]
+pub struct Sign {
+    /// The path to a file containing the raw (SBOR-encoded) transaction intent
+    #[clap(long)]
+    intent: PathBuf,
+
+    /// The hex-encoded Secp256k1 private key to sign with
+    #[clap(long)]
+    key: String,
+}
+
+impl Sign {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let bytes = std::fs::read(&self.intent)
+            .map_err(|err| Error::IOErrorAtPath(err, self.intent.clone()))?;
+        let intent = IntentV1::from_raw(&RawIntent(bytes)).map_err(Error::SborDecodeError)?;
+        let intent_hash = intent
+            .prepare()
+            .map_err(Error::TransactionPrepareError)?
+            .intent_hash();
+
+        let private_key = hex::decode(self.key.trim())
+            .ok()
+            .and_then(|bytes| Secp256k1PrivateKey::from_bytes(&bytes).ok())
+            .ok_or(Error::InvalidPrivateKey)?;
+        let signature = private_key.sign(&intent_hash);
+
+        writeln!(
+            out,
+            "Intent hash: {}",
+            intent_hash.into_hash().to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "Public key: {}",
+            private_key.public_key().to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(out, "Signature: {}", signature.to_string().green()).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
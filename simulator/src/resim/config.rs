@@ -36,6 +36,17 @@ pub fn get_configs_path() -> Result<PathBuf, Error> {
     Ok(path.with_extension("sbor"))
 }
 
+/// The path where the receipt of a committed transaction is kept, for `resim show-tx`.
+pub fn get_transaction_receipt_path(transaction_hash: &Hash) -> Result<PathBuf, Error> {
+    let mut path = get_data_dir()?;
+    path.push("transactions");
+    if !path.exists() {
+        std::fs::create_dir_all(&path).map_err(Error::IOError)?;
+    }
+    path.push(transaction_hash.to_string());
+    Ok(path.with_extension("sbor"))
+}
+
 pub fn get_configs() -> Result<Configs, Error> {
     let path = get_configs_path()?;
     if path.exists() {
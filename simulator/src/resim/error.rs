@@ -5,7 +5,7 @@ use radix_engine::errors::{RejectionError, RuntimeError};
 use radix_engine::transaction::AbortReason;
 use radix_engine::types::{ComponentAddress, Hash, PackageAddress};
 use radix_engine::utils::ExtractSchemaError;
-use radix_engine::vm::wasm::PrepareError;
+use radix_engine::vm::wasm::{PrepareError, WasmDeadCodeReport};
 use radix_engine_interface::blueprints::resource::ParseNonFungibleGlobalIdError;
 use radix_engine_interface::network::ParseNetworkError;
 use sbor::*;
@@ -44,6 +44,8 @@ pub enum Error {
 
     InvalidPackage(PrepareError),
 
+    DeadCodeFound(WasmDeadCodeReport),
+
     TransactionConstructionError(BuildCallInstructionError),
 
     TransactionValidationError(TransactionValidationError),
@@ -75,4 +77,63 @@ pub enum Error {
     OwnerBadgeNotSpecified,
 
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
+
+    JsonError(serde_json::Error),
+
+    InvalidVarsFile(PathBuf, String),
+
+    BatchManifestFailed(PathBuf, Box<Error>),
+}
+
+impl Error {
+    /// A stable, machine-readable code identifying the kind of error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::NoDefaultAccount => "no_default_account",
+            Error::NoDefaultPrivateKey => "no_default_private_key",
+            Error::NoDefaultOwnerBadge => "no_default_owner_badge",
+            Error::HomeDirUnknown => "home_dir_unknown",
+            Error::PackageNotFound(..) => "package_not_found",
+            Error::SchemaNotFound(..) => "schema_not_found",
+            Error::BlueprintNotFound(..) => "blueprint_not_found",
+            Error::ComponentNotFound(..) => "component_not_found",
+            Error::InstanceSchemaNot(..) => "instance_schema_not_found",
+            Error::IOError(..) => "io_error",
+            Error::IOErrorAtPath(..) => "io_error",
+            Error::SborDecodeError(..) => "sbor_decode_error",
+            Error::SborEncodeError(..) => "sbor_encode_error",
+            Error::BuildError(..) => "build_error",
+            Error::ExtractSchemaError(..) => "extract_schema_error",
+            Error::InvalidPackage(..) => "invalid_package",
+            Error::DeadCodeFound(..) => "dead_code_found",
+            Error::TransactionConstructionError(..) => "transaction_construction_error",
+            Error::TransactionValidationError(..) => "transaction_validation_error",
+            Error::TransactionPrepareError(..) => "transaction_prepare_error",
+            Error::TransactionFailed(..) => "transaction_failed",
+            Error::TransactionRejected(..) => "transaction_rejected",
+            Error::TransactionAborted(..) => "transaction_aborted",
+            Error::LedgerDumpError(..) => "ledger_dump_error",
+            Error::CompileError(..) => "compile_error",
+            Error::DecompileError(..) => "decompile_error",
+            Error::InvalidId(..) => "invalid_id",
+            Error::InvalidPrivateKey => "invalid_private_key",
+            Error::NonFungibleGlobalIdError(..) => "invalid_non_fungible_global_id",
+            Error::FailedToBuildArguments(..) => "failed_to_build_arguments",
+            Error::ParseNetworkError(..) => "invalid_network",
+            Error::OwnerBadgeNotSpecified => "owner_badge_not_specified",
+            Error::InstructionSchemaValidationError(..) => "instruction_schema_validation_error",
+            Error::JsonError(..) => "json_error",
+            Error::InvalidVarsFile(..) => "invalid_vars_file",
+            Error::BatchManifestFailed(_, error) => error.code(),
+        }
+    }
+
+    /// The error as reported by `--output json`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "status": "error",
+            "code": self.code(),
+            "message": format!("{:?}", self),
+        })
+    }
 }
@@ -1,8 +1,10 @@
 mod addressing;
+mod cmd_access_controller;
 mod cmd_call_function;
 mod cmd_call_method;
 mod cmd_export_package_definition;
 mod cmd_generate_key_pair;
+mod cmd_ledger;
 mod cmd_mint;
 mod cmd_new_account;
 mod cmd_new_badge_fixed;
@@ -13,21 +15,26 @@ mod cmd_new_token_mutable;
 mod cmd_publish;
 mod cmd_reset;
 mod cmd_run;
+mod cmd_run_batch;
 mod cmd_set_current_epoch;
 mod cmd_set_current_time;
 mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_show_tx;
+mod cmd_sign;
 mod cmd_transfer;
 mod config;
 mod error;
 
 pub use addressing::*;
+pub use cmd_access_controller::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
 pub use cmd_export_package_definition::*;
 pub use cmd_generate_key_pair::*;
+pub use cmd_ledger::*;
 pub use cmd_mint::*;
 pub use cmd_new_account::*;
 pub use cmd_new_badge_fixed::*;
@@ -38,12 +45,15 @@ pub use cmd_new_token_mutable::*;
 pub use cmd_publish::*;
 pub use cmd_reset::*;
 pub use cmd_run::*;
+pub use cmd_run_batch::*;
 pub use cmd_set_current_epoch::*;
 pub use cmd_set_current_time::*;
 pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_show_tx::*;
+pub use cmd_sign::*;
 pub use cmd_transfer::*;
 pub use config::*;
 pub use error::*;
@@ -52,6 +62,7 @@ pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
 
+use crate::utils::render_flamegraph;
 use clap::{Parser, Subcommand};
 use radix_engine::blueprints::consensus_manager::{
     ConsensusManagerSubstate, ProposerMilliTimestampSubstate, ProposerMinuteTimestampSubstate,
@@ -86,12 +97,14 @@ use radix_engine_stores::rocks_db::RocksdbSubstateStore;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
 use transaction::manifest::decompile;
 use transaction::model::TestTransaction;
 use transaction::model::{BlobV1, BlobsV1, InstructionV1, InstructionsV1};
 use transaction::model::{SystemTransactionV1, TransactionPayload};
 use transaction::prelude::*;
 use transaction::signing::secp256k1::Secp256k1PrivateKey;
+use transaction::validation::{NotarizedTransactionValidator, ValidationConfig};
 use utils::ContextualDisplay;
 
 /// Build fast, reward everyone, and scale without friction
@@ -100,6 +113,10 @@ use utils::ContextualDisplay;
 pub struct ResimCli {
     #[clap(subcommand)]
     pub(crate) command: Command,
+
+    /// The format of the errors, [text | json]
+    #[clap(long, global = true, default_value = "text")]
+    pub(crate) output: OutputFormat,
 }
 
 impl ResimCli {
@@ -108,12 +125,37 @@ impl ResimCli {
     }
 }
 
+/// How resim reports errors: either human-readable text, or a JSON object which scripts can parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "Invalid output format {}, expected text or json",
+                s
+            )),
+        }
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub enum Command {
+    AccessController(AccessController),
     CallFunction(CallFunction),
     CallMethod(CallMethod),
     ExportPackageDefinition(ExportPackageDefinition),
+    #[clap(alias = "keygen")]
     GenerateKeyPair(GenerateKeyPair),
+    Ledger(Ledger),
     Mint(crate::resim::cmd_mint::Mint),
     NewAccount(NewAccount),
     NewSimpleBadge(NewSimpleBadge),
@@ -124,12 +166,16 @@ pub enum Command {
     Publish(Publish),
     Reset(Reset),
     Run(Run),
+    RunBatch(RunBatch),
     SetCurrentEpoch(SetCurrentEpoch),
+    #[clap(alias = "set-time")]
     SetCurrentTime(SetCurrentTime),
     SetDefaultAccount(SetDefaultAccount),
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
+    ShowTx(ShowTx),
     Show(Show),
+    Sign(Sign),
     Transfer(Transfer),
 }
 
@@ -138,11 +184,13 @@ pub fn run() -> Result<(), Error> {
 
     let mut out = std::io::stdout();
 
-    match cli.command {
+    let result = match cli.command {
+        Command::AccessController(cmd) => cmd.run(&mut out),
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
         Command::ExportPackageDefinition(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
+        Command::Ledger(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
         Command::NewAccount(cmd) => cmd.run(&mut out),
         Command::NewSimpleBadge(cmd) => cmd.run(&mut out).map(|_| ()),
@@ -153,13 +201,24 @@ pub fn run() -> Result<(), Error> {
         Command::Publish(cmd) => cmd.run(&mut out),
         Command::Reset(cmd) => cmd.run(&mut out),
         Command::Run(cmd) => cmd.run(&mut out),
+        Command::RunBatch(cmd) => cmd.run(&mut out),
         Command::SetCurrentEpoch(cmd) => cmd.run(&mut out),
         Command::SetCurrentTime(cmd) => cmd.run(&mut out),
         Command::SetDefaultAccount(cmd) => cmd.run(&mut out),
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
+        Command::ShowTx(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
+        Command::Sign(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
+    };
+
+    match (cli.output, result) {
+        (OutputFormat::Json, Err(error)) => {
+            println!("{}", error.to_json());
+            std::process::exit(1);
+        }
+        (_, result) => result,
     }
 }
 
@@ -219,11 +278,41 @@ pub fn handle_manifest<O: std::io::Write>(
     trace: bool,
     print_receipt: bool,
     out: &mut O,
+) -> Result<Option<TransactionReceipt>, Error> {
+    handle_manifest_with_profile(
+        manifest,
+        MessageV1::None,
+        signing_keys,
+        network,
+        write_manifest,
+        trace,
+        &None,
+        print_receipt,
+        out,
+    )
+}
+
+/// Like [`handle_manifest`], but also attaches the given message to the transaction, and writes
+/// the execution profile to the given path: a flamegraph if the path ends with `.svg`, and
+/// collapsed stacks otherwise.
+pub fn handle_manifest_with_profile<O: std::io::Write>(
+    manifest: TransactionManifestV1,
+    message: MessageV1,
+    signing_keys: &Option<String>,
+    network: &Option<String>,
+    write_manifest: &Option<PathBuf>,
+    trace: bool,
+    profile: &Option<PathBuf>,
+    print_receipt: bool,
+    out: &mut O,
 ) -> Result<Option<TransactionReceipt>, Error> {
     let network = match network {
         Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
         None => NetworkDefinition::simulator(),
     };
+    NotarizedTransactionValidator::new(ValidationConfig::default(network.id))
+        .validate_message_v1(&message)
+        .map_err(|error| Error::TransactionValidationError(error.into()))?;
     match write_manifest {
         Some(path) => {
             if !env::var(ENV_DISABLE_MANIFEST_OUTPUT).is_ok() {
@@ -254,13 +343,17 @@ pub fn handle_manifest<O: std::io::Write>(
                 .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
                 .collect::<BTreeSet<NonFungibleGlobalId>>();
             let nonce = get_nonce()?;
-            let transaction = TestTransaction::new_from_nonce(manifest, nonce);
+            let transaction =
+                TestTransaction::new_from_nonce(manifest, nonce).with_message(message);
+            let transaction_hash = transaction.hash;
 
             let receipt = execute_and_commit_transaction(
                 &mut substate_db,
                 &scrypto_interpreter,
                 &FeeReserveConfig::default(),
-                &ExecutionConfig::for_test_transaction().with_kernel_trace(trace),
+                &ExecutionConfig::for_test_transaction()
+                    .with_kernel_trace(trace)
+                    .with_profiling(profile.is_some()),
                 &transaction
                     .prepare()
                     .map_err(Error::TransactionPrepareError)?
@@ -276,9 +369,35 @@ pub fn handle_manifest<O: std::io::Write>(
                     })
                     .build();
                 writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
+                writeln!(out, "Transaction Hash: {}", transaction_hash).map_err(Error::IOError)?;
             }
             drop(substate_db);
 
+            if let TransactionResult::Commit(_) = &receipt.transaction_result {
+                let path = get_transaction_receipt_path(&transaction_hash)?;
+                fs::write(
+                    &path,
+                    scrypto_encode(&receipt).map_err(Error::SborEncodeError)?,
+                )
+                .map_err(|err| Error::IOErrorAtPath(err, path))?;
+            }
+
+            if let (Some(path), Some(execution_profile)) = (profile, &receipt.execution_profile) {
+                let output = if path.extension().map_or(false, |ext| ext == "svg") {
+                    render_flamegraph(execution_profile, &path.display().to_string())
+                } else {
+                    execution_profile.to_collapsed_stacks()
+                };
+                fs::write(path, output).map_err(|err| Error::IOErrorAtPath(err, path.clone()))?;
+                writeln!(
+                    out,
+                    "Profile written to {} ({:.3} ms profiled)",
+                    path.display(),
+                    execution_profile.total_time() as f64 / 1_000_000.0
+                )
+                .map_err(Error::IOError)?;
+            }
+
             process_receipt(receipt).map(Option::Some)
         }
     }
@@ -539,3 +658,52 @@ pub fn db_upsert_epoch(epoch: Epoch) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Appends the packages, components and resources created by a transaction to the JSON file at
+/// the given path, so that front-ends and scripts can pick up the deployed addresses.
+///
+/// The file holds an array of `{ "label", "entity_type", "address" }` entries and is created if
+/// it does not exist yet.
+pub fn export_new_entities(
+    path: &PathBuf,
+    label: &str,
+    receipt: &TransactionReceipt,
+) -> Result<(), Error> {
+    let mut entries = match fs::read(path) {
+        Ok(bytes) if !bytes.is_empty() => {
+            serde_json::from_slice::<Vec<serde_json::Value>>(&bytes).map_err(Error::JsonError)?
+        }
+        Ok(_) => Vec::new(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(Error::IOErrorAtPath(err, path.clone())),
+    };
+
+    let encoder = AddressBech32Encoder::for_simulator();
+    let commit = receipt.expect_commit_success();
+    let new_entities = commit
+        .new_package_addresses()
+        .iter()
+        .map(|address| ("package", address.display(&encoder).to_string()))
+        .chain(
+            commit
+                .new_component_addresses()
+                .iter()
+                .map(|address| ("component", address.display(&encoder).to_string())),
+        )
+        .chain(
+            commit
+                .new_resource_addresses()
+                .iter()
+                .map(|address| ("resource", address.display(&encoder).to_string())),
+        );
+    for (entity_type, address) in new_entities {
+        entries.push(serde_json::json!({
+            "label": label,
+            "entity_type": entity_type,
+            "address": address,
+        }));
+    }
+
+    let json = serde_json::to_string_pretty(&entries).map_err(Error::JsonError)?;
+    fs::write(path, json).map_err(|err| Error::IOErrorAtPath(err, path.clone()))
+}
@@ -2,7 +2,7 @@ use clap::Parser;
 use radix_engine::{types::*, utils::*};
 use std::path::PathBuf;
 use std::str::FromStr;
-use transaction::manifest::{compile, BlobProvider};
+use transaction::manifest::{compile, render_compile_error, BlobProvider};
 
 /// Radix transaction manifest compiler
 #[derive(Parser, Debug)]
@@ -12,7 +12,7 @@ pub struct Args {
     #[clap(short, long)]
     output: PathBuf,
 
-    /// Network to Use [Simulator | Alphanet | Mainnet]
+    /// Network to Use [Simulator | Adapanet | Nebunet | Mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     network: Option<String>,
 
@@ -48,8 +48,14 @@ pub fn run() -> Result<(), Error> {
             blobs.push(std::fs::read(path).map_err(Error::IoError)?);
         }
     }
-    let transaction = compile(&content, &network, BlobProvider::new_with_blobs(blobs))
-        .map_err(Error::CompileError)?;
+    let transaction =
+        compile(&content, &network, BlobProvider::new_with_blobs(blobs)).map_err(|error| {
+            eprint!(
+                "{}",
+                render_compile_error(&content, &args.input.display().to_string(), &error)
+            );
+            Error::CompileError(error)
+        })?;
     validate_call_arguments_to_native_components(&transaction.instructions)
         .map_err(Error::InstructionSchemaValidationError)?;
     std::fs::write(
@@ -1,8 +1,14 @@
+mod stream;
+
+pub use stream::*;
+
 use clap::Parser;
 use radix_engine::types::*;
 use radix_engine::utils::validate_call_arguments_to_native_components;
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::data::manifest::manifest_decode;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
 use std::str::FromStr;
 use transaction::manifest::decompile;
@@ -16,7 +22,7 @@ pub struct Args {
     #[clap(short, long)]
     output: PathBuf,
 
-    /// Network to Use [Simulator | Alphanet | Mainnet]
+    /// Network to Use [Simulator | Adapanet | Nebunet | Mainnet | <logical_name>:<id>:<hrp_suffix>]
     #[clap(short, long)]
     network: Option<String>,
 
@@ -24,6 +30,11 @@ pub struct Args {
     #[clap(short, long, action)]
     export_blobs: bool,
 
+    /// Whether to decode and decompile the manifest one instruction at a time, for manifests
+    /// too large to be held in memory
+    #[clap(short, long, action)]
+    stream: bool,
+
     /// Input file
     #[clap(required = true)]
     input: PathBuf,
@@ -41,11 +52,28 @@ pub enum Error {
 pub fn run() -> Result<(), Error> {
     let args = Args::parse();
 
-    let content = std::fs::read(&args.input).map_err(Error::IoError)?;
     let network = match args.network {
         Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
         None => NetworkDefinition::simulator(),
     };
+
+    if args.stream {
+        let input = File::open(&args.input).map_err(Error::IoError)?;
+        let output = File::create(&args.output).map_err(Error::IoError)?;
+        let blob_directory = if args.export_blobs {
+            args.output.parent()
+        } else {
+            None
+        };
+        return decompile_stream(
+            &mut ManifestStreamReader::new(BufReader::new(input)),
+            &mut BufWriter::new(output),
+            &network,
+            blob_directory,
+        );
+    }
+
+    let content = std::fs::read(&args.input).map_err(Error::IoError)?;
     let manifest =
         manifest_decode::<TransactionManifestV1>(&content).map_err(Error::DecodeError)?;
     validate_call_arguments_to_native_components(&manifest.instructions)
@@ -0,0 +1,209 @@
+use super::Error;
+use radix_engine::types::*;
+use radix_engine::utils::validate_call_arguments_to_native_components;
+use radix_engine_interface::crypto::hash;
+use std::io::{Read, Write};
+use std::path::Path;
+use transaction::manifest::decompiler::{decompile_instruction, DecompilationContext};
+use transaction::prelude::*;
+
+const DEFAULT_WINDOW_SIZE: usize = 64 * 1024;
+
+/// Decodes a manifest SBOR payload piece by piece from a reader, keeping in memory only the
+/// bytes of the value being decoded.
+pub struct ManifestStreamReader<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+    offset: usize,
+    window_size: usize,
+    end_of_input: bool,
+}
+
+impl<R: Read> ManifestStreamReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_window_size(reader, DEFAULT_WINDOW_SIZE)
+    }
+
+    pub fn with_window_size(reader: R, window_size: usize) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            offset: 0,
+            window_size: window_size.max(1),
+            end_of_input: false,
+        }
+    }
+
+    /// Runs the given decoding step against the buffered bytes, reading more input and retrying
+    /// for as long as the step runs out of bytes.
+    pub fn decode_with<T, F>(&mut self, mut step: F) -> Result<T, Error>
+    where
+        F: FnMut(&mut ManifestDecoder) -> Result<T, DecodeError>,
+    {
+        loop {
+            // The steps decode values nested in the manifest tuple and the instruction array
+            let mut decoder =
+                ManifestDecoder::new(&self.buffer[self.offset..], MANIFEST_SBOR_V1_MAX_DEPTH - 2);
+            match step(&mut decoder) {
+                Ok(value) => {
+                    self.offset += decoder.get_offset();
+                    return Ok(value);
+                }
+                Err(DecodeError::BufferUnderflow { .. }) if !self.end_of_input => {
+                    self.fill()?;
+                }
+                Err(error) => return Err(Error::DecodeError(error)),
+            }
+        }
+    }
+
+    /// Fails if there are bytes left after the payload.
+    pub fn check_end(&mut self) -> Result<(), Error> {
+        while !self.end_of_input {
+            self.fill()?;
+        }
+        match self.buffer.len() - self.offset {
+            0 => Ok(()),
+            n => Err(Error::DecodeError(DecodeError::ExtraTrailingBytes(n))),
+        }
+    }
+
+    fn fill(&mut self) -> Result<(), Error> {
+        self.buffer.drain(..self.offset);
+        self.offset = 0;
+
+        // Grow the read size with the pending bytes, so that decoding a large value is retried
+        // a logarithmic number of times
+        let read_size = self.window_size.max(self.buffer.len());
+        let filled = self.buffer.len();
+        self.buffer.resize(filled + read_size, 0);
+        let mut read = 0;
+        while read < read_size {
+            match self.reader.read(&mut self.buffer[filled + read..]) {
+                Ok(0) => {
+                    self.end_of_input = true;
+                    break;
+                }
+                Ok(n) => read += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(Error::IoError(e)),
+            }
+        }
+        self.buffer.truncate(filled + read);
+        Ok(())
+    }
+}
+
+/// Decompiles a `TransactionManifestV1` payload instruction by instruction, writing each one out
+/// as soon as it's decoded, and exports the blobs into `blob_directory` if given.
+pub fn decompile_stream<R: Read, W: Write>(
+    reader: &mut ManifestStreamReader<R>,
+    writer: &mut W,
+    network: &NetworkDefinition,
+    blob_directory: Option<&Path>,
+) -> Result<(), Error> {
+    reader.decode_with(|decoder| {
+        decoder.read_and_check_payload_prefix(MANIFEST_SBOR_V1_PAYLOAD_PREFIX)?;
+        decoder.read_and_check_value_kind(ValueKind::Tuple)?;
+        decoder.read_and_check_size(2)
+    })?;
+
+    let instruction_count = reader.decode_with(|decoder| {
+        decoder.read_and_check_value_kind(ValueKind::Array)?;
+        decoder.read_and_check_value_kind(ValueKind::Enum)?;
+        decoder.read_size()
+    })?;
+    let address_bech32_encoder = AddressBech32Encoder::new(network);
+    let mut context = DecompilationContext::new(&address_bech32_encoder, Default::default());
+    let mut output = String::new();
+    for index in 0..instruction_count {
+        let instruction = reader.decode_with(|decoder| {
+            decoder.decode_deeper_body_with_value_kind::<InstructionV1>(ValueKind::Enum)
+        })?;
+        validate_call_arguments_to_native_components(std::slice::from_ref(&instruction)).map_err(
+            |mut error| {
+                error.instruction_index = index;
+                Error::InstructionSchemaValidationError(error)
+            },
+        )?;
+
+        output.clear();
+        decompile_instruction(&mut output, &instruction, &mut context)
+            .map_err(Error::DecompileError)?;
+        writer.write_all(output.as_bytes()).map_err(Error::IoError)?;
+    }
+    writer.flush().map_err(Error::IoError)?;
+
+    let (key_value_kind, value_value_kind, blob_count) = reader.decode_with(|decoder| {
+        decoder.read_and_check_value_kind(ValueKind::Map)?;
+        let key_value_kind = decoder.read_value_kind()?;
+        let value_value_kind = decoder.read_value_kind()?;
+        Ok((key_value_kind, value_value_kind, decoder.read_size()?))
+    })?;
+    for _ in 0..blob_count {
+        let (_, blob) = reader.decode_with(|decoder| {
+            let key = decoder.decode_deeper_body_with_value_kind::<Hash>(key_value_kind)?;
+            let value = decoder.decode_deeper_body_with_value_kind::<Vec<u8>>(value_value_kind)?;
+            Ok((key, value))
+        })?;
+        if let Some(directory) = blob_directory {
+            let blob_hash = hash(&blob);
+            std::fs::write(directory.join(format!("{}.blob", blob_hash)), &blob)
+                .map_err(Error::IoError)?;
+        }
+    }
+
+    reader.check_end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::blueprints::package::PackageDefinition;
+    use transaction::manifest::decompile;
+
+    #[test]
+    fn test_streamed_decompilation_matches_decompile() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .publish_package(vec![7u8; 1000], PackageDefinition::default())
+            .get_free_xrd_from_faucet()
+            .try_deposit_batch_or_abort(account)
+            .build();
+        let payload = manifest_encode(&manifest).unwrap();
+        let network = NetworkDefinition::simulator();
+
+        // A tiny window forces every instruction and the blob to be decoded over several reads
+        let mut reader = ManifestStreamReader::with_window_size(payload.as_slice(), 16);
+        let mut output = Vec::new();
+        decompile_stream(&mut reader, &mut output, &network, None).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            decompile(&manifest.instructions, &network).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_streamed_decompilation_rejects_trailing_bytes() {
+        let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+        let mut payload = manifest_encode(&manifest).unwrap();
+        payload.push(0);
+
+        let mut reader = ManifestStreamReader::new(payload.as_slice());
+        let result = decompile_stream(
+            &mut reader,
+            &mut Vec::new(),
+            &NetworkDefinition::simulator(),
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::DecodeError(DecodeError::ExtraTrailingBytes(1)))
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+use clap::Parser;
+use colored::*;
+use radix_engine_interface::blueprints::package::{compare_package_definitions, PackageDefinition};
+use radix_engine_interface::data::manifest::manifest_decode;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::scrypto::*;
+
+/// Compare two package definitions and report the changes, failing on breaking ones
+#[derive(Parser, Debug)]
+pub struct Compare {
+    /// The package definition (.rpd) of the old version
+    old: PathBuf,
+
+    /// The package definition (.rpd) of the new version
+    new: PathBuf,
+}
+
+impl Compare {
+    pub fn run(&self) -> Result<(), Error> {
+        let old = read_definition(&self.old)?;
+        let new = read_definition(&self.new)?;
+
+        let changes = compare_package_definitions(&old, &new);
+        let breaking_changes = changes.iter().filter(|change| change.is_breaking()).count();
+        for change in &changes {
+            if change.is_breaking() {
+                println!("{} {}", "breaking:".red(), change);
+            } else {
+                println!("{} {}", "compatible:".green(), change);
+            }
+        }
+        if changes.is_empty() {
+            println!("No changes");
+        }
+
+        if breaking_changes > 0 {
+            Err(Error::BreakingSchemaChanges(breaking_changes))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn read_definition(path: &Path) -> Result<PackageDefinition, Error> {
+    manifest_decode(&fs::read(path).map_err(Error::IOError)?).map_err(Error::DefinitionDecodeError)
+}
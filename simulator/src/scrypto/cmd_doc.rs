@@ -0,0 +1,79 @@
+use clap::Parser;
+use radix_engine_interface::blueprints::package::{
+    generate_package_html_docs, generate_package_markdown_docs, PackageDefinition,
+};
+use radix_engine_interface::data::manifest::manifest_decode;
+use std::env::current_dir;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::scrypto::*;
+use crate::utils::*;
+
+/// The format of the generated documentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocFormat {
+    Markdown,
+    Html,
+}
+
+impl FromStr for DocFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            _ => Err(format!(
+                "Invalid documentation format {}, expected markdown or html",
+                s
+            )),
+        }
+    }
+}
+
+/// Generate the documentation of the blueprints of a Scrypto package from its schema
+#[derive(Parser, Debug)]
+pub struct Doc {
+    /// The package directory
+    #[clap(long)]
+    path: Option<PathBuf>,
+
+    /// The format of the documentation, [markdown | html]
+    #[clap(short, long, default_value = "markdown")]
+    format: DocFormat,
+
+    /// The output file, printed to stdout if not specified
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    trace: bool,
+}
+
+impl Doc {
+    pub fn run(&self) -> Result<(), Error> {
+        let (_, definition_path) = build_package(
+            self.path.clone().unwrap_or(current_dir().unwrap()),
+            self.trace,
+            false,
+        )
+        .map_err(Error::BuildError)?;
+        let definition: PackageDefinition =
+            manifest_decode(&fs::read(&definition_path).map_err(Error::IOError)?)
+                .map_err(Error::DefinitionDecodeError)?;
+
+        let docs = match self.format {
+            DocFormat::Markdown => generate_package_markdown_docs(&definition),
+            DocFormat::Html => generate_package_html_docs(&definition),
+        };
+        match &self.output {
+            Some(output) => fs::write(output, docs).map_err(Error::IOError)?,
+            None => println!("{}", docs),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use clap::Parser;
+use radix_engine_interface::blueprints::package::{
+    generate_event_json_schema, generate_event_typescript_types, PackageDefinition,
+};
+use radix_engine_interface::data::manifest::manifest_decode;
+use std::env::current_dir;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::scrypto::*;
+use crate::utils::*;
+
+/// Generate the JSON schema and TypeScript types of the events of a Scrypto package
+#[derive(Parser, Debug)]
+pub struct GenerateEventSchemas {
+    /// The package directory
+    #[clap(long)]
+    path: Option<PathBuf>,
+
+    /// The output file of the JSON schema, printed to stdout if not specified
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// The output file of the TypeScript types, not generated if not specified
+    #[clap(long)]
+    typescript: Option<PathBuf>,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    trace: bool,
+}
+
+impl GenerateEventSchemas {
+    pub fn run(&self) -> Result<(), Error> {
+        let (_, definition_path) = build_package(
+            self.path.clone().unwrap_or(current_dir().unwrap()),
+            self.trace,
+            false,
+        )
+        .map_err(Error::BuildError)?;
+        let definition: PackageDefinition =
+            manifest_decode(&fs::read(&definition_path).map_err(Error::IOError)?)
+                .map_err(Error::DefinitionDecodeError)?;
+
+        let json_schema = serde_json::to_string_pretty(&generate_event_json_schema(&definition))
+            .expect("JSON values can always be serialized");
+        match &self.output {
+            Some(output) => fs::write(output, json_schema).map_err(Error::IOError)?,
+            None => println!("{}", json_schema),
+        }
+
+        if let Some(typescript) = &self.typescript {
+            fs::write(typescript, generate_event_typescript_types(&definition))
+                .map_err(Error::IOError)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use sbor::DecodeError;
 use std::io;
 
 use crate::utils::*;
@@ -12,5 +13,9 @@ pub enum Error {
 
     FormatError(FormatError),
 
+    DefinitionDecodeError(DecodeError),
+
+    BreakingSchemaChanges(usize),
+
     PackageAlreadyExists,
 }
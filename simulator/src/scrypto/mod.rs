@@ -1,11 +1,17 @@
 mod cmd_build;
+mod cmd_compare;
+mod cmd_doc;
 mod cmd_fmt;
+mod cmd_generate_event_schemas;
 mod cmd_new_package;
 mod cmd_test;
 mod error;
 
 pub use cmd_build::*;
+pub use cmd_compare::*;
+pub use cmd_doc::*;
 pub use cmd_fmt::*;
+pub use cmd_generate_event_schemas::*;
 pub use cmd_new_package::*;
 pub use cmd_test::*;
 pub use error::*;
@@ -23,7 +29,10 @@ pub struct ScryptoCli {
 #[derive(Subcommand, Debug)]
 pub enum Command {
     Build(Build),
+    Compare(Compare),
+    Doc(Doc),
     Fmt(Fmt),
+    GenerateEventSchemas(GenerateEventSchemas),
     NewPackage(NewPackage),
     Test(Test),
 }
@@ -33,7 +42,10 @@ pub fn run() -> Result<(), Error> {
 
     match cli.command {
         Command::Build(cmd) => cmd.run(),
+        Command::Compare(cmd) => cmd.run(),
+        Command::Doc(cmd) => cmd.run(),
         Command::Fmt(cmd) => cmd.run(),
+        Command::GenerateEventSchemas(cmd) => cmd.run(),
         Command::NewPackage(cmd) => cmd.run(),
         Command::Test(cmd) => cmd.run(),
     }
@@ -50,6 +50,12 @@ pub enum BuildCallArgumentError {
 
     /// Failed to interpret this string as a resource specifier
     InvalidResourceSpecifier(String),
+
+    /// The enum variant is not one of the variants of the expected enum.
+    UnknownEnumVariant(String, Vec<String>),
+
+    /// The enum variant is given with a wrong number of fields (actual, expected).
+    WrongNumberOfEnumVariantFields(String, usize, usize),
 }
 
 impl From<BuildCallArgumentsError> for BuildCallInstructionError {
@@ -115,10 +121,8 @@ pub fn build_call_arguments<'a>(
                 let (returned_builder, value) = build_call_argument(
                     builder,
                     address_bech32_decoder,
-                    schema.resolve_type_kind(*f).expect("Inconsistent schema"),
-                    schema
-                        .resolve_type_validation(*f)
-                        .expect("Inconsistent schema"),
+                    schema,
+                    *f,
                     args[i].clone(),
                     account,
                 )?;
@@ -145,14 +149,27 @@ macro_rules! parse_basic_type {
     };
 }
 
+/// Builds a single argument of the type at `type_index` in the schema, out of its string
+/// representation on the command line.
+///
+/// Besides the basic types, addresses, buckets and proofs, the following are supported:
+/// * Enums, given by variant name, e.g. `Red` or `Amount(10)`. An `Option` can also be given
+///   as its inner value directly, e.g. `10` for `Some(10)`.
+/// * Arrays, given as comma separated elements, e.g. `1,2,3`. Byte arrays are given in hex.
 fn build_call_argument<'a>(
     mut builder: ManifestBuilder,
     address_bech32_decoder: &AddressBech32Decoder,
-    type_kind: &ScryptoTypeKind<LocalTypeIndex>,
-    type_validation: &TypeValidation<ScryptoCustomTypeValidation>,
+    schema: &ScryptoSchema,
+    type_index: LocalTypeIndex,
     argument: String,
     account: Option<ComponentAddress>,
 ) -> Result<(ManifestBuilder, ManifestValue), BuildCallArgumentError> {
+    let type_kind = schema
+        .resolve_type_kind(type_index)
+        .expect("Inconsistent schema");
+    let type_validation = schema
+        .resolve_type_validation(type_index)
+        .expect("Inconsistent schema");
     match type_kind {
         ScryptoTypeKind::Bool => parse_basic_type!(builder, argument, Bool),
         ScryptoTypeKind::I8 => parse_basic_type!(builder, argument, I8),
@@ -247,6 +264,23 @@ fn build_call_argument<'a>(
                 },
             ))
         }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Reference)
+            if matches!(
+                type_validation,
+                TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                    ReferenceValidation::IsGlobal | ReferenceValidation::IsGlobalTyped(..)
+                ))
+            ) =>
+        {
+            let value = GlobalAddress::try_from_bech32(&address_bech32_decoder, &argument)
+                .ok_or(BuildCallArgumentError::FailedToParse(argument))?;
+            Ok((
+                builder,
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Address(value.into()),
+                },
+            ))
+        }
         ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Own)
             if matches!(
                 type_validation,
@@ -324,10 +358,238 @@ fn build_call_argument<'a>(
                 },
             ))
         }
+        ScryptoTypeKind::Array { element_type } => build_array_argument(
+            builder,
+            address_bech32_decoder,
+            schema,
+            *element_type,
+            argument,
+            account,
+        ),
+        ScryptoTypeKind::Enum { variants } => build_enum_argument(
+            builder,
+            address_bech32_decoder,
+            schema,
+            type_index,
+            variants,
+            argument,
+            account,
+        ),
         _ => Err(BuildCallArgumentError::UnsupportedType(type_kind.clone())),
     }
 }
 
+fn build_array_argument(
+    mut builder: ManifestBuilder,
+    address_bech32_decoder: &AddressBech32Decoder,
+    schema: &ScryptoSchema,
+    element_type: LocalTypeIndex,
+    argument: String,
+    account: Option<ComponentAddress>,
+) -> Result<(ManifestBuilder, ManifestValue), BuildCallArgumentError> {
+    let element_type_kind = schema
+        .resolve_type_kind(element_type)
+        .expect("Inconsistent schema");
+    let element_type_validation = schema
+        .resolve_type_validation(element_type)
+        .expect("Inconsistent schema");
+
+    // Byte arrays are given in hex rather than element by element
+    if let ScryptoTypeKind::U8 = element_type_kind {
+        let bytes =
+            hex::decode(&argument).map_err(|_| BuildCallArgumentError::FailedToParse(argument))?;
+        return Ok((
+            builder,
+            ManifestValue::Array {
+                element_value_kind: ManifestValueKind::U8,
+                elements: bytes
+                    .into_iter()
+                    .map(|value| ManifestValue::U8 { value })
+                    .collect(),
+            },
+        ));
+    }
+
+    let element_value_kind = manifest_value_kind(element_type_kind, element_type_validation)
+        .ok_or_else(|| BuildCallArgumentError::UnsupportedType(element_type_kind.clone()))?;
+    let mut elements = Vec::new();
+    for element in split_argument(&argument) {
+        let (returned_builder, value) = build_call_argument(
+            builder,
+            address_bech32_decoder,
+            schema,
+            element_type,
+            element,
+            account,
+        )?;
+        builder = returned_builder;
+        elements.push(value);
+    }
+    Ok((
+        builder,
+        ManifestValue::Array {
+            element_value_kind,
+            elements,
+        },
+    ))
+}
+
+fn build_enum_argument(
+    mut builder: ManifestBuilder,
+    address_bech32_decoder: &AddressBech32Decoder,
+    schema: &ScryptoSchema,
+    type_index: LocalTypeIndex,
+    variants: &BTreeMap<u8, Vec<LocalTypeIndex>>,
+    argument: String,
+    account: Option<ComponentAddress>,
+) -> Result<(ManifestBuilder, ManifestValue), BuildCallArgumentError> {
+    let variant_name = |discriminator: u8, fields_length: usize| {
+        schema
+            .resolve_matching_enum_metadata(type_index, discriminator, fields_length)
+            .variant_name
+            .map(|name| name.to_owned())
+            .unwrap_or_else(|| discriminator.to_string())
+    };
+
+    // The variant is given as `Name` or `Name(field1,field2,..)`
+    let (name, fields) = match argument.find('(') {
+        Some(index) if argument.ends_with(')') => (
+            argument[..index].trim(),
+            split_argument(&argument[index + 1..argument.len() - 1]),
+        ),
+        _ => (argument.trim(), Vec::new()),
+    };
+    let variant = variants.iter().find(|(discriminator, field_types)| {
+        variant_name(**discriminator, field_types.len()) == name
+    });
+
+    let (discriminator, field_types, fields) = match variant {
+        Some((discriminator, field_types)) => (*discriminator, field_types, fields),
+        None => {
+            // An option can be given as its inner value directly
+            let is_option = schema
+                .resolve_type_metadata(type_index)
+                .and_then(|metadata| metadata.get_name())
+                == Some("Option");
+            match variants.get(&OPTION_VARIANT_SOME) {
+                Some(field_types) if is_option && field_types.len() == 1 => {
+                    (OPTION_VARIANT_SOME, field_types, vec![argument.clone()])
+                }
+                _ => {
+                    return Err(BuildCallArgumentError::UnknownEnumVariant(
+                        name.to_owned(),
+                        variants
+                            .iter()
+                            .map(|(discriminator, field_types)| {
+                                variant_name(*discriminator, field_types.len())
+                            })
+                            .collect(),
+                    ))
+                }
+            }
+        }
+    };
+
+    if fields.len() != field_types.len() {
+        return Err(BuildCallArgumentError::WrongNumberOfEnumVariantFields(
+            argument,
+            fields.len(),
+            field_types.len(),
+        ));
+    }
+    let mut built_fields = Vec::new();
+    for (field_type, field) in field_types.iter().zip(fields) {
+        let (returned_builder, value) = build_call_argument(
+            builder,
+            address_bech32_decoder,
+            schema,
+            *field_type,
+            field,
+            account,
+        )?;
+        builder = returned_builder;
+        built_fields.push(value);
+    }
+    Ok((
+        builder,
+        ManifestValue::Enum {
+            discriminator,
+            fields: built_fields,
+        },
+    ))
+}
+
+/// Returns the kind of the manifest values built for the given type, if supported.
+fn manifest_value_kind(
+    type_kind: &ScryptoTypeKind<LocalTypeIndex>,
+    type_validation: &TypeValidation<ScryptoCustomTypeValidation>,
+) -> Option<ManifestValueKind> {
+    let value_kind = match type_kind {
+        ScryptoTypeKind::Bool => ManifestValueKind::Bool,
+        ScryptoTypeKind::I8 => ManifestValueKind::I8,
+        ScryptoTypeKind::I16 => ManifestValueKind::I16,
+        ScryptoTypeKind::I32 => ManifestValueKind::I32,
+        ScryptoTypeKind::I64 => ManifestValueKind::I64,
+        ScryptoTypeKind::I128 => ManifestValueKind::I128,
+        ScryptoTypeKind::U8 => ManifestValueKind::U8,
+        ScryptoTypeKind::U16 => ManifestValueKind::U16,
+        ScryptoTypeKind::U32 => ManifestValueKind::U32,
+        ScryptoTypeKind::U64 => ManifestValueKind::U64,
+        ScryptoTypeKind::U128 => ManifestValueKind::U128,
+        ScryptoTypeKind::String => ManifestValueKind::String,
+        ScryptoTypeKind::Array { .. } => ManifestValueKind::Array,
+        ScryptoTypeKind::Enum { .. } => ManifestValueKind::Enum,
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Decimal) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::Decimal)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::PreciseDecimal)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::NonFungibleLocalId)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::Address)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Own) => match type_validation {
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(OwnValidation::IsBucket)) => {
+                ManifestValueKind::Custom(ManifestCustomValueKind::Bucket)
+            }
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(OwnValidation::IsProof)) => {
+                ManifestValueKind::Custom(ManifestCustomValueKind::Proof)
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(value_kind)
+}
+
+/// Splits a composite argument at its top-level commas, i.e. the ones which are not nested
+/// within brackets, e.g. `Some(1,2),<a,b>,3` into `Some(1,2)`, `<a,b>` and `3`.
+fn split_argument(argument: &str) -> Vec<String> {
+    if argument.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in argument.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(argument[start..index].trim().to_owned());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(argument[start..].trim().to_owned());
+    parts
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -618,6 +880,125 @@ mod test {
         assert_eq!(parsed_arg, NonFungibleLocalId::integer(12))
     }
 
+    #[test]
+    pub fn parsing_of_global_address_succeeds() {
+        // Arrange
+        let component_address = component_address(EntityType::GlobalAccount, 5);
+
+        let arg = AddressBech32Encoder::for_simulator()
+            .encode(component_address.as_ref())
+            .unwrap();
+        let type_kind = ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Reference);
+        let type_validation = TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+            ReferenceValidation::IsGlobal,
+        ));
+
+        // Act
+        let parsed_arg: GlobalAddress =
+            build_and_decode_arg(arg, type_kind, type_validation).expect("Failed to parse arg");
+
+        // Assert
+        assert_eq!(parsed_arg, component_address.into())
+    }
+
+    #[test]
+    pub fn parsing_of_option_succeeds() {
+        // Act & Assert
+        assert_eq!(
+            build_and_decode_typed_arg::<Option<Decimal>>("None").unwrap(),
+            None
+        );
+        assert_eq!(
+            build_and_decode_typed_arg::<Option<Decimal>>("Some(12)").unwrap(),
+            Some(dec!("12"))
+        );
+        assert_eq!(
+            build_and_decode_typed_arg::<Option<Decimal>>("12").unwrap(),
+            Some(dec!("12"))
+        );
+    }
+
+    #[test]
+    pub fn parsing_of_array_succeeds() {
+        // Act & Assert
+        assert_eq!(
+            build_and_decode_typed_arg::<Vec<u32>>("1,2, 3").unwrap(),
+            vec![1u32, 2, 3]
+        );
+        assert_eq!(
+            build_and_decode_typed_arg::<Vec<u32>>("").unwrap(),
+            Vec::<u32>::new()
+        );
+        assert_eq!(
+            build_and_decode_typed_arg::<Vec<Option<u8>>>("Some(1),None,3").unwrap(),
+            vec![Some(1u8), None, Some(3)]
+        );
+        assert_eq!(
+            build_and_decode_typed_arg::<Vec<u8>>("0a0b").unwrap(),
+            vec![10u8, 11]
+        );
+    }
+
+    #[test]
+    pub fn parsing_of_enum_by_variant_name_succeeds() {
+        // Act & Assert
+        assert_eq!(
+            build_and_decode_typed_arg::<TestEnum>("Red").unwrap(),
+            TestEnum::Red
+        );
+        assert_eq!(
+            build_and_decode_typed_arg::<TestEnum>("Amount(1.5)").unwrap(),
+            TestEnum::Amount(dec!("1.5"))
+        );
+        assert_eq!(
+            build_and_decode_typed_arg::<TestEnum>("Pair(1, hello)").unwrap(),
+            TestEnum::Pair(1, "hello".to_owned())
+        );
+    }
+
+    #[test]
+    pub fn parsing_of_unknown_enum_variant_fails() {
+        // Act
+        let result = build_and_decode_typed_arg::<TestEnum>("Blue");
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(BuildAndDecodeArgError::BuildCallArgumentError(
+                BuildCallArgumentError::UnknownEnumVariant(variant, expected)
+            )) if variant == "Blue" && expected == vec!["Red", "Amount", "Pair"]
+        ));
+    }
+
+    #[test]
+    pub fn parsing_of_enum_variant_with_wrong_number_of_fields_fails() {
+        // Act
+        let result = build_and_decode_typed_arg::<TestEnum>("Amount");
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(BuildAndDecodeArgError::BuildCallArgumentError(
+                BuildCallArgumentError::WrongNumberOfEnumVariantFields(_, 0, 1)
+            ))
+        ));
+    }
+
+    #[derive(Debug, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+    pub enum TestEnum {
+        Red,
+        Amount(Decimal),
+        Pair(u32, String),
+    }
+
+    pub fn build_and_decode_typed_arg<T: ScryptoDescribe + ManifestDecode>(
+        arg: &str,
+    ) -> Result<T, BuildAndDecodeArgError> {
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<T, ScryptoCustomSchema>();
+        build_and_decode_arg_with_schema(arg, &schema, type_index)
+    }
+
     #[test]
     pub fn parsing_of_ruid_non_fungible_local_id_succeeds() {
         // Arrange
@@ -644,13 +1025,26 @@ mod test {
         arg: S,
         type_kind: ScryptoTypeKind<LocalTypeIndex>,
         type_validation: TypeValidation<ScryptoCustomTypeValidation>,
+    ) -> Result<T, BuildAndDecodeArgError> {
+        let schema = ScryptoSchema {
+            type_kinds: vec![type_kind],
+            type_metadata: vec![TypeMetadata::unnamed()],
+            type_validations: vec![type_validation],
+        };
+        build_and_decode_arg_with_schema(arg, &schema, LocalTypeIndex::SchemaLocalIndex(0))
+    }
+
+    pub fn build_and_decode_arg_with_schema<S: AsRef<str>, T: ManifestDecode>(
+        arg: S,
+        schema: &ScryptoSchema,
+        type_index: LocalTypeIndex,
     ) -> Result<T, BuildAndDecodeArgError> {
         let builder = ManifestBuilder::new();
         let (_, built_arg) = build_call_argument(
             builder,
             &AddressBech32Decoder::for_simulator(),
-            &type_kind,
-            &type_validation,
+            schema,
+            type_index,
             arg.as_ref().to_owned(),
             None,
         )
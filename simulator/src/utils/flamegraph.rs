@@ -0,0 +1,191 @@
+use radix_engine::system::system_modules::profiling::ExecutionProfile;
+use std::collections::BTreeMap;
+
+const IMAGE_WIDTH: f64 = 1200.0;
+const FRAME_HEIGHT: f64 = 16.0;
+const TITLE_HEIGHT: f64 = 32.0;
+const FONT_WIDTH: f64 = 7.0;
+
+#[derive(Default)]
+struct FlameNode {
+    self_time: u64,
+    children: BTreeMap<String, FlameNode>,
+}
+
+impl FlameNode {
+    fn total_time(&self) -> u64 {
+        self.self_time
+            + self
+                .children
+                .values()
+                .map(|child| child.total_time())
+                .sum::<u64>()
+    }
+
+    fn depth(&self) -> usize {
+        self.children
+            .values()
+            .map(|child| child.depth() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Renders an execution profile as a flamegraph SVG, with callers at the bottom.
+pub fn render_flamegraph(profile: &ExecutionProfile, title: &str) -> String {
+    let mut root = FlameNode::default();
+    for (stack, nanos) in &profile.stacks {
+        let mut node = &mut root;
+        for frame in stack.split(';') {
+            node = node.children.entry(frame.to_string()).or_default();
+        }
+        node.self_time += nanos;
+    }
+
+    let total_time = root.total_time().max(1);
+    let depth = root.depth();
+    let height = TITLE_HEIGHT + FRAME_HEIGHT * depth as f64 + FRAME_HEIGHT;
+
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg version=\"1.1\" width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\" font-family=\"monospace\" font-size=\"12\">\n",
+        IMAGE_WIDTH, height
+    ));
+    svg.push_str(&format!(
+        "<rect width=\"100%\" height=\"100%\" fill=\"#f8f8f8\"/>\n<text x=\"{}\" y=\"20\" text-anchor=\"middle\" font-size=\"16\">{}</text>\n",
+        IMAGE_WIDTH / 2.0,
+        escape(title)
+    ));
+    svg.push_str(&format!(
+        "<text x=\"10\" y=\"{}\">substate reads: {} ({} bytes), substate writes: {} ({} bytes)</text>\n",
+        height - 4.0,
+        profile.substate_reads,
+        profile.substate_read_bytes,
+        profile.substate_writes,
+        profile.substate_write_bytes,
+    ));
+
+    let mut x = 0.0;
+    for (name, child) in &root.children {
+        x += render_node(&mut svg, name, child, x, 0, depth, total_time);
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn render_node(
+    svg: &mut String,
+    name: &str,
+    node: &FlameNode,
+    x: f64,
+    level: usize,
+    depth: usize,
+    total_time: u64,
+) -> f64 {
+    let time = node.total_time();
+    let width = IMAGE_WIDTH * time as f64 / total_time as f64;
+    let y = TITLE_HEIGHT + FRAME_HEIGHT * (depth - level - 1) as f64;
+
+    svg.push_str(&format!(
+        "<g><title>{} ({:.3} ms, {:.2}%)</title><rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{}\" fill=\"{}\" rx=\"2\"/>",
+        escape(name),
+        time as f64 / 1_000_000.0,
+        time as f64 * 100.0 / total_time as f64,
+        x,
+        y,
+        width,
+        FRAME_HEIGHT - 1.0,
+        color(name),
+    ));
+    let max_chars = ((width - 6.0) / FONT_WIDTH).floor() as usize;
+    if max_chars >= 3 {
+        let label = if name.chars().count() > max_chars {
+            format!("{}..", name.chars().take(max_chars - 2).collect::<String>())
+        } else {
+            name.to_string()
+        };
+        svg.push_str(&format!(
+            "<text x=\"{:.2}\" y=\"{:.2}\">{}</text>",
+            x + 3.0,
+            y + FRAME_HEIGHT - 4.0,
+            escape(&label)
+        ));
+    }
+    svg.push_str("</g>\n");
+
+    let mut child_x = x;
+    for (child_name, child) in &node.children {
+        child_x += render_node(
+            svg,
+            child_name,
+            child,
+            child_x,
+            level + 1,
+            depth,
+            total_time,
+        );
+    }
+
+    width
+}
+
+fn color(name: &str) -> String {
+    let hash = name.bytes().fold(0u32, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(byte as u32)
+    });
+    if name.starts_with("wasm:") {
+        format!(
+            "rgb({},{},{})",
+            80 + hash % 60,
+            160 + hash % 80,
+            80 + hash % 40
+        )
+    } else if name == "open_substate" {
+        format!(
+            "rgb({},{},{})",
+            80 + hash % 40,
+            140 + hash % 60,
+            200 + hash % 55
+        )
+    } else {
+        format!(
+            "rgb({},{},{})",
+            200 + hash % 55,
+            80 + hash % 130,
+            40 + hash % 40
+        )
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine::types::*;
+
+    #[test]
+    fn test_render_flamegraph() {
+        let mut stacks = index_map_new();
+        stacks.insert("Account::withdraw".to_string(), 1_000_000);
+        stacks.insert("Account::withdraw;wasm:withdraw".to_string(), 3_000_000);
+        stacks.insert("Account::withdraw;open_substate".to_string(), 1_000_000);
+        let profile = ExecutionProfile {
+            stacks,
+            ..Default::default()
+        };
+
+        let svg = render_flamegraph(&profile, "Vault<Account>");
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Vault&lt;Account&gt;"));
+        assert!(svg.contains("<title>Account::withdraw (5.000 ms, 100.00%)</title>"));
+        assert!(svg.contains("<title>wasm:withdraw (3.000 ms, 60.00%)</title>"));
+    }
+}
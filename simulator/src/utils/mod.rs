@@ -1,11 +1,13 @@
 mod cargo;
 mod common_instructions;
 mod display;
+mod flamegraph;
 mod iter;
 mod resource_specifier;
 
 pub use cargo::*;
 pub use common_instructions::*;
 pub use display::list_item_prefix;
+pub use flamegraph::render_flamegraph;
 pub use iter::{IdentifyLast, Iter};
 pub use resource_specifier::*;
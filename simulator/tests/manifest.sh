@@ -19,6 +19,7 @@ output=`$resim run ./tests/m1.rtm | awk '/Component:|Resource:/ {print $NF}'`
 export component=`echo $output | cut -d " " -f1`
 export resource=`echo $output | cut -d " " -f2`
 
-$resim run ./tests/m2.rtm
+tx_hash=`$resim run ./tests/m2.rtm --message "Thanks for the tokens" | awk '/Transaction Hash:/ {print $NF}'`
+$resim show-tx $tx_hash | grep "Thanks for the tokens"
 
 $resim show-ledger
\ No newline at end of file
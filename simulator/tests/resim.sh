@@ -37,6 +37,12 @@ if [[ ${ledger_state} != *"2023-01-27T13:01:00Z"* ]];then
     echo "Time not set!"
     exit 1
 fi
+$resim set-time 2023-02-01T08:30:00Z
+ledger_state=`$resim show-ledger`
+if [[ ${ledger_state} != *"2023-02-01T08:30:00Z"* ]];then
+    echo "Time not set!"
+    exit 1
+fi
 
 # Test - show account
 # TODO: renable after showing resource metadata in component dump
@@ -64,6 +70,7 @@ $resim call-method $account2 try_deposit_or_abort "$non_fungible_global_id"
 # Test - mint and transfer (Mintable that requires a `ResourceAddress`)
 $resim mint 777 $token_address --proofs $minter_badge:1
 $resim transfer 111 $token_address $account2
+$resim show $account2 --resources $token_address $minter_badge
 
 # Test - publish, call-function and call-method and non-fungibles
 owner_badge=`$resim new-simple-badge --name 'OwnerBadge' | awk '/NonFungibleGlobalId:/ {print $NF}'`
@@ -74,8 +81,18 @@ $resim call-method $component free_token
 # Test - export package definition
 $resim export-package-definition $package target/temp.rpd
 
+# Test - export new entity addresses
+rm -f target/addresses.json
+$resim publish ../examples/hello-world --owner-badge $owner_badge --export-addresses target/addresses.json
+$resim call-function $package Hello instantiate_hello --export-new-entities target/addresses.json
+exported=`cat target/addresses.json`
+if [[ ${exported} != *"\"label\": \"hello-world\""* ]] || [[ ${exported} != *"\"label\": \"Hello::instantiate_hello\""* ]];then
+    echo "Addresses not exported!"
+    exit 1
+fi
+
 # Test - dump component state
-$resim show $package
+$resim show $package | grep -q "Unclaimed Royalties"
 $resim show $component
 $resim show $account
 $resim show $account2
@@ -92,8 +109,23 @@ $resim run ./target/temp2.rtm --blobs $blobs
 $resim new-account --manifest ./target/temp3.rtm
 $resim run ./target/temp3.rtm
 
+# Test - profile a manifest run
+$resim run ./target/temp3.rtm --profile ./target/profile.svg
+grep -q "<svg" ./target/profile.svg
+$resim run ./target/temp3.rtm --profile ./target/profile.folded
+grep -q "TransactionProcessor::run" ./target/profile.folded
+
+# Test - run a directory of manifests
+rm -rf ./target/batch && mkdir -p ./target/batch
+cp ./target/temp3.rtm ./target/batch/01_new_account.rtm
+echo 'CALL_METHOD Address("${account}") "lock_fee" Decimal("${fee}");' > ./target/batch/02_lock_fee.rtm
+printf 'fee = "10"\n\n[02_lock_fee]\naccount = "%s"\n' $account > ./target/batch/vars.toml
+$resim run-batch ./target/batch
+test -f ./target/batch/02_lock_fee.receipt
+
 # Test - run manifest with a given set of signing keys
 $resim generate-key-pair
+$resim keygen --network simulator
 $resim run ./target/temp2.rtm --blobs $blobs
 
 # Test - nft
@@ -130,3 +162,32 @@ token_address=`$resim new-token-mutable $non_fungible_global_id | awk '/Resource
 
 # Test - mint and transfer (Mintable that requires a `NonFungibleGlobalId`)
 $resim mint 777 $token_address --proofs "$non_fungible_global_id"
+
+# Test - access controller recovery
+primary_badge=`$resim new-badge-fixed 1 --name 'PrimaryBadge' | awk '/Resource:/ {print $NF}'`
+recovery_badge=`$resim new-badge-fixed 1 --name 'RecoveryBadge' | awk '/Resource:/ {print $NF}'`
+confirmation_badge=`$resim new-badge-fixed 1 --name 'ConfirmationBadge' | awk '/Resource:/ {print $NF}'`
+new_primary_badge=`$resim new-badge-fixed 1 --name 'NewPrimaryBadge' | awk '/Resource:/ {print $NF}'`
+access_controller=`$resim access-controller create $token_address:10 --primary-badge $primary_badge --recovery-badge $recovery_badge --confirmation-badge $confirmation_badge --timed-recovery-delay-in-minutes 10 | awk '/Component:/ {print $NF}'`
+$resim access-controller initiate-recovery $access_controller recovery --primary-badge $new_primary_badge --recovery-badge $recovery_badge --confirmation-badge $confirmation_badge --timed-recovery-delay-in-minutes 10 --proofs $recovery_badge:1
+$resim access-controller quick-confirm $access_controller recovery --primary-badge $new_primary_badge --recovery-badge $recovery_badge --confirmation-badge $confirmation_badge --timed-recovery-delay-in-minutes 10 --proofs $confirmation_badge:1
+$resim access-controller initiate-recovery $access_controller recovery --primary-badge $primary_badge --recovery-badge $recovery_badge --confirmation-badge $confirmation_badge --proofs $recovery_badge:1
+$resim set-current-time 2023-02-01T09:00:00Z
+$resim access-controller timed-confirm $access_controller --primary-badge $primary_badge --recovery-badge $recovery_badge --confirmation-badge $confirmation_badge --proofs $recovery_badge:1
+
+# Test - ledger pruning
+$resim ledger prune --retained-state-versions 10
+
+# Test - ledger export
+$resim ledger export target/ledger.csv
+grep '^node_id,entity_type,partition_number,sort_key,blueprint,blueprint_version,size,value$' target/ledger.csv
+grep ':Hello,1.0.0,' target/ledger.csv
+$resim ledger export target/ledger.parquet --format parquet
+
+# Test - genesis export
+$resim ledger export-genesis target/genesis.sbor | grep 'Exported'
+test -s target/genesis.sbor
+
+# Test - machine-readable errors
+error_output=`$resim show component_sim1invalid --output json || true`
+echo "$error_output" | grep '"status":"error"'
@@ -15,6 +15,13 @@ $scrypto new-package hello-world --path $test_pkg --local
 # Build
 $scrypto build --path $test_pkg
 
+# Generate event schemas
+$scrypto generate-event-schemas --path $test_pkg --output $test_pkg/events.json --typescript $test_pkg/events.ts
+
+# Generate docs
+$scrypto doc --path $test_pkg --output $test_pkg/docs.md
+$scrypto doc --path $test_pkg --format html --output $test_pkg/docs.html
+
 # Test
 $scrypto test --path $test_pkg
 $scrypto test --path $test_pkg -- test_hello --nocapture
@@ -1,11 +1,16 @@
 use radix_engine::{
-    system::bootstrap::Bootstrapper,
+    blueprints::consensus_manager::{
+        ConsensusManagerConfigSubstate, ConsensusManagerSubstate, ProposerMilliTimestampSubstate,
+    },
+    system::bootstrap::*,
     vm::{
         wasm::{DefaultWasmEngine, WasmEngine},
         ScryptoVm,
     },
 };
-use radix_engine_store_interface::interface::*;
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_store_interface::{db_key_mapper::*, interface::*};
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use transaction::validation::{NotarizedTransactionValidator, ValidationConfig};
 
@@ -25,8 +30,44 @@ pub fn run_all_in_memory_and_dump_examples(
     let mut substate_db = InMemorySubstateDatabase::standard();
     let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
 
+    // A single genesis validator is required for the scenarios to be able to advance the epoch
+    let validator_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let staker_account = ComponentAddress::virtual_account_from_public_key(&validator_key);
     let receipts = Bootstrapper::new(&mut substate_db, &scrypto_vm, false)
-        .bootstrap_test_default()
+        .bootstrap_with_genesis_data(
+            vec![
+                GenesisDataChunk::Validators(vec![validator_key.clone().into()]),
+                GenesisDataChunk::Stakes {
+                    accounts: vec![staker_account],
+                    allocations: vec![(
+                        validator_key,
+                        vec![GenesisStakeAllocation {
+                            account_index: 0,
+                            xrd_amount: Decimal::one(),
+                        }],
+                    )],
+                },
+            ],
+            Epoch::of(1),
+            ConsensusManagerConfig {
+                max_validators: 10,
+                epoch_change_condition: EpochChangeCondition {
+                    min_round_count: 1,
+                    max_round_count: 1,
+                    target_duration_millis: 0,
+                },
+                num_unstake_epochs: 1,
+                total_emission_xrd_per_epoch: Decimal::one(),
+                min_validator_reliability: Decimal::one(),
+                num_owner_stake_units_unlock_epochs: 2,
+                num_fee_increase_delay_epochs: 1,
+                validator_creation_usd_cost: *DEFAULT_VALIDATOR_USD_COST,
+                usd_price_in_xrd: Decimal::try_from(DEFAULT_USD_PRICE_IN_XRD).unwrap(),
+            },
+            1,
+            Some(0),
+            *DEFAULT_TESTING_FAUCET_SUPPLY,
+        )
         .unwrap();
     let epoch = receipts
         .wrap_up_receipt
@@ -117,11 +158,139 @@ where
                     &transaction.get_executable(),
                 ));
             }
+            NextAction::AdvanceConsensus(advance) => {
+                previous = Some(advance_consensus(
+                    substate_db,
+                    scrypto_interpreter,
+                    fee_reserve_config,
+                    &advance,
+                ));
+            }
             NextAction::Completed(end_state) => break Ok(end_state),
         }
     }
 }
 
+/// Executes the consensus manager system transactions progressing the ledger as requested, as if
+/// the rounds were proposed by the first validator of the validator set.
+///
+/// Returns the receipt of the last executed transaction, or of the first failed one.
+pub fn advance_consensus<S, W>(
+    substate_db: &mut S,
+    scrypto_interpreter: &ScryptoVm<W>,
+    fee_reserve_config: &FeeReserveConfig,
+    advance: &ConsensusAdvance,
+) -> TransactionReceipt
+where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    W: WasmEngine,
+{
+    let elapsed_ms = i64::try_from(advance.time.as_millis()).expect("Time advance too large");
+    let mut rounds = Vec::new();
+    if elapsed_ms > 0 {
+        rounds.push((false, elapsed_ms));
+    }
+    rounds.extend((0..advance.epochs).map(|_| (true, 0)));
+
+    let mut receipt = None;
+    for (change_epoch, elapsed_ms) in rounds {
+        let round_receipt = execute_next_round(
+            substate_db,
+            scrypto_interpreter,
+            fee_reserve_config,
+            change_epoch,
+            elapsed_ms,
+        );
+        let is_success = round_receipt.is_commit_success();
+        receipt = Some(round_receipt);
+        if !is_success {
+            break;
+        }
+    }
+    receipt.expect("Consensus advance should not be empty")
+}
+
+fn execute_next_round<S, W>(
+    substate_db: &mut S,
+    scrypto_interpreter: &ScryptoVm<W>,
+    fee_reserve_config: &FeeReserveConfig,
+    change_epoch: bool,
+    elapsed_ms: i64,
+) -> TransactionReceipt
+where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    W: WasmEngine,
+{
+    let manager = substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, ConsensusManagerSubstate>(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::ConsensusManager.into(),
+        )
+        .unwrap();
+    let config = substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, ConsensusManagerConfigSubstate>(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::Config.into(),
+        )
+        .unwrap()
+        .config;
+    let proposer_timestamp_ms = substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, ProposerMilliTimestampSubstate>(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::CurrentTime.into(),
+        )
+        .unwrap()
+        .epoch_milli;
+
+    let next_round_number = manager.round.number() + 1;
+    let round = if change_epoch {
+        // Reaching the maximum round count always changes the epoch
+        Round::of(
+            config
+                .epoch_change_condition
+                .max_round_count
+                .max(next_round_number),
+        )
+    } else {
+        Round::of(next_round_number)
+    };
+    let transaction = SystemTransactionV1 {
+        instructions: InstructionsV1(vec![InstructionV1::CallMethod {
+            address: CONSENSUS_MANAGER.into(),
+            method_name: CONSENSUS_MANAGER_NEXT_ROUND_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&ConsensusManagerNextRoundInput {
+                round,
+                proposer_timestamp_ms: proposer_timestamp_ms + elapsed_ms,
+                leader_proposal_history: LeaderProposalHistory {
+                    gap_round_leaders: (next_round_number..round.number()).map(|_| 0).collect(),
+                    current_leader: 0,
+                    is_fallback: false,
+                },
+            }),
+        }]),
+        blobs: BlobsV1 { blobs: vec![] },
+        hash_for_execution: hash(format!(
+            "Scenario next round: {} {}",
+            manager.epoch.number(),
+            round.number()
+        )),
+        pre_allocated_addresses: vec![],
+    }
+    .prepare()
+    .expect("Next round transaction should be preparable");
+
+    execute_and_commit_transaction(
+        substate_db,
+        scrypto_interpreter,
+        fee_reserve_config,
+        &ExecutionConfig::for_system_transaction(),
+        &transaction.get_executable(btreeset![AuthAddresses::validator_role()]),
+    )
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod test {
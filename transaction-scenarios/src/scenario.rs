@@ -1,3 +1,4 @@
+use core::time::Duration;
 use radix_engine::errors::RuntimeError;
 use transaction::errors::TransactionValidationError;
 use transaction::manifest::decompiler::ManifestObjectNames;
@@ -76,6 +77,23 @@ impl Completeable for ManifestBuilder {
     }
 }
 
+/// A request to progress the ledger through consensus, by the means of consensus manager
+/// system transactions executed by the runner between two stages of a scenario.
+///
+/// The time is advanced first, by a single round. Then each epoch is advanced by a round which
+/// meets the epoch change condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConsensusAdvance {
+    pub epochs: u64,
+    pub time: Duration,
+}
+
+impl ConsensusAdvance {
+    pub fn is_empty(&self) -> bool {
+        self.epochs == 0 && self.time.is_zero()
+    }
+}
+
 /// A core set of functionality and utilities common to every scenario
 pub struct ScenarioCore {
     network: NetworkDefinition,
@@ -84,6 +102,8 @@ pub struct ScenarioCore {
     default_notary: PrivateKey,
     last_transaction_name: Option<String>,
     stage_counter: usize,
+    pending_consensus_advance: ConsensusAdvance,
+    consensus_advance_in_progress: bool,
 }
 
 impl ScenarioCore {
@@ -95,6 +115,8 @@ impl ScenarioCore {
             default_notary: ed25519_account_1().key,
             last_transaction_name: None,
             stage_counter: 0,
+            pending_consensus_advance: ConsensusAdvance::default(),
+            consensus_advance_in_progress: false,
         }
     }
 
@@ -148,6 +170,44 @@ impl ScenarioCore {
         self.nonce
     }
 
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Requests the ledger to progress by the given number of epochs before the next stage of the
+    /// scenario. The epoch of the subsequent transactions is updated accordingly.
+    pub fn advance_epochs(&mut self, epochs: u64) {
+        self.pending_consensus_advance.epochs += epochs;
+    }
+
+    /// Requests the proposer timestamp to progress by the given duration before the next stage of
+    /// the scenario. Note that this may result in an epoch change.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.pending_consensus_advance.time += duration;
+    }
+
+    fn take_pending_consensus_advance(&mut self) -> Option<ConsensusAdvance> {
+        let advance = core::mem::take(&mut self.pending_consensus_advance);
+        if advance.is_empty() {
+            return None;
+        }
+        self.consensus_advance_in_progress = true;
+        self.last_transaction_name = Some("consensus-advance".to_owned());
+        Some(advance)
+    }
+
+    fn check_consensus_advance(
+        &mut self,
+        receipt: &TransactionReceipt,
+    ) -> Result<(), ScenarioError> {
+        self.consensus_advance_in_progress = false;
+        let commit_result = self.check_commit_success(receipt)?;
+        if let Some(next_epoch) = commit_result.next_epoch() {
+            self.epoch = next_epoch.epoch;
+        }
+        Ok(())
+    }
+
     pub fn next_transaction(
         &mut self,
         logical_name: &str,
@@ -291,6 +351,9 @@ impl ScenarioError {
 
 pub enum NextAction {
     Transaction(NextTransaction),
+    /// The runner should progress the ledger as requested, and provide the receipt of the last
+    /// system transaction it executes for that purpose.
+    AdvanceConsensus(ConsensusAdvance),
     Completed(EndState),
 }
 
@@ -541,15 +604,24 @@ impl<Config, State> ScenarioInstance for Scenario<Config, State> {
 
     fn next(&mut self, previous: Option<&TransactionReceipt>) -> Result<NextAction, ScenarioError> {
         let core = &mut self.core;
-        let next_transaction_index = core.next_stage() - 1;
-        if next_transaction_index == 0 {
+        if core.consensus_advance_in_progress {
+            let receipt = core.check_previous(&previous)?;
+            core.check_consensus_advance(receipt)?;
+        } else if core.stage_counter == 0 {
             core.check_start(&previous)?;
         } else {
             let receipt = core.check_previous(&previous)?;
-            self.transactions[next_transaction_index - 1]
-                .handler
-                .as_ref()(core, &self.config, &mut self.state, receipt)?;
+            self.transactions[core.stage_counter - 1].handler.as_ref()(
+                core,
+                &self.config,
+                &mut self.state,
+                receipt,
+            )?;
+        }
+        if let Some(advance) = core.take_pending_consensus_advance() {
+            return Ok(NextAction::AdvanceConsensus(advance));
         }
+        let next_transaction_index = core.next_stage() - 1;
         let next_action = if next_transaction_index < self.transactions.len() {
             let next_transaction = self.transactions[next_transaction_index].creator.as_ref()(
                 core,
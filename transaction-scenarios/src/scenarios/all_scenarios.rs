@@ -28,6 +28,9 @@ impl Iterator for AllScenariosIterator {
             5 => Some(Box::new(|core| {
                 non_fungible_resource::NonFungibleResourceScenarioCreator::create(core)
             })),
+            6 => Some(Box::new(|core| {
+                nft_marketplace::NftMarketplaceScenarioCreator::create(core)
+            })),
             _ => None,
         }
     }
@@ -3,6 +3,7 @@ use crate::internal_prelude::*;
 mod all_scenarios;
 pub mod fungible_resource;
 pub mod metadata;
+pub mod nft_marketplace;
 pub mod non_fungible_resource;
 pub mod radiswap;
 pub mod transfer_xrd;
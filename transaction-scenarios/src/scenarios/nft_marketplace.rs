@@ -0,0 +1,343 @@
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::*;
+
+use crate::internal_prelude::*;
+
+pub struct NftMarketplaceScenarioConfig {
+    pub creator_account: VirtualAccount,
+    pub seller_account: VirtualAccount,
+    pub buyer_account_1: VirtualAccount,
+    pub buyer_account_2: VirtualAccount,
+}
+
+impl Default for NftMarketplaceScenarioConfig {
+    fn default() -> Self {
+        Self {
+            creator_account: ed25519_account_for_private_key(512391),
+            seller_account: ed25519_account_for_private_key(512392),
+            buyer_account_1: ed25519_account_for_private_key(512393),
+            buyer_account_2: ed25519_account_for_private_key(512394),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct NftMarketplaceScenarioState {
+    creator_badge: State<NonFungibleGlobalId>,
+    collection: State<ResourceAddress>,
+    marketplace_package: State<PackageAddress>,
+    marketplace: State<ComponentAddress>,
+}
+
+pub struct NftMarketplaceScenarioCreator;
+
+impl ScenarioCreator for NftMarketplaceScenarioCreator {
+    type Config = NftMarketplaceScenarioConfig;
+    type State = NftMarketplaceScenarioState;
+
+    fn create_with_config_and_state(
+        core: ScenarioCore,
+        config: Self::Config,
+        start_state: Self::State,
+    ) -> Box<dyn ScenarioInstance> {
+        let metadata = ScenarioMetadata {
+            logical_name: "nft_marketplace",
+        };
+
+        #[allow(unused_variables)]
+        ScenarioBuilder::new(core, metadata, config, start_state)
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee(
+                        "nft-marketplace-create-creator-badge",
+                        |builder| {
+                            builder
+                                .create_non_fungible_resource(
+                                    OwnerRole::None,
+                                    NonFungibleIdType::Integer,
+                                    true,
+                                    NonFungibleResourceRoles::default(),
+                                    metadata! {
+                                        init {
+                                            "name" => "Collection Creator Badge", locked;
+                                            "description" => "[EXAMPLE] The badge of the creator of an example NFT collection", locked;
+                                            "tags" => ["badge", "nft"], locked;
+                                        }
+                                    },
+                                    Some([
+                                        (NonFungibleLocalId::integer(1), ())
+                                    ]),
+                                )
+                                .try_deposit_batch_or_abort(config.creator_account.address)
+                        },
+                        vec![],
+                    )
+                },
+                |core, config, state, result| {
+                    let new_resources = result.new_resource_addresses();
+                    state.creator_badge.set(NonFungibleGlobalId::new(
+                        new_resources[0],
+                        NonFungibleLocalId::integer(1),
+                    ));
+                    Ok(())
+                },
+            )
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    let creator_rule = rule!(require(state.creator_badge.get()?));
+                    core.next_transaction_with_faucet_lock_fee_fallible(
+                        "nft-marketplace-create-collection",
+                        |builder| {
+                            builder
+                                .create_non_fungible_resource(
+                                    radix_engine::types::OwnerRole::Fixed(creator_rule.clone()),
+                                    NonFungibleIdType::Integer,
+                                    true,
+                                    NonFungibleResourceRoles {
+                                        mint_roles: mint_roles! {
+                                            minter => creator_rule.clone();
+                                            minter_updater => rule!(deny_all);
+                                        },
+                                        ..Default::default()
+                                    },
+                                    metadata! {
+                                        init {
+                                            "name" => "Example Collectibles", locked;
+                                            "description" => "[EXAMPLE] An NFT collection traded on the example marketplace", updatable;
+                                            "tags" => ["nft", "collectible", "marketplace"], updatable;
+                                            "icon_url" => Url::of("https://www.example.com/"), updatable;
+                                            "info_url" => Url::of("https://www.example.com/"), updatable;
+                                        }
+                                    },
+                                    None::<BTreeMap<NonFungibleLocalId, Collectible>>,
+                                )
+                                .done()
+                        },
+                        vec![],
+                    )
+                },
+                |core, config, state, result| {
+                    state.collection.set(result.new_resource_addresses()[0]);
+                    Ok(())
+                },
+            )
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    let code = include_bytes!("../../../assets/nft_marketplace.wasm");
+                    let schema = manifest_decode::<PackageDefinition>(include_bytes!(
+                        "../../../assets/nft_marketplace.rpd"
+                    ))
+                    .unwrap();
+                    let creator_rule = rule!(require(state.creator_badge.get()?));
+                    let owner_role = radix_engine::types::OwnerRole::Fixed(creator_rule.clone());
+                    core.next_transaction_with_faucet_lock_fee_fallible(
+                        "nft-marketplace-publish-and-instantiate",
+                        |builder| {
+                            let lookup = builder.name_lookup();
+                            builder
+                                .allocate_global_address(
+                                    PACKAGE_PACKAGE,
+                                    PACKAGE_BLUEPRINT,
+                                    "marketplace_package_reservation",
+                                    "marketplace_package",
+                                )
+                                .publish_package_advanced(
+                                    Some("marketplace_package_reservation".to_string()),
+                                    code.to_vec(),
+                                    schema,
+                                    metadata_init! {
+                                        "name" => "NFT Marketplace Package", locked;
+                                        "description" => "[EXAMPLE] A package of the logic of a simple NFT marketplace.".to_owned(), locked;
+                                        "tags" => ["nft", "marketplace"], locked;
+                                    },
+                                    owner_role.clone(),
+                                )
+                                .call_function(
+                                    lookup.named_address("marketplace_package"),
+                                    "NftMarketplace",
+                                    "instantiate",
+                                    manifest_args!(
+                                        owner_role.clone(),
+                                        state.collection.get()?,
+                                        creator_rule.clone(),
+                                        dec!(2),
+                                    ),
+                                )
+                                .done()
+                        },
+                        vec![],
+                    )
+                },
+                |core, config, state, result| {
+                    state.marketplace_package.set(result.new_package_addresses()[0]);
+                    state.marketplace.set(result.new_component_addresses()[0]);
+                    Ok(())
+                },
+            )
+            .successful_transaction(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee_fallible(
+                        "nft-marketplace-update-creator-royalty",
+                        |builder| {
+                            builder
+                                .create_proof_from_account_of_non_fungibles(
+                                    config.creator_account.address,
+                                    state.creator_badge.get()?.resource_address(),
+                                    &btreeset!(state.creator_badge.get()?.local_id().clone()),
+                                )
+                                .set_component_royalty(
+                                    state.marketplace.get()?,
+                                    "buy",
+                                    RoyaltyAmount::Xrd(dec!(5)),
+                                )
+                                .done()
+                        },
+                        vec![&config.creator_account.key],
+                    )
+                }
+            )
+            .successful_transaction(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee_fallible(
+                        "nft-marketplace-mint-batch",
+                        |builder| {
+                            builder
+                                .create_proof_from_account_of_non_fungibles(
+                                    config.creator_account.address,
+                                    state.creator_badge.get()?.resource_address(),
+                                    &btreeset!(state.creator_badge.get()?.local_id().clone()),
+                                )
+                                .mint_non_fungible(
+                                    state.collection.get()?,
+                                    (1..=5u64).map(|edition| {
+                                        (
+                                            NonFungibleLocalId::integer(edition),
+                                            Collectible {
+                                                name: format!("Collectible #{}", edition),
+                                                edition,
+                                            },
+                                        )
+                                    }),
+                                )
+                                .try_deposit_batch_or_abort(config.seller_account.address)
+                                .done()
+                        },
+                        vec![&config.creator_account.key],
+                    )
+                }
+            )
+            .successful_transaction(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee_fallible(
+                        "nft-marketplace-list",
+                        |builder| {
+                            builder
+                                .withdraw_non_fungibles_from_account(
+                                    config.seller_account.address,
+                                    state.collection.get()?,
+                                    &btreeset!(
+                                        NonFungibleLocalId::integer(1),
+                                        NonFungibleLocalId::integer(2),
+                                        NonFungibleLocalId::integer(3)
+                                    ),
+                                )
+                                .take_all_from_worktop(state.collection.get()?, "listed")
+                                .call_method_with_name_lookup(
+                                    state.marketplace.get()?,
+                                    "list",
+                                    |lookup| (
+                                        lookup.bucket("listed"),
+                                        dec!(100),
+                                        config.seller_account.address,
+                                    ),
+                                )
+                                .done()
+                        },
+                        vec![&config.seller_account.key],
+                    )
+                }
+            )
+            .successful_transaction(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee_fallible(
+                        "nft-marketplace-buy",
+                        |builder| {
+                            builder
+                                .get_free_xrd_from_faucet()
+                                .take_from_worktop(XRD, 150, "payment_1")
+                                .take_from_worktop(XRD, 100, "payment_2")
+                                .call_method_with_name_lookup(
+                                    state.marketplace.get()?,
+                                    "buy",
+                                    |lookup| (
+                                        NonFungibleLocalId::integer(1),
+                                        lookup.bucket("payment_1"),
+                                    ),
+                                )
+                                .call_method_with_name_lookup(
+                                    state.marketplace.get()?,
+                                    "buy",
+                                    |lookup| (
+                                        NonFungibleLocalId::integer(2),
+                                        lookup.bucket("payment_2"),
+                                    ),
+                                )
+                                .take_non_fungibles_from_worktop(
+                                    state.collection.get()?,
+                                    &btreeset!(NonFungibleLocalId::integer(2)),
+                                    "bought_2",
+                                )
+                                .try_deposit_or_abort(config.buyer_account_2.address, "bought_2")
+                                .try_deposit_batch_or_abort(config.buyer_account_1.address)
+                                .done()
+                        },
+                        vec![],
+                    )
+                }
+            )
+            .successful_transaction(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee_fallible(
+                        "nft-marketplace-claim-royalties",
+                        |builder| {
+                            builder
+                                .create_proof_from_account_of_non_fungibles(
+                                    config.creator_account.address,
+                                    state.creator_badge.get()?.resource_address(),
+                                    &btreeset!(state.creator_badge.get()?.local_id().clone()),
+                                )
+                                .claim_component_royalties(state.marketplace.get()?)
+                                .claim_package_royalties(state.marketplace_package.get()?)
+                                .try_deposit_batch_or_abort(config.creator_account.address)
+                                .done()
+                        },
+                        vec![&config.creator_account.key],
+                    )
+                }
+            )
+            .finalize(|core, config, state| {
+                Ok(ScenarioOutput {
+                    interesting_addresses: DescribedAddresses::new()
+                        .add("creator_account", &config.creator_account)
+                        .add("seller_account", &config.seller_account)
+                        .add("buyer_account_1", &config.buyer_account_1)
+                        .add("buyer_account_2", &config.buyer_account_2)
+                        .add("creator_badge", state.creator_badge.get()?)
+                        .add("collection", state.collection.get()?)
+                        .add("marketplace_package", state.marketplace_package.get()?)
+                        .add("marketplace", state.marketplace.get()?),
+                })
+            })
+    }
+}
+
+#[derive(ScryptoSbor, ManifestSbor)]
+struct Collectible {
+    name: String,
+    edition: u64,
+}
+
+impl NonFungibleData for Collectible {
+    const MUTABLE_FIELDS: &'static [&'static str] = &[];
+}
@@ -0,0 +1,385 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::account::*;
+use sbor::Encoder;
+
+/// A variant of the [`ManifestBuilder`] which checks the use of buckets and proofs at compile
+/// time.
+///
+/// Instead of being referred to by name, the buckets and proofs created by the builder are
+/// returned as [`CheckedBucket`]s and [`CheckedProof`]s, which are consumed by the instructions
+/// using them up. As such:
+/// * A bucket or proof can't be used before it has been created, as there is no way to refer to
+///   it by name.
+/// * A bucket or proof can't be consumed twice, as it is moved into the consuming instruction.
+/// * A bucket or proof can't be used with another builder, as it is branded with the lifetime of
+///   the builder which created it, and can't escape the closure passed to
+///   [`ManifestBuilder::checked`].
+///
+/// Instructions which don't involve buckets or proofs can be added with
+/// [`CheckedManifestBuilder::unchecked`].
+///
+/// ```
+/// # use transaction::prelude::*;
+/// # let from_account_address = ComponentAddress::virtual_account_from_public_key(
+/// #   &Ed25519PublicKey([0; Ed25519PublicKey::LENGTH])
+/// # );
+/// # let to_account_address = ComponentAddress::virtual_account_from_public_key(
+/// #   &Ed25519PublicKey([1; Ed25519PublicKey::LENGTH])
+/// # );
+/// let manifest = ManifestBuilder::new()
+///     .lock_fee_from_faucet()
+///     .withdraw_from_account(from_account_address, XRD, dec!(1))
+///     .checked(|builder| {
+///         let (builder, bucket) = builder.take_from_worktop(XRD, dec!(1));
+///         builder.try_deposit_or_abort(to_account_address, bucket)
+///     })
+///     .build();
+/// ```
+///
+/// Consuming a bucket twice fails to compile:
+/// ```compile_fail
+/// # use transaction::prelude::*;
+/// # let to_account_address = ComponentAddress::virtual_account_from_public_key(
+/// #   &Ed25519PublicKey([1; Ed25519PublicKey::LENGTH])
+/// # );
+/// let manifest = ManifestBuilder::new()
+///     .checked(|builder| {
+///         let (builder, bucket) = builder.take_all_from_worktop(XRD);
+///         builder
+///             .return_to_worktop(bucket)
+///             .try_deposit_or_abort(to_account_address, bucket)
+///     })
+///     .build();
+/// ```
+///
+/// So does using a bucket outside of the builder which created it:
+/// ```compile_fail
+/// # use transaction::prelude::*;
+/// let mut escaped = None;
+/// let manifest = ManifestBuilder::new()
+///     .checked(|builder| {
+///         let (builder, bucket) = builder.take_all_from_worktop(XRD);
+///         escaped = Some(bucket);
+///         builder
+///     })
+///     .checked(|builder| builder.return_to_worktop(escaped.unwrap()))
+///     .build();
+/// ```
+pub struct CheckedManifestBuilder<'b> {
+    inner: ManifestBuilder,
+    brand: PhantomData<fn(&'b ()) -> &'b ()>,
+}
+
+/// A bucket created by a [`CheckedManifestBuilder`], which must be consumed by value.
+///
+/// It can be passed in the arguments of a call, which consumes it.
+#[must_use]
+pub struct CheckedBucket<'b> {
+    bucket: ManifestBucket,
+    brand: PhantomData<fn(&'b ()) -> &'b ()>,
+}
+
+/// A proof created by a [`CheckedManifestBuilder`], which must be consumed by value.
+///
+/// It can be passed in the arguments of a call, which consumes it.
+#[must_use]
+pub struct CheckedProof<'b> {
+    proof: ManifestProof,
+    brand: PhantomData<fn(&'b ()) -> &'b ()>,
+}
+
+impl ManifestBuilder {
+    /// Continues building the manifest with a [`CheckedManifestBuilder`].
+    ///
+    /// The buckets and proofs created by the checked builder can't escape the given closure.
+    pub fn checked(
+        self,
+        next: impl for<'b> FnOnce(CheckedManifestBuilder<'b>) -> CheckedManifestBuilder<'b>,
+    ) -> Self {
+        next(CheckedManifestBuilder {
+            inner: self,
+            brand: PhantomData,
+        })
+        .inner
+    }
+}
+
+impl<'b> CheckedManifestBuilder<'b> {
+    /// Adds instructions with the underlying [`ManifestBuilder`].
+    ///
+    /// The given closure shouldn't refer to the buckets and proofs created by the checked builder,
+    /// nor consume them all, eg with `deposit_batch` or `drop_all_proofs`.
+    pub fn unchecked(self, next: impl FnOnce(ManifestBuilder) -> ManifestBuilder) -> Self {
+        Self {
+            inner: next(self.inner),
+            brand: PhantomData,
+        }
+    }
+
+    pub fn then(self, next: impl FnOnce(Self) -> Self) -> Self {
+        next(self)
+    }
+
+    fn new_bucket(
+        self,
+        add_instruction: impl FnOnce(ManifestBuilder, &str) -> ManifestBuilder,
+    ) -> (Self, CheckedBucket<'b>) {
+        let name = self.inner.generate_bucket_name("bucket");
+        let inner = add_instruction(self.inner, &name);
+        let bucket = CheckedBucket {
+            bucket: inner.bucket(&name),
+            brand: PhantomData,
+        };
+        (
+            Self {
+                inner,
+                brand: PhantomData,
+            },
+            bucket,
+        )
+    }
+
+    fn new_proof(
+        self,
+        add_instruction: impl FnOnce(ManifestBuilder, &str) -> ManifestBuilder,
+    ) -> (Self, CheckedProof<'b>) {
+        let name = self.inner.generate_proof_name("proof");
+        let inner = add_instruction(self.inner, &name);
+        let proof = CheckedProof {
+            proof: inner.proof(&name),
+            brand: PhantomData,
+        };
+        (
+            Self {
+                inner,
+                brand: PhantomData,
+            },
+            proof,
+        )
+    }
+
+    /// Takes resource from worktop.
+    pub fn take_all_from_worktop(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> (Self, CheckedBucket<'b>) {
+        self.new_bucket(|builder, name| builder.take_all_from_worktop(resource_address, name))
+    }
+
+    /// Takes resource from worktop, by amount.
+    pub fn take_from_worktop(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> (Self, CheckedBucket<'b>) {
+        self.new_bucket(|builder, name| builder.take_from_worktop(resource_address, amount, name))
+    }
+
+    /// Takes resource from worktop, by non-fungible ids.
+    pub fn take_non_fungibles_from_worktop(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        ids: &BTreeSet<NonFungibleLocalId>,
+    ) -> (Self, CheckedBucket<'b>) {
+        self.new_bucket(|builder, name| {
+            builder.take_non_fungibles_from_worktop(resource_address, ids, name)
+        })
+    }
+
+    /// Adds a bucket of resource to worktop.
+    pub fn return_to_worktop(self, bucket: CheckedBucket<'b>) -> Self {
+        self.unchecked(|builder| builder.return_to_worktop(bucket.bucket))
+    }
+
+    pub fn deposit(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        bucket: CheckedBucket<'b>,
+    ) -> Self {
+        self.unchecked(|builder| builder.deposit(account_address, bucket.bucket))
+    }
+
+    pub fn try_deposit_or_abort(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        bucket: CheckedBucket<'b>,
+    ) -> Self {
+        self.unchecked(|builder| builder.try_deposit_or_abort(account_address, bucket.bucket))
+    }
+
+    /// Pops the most recent proof from auth zone.
+    pub fn pop_from_auth_zone(self) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| builder.pop_from_auth_zone(name))
+    }
+
+    /// Pushes a proof onto the auth zone
+    pub fn push_to_auth_zone(self, proof: CheckedProof<'b>) -> Self {
+        self.unchecked(|builder| builder.push_to_auth_zone(proof.proof))
+    }
+
+    /// Creates proof from the auth zone by amount.
+    pub fn create_proof_from_auth_zone_of_amount(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| {
+            builder.create_proof_from_auth_zone_of_amount(resource_address, amount, name)
+        })
+    }
+
+    /// Creates proof from the auth zone by non-fungible ids.
+    pub fn create_proof_from_auth_zone_of_non_fungibles(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        ids: &BTreeSet<NonFungibleLocalId>,
+    ) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| {
+            builder.create_proof_from_auth_zone_of_non_fungibles(resource_address, ids, name)
+        })
+    }
+
+    /// Creates proof from the auth zone
+    pub fn create_proof_from_auth_zone_of_all(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| {
+            builder.create_proof_from_auth_zone_of_all(resource_address, name)
+        })
+    }
+
+    /// Creates proof from a bucket. The bucket is not consumed by this process.
+    pub fn create_proof_from_bucket_of_amount(
+        self,
+        bucket: &CheckedBucket<'b>,
+        amount: impl ResolvableDecimal,
+    ) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| {
+            builder.create_proof_from_bucket_of_amount(bucket.bucket, amount, name)
+        })
+    }
+
+    /// Creates proof from a bucket. The bucket is not consumed by this process.
+    pub fn create_proof_from_bucket_of_non_fungibles(
+        self,
+        bucket: &CheckedBucket<'b>,
+        ids: &BTreeSet<NonFungibleLocalId>,
+    ) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| {
+            builder.create_proof_from_bucket_of_non_fungibles(bucket.bucket, ids, name)
+        })
+    }
+
+    /// Creates proof from a bucket. The bucket is not consumed by this process.
+    pub fn create_proof_from_bucket_of_all(
+        self,
+        bucket: &CheckedBucket<'b>,
+    ) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| builder.create_proof_from_bucket_of_all(bucket.bucket, name))
+    }
+
+    /// Clones a proof.
+    pub fn clone_proof(self, proof: &CheckedProof<'b>) -> (Self, CheckedProof<'b>) {
+        self.new_proof(|builder, name| builder.clone_proof(proof.proof, name))
+    }
+
+    /// Drops a proof.
+    pub fn drop_proof(self, proof: CheckedProof<'b>) -> Self {
+        self.unchecked(|builder| builder.drop_proof(proof.proof))
+    }
+
+    /// Calls a function, consuming the buckets and proofs passed by value in the arguments.
+    pub fn call_function(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        blueprint_name: impl Into<String>,
+        function_name: impl Into<String>,
+        arguments: impl ResolvableArguments,
+    ) -> Self {
+        self.unchecked(|builder| {
+            builder.call_function(package_address, blueprint_name, function_name, arguments)
+        })
+    }
+
+    /// Calls a method, consuming the buckets and proofs passed by value in the arguments.
+    pub fn call_method(
+        self,
+        address: impl ResolvableGlobalAddress,
+        method_name: impl Into<String>,
+        arguments: impl ResolvableArguments,
+    ) -> Self {
+        self.unchecked(|builder| builder.call_method(address, method_name, arguments))
+    }
+}
+
+impl<'b> Categorize<ManifestCustomValueKind> for CheckedBucket<'b> {
+    fn value_kind() -> ValueKind<ManifestCustomValueKind> {
+        ManifestBucket::value_kind()
+    }
+}
+
+impl<'b, E: Encoder<ManifestCustomValueKind>> Encode<ManifestCustomValueKind, E>
+    for CheckedBucket<'b>
+{
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.bucket.encode_value_kind(encoder)
+    }
+
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.bucket.encode_body(encoder)
+    }
+}
+
+impl<'b> Categorize<ManifestCustomValueKind> for CheckedProof<'b> {
+    fn value_kind() -> ValueKind<ManifestCustomValueKind> {
+        ManifestProof::value_kind()
+    }
+}
+
+impl<'b, E: Encoder<ManifestCustomValueKind>> Encode<ManifestCustomValueKind, E>
+    for CheckedProof<'b>
+{
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.proof.encode_value_kind(encoder)
+    }
+
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.proof.encode_body(encoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_builder_matches_unchecked_builder() {
+        let account = ComponentAddress::virtual_account_from_public_key(&Ed25519PublicKey(
+            [0; Ed25519PublicKey::LENGTH],
+        ));
+
+        let checked = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .checked(|builder| {
+                let (builder, bucket) = builder.take_from_worktop(XRD, dec!(1));
+                let (builder, proof) = builder.create_proof_from_bucket_of_all(&bucket);
+                builder
+                    .drop_proof(proof)
+                    .call_method(account, ACCOUNT_DEPOSIT_IDENT, (bucket,))
+            })
+            .build();
+
+        let unchecked = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .take_from_worktop(XRD, dec!(1), "bucket")
+            .create_proof_from_bucket_of_all("bucket", "proof")
+            .drop_proof("proof")
+            .call_method_with_name_lookup(account, ACCOUNT_DEPOSIT_IDENT, |lookup| {
+                (lookup.bucket("bucket"),)
+            })
+            .build();
+
+        assert_eq!(checked.instructions, unchecked.instructions);
+    }
+}
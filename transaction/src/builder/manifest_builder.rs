@@ -11,6 +11,7 @@ use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
 use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::pool::*;
 use radix_engine_interface::blueprints::resource::*;
 
 /// A manifest builder for use in tests.
@@ -61,6 +62,28 @@ use radix_engine_interface::blueprints::resource::*;
 ///     .build();
 /// ```
 ///
+/// Alternatively, a name lookup can be created alongside the builder, so that named buckets
+/// and proofs can be referenced in arguments without a closure:
+/// ```
+/// # use transaction::prelude::*;
+/// # let package_address = RESOURCE_PACKAGE; // Just some address to get it to compile
+/// # let from_account_address = ComponentAddress::virtual_account_from_public_key(
+/// #   &Ed25519PublicKey([0; Ed25519PublicKey::LENGTH])
+/// # );
+/// let (builder, lookup) = ManifestBuilder::new_with_name_lookup();
+/// let manifest = builder
+///     .lock_fee_from_faucet()
+///     .withdraw_from_account(from_account_address, XRD, dec!(1))
+///     .take_from_worktop(XRD, dec!(1), "xrd")
+///     .call_function(
+///         package_address,
+///         "SomeBlueprint",
+///         "some_function",
+///         (lookup.bucket("xrd"),),
+///     )
+///     .build();
+/// ```
+///
 /// Advanced use case, where we need to generate a collision-free bucket name:
 /// ```
 /// # use transaction::prelude::*;
@@ -105,6 +128,17 @@ impl ManifestBuilder {
         }
     }
 
+    /// Starts a new transaction builder, along with a lookup of its named buckets, proofs,
+    /// address reservations and addresses.
+    ///
+    /// Names are resolved when the lookup is used, so the lookup can be used in the arguments
+    /// of an instruction to refer to objects named by the previous instructions of the chain.
+    pub fn new_with_name_lookup() -> (Self, ManifestNameLookup) {
+        let builder = Self::new();
+        let lookup = builder.name_lookup();
+        (builder, lookup)
+    }
+
     pub fn name_lookup(&self) -> ManifestNameLookup {
         self.registrar.name_lookup()
     }
@@ -354,6 +388,39 @@ impl ManifestBuilder {
         })
     }
 
+    /// Asserts that the buckets returned by the next call contain at least the given resources.
+    pub fn assert_next_call_returns_include(self, resources: Vec<ResourceAssertion>) -> Self {
+        self.add_instruction(InstructionV1::AssertNextCallReturnsInclude { resources })
+    }
+
+    /// Asserts that the buckets returned by the next call contain exactly the given resources.
+    pub fn assert_next_call_returns_exactly(self, resources: Vec<ResourceAssertion>) -> Self {
+        self.add_instruction(InstructionV1::AssertNextCallReturnsExactly { resources })
+    }
+
+    /// Asserts that the value returned by the next call is equal to the given value.
+    pub fn assert_next_call_returns_value<V: ManifestEncode + ?Sized>(self, value: &V) -> Self {
+        self.add_instruction(InstructionV1::AssertNextCallReturnsValue {
+            value: to_manifest_value_and_unwrap!(value),
+        })
+    }
+
+    /// Asserts that the metadata entry under the given key of a global entity is equal to the
+    /// given value, e.g. that a component's `dapp_definition` is the one shown to the user.
+    pub fn assert_metadata(
+        self,
+        address: impl ResolvableGlobalAddress,
+        key: impl Into<String>,
+        value: MetadataValue,
+    ) -> Self {
+        let address = address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::AssertMetadata {
+            address,
+            key: key.into(),
+            value,
+        })
+    }
+
     /// Pops the most recent proof from auth zone.
     pub fn pop_from_auth_zone(self, new_proof: impl NewManifestProof) -> Self {
         new_proof.register(&self.registrar);
@@ -506,6 +573,21 @@ impl ManifestBuilder {
         self.add_instruction(InstructionV1::ClearSignatureProofs)
     }
 
+    /// Starts an optional section, which must be ended by [`Self::rollback_to_savepoint`].
+    ///
+    /// If an instruction in the section fails, the changes made by the section are reverted and
+    /// the rest of the manifest carries on. The section must consume all the named buckets and
+    /// proofs it creates, and none of the ones created before it.
+    pub fn savepoint(self) -> Self {
+        self.add_instruction(InstructionV1::Savepoint)
+    }
+
+    /// Ends the section started by the matching [`Self::savepoint`], reverting its changes if one
+    /// of its instructions failed.
+    pub fn rollback_to_savepoint(self) -> Self {
+        self.add_instruction(InstructionV1::RollbackToSavepoint)
+    }
+
     /// Creates a fungible resource
     pub fn create_fungible_resource(
         self,
@@ -553,6 +635,28 @@ impl ManifestBuilder {
     }
 
     /// Creates a new non-fungible resource
+    ///
+    /// For example, a soulbound resource whose non-fungibles stay in the account they are minted
+    /// into:
+    /// ```
+    /// # use transaction::prelude::*;
+    /// # use radix_engine_interface::blueprints::resource::*;
+    /// # let owner_key = Ed25519PublicKey([0; Ed25519PublicKey::LENGTH]);
+    /// # let account = ComponentAddress::virtual_account_from_public_key(&owner_key);
+    /// let owner_badge = NonFungibleGlobalId::from_public_key(&owner_key);
+    /// let manifest = ManifestBuilder::new()
+    ///     .lock_fee_from_faucet()
+    ///     .create_non_fungible_resource(
+    ///         OwnerRole::None,
+    ///         NonFungibleIdType::Integer,
+    ///         false,
+    ///         NonFungibleResourceRoles::soulbound(rule!(require(owner_badge))),
+    ///         metadata!(),
+    ///         Some([(NonFungibleLocalId::integer(1), ())]),
+    ///     )
+    ///     .try_deposit_batch_or_abort(account)
+    ///     .build();
+    /// ```
     pub fn create_non_fungible_resource<T, V>(
         self,
         owner_role: OwnerRole,
@@ -687,6 +791,16 @@ impl ManifestBuilder {
         })
     }
 
+    /// Securifies a virtual identity, putting the identity owner badge on the worktop.
+    pub fn securify_identity(self, identity_address: impl ResolvableComponentAddress) -> Self {
+        let address = identity_address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: IDENTITY_SECURIFY_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&IdentitySecurifyToSingleBadgeInput {}),
+        })
+    }
+
     pub fn create_validator(
         self,
         key: Secp256k1PublicKey,
@@ -725,6 +839,23 @@ impl ManifestBuilder {
         )
     }
 
+    /// Requests a change of the validator fee factor which becomes effective after the given
+    /// number of epochs, or after the minimum delay for the change if that is longer.
+    pub fn schedule_validator_fee_change(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+        new_fee_factor: impl ResolvableDecimal,
+        delay_epochs: u64,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        let new_fee_factor = new_fee_factor.resolve();
+        self.call_method(
+            address,
+            VALIDATOR_SCHEDULE_FEE_CHANGE_IDENT,
+            (new_fee_factor, delay_epochs),
+        )
+    }
+
     pub fn stake_validator_as_owner(
         self,
         validator_address: impl ResolvableComponentAddress,
@@ -1390,6 +1521,20 @@ impl ManifestBuilder {
         })
     }
 
+    /// Burns an amount of a recallable resource directly from a vault, with the burner role.
+    pub fn burn_from_vault(
+        self,
+        vault_address: InternalAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let amount = amount.resolve();
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_address,
+            method_name: VAULT_BURN_FROM_VAULT_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&VaultBurnFromVaultInput { amount }),
+        })
+    }
+
     pub fn freeze_withdraw(self, vault_id: InternalAddress) -> Self {
         self.add_instruction(InstructionV1::CallDirectVaultMethod {
             address: vault_id,
@@ -1611,6 +1756,25 @@ impl ManifestBuilder {
         })
     }
 
+    /// Withdraws multiple resources from an account in a single call, putting one bucket per
+    /// resource specifier on the worktop.
+    pub fn withdraw_batch_from_account(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resources: impl IntoIterator<Item = ResourceSpecifier>,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let args = to_manifest_value_and_unwrap!(&AccountWithdrawBatchInput {
+            resources: resources.into_iter().collect(),
+        });
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: ACCOUNT_WITHDRAW_BATCH_IDENT.to_string(),
+            args,
+        })
+    }
+
     /// Withdraws resource from an account.
     pub fn burn_in_account(
         self,
@@ -1763,6 +1927,100 @@ impl ManifestBuilder {
         )
     }
 
+    pub fn create_account_locker(
+        self,
+        owner_role: OwnerRole,
+        storer_rule: AccessRule,
+        recoverer_rule: AccessRule,
+    ) -> Self {
+        self.add_instruction(InstructionV1::CallFunction {
+            package_address: ACCOUNT_PACKAGE.into(),
+            blueprint_name: ACCOUNT_LOCKER_BLUEPRINT.to_string(),
+            function_name: ACCOUNT_LOCKER_INSTANTIATE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&AccountLockerInstantiateManifestInput {
+                owner_role,
+                storer_rule,
+                recoverer_rule,
+            }),
+        })
+    }
+
+    /// Deposits the bucket into the claimant account if the account accepts it, otherwise
+    /// stores it in the account locker for the claimant to claim later.
+    pub fn send_or_store(
+        self,
+        locker_address: impl ResolvableComponentAddress,
+        claimant: ComponentAddress,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        self.store_in_account_locker(locker_address, claimant, bucket, true)
+    }
+
+    pub fn store_in_account_locker(
+        self,
+        locker_address: impl ResolvableComponentAddress,
+        claimant: ComponentAddress,
+        bucket: impl ExistingManifestBucket,
+        try_direct_send: bool,
+    ) -> Self {
+        let address = locker_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+
+        self.call_method(
+            address,
+            ACCOUNT_LOCKER_STORE_IDENT,
+            AccountLockerStoreManifestInput {
+                claimant,
+                bucket,
+                try_direct_send,
+            },
+        )
+    }
+
+    pub fn claim_from_account_locker(
+        self,
+        locker_address: impl ResolvableComponentAddress,
+        claimant: ComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let address = locker_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        let amount = amount.resolve();
+
+        self.call_method(
+            address,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            AccountLockerClaimManifestInput {
+                claimant,
+                resource_address,
+                amount,
+            },
+        )
+    }
+
+    pub fn recover_from_account_locker(
+        self,
+        locker_address: impl ResolvableComponentAddress,
+        claimant: ComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let address = locker_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        let amount = amount.resolve();
+
+        self.call_method(
+            address,
+            ACCOUNT_LOCKER_RECOVER_IDENT,
+            AccountLockerRecoverManifestInput {
+                claimant,
+                resource_address,
+                amount,
+            },
+        )
+    }
+
     pub fn create_access_controller(
         self,
         controlled_asset: impl ExistingManifestBucket,
@@ -1788,6 +2046,178 @@ impl ManifestBuilder {
         )
     }
 
+    /// Proposes a recovery of the access controller to the given rule set, as the primary role.
+    pub fn initiate_recovery_as_primary(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
+            AccessControllerInitiateRecoveryAsPrimaryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    /// Proposes a recovery of the access controller to the given rule set, as the recovery role.
+    pub fn initiate_recovery_as_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            AccessControllerInitiateRecoveryAsRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    /// Confirms the recovery proposed by the given proposer, which must match the given rule set
+    /// and delay. This must be authorized by one of the roles other than the proposer.
+    pub fn quick_confirm_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        proposer: Proposer,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        match proposer {
+            Proposer::Primary => self.call_method(
+                address,
+                ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInput {
+                    rule_set,
+                    timed_recovery_delay_in_minutes,
+                },
+            ),
+            Proposer::Recovery => self.call_method(
+                address,
+                ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInput {
+                    rule_set,
+                    timed_recovery_delay_in_minutes,
+                },
+            ),
+        }
+    }
+
+    /// Confirms the timed recovery proposed by the recovery role, once its delay has elapsed.
+    pub fn timed_confirm_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+            AccessControllerTimedConfirmRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    /// Cancels the recovery proposed by the given proposer, which must authorize the call.
+    pub fn cancel_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        proposer: Proposer,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        match proposer {
+            Proposer::Primary => self.call_method(
+                address,
+                ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                AccessControllerCancelPrimaryRoleRecoveryProposalInput,
+            ),
+            Proposer::Recovery => self.call_method(
+                address,
+                ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+                AccessControllerCancelRecoveryRoleRecoveryProposalInput,
+            ),
+        }
+    }
+
+    /// Creates a pool of a single resource, protected against the inflation of the value of its
+    /// pool units as configured.
+    pub fn create_one_resource_pool(
+        self,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_address: impl ResolvableResourceAddress,
+        contribution_protection: PoolContributionProtection,
+    ) -> Self {
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.call_function(
+            POOL_PACKAGE,
+            ONE_RESOURCE_POOL_BLUEPRINT,
+            ONE_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_IDENT,
+            OneResourcePoolInstantiateWithContributionProtectionManifestInput {
+                owner_role,
+                pool_manager_rule,
+                resource_address,
+                contribution_protection,
+            },
+        )
+    }
+
+    /// Creates a pool of two resources, protected against the inflation of the value of its pool
+    /// units as configured.
+    pub fn create_two_resource_pool(
+        self,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: (ResourceAddress, ResourceAddress),
+        contribution_protection: PoolContributionProtection,
+    ) -> Self {
+        self.call_function(
+            POOL_PACKAGE,
+            TWO_RESOURCE_POOL_BLUEPRINT,
+            TWO_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_IDENT,
+            TwoResourcePoolInstantiateWithContributionProtectionManifestInput {
+                owner_role,
+                pool_manager_rule,
+                resource_addresses,
+                contribution_protection,
+            },
+        )
+    }
+
+    /// Creates a pool of any number of resources, protected against the inflation of the value of
+    /// its pool units as configured.
+    pub fn create_multi_resource_pool(
+        self,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: BTreeSet<ResourceAddress>,
+        contribution_protection: PoolContributionProtection,
+    ) -> Self {
+        self.call_function(
+            POOL_PACKAGE,
+            MULTI_RESOURCE_POOL_BLUEPRINT,
+            MULTI_RESOURCE_POOL_INSTANTIATE_WITH_CONTRIBUTION_PROTECTION_IDENT,
+            MultiResourcePoolInstantiateWithContributionProtectionManifestInput {
+                owner_role,
+                pool_manager_rule,
+                resource_addresses,
+                contribution_protection,
+            },
+        )
+    }
+
     /// Builds a transaction manifest.
     pub fn build(self) -> TransactionManifestV1 {
         let manifest = TransactionManifestV1 {
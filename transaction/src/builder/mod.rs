@@ -1,7 +1,15 @@
+mod checked_manifest_builder;
 mod manifest_builder;
 mod manifest_namer;
+mod signature_collector;
+mod system_transaction_builder;
 mod transaction_builder;
+mod transaction_presets;
 
+pub use checked_manifest_builder::*;
 pub use manifest_builder::*;
 pub use manifest_namer::*;
+pub use signature_collector::*;
+pub use system_transaction_builder::*;
 pub use transaction_builder::*;
+pub use transaction_presets::*;
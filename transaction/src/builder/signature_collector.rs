@@ -0,0 +1,263 @@
+use crate::errors::*;
+use crate::model::*;
+use crate::signing::Signer;
+use crate::validation::*;
+use radix_engine_common::prelude::IsHash;
+use radix_engine_interface::prelude::*;
+
+/// An unsigned or partially signed transaction intent.
+///
+/// This is the artifact which is passed between the parties of a multi-party signing
+/// workflow. It encodes as a `SignedIntent` payload, so it can be stored or transferred
+/// as raw bytes, and each party can add their signature out-of-band before it's handed
+/// to the notary.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartialTransaction {
+    pub intent: IntentV1,
+    pub intent_signatures: Vec<SignatureWithPublicKeyV1>,
+}
+
+impl PartialTransaction {
+    pub fn new(intent: IntentV1) -> Self {
+        Self {
+            intent,
+            intent_signatures: vec![],
+        }
+    }
+
+    pub fn to_raw(&self) -> Result<RawSignedIntent, EncodeError> {
+        self.to_signed_intent().to_raw()
+    }
+
+    pub fn from_raw(raw: &RawSignedIntent) -> Result<Self, DecodeError> {
+        let signed_intent = SignedIntentV1::from_raw(raw)?;
+        Ok(Self {
+            intent: signed_intent.intent,
+            intent_signatures: signed_intent
+                .intent_signatures
+                .signatures
+                .into_iter()
+                .map(|sig| sig.0)
+                .collect(),
+        })
+    }
+
+    pub fn to_signed_intent(&self) -> SignedIntentV1 {
+        SignedIntentV1 {
+            intent: self.intent.clone(),
+            intent_signatures: IntentSignaturesV1 {
+                signatures: self
+                    .intent_signatures
+                    .iter()
+                    .cloned()
+                    .map(|sig| IntentSignatureV1(sig))
+                    .collect(),
+            },
+        }
+    }
+}
+
+/// Collects intent signatures for a [`PartialTransaction`], validating each signature as
+/// it's added, and assembles the notarized transaction once the notary has signed.
+pub struct SignatureCollector {
+    intent: IntentV1,
+    intent_hash: IntentHash,
+    intent_signatures: Vec<SignatureWithPublicKeyV1>,
+    signers: IndexSet<PublicKey>,
+}
+
+impl SignatureCollector {
+    pub fn new(intent: IntentV1) -> Result<Self, SignatureCollectionError> {
+        Self::from_partial(PartialTransaction::new(intent))
+    }
+
+    /// Resumes collecting signatures, re-validating any signatures already present.
+    pub fn from_partial(partial: PartialTransaction) -> Result<Self, SignatureCollectionError> {
+        let intent_hash = partial
+            .intent
+            .prepare()
+            .map_err(SignatureCollectionError::PrepareError)?
+            .intent_hash();
+        let mut collector = Self {
+            intent: partial.intent,
+            intent_hash,
+            intent_signatures: vec![],
+            signers: index_set_new(),
+        };
+        for signature in partial.intent_signatures {
+            collector.add_signature(signature)?;
+        }
+        Ok(collector)
+    }
+
+    pub fn from_raw(raw: &RawSignedIntent) -> Result<Self, SignatureCollectionError> {
+        let partial =
+            PartialTransaction::from_raw(raw).map_err(SignatureCollectionError::DecodeError)?;
+        Self::from_partial(partial)
+    }
+
+    pub fn intent(&self) -> &IntentV1 {
+        &self.intent
+    }
+
+    /// The hash which each signer needs to sign.
+    pub fn intent_hash(&self) -> IntentHash {
+        self.intent_hash
+    }
+
+    pub fn signers(&self) -> Vec<PublicKey> {
+        self.signers.iter().cloned().collect()
+    }
+
+    pub fn sign<S: Signer>(&mut self, signer: &S) -> Result<(), SignatureCollectionError> {
+        self.add_signature(signer.sign_with_public_key(&self.intent_hash))
+    }
+
+    /// Adds a signature which was produced out-of-band over [`Self::intent_hash`].
+    pub fn add_signature(
+        &mut self,
+        signature: SignatureWithPublicKeyV1,
+    ) -> Result<(), SignatureCollectionError> {
+        if self.intent_signatures.len() >= MAX_NUMBER_OF_INTENT_SIGNATURES {
+            return Err(SignatureValidationError::TooManySignatures.into());
+        }
+
+        let intent_hash = self.intent_hash.into_hash();
+        let public_key = recover(&intent_hash, &signature)
+            .ok_or(SignatureValidationError::InvalidIntentSignature)?;
+        if !verify(&intent_hash, &public_key, &signature.signature()) {
+            return Err(SignatureValidationError::InvalidIntentSignature.into());
+        }
+        if !self.signers.insert(public_key) {
+            return Err(SignatureValidationError::DuplicateSigner.into());
+        }
+
+        self.intent_signatures.push(signature);
+        Ok(())
+    }
+
+    pub fn to_partial(&self) -> PartialTransaction {
+        PartialTransaction {
+            intent: self.intent.clone(),
+            intent_signatures: self.intent_signatures.clone(),
+        }
+    }
+
+    /// The hash which the notary needs to sign, given the signatures collected so far.
+    pub fn signed_intent_hash(&self) -> Result<SignedIntentHash, SignatureCollectionError> {
+        Ok(self
+            .to_partial()
+            .to_signed_intent()
+            .prepare()
+            .map_err(SignatureCollectionError::PrepareError)?
+            .signed_intent_hash())
+    }
+
+    pub fn notarize<S: Signer>(
+        self,
+        notary: &S,
+    ) -> Result<NotarizedTransactionV1, SignatureCollectionError> {
+        let signature = notary.sign_without_public_key(&self.signed_intent_hash()?);
+        self.complete(signature)
+    }
+
+    /// Assembles the notarized transaction from a notary signature which was produced
+    /// out-of-band over [`Self::signed_intent_hash`].
+    pub fn complete(
+        self,
+        notary_signature: SignatureV1,
+    ) -> Result<NotarizedTransactionV1, SignatureCollectionError> {
+        let signed_intent_hash = self.signed_intent_hash()?;
+        if !verify(
+            &signed_intent_hash.into_hash(),
+            &self.intent.header.notary_public_key,
+            &notary_signature,
+        ) {
+            return Err(SignatureValidationError::InvalidNotarySignature.into());
+        }
+
+        Ok(NotarizedTransactionV1 {
+            signed_intent: self.to_partial().to_signed_intent(),
+            notary_signature: NotarySignatureV1(notary_signature),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::*;
+    use crate::signing::ed25519::Ed25519PrivateKey;
+    use crate::signing::secp256k1::Secp256k1PrivateKey;
+
+    fn intent(notary: &Secp256k1PrivateKey) -> IntentV1 {
+        let (instructions, blobs) = ManifestBuilder::new()
+            .clear_auth_zone()
+            .build()
+            .for_intent();
+        IntentV1 {
+            header: TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                nonce: 5,
+                notary_public_key: notary.public_key().into(),
+                notary_is_signatory: false,
+                tip_percentage: 5,
+            },
+            instructions,
+            blobs,
+            message: MessageV1::None,
+        }
+    }
+
+    #[test]
+    fn signatures_can_be_collected_out_of_band() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer1 = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let signer2 = Ed25519PrivateKey::from_u64(3).unwrap();
+
+        let mut collector = SignatureCollector::new(intent(&notary)).unwrap();
+        collector.sign(&signer1).unwrap();
+        let raw = collector.to_partial().to_raw().unwrap();
+
+        let mut collector = SignatureCollector::from_raw(&raw).unwrap();
+        collector.sign(&signer2).unwrap();
+        let transaction = collector.notarize(&notary).unwrap();
+
+        let validated = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+            .validate(transaction.prepare().unwrap())
+            .unwrap();
+        assert_eq!(
+            validated.signer_keys,
+            vec![signer1.public_key().into(), signer2.public_key().into()]
+        );
+    }
+
+    #[test]
+    fn invalid_signatures_are_rejected() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = Secp256k1PrivateKey::from_u64(2).unwrap();
+
+        let mut collector = SignatureCollector::new(intent(&notary)).unwrap();
+        collector.sign(&signer).unwrap();
+        assert_eq!(
+            collector.sign(&signer),
+            Err(SignatureCollectionError::SignatureValidationError(
+                SignatureValidationError::DuplicateSigner
+            ))
+        );
+        assert_eq!(
+            collector.add_signature(signer.sign_with_public_key(&Hash([0u8; 32]))),
+            Err(SignatureCollectionError::SignatureValidationError(
+                SignatureValidationError::InvalidIntentSignature
+            ))
+        );
+        assert!(matches!(
+            collector.notarize(&signer),
+            Err(SignatureCollectionError::SignatureValidationError(
+                SignatureValidationError::InvalidNotarySignature
+            ))
+        ));
+    }
+}
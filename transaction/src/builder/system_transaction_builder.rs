@@ -0,0 +1,115 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::consensus_manager::*;
+
+/// A builder of system transactions, which are not signed nor notarized but executed with the
+/// authority of the system or validator role, e.g. as part of consensus.
+pub struct SystemTransactionBuilder {
+    instructions: Vec<InstructionV1>,
+    blobs: Vec<BlobV1>,
+    pre_allocated_addresses: Vec<PreAllocatedAddress>,
+}
+
+impl SystemTransactionBuilder {
+    pub fn new() -> Self {
+        Self {
+            instructions: vec![],
+            blobs: vec![],
+            pre_allocated_addresses: vec![],
+        }
+    }
+
+    pub fn add_instruction(mut self, instruction: InstructionV1) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    pub fn add_blob(mut self, blob: Vec<u8>) -> Self {
+        self.blobs.push(BlobV1(blob));
+        self
+    }
+
+    pub fn pre_allocate_address(
+        mut self,
+        blueprint_id: BlueprintId,
+        address: GlobalAddress,
+    ) -> Self {
+        self.pre_allocated_addresses.push(PreAllocatedAddress {
+            blueprint_id,
+            address,
+        });
+        self
+    }
+
+    /// Updates the epoch change condition and the maximum number of active validators of the
+    /// consensus manager. The transaction must be executed with the validator role.
+    pub fn update_consensus_config(
+        self,
+        epoch_change_condition: EpochChangeCondition,
+        max_validators: u32,
+    ) -> Self {
+        self.add_instruction(InstructionV1::CallMethod {
+            address: CONSENSUS_MANAGER.into(),
+            method_name: CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&ConsensusManagerUpdateConfigInput {
+                epoch_change_condition,
+                max_validators,
+            }),
+        })
+    }
+
+    /// Updates the emission parameters of the consensus manager, applied from the current
+    /// epoch's emissions. The transaction must be executed with the validator role.
+    pub fn update_emission_config(
+        self,
+        total_emission_xrd_per_epoch: Decimal,
+        min_validator_reliability: Decimal,
+    ) -> Self {
+        self.add_instruction(InstructionV1::CallMethod {
+            address: CONSENSUS_MANAGER.into(),
+            method_name: CONSENSUS_MANAGER_UPDATE_EMISSION_CONFIG_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&ConsensusManagerUpdateEmissionConfigInput {
+                total_emission_xrd_per_epoch,
+                min_validator_reliability,
+            }),
+        })
+    }
+
+    pub fn build(self, hash_for_execution: Hash) -> SystemTransactionV1 {
+        SystemTransactionV1 {
+            instructions: InstructionsV1(self.instructions),
+            blobs: BlobsV1 { blobs: self.blobs },
+            pre_allocated_addresses: self.pre_allocated_addresses,
+            hash_for_execution,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_consensus_config_calls_consensus_manager() {
+        let epoch_change_condition = EpochChangeCondition {
+            min_round_count: 1,
+            max_round_count: 10,
+            target_duration_millis: 1000,
+        };
+        let transaction = SystemTransactionBuilder::new()
+            .update_consensus_config(epoch_change_condition.clone(), 50)
+            .build(hash("update consensus config"));
+
+        assert_eq!(
+            transaction.instructions.0,
+            vec![InstructionV1::CallMethod {
+                address: CONSENSUS_MANAGER.into(),
+                method_name: CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+                args: to_manifest_value_and_unwrap!(&ConsensusManagerUpdateConfigInput {
+                    epoch_change_condition,
+                    max_validators: 50,
+                }),
+            }]
+        );
+        assert!(transaction.prepare().is_ok());
+    }
+}
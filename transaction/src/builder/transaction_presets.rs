@@ -0,0 +1,196 @@
+use crate::internal_prelude::*;
+
+/// The header parameters of the transactions built by the [`TransactionBuilder`] presets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionPresetConfig {
+    pub network_id: u8,
+    pub current_epoch: Epoch,
+    /// The number of epochs, from the current one, in which the transaction can be committed.
+    pub epoch_window: u64,
+    pub nonce: u32,
+    pub tip_percentage: u16,
+    /// The fee locked from the fee payer's account.
+    pub fee: Decimal,
+}
+
+impl TransactionPresetConfig {
+    /// Creates a config with no tip, a fee of 10 XRD and a window of 10 epochs.
+    ///
+    /// The nonce must be unique among the transactions with the same intent, as it's what keeps
+    /// them from being rejected as duplicates.
+    pub fn new(network: &NetworkDefinition, current_epoch: Epoch, nonce: u32) -> Self {
+        Self {
+            network_id: network.id,
+            current_epoch,
+            epoch_window: 10,
+            nonce,
+            tip_percentage: 0,
+            fee: dec!("10"),
+        }
+    }
+
+    pub fn epoch_window(mut self, epoch_window: u64) -> Self {
+        self.epoch_window = epoch_window;
+        self
+    }
+
+    pub fn tip_percentage(mut self, tip_percentage: u16) -> Self {
+        self.tip_percentage = tip_percentage;
+        self
+    }
+
+    pub fn fee(mut self, fee: Decimal) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    fn header(&self, notary_public_key: PublicKey) -> TransactionHeaderV1 {
+        TransactionHeaderV1 {
+            network_id: self.network_id,
+            start_epoch_inclusive: self.current_epoch,
+            end_epoch_exclusive: self.current_epoch.after(self.epoch_window),
+            nonce: self.nonce,
+            notary_public_key,
+            notary_is_signatory: true,
+            tip_percentage: self.tip_percentage,
+        }
+    }
+}
+
+/// High-level presets producing complete, notarized transactions.
+///
+/// The accounts involved are the virtual accounts of the given keys. The fee payer notarizes the
+/// transaction as a signatory, and every other account owner signs the intent.
+impl TransactionBuilder {
+    /// Transfers an amount of a resource from one account to another.
+    pub fn simple_transfer(
+        from: &PrivateKey,
+        to: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        fee_payer: &PrivateKey,
+        config: &TransactionPresetConfig,
+    ) -> NotarizedTransactionV1 {
+        Self::multi_transfer(from, &[(to, resource_address, amount)], fee_payer, config)
+    }
+
+    /// Transfers resources from one account to several accounts, as `(to, resource, amount)`
+    /// transfers.
+    pub fn multi_transfer(
+        from: &PrivateKey,
+        transfers: &[(ComponentAddress, ResourceAddress, Decimal)],
+        fee_payer: &PrivateKey,
+        config: &TransactionPresetConfig,
+    ) -> NotarizedTransactionV1 {
+        let from_account = ComponentAddress::virtual_account_from_public_key(&from.public_key());
+
+        let mut builder = ManifestBuilder::new().lock_fee(
+            ComponentAddress::virtual_account_from_public_key(&fee_payer.public_key()),
+            config.fee,
+        );
+        for (to, resource_address, amount) in transfers {
+            builder = builder
+                .withdraw_from_account(from_account, *resource_address, *amount)
+                .try_deposit_batch_or_abort(*to);
+        }
+
+        Self::signed_preset(builder.build(), &[from], fee_payer, config)
+    }
+
+    /// Calls a method, with the fee paid and the transaction notarized by the fee payer.
+    pub fn call_method_preset(
+        address: impl ResolvableGlobalAddress,
+        method_name: impl Into<String>,
+        args: impl ResolvableArguments,
+        signers: &[&PrivateKey],
+        fee_payer: &PrivateKey,
+        config: &TransactionPresetConfig,
+    ) -> NotarizedTransactionV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(
+                ComponentAddress::virtual_account_from_public_key(&fee_payer.public_key()),
+                config.fee,
+            )
+            .call_method(address, method_name, args)
+            .build();
+
+        Self::signed_preset(manifest, signers, fee_payer, config)
+    }
+
+    /// Signs and notarizes a manifest, skipping the intent signatures of the fee payer's key
+    /// which is already a signatory as the notary.
+    pub fn signed_preset(
+        manifest: TransactionManifestV1,
+        signers: &[&PrivateKey],
+        fee_payer: &PrivateKey,
+        config: &TransactionPresetConfig,
+    ) -> NotarizedTransactionV1 {
+        let notary_public_key = fee_payer.public_key();
+        let mut builder = TransactionBuilder::new()
+            .header(config.header(notary_public_key))
+            .manifest(manifest);
+        let mut signed_keys = index_set_new();
+        for signer in signers {
+            let public_key = signer.public_key();
+            if public_key != notary_public_key && signed_keys.insert(public_key) {
+                builder = builder.sign(*signer);
+            }
+        }
+        builder.notarize(fee_payer).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(transaction: &NotarizedTransactionV1) -> Vec<PublicKey> {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        validator
+            .validate(transaction.prepare().unwrap())
+            .unwrap()
+            .signer_keys
+    }
+
+    #[test]
+    fn simple_transfer_is_signed_by_sender_and_fee_payer() {
+        let from: PrivateKey = Secp256k1PrivateKey::from_u64(1).unwrap().into();
+        let fee_payer: PrivateKey = Ed25519PrivateKey::from_u64(2).unwrap().into();
+        let to = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(3).unwrap().public_key(),
+        );
+        let config = TransactionPresetConfig::new(&NetworkDefinition::simulator(), Epoch::of(5), 1);
+
+        let transaction =
+            TransactionBuilder::simple_transfer(&from, to, XRD, dec!("1.5"), &fee_payer, &config);
+
+        let header = &transaction.signed_intent.intent.header;
+        assert_eq!(header.start_epoch_inclusive, Epoch::of(5));
+        assert_eq!(header.end_epoch_exclusive, Epoch::of(15));
+        assert_eq!(transaction.signed_intent.intent.instructions.0.len(), 4);
+        let signer_keys = validate(&transaction);
+        assert!(signer_keys.contains(&from.public_key()));
+        assert!(signer_keys.contains(&fee_payer.public_key()));
+    }
+
+    #[test]
+    fn fee_payer_is_not_signed_twice() {
+        let account_key: PrivateKey = Secp256k1PrivateKey::from_u64(1).unwrap().into();
+        let config = TransactionPresetConfig::new(&NetworkDefinition::simulator(), Epoch::of(5), 1)
+            .tip_percentage(5);
+
+        let transaction = TransactionBuilder::multi_transfer(
+            &account_key,
+            &[(FAUCET, XRD, dec!("1")), (FAUCET, XRD, dec!("2"))],
+            &account_key,
+            &config,
+        );
+
+        assert!(transaction
+            .signed_intent
+            .intent_signatures
+            .signatures
+            .is_empty());
+        assert_eq!(validate(&transaction), vec![account_key.public_key()]);
+    }
+}
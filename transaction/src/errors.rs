@@ -11,6 +11,25 @@ pub enum HeaderValidationError {
     InvalidTipPercentage,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EpochWindowValidationError {
+    HeaderValidationError(HeaderValidationError),
+    TransactionEpochNotYetValid {
+        valid_from: Epoch,
+        current_epoch: Epoch,
+    },
+    TransactionEpochNoLongerValid {
+        valid_until: Epoch,
+        current_epoch: Epoch,
+    },
+}
+
+impl From<HeaderValidationError> for EpochWindowValidationError {
+    fn from(err: HeaderValidationError) -> Self {
+        Self::HeaderValidationError(err)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SignatureValidationError {
     TooManySignatures,
@@ -26,6 +45,19 @@ impl From<EncodeError> for SignatureValidationError {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureCollectionError {
+    PrepareError(PrepareError),
+    DecodeError(DecodeError),
+    SignatureValidationError(SignatureValidationError),
+}
+
+impl From<SignatureValidationError> for SignatureCollectionError {
+    fn from(err: SignatureValidationError) -> Self {
+        Self::SignatureValidationError(err)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ManifestIdValidationError {
     BucketNotFound(ManifestBucket),
@@ -33,6 +65,9 @@ pub enum ManifestIdValidationError {
     BucketLocked(ManifestBucket),
     AddressReservationNotFound(ManifestAddressReservation),
     AddressNotFound(u32),
+    NoSavepointToRollBackTo,
+    SavepointNotRolledBack,
+    NamedIdsChangedInSavepointSection,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -51,6 +86,7 @@ pub enum TransactionValidationError {
     IdValidationError(ManifestIdValidationError),
     CallDataValidationError(CallDataValidationError),
     InvalidMessage(InvalidMessageError),
+    InactiveInstruction(InactiveInstructionError),
 }
 
 impl From<PrepareError> for TransactionValidationError {
@@ -71,6 +107,27 @@ impl From<InvalidMessageError> for TransactionValidationError {
     }
 }
 
+impl From<InactiveInstructionError> for TransactionValidationError {
+    fn from(value: InactiveInstructionError) -> Self {
+        Self::InactiveInstruction(value)
+    }
+}
+
+/// An instruction isn't accepted by the protocol version of the target network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InactiveInstructionError {
+    NotYetIntroduced {
+        instruction_index: usize,
+        introduced_in: ProtocolVersion,
+        protocol_version: ProtocolVersion,
+    },
+    Deprecated {
+        instruction_index: usize,
+        deprecated_in: ProtocolVersion,
+        protocol_version: ProtocolVersion,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InvalidMessageError {
     PlaintextMessageTooLong {
@@ -40,6 +40,24 @@ pub enum Instruction {
         resource_address: Value,
     },
 
+    AssertNextCallReturnsInclude {
+        resources: Value,
+    },
+
+    AssertNextCallReturnsExactly {
+        resources: Value,
+    },
+
+    AssertNextCallReturnsValue {
+        value: Value,
+    },
+
+    AssertMetadata {
+        address: Value,
+        key: Value,
+        value: Value,
+    },
+
     PopFromAuthZone {
         new_proof: Value,
     },
@@ -139,6 +157,10 @@ pub enum Instruction {
         named_address: Value,
     },
 
+    Savepoint,
+
+    RollbackToSavepoint,
+
     /* Call direct vault method aliases */
     RecallFromVault {
         vault_id: Value,
@@ -156,6 +178,10 @@ pub enum Instruction {
         vault_id: Value,
         args: Vec<Value>,
     },
+    BurnFromVault {
+        vault_id: Value,
+        args: Vec<Value>,
+    },
 
     /* Call function aliases */
     PublishPackage {
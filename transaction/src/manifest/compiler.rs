@@ -24,3 +24,16 @@ where
     generator::generate_manifest(&instructions, &address_bech32_decoder, blobs)
         .map_err(CompileError::GeneratorError)
 }
+
+/// Compiles a manifest like [`compile`], then shrinks it with [`compress`].
+pub fn compile_compressed<B>(
+    s: &str,
+    network: &NetworkDefinition,
+    blobs: B,
+    config: &CompressionConfig,
+) -> Result<TransactionManifestV1, CompileError>
+where
+    B: IsBlobProvider,
+{
+    compile(s, network, blobs).map(|manifest| compress(manifest, config))
+}
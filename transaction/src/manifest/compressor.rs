@@ -0,0 +1,308 @@
+use crate::internal_prelude::*;
+
+/// Byte arrays shorter than this are left inline by default: a blob reference is 32 bytes itself.
+pub const DEFAULT_MIN_BLOB_SIZE: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressionConfig {
+    /// The minimum length of a byte array to be factored into a blob.
+    pub min_blob_size: usize,
+    /// Whether identical consecutive instructions which are idempotent are merged into one.
+    pub merge_instructions: bool,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            min_blob_size: DEFAULT_MIN_BLOB_SIZE,
+            merge_instructions: true,
+        }
+    }
+}
+
+/// Shrinks the encoded size of a manifest without changing what it does.
+///
+/// Byte arrays passed to invocations which occur more than once, or which are already the content
+/// of a blob, are replaced by a reference to a blob; the transaction processor substitutes blob
+/// references with their content before invoking.
+///
+/// Identical consecutive instructions are merged if executing them twice is the same as executing
+/// them once, e.g. `CLEAR_AUTH_ZONE` or `ASSERT_WORKTOP_CONTAINS`.
+pub fn compress(
+    mut manifest: TransactionManifestV1,
+    config: &CompressionConfig,
+) -> TransactionManifestV1 {
+    if config.merge_instructions {
+        manifest
+            .instructions
+            .dedup_by(|next, previous| next == previous && is_idempotent(previous));
+    }
+
+    let mut occurrences = index_map_new::<Vec<u8>, usize>();
+    for instruction in &manifest.instructions {
+        if let Some(args) = invocation_args(instruction) {
+            visit_factorable_bytes(args, false, config.min_blob_size, &mut |bytes| {
+                *occurrences.entry(bytes).or_default() += 1;
+            });
+        }
+    }
+
+    let existing_blobs: IndexMap<Vec<u8>, Hash> = manifest
+        .blobs
+        .iter()
+        .map(|(blob_hash, blob)| (blob.clone(), *blob_hash))
+        .collect();
+    let mut blob_refs = index_map_new::<Vec<u8>, Hash>();
+    for (bytes, count) in occurrences {
+        if let Some(blob_hash) = existing_blobs.get(&bytes) {
+            blob_refs.insert(bytes, *blob_hash);
+        } else if count > 1 {
+            let blob_hash = hash(&bytes);
+            manifest.blobs.insert(blob_hash, bytes.clone());
+            blob_refs.insert(bytes, blob_hash);
+        }
+    }
+    if blob_refs.is_empty() {
+        return manifest;
+    }
+
+    for instruction in &mut manifest.instructions {
+        if let Some(args) = invocation_args_mut(instruction) {
+            replace_factorable_bytes(args, false, config.min_blob_size, &blob_refs);
+        }
+    }
+
+    manifest
+}
+
+fn is_idempotent(instruction: &InstructionV1) -> bool {
+    matches!(
+        instruction,
+        InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::AssertMetadata { .. }
+            | InstructionV1::ClearAuthZone
+            | InstructionV1::ClearSignatureProofs
+            | InstructionV1::DropAllProofs
+    )
+}
+
+fn invocation_args(instruction: &InstructionV1) -> Option<&ManifestValue> {
+    match instruction {
+        InstructionV1::CallFunction { args, .. }
+        | InstructionV1::CallMethod { args, .. }
+        | InstructionV1::CallRoyaltyMethod { args, .. }
+        | InstructionV1::CallMetadataMethod { args, .. }
+        | InstructionV1::CallAccessRulesMethod { args, .. }
+        | InstructionV1::CallDirectVaultMethod { args, .. } => Some(args),
+        _ => None,
+    }
+}
+
+fn invocation_args_mut(instruction: &mut InstructionV1) -> Option<&mut ManifestValue> {
+    match instruction {
+        InstructionV1::CallFunction { args, .. }
+        | InstructionV1::CallMethod { args, .. }
+        | InstructionV1::CallRoyaltyMethod { args, .. }
+        | InstructionV1::CallMetadataMethod { args, .. }
+        | InstructionV1::CallAccessRulesMethod { args, .. }
+        | InstructionV1::CallDirectVaultMethod { args, .. } => Some(args),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: &ManifestValue) -> Option<Vec<u8>> {
+    match value {
+        ManifestValue::Array {
+            element_value_kind: ManifestValueKind::U8,
+            elements,
+        } => elements
+            .iter()
+            .map(|element| match element {
+                ManifestValue::U8 { value } => Some(*value),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Calls `visit` with each byte array of at least `min_size` bytes which can be replaced by a blob
+/// reference: a value can only change kind if it's a field of a tuple or an enum, as the elements
+/// of arrays and maps must all be of the same kind.
+fn visit_factorable_bytes(
+    value: &ManifestValue,
+    replaceable: bool,
+    min_size: usize,
+    visit: &mut impl FnMut(Vec<u8>),
+) {
+    if let Some(bytes) = as_bytes(value) {
+        if replaceable && bytes.len() >= min_size {
+            visit(bytes);
+        }
+        return;
+    }
+
+    match value {
+        ManifestValue::Tuple { fields } | ManifestValue::Enum { fields, .. } => {
+            for field in fields {
+                visit_factorable_bytes(field, true, min_size, visit);
+            }
+        }
+        ManifestValue::Array { elements, .. } => {
+            for element in elements {
+                visit_factorable_bytes(element, false, min_size, visit);
+            }
+        }
+        ManifestValue::Map { entries, .. } => {
+            for (key, value) in entries {
+                visit_factorable_bytes(key, false, min_size, visit);
+                visit_factorable_bytes(value, false, min_size, visit);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn replace_factorable_bytes(
+    value: &mut ManifestValue,
+    replaceable: bool,
+    min_size: usize,
+    blob_refs: &IndexMap<Vec<u8>, Hash>,
+) {
+    if let Some(bytes) = as_bytes(value) {
+        if replaceable && bytes.len() >= min_size {
+            if let Some(blob_hash) = blob_refs.get(&bytes) {
+                *value = ManifestValue::Custom {
+                    value: ManifestCustomValue::Blob(ManifestBlobRef(blob_hash.0)),
+                };
+            }
+        }
+        return;
+    }
+
+    match value {
+        ManifestValue::Tuple { fields } | ManifestValue::Enum { fields, .. } => {
+            for field in fields {
+                replace_factorable_bytes(field, true, min_size, blob_refs);
+            }
+        }
+        ManifestValue::Array { elements, .. } => {
+            for element in elements {
+                replace_factorable_bytes(element, false, min_size, blob_refs);
+            }
+        }
+        ManifestValue::Map { entries, .. } => {
+            for (key, value) in entries {
+                replace_factorable_bytes(key, false, min_size, blob_refs);
+                replace_factorable_bytes(value, false, min_size, blob_refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile_compressed_simulator(manifest: &str) -> TransactionManifestV1 {
+        compile_compressed(
+            manifest,
+            &NetworkDefinition::simulator(),
+            MockBlobProvider::new(),
+            &CompressionConfig::default(),
+        )
+        .unwrap()
+    }
+
+    fn all_invocation_args(manifest: &TransactionManifestV1) -> Vec<&ManifestValue> {
+        manifest
+            .instructions
+            .iter()
+            .filter_map(invocation_args)
+            .collect()
+    }
+
+    #[test]
+    fn test_repeated_bytes_are_factored_into_a_blob() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let bytes = "ab".repeat(DEFAULT_MIN_BLOB_SIZE);
+        let manifest = compile_compressed_simulator(&format!(
+            r#"
+CALL_METHOD Address("{component}") "store" Bytes("{bytes}") Bytes("0102");
+CALL_METHOD Address("{component}") "store" Bytes("{bytes}") Bytes("0102");
+"#,
+            component = FAUCET.display(&encoder),
+            bytes = bytes,
+        ));
+
+        let blob_hash = hash(hex::decode(&bytes).unwrap());
+        assert_eq!(manifest.blobs.keys().collect::<Vec<_>>(), vec![&blob_hash]);
+        for args in all_invocation_args(&manifest) {
+            let ManifestValue::Tuple { fields } = args else {
+                panic!("Expected a tuple of arguments");
+            };
+            assert_eq!(
+                fields[0],
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Blob(ManifestBlobRef(blob_hash.0)),
+                }
+            );
+            // Below the size threshold
+            assert_eq!(as_bytes(&fields[1]), Some(vec![1, 2]));
+        }
+    }
+
+    #[test]
+    fn test_bytes_occurring_once_are_left_inline() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let manifest = compile_compressed_simulator(&format!(
+            r#"CALL_METHOD Address("{component}") "store" Bytes("{bytes}");"#,
+            component = FAUCET.display(&encoder),
+            bytes = "ab".repeat(DEFAULT_MIN_BLOB_SIZE),
+        ));
+
+        assert!(manifest.blobs.is_empty());
+    }
+
+    #[test]
+    fn test_only_idempotent_instructions_are_merged() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let manifest = compile_compressed_simulator(&format!(
+            r#"
+CLEAR_AUTH_ZONE;
+CLEAR_AUTH_ZONE;
+ASSERT_WORKTOP_CONTAINS_ANY Address("{xrd}");
+ASSERT_WORKTOP_CONTAINS_ANY Address("{xrd}");
+CALL_METHOD Address("{component}") "free";
+CALL_METHOD Address("{component}") "free";
+CLEAR_AUTH_ZONE;
+"#,
+            xrd = XRD.display(&encoder),
+            component = FAUCET.display(&encoder),
+        ));
+
+        assert_eq!(
+            manifest.instructions,
+            vec![
+                InstructionV1::ClearAuthZone,
+                InstructionV1::AssertWorktopContainsAny {
+                    resource_address: XRD
+                },
+                InstructionV1::CallMethod {
+                    address: DynamicGlobalAddress::Static(FAUCET.into()),
+                    method_name: "free".to_string(),
+                    args: ManifestValue::Tuple { fields: vec![] },
+                },
+                InstructionV1::CallMethod {
+                    address: DynamicGlobalAddress::Static(FAUCET.into()),
+                    method_name: "free".to_string(),
+                    args: ManifestValue::Tuple { fields: vec![] },
+                },
+                InstructionV1::ClearAuthZone,
+            ]
+        );
+    }
+}
@@ -37,8 +37,8 @@ use radix_engine_interface::blueprints::resource::{
     NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
     NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
     NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT, NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT,
-    NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT, VAULT_FREEZE_IDENT, VAULT_RECALL_IDENT,
-    VAULT_UNFREEZE_IDENT,
+    NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT, VAULT_BURN_FROM_VAULT_IDENT, VAULT_FREEZE_IDENT,
+    VAULT_RECALL_IDENT, VAULT_UNFREEZE_IDENT,
 };
 use radix_engine_interface::constants::{
     ACCESS_CONTROLLER_PACKAGE, ACCOUNT_PACKAGE, IDENTITY_PACKAGE, RESOURCE_PACKAGE,
@@ -259,6 +259,34 @@ pub fn decompile_instruction<F: fmt::Write>(
             "ASSERT_WORKTOP_CONTAINS_ANY",
             to_manifest_value(&(resource_address,))?,
         ),
+        InstructionV1::AssertNextCallReturnsInclude { resources } => (
+            "ASSERT_NEXT_CALL_RETURNS_INCLUDE",
+            to_manifest_value(&(resources,))?,
+        ),
+        InstructionV1::AssertNextCallReturnsExactly { resources } => (
+            "ASSERT_NEXT_CALL_RETURNS_EXACTLY",
+            to_manifest_value(&(resources,))?,
+        ),
+        InstructionV1::AssertNextCallReturnsValue { value } => (
+            "ASSERT_NEXT_CALL_RETURNS_VALUE",
+            Value::Tuple {
+                fields: vec![value.clone()],
+            },
+        ),
+        InstructionV1::AssertMetadata {
+            address,
+            key,
+            value,
+        } => (
+            "ASSERT_METADATA",
+            Value::Tuple {
+                fields: vec![
+                    address.to_instruction_argument(),
+                    to_manifest_value(key)?,
+                    to_manifest_value(value)?,
+                ],
+            },
+        ),
         InstructionV1::PopFromAuthZone => {
             let proof = context.new_proof();
             ("POP_FROM_AUTH_ZONE", to_manifest_value(&(proof,))?)
@@ -617,6 +645,10 @@ pub fn decompile_instruction<F: fmt::Write>(
                     fields.push(to_manifest_value(vault_id)?);
                     "RECALL_NON_FUNGIBLES_FROM_VAULT"
                 }
+                VAULT_BURN_FROM_VAULT_IDENT => {
+                    fields.push(to_manifest_value(vault_id)?);
+                    "BURN_FROM_VAULT"
+                }
                 /* Default */
                 _ => {
                     fields.push(to_manifest_value(vault_id)?);
@@ -652,6 +684,8 @@ pub fn decompile_instruction<F: fmt::Write>(
                 ))?,
             )
         }
+        InstructionV1::Savepoint => ("SAVEPOINT", to_manifest_value(&())?),
+        InstructionV1::RollbackToSavepoint => ("ROLLBACK_TO_SAVEPOINT", to_manifest_value(&())?),
     };
 
     write!(f, "{}", display_name)?;
@@ -0,0 +1,359 @@
+use crate::internal_prelude::*;
+use crate::manifest::lexer::{LexerError, Position, Span, TokenKind};
+use crate::manifest::parser::{ParserError, TokenType};
+
+/// A human-readable report of a [`CompileError`], located in the manifest source if possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// The part of the source the error is about, if known
+    pub span: Option<Span>,
+    /// A hint on how to fix the error, e.g. a suggestion for a misspelled name
+    pub help: Option<String>,
+}
+
+impl CompileError {
+    /// Describes the error, locating it in the given source (which it has been compiled from).
+    pub fn diagnostic(&self, source: &str) -> Diagnostic {
+        let (message, span, help) = match self {
+            CompileError::LexerError(error) => match error {
+                LexerError::UnexpectedEof => (
+                    "unexpected end of file".to_string(),
+                    Some(end_of_source_span(source)),
+                    None,
+                ),
+                LexerError::UnexpectedChar(c, position) => (
+                    format!("unexpected character {:?}", c),
+                    Some(char_span(*position)),
+                    None,
+                ),
+                LexerError::InvalidInteger(integer, position) => (
+                    format!("invalid integer `{}`", integer),
+                    Some(char_span(*position)),
+                    None,
+                ),
+                LexerError::InvalidUnicode(code_point, position) => (
+                    format!("invalid unicode code point {}", code_point),
+                    Some(char_span(*position)),
+                    None,
+                ),
+                LexerError::UnknownIdentifier(identifier, position) => (
+                    format!("unknown identifier `{}`", identifier),
+                    Some(char_span(*position)),
+                    None,
+                ),
+            },
+            CompileError::ParserError(error) => match error {
+                ParserError::UnexpectedEof => (
+                    "unexpected end of file".to_string(),
+                    Some(end_of_source_span(source)),
+                    None,
+                ),
+                ParserError::UnexpectedToken { expected, actual } => (
+                    format!(
+                        "expected {}, found {}",
+                        describe_token_type(expected),
+                        describe_token_kind(&actual.kind)
+                    ),
+                    Some(actual.span),
+                    None,
+                ),
+                ParserError::InvalidNumberOfValues {
+                    expected,
+                    actual,
+                    span,
+                } => (
+                    format!("expected {} value(s), found {}", expected, actual),
+                    Some(*span),
+                    None,
+                ),
+                ParserError::InvalidNumberOfTypes {
+                    expected,
+                    actual,
+                    span,
+                } => (
+                    format!("expected {} type(s), found {}", expected, actual),
+                    Some(*span),
+                    None,
+                ),
+                ParserError::InvalidHex(hex) => (format!("invalid hex `{}`", hex), None, None),
+                ParserError::UnknownInstruction {
+                    instruction,
+                    span,
+                    suggestion,
+                } => (
+                    format!("unknown instruction `{}`", instruction),
+                    Some(*span),
+                    suggestion.as_deref().map(did_you_mean),
+                ),
+                ParserError::UnknownEnumDiscriminator {
+                    discriminator,
+                    span,
+                    suggestion,
+                } => (
+                    format!("unknown enum discriminator `{}`", discriminator),
+                    Some(*span),
+                    suggestion.as_deref().map(did_you_mean),
+                ),
+                ParserError::MaxDepthExceeded(max_depth) => (
+                    format!("value nested deeper than the maximum of {}", max_depth),
+                    None,
+                    None,
+                ),
+            },
+            CompileError::GeneratorError(error) => (format!("{:?}", error), None, None),
+        };
+        Diagnostic {
+            message,
+            span,
+            help,
+        }
+    }
+}
+
+impl Diagnostic {
+    /// Renders the diagnostic like rustc does, quoting the erroneous line of the source.
+    ///
+    /// ```text
+    /// error: unknown instruction `CALL_METHD`
+    ///  --> manifest.rtm:3:1
+    ///   |
+    /// 3 | CALL_METHD
+    ///   | ^^^^^^^^^^ help: did you mean `CALL_METHOD`?
+    /// ```
+    pub fn render(&self, source: &str, source_name: &str) -> String {
+        let mut output = format!("error: {}\n", self.message);
+        match &self.span {
+            Some(span) => {
+                let line_number = span.start.line_number;
+                let line = source.lines().nth(line_number - 1).unwrap_or("");
+                let column = span.start.line_char_index;
+                let width = if span.end.line_number == line_number {
+                    span.end.line_char_index.saturating_sub(column)
+                } else {
+                    line.chars().count().saturating_sub(column)
+                }
+                .max(1);
+                let gutter = " ".repeat(line_number.to_string().len());
+
+                output.push_str(&format!(
+                    "{}--> {}:{}:{}\n",
+                    gutter,
+                    source_name,
+                    line_number,
+                    column + 1
+                ));
+                output.push_str(&format!("{} |\n", gutter));
+                output.push_str(&format!("{} | {}\n", line_number, line));
+                output.push_str(&format!(
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(column),
+                    "^".repeat(width)
+                ));
+                if let Some(help) = &self.help {
+                    output.push_str(&format!(" help: {}", help));
+                }
+                output.push('\n');
+            }
+            None => {
+                output.push_str(&format!(" --> {}\n", source_name));
+                if let Some(help) = &self.help {
+                    output.push_str(&format!("  = help: {}\n", help));
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Renders the diagnostic of a [`CompileError`] for the given source, see [`Diagnostic::render`].
+pub fn render_compile_error(source: &str, source_name: &str, error: &CompileError) -> String {
+    error.diagnostic(source).render(source, source_name)
+}
+
+/// Finds the candidate closest to the given (misspelled) input, if any is close enough to be a
+/// plausible suggestion.
+///
+/// The comparison is case-insensitive, and candidates further than a third of the input length
+/// (in edits) are ignored.
+pub fn closest_match<'a>(
+    input: &str,
+    candidates: impl Iterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let input = input.to_lowercase();
+    let max_distance = (input.chars().count() / 3).max(1);
+    let mut closest: Option<(&'a str, usize)> = None;
+    for candidate in candidates {
+        let distance = edit_distance(&input, &candidate.to_lowercase());
+        if distance <= max_distance && closest.map_or(true, |(_, best)| distance < best) {
+            closest = Some((candidate, distance));
+        }
+    }
+    closest.map(|(candidate, _)| candidate)
+}
+
+/// The Levenshtein distance between two strings, in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        sbor::rust::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+fn did_you_mean(suggestion: &str) -> String {
+    format!("did you mean `{}`?", suggestion)
+}
+
+/// The span of the char at the given position.
+fn char_span(position: Position) -> Span {
+    Span {
+        start: position,
+        end: Position {
+            full_index: position.full_index + 1,
+            line_number: position.line_number,
+            line_char_index: position.line_char_index + 1,
+        },
+    }
+}
+
+/// The (empty) span at the end of the source.
+fn end_of_source_span(source: &str) -> Span {
+    let mut end = Position {
+        full_index: 0,
+        line_number: 1,
+        line_char_index: 0,
+    };
+    for c in source.chars() {
+        end.full_index += 1;
+        if c == '\n' {
+            end.line_number += 1;
+            end.line_char_index = 0;
+        } else {
+            end.line_char_index += 1;
+        }
+    }
+    Span { start: end, end }
+}
+
+fn describe_token_type(token_type: &TokenType) -> String {
+    match token_type {
+        TokenType::Instruction => "an instruction".to_string(),
+        TokenType::Value => "a value".to_string(),
+        TokenType::ValueKind => "a value kind".to_string(),
+        TokenType::EnumDiscriminator => "an enum discriminator".to_string(),
+        TokenType::Exact(kind) => describe_token_kind(kind),
+        TokenType::OneOf(kinds) => format!(
+            "one of {}",
+            kinds
+                .iter()
+                .map(describe_token_kind)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn describe_token_kind(kind: &TokenKind) -> String {
+    match kind {
+        TokenKind::BoolLiteral(value) => format!("`{}`", value),
+        TokenKind::I8Literal(value) => format!("`{}i8`", value),
+        TokenKind::I16Literal(value) => format!("`{}i16`", value),
+        TokenKind::I32Literal(value) => format!("`{}i32`", value),
+        TokenKind::I64Literal(value) => format!("`{}i64`", value),
+        TokenKind::I128Literal(value) => format!("`{}i128`", value),
+        TokenKind::U8Literal(value) => format!("`{}u8`", value),
+        TokenKind::U16Literal(value) => format!("`{}u16`", value),
+        TokenKind::U32Literal(value) => format!("`{}u32`", value),
+        TokenKind::U64Literal(value) => format!("`{}u64`", value),
+        TokenKind::U128Literal(value) => format!("`{}u128`", value),
+        TokenKind::StringLiteral(value) => format!("`{:?}`", value),
+        TokenKind::Ident(ident) => format!("`{}`", ident),
+        TokenKind::OpenParenthesis => "`(`".to_string(),
+        TokenKind::CloseParenthesis => "`)`".to_string(),
+        TokenKind::LessThan => "`<`".to_string(),
+        TokenKind::GreaterThan => "`>`".to_string(),
+        TokenKind::Comma => "`,`".to_string(),
+        TokenKind::Semicolon => "`;`".to_string(),
+        TokenKind::FatArrow => "`=>`".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile_error(source: &str) -> CompileError {
+        compile(source, &NetworkDefinition::simulator(), BlobProvider::new())
+            .expect_err("Manifest should fail to compile")
+    }
+
+    #[test]
+    fn test_closest_match() {
+        let candidates = ["CALL_METHOD", "CALL_FUNCTION", "DROP_ALL_PROOFS"];
+        assert_eq!(
+            closest_match("CALL_METHD", candidates.iter().cloned()),
+            Some("CALL_METHOD")
+        );
+        assert_eq!(
+            closest_match("call_function", candidates.iter().cloned()),
+            Some("CALL_FUNCTION")
+        );
+        assert_eq!(closest_match("BURN", candidates.iter().cloned()), None);
+    }
+
+    #[test]
+    fn test_unknown_instruction_is_rendered_with_suggestion() {
+        let source = "DROP_ALL_PROOFS;\nCALL_METHD\n    Address(\"abc\")\n    \"foo\";\n";
+
+        let rendered = render_compile_error(source, "manifest.rtm", &compile_error(source));
+
+        assert_eq!(
+            rendered,
+            "error: unknown instruction `CALL_METHD`\n \
+             --> manifest.rtm:2:1\n  \
+             |\n\
+             2 | CALL_METHD\n  \
+             | ^^^^^^^^^^ help: did you mean `CALL_METHOD`?\n"
+        );
+    }
+
+    #[test]
+    fn test_unexpected_token_is_rendered_with_expected_tokens() {
+        let source = "CALL_METHOD Address(\"abc\") \"foo\" Tuple(1u8 2u8);";
+
+        let rendered = render_compile_error(source, "manifest.rtm", &compile_error(source));
+
+        assert_eq!(
+            rendered,
+            "error: expected one of `,`, `)`, found `2u8`\n \
+             --> manifest.rtm:1:44\n  \
+             |\n\
+             1 | CALL_METHOD Address(\"abc\") \"foo\" Tuple(1u8 2u8);\n  \
+             |                                            ^^^\n"
+        );
+    }
+
+    #[test]
+    fn test_unexpected_eof_points_at_end_of_source() {
+        let source = "CALL_METHOD\n    Address(\"abc\")";
+
+        let diagnostic = compile_error(source).diagnostic(source);
+
+        assert_eq!(diagnostic.message, "unexpected end of file");
+        assert_eq!(
+            diagnostic
+                .span
+                .map(|span| (span.start.line_number, span.start.line_char_index)),
+            Some((2, 18))
+        );
+    }
+}
@@ -335,6 +335,26 @@ CALL_FUNCTION
         );
     }
 
+    #[test]
+    fn test_resource_burn_from_vault() {
+        compile_and_decompile_with_inversion_test(
+            "resource_burn_from_vault",
+            apply_address_replacements(include_str!(
+                "../../examples/resources/burn_from_vault.rtm"
+            )),
+            &NetworkDefinition::simulator(),
+            vec![],
+            apply_address_replacements(
+                r##"
+BURN_FROM_VAULT
+    Address("${vault_address}")
+    Decimal("1.2")
+;
+"##,
+            ),
+        );
+    }
+
     #[test]
     fn test_resource_recall_nonfungibles() {
         compile_and_decompile_with_inversion_test(
@@ -1267,6 +1287,70 @@ CALL_METHOD
         );
     }
 
+    #[test]
+    fn test_savepoint() {
+        let canonical_manifest = apply_address_replacements(
+            r##"
+CALL_METHOD
+    Address("${account_address}")
+    "lock_fee"
+    Decimal("500")
+;
+CALL_METHOD
+    Address("${account_address}")
+    "withdraw"
+    Address("${fungible_resource_address}")
+    Decimal("123")
+;
+SAVEPOINT;
+TAKE_FROM_WORKTOP
+    Address("${fungible_resource_address}")
+    Decimal("123")
+    Bucket("bucket1")
+;
+CALL_METHOD
+    Address("${component_address}")
+    "swap"
+    Bucket("bucket1")
+;
+ROLLBACK_TO_SAVEPOINT;
+CALL_METHOD
+    Address("${account_address}")
+    "deposit_batch"
+    Expression("ENTIRE_WORKTOP")
+;
+        "##,
+        );
+        compile_and_decompile_with_inversion_test(
+            "savepoint",
+            &canonical_manifest,
+            &NetworkDefinition::simulator(),
+            vec![],
+            &canonical_manifest,
+        );
+    }
+
+    #[test]
+    fn test_savepoint_section_must_consume_its_buckets() {
+        let manifest = apply_address_replacements(
+            r##"
+SAVEPOINT;
+TAKE_FROM_WORKTOP
+    Address("${fungible_resource_address}")
+    Decimal("123")
+    Bucket("bucket1")
+;
+ROLLBACK_TO_SAVEPOINT;
+        "##,
+        );
+        assert!(matches!(
+            compile(&manifest, &NetworkDefinition::simulator(), BlobProvider::new()),
+            Err(CompileError::GeneratorError(GeneratorError::IdValidationError(
+                ManifestIdValidationError::NamedIdsChangedInSavepointSection
+            )))
+        ));
+    }
+
     #[test]
     fn test_simple_transfer_with_multiple_locked_fees() {
         // Note - this test is intended for demonstration for the ledger
@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use sbor::rust::collections::NonIterMap;
+use sbor::rust::collections::{index_map_new, IndexMap};
 
 macro_rules! known_enum {
     ($map: expr, enum $name:ident { $($variant:ident = $id:expr;)* }) => {
@@ -11,8 +11,10 @@ macro_rules! known_enum {
 
 // FIXME: we need a final sanity check before mainnet launch!
 lazy_static! {
-    pub static ref KNOWN_ENUM_DISCRIMINATORS: NonIterMap<&'static str, u8> = {
-        let mut m = NonIterMap::new();
+    /// The discriminators of the known enum variants, in declaration order so that the variant
+    /// names can be listed (e.g. to suggest one for a misspelled variant).
+    pub static ref KNOWN_ENUM_DISCRIMINATORS: IndexMap<&'static str, u8> = {
+        let mut m = index_map_new();
 
         // Protocol Buffer syntax
 
@@ -77,6 +79,7 @@ lazy_static! {
                 AllowAll = 0;
                 DenyAll = 1;
                 Protected = 2;
+                StateGated = 3;
             }
         );
 
@@ -163,6 +166,14 @@ lazy_static! {
             }
         );
 
+        known_enum!(
+            m,
+            enum ResourceAssertion {
+                Amount = 0;
+                NonFungibles = 1;
+            }
+        );
+
         known_enum!(
             m,
             enum OwnerRole {
@@ -0,0 +1,118 @@
+use crate::data::*;
+use crate::internal_prelude::*;
+
+/// Rough size and complexity figures of a manifest, computed without executing it.
+///
+/// These are static estimates meant for warning users before submission and for asserting
+/// complexity budgets in tests; the actual execution may touch more state than predicted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ManifestStats {
+    /// The number of instructions in the manifest.
+    pub instruction_count: usize,
+    /// The number of substates expected to be read or written: one per distinct entity
+    /// statically referenced by the manifest, plus one per invocation.
+    pub expected_substate_touches: usize,
+    /// The total size of all blobs, in bytes.
+    pub blob_bytes: usize,
+    /// The number of signatures which may need to be validated to authorize the manifest: one
+    /// per distinct account, identity or access controller whose methods are called. The notary
+    /// signature is not included.
+    pub worst_case_signature_validations: usize,
+}
+
+/// Estimates the size and complexity of the given manifest.
+pub fn estimate(manifest: &TransactionManifestV1) -> ManifestStats {
+    let mut references = index_set_new();
+    let mut signers = index_set_new();
+    let mut invocation_count = 0;
+
+    for instruction in &manifest.instructions {
+        if let Ok(encoded) = manifest_encode(instruction) {
+            references.extend(extract_references(
+                &encoded,
+                traversal::ExpectedStart::Value,
+            ));
+        }
+
+        match instruction {
+            InstructionV1::CallMethod { address, .. } => {
+                invocation_count += 1;
+                if let DynamicGlobalAddress::Static(address) = address {
+                    if may_require_signature(address) {
+                        signers.insert(*address);
+                    }
+                }
+            }
+            InstructionV1::CallFunction { .. }
+            | InstructionV1::CallRoyaltyMethod { .. }
+            | InstructionV1::CallMetadataMethod { .. }
+            | InstructionV1::CallAccessRulesMethod { .. }
+            | InstructionV1::CallDirectVaultMethod { .. }
+            | InstructionV1::AssertMetadata { .. } => {
+                invocation_count += 1;
+            }
+            _ => {}
+        }
+    }
+
+    ManifestStats {
+        instruction_count: manifest.instructions.len(),
+        expected_substate_touches: references.len() + invocation_count,
+        blob_bytes: manifest.blobs.values().map(|blob| blob.len()).sum(),
+        worst_case_signature_validations: signers.len(),
+    }
+}
+
+fn may_require_signature(address: &GlobalAddress) -> bool {
+    matches!(
+        address.as_node_id().entity_type(),
+        Some(
+            EntityType::GlobalAccount
+                | EntityType::GlobalVirtualSecp256k1Account
+                | EntityType::GlobalVirtualEd25519Account
+                | EntityType::GlobalIdentity
+                | EntityType::GlobalVirtualSecp256k1Identity
+                | EntityType::GlobalVirtualEd25519Identity
+                | EntityType::GlobalAccessController
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::ManifestBuilder;
+
+    #[test]
+    fn test_estimate_counts_instructions_blobs_and_signers() {
+        let account1 = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let account2 = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(2).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account1, 10)
+            .withdraw_from_account(account1, XRD, 5)
+            .take_all_from_worktop(XRD, "xrd")
+            .try_deposit_or_abort(account2, "xrd")
+            .publish_package_advanced(
+                None,
+                vec![0u8; 100],
+                PackageDefinition::default(),
+                BTreeMap::<String, MetadataValue>::new(),
+                OwnerRole::None,
+            )
+            .build();
+
+        let stats = estimate(&manifest);
+
+        assert_eq!(stats.instruction_count, 5);
+        assert_eq!(stats.blob_bytes, 100);
+        // Both accounts are called, so both are counted even though the deposit into account2
+        // may not need a signature
+        assert_eq!(stats.worst_case_signature_validations, 2);
+        // account1, account2, XRD and the package package, plus four invocations
+        assert_eq!(stats.expected_substate_touches, 8);
+    }
+}
@@ -243,6 +243,9 @@ where
             &blobs,
         )?);
     }
+    id_validator
+        .check_all_savepoints_dropped()
+        .map_err(GeneratorError::IdValidationError)?;
 
     Ok(TransactionManifestV1 {
         instructions: output,
@@ -336,6 +339,40 @@ where
                 )?,
             }
         }
+        ast::Instruction::AssertNextCallReturnsInclude { resources } => {
+            InstructionV1::AssertNextCallReturnsInclude {
+                resources: generate_resource_assertions(
+                    resources,
+                    resolver,
+                    address_bech32_decoder,
+                    blobs,
+                )?,
+            }
+        }
+        ast::Instruction::AssertNextCallReturnsExactly { resources } => {
+            InstructionV1::AssertNextCallReturnsExactly {
+                resources: generate_resource_assertions(
+                    resources,
+                    resolver,
+                    address_bech32_decoder,
+                    blobs,
+                )?,
+            }
+        }
+        ast::Instruction::AssertNextCallReturnsValue { value } => {
+            InstructionV1::AssertNextCallReturnsValue {
+                value: generate_value(value, None, resolver, address_bech32_decoder, blobs)?,
+            }
+        }
+        ast::Instruction::AssertMetadata {
+            address,
+            key,
+            value,
+        } => InstructionV1::AssertMetadata {
+            address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
+            key: generate_string(key)?,
+            value: generate_metadata_value(value, resolver, address_bech32_decoder, blobs)?,
+        },
         ast::Instruction::PopFromAuthZone { new_proof } => {
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
@@ -595,6 +632,18 @@ where
             }
         }
 
+        ast::Instruction::Savepoint => {
+            id_validator.new_savepoint();
+            InstructionV1::Savepoint
+        }
+
+        ast::Instruction::RollbackToSavepoint => {
+            id_validator
+                .drop_savepoint()
+                .map_err(GeneratorError::IdValidationError)?;
+            InstructionV1::RollbackToSavepoint
+        }
+
         /* direct vault method aliases */
         ast::Instruction::RecallFromVault { vault_id, args } => {
             InstructionV1::CallDirectVaultMethod {
@@ -622,6 +671,13 @@ where
                 args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
             }
         }
+        ast::Instruction::BurnFromVault { vault_id, args } => {
+            InstructionV1::CallDirectVaultMethod {
+                address: generate_local_address(vault_id, address_bech32_decoder)?,
+                method_name: VAULT_BURN_FROM_VAULT_IDENT.to_string(),
+                args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
+            }
+        }
 
         /* call function aliases */
         ast::Instruction::PublishPackage { args } => InstructionV1::CallFunction {
@@ -1217,6 +1273,46 @@ fn generate_non_fungible_local_ids(
     }
 }
 
+fn generate_resource_assertions<B>(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+    address_bech32_decoder: &AddressBech32Decoder,
+    blobs: &B,
+) -> Result<Vec<ResourceAssertion>, GeneratorError>
+where
+    B: IsBlobProvider,
+{
+    let value = generate_value(
+        value,
+        Some(ManifestValueKind::Array),
+        resolver,
+        address_bech32_decoder,
+        blobs,
+    )?;
+    let encoded = manifest_encode(&value).map_err(GeneratorError::ArgumentEncodingError)?;
+    manifest_decode(&encoded).map_err(GeneratorError::ArgumentDecodingError)
+}
+
+fn generate_metadata_value<B>(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+    address_bech32_decoder: &AddressBech32Decoder,
+    blobs: &B,
+) -> Result<MetadataValue, GeneratorError>
+where
+    B: IsBlobProvider,
+{
+    let value = generate_value(
+        value,
+        Some(ManifestValueKind::Enum),
+        resolver,
+        address_bech32_decoder,
+        blobs,
+    )?;
+    let encoded = manifest_encode(&value).map_err(GeneratorError::ArgumentEncodingError)?;
+    manifest_decode(&encoded).map_err(GeneratorError::ArgumentDecodingError)
+}
+
 fn generate_byte_vec_from_hex(value: &ast::Value) -> Result<Vec<u8>, GeneratorError> {
     let bytes = match value {
         ast::Value::String(s) => {
@@ -1678,6 +1774,29 @@ mod tests {
                 resource_address,
             },
         );
+        generate_instruction_ok!(
+            r#"ASSERT_NEXT_CALL_RETURNS_INCLUDE  Array<Enum>(Enum<ResourceAssertion::Amount>(Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez"), Decimal("1")));"#,
+            InstructionV1::AssertNextCallReturnsInclude {
+                resources: vec![ResourceAssertion::Amount(
+                    resource_address,
+                    Decimal::from(1)
+                )],
+            },
+        );
+        generate_instruction_ok!(
+            r#"ASSERT_NEXT_CALL_RETURNS_VALUE  Decimal("1");"#,
+            InstructionV1::AssertNextCallReturnsValue {
+                value: to_manifest_value_and_unwrap!(&Decimal::from(1)),
+            },
+        );
+        generate_instruction_ok!(
+            r#"ASSERT_METADATA  Address("component_sim1cqvgx33089ukm2pl97pv4max0x40ruvfy4lt60yvya744cvemygpmu")  "name"  Enum<Metadata::String>("Faucet");"#,
+            InstructionV1::AssertMetadata {
+                address: component.into(),
+                key: "name".to_string(),
+                value: MetadataValue::String("Faucet".to_string()),
+            },
+        );
         generate_instruction_ok!(
             r#"CALL_FUNCTION  Address("package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk")  "Airdrop"  "new"  500u32  PreciseDecimal("120");"#,
             InstructionV1::CallFunction {
@@ -1,16 +1,24 @@
 pub mod ast;
 pub mod blob_provider;
 pub mod compiler;
+pub mod compressor;
 pub mod decompiler;
+pub mod diagnostics;
 #[cfg(feature = "std")]
 pub mod dumper;
 pub mod e2e;
 pub mod enums;
+pub mod estimator;
 pub mod generator;
 pub mod lexer;
 pub mod parser;
+pub mod retarget;
 
 pub use blob_provider::*;
-pub use compiler::{compile, CompileError};
+pub use compiler::{compile, compile_compressed, CompileError};
+pub use compressor::{compress, CompressionConfig, DEFAULT_MIN_BLOB_SIZE};
 pub use decompiler::{decompile, DecompileError};
+pub use diagnostics::{render_compile_error, Diagnostic};
 pub use enums::*;
+pub use estimator::{estimate, ManifestStats};
+pub use retarget::{retarget, RetargetError};
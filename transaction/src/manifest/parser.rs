@@ -1,6 +1,7 @@
 use crate::manifest::ast::{Instruction, Value, ValueKind};
+use crate::manifest::diagnostics::closest_match;
 use crate::manifest::enums::KNOWN_ENUM_DISCRIMINATORS;
-use crate::manifest::lexer::{Token, TokenKind};
+use crate::manifest::lexer::{Position, Span, Token, TokenKind};
 use radix_engine_interface::data::manifest::MANIFEST_SBOR_V1_MAX_DEPTH;
 
 // For values greater than below it is not possible to encode compiled manifest due to
@@ -10,11 +11,33 @@ pub const PARSER_MAX_DEPTH: usize = MANIFEST_SBOR_V1_MAX_DEPTH - 4;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParserError {
     UnexpectedEof,
-    UnexpectedToken { expected: TokenType, actual: Token },
-    InvalidNumberOfValues { expected: usize, actual: usize },
-    InvalidNumberOfTypes { expected: usize, actual: usize },
+    UnexpectedToken {
+        expected: TokenType,
+        actual: Token,
+    },
+    InvalidNumberOfValues {
+        expected: usize,
+        actual: usize,
+        span: Span,
+    },
+    InvalidNumberOfTypes {
+        expected: usize,
+        actual: usize,
+        span: Span,
+    },
     InvalidHex(String),
-    UnknownEnumDiscriminator(String),
+    UnknownInstruction {
+        instruction: String,
+        span: Span,
+        /// The known instruction closest to the unknown one, if any is close enough
+        suggestion: Option<String>,
+    },
+    UnknownEnumDiscriminator {
+        discriminator: String,
+        span: Span,
+        /// The known enum variant closest to the unknown one, if any is close enough
+        suggestion: Option<String>,
+    },
     MaxDepthExceeded(usize),
 }
 
@@ -25,6 +48,7 @@ pub enum TokenType {
     ValueKind,
     EnumDiscriminator,
     Exact(TokenKind),
+    OneOf(Vec<TokenKind>),
 }
 
 pub enum InstructionIdent {
@@ -38,6 +62,10 @@ pub enum InstructionIdent {
     AssertWorktopContains,
     AssertWorktopContainsNonFungibles,
     AssertWorktopContainsAny,
+    AssertNextCallReturnsInclude,
+    AssertNextCallReturnsExactly,
+    AssertNextCallReturnsValue,
+    AssertMetadata,
 
     PopFromAuthZone,
     PushToAuthZone,
@@ -59,6 +87,8 @@ pub enum InstructionIdent {
     CallAccessRulesMethod,
     DropAllProofs,
     AllocateGlobalAddress,
+    Savepoint,
+    RollbackToSavepoint,
 
     // ==============
     // Call direct vault method aliases
@@ -67,6 +97,7 @@ pub enum InstructionIdent {
     FreezeVault,
     UnfreezeVault,
     RecallNonFungiblesFromVault,
+    BurnFromVault,
 
     // ==============
     // Call function aliases
@@ -107,6 +138,73 @@ pub enum InstructionIdent {
 }
 
 impl InstructionIdent {
+    /// The idents of all the instructions, in the order of [`Self::from_ident`].
+    pub const IDENTS: &'static [&'static str] = &[
+        "TAKE_FROM_WORKTOP",
+        "TAKE_NON_FUNGIBLES_FROM_WORKTOP",
+        "TAKE_ALL_FROM_WORKTOP",
+        "RETURN_TO_WORKTOP",
+        "ASSERT_WORKTOP_CONTAINS",
+        "ASSERT_WORKTOP_CONTAINS_NON_FUNGIBLES",
+        "ASSERT_WORKTOP_CONTAINS_ANY",
+        "ASSERT_NEXT_CALL_RETURNS_INCLUDE",
+        "ASSERT_NEXT_CALL_RETURNS_EXACTLY",
+        "ASSERT_NEXT_CALL_RETURNS_VALUE",
+        "ASSERT_METADATA",
+        "POP_FROM_AUTH_ZONE",
+        "PUSH_TO_AUTH_ZONE",
+        "CLEAR_AUTH_ZONE",
+        "CREATE_PROOF_FROM_AUTH_ZONE_OF_AMOUNT",
+        "CREATE_PROOF_FROM_AUTH_ZONE_OF_NON_FUNGIBLES",
+        "CREATE_PROOF_FROM_AUTH_ZONE_OF_ALL",
+        "CLEAR_SIGNATURE_PROOFS",
+        "CREATE_PROOF_FROM_BUCKET_OF_AMOUNT",
+        "CREATE_PROOF_FROM_BUCKET_OF_NON_FUNGIBLES",
+        "CREATE_PROOF_FROM_BUCKET_OF_ALL",
+        "BURN_RESOURCE",
+        "CLONE_PROOF",
+        "DROP_PROOF",
+        "CALL_FUNCTION",
+        "CALL_METHOD",
+        "CALL_ROYALTY_METHOD",
+        "CALL_METADATA_METHOD",
+        "CALL_ACCESS_RULES_METHOD",
+        "DROP_ALL_PROOFS",
+        "ALLOCATE_GLOBAL_ADDRESS",
+        "SAVEPOINT",
+        "ROLLBACK_TO_SAVEPOINT",
+        "RECALL_FROM_VAULT",
+        "FREEZE_VAULT",
+        "UNFREEZE_VAULT",
+        "RECALL_NON_FUNGIBLES_FROM_VAULT",
+        "BURN_FROM_VAULT",
+        "PUBLISH_PACKAGE",
+        "PUBLISH_PACKAGE_ADVANCED",
+        "CREATE_FUNGIBLE_RESOURCE",
+        "CREATE_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY",
+        "CREATE_NON_FUNGIBLE_RESOURCE",
+        "CREATE_NON_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY",
+        "CREATE_IDENTITY",
+        "CREATE_IDENTITY_ADVANCED",
+        "CREATE_ACCOUNT",
+        "CREATE_ACCOUNT_ADVANCED",
+        "CREATE_ACCESS_CONTROLLER",
+        "SET_METADATA",
+        "REMOVE_METADATA",
+        "LOCK_METADATA",
+        "SET_COMPONENT_ROYALTY",
+        "LOCK_COMPONENT_ROYALTY",
+        "CLAIM_COMPONENT_ROYALTIES",
+        "SET_OWNER_ROLE",
+        "LOCK_OWNER_ROLE",
+        "SET_ROLE",
+        "MINT_FUNGIBLE",
+        "MINT_NON_FUNGIBLE",
+        "MINT_RUID_NON_FUNGIBLE",
+        "CLAIM_PACKAGE_ROYALTIES",
+        "CREATE_VALIDATOR",
+    ];
+
     pub fn from_ident(ident: &str) -> Option<Self> {
         let value = match ident {
             // ==============
@@ -121,6 +219,10 @@ impl InstructionIdent {
                 InstructionIdent::AssertWorktopContainsNonFungibles
             }
             "ASSERT_WORKTOP_CONTAINS_ANY" => InstructionIdent::AssertWorktopContainsAny,
+            "ASSERT_NEXT_CALL_RETURNS_INCLUDE" => InstructionIdent::AssertNextCallReturnsInclude,
+            "ASSERT_NEXT_CALL_RETURNS_EXACTLY" => InstructionIdent::AssertNextCallReturnsExactly,
+            "ASSERT_NEXT_CALL_RETURNS_VALUE" => InstructionIdent::AssertNextCallReturnsValue,
+            "ASSERT_METADATA" => InstructionIdent::AssertMetadata,
 
             "POP_FROM_AUTH_ZONE" => InstructionIdent::PopFromAuthZone,
             "PUSH_TO_AUTH_ZONE" => InstructionIdent::PushToAuthZone,
@@ -152,6 +254,8 @@ impl InstructionIdent {
 
             "DROP_ALL_PROOFS" => InstructionIdent::DropAllProofs,
             "ALLOCATE_GLOBAL_ADDRESS" => InstructionIdent::AllocateGlobalAddress,
+            "SAVEPOINT" => InstructionIdent::Savepoint,
+            "ROLLBACK_TO_SAVEPOINT" => InstructionIdent::RollbackToSavepoint,
 
             // ==============
             // Call direct vault method aliases
@@ -160,6 +264,7 @@ impl InstructionIdent {
             "FREEZE_VAULT" => InstructionIdent::FreezeVault,
             "UNFREEZE_VAULT" => InstructionIdent::UnfreezeVault,
             "RECALL_NON_FUNGIBLES_FROM_VAULT" => InstructionIdent::RecallNonFungiblesFromVault,
+            "BURN_FROM_VAULT" => InstructionIdent::BurnFromVault,
 
             // ==============
             // Call function aliases
@@ -467,9 +572,16 @@ impl Parser {
         let token = self.advance()?;
         let instruction_ident = match &token.kind {
             TokenKind::Ident(ident_str) => {
-                InstructionIdent::from_ident(ident_str).ok_or(ParserError::UnexpectedToken {
-                    expected: TokenType::Instruction,
-                    actual: token,
+                InstructionIdent::from_ident(ident_str).ok_or_else(|| {
+                    ParserError::UnknownInstruction {
+                        instruction: ident_str.clone(),
+                        span: token.span,
+                        suggestion: closest_match(
+                            ident_str,
+                            InstructionIdent::IDENTS.iter().cloned(),
+                        )
+                        .map(|ident| ident.to_string()),
+                    }
                 })?
             }
             _ => {
@@ -512,6 +624,26 @@ impl Parser {
             InstructionIdent::AssertWorktopContainsAny => Instruction::AssertWorktopContainsAny {
                 resource_address: self.parse_value()?,
             },
+            InstructionIdent::AssertNextCallReturnsInclude => {
+                Instruction::AssertNextCallReturnsInclude {
+                    resources: self.parse_value()?,
+                }
+            }
+            InstructionIdent::AssertNextCallReturnsExactly => {
+                Instruction::AssertNextCallReturnsExactly {
+                    resources: self.parse_value()?,
+                }
+            }
+            InstructionIdent::AssertNextCallReturnsValue => {
+                Instruction::AssertNextCallReturnsValue {
+                    value: self.parse_value()?,
+                }
+            }
+            InstructionIdent::AssertMetadata => Instruction::AssertMetadata {
+                address: self.parse_value()?,
+                key: self.parse_value()?,
+                value: self.parse_value()?,
+            },
             InstructionIdent::PopFromAuthZone => Instruction::PopFromAuthZone {
                 new_proof: self.parse_value()?,
             },
@@ -605,6 +737,8 @@ impl Parser {
                 address_reservation: self.parse_value()?,
                 named_address: self.parse_value()?,
             },
+            InstructionIdent::Savepoint => Instruction::Savepoint,
+            InstructionIdent::RollbackToSavepoint => Instruction::RollbackToSavepoint,
 
             /* Call direct vault method aliases */
             InstructionIdent::RecallFromVault => Instruction::RecallFromVault {
@@ -625,6 +759,10 @@ impl Parser {
                     args: self.parse_values_till_semicolon()?,
                 }
             }
+            InstructionIdent::BurnFromVault => Instruction::BurnFromVault {
+                vault_id: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
 
             /* Call function aliases */
             InstructionIdent::PublishPackage => Instruction::PublishPackage {
@@ -815,7 +953,15 @@ impl Parser {
             TokenKind::Ident(discriminator) => KNOWN_ENUM_DISCRIMINATORS
                 .get(discriminator.as_str())
                 .cloned()
-                .ok_or(ParserError::UnknownEnumDiscriminator(discriminator.clone()))?,
+                .ok_or_else(|| ParserError::UnknownEnumDiscriminator {
+                    suggestion: closest_match(
+                        &discriminator,
+                        KNOWN_ENUM_DISCRIMINATORS.keys().cloned(),
+                    )
+                    .map(|variant| variant.to_string()),
+                    discriminator,
+                    span: discriminator_token.span,
+                })?,
             _ => {
                 return Err(ParserError::UnexpectedToken {
                     expected: TokenType::EnumDiscriminator,
@@ -855,9 +1001,7 @@ impl Parser {
             advance_match!(self, TokenKind::FatArrow);
             let value_value = self.parse_value()?;
             entries.push((key_value, value_value));
-            if self.peek()?.kind != TokenKind::CloseParenthesis {
-                advance_match!(self, TokenKind::Comma);
-            }
+            self.advance_separator(TokenKind::CloseParenthesis)?;
         }
         advance_match!(self, TokenKind::CloseParenthesis);
         Ok(Value::Map(generics[0], generics[1], entries))
@@ -873,21 +1017,46 @@ impl Parser {
         let mut values = Vec::new();
         while self.peek()?.kind != close {
             values.push(self.parse_value()?);
-            if self.peek()?.kind != close {
-                advance_match!(self, TokenKind::Comma);
-            }
+            self.advance_separator(close.clone())?;
         }
         advance_match!(self, close);
         Ok(values)
     }
 
+    /// Consumes the comma after an element of a list, unless the element is the last one before
+    /// the given closing mark.
+    fn advance_separator(&mut self, close: TokenKind) -> Result<(), ParserError> {
+        let token = self.peek()?;
+        if token.kind == close {
+            Ok(())
+        } else if token.kind == TokenKind::Comma {
+            self.advance()?;
+            Ok(())
+        } else {
+            Err(ParserError::UnexpectedToken {
+                expected: TokenType::OneOf(vec![TokenKind::Comma, close]),
+                actual: token,
+            })
+        }
+    }
+
+    /// The span from the given start to the end of the last consumed token.
+    fn span_from(&self, start: Position) -> Span {
+        Span {
+            start,
+            end: self.tokens[self.current - 1].span.end,
+        }
+    }
+
     fn parse_values_one(&mut self) -> Result<Value, ParserError> {
+        let start = self.peek()?.span.start;
         let values =
             self.parse_values_any(TokenKind::OpenParenthesis, TokenKind::CloseParenthesis)?;
         if values.len() != 1 {
             Err(ParserError::InvalidNumberOfValues {
                 actual: values.len(),
                 expected: 1,
+                span: self.span_from(start),
             })
         } else {
             Ok(values[0].clone())
@@ -895,13 +1064,12 @@ impl Parser {
     }
 
     fn parse_generics(&mut self, n: usize) -> Result<Vec<ValueKind>, ParserError> {
+        let start = self.peek()?.span.start;
         advance_match!(self, TokenKind::LessThan);
         let mut types = Vec::new();
         while self.peek()?.kind != TokenKind::GreaterThan {
             types.push(self.parse_type()?);
-            if self.peek()?.kind != TokenKind::GreaterThan {
-                advance_match!(self, TokenKind::Comma);
-            }
+            self.advance_separator(TokenKind::GreaterThan)?;
         }
         advance_match!(self, TokenKind::GreaterThan);
 
@@ -909,6 +1077,7 @@ impl Parser {
             Err(ParserError::InvalidNumberOfTypes {
                 expected: n,
                 actual: types.len(),
+                span: self.span_from(start),
             })
         } else {
             Ok(types)
@@ -1150,11 +1319,128 @@ mod tests {
             r#"Address("abc", "def")"#,
             ParserError::InvalidNumberOfValues {
                 actual: 2,
-                expected: 1
+                expected: 1,
+                span: Span {
+                    start: Position {
+                        full_index: 7,
+                        line_number: 1,
+                        line_char_index: 7
+                    },
+                    end: Position {
+                        full_index: 21,
+                        line_number: 1,
+                        line_char_index: 21
+                    }
+                }
             }
         );
     }
 
+    #[test]
+    fn test_missing_separator() {
+        parse_value_error!(
+            r#"Tuple(1u8 2u8)"#,
+            ParserError::UnexpectedToken {
+                expected: TokenType::OneOf(vec![TokenKind::Comma, TokenKind::CloseParenthesis]),
+                actual: Token {
+                    kind: TokenKind::U8Literal(2),
+                    span: Span {
+                        start: Position {
+                            full_index: 10,
+                            line_number: 1,
+                            line_char_index: 10,
+                        },
+                        end: Position {
+                            full_index: 13,
+                            line_number: 1,
+                            line_char_index: 13,
+                        }
+                    }
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_enum_discriminator_suggests_closest_variant() {
+        parse_value_error!(
+            r#"Enum<Metadata::Strnig>("Hello")"#,
+            ParserError::UnknownEnumDiscriminator {
+                discriminator: "Metadata::Strnig".to_string(),
+                span: Span {
+                    start: Position {
+                        full_index: 5,
+                        line_number: 1,
+                        line_char_index: 5,
+                    },
+                    end: Position {
+                        full_index: 21,
+                        line_number: 1,
+                        line_char_index: 21,
+                    }
+                },
+                suggestion: Some("Metadata::String".to_string()),
+            }
+        );
+        parse_value_error!(
+            r#"Enum<Nothing::Like::This>()"#,
+            ParserError::UnknownEnumDiscriminator {
+                discriminator: "Nothing::Like::This".to_string(),
+                span: Span {
+                    start: Position {
+                        full_index: 5,
+                        line_number: 1,
+                        line_char_index: 5,
+                    },
+                    end: Position {
+                        full_index: 24,
+                        line_number: 1,
+                        line_char_index: 24,
+                    }
+                },
+                suggestion: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_instruction_suggests_closest_instruction() {
+        let mut parser = Parser::new(
+            tokenize(r#"CALL_METHD Address("abc") "foo";"#).unwrap(),
+            PARSER_MAX_DEPTH,
+        );
+        assert_eq!(
+            parser.parse_instruction(),
+            Err(ParserError::UnknownInstruction {
+                instruction: "CALL_METHD".to_string(),
+                span: Span {
+                    start: Position {
+                        full_index: 0,
+                        line_number: 1,
+                        line_char_index: 0,
+                    },
+                    end: Position {
+                        full_index: 10,
+                        line_number: 1,
+                        line_char_index: 10,
+                    }
+                },
+                suggestion: Some("CALL_METHOD".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_instruction_idents_are_known() {
+        for ident in InstructionIdent::IDENTS {
+            assert!(
+                InstructionIdent::from_ident(ident).is_some(),
+                "Unknown instruction {}",
+                ident
+            );
+        }
+    }
+
     #[test]
     fn test_deep_value_does_not_panic_with_stack_overflow() {
         let depth: usize = 1000;
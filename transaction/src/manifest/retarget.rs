@@ -0,0 +1,158 @@
+use crate::internal_prelude::*;
+use lexer::TokenKind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetargetError {
+    /// The manifest does not compile on the network it is retargeted from.
+    CompileError(CompileError),
+    InvalidAddress(String),
+    /// The address is mapped to an address of another entity type.
+    EntityTypeMismatch(NodeId, NodeId),
+}
+
+/// Re-encodes all the addresses of a manifest for another network, leaving the rest of the text
+/// (including whitespace and comments) untouched.
+///
+/// Entities which exist at the same address on both networks, e.g. the native packages and
+/// XRD, are re-encoded as is. The others, e.g. components instantiated separately on each
+/// network, must be explicitly mapped to their counterpart in `address_map`.
+pub fn retarget(
+    manifest_text: &str,
+    from_network: &NetworkDefinition,
+    to_network: &NetworkDefinition,
+    address_map: &BTreeMap<NodeId, NodeId>,
+) -> Result<String, RetargetError> {
+    // Rejects any address which is not encoded for the source network
+    compile(manifest_text, from_network, MockBlobProvider::new())
+        .map_err(RetargetError::CompileError)?;
+
+    let address_bech32_decoder = AddressBech32Decoder::new(from_network);
+    let address_bech32_encoder = AddressBech32Encoder::new(to_network);
+    let tokens = lexer::tokenize(manifest_text)
+        .map_err(|e| RetargetError::CompileError(CompileError::LexerError(e)))?;
+
+    let text: Vec<char> = manifest_text.chars().collect();
+    let mut retargeted = String::with_capacity(manifest_text.len());
+    let mut cursor = 0;
+    for window in tokens.windows(3) {
+        let (address, span) = match (&window[0].kind, &window[1].kind, &window[2].kind) {
+            (
+                TokenKind::Ident(ident),
+                TokenKind::OpenParenthesis,
+                TokenKind::StringLiteral(address),
+            ) if ident == "Address" => (address, window[2].span),
+            _ => continue,
+        };
+
+        let node_id = address_bech32_decoder
+            .validate_and_decode(address)
+            .ok()
+            .and_then(|(_, full_data)| full_data.try_into().ok())
+            .map(NodeId)
+            .ok_or_else(|| RetargetError::InvalidAddress(address.clone()))?;
+        let target_node_id = match address_map.get(&node_id) {
+            Some(target_node_id) => {
+                if target_node_id.entity_type() != node_id.entity_type() {
+                    return Err(RetargetError::EntityTypeMismatch(node_id, *target_node_id));
+                }
+                *target_node_id
+            }
+            None => node_id,
+        };
+        let target_address = address_bech32_encoder
+            .encode(target_node_id.as_ref())
+            .map_err(|_| RetargetError::InvalidAddress(address.clone()))?;
+
+        retargeted.extend(&text[cursor..span.start.full_index]);
+        retargeted.push('"');
+        retargeted.push_str(&target_address);
+        retargeted.push('"');
+        cursor = span.end.full_index;
+    }
+    retargeted.extend(&text[cursor..]);
+
+    Ok(retargeted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retarget_maps_non_derivable_addresses_and_keeps_the_rest() {
+        let from_network = NetworkDefinition::kisharnet();
+        let to_network = NetworkDefinition::mainnet();
+        let from_encoder = AddressBech32Encoder::new(&from_network);
+        let to_encoder = AddressBech32Encoder::new(&to_network);
+
+        let from_account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let from_component = ComponentAddress::new_or_panic(
+            NodeId::new(
+                EntityType::GlobalGenericComponent as u8,
+                &[1u8; NodeId::RID_LENGTH],
+            )
+            .0,
+        );
+        let to_component = ComponentAddress::new_or_panic(
+            NodeId::new(
+                EntityType::GlobalGenericComponent as u8,
+                &[2u8; NodeId::RID_LENGTH],
+            )
+            .0,
+        );
+        let manifest = format!(
+            r#"
+# Lock the fee
+CALL_METHOD Address("{account}") "lock_fee" Decimal("10");
+CALL_METHOD Address("{component}") "swap" Address("{xrd}");
+"#,
+            account = from_account.display(&from_encoder),
+            component = from_component.display(&from_encoder),
+            xrd = XRD.display(&from_encoder),
+        );
+
+        let retargeted = retarget(
+            &manifest,
+            &from_network,
+            &to_network,
+            &btreemap!(*from_component.as_node_id() => *to_component.as_node_id()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            retargeted,
+            format!(
+                r#"
+# Lock the fee
+CALL_METHOD Address("{account}") "lock_fee" Decimal("10");
+CALL_METHOD Address("{component}") "swap" Address("{xrd}");
+"#,
+                account = from_account.display(&to_encoder),
+                component = to_component.display(&to_encoder),
+                xrd = XRD.display(&to_encoder),
+            )
+        );
+        assert!(compile(&retargeted, &to_network, MockBlobProvider::new()).is_ok());
+    }
+
+    #[test]
+    fn test_retarget_rejects_addresses_of_another_network() {
+        let encoder = AddressBech32Encoder::new(&NetworkDefinition::mainnet());
+        let manifest = format!(
+            r#"CALL_METHOD Address("{}") "lock_fee" Decimal("10");"#,
+            FAUCET.display(&encoder)
+        );
+
+        assert!(matches!(
+            retarget(
+                &manifest,
+                &NetworkDefinition::kisharnet(),
+                &NetworkDefinition::mainnet(),
+                &BTreeMap::new(),
+            ),
+            Err(RetargetError::CompileError(..))
+        ));
+    }
+}
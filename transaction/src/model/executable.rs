@@ -20,6 +20,8 @@ pub struct ExecutionContext {
     pub payload_size: usize,
     pub auth_zone_params: AuthZoneParams,
     pub fee_payment: FeePayment,
+    /// The message attached to the intent, if any.
+    pub message: MessageV1,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -157,4 +159,8 @@ impl<'a> Executable<'a> {
     pub fn payload_size(&self) -> usize {
         self.context.payload_size
     }
+
+    pub fn message(&self) -> &MessageV1 {
+        &self.context.message
+    }
 }
@@ -2,6 +2,7 @@ mod concepts;
 mod executable;
 mod hash;
 mod preparation;
+mod protocol_version;
 mod v1;
 mod versioned;
 
@@ -9,5 +10,6 @@ pub use concepts::*;
 pub use executable::*;
 pub use hash::*;
 pub use preparation::*;
+pub use protocol_version::*;
 pub use v1::*;
 pub use versioned::*;
@@ -0,0 +1,33 @@
+use crate::internal_prelude::*;
+use sbor::rust::fmt;
+
+/// A version of the protocol run by a network, which determines the instructions that its
+/// transactions may use.
+///
+/// Versions are ordered, so that a later version compares greater than an earlier one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ScryptoSbor, ManifestSbor)]
+pub enum ProtocolVersion {
+    /// The initial version of the protocol.
+    V1,
+    /// Adds the next call assertions, the metadata assertion and the savepoint instructions.
+    V2,
+}
+
+impl ProtocolVersion {
+    pub const LATEST: Self = Self::V2;
+}
+
+impl Default for ProtocolVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::V1 => write!(f, "v1"),
+            Self::V2 => write!(f, "v2"),
+        }
+    }
+}
@@ -296,6 +296,23 @@ impl TryFrom<ManifestAddress> for DynamicResourceAddress {
     }
 }
 
+/// A resource which the next call is expected to return, see
+/// [`InstructionV1::AssertNextCallReturnsInclude`] and [`InstructionV1::AssertNextCallReturnsExactly`].
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor, ScryptoSbor)]
+pub enum ResourceAssertion {
+    Amount(ResourceAddress, Decimal),
+    NonFungibles(ResourceAddress, Vec<NonFungibleLocalId>),
+}
+
+impl ResourceAssertion {
+    pub fn resource_address(&self) -> ResourceAddress {
+        match self {
+            Self::Amount(resource_address, _) => *resource_address,
+            Self::NonFungibles(resource_address, _) => *resource_address,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
 pub enum InstructionV1 {
     //==============
@@ -341,6 +358,32 @@ pub enum InstructionV1 {
         ids: Vec<NonFungibleLocalId>,
     },
 
+    //==============
+    // Next call assertions
+    //==============
+    /// Asserts the buckets returned by the next call contain at least the given resources.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_NEXT_CALL_RETURNS_INCLUDE_DISCRIMINATOR))]
+    AssertNextCallReturnsInclude { resources: Vec<ResourceAssertion> },
+
+    /// Asserts the buckets returned by the next call contain exactly the given resources, and nothing else.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_NEXT_CALL_RETURNS_EXACTLY_DISCRIMINATOR))]
+    AssertNextCallReturnsExactly { resources: Vec<ResourceAssertion> },
+
+    /// Asserts the value returned by the next call is equal to the given value.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_NEXT_CALL_RETURNS_VALUE_DISCRIMINATOR))]
+    AssertNextCallReturnsValue { value: ManifestValue },
+
+    //==============
+    // Metadata assertions
+    //==============
+    /// Asserts the metadata entry under the given key of a global entity is equal to the given value.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_METADATA_DISCRIMINATOR))]
+    AssertMetadata {
+        address: DynamicGlobalAddress,
+        key: String,
+        value: MetadataValue,
+    },
+
     //==============
     // Auth zone
     //==============
@@ -467,6 +510,70 @@ pub enum InstructionV1 {
         package_address: PackageAddress,
         blueprint_name: String,
     },
+
+    //==============
+    // Savepoints
+    //==============
+    /// Starts an optional section, which is ended by the matching `RollbackToSavepoint`.
+    ///
+    /// If an instruction in the section fails, the changes made by the section are reverted and
+    /// execution resumes after the matching `RollbackToSavepoint`. Fees consumed by the section
+    /// are still charged, and fees locked by it remain locked.
+    #[sbor(discriminator(INSTRUCTION_SAVEPOINT_DISCRIMINATOR))]
+    Savepoint,
+
+    /// Ends the section started by the matching `Savepoint`, reverting its changes if one of its
+    /// instructions failed, or keeping them otherwise.
+    #[sbor(discriminator(INSTRUCTION_ROLLBACK_TO_SAVEPOINT_DISCRIMINATOR))]
+    RollbackToSavepoint,
+}
+
+impl InstructionV1 {
+    /// The protocol version from which the instruction is accepted.
+    pub fn introduced_in(&self) -> ProtocolVersion {
+        match self {
+            InstructionV1::TakeAllFromWorktop { .. }
+            | InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. }
+            | InstructionV1::ReturnToWorktop { .. }
+            | InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::ClearAuthZone
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+            | InstructionV1::ClearSignatureProofs
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::BurnResource { .. }
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::CallFunction { .. }
+            | InstructionV1::CallMethod { .. }
+            | InstructionV1::CallRoyaltyMethod { .. }
+            | InstructionV1::CallMetadataMethod { .. }
+            | InstructionV1::CallAccessRulesMethod { .. }
+            | InstructionV1::CallDirectVaultMethod { .. }
+            | InstructionV1::DropAllProofs
+            | InstructionV1::AllocateGlobalAddress { .. } => ProtocolVersion::V1,
+            InstructionV1::AssertNextCallReturnsInclude { .. }
+            | InstructionV1::AssertNextCallReturnsExactly { .. }
+            | InstructionV1::AssertNextCallReturnsValue { .. }
+            | InstructionV1::AssertMetadata { .. }
+            | InstructionV1::Savepoint
+            | InstructionV1::RollbackToSavepoint => ProtocolVersion::V2,
+        }
+    }
+
+    /// The protocol version from which the instruction is no longer accepted, if any.
+    pub fn deprecated_in(&self) -> Option<ProtocolVersion> {
+        // No instruction has been deprecated yet
+        None
+    }
 }
 
 //===============================================================
@@ -493,6 +600,18 @@ pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_DISCRIMINATOR: u8 = 0x04;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_NON_FUNGIBLES_DISCRIMINATOR: u8 = 0x05;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_ANY_DISCRIMINATOR: u8 = 0x06;
 
+//==============
+// Next call assertions
+//==============
+pub const INSTRUCTION_ASSERT_NEXT_CALL_RETURNS_INCLUDE_DISCRIMINATOR: u8 = 0x07;
+pub const INSTRUCTION_ASSERT_NEXT_CALL_RETURNS_EXACTLY_DISCRIMINATOR: u8 = 0x08;
+pub const INSTRUCTION_ASSERT_NEXT_CALL_RETURNS_VALUE_DISCRIMINATOR: u8 = 0x09;
+
+//==============
+// Metadata assertions
+//==============
+pub const INSTRUCTION_ASSERT_METADATA_DISCRIMINATOR: u8 = 0x0A;
+
 //==============
 // Auth zone
 //==============
@@ -533,3 +652,9 @@ pub const INSTRUCTION_CALL_DIRECT_VAULT_METHOD_DISCRIMINATOR: u8 = 0x45;
 //==============
 pub const INSTRUCTION_DROP_ALL_PROOFS_DISCRIMINATOR: u8 = 0x50;
 pub const INSTRUCTION_ALLOCATE_GLOBAL_ADDRESS_DISCRIMINATOR: u8 = 0x51;
+
+//==============
+// Savepoints
+//==============
+pub const INSTRUCTION_SAVEPOINT_DISCRIMINATOR: u8 = 0x60;
+pub const INSTRUCTION_ROLLBACK_TO_SAVEPOINT_DISCRIMINATOR: u8 = 0x61;
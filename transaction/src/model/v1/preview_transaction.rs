@@ -6,6 +6,9 @@ pub struct PreviewFlags {
     pub use_free_credit: bool,
     pub assume_all_signature_proofs: bool,
     pub skip_epoch_check: bool,
+    /// Seeds the RUIDs generated by the transaction instead of its hash, so that repeated previews
+    /// of a transaction which is still being built produce stable ids.
+    pub ruid_seed: Option<Hash>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
@@ -81,6 +84,7 @@ impl ValidatedPreviewIntent {
                     virtual_resources,
                 },
                 fee_payment,
+                message: intent.message.inner.clone(),
                 pre_allocated_addresses: vec![],
             },
         )
@@ -118,6 +118,7 @@ impl PreparedSystemTransactionV1 {
                     tip_percentage: 0,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
+                message: MessageV1::None,
                 pre_allocated_addresses: self.pre_allocated_addresses.inner.clone(),
             },
         )
@@ -10,6 +10,7 @@ use std::collections::BTreeSet;
 pub struct TestTransaction {
     pub instructions: InstructionsV1,
     pub blobs: BlobsV1,
+    pub message: MessageV1,
     pub hash: Hash,
 }
 
@@ -18,6 +19,7 @@ pub struct PreparedTestTransaction {
     pub encoded_instructions: Vec<u8>,
     pub references: IndexSet<Reference>,
     pub blobs: IndexMap<Hash, Vec<u8>>,
+    pub message: MessageV1,
     pub hash: Hash,
 }
 
@@ -32,16 +34,24 @@ impl TestTransaction {
         Self {
             instructions,
             blobs,
+            message: MessageV1::None,
             hash,
         }
     }
 
+    /// Attaches a message, as an intent would carry, e.g. a transfer memo.
+    pub fn with_message(mut self, message: MessageV1) -> Self {
+        self.message = message;
+        self
+    }
+
     pub fn prepare(self) -> Result<PreparedTestTransaction, PrepareError> {
         let prepared_instructions = self.instructions.prepare_partial()?;
         Ok(PreparedTestTransaction {
             encoded_instructions: manifest_encode(&prepared_instructions.inner.0)?,
             references: prepared_instructions.references,
             blobs: self.blobs.prepare_partial()?.blobs_by_hash,
+            message: self.message,
             hash: self.hash,
         })
     }
@@ -71,6 +81,7 @@ impl PreparedTestTransaction {
                     tip_percentage: DEFAULT_TIP_PERCENTAGE,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
+                message: self.message.clone(),
                 pre_allocated_addresses: vec![],
             },
         )
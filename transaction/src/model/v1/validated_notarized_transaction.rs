@@ -55,6 +55,7 @@ impl ValidatedNotarizedTransactionV1 {
                     tip_percentage: intent.header.inner.tip_percentage,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
+                message: intent.message.inner.clone(),
                 pre_allocated_addresses: vec![],
             },
         )
@@ -31,6 +31,17 @@ pub struct ManifestValidator {
     address_reservation_ids: IndexSet<ManifestAddressReservation>,
     /// Set of named global address ids
     address_ids: IndexSet<u32>,
+    /// The named ids alive at each open savepoint
+    savepoints: Vec<NamedIds>,
+}
+
+/// The named ids alive at some point of a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NamedIds {
+    bucket_ids: NonIterMap<ManifestBucket, usize>,
+    proof_ids: NonIterMap<ManifestProof, ProofKind>,
+    address_reservation_ids: IndexSet<ManifestAddressReservation>,
+    address_ids: IndexSet<u32>,
 }
 
 impl ManifestValidator {
@@ -162,6 +173,43 @@ impl ManifestValidator {
         }
     }
 
+    pub fn new_savepoint(&mut self) {
+        let named_ids = self.named_ids();
+        self.savepoints.push(named_ids);
+    }
+
+    /// Ends the section of the last savepoint.
+    ///
+    /// A section must leave the named buckets, proofs and addresses as it found them, so that they
+    /// are the same whether or not it is rolled back.
+    pub fn drop_savepoint(&mut self) -> Result<(), ManifestIdValidationError> {
+        let named_ids = self
+            .savepoints
+            .pop()
+            .ok_or(ManifestIdValidationError::NoSavepointToRollBackTo)?;
+        if named_ids != self.named_ids() {
+            return Err(ManifestIdValidationError::NamedIdsChangedInSavepointSection);
+        }
+        Ok(())
+    }
+
+    pub fn check_all_savepoints_dropped(&self) -> Result<(), ManifestIdValidationError> {
+        if self.savepoints.is_empty() {
+            Ok(())
+        } else {
+            Err(ManifestIdValidationError::SavepointNotRolledBack)
+        }
+    }
+
+    fn named_ids(&self) -> NamedIds {
+        NamedIds {
+            bucket_ids: self.bucket_ids.clone(),
+            proof_ids: self.proof_ids.clone(),
+            address_reservation_ids: self.address_reservation_ids.clone(),
+            address_ids: self.address_ids.clone(),
+        }
+    }
+
     pub fn process_call_data(
         &mut self,
         args: &ManifestValue,
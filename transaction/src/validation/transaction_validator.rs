@@ -45,6 +45,47 @@ pub trait TransactionValidator<Prepared: TransactionPayloadPreparable> {
     ) -> Result<Self::Validated, TransactionValidationError>;
 }
 
+fn validate_header_epoch_range(
+    header: &TransactionHeaderV1,
+    config: &ValidationConfig,
+) -> Result<(), HeaderValidationError> {
+    if header.end_epoch_exclusive <= header.start_epoch_inclusive {
+        return Err(HeaderValidationError::InvalidEpochRange);
+    }
+    let max_end_epoch = header.start_epoch_inclusive.after(config.max_epoch_range);
+    if header.end_epoch_exclusive > max_end_epoch {
+        return Err(HeaderValidationError::EpochRangeTooLarge);
+    }
+
+    Ok(())
+}
+
+/// Validates the epoch window of a transaction header at the given current epoch, exactly as a node
+/// does: first statically when the transaction is received, then against the current epoch of the
+/// ledger when the transaction is executed (which would result in a rejection).
+pub fn validate_header_epoch_window(
+    header: &TransactionHeaderV1,
+    current_epoch: Epoch,
+    config: &ValidationConfig,
+) -> Result<(), EpochWindowValidationError> {
+    validate_header_epoch_range(header, config)?;
+
+    if current_epoch < header.start_epoch_inclusive {
+        return Err(EpochWindowValidationError::TransactionEpochNotYetValid {
+            valid_from: header.start_epoch_inclusive,
+            current_epoch,
+        });
+    }
+    if current_epoch >= header.end_epoch_exclusive {
+        return Err(EpochWindowValidationError::TransactionEpochNoLongerValid {
+            valid_until: header.end_epoch_exclusive.previous(),
+            current_epoch,
+        });
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ValidationConfig {
     pub network_id: u8,
@@ -55,6 +96,8 @@ pub struct ValidationConfig {
     pub max_tip_percentage: u16,
     pub max_epoch_range: u64,
     pub message_validation: MessageValidationConfig,
+    /// The protocol version of the network, which determines the accepted instructions
+    pub protocol_version: ProtocolVersion,
 }
 
 impl ValidationConfig {
@@ -68,9 +111,15 @@ impl ValidationConfig {
             max_tip_percentage: DEFAULT_MAX_TIP_PERCENTAGE,
             max_epoch_range: DEFAULT_MAX_EPOCH_RANGE,
             message_validation: MessageValidationConfig::default(),
+            protocol_version: ProtocolVersion::LATEST,
         }
     }
 
+    pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
     pub fn simulator() -> Self {
         Self::default(NetworkDefinition::simulator().id)
     }
@@ -160,11 +209,41 @@ impl NotarizedTransactionValidator {
 
         self.validate_message_v1(&intent.message.inner)?;
 
+        self.validate_instruction_versions_v1(&intent.instructions.inner.0)?;
+
         Self::validate_instructions_v1(&intent.instructions.inner.0)?;
 
         return Ok(());
     }
 
+    /// Checks that every instruction is accepted by the configured protocol version.
+    pub fn validate_instruction_versions_v1(
+        &self,
+        instructions: &[InstructionV1],
+    ) -> Result<(), InactiveInstructionError> {
+        let protocol_version = self.config.protocol_version;
+        for (instruction_index, instruction) in instructions.iter().enumerate() {
+            let introduced_in = instruction.introduced_in();
+            if protocol_version < introduced_in {
+                return Err(InactiveInstructionError::NotYetIntroduced {
+                    instruction_index,
+                    introduced_in,
+                    protocol_version,
+                });
+            }
+            if let Some(deprecated_in) = instruction.deprecated_in() {
+                if protocol_version >= deprecated_in {
+                    return Err(InactiveInstructionError::Deprecated {
+                        instruction_index,
+                        deprecated_in,
+                        protocol_version,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn validate_instructions_v1(
         instructions: &[InstructionV1],
     ) -> Result<(), TransactionValidationError> {
@@ -189,6 +268,10 @@ impl NotarizedTransactionValidator {
                 InstructionV1::AssertWorktopContainsAny { .. } => {}
                 InstructionV1::AssertWorktopContains { .. } => {}
                 InstructionV1::AssertWorktopContainsNonFungibles { .. } => {}
+                InstructionV1::AssertNextCallReturnsInclude { .. } => {}
+                InstructionV1::AssertNextCallReturnsExactly { .. } => {}
+                InstructionV1::AssertNextCallReturnsValue { .. } => {}
+                InstructionV1::AssertMetadata { .. } => {}
                 InstructionV1::PopFromAuthZone => {
                     let _ = id_validator
                         .new_proof(ProofKind::AuthZoneProof)
@@ -264,8 +347,19 @@ impl NotarizedTransactionValidator {
                     let _ = id_validator.new_address_reservation();
                     id_validator.new_named_address();
                 }
+                InstructionV1::Savepoint => {
+                    id_validator.new_savepoint();
+                }
+                InstructionV1::RollbackToSavepoint => {
+                    id_validator
+                        .drop_savepoint()
+                        .map_err(TransactionValidationError::IdValidationError)?;
+                }
             }
         }
+        id_validator
+            .check_all_savepoints_dropped()
+            .map_err(TransactionValidationError::IdValidationError)?;
 
         Ok(())
     }
@@ -280,15 +374,7 @@ impl NotarizedTransactionValidator {
         }
 
         // epoch
-        if header.end_epoch_exclusive <= header.start_epoch_inclusive {
-            return Err(HeaderValidationError::InvalidEpochRange);
-        }
-        let max_end_epoch = header
-            .start_epoch_inclusive
-            .after(self.config.max_epoch_range);
-        if header.end_epoch_exclusive > max_end_epoch {
-            return Err(HeaderValidationError::EpochRangeTooLarge);
-        }
+        validate_header_epoch_range(header, &self.config)?;
 
         // tip percentage
         if header.tip_percentage < self.config.min_tip_percentage
@@ -472,6 +558,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_header_epoch_window() {
+        let config = ValidationConfig::simulator();
+        let header = create_transaction(Epoch::of(10), Epoch::of(20), 5, vec![1], 2)
+            .signed_intent
+            .intent
+            .header;
+
+        assert_eq!(
+            validate_header_epoch_window(&header, Epoch::of(9), &config),
+            Err(EpochWindowValidationError::TransactionEpochNotYetValid {
+                valid_from: Epoch::of(10),
+                current_epoch: Epoch::of(9),
+            })
+        );
+        assert_eq!(
+            validate_header_epoch_window(&header, Epoch::of(10), &config),
+            Ok(())
+        );
+        assert_eq!(
+            validate_header_epoch_window(&header, Epoch::of(19), &config),
+            Ok(())
+        );
+        assert_eq!(
+            validate_header_epoch_window(&header, Epoch::of(20), &config),
+            Err(EpochWindowValidationError::TransactionEpochNoLongerValid {
+                valid_until: Epoch::of(19),
+                current_epoch: Epoch::of(20),
+            })
+        );
+
+        let header = create_transaction(Epoch::of(10), Epoch::of(10), 5, vec![1], 2)
+            .signed_intent
+            .intent
+            .header;
+        assert_eq!(
+            validate_header_epoch_window(&header, Epoch::of(10), &config),
+            Err(EpochWindowValidationError::HeaderValidationError(
+                HeaderValidationError::InvalidEpochRange
+            ))
+        );
+    }
+
     #[test]
     fn test_invalid_signatures() {
         assert_invalid_tx!(
@@ -502,6 +631,7 @@ mod tests {
                 use_free_credit: true,
                 assume_all_signature_proofs: false,
                 skip_epoch_check: false,
+                ruid_seed: None,
             },
         };
 
@@ -697,6 +827,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_instructions_gated_by_protocol_version() {
+        let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let transaction = TransactionBuilder::new()
+            .header(TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::of(1),
+                end_epoch_exclusive: Epoch::of(10),
+                nonce: 0,
+                notary_public_key: sk_notary.public_key().into(),
+                notary_is_signatory: false,
+                tip_percentage: 5,
+            })
+            .manifest(
+                ManifestBuilder::new()
+                    .clear_auth_zone()
+                    .savepoint()
+                    .rollback_to_savepoint()
+                    .build(),
+            )
+            .notarize(&sk_notary)
+            .build();
+
+        // Accepted by the latest protocol version
+        assert!(validate_default(&transaction).is_ok());
+
+        // Rejected by a protocol version preceding the savepoint instructions
+        let validator = NotarizedTransactionValidator::new(
+            ValidationConfig::simulator().with_protocol_version(ProtocolVersion::V1),
+        );
+        assert_eq!(
+            validator.validate(transaction.prepare().unwrap()),
+            Err(TransactionValidationError::InactiveInstruction(
+                InactiveInstructionError::NotYetIntroduced {
+                    instruction_index: 1,
+                    introduced_in: ProtocolVersion::V2,
+                    protocol_version: ProtocolVersion::V1,
+                }
+            ))
+        );
+
+        // Instructions of the initial protocol version are still accepted
+        assert!(validator
+            .validate(
+                create_transaction(Epoch::of(1), Epoch::of(10), 0, vec![], 1)
+                    .prepare()
+                    .unwrap()
+            )
+            .is_ok());
+    }
+
     fn validate_default_expecting_message_error(
         transaction: &NotarizedTransactionV1,
     ) -> InvalidMessageError {
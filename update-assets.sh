@@ -68,3 +68,16 @@ cp \
   ../metadata.rpd
 
 echo "Done!"
+
+echo "Building nft_marketplace..."
+(cd nft_marketplace; $scrypto build)
+npx wasm-opt@1.3 \
+  -Os -g \
+  --strip-debug --strip-dwarf --strip-producers \
+  -o ../nft_marketplace.wasm \
+  ./target/wasm32-unknown-unknown/release/nft_marketplace.wasm
+cp \
+  ./target/wasm32-unknown-unknown/release/nft_marketplace.rpd \
+  ../nft_marketplace.rpd
+
+echo "Done!"